@@ -8,21 +8,26 @@ use serde::{Serialize, de::DeserializeOwned};
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
-use crate::cache::{CacheStorage, CacheTtl, cache_key};
+use crate::cache::redact::redact_alert_message;
+use crate::cache::{CacheStorage, CacheTtl, cache_key, url_cache_key};
 use crate::client::api::{
     AppApi, AuthApi, ConfigApi, EnvironmentApi, ListingApi, OASApi, PerchApi, RepoApi,
-    ScanDetailApi, TeamApi,
+    ScanDetailApi, TeamApi, TriageApi,
 };
 use crate::client::models::{
     AlertMsgResponse, AlertResponse, Application, ApplicationAlert, AuditFilterParams, AuditRecord,
-    ConfigType, CreateApplicationRequest, CreateTeamRequest, CurrentFindingsResponse, Environment,
-    JwtToken, OASAsset, OrgPolicy, Organization, PerchCommandResponse, PerchDevice,
-    ReplaceRepoAppMappingsRequest, ReplaceRepoAppMappingsResponse, Repository, ScanConfig,
-    ScanResult, Secret, StackHawkPolicy, Team, TeamDetail, UpdateApplicationTeamRequest,
-    UpdateTeamRequest, User, ValidatedAssetResponse,
+    BulkTriageRequest, BulkTriageResponse, ConfigType, CreateApplicationRequest, CreateTeamRequest,
+    CurrentFindingsResponse, Environment, JwtToken, OASAsset, OrgPolicy, Organization,
+    PerchCommandResponse, PerchDevice, ReplaceRepoAppMappingsRequest,
+    ReplaceRepoAppMappingsResponse, Repository, ScanConfig, ScanResult, Secret, StackHawkPolicy,
+    Team, TeamDetail, UpdateApplicationTeamRequest, UpdateTeamRequest, User,
+    ValidatedAssetResponse,
+};
+use crate::client::{
+    PagedResponse, PaginationParams, ScanFilterParams, StackHawkClient, cancel, stats,
 };
-use crate::client::{PagedResponse, PaginationParams, ScanFilterParams};
 use crate::error::Result;
+use crate::warnings::{WarningKind, warn};
 
 /// Cached wrapper for any client implementing the API traits.
 ///
@@ -38,6 +43,12 @@ pub struct CachedStackHawkClient<C: AuthApi + ListingApi + ScanDetailApi> {
     cache: Option<Arc<Mutex<CacheStorage>>>,
     /// API host used for cache key generation (prevents cross-environment cache hits)
     api_host: Option<String>,
+    /// Serve cached entries past their TTL (`--offline`)
+    offline: bool,
+    /// Redact credentials in scan messages before caching them
+    redact_sensitive: bool,
+    /// Skip cache reads but still cache responses (background refresh)
+    refresh: bool,
 }
 
 impl<C: AuthApi + ListingApi + ScanDetailApi> CachedStackHawkClient<C> {
@@ -70,6 +81,41 @@ impl<C: AuthApi + ListingApi + ScanDetailApi> CachedStackHawkClient<C> {
             inner: Arc::new(inner),
             cache,
             api_host,
+            offline: false,
+            redact_sensitive: false,
+            refresh: false,
+        }
+    }
+
+    /// Serve cached entries regardless of TTL (`--offline`).
+    ///
+    /// The inner client is expected to refuse network requests, so a cache
+    /// miss fails fast instead of reaching the API.
+    pub fn with_offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Redact credential headers and cookies in scan messages before they
+    /// are cached (`cache.redact_sensitive`).
+    ///
+    /// Only the cached copy is redacted; the response that was fetched is
+    /// returned unchanged.
+    pub fn with_redact_sensitive(mut self, redact: bool) -> Self {
+        self.redact_sensitive = redact;
+        self
+    }
+
+    /// A handle on the same client and cache that always fetches from the API
+    /// and caches what it gets, for refreshing entries before they expire
+    pub fn refreshing(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            cache: self.cache.clone(),
+            api_host: self.api_host.clone(),
+            offline: false,
+            redact_sensitive: self.redact_sensitive,
+            refresh: true,
         }
     }
 
@@ -81,19 +127,36 @@ impl<C: AuthApi + ListingApi + ScanDetailApi> CachedStackHawkClient<C> {
 
     /// Try to get cached data (runs on blocking thread pool)
     async fn get_cached<T: DeserializeOwned + Send + 'static>(&self, key: &str) -> Option<T> {
+        if self.refresh {
+            return None;
+        }
         let cache = self.cache.clone()?;
         let key = key.to_string();
+        let offline = self.offline;
 
-        tokio::task::spawn_blocking(move || {
+        let cached = tokio::task::spawn_blocking(move || {
             let guard = cache.lock().ok()?;
-            guard
-                .get(&key)
-                .ok()
-                .flatten()
-                .and_then(|data| serde_json::from_slice(&data).ok())
+            let (data, expired) = if offline {
+                let (data, expires_at) = guard.get_any(&key).ok().flatten()?;
+                (data, expires_at <= chrono::Utc::now().timestamp())
+            } else {
+                (guard.get(&key).ok().flatten()?, false)
+            };
+            serde_json::from_slice(&data).ok().map(|v| (v, expired))
         })
         .await
-        .ok()?
+        .ok()
+        .flatten();
+        stats::record_cache(cached.is_some());
+        let (value, expired) = cached?;
+
+        if expired {
+            warn(
+                WarningKind::StaleCache,
+                "Offline: showing cached data past its refresh time",
+            );
+        }
+        Some(value)
     }
 
     /// Store data in cache (runs on blocking thread pool, fire-and-forget)
@@ -105,6 +168,12 @@ impl<C: AuthApi + ListingApi + ScanDetailApi> CachedStackHawkClient<C> {
         org_id: Option<&str>,
         ttl: Duration,
     ) {
+        // After cancellation, fetchers may have returned partial lists
+        if cancel::reason().is_some() {
+            log::debug!("Cancelled: not caching {}", key);
+            return;
+        }
+
         // Serialize before spawning to avoid Send requirement on T reference
         let json = match serde_json::to_vec(data) {
             Ok(j) => j,
@@ -184,6 +253,28 @@ impl<C: AuthApi + ListingApi + ScanDetailApi> CachedStackHawkClient<C> {
             });
         }
     }
+
+    /// Invalidate cached findings and triage state.
+    ///
+    /// Called after triage so scan alerts, paths, and scan stats reflect the
+    /// new statuses. Alert entries are not keyed by org, so all are cleared.
+    fn invalidate_findings_cache(&self, org_id: &str) {
+        if let Some(ref cache) = self.cache {
+            let cache = cache.clone();
+            let org_id = org_id.to_string();
+
+            // Fire-and-forget: don't block waiting for cache clear
+            tokio::task::spawn_blocking(move || {
+                if let Ok(guard) = cache.lock() {
+                    let _ = guard.delete_by_endpoint("list_scan_alerts", None);
+                    let _ = guard.delete_by_endpoint("get_alert_with_paths", None);
+                    let _ = guard.delete_by_endpoint("get_scan", Some(&org_id));
+                    let _ = guard.delete_by_endpoint("list_org_findings", Some(&org_id));
+                    log::debug!("Invalidated findings cache for org {}", org_id);
+                }
+            });
+        }
+    }
 }
 
 /// Convert pagination params to cache key params
@@ -271,6 +362,37 @@ fn audit_filters_to_params(filters: Option<&AuditFilterParams>) -> Vec<(&'static
 // AuthApi Implementation
 // ============================================================================
 
+impl CachedStackHawkClient<StackHawkClient> {
+    /// Raw API request (`hawkop api`), optionally caching GET responses
+    ///
+    /// Nothing is cached unless `ttl` is given: the response is then read
+    /// from and stored under the request's URL (see [`url_cache_key`]) for
+    /// that long. Requests with another method or a body always reach the
+    /// API.
+    pub async fn raw_request(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<&serde_json::Value>,
+        ttl: Option<Duration>,
+    ) -> Result<serde_json::Value> {
+        let ttl = ttl.filter(|_| method == reqwest::Method::GET && body.is_none());
+        let Some(ttl) = ttl else {
+            return self.inner.raw_request(method, path, body).await;
+        };
+
+        let key = url_cache_key(&self.inner.raw_url(path), self.api_host.as_deref());
+        if let Some(cached) = self.get_cached(&key).await {
+            log::debug!("Cache hit: api {}", path);
+            return Ok(cached);
+        }
+
+        let result = self.inner.raw_request(method, path, None).await?;
+        self.set_cached(&key, &result, "api", None, ttl);
+        Ok(result)
+    }
+}
+
 #[async_trait]
 impl<C: AuthApi + ListingApi + ScanDetailApi + 'static> AuthApi for CachedStackHawkClient<C> {
     /// Authenticate - NEVER cached (security sensitive)
@@ -294,7 +416,7 @@ impl<C: AuthApi + ListingApi + ScanDetailApi + 'static> ListingApi for CachedSta
         }
 
         let result = self.inner.list_orgs().await?;
-        self.set_cached(&key, &result, "list_orgs", None, CacheTtl::ORGS);
+        self.set_cached(&key, &result, "list_orgs", None, CacheTtl::ORGS.duration());
         Ok(result)
     }
 
@@ -318,7 +440,13 @@ impl<C: AuthApi + ListingApi + ScanDetailApi + 'static> ListingApi for CachedSta
         }
 
         let result = self.inner.list_apps(org_id, pagination).await?;
-        self.set_cached(&key, &result, "list_apps", Some(org_id), CacheTtl::APPS);
+        self.set_cached(
+            &key,
+            &result,
+            "list_apps",
+            Some(org_id),
+            CacheTtl::APPS.duration(),
+        );
         Ok(result)
     }
 
@@ -347,7 +475,7 @@ impl<C: AuthApi + ListingApi + ScanDetailApi + 'static> ListingApi for CachedSta
             &result,
             "list_apps_paged",
             Some(org_id),
-            CacheTtl::APPS,
+            CacheTtl::APPS.duration(),
         );
         Ok(result)
     }
@@ -379,7 +507,7 @@ impl<C: AuthApi + ListingApi + ScanDetailApi + 'static> ListingApi for CachedSta
             &result,
             "list_scans",
             Some(org_id),
-            CacheTtl::SCAN_LIST,
+            CacheTtl::SCAN_LIST.duration(),
         );
         Ok(result)
     }
@@ -414,7 +542,7 @@ impl<C: AuthApi + ListingApi + ScanDetailApi + 'static> ListingApi for CachedSta
             &result,
             "list_scans_paged",
             Some(org_id),
-            CacheTtl::SCAN_LIST,
+            CacheTtl::SCAN_LIST.duration(),
         );
         Ok(result)
     }
@@ -439,7 +567,13 @@ impl<C: AuthApi + ListingApi + ScanDetailApi + 'static> ListingApi for CachedSta
         }
 
         let result = self.inner.list_users(org_id, pagination).await?;
-        self.set_cached(&key, &result, "list_users", Some(org_id), CacheTtl::USERS);
+        self.set_cached(
+            &key,
+            &result,
+            "list_users",
+            Some(org_id),
+            CacheTtl::USERS.duration(),
+        );
         Ok(result)
     }
 
@@ -463,7 +597,13 @@ impl<C: AuthApi + ListingApi + ScanDetailApi + 'static> ListingApi for CachedSta
         }
 
         let result = self.inner.list_teams(org_id, pagination).await?;
-        self.set_cached(&key, &result, "list_teams", Some(org_id), CacheTtl::TEAMS);
+        self.set_cached(
+            &key,
+            &result,
+            "list_teams",
+            Some(org_id),
+            CacheTtl::TEAMS.duration(),
+        );
         Ok(result)
     }
 
@@ -492,7 +632,7 @@ impl<C: AuthApi + ListingApi + ScanDetailApi + 'static> ListingApi for CachedSta
             &result,
             "list_users_paged",
             Some(org_id),
-            CacheTtl::USERS,
+            CacheTtl::USERS.duration(),
         );
         Ok(result)
     }
@@ -522,7 +662,7 @@ impl<C: AuthApi + ListingApi + ScanDetailApi + 'static> ListingApi for CachedSta
             &result,
             "list_teams_paged",
             Some(org_id),
-            CacheTtl::TEAMS,
+            CacheTtl::TEAMS.duration(),
         );
         Ok(result)
     }
@@ -546,7 +686,7 @@ impl<C: AuthApi + ListingApi + ScanDetailApi + 'static> ListingApi for CachedSta
             &result,
             "list_stackhawk_policies",
             None,
-            CacheTtl::POLICIES,
+            CacheTtl::POLICIES.duration(),
         );
         Ok(result)
     }
@@ -576,7 +716,7 @@ impl<C: AuthApi + ListingApi + ScanDetailApi + 'static> ListingApi for CachedSta
             &result,
             "list_org_policies",
             Some(org_id),
-            CacheTtl::POLICIES,
+            CacheTtl::POLICIES.duration(),
         );
         Ok(result)
     }
@@ -601,7 +741,13 @@ impl<C: AuthApi + ListingApi + ScanDetailApi + 'static> ListingApi for CachedSta
         }
 
         let result = self.inner.list_repos(org_id, pagination).await?;
-        self.set_cached(&key, &result, "list_repos", Some(org_id), CacheTtl::REPOS);
+        self.set_cached(
+            &key,
+            &result,
+            "list_repos",
+            Some(org_id),
+            CacheTtl::REPOS.duration(),
+        );
         Ok(result)
     }
 
@@ -625,7 +771,13 @@ impl<C: AuthApi + ListingApi + ScanDetailApi + 'static> ListingApi for CachedSta
         }
 
         let result = self.inner.list_oas(org_id, pagination).await?;
-        self.set_cached(&key, &result, "list_oas", Some(org_id), CacheTtl::OAS);
+        self.set_cached(
+            &key,
+            &result,
+            "list_oas",
+            Some(org_id),
+            CacheTtl::OAS.duration(),
+        );
         Ok(result)
     }
 
@@ -654,7 +806,7 @@ impl<C: AuthApi + ListingApi + ScanDetailApi + 'static> ListingApi for CachedSta
             &result,
             "list_scan_configs",
             Some(org_id),
-            CacheTtl::SCAN_CONFIGS,
+            CacheTtl::SCAN_CONFIGS.duration(),
         );
         Ok(result)
     }
@@ -668,7 +820,13 @@ impl<C: AuthApi + ListingApi + ScanDetailApi + 'static> ListingApi for CachedSta
         }
 
         let result = self.inner.list_secrets().await?;
-        self.set_cached(&key, &result, "list_secrets", None, CacheTtl::SECRETS);
+        self.set_cached(
+            &key,
+            &result,
+            "list_secrets",
+            None,
+            CacheTtl::SECRETS.duration(),
+        );
         Ok(result)
     }
 
@@ -692,7 +850,13 @@ impl<C: AuthApi + ListingApi + ScanDetailApi + 'static> ListingApi for CachedSta
         }
 
         let result = self.inner.list_audit(org_id, filters).await?;
-        self.set_cached(&key, &result, "list_audit", Some(org_id), CacheTtl::AUDIT);
+        self.set_cached(
+            &key,
+            &result,
+            "list_audit",
+            Some(org_id),
+            CacheTtl::AUDIT.duration(),
+        );
         Ok(result)
     }
 }
@@ -720,9 +884,9 @@ impl<C: AuthApi + ListingApi + ScanDetailApi + 'static> ScanDetailApi for Cached
 
         // TTL depends on scan status
         let ttl = match result.scan.status.to_uppercase().as_str() {
-            "COMPLETED" => CacheTtl::SCAN_DETAIL_COMPLETED,
-            "STARTED" | "RUNNING" | "PENDING" => CacheTtl::SCAN_DETAIL_RUNNING,
-            _ => CacheTtl::SCAN_LIST,
+            "COMPLETED" => CacheTtl::SCAN_DETAIL_COMPLETED.duration(),
+            "STARTED" | "RUNNING" | "PENDING" => CacheTtl::SCAN_DETAIL_RUNNING.duration(),
+            _ => CacheTtl::SCAN_LIST.duration(),
         };
 
         self.set_cached(&key, &result, "get_scan", Some(org_id), ttl);
@@ -750,7 +914,13 @@ impl<C: AuthApi + ListingApi + ScanDetailApi + 'static> ScanDetailApi for Cached
         }
 
         let result = self.inner.list_scan_alerts(scan_id, pagination).await?;
-        self.set_cached(&key, &result, "list_scan_alerts", None, CacheTtl::ALERTS);
+        self.set_cached(
+            &key,
+            &result,
+            "list_scan_alerts",
+            None,
+            CacheTtl::ALERTS.duration(),
+        );
         Ok(result)
     }
 
@@ -785,7 +955,7 @@ impl<C: AuthApi + ListingApi + ScanDetailApi + 'static> ScanDetailApi for Cached
             &result,
             "get_alert_with_paths",
             None,
-            CacheTtl::ALERT_PATHS,
+            CacheTtl::ALERT_PATHS.duration(),
         );
         Ok(result)
     }
@@ -802,6 +972,15 @@ impl<C: AuthApi + ListingApi + ScanDetailApi + 'static> ScanDetailApi for Cached
             ("alert_uri_id", alert_uri_id),
             ("message_id", message_id),
             ("include_curl", if include_curl { "true" } else { "false" }),
+            // Keeps unredacted entries from being served once redaction is on
+            (
+                "redacted",
+                if self.redact_sensitive {
+                    "true"
+                } else {
+                    "false"
+                },
+            ),
         ];
         let key = cache_key("get_alert_message", self.api_host.as_deref(), None, &params);
 
@@ -816,12 +995,17 @@ impl<C: AuthApi + ListingApi + ScanDetailApi + 'static> ScanDetailApi for Cached
             .await?;
 
         // Alert messages are immutable - cache for 24 hours
+        let cached = if self.redact_sensitive {
+            &redact_alert_message(&result)
+        } else {
+            &result
+        };
         self.set_cached(
             &key,
-            &result,
+            cached,
             "get_alert_message",
             None,
-            CacheTtl::SCAN_DETAIL_COMPLETED,
+            CacheTtl::SCAN_DETAIL_COMPLETED.duration(),
         );
         Ok(result)
     }
@@ -869,7 +1053,7 @@ impl<C: AuthApi + ListingApi + ScanDetailApi + 'static> ScanDetailApi for Cached
             &result,
             "list_org_findings",
             Some(org_id),
-            CacheTtl::ALERTS,
+            CacheTtl::ALERTS.duration(),
         );
         Ok(result)
     }
@@ -898,7 +1082,13 @@ impl<C: AuthApi + ListingApi + ScanDetailApi + TeamApi + 'static> TeamApi
         }
 
         let result = self.inner.get_team(org_id, team_id).await?;
-        self.set_cached(&key, &result, "get_team", Some(org_id), CacheTtl::TEAMS);
+        self.set_cached(
+            &key,
+            &result,
+            "get_team",
+            Some(org_id),
+            CacheTtl::TEAMS.duration(),
+        );
         Ok(result)
     }
 
@@ -917,7 +1107,13 @@ impl<C: AuthApi + ListingApi + ScanDetailApi + TeamApi + 'static> TeamApi
             Some(org_id),
             &[("team_id", team_id)],
         );
-        self.set_cached(&key, &result, "get_team", Some(org_id), CacheTtl::TEAMS);
+        self.set_cached(
+            &key,
+            &result,
+            "get_team",
+            Some(org_id),
+            CacheTtl::TEAMS.duration(),
+        );
 
         Ok(result)
     }
@@ -1212,6 +1408,23 @@ impl<
     }
 }
 
+// ============================================================================
+// TriageApi Implementation (Finding triage)
+// ============================================================================
+
+#[async_trait]
+impl<C: AuthApi + ListingApi + ScanDetailApi + TriageApi + 'static> TriageApi
+    for CachedStackHawkClient<C>
+{
+    /// Bulk triage - invalidates findings caches after mutation
+    async fn bulk_triage_findings(&self, request: BulkTriageRequest) -> Result<BulkTriageResponse> {
+        let org_id = request.organization_id.clone();
+        let result = self.inner.bulk_triage_findings(request).await?;
+        self.invalidate_findings_cache(&org_id);
+        Ok(result)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1235,6 +1448,9 @@ mod tests {
             inner: Arc::new(mock),
             cache,
             api_host: None, // Default to None for tests
+            offline: false,
+            redact_sensitive: false,
+            refresh: false,
         };
 
         (client, temp_dir)
@@ -1289,4 +1505,39 @@ mod tests {
         let counts = client.inner.call_counts().await;
         assert_eq!(counts.list_orgs, 1);
     }
+
+    #[tokio::test]
+    async fn test_refreshing_skips_reads() {
+        let (client, _dir) = create_test_client(true);
+        let refreshing = client.refreshing();
+
+        let _ = client.list_orgs().await.unwrap();
+        let _ = refreshing.list_orgs().await.unwrap();
+        let _ = refreshing.list_orgs().await.unwrap();
+
+        // Refreshing calls always reach the API; both handles share it
+        let counts = client.inner.call_counts().await;
+        assert_eq!(counts.list_orgs, 3);
+    }
+
+    #[tokio::test]
+    async fn test_offline_serves_expired_entries() {
+        let (client, _dir) = create_test_client(true);
+        let client = client.with_offline(true);
+
+        let key = cache_key("list_orgs", None, None, &[]);
+        let data = serde_json::to_vec(&Vec::<Organization>::new()).unwrap();
+        client
+            .cache
+            .as_ref()
+            .unwrap()
+            .lock()
+            .unwrap()
+            .put(&key, &data, "list_orgs", None, Duration::from_secs(0))
+            .unwrap();
+
+        assert!(client.list_orgs().await.unwrap().is_empty());
+        let counts = client.inner.call_counts().await;
+        assert_eq!(counts.list_orgs, 0);
+    }
 }
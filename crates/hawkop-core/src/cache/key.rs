@@ -46,6 +46,28 @@ pub fn cache_key(
     format!("{:x}", hasher.finalize())
 }
 
+/// Cache key for a request to an arbitrary API URL (`hawkop api`)
+///
+/// The URL's path is the endpoint and its decoded query string the
+/// parameters, so `?a=1&b=2` and `?b=2&a=1` share an entry. Keys are
+/// prefixed apart from the named endpoints `cache_key` is used with.
+pub fn url_cache_key(url: &str, api_host: Option<&str>) -> String {
+    let Ok(parsed) = reqwest::Url::parse(url) else {
+        return cache_key(&format!("url {}", url), api_host, None, &[]);
+    };
+    let params: Vec<(String, String)> = parsed.query_pairs().into_owned().collect();
+    let params_ref: Vec<(&str, &str)> = params
+        .iter()
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .collect();
+    cache_key(
+        &format!("url {}", parsed.path()),
+        api_host,
+        None,
+        &params_ref,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -115,4 +137,24 @@ mod tests {
         // Same API host should produce same key
         assert_eq!(key1, key2);
     }
+
+    #[test]
+    fn test_url_cache_key() {
+        let host = Some("https://api.stackhawk.com");
+        let key = |url: &str| url_cache_key(url, host);
+        let base = "https://api.stackhawk.com/api/v1/org/o1/apps";
+
+        // Parameter order and encoding don't matter
+        assert_eq!(
+            key(&format!("{}?pageSize=5&sortDir=asc", base)),
+            key(&format!("{}?sortDir=asc&pageSize=%35", base))
+        );
+        assert_ne!(key(base), key(&format!("{}?pageSize=5", base)));
+        assert_ne!(
+            key(base),
+            key("https://api.stackhawk.com/api/v2/org/o1/apps")
+        );
+        // Never collides with a named endpoint
+        assert_ne!(key(base), cache_key("/api/v1/org/o1/apps", host, None, &[]));
+    }
 }
@@ -0,0 +1,145 @@
+//! Local cache for API responses
+//!
+//! Provides SQLite-backed caching with file blob storage for large responses.
+//! Designed to speed up shell completions and reduce API calls.
+
+pub mod client;
+pub mod key;
+pub mod redact;
+pub mod storage;
+
+use std::sync::RwLock;
+use std::time::Duration;
+
+/// Cache TTL per data type
+///
+/// Each constant holds a data type's default TTL. The `cache.ttl` config
+/// section can override them by name (see [`CacheTtl::set_overrides`]), so
+/// read the effective value with [`CacheTtl::duration`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheTtl {
+    name: &'static str,
+    default: Duration,
+}
+
+/// TTLs set by [`CacheTtl::set_overrides`], keyed by name
+static TTL_OVERRIDES: RwLock<Vec<(&'static str, Duration)>> = RwLock::new(Vec::new());
+
+impl CacheTtl {
+    // Scan data - new scans appear frequently
+    pub const SCAN_LIST: Self = Self::new("scan_list", 2 * 60); // 2 min
+    pub const SCAN_DETAIL_COMPLETED: Self = Self::new("scan_detail_completed", 24 * 60 * 60); // 24 hr
+    pub const SCAN_DETAIL_RUNNING: Self = Self::new("scan_detail_running", 30); // 30 sec
+
+    // Alert/finding data - triage can change
+    pub const ALERTS: Self = Self::new("alerts", 10 * 60); // 10 min
+    pub const ALERT_PATHS: Self = Self::new("alert_paths", 10 * 60); // 10 min
+
+    // Completion caching - scan findings are stable once scan completes
+    // Plugin IDs and URI IDs don't change, only triage state does
+    pub const COMPLETION_ALERTS: Self = Self::new("completion_alerts", 4 * 60 * 60); // 4 hr
+
+    // Relatively stable data
+    pub const APPS: Self = Self::new("apps", 60 * 60); // 1 hr
+    pub const ORGS: Self = Self::new("orgs", 60 * 60); // 1 hr
+    pub const USERS: Self = Self::new("users", 60 * 60); // 1 hr
+    pub const TEAMS: Self = Self::new("teams", 60); // 1 min - teams change frequently via CRUD
+    pub const POLICIES: Self = Self::new("policies", 60 * 60); // 1 hr
+
+    // Other data
+    pub const REPOS: Self = Self::new("repos", 60 * 60); // 1 hr
+    pub const OAS: Self = Self::new("oas", 60 * 60); // 1 hr
+    pub const SCAN_CONFIGS: Self = Self::new("scan_configs", 60 * 60); // 1 hr
+    pub const SECRETS: Self = Self::new("secrets", 60 * 60); // 1 hr
+    pub const AUDIT: Self = Self::new("audit", 5 * 60); // 5 min
+
+    /// Every data type, in declaration order
+    pub const ALL: [CacheTtl; 16] = [
+        Self::SCAN_LIST,
+        Self::SCAN_DETAIL_COMPLETED,
+        Self::SCAN_DETAIL_RUNNING,
+        Self::ALERTS,
+        Self::ALERT_PATHS,
+        Self::COMPLETION_ALERTS,
+        Self::APPS,
+        Self::ORGS,
+        Self::USERS,
+        Self::TEAMS,
+        Self::POLICIES,
+        Self::REPOS,
+        Self::OAS,
+        Self::SCAN_CONFIGS,
+        Self::SECRETS,
+        Self::AUDIT,
+    ];
+
+    const fn new(name: &'static str, secs: u64) -> Self {
+        Self {
+            name,
+            default: Duration::from_secs(secs),
+        }
+    }
+
+    /// Name used in the `cache.ttl` config section (e.g. `scan_list`)
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Built-in TTL, ignoring overrides
+    pub fn default_duration(&self) -> Duration {
+        self.default
+    }
+
+    /// Look up a data type by its config name
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|ttl| ttl.name == name)
+    }
+
+    /// Effective TTL: the configured override, or the default
+    pub fn duration(&self) -> Duration {
+        TTL_OVERRIDES
+            .read()
+            .ok()
+            .and_then(|overrides| {
+                overrides
+                    .iter()
+                    .find(|(name, _)| *name == self.name)
+                    .map(|(_, ttl)| *ttl)
+            })
+            .unwrap_or(self.default)
+    }
+
+    /// Replace the TTL overrides for this process
+    pub fn set_overrides(overrides: impl IntoIterator<Item = (CacheTtl, Duration)>) {
+        if let Ok(mut current) = TTL_OVERRIDES.write() {
+            *current = overrides
+                .into_iter()
+                .map(|(ttl, duration)| (ttl.name, duration))
+                .collect();
+        }
+    }
+}
+
+// Re-export main types
+pub use client::CachedStackHawkClient;
+pub use key::{cache_key, url_cache_key};
+pub use storage::{CacheStorage, set_max_size};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ttl_overrides_by_name() {
+        let audit = CacheTtl::from_name("audit").unwrap();
+        assert_eq!(audit, CacheTtl::AUDIT);
+        assert!(CacheTtl::from_name("nope").is_none());
+
+        CacheTtl::set_overrides([(audit, Duration::from_secs(42))]);
+        assert_eq!(CacheTtl::AUDIT.duration(), Duration::from_secs(42));
+        assert_eq!(CacheTtl::APPS.duration(), CacheTtl::APPS.default_duration());
+
+        CacheTtl::set_overrides([]);
+        assert_eq!(CacheTtl::AUDIT.duration(), Duration::from_secs(5 * 60));
+    }
+}
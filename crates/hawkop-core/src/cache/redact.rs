@@ -0,0 +1,36 @@
+//! Redaction of credentials in cached scan messages
+//!
+//! Scan messages carry the HTTP traffic HawkScan recorded, including the
+//! scanner's `Authorization` header and session cookies. With
+//! `cache.redact_sensitive` set, these values are replaced before a message
+//! is written to the cache, so the SQLite file never holds them. The
+//! scanning itself is shared with logging and `scan get` (see
+//! [`crate::redact`]).
+
+use crate::client::models::AlertMsgResponse;
+use crate::redact::{REDACTED, redact_headers};
+
+/// Copy of `message` with credential headers and cookies redacted
+///
+/// Covers the request and response headers, the cookie parameters, and the
+/// curl validation command. Bodies are left as they are.
+pub fn redact_alert_message(message: &AlertMsgResponse) -> AlertMsgResponse {
+    let mut message = message.clone();
+    let scan_message = &mut message.scan_message;
+    for text in [
+        &mut scan_message.request_header,
+        &mut scan_message.response_header,
+        &mut message.validation_command,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        *text = redact_headers(text);
+    }
+    if let Some(cookies) = scan_message.cookie_params.as_mut()
+        && !cookies.trim().is_empty()
+    {
+        *cookies = REDACTED.to_string();
+    }
+    message
+}
@@ -1,33 +1,47 @@
 //! SQLite-based cache storage with file blob support
 //!
 //! Stores small responses inline in SQLite, large responses (>10KB) as files.
+//! With a size limit set, least recently used entries are evicted on write.
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use rusqlite::{Connection, OptionalExtension, params};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Duration;
 
+use crate::client::models::JwtToken;
 use crate::error::CacheError;
 
 /// Schema version - increment to trigger nuke-and-rebuild
-const SCHEMA_VERSION: i32 = 1;
+const SCHEMA_VERSION: i32 = 2;
 
 /// Responses larger than this are stored as external blobs
 const INLINE_THRESHOLD: usize = 10 * 1024; // 10KB
 
 type Result<T> = std::result::Result<T, CacheError>;
 
+/// Size limit for [`CacheStorage::open`] in bytes (0 = unlimited)
+static MAX_SIZE_BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// Limit the total size of the default cache (from `cache.max_size`)
+pub fn set_max_size(max_bytes: Option<u64>) {
+    MAX_SIZE_BYTES.store(max_bytes.unwrap_or(0), Ordering::Relaxed);
+}
+
 /// SQLite-backed cache storage with file blob support
 pub struct CacheStorage {
     conn: Connection,
     blobs_dir: PathBuf,
+    /// Evict least recently used entries past this many bytes
+    max_size: Option<u64>,
 }
 
 impl CacheStorage {
     /// Open or create cache storage at the default XDG cache location
     pub fn open() -> Result<Self> {
         let cache_dir = Self::cache_dir()?;
-        Self::open_at(&cache_dir)
+        let max_size = Some(MAX_SIZE_BYTES.load(Ordering::Relaxed)).filter(|&max| max > 0);
+        Ok(Self::open_at(&cache_dir)?.with_max_size(max_size))
     }
 
     /// Get the cache directory path (~/.cache/hawkop on Linux/macOS)
@@ -75,55 +89,113 @@ impl CacheStorage {
                 blob_path TEXT,
                 created_at INTEGER NOT NULL,
                 expires_at INTEGER NOT NULL,
-                size_bytes INTEGER NOT NULL
+                size_bytes INTEGER NOT NULL,
+                accessed_at INTEGER NOT NULL
             );
 
             CREATE INDEX IF NOT EXISTS idx_expires_at ON cache_entries(expires_at);
+            CREATE INDEX IF NOT EXISTS idx_accessed_at ON cache_entries(accessed_at);
             CREATE INDEX IF NOT EXISTS idx_org_id ON cache_entries(org_id);
             CREATE INDEX IF NOT EXISTS idx_endpoint ON cache_entries(endpoint);
+
+            CREATE TABLE IF NOT EXISTS auth_tokens (
+                token_key TEXT PRIMARY KEY NOT NULL,
+                token TEXT NOT NULL,
+                expires_at INTEGER NOT NULL,
+                clock_skew_secs INTEGER NOT NULL,
+                updated_at INTEGER NOT NULL
+            );
             "#,
         )?;
 
+        // The database holds JWTs, so keep it private to the user
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            if let Err(e) =
+                std::fs::set_permissions(&db_path, std::fs::Permissions::from_mode(0o600))
+            {
+                log::debug!("Failed to restrict cache DB permissions: {}", e);
+            }
+        }
+
         conn.pragma_update(None, "user_version", SCHEMA_VERSION)?;
 
-        Ok(Self { conn, blobs_dir })
+        Ok(Self {
+            conn,
+            blobs_dir,
+            max_size: None,
+        })
+    }
+
+    /// Evict least recently used entries once the cache exceeds `max_bytes`
+    pub fn with_max_size(mut self, max_bytes: Option<u64>) -> Self {
+        self.max_size = max_bytes;
+        self
+    }
+
+    /// Size limit in bytes, if any
+    pub fn max_size(&self) -> Option<u64> {
+        self.max_size
     }
 
     /// Get cached data if valid (not expired)
     pub fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
         let now = Utc::now().timestamp();
+        Ok(self
+            .get_any(key)?
+            .filter(|(_, expires_at)| *expires_at > now)
+            .map(|(data, _)| data))
+    }
 
-        let result: Option<(Option<String>, Option<String>)> = self
+    /// Get cached data regardless of expiry, with its `expires_at` timestamp
+    ///
+    /// Used by offline mode, which serves expired entries rather than
+    /// going to the network.
+    pub fn get_any(&self, key: &str) -> Result<Option<(Vec<u8>, i64)>> {
+        let result: Option<(Option<String>, Option<String>, i64)> = self
             .conn
             .query_row(
-                "SELECT data, blob_path FROM cache_entries
-                 WHERE cache_key = ?1 AND expires_at > ?2",
-                params![key, now],
-                |row| Ok((row.get(0)?, row.get(1)?)),
+                "SELECT data, blob_path, expires_at FROM cache_entries
+                 WHERE cache_key = ?1",
+                params![key],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
             )
             .optional()?;
 
-        match result {
-            Some((Some(data), None)) => Ok(Some(data.into_bytes())),
-            Some((None, Some(blob_path))) => {
+        let found = match result {
+            Some((Some(data), None, expires_at)) => Some((data.into_bytes(), expires_at)),
+            Some((None, Some(blob_path), expires_at)) => {
                 let full_path = self.blobs_dir.join(&blob_path);
                 match std::fs::read(&full_path) {
-                    Ok(data) => Ok(Some(data)),
+                    Ok(data) => Some((data, expires_at)),
                     Err(e) => {
                         log::warn!("Failed to read blob {}: {}", blob_path, e);
                         // Delete stale entry
                         let _ = self
                             .conn
                             .execute("DELETE FROM cache_entries WHERE cache_key = ?1", [key]);
-                        Ok(None)
+                        None
                     }
                 }
             }
-            _ => Ok(None),
+            _ => None,
+        };
+
+        if found.is_some() {
+            // Record the hit for LRU eviction
+            self.conn.execute(
+                "UPDATE cache_entries SET accessed_at = ?2 WHERE cache_key = ?1",
+                params![key, Utc::now().timestamp_millis()],
+            )?;
         }
+        Ok(found)
     }
 
     /// Store data with TTL
+    ///
+    /// With a size limit, entries larger than the limit are not stored, and
+    /// least recently used entries are evicted to make room.
     pub fn put(
         &self,
         key: &str,
@@ -132,23 +204,38 @@ impl CacheStorage {
         org_id: Option<&str>,
         ttl: Duration,
     ) -> Result<()> {
-        let now = Utc::now().timestamp();
-        let expires = now + ttl.as_secs() as i64;
+        if let Some(max_size) = self.max_size
+            && data.len() as u64 > max_size
+        {
+            log::debug!(
+                "Not caching {} ({} bytes is over the cache limit)",
+                endpoint,
+                data.len()
+            );
+            return Ok(());
+        }
+
+        let now = Utc::now();
+        let created = now.timestamp();
+        let expires = created + ttl.as_secs() as i64;
+        let accessed = now.timestamp_millis();
 
         if data.len() <= INLINE_THRESHOLD {
             // Store inline in SQLite
             self.conn.execute(
                 "INSERT OR REPLACE INTO cache_entries
-                 (cache_key, org_id, endpoint, data, blob_path, created_at, expires_at, size_bytes)
-                 VALUES (?1, ?2, ?3, ?4, NULL, ?5, ?6, ?7)",
+                 (cache_key, org_id, endpoint, data, blob_path, created_at, expires_at, size_bytes,
+                  accessed_at)
+                 VALUES (?1, ?2, ?3, ?4, NULL, ?5, ?6, ?7, ?8)",
                 params![
                     key,
                     org_id,
                     endpoint,
                     String::from_utf8_lossy(data).to_string(),
-                    now,
+                    created,
                     expires,
-                    data.len()
+                    data.len(),
+                    accessed
                 ],
             )?;
         } else {
@@ -156,14 +243,74 @@ impl CacheStorage {
             let blob_path = self.write_blob(key, data)?;
             self.conn.execute(
                 "INSERT OR REPLACE INTO cache_entries
-                 (cache_key, org_id, endpoint, data, blob_path, created_at, expires_at, size_bytes)
-                 VALUES (?1, ?2, ?3, NULL, ?4, ?5, ?6, ?7)",
-                params![key, org_id, endpoint, blob_path, now, expires, data.len()],
+                 (cache_key, org_id, endpoint, data, blob_path, created_at, expires_at, size_bytes,
+                  accessed_at)
+                 VALUES (?1, ?2, ?3, NULL, ?4, ?5, ?6, ?7, ?8)",
+                params![
+                    key,
+                    org_id,
+                    endpoint,
+                    blob_path,
+                    created,
+                    expires,
+                    data.len(),
+                    accessed
+                ],
             )?;
         }
+
+        if let Some(max_size) = self.max_size {
+            let evicted = self.evict_to(max_size)?;
+            if evicted > 0 {
+                log::debug!("Cache over {} bytes, evicted {} entries", max_size, evicted);
+            }
+        }
         Ok(())
     }
 
+    /// Delete least recently used entries until the total size fits in `max_bytes`
+    ///
+    /// Returns the number of entries removed.
+    pub fn evict_to(&self, max_bytes: u64) -> Result<usize> {
+        let total: i64 = self.conn.query_row(
+            "SELECT COALESCE(SUM(size_bytes), 0) FROM cache_entries",
+            [],
+            |r| r.get(0),
+        )?;
+        let mut excess = total - max_bytes as i64;
+        if excess <= 0 {
+            return Ok(0);
+        }
+
+        let mut victims = Vec::new();
+        {
+            let mut stmt = self.conn.prepare(
+                "SELECT cache_key, blob_path, size_bytes FROM cache_entries
+                 ORDER BY accessed_at ASC",
+            )?;
+            let mut rows = stmt.query([])?;
+            while excess > 0
+                && let Some(row) = rows.next()?
+            {
+                let (key, blob_path, size): (String, Option<String>, i64) =
+                    (row.get(0)?, row.get(1)?, row.get(2)?);
+                excess -= size;
+                victims.push((key, blob_path));
+            }
+        }
+
+        for (key, blob_path) in &victims {
+            self.conn
+                .execute("DELETE FROM cache_entries WHERE cache_key = ?1", [key])?;
+            if let Some(blob_path) = blob_path
+                && let Err(e) = std::fs::remove_file(self.blobs_dir.join(blob_path))
+            {
+                log::debug!("Failed to remove evicted blob {}: {}", blob_path, e);
+            }
+        }
+        Ok(victims.len())
+    }
+
     /// Clear all cache entries
     pub fn clear_all(&self) -> Result<ClearStats> {
         let count: i64 = self
@@ -214,6 +361,65 @@ impl CacheStorage {
         Ok(deleted)
     }
 
+    /// Get the JWT stored under `key`, expired or not
+    ///
+    /// Tokens live in their own table, apart from cached responses: they are
+    /// not counted in [`stats`](Self::stats), evicted, or removed by
+    /// [`clear_all`](Self::clear_all).
+    pub fn get_token(&self, key: &str) -> Result<Option<JwtToken>> {
+        let row: Option<(String, i64, i64)> = self
+            .conn
+            .query_row(
+                "SELECT token, expires_at, clock_skew_secs FROM auth_tokens
+                 WHERE token_key = ?1",
+                params![key],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .optional()?;
+
+        Ok(row.and_then(|(token, expires_at, clock_skew_secs)| {
+            Some(JwtToken {
+                token,
+                expires_at: DateTime::from_timestamp(expires_at, 0)?,
+                clock_skew_secs,
+            })
+        }))
+    }
+
+    /// Store a JWT under `key`, replacing any earlier one
+    ///
+    /// Tokens under other keys that have expired by the server's clock are
+    /// removed at the same time, so keys for rotated API keys don't
+    /// accumulate.
+    pub fn put_token(&self, key: &str, jwt: &JwtToken) -> Result<()> {
+        let now = Utc::now().timestamp();
+        self.conn.execute(
+            "INSERT OR REPLACE INTO auth_tokens
+             (token_key, token, expires_at, clock_skew_secs, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                key,
+                jwt.token,
+                jwt.expires_at.timestamp(),
+                jwt.clock_skew_secs,
+                now
+            ],
+        )?;
+        self.conn.execute(
+            "DELETE FROM auth_tokens WHERE token_key != ?1 AND expires_at - clock_skew_secs < ?2",
+            params![key, now],
+        )?;
+        Ok(())
+    }
+
+    /// Delete the JWT stored under `key`; returns whether there was one
+    pub fn delete_token(&self, key: &str) -> Result<bool> {
+        let deleted = self
+            .conn
+            .execute("DELETE FROM auth_tokens WHERE token_key = ?1", [key])?;
+        Ok(deleted > 0)
+    }
+
     /// Get cache statistics
     pub fn stats(&self) -> Result<CacheStats> {
         let now = Utc::now().timestamp();
@@ -360,6 +566,10 @@ mod tests {
 
         let result = storage.get("key3").unwrap();
         assert_eq!(result, None); // Expired
+
+        // Offline reads still see the expired entry
+        let (data, _) = storage.get_any("key3").unwrap().unwrap();
+        assert_eq!(data, b"data");
     }
 
     #[test]
@@ -380,6 +590,83 @@ mod tests {
         assert!(storage.get("k2").unwrap().is_none());
     }
 
+    #[test]
+    fn test_eviction_drops_least_recently_used() {
+        let (storage, _dir) = test_storage();
+        let storage = storage.with_max_size(Some(25_000));
+        let blob = vec![b'x'; 12_000];
+
+        storage
+            .put("old", &blob, "test", None, Duration::from_secs(60))
+            .unwrap();
+        storage
+            .put("used", &blob, "test", None, Duration::from_secs(60))
+            .unwrap();
+        std::thread::sleep(Duration::from_millis(5));
+        // Reading "used" makes "old" the least recently used
+        storage.get("used").unwrap().unwrap();
+        std::thread::sleep(Duration::from_millis(5));
+        storage
+            .put("new", &blob, "test", None, Duration::from_secs(60))
+            .unwrap();
+
+        assert!(storage.get("old").unwrap().is_none());
+        assert!(storage.get("used").unwrap().is_some());
+        assert!(storage.get("new").unwrap().is_some());
+        assert_eq!(storage.stats().unwrap().total_size_bytes, 24_000);
+
+        // Larger than the whole limit: skipped rather than evicting everything
+        storage
+            .put(
+                "huge",
+                &vec![b'x'; 30_000],
+                "test",
+                None,
+                Duration::from_secs(60),
+            )
+            .unwrap();
+        assert!(storage.get("huge").unwrap().is_none());
+        assert!(storage.get("new").unwrap().is_some());
+    }
+
+    #[test]
+    fn test_tokens() {
+        let (storage, _dir) = test_storage();
+        // Expiry relative to the local clock, and server-minus-local skew
+        let jwt = |token: &str, expires_in: i64, clock_skew_secs: i64| JwtToken {
+            token: token.to_string(),
+            expires_at: DateTime::from_timestamp(Utc::now().timestamp() + expires_in, 0).unwrap(),
+            clock_skew_secs,
+        };
+
+        assert!(storage.get_token("k1").unwrap().is_none());
+        storage.put_token("k1", &jwt("expired", -60, 0)).unwrap();
+        // Past by the local clock, but the server's clock is an hour behind
+        storage
+            .put_token("k3", &jwt("slow server", -60, -3600))
+            .unwrap();
+        // Ahead by the local clock, but the server's clock is an hour ahead
+        storage
+            .put_token("k4", &jwt("fast server", 60, 3600))
+            .unwrap();
+        storage.put_token("k2", &jwt("valid", 3600, 0)).unwrap();
+
+        // Storing k2 dropped the tokens expired by the server's clock
+        assert!(storage.get_token("k1").unwrap().is_none());
+        assert!(storage.get_token("k3").unwrap().is_some());
+        assert!(storage.get_token("k4").unwrap().is_none());
+        let stored = storage.get_token("k2").unwrap().unwrap();
+        assert_eq!(stored.token, "valid");
+
+        // Tokens are not cache entries
+        storage.clear_all().unwrap();
+        assert_eq!(storage.stats().unwrap().total_entries, 0);
+        assert!(storage.get_token("k2").unwrap().is_some());
+
+        assert!(storage.delete_token("k2").unwrap());
+        assert!(!storage.delete_token("k2").unwrap());
+    }
+
     #[test]
     fn test_stats() {
         let (storage, _dir) = test_storage();
@@ -11,6 +11,7 @@
 //! - [`ConfigApi`] - Configuration management operations
 //! - [`EnvironmentApi`] - Environment management operations
 //! - [`OASApi`] - OpenAPI specification operations
+//! - [`TriageApi`] - Finding triage operations
 
 mod app;
 mod auth;
@@ -22,6 +23,7 @@ mod perch;
 mod repo;
 mod scan_detail;
 mod team;
+mod triage;
 
 pub use app::AppApi;
 pub use auth::AuthApi;
@@ -33,3 +35,4 @@ pub use perch::PerchApi;
 pub use repo::RepoApi;
 pub use scan_detail::ScanDetailApi;
 pub use team::TeamApi;
+pub use triage::TriageApi;
@@ -0,0 +1,138 @@
+//! Repository API trait for write operations
+//!
+//! This trait covers repository-application mapping operations:
+//! - Get a single repository's details (for read-merge-write)
+//! - Replace all application mappings for a repository
+//! - Map or unmap a single application (read-merge-write over the above)
+//!
+//! Note: Read-only listing is handled by [`ListingApi::list_repos`].
+//! This trait adds the write side for linking apps to repos.
+
+use async_trait::async_trait;
+
+use crate::client::models::{
+    ReplaceRepoAppMappingsRequest, ReplaceRepoAppMappingsResponse, RepoAppInfoWrite, Repository,
+};
+use crate::error::Result;
+
+/// Repository write operations for the StackHawk API
+///
+/// Manages the association between repositories (from attack surface mapping)
+/// and StackHawk applications. The primary use case is linking apps to repos
+/// to enable API Discovery coverage tracking.
+///
+/// **Important**: The underlying API uses full-replacement semantics for app
+/// mappings. The `replace_repo_app_mappings` method replaces ALL mappings,
+/// so callers must implement a read-merge-write pattern to preserve existing
+/// associations when adding a new link.
+#[async_trait]
+#[allow(dead_code)]
+pub trait RepoApi: Send + Sync {
+    // ========================================================================
+    // Read Operations
+    // ========================================================================
+
+    /// Get a single repository by ID.
+    ///
+    /// Used before mutations (read-merge-write) to fetch existing app
+    /// mappings that must be preserved.
+    async fn get_repo(&self, org_id: &str, repo_id: &str) -> Result<Repository>;
+
+    // ========================================================================
+    // Write Operations
+    // ========================================================================
+
+    /// Replace all application mappings for a repository.
+    ///
+    /// **This is a full replacement** — any existing mappings not included
+    /// in the request will be removed. Callers should:
+    /// 1. Read current mappings via `get_repo()` or `list_repos()`
+    /// 2. Merge desired changes into the existing list
+    /// 3. POST the complete list
+    ///
+    /// Each app info can specify either:
+    /// - `id` only — links an existing application
+    /// - `name` only — creates a new application and links it
+    async fn replace_repo_app_mappings(
+        &self,
+        request: ReplaceRepoAppMappingsRequest,
+    ) -> Result<ReplaceRepoAppMappingsResponse>;
+
+    /// Map an existing application to a repository, keeping other mappings.
+    ///
+    /// The API has no single-mapping endpoint, so the default implementation
+    /// reads the repository, appends the app, and replaces the full list.
+    /// Nothing is written when the app is already mapped.
+    async fn map_repo_app(
+        &self,
+        org_id: &str,
+        repo_id: &str,
+        app_id: &str,
+    ) -> Result<ReplaceRepoAppMappingsResponse> {
+        let repo = self.get_repo(org_id, repo_id).await?;
+        if repo
+            .app_infos
+            .iter()
+            .any(|info| info.app_id.as_deref() == Some(app_id))
+        {
+            return Ok(unchanged(org_id, repo_id, repo));
+        }
+        let mut app_infos = current_mappings(&repo);
+        app_infos.push(RepoAppInfoWrite {
+            id: Some(app_id.to_string()),
+            name: None,
+        });
+        self.replace_repo_app_mappings(ReplaceRepoAppMappingsRequest {
+            org_id: org_id.to_string(),
+            repo_id: repo_id.to_string(),
+            app_infos,
+        })
+        .await
+    }
+
+    /// Remove one application's mapping from a repository, keeping the rest.
+    ///
+    /// Read-merge-write like [`map_repo_app`](Self::map_repo_app); nothing
+    /// is written when the app is not mapped. The app itself is untouched.
+    async fn unmap_repo_app(
+        &self,
+        org_id: &str,
+        repo_id: &str,
+        app_id: &str,
+    ) -> Result<ReplaceRepoAppMappingsResponse> {
+        let repo = self.get_repo(org_id, repo_id).await?;
+        let app_infos: Vec<RepoAppInfoWrite> = current_mappings(&repo)
+            .into_iter()
+            .filter(|info| info.id.as_deref() != Some(app_id))
+            .collect();
+        if app_infos.len() == repo.app_infos.len() {
+            return Ok(unchanged(org_id, repo_id, repo));
+        }
+        self.replace_repo_app_mappings(ReplaceRepoAppMappingsRequest {
+            org_id: org_id.to_string(),
+            repo_id: repo_id.to_string(),
+            app_infos,
+        })
+        .await
+    }
+}
+
+/// A repository's mappings in write form
+fn current_mappings(repo: &Repository) -> Vec<RepoAppInfoWrite> {
+    repo.app_infos
+        .iter()
+        .map(|info| RepoAppInfoWrite {
+            id: info.app_id.clone(),
+            name: info.app_name.clone(),
+        })
+        .collect()
+}
+
+/// The response for a mapping change that needed no write
+fn unchanged(org_id: &str, repo_id: &str, repo: Repository) -> ReplaceRepoAppMappingsResponse {
+    ReplaceRepoAppMappingsResponse {
+        org_id: Some(org_id.to_string()),
+        repo_id: Some(repo_id.to_string()),
+        app_infos: repo.app_infos,
+    }
+}
@@ -0,0 +1,19 @@
+//! Triage API trait for finding write operations
+
+use async_trait::async_trait;
+
+use crate::client::models::{BulkTriageRequest, BulkTriageResponse};
+use crate::error::Result;
+
+/// Finding triage operations for the StackHawk API
+///
+/// Triage is keyed by finding hash and scoped to one application
+/// environment. Once applied, a triage rule also covers future scans.
+#[async_trait]
+pub trait TriageApi: Send + Sync {
+    /// Apply triage actions to findings in one app environment
+    ///
+    /// The API reports failures per action (e.g. unknown finding hash) and
+    /// still applies the rest; results are in request order.
+    async fn bulk_triage_findings(&self, request: BulkTriageRequest) -> Result<BulkTriageResponse>;
+}
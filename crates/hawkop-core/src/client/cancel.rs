@@ -0,0 +1,107 @@
+//! Cooperative cancellation of long fetches.
+//!
+//! A binary calls [`request`] when the user presses Ctrl-C or a time limit
+//! runs out. The page fetchers in [`super::parallel`] watch for it: they stop
+//! starting pages, drop the requests in flight, and fail with
+//! [`Error::Cancelled`]. With [`set_partial`] enabled they instead return the
+//! items fetched so far, with a `partial_results` warning, so the command can
+//! still print them.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{LazyLock, OnceLock};
+use std::time::Duration;
+
+use tokio::sync::Notify;
+
+use crate::error::Error;
+use crate::warnings::{WarningKind, warn};
+
+/// Why the command is being cancelled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CancelReason {
+    /// The user pressed Ctrl-C
+    Interrupted,
+    /// The command ran longer than its time limit
+    TimedOut(Duration),
+}
+
+impl std::fmt::Display for CancelReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Interrupted => write!(f, "Interrupted"),
+            Self::TimedOut(limit) => write!(f, "Timed out after {}s", limit.as_secs()),
+        }
+    }
+}
+
+impl From<CancelReason> for Error {
+    fn from(reason: CancelReason) -> Self {
+        Error::Cancelled(reason)
+    }
+}
+
+/// First cancellation requested in this process
+static REASON: OnceLock<CancelReason> = OnceLock::new();
+
+/// Wakes the tasks waiting in [`requested`]
+static NOTIFY: LazyLock<Notify> = LazyLock::new(Notify::new);
+
+/// Whether cancelled fetches return what they have
+static PARTIAL: AtomicBool = AtomicBool::new(false);
+
+/// Cancel the running command; later requests keep the first reason
+pub fn request(reason: CancelReason) {
+    let _ = REASON.set(reason);
+    NOTIFY.notify_waiters();
+}
+
+/// The cancellation requested so far, if any
+pub fn reason() -> Option<CancelReason> {
+    REASON.get().copied()
+}
+
+/// Wait until cancellation is requested
+pub async fn requested() -> CancelReason {
+    loop {
+        let notified = NOTIFY.notified();
+        tokio::pin!(notified);
+        // Register before checking, so a request in between isn't missed
+        notified.as_mut().enable();
+        if let Some(reason) = reason() {
+            return reason;
+        }
+        notified.await;
+    }
+}
+
+/// Have cancelled fetches return the items fetched so far
+pub fn set_partial(partial: bool) {
+    PARTIAL.store(partial, Ordering::Relaxed);
+}
+
+/// Whether cancelled fetches return the items fetched so far
+pub fn partial() -> bool {
+    PARTIAL.load(Ordering::Relaxed)
+}
+
+/// Result of a fetch stopped by `reason` with `items` fetched
+///
+/// `unfetched` is the number of pages not fetched, when it is known.
+pub(crate) fn stop<T>(
+    items: Vec<T>,
+    reason: CancelReason,
+    unfetched: Option<usize>,
+) -> crate::error::Result<Vec<T>> {
+    if !partial() {
+        return Err(reason.into());
+    }
+    let missing = match unfetched {
+        Some(pages) => format!("{} page(s) not fetched", pages),
+        None => "remaining pages not fetched".to_string(),
+    };
+    warn(
+        WarningKind::PartialResults,
+        format!("{}: {}; results are partial", reason, missing),
+    );
+    Ok(items)
+}
@@ -69,6 +69,7 @@ impl OrganizationBuilder {
             name: self.name,
             user_count: self.user_count,
             app_count: self.app_count,
+            ..Default::default()
         }
     }
 }
@@ -189,6 +190,8 @@ pub struct ScanResultBuilder {
     timestamp: String,
     scan_duration: Option<String>,
     hawkscan_version: Option<String>,
+    external_user_id: Option<String>,
+    url_count: Option<u32>,
     alert_stats: Option<AlertStats>,
 }
 
@@ -206,6 +209,8 @@ impl ScanResultBuilder {
             timestamp: chrono::Utc::now().timestamp_millis().to_string(),
             scan_duration: Some("120".to_string()),
             hawkscan_version: Some("3.0.0".to_string()),
+            external_user_id: None,
+            url_count: None,
             alert_stats: None,
         }
     }
@@ -246,40 +251,42 @@ impl ScanResultBuilder {
         self
     }
 
+    /// Set the ID of the user who started the scan.
+    pub fn external_user_id(mut self, id: impl Into<String>) -> Self {
+        self.external_user_id = Some(id.into());
+        self
+    }
+
+    /// Set the number of URLs scanned so far.
+    pub fn url_count(mut self, count: u32) -> Self {
+        self.url_count = Some(count);
+        self
+    }
+
     /// Add alert statistics with the given finding counts (new/UNKNOWN status).
     /// Arguments are: high, medium, low severity counts.
     pub fn with_findings(mut self, high: u32, medium: u32, low: u32) -> Self {
-        let total = high + medium + low;
-        let mut severity_stats = HashMap::new();
-        if high > 0 {
-            severity_stats.insert("High".to_string(), high);
-        }
-        if medium > 0 {
-            severity_stats.insert("Medium".to_string(), medium);
+        self = self.with_status_findings("UNKNOWN", high, medium, low);
+        if let Some(stats) = self.alert_stats.as_mut() {
+            stats.unique_alerts += high + medium + low;
         }
-        if low > 0 {
-            severity_stats.insert("Low".to_string(), low);
-        }
-
-        let stats = self.alert_stats.get_or_insert(AlertStats {
-            total_alerts: 0,
-            unique_alerts: 0,
-            alert_status_stats: Vec::new(),
-        });
-
-        stats.total_alerts += total;
-        stats.unique_alerts += total;
-        stats.alert_status_stats.push(AlertStatusStats {
-            alert_status: "UNKNOWN".to_string(),
-            total_count: total,
-            severity_stats,
-        });
-
         self
     }
 
     /// Add triaged findings (PROMOTED status).
-    pub fn with_triaged_findings(mut self, high: u32, medium: u32, low: u32) -> Self {
+    pub fn with_triaged_findings(self, high: u32, medium: u32, low: u32) -> Self {
+        self.with_status_findings("PROMOTED", high, medium, low)
+    }
+
+    /// Add findings with the given triage status (UNKNOWN, PROMOTED,
+    /// RISK_ACCEPTED, FALSE_POSITIVE).
+    pub fn with_status_findings(
+        mut self,
+        status: impl Into<String>,
+        high: u32,
+        medium: u32,
+        low: u32,
+    ) -> Self {
         let total = high + medium + low;
         let mut severity_stats = HashMap::new();
         if high > 0 {
@@ -300,7 +307,7 @@ impl ScanResultBuilder {
 
         stats.total_alerts += total;
         stats.alert_status_stats.push(AlertStatusStats {
-            alert_status: "PROMOTED".to_string(),
+            alert_status: status.into(),
             total_count: total,
             severity_stats,
         });
@@ -319,10 +326,10 @@ impl ScanResultBuilder {
                 status: self.status,
                 timestamp: self.timestamp,
                 version: self.hawkscan_version.unwrap_or_default(),
-                external_user_id: None,
+                external_user_id: self.external_user_id,
             },
             scan_duration: self.scan_duration,
-            url_count: None,
+            url_count: self.url_count,
             alert_stats: self.alert_stats,
             severity_stats: None,
             app_host: None,
@@ -396,6 +403,7 @@ impl UserBuilder {
                 first_name: self.first_name,
                 last_name: self.last_name,
                 full_name: self.full_name,
+                organizations: Vec::new(),
             },
         }
     }
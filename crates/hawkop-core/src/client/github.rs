@@ -0,0 +1,140 @@
+//! Minimal GitHub REST client for code-scanning uploads
+//!
+//! Only what `scan upload-github` needs: upload a SARIF log and report the
+//! upload ID. Authentication is a personal access token or the Actions
+//! `GITHUB_TOKEN` with `security_events: write`.
+
+use std::io::Write;
+use std::time::Duration;
+
+use base64::{Engine as _, engine::general_purpose};
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use reqwest::Client as HttpClient;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ApiError, Error, Result};
+
+/// Public GitHub API; GitHub Enterprise Server uses `https://<host>/api/v3`
+pub const DEFAULT_GITHUB_API_URL: &str = "https://api.github.com";
+
+/// GitHub API client scoped to one token
+pub struct GitHubClient {
+    http: HttpClient,
+    api_url: String,
+    token: String,
+}
+
+/// Result of a SARIF upload (processing continues asynchronously on GitHub)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SarifUpload {
+    /// Upload ID
+    pub id: String,
+
+    /// API URL for checking processing status
+    pub url: String,
+}
+
+impl GitHubClient {
+    /// Create a client for `api_url` (trailing slashes are ignored)
+    pub fn new(api_url: &str, token: impl Into<String>) -> Result<Self> {
+        let http = HttpClient::builder()
+            .timeout(Duration::from_secs(60))
+            .user_agent(concat!("hawkop/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| ApiError::Network(e.to_string()))?;
+        Ok(Self {
+            http,
+            api_url: api_url.trim_end_matches('/').to_string(),
+            token: token.into(),
+        })
+    }
+
+    /// Upload a SARIF log to code scanning for `repo` ("owner/name")
+    ///
+    /// `git_ref` is the full ref (e.g. `refs/heads/main`) and `commit_sha`
+    /// the commit the results belong to.
+    pub async fn upload_sarif(
+        &self,
+        repo: &str,
+        commit_sha: &str,
+        git_ref: &str,
+        sarif: &serde_json::Value,
+    ) -> Result<SarifUpload> {
+        let body = serde_json::json!({
+            "commit_sha": commit_sha,
+            "ref": git_ref,
+            "sarif": encode_sarif(sarif)?,
+            "tool_name": "StackHawk",
+        });
+
+        let url = format!("{}/repos/{}/code-scanning/sarifs", self.api_url, repo);
+        log::debug!("POST {}", url);
+        let response = self
+            .http
+            .post(&url)
+            .bearer_auth(&self.token)
+            .header("Accept", "application/vnd.github+json")
+            .header("X-GitHub-Api-Version", "2022-11-28")
+            .json(&body)
+            .send()
+            .await
+            .map_err(ApiError::from)?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let text = response.text().await.unwrap_or_default();
+            let message = serde_json::from_str::<serde_json::Value>(&text)
+                .ok()
+                .and_then(|v| v.get("message").and_then(|m| m.as_str()).map(String::from))
+                .unwrap_or(text);
+            return Err(Error::Other(format!(
+                "GitHub code scanning upload failed ({}): {}{}",
+                status.as_u16(),
+                message,
+                upload_hint(status.as_u16())
+            )));
+        }
+
+        response
+            .json::<SarifUpload>()
+            .await
+            .map_err(|e| ApiError::InvalidResponse(e.to_string()).into())
+    }
+}
+
+/// Gzip then base64, as the code-scanning API requires
+fn encode_sarif(sarif: &serde_json::Value) -> Result<String> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(serde_json::to_string(sarif)?.as_bytes())?;
+    Ok(general_purpose::STANDARD.encode(encoder.finish()?))
+}
+
+fn upload_hint(status: u16) -> &'static str {
+    match status {
+        401 => "\n→ Check the token passed with --token or GITHUB_TOKEN",
+        403 => "\n→ The token needs the security_events scope (security-events: write in Actions)",
+        404 => "\n→ Check --repo, and that code scanning is available for the repository",
+        _ => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::GzDecoder;
+    use std::io::Read;
+
+    #[test]
+    fn test_encode_sarif_round_trips() {
+        let sarif = serde_json::json!({"version": "2.1.0"});
+        let encoded = encode_sarif(&sarif).unwrap();
+
+        let compressed = general_purpose::STANDARD.decode(encoded).unwrap();
+        let mut decoded = String::new();
+        GzDecoder::new(&compressed[..])
+            .read_to_string(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, r#"{"version":"2.1.0"}"#);
+    }
+}
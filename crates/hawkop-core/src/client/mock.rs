@@ -7,13 +7,14 @@ use async_trait::async_trait;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-use super::api::{AppApi, AuthApi, ListingApi, RepoApi, ScanDetailApi, TeamApi};
+use super::api::{AppApi, AuthApi, ListingApi, RepoApi, ScanDetailApi, TeamApi, TriageApi};
 use super::models::{
     AlertMsgResponse, AlertResponse, Application, ApplicationAlert, AuditFilterParams, AuditRecord,
-    CreateApplicationRequest, CreateTeamRequest, CurrentFindingsResponse, JwtToken, OASAsset,
-    OrgPolicy, Organization, ReplaceRepoAppMappingsRequest, ReplaceRepoAppMappingsResponse,
-    RepoAppInfo, Repository, ScanConfig, ScanMessage, ScanResult, Secret, StackHawkPolicy, Team,
-    TeamApplication, TeamDetail, TeamUser, UpdateApplicationTeamRequest, UpdateTeamRequest, User,
+    BulkTriageRequest, BulkTriageResponse, CreateApplicationRequest, CreateTeamRequest,
+    CurrentFindingsResponse, FindingTriageResult, JwtToken, OASAsset, OrgPolicy, Organization,
+    ReplaceRepoAppMappingsRequest, ReplaceRepoAppMappingsResponse, RepoAppInfo, Repository,
+    ScanConfig, ScanMessage, ScanResult, Secret, StackHawkPolicy, Team, TeamApplication,
+    TeamDetail, TeamUser, UpdateApplicationTeamRequest, UpdateTeamRequest, User,
 };
 use super::pagination::{PagedResponse, PaginationParams, ScanFilterParams};
 use crate::error::{ApiError, Result};
@@ -306,6 +307,7 @@ impl AuthApi for MockStackHawkClient {
         Ok(jwt.clone().unwrap_or_else(|| JwtToken {
             token: "mock-jwt-token".to_string(),
             expires_at: chrono::Utc::now() + chrono::Duration::hours(1),
+            clock_skew_secs: 0,
         }))
     }
 }
@@ -865,6 +867,33 @@ impl RepoApi for MockStackHawkClient {
     }
 }
 
+// ============================================================================
+// TriageApi Implementation
+// ============================================================================
+
+#[async_trait]
+impl TriageApi for MockStackHawkClient {
+    async fn bulk_triage_findings(&self, request: BulkTriageRequest) -> Result<BulkTriageResponse> {
+        self.check_error().await?;
+
+        // Every action succeeds in the mock
+        Ok(BulkTriageResponse {
+            results: request
+                .actions
+                .into_iter()
+                .map(|action| FindingTriageResult {
+                    finding_hash: action.finding_hash,
+                    success: true,
+                    error: None,
+                    status: Some(action.status),
+                    note: action.note,
+                    updated_at: None,
+                })
+                .collect(),
+        })
+    }
+}
+
 // ============================================================================
 // AppApi Implementation
 // ============================================================================
@@ -959,12 +988,14 @@ mod tests {
                     name: "Test Org 1".to_string(),
                     user_count: Some(5),
                     app_count: Some(3),
+                    ..Default::default()
                 },
                 Organization {
                     id: "org-2".to_string(),
                     name: "Test Org 2".to_string(),
                     user_count: None,
                     app_count: None,
+                    ..Default::default()
                 },
             ])
             .await;
@@ -1029,6 +1060,7 @@ mod tests {
         let jwt = JwtToken {
             token: "custom-token".to_string(),
             expires_at: chrono::Utc::now() + chrono::Duration::hours(2),
+            clock_skew_secs: 0,
         };
 
         let mock = MockStackHawkClient::new().with_jwt(jwt.clone()).await;
@@ -8,30 +8,39 @@
 //! - [`ScanDetailApi`] - Scan drill-down operations
 
 pub mod api;
-#[cfg(test)]
+pub mod cancel;
+#[cfg(any(test, feature = "test-util"))]
 pub mod fixtures;
-#[cfg(test)]
+pub mod github;
+#[cfg(any(test, feature = "test-util"))]
 pub mod mock;
 pub mod models;
 pub mod pagination;
 pub mod parallel;
+pub mod progress;
 pub mod rate_limit;
+pub mod schema;
 pub mod stackhawk;
+pub mod stats;
+pub mod trace;
 
 // Re-export sub-traits
 pub use api::{
     AppApi, AuthApi, ConfigApi, EnvironmentApi, ListingApi, OASApi, PerchApi, RepoApi,
-    ScanDetailApi, TeamApi,
+    ScanDetailApi, TeamApi, TriageApi,
 };
 
-#[cfg(test)]
+#[cfg(any(test, feature = "test-util"))]
 #[allow(unused_imports)]
 pub use mock::MockStackHawkClient;
 #[allow(unused_imports)]
 pub use pagination::{
     MAX_PAGE_SIZE, PagedResponse, PaginatedResponse, PaginationMeta, PaginationParams,
-    ScanFilterParams, SortOrder,
+    ScanFilterParams, SortOrder, default_page_size, set_default_page_size,
 };
 #[allow(unused_imports)]
-pub use parallel::fetch_remaining_pages;
-pub use stackhawk::StackHawkClient;
+pub use parallel::{
+    fetch_all_pages, fetch_pages_in_order, fetch_remaining_pages, max_concurrency,
+    set_max_concurrency,
+};
+pub use stackhawk::{StackHawkClient, TokenRefreshHook, resolve_api_host};
@@ -1,9 +1,10 @@
 //! Application models
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Application resource
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Application {
     /// Application ID
     #[serde(rename = "applicationId")]
@@ -46,7 +47,7 @@ pub struct Application {
 /// Maps to `POST /api/v1/org/{orgId}/app` (application.NewApplicationRequest).
 /// Only writable fields are included — readOnly fields (applicationStatus,
 /// dataType, organizationId, riskLevel) are set by the API.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateApplicationRequest {
     /// Application name (required)
@@ -73,14 +74,32 @@ pub struct CreateApplicationRequest {
 }
 
 /// Cloud scan target for hosted/cloud applications
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CloudScanTarget {
     /// Target URL to scan
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", alias = "targetURL")]
     pub target_url: Option<String>,
 
     /// Whether the domain has been verified
     #[serde(default)]
     pub is_domain_verified: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cloud_scan_target_accepts_spec_field_name() {
+        let target: CloudScanTarget = serde_json::from_str(
+            r#"{"targetURL":"https://api.example.com","isDomainVerified":true}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            target.target_url.as_deref(),
+            Some("https://api.example.com")
+        );
+        assert!(target.is_domain_verified);
+    }
+}
@@ -1,9 +1,10 @@
 //! Audit log models
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Audit log record
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AuditRecord {
     /// Unique audit record ID
@@ -42,8 +43,13 @@ pub struct AuditRecord {
     #[serde(default)]
     pub timestamp: String,
 
-    /// User IP address
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// User IP address (the API spells it `userIPAddr`)
+    #[serde(
+        default,
+        rename = "userIPAddr",
+        alias = "userIpAddr",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub user_ip_addr: Option<String>,
 }
 
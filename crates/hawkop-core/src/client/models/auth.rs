@@ -0,0 +1,125 @@
+//! Authentication models
+
+use chrono::{DateTime, Duration, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Refresh tokens this long before they expire
+pub const JWT_REFRESH_BUFFER_SECS: i64 = 5 * 60;
+
+/// Warn when the local clock differs from server time by more than this
+pub const CLOCK_SKEW_WARN_SECS: i64 = 60;
+
+/// JWT authentication token
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct JwtToken {
+    /// The JWT token string
+    pub token: String,
+
+    /// Token expiration time (server clock)
+    #[serde(rename = "expiresAt")]
+    pub expires_at: DateTime<Utc>,
+
+    /// Server clock minus local clock, in seconds, measured at login
+    #[serde(default, rename = "clockSkewSecs")]
+    pub clock_skew_secs: i64,
+}
+
+/// Whether a token expiring at `expires_at` (server clock) should be refreshed.
+///
+/// `local_now` is converted to server time using the measured skew before
+/// applying the refresh buffer, so a drifted local clock neither keeps using
+/// expired tokens nor re-authenticates on every run.
+pub fn token_needs_refresh(
+    expires_at: DateTime<Utc>,
+    clock_skew_secs: i64,
+    local_now: DateTime<Utc>,
+) -> bool {
+    let server_now = local_now + Duration::seconds(clock_skew_secs);
+    expires_at - Duration::seconds(JWT_REFRESH_BUFFER_SECS) < server_now
+}
+
+/// Clock skew (server minus local, in seconds) from an HTTP `Date` header.
+///
+/// Returns `None` if the header is not a valid RFC 2822 / IMF-fixdate value.
+pub fn clock_skew_from_date_header(date_header: &str, local_now: DateTime<Utc>) -> Option<i64> {
+    let server_time = DateTime::parse_from_rfc2822(date_header.trim()).ok()?;
+    Some((server_time.with_timezone(&Utc) - local_now).num_seconds())
+}
+
+/// Human-readable description of a skew ("local clock is 3m 20s behind")
+pub fn describe_clock_skew(clock_skew_secs: i64) -> String {
+    let abs = clock_skew_secs.unsigned_abs();
+    let amount = if abs >= 3600 {
+        format!("{}h {}m", abs / 3600, (abs % 3600) / 60)
+    } else if abs >= 60 {
+        format!("{}m {}s", abs / 60, abs % 60)
+    } else {
+        format!("{}s", abs)
+    };
+    // Positive skew: server is ahead, so the local clock is behind
+    let direction = if clock_skew_secs > 0 {
+        "behind"
+    } else {
+        "ahead of"
+    };
+    format!("local clock is {} {} server time", amount, direction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_needs_refresh_without_skew() {
+        let now = Utc::now();
+        assert!(token_needs_refresh(now - Duration::hours(1), 0, now));
+        assert!(token_needs_refresh(now + Duration::minutes(2), 0, now));
+        assert!(!token_needs_refresh(now + Duration::hours(1), 0, now));
+    }
+
+    #[test]
+    fn test_needs_refresh_local_clock_behind() {
+        // Server is 2h ahead: a token the local clock thinks has 1h left is expired
+        let now = Utc::now();
+        let skew = Duration::hours(2).num_seconds();
+        assert!(token_needs_refresh(now + Duration::hours(1), skew, now));
+    }
+
+    #[test]
+    fn test_needs_refresh_local_clock_ahead() {
+        // Local clock is 2h fast: a freshly issued 1h token is still valid
+        let now = Utc::now();
+        let skew = -Duration::hours(2).num_seconds();
+        let expires_at = now - Duration::hours(1);
+        assert!(!token_needs_refresh(expires_at, skew, now));
+    }
+
+    #[test]
+    fn test_clock_skew_from_date_header() {
+        let local = DateTime::parse_from_rfc3339("2026-01-15T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            clock_skew_from_date_header("Thu, 15 Jan 2026 12:05:00 GMT", local),
+            Some(300)
+        );
+        assert_eq!(
+            clock_skew_from_date_header("Thu, 15 Jan 2026 11:59:30 GMT", local),
+            Some(-30)
+        );
+        assert_eq!(clock_skew_from_date_header("not a date", local), None);
+    }
+
+    #[test]
+    fn test_describe_clock_skew() {
+        assert_eq!(
+            describe_clock_skew(200),
+            "local clock is 3m 20s behind server time"
+        );
+        assert_eq!(
+            describe_clock_skew(-7260),
+            "local clock is 2h 1m ahead of server time"
+        );
+    }
+}
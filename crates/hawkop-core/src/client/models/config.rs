@@ -2,10 +2,11 @@
 //!
 //! Models for managing organization and application scan configurations.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Organization scan configuration (from list endpoint)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ScanConfig {
     /// Configuration name
@@ -28,7 +29,7 @@ pub struct ScanConfig {
 /// Response from GET /configuration/{orgId}/{configName}
 ///
 /// Contains a pre-signed URL to download the actual YAML content.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct GetHostedAssetResponse {
     /// HTTP headers (if any)
@@ -49,7 +50,7 @@ pub struct GetHostedAssetResponse {
 // ============================================================================
 
 /// Configuration scope/type
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum ConfigType {
     /// Organization-scoped configuration (usable via hawk://configName)
@@ -72,7 +73,7 @@ impl std::fmt::Display for ConfigType {
 }
 
 /// Request to create or update a scan configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UpsertScanConfigurationRequest {
     /// The HawkScan YAML configuration content
@@ -91,7 +92,7 @@ pub struct UpsertScanConfigurationRequest {
 // ============================================================================
 
 /// Request to rename a scan configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct RenameConfigurationRequest {
     /// The original name of the configuration
@@ -106,7 +107,7 @@ pub struct RenameConfigurationRequest {
 // ============================================================================
 
 /// Response from configuration validation
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ValidatedAssetResponse {
     /// List of validation problems/markers
@@ -132,7 +133,7 @@ impl ValidatedAssetResponse {
 }
 
 /// A validation problem marker
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ValidationMarker {
     /// Problem code line
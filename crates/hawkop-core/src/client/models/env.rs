@@ -2,6 +2,7 @@
 //!
 //! Models for managing application environments.
 
+use schemars::JsonSchema;
 use serde::de;
 use serde::{Deserialize, Deserializer, Serialize};
 
@@ -50,7 +51,7 @@ where
 }
 
 /// An application environment with scan statistics
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Environment {
     /// Environment UUID
@@ -71,7 +72,7 @@ pub struct Environment {
 }
 
 /// Summary of scan results for an environment
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct EnvScanSummary {
     /// Scan ID
@@ -105,7 +106,7 @@ pub struct EnvScanSummary {
 }
 
 /// Alert statistics for a scan
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct EnvAlertStats {
     /// High severity count (API may return as string)
@@ -126,7 +127,7 @@ pub struct EnvAlertStats {
 // ============================================================================
 
 /// Response from listing environments
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ListEnvironmentsResponse {
     /// List of environments
@@ -151,7 +152,7 @@ pub struct ListEnvironmentsResponse {
 // ============================================================================
 
 /// Request to create a new environment
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct NewEnvironmentRequest {
     /// Environment name
@@ -163,7 +164,7 @@ pub struct NewEnvironmentRequest {
 // ============================================================================
 
 /// Response containing the default YAML configuration for an environment
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct EnvironmentConfigResponse {
     /// The HawkScan configuration (as nested object or string)
@@ -1,14 +1,45 @@
 //! Finding and alert models for scan drill-down
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use super::deserialize_optional_i64_or_string;
 use super::scan::{AlertStats, Scan, ScanMetadata, ScanTag};
 
+/// Finding severity, most severe first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    High,
+    Medium,
+    Low,
+    /// "Informational" or "Info"
+    Info,
+}
+
+impl Severity {
+    /// Parse an API severity in any case ("High", "HIGH", "high", ...)
+    pub fn parse(severity: &str) -> Option<Self> {
+        match severity.to_lowercase().as_str() {
+            "high" => Some(Self::High),
+            "medium" => Some(Self::Medium),
+            "low" => Some(Self::Low),
+            "informational" | "info" => Some(Self::Info),
+            _ => None,
+        }
+    }
+}
+
+/// Sort key of an API severity: High first, then Medium, Low, Info, and
+/// unrecognized values last
+pub fn severity_rank(severity: &str) -> u8 {
+    Severity::parse(severity).map_or(4, |s| s as u8)
+}
+
 /// Application alert (plugin-level finding summary)
 ///
 /// Represents a vulnerability type detected by a specific scanner plugin.
 /// Contains aggregate stats across all affected paths.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ApplicationAlert {
     /// Plugin identifier (e.g., "40012" for SQL Injection)
@@ -47,7 +78,7 @@ pub struct ApplicationAlert {
 /// Application alert URI (path-level finding)
 ///
 /// Represents a specific vulnerable endpoint discovered by a plugin.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ApplicationAlertUri {
     /// Unique identifier for this alert+path combination
@@ -97,7 +128,7 @@ pub struct ApplicationAlertUri {
 /// Alert response containing alert details and affected paths
 ///
 /// Returned by the alert findings endpoint, includes pagination for paths.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AlertResponse {
     /// The alert/plugin details
@@ -133,7 +164,7 @@ pub struct AlertResponse {
 }
 
 /// HTTP scan message containing request and response details
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ScanMessage {
     /// Message ID
@@ -163,7 +194,7 @@ pub struct ScanMessage {
 /// Alert message response with full finding details
 ///
 /// Includes the HTTP request/response and optional curl validation command.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AlertMsgResponse {
     /// HTTP request/response details
@@ -199,7 +230,7 @@ pub struct AlertMsgResponse {
 }
 
 /// Wrapper for scan alerts list response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ScanAlertsResponse {
     /// Scan result with populated application_alerts
@@ -220,7 +251,7 @@ pub struct ScanAlertsResponse {
 }
 
 /// Scan result containing populated alerts list
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ScanResultWithAlerts {
     /// Core scan data (may not be present in some API responses)
@@ -266,13 +297,13 @@ pub struct ScanResultWithAlerts {
 
 /// Row from the organization findings report
 ///
-/// Used by `scan get --detail full` for enrichment and by `findings list` command.
+/// Used by `scan get --detail full` for enrichment and by `finding list`.
 ///
 /// Returned by `GET /api/v1/reports/org/{orgId}/findings`. Contains enriched
 /// finding data including remediation advice, first/last seen dates, and
 /// stable finding hashes for cross-scan identification.
 #[allow(dead_code)] // Used in Sprint 2: scan get --detail full
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CurrentFindingRow {
     /// Stable SHA-256 hash identifying this finding across scans
@@ -358,7 +389,7 @@ pub struct CurrentFindingRow {
 
 /// Response wrapper for the organization findings report
 #[allow(dead_code)] // Used in Sprint 2: scan get --detail full
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CurrentFindingsResponse {
     /// List of finding rows
@@ -378,6 +409,84 @@ pub struct CurrentFindingsResponse {
     pub next_page_token: Option<String>,
 }
 
+/// One action in a bulk triage request
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FindingTriageAction {
+    /// Stable SHA-256 hash of the finding (`ApplicationAlertUri.finding_hash`)
+    pub finding_hash: String,
+
+    /// Triage action: FALSE_POSITIVE, RISK_ACCEPTED, or ADD_COMMENT
+    pub status: String,
+
+    /// Note explaining the triage decision
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// Request to triage findings within one application environment
+///
+/// Used by `POST /api/v1/org/{orgId}/app/{appId}/env/{envId}/findings/triage`.
+/// The API accepts at most [`BulkTriageRequest::MAX_ACTIONS`] actions.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkTriageRequest {
+    /// Organization ID
+    pub organization_id: String,
+
+    /// Application ID
+    pub application_id: String,
+
+    /// Environment ID
+    pub environment_id: String,
+
+    /// Actions to apply
+    pub actions: Vec<FindingTriageAction>,
+}
+
+impl BulkTriageRequest {
+    /// Maximum actions per request
+    pub const MAX_ACTIONS: usize = 100;
+}
+
+/// Response from bulk triage, one result per action in request order
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkTriageResponse {
+    /// Per-action results
+    #[serde(default)]
+    pub results: Vec<FindingTriageResult>,
+}
+
+/// Result of a single triage action (failures do not fail the request)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FindingTriageResult {
+    /// Finding hash the action applied to
+    #[serde(default)]
+    pub finding_hash: String,
+
+    /// Whether the action succeeded
+    #[serde(default)]
+    pub success: bool,
+
+    /// Error message when the action failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+
+    /// Triage status after the action
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    /// Note after the action
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+
+    /// When the triage was applied
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
 /// Custom deserializer for fields that may be int or string (u32)
 fn deserialize_optional_int_or_string<'de, D>(
     deserializer: D,
@@ -401,25 +510,19 @@ where
     }
 }
 
-/// Custom deserializer for i64 fields that may come as strings
-fn deserialize_optional_i64_or_string<'de, D>(
-    deserializer: D,
-) -> std::result::Result<Option<i64>, D::Error>
-where
-    D: serde::de::Deserializer<'de>,
-{
-    use serde::Deserialize;
-
-    #[derive(Deserialize)]
-    #[serde(untagged)]
-    enum IntOrString {
-        Int(i64),
-        String(String),
-    }
-
-    match Option::<IntOrString>::deserialize(deserializer)? {
-        Some(IntOrString::Int(i)) => Ok(Some(i)),
-        Some(IntOrString::String(s)) => s.parse().map(Some).map_err(serde::de::Error::custom),
-        None => Ok(None),
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_severity_rank_ignores_case() {
+        assert_eq!(severity_rank("High"), 0);
+        assert_eq!(severity_rank("HIGH"), 0);
+        assert_eq!(severity_rank("medium"), 1);
+        assert_eq!(severity_rank("Low"), 2);
+        assert_eq!(severity_rank("Informational"), 3);
+        assert_eq!(severity_rank("INFO"), 3);
+        assert_eq!(severity_rank(""), 4);
+        assert!(Severity::High < Severity::Info);
     }
 }
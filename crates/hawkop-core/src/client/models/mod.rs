@@ -25,7 +25,10 @@ mod user;
 // Re-export all models for convenient access
 pub use app::{Application, CloudScanTarget, CreateApplicationRequest};
 pub use audit::{AuditFilterParams, AuditRecord};
-pub use auth::JwtToken;
+pub use auth::{
+    CLOCK_SKEW_WARN_SECS, JwtToken, clock_skew_from_date_header, describe_clock_skew,
+    token_needs_refresh,
+};
 pub use config::{
     ConfigType, GetHostedAssetResponse, RenameConfigurationRequest, ScanConfig,
     UpsertScanConfigurationRequest, ValidatedAssetResponse, ValidationMarker,
@@ -35,11 +38,13 @@ pub use env::{
     ListEnvironmentsResponse, NewEnvironmentRequest,
 };
 pub use finding::{
-    AlertMsgResponse, AlertResponse, ApplicationAlert, ApplicationAlertUri, CurrentFindingRow,
-    CurrentFindingsResponse, ScanAlertsResponse, ScanMessage, ScanResultWithAlerts,
+    AlertMsgResponse, AlertResponse, ApplicationAlert, ApplicationAlertUri, BulkTriageRequest,
+    BulkTriageResponse, CurrentFindingRow, CurrentFindingsResponse, FindingTriageAction,
+    FindingTriageResult, ScanAlertsResponse, ScanMessage, ScanResultWithAlerts, Severity,
+    severity_rank,
 };
 pub use oas::{GetApplicationMappedOASResponse, OASAsset};
-pub use org::Organization;
+pub use org::{OrgFeature, Organization};
 pub use perch::{
     GetPerchDeviceResponse, PerchCommand, PerchCommandRequest, PerchCommandResponse, PerchDevice,
     PerchError,
@@ -53,5 +58,28 @@ pub use scan::{AlertStats, AlertStatusStats, Scan, ScanMetadata, ScanResult, Sca
 pub use secret::Secret;
 pub use user::{
     CreateTeamRequest, Team, TeamApplication, TeamDetail, TeamUser, UpdateApplicationTeamRequest,
-    UpdateTeamRequest, User, UserExternal,
+    UpdateTeamRequest, User, UserExternal, UserOrganization,
 };
+
+/// Int64 fields may be serialized as strings by the API
+pub(crate) fn deserialize_optional_i64_or_string<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<i64>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum IntOrString {
+        Int(i64),
+        String(String),
+    }
+
+    match Option::<IntOrString>::deserialize(deserializer)? {
+        Some(IntOrString::Int(i)) => Ok(Some(i)),
+        Some(IntOrString::String(s)) => s.parse().map(Some).map_err(serde::de::Error::custom),
+        None => Ok(None),
+    }
+}
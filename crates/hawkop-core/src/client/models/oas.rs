@@ -1,9 +1,10 @@
 //! OpenAPI specification models
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Hosted OpenAPI specification asset
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct OASAsset {
     /// Unique OAS ID
@@ -36,7 +37,7 @@ pub struct OASAsset {
 // ============================================================================
 
 /// Response from getting application-mapped OAS specs
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct GetApplicationMappedOASResponse {
     /// The application ID
@@ -0,0 +1,212 @@
+//! Organization models
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::deserialize_optional_i64_or_string;
+
+/// Organization resource
+///
+/// Returned by `GET /user` as part of each organization membership, which
+/// includes the plan, subscription, and settings.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Organization {
+    /// Organization ID
+    pub id: String,
+
+    /// Organization name
+    pub name: String,
+
+    /// Number of users (optional, may not be in all responses)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_count: Option<usize>,
+
+    /// Number of applications (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app_count: Option<usize>,
+
+    /// Creation time (epoch milliseconds)
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_optional_i64_or_string"
+    )]
+    pub created_timestamp: Option<i64>,
+
+    /// Feature plan the organization belongs to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plan: Option<String>,
+
+    /// Feature flags available to the organization
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub features: Vec<OrgFeature>,
+
+    /// Billing subscription (entitlements and soft limits)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscription: Option<OrgSubscription>,
+
+    /// Organization settings
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub settings: Option<OrgSettings>,
+}
+
+/// A feature flag and whether it is enabled
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OrgFeature {
+    /// Feature name (e.g., "CLOUD_SCAN")
+    #[serde(default)]
+    pub name: String,
+
+    /// Whether the feature is enabled
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+/// Subscription entitling the organization to the platform
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OrgSubscription {
+    /// Status: TRIALING, ACTIVE, FREE, PAST_DUE, CANCELLED, ...
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    /// Name of the purchased product
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product_name: Option<String>,
+
+    /// Soft application limit
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_optional_i64_or_string"
+    )]
+    pub app_count: Option<i64>,
+
+    /// Soft user limit
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_optional_i64_or_string"
+    )]
+    pub user_count: Option<i64>,
+
+    /// Quantity of units purchased
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_optional_i64_or_string"
+    )]
+    pub quantity: Option<i64>,
+
+    /// Billing interval: MONTH or YEAR
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recurring_interval: Option<String>,
+
+    /// Subscription start (epoch seconds)
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_optional_i64_or_string"
+    )]
+    pub start_date: Option<i64>,
+
+    /// Subscription end (epoch seconds)
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_optional_i64_or_string"
+    )]
+    pub end_date: Option<i64>,
+
+    /// Whether the subscription was created via self-serve
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_self_serve: Option<bool>,
+}
+
+/// Organization settings relevant to usage and access
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OrgSettings {
+    /// Hosted (cloud) scan settings
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cloud_scan_settings: Option<CloudScanSettings>,
+
+    /// Number of custom plugins the organization may register
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_optional_i64_or_string"
+    )]
+    pub custom_plugin_limit: Option<i64>,
+
+    /// Whether SAML is the only allowed sign-in method
+    #[serde(default)]
+    pub is_organization_saml_only: bool,
+
+    /// Whether AI features are turned off
+    #[serde(default, rename = "isTurnedOffAI")]
+    pub is_turned_off_ai: bool,
+
+    /// Whether OpenAPI spec generation is turned off
+    #[serde(default, rename = "isTurnedOffOASGen")]
+    pub is_turned_off_oas_gen: bool,
+}
+
+/// Hosted scan allotment
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CloudScanSettings {
+    /// Seconds of hosted scanning allowed per usage period
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_optional_i64_or_string"
+    )]
+    pub usage_period_limit_seconds: Option<i64>,
+
+    /// Day of the month the usage period starts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub day_of_month_usage_period_start: Option<i32>,
+
+    /// HawkScan image version for hosted scans ("dev" or "latest")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hawkscan_version: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_organization_deserializes_plan_and_subscription() {
+        let json = r#"{
+            "id": "org-1",
+            "name": "Acme",
+            "plan": "ENTERPRISE",
+            "features": [{"name": "CLOUD_SCAN", "enabled": true}],
+            "subscription": {"status": "ACTIVE", "appCount": 50, "userCount": "25"},
+            "settings": {
+                "isTurnedOffAI": true,
+                "cloudScanSettings": {"usagePeriodLimitSeconds": 36000}
+            }
+        }"#;
+        let org: Organization = serde_json::from_str(json).unwrap();
+
+        assert_eq!(org.plan.as_deref(), Some("ENTERPRISE"));
+        assert!(org.features[0].enabled);
+        let sub = org.subscription.unwrap();
+        assert_eq!(sub.app_count, Some(50));
+        assert_eq!(sub.user_count, Some(25));
+        let settings = org.settings.unwrap();
+        assert!(settings.is_turned_off_ai);
+        assert_eq!(
+            settings
+                .cloud_scan_settings
+                .unwrap()
+                .usage_period_limit_seconds,
+            Some(36000)
+        );
+    }
+}
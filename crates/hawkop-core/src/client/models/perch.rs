@@ -3,10 +3,11 @@
 //! Models for controlling hosted scans via the StackHawk Perch service.
 //! These endpoints allow starting, stopping, and monitoring cloud-based scans.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Status of a hosted scan device/runner
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PerchDevice {
     /// Application ID this device is scanning
@@ -38,7 +39,7 @@ pub struct PerchDevice {
 }
 
 /// A command issued to the Perch service
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PerchCommand {
     /// Command type (e.g., "START", "STOP")
@@ -55,7 +56,7 @@ pub struct PerchCommand {
 }
 
 /// Error information from a Perch operation
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PerchError {
     /// Error type/code
@@ -66,7 +67,7 @@ pub struct PerchError {
 }
 
 /// Request to start or stop a hosted scan
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PerchCommandRequest {
     /// The command to execute
@@ -83,7 +84,7 @@ pub struct PerchCommandRequest {
 }
 
 /// Response from a Perch command (start/stop)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PerchCommandResponse {
     /// Response ID
@@ -91,7 +92,7 @@ pub struct PerchCommandResponse {
 }
 
 /// Response from getting device status
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct GetPerchDeviceResponse {
     /// The device/runner information
@@ -1,9 +1,10 @@
 //! Policy models
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Policy type (StackHawk preset or Organization custom)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum PolicyType {
     /// Preset policy created by StackHawk (read-only)
     StackHawk,
@@ -21,7 +22,7 @@ impl std::fmt::Display for PolicyType {
 }
 
 /// StackHawk scan policy (preset, read-only)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct StackHawkPolicy {
     /// Policy ID
@@ -41,7 +42,7 @@ pub struct StackHawkPolicy {
 }
 
 /// Organization scan policy (custom, editable)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct OrgPolicy {
     /// Policy name (unique identifier)
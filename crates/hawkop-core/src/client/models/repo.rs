@@ -1,9 +1,10 @@
 //! Repository models for attack surface mapping
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Repository from attack surface mapping
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Repository {
     /// Repository ID
@@ -64,7 +65,7 @@ pub struct Repository {
 }
 
 /// OpenAPI specification info for repository
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct OpenApiSpecInfo {
     /// Number of generated OAS files
@@ -73,7 +74,7 @@ pub struct OpenApiSpecInfo {
 }
 
 /// Sensitive data tag detected in repository
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct SensitiveDataTag {
     /// Tag name (e.g., PII, PCI, PHI)
@@ -82,7 +83,7 @@ pub struct SensitiveDataTag {
 }
 
 /// Repository contributor information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct RepoContributor {
     /// Contributor name
@@ -95,7 +96,7 @@ pub struct RepoContributor {
 }
 
 /// Application info linked to repository
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct RepoAppInfo {
     /// Application ID
@@ -108,7 +109,7 @@ pub struct RepoAppInfo {
 }
 
 /// API Discovery insight for repository
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct RepoInsight {
     /// Insight name (e.g., "apiStyle")
@@ -125,7 +126,7 @@ pub struct RepoInsight {
 /// Used by `POST /api/v1/org/{orgId}/repo/{repoId}/applications`.
 /// The API replaces all mappings, so callers must include existing
 /// mappings they want to preserve (read-merge-write pattern).
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ReplaceRepoAppMappingsRequest {
     /// Organization ID
@@ -142,7 +143,7 @@ pub struct ReplaceRepoAppMappingsRequest {
 ///
 /// When linking an existing app, only `id` is needed.
 /// When creating a new app, only `name` is needed.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct RepoAppInfoWrite {
     /// Application ID (for linking existing apps)
@@ -155,7 +156,7 @@ pub struct RepoAppInfoWrite {
 }
 
 /// Response from replace repository application mappings
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ReplaceRepoAppMappingsResponse {
     /// Organization ID
@@ -1,10 +1,11 @@
 //! Scan models
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Scan result from the API
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Scan {
     /// Scan ID
@@ -59,7 +60,7 @@ where
 }
 
 /// Full scan result with duration and stats from applicationScanResults
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ScanResult {
     /// Core scan data
@@ -99,7 +100,7 @@ pub struct ScanResult {
 }
 
 /// Scan tag (name-value metadata pair)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ScanTag {
     /// Tag name
@@ -112,7 +113,7 @@ pub struct ScanTag {
 }
 
 /// Alert statistics from scan results
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AlertStats {
     /// Total number of alerts
@@ -129,7 +130,7 @@ pub struct AlertStats {
 }
 
 /// Alert statistics by status (UNKNOWN = new, PROMOTED = triaged)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AlertStatusStats {
     /// Alert status: UNKNOWN (new), PROMOTED (triaged), etc.
@@ -152,7 +153,7 @@ pub struct AlertStatusStats {
 /// - `policyName`: The policy code name (e.g., "DEFAULT_API")
 /// - `policyDisplayName`: The human-friendly policy name (e.g., "OpenAPI/REST API")
 /// - `isCustomPolicy`: Whether the policy is customized
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ScanMetadata {
     /// Key-value tags containing scan context (userId, policyName, etc.)
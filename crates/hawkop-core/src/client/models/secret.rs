@@ -1,9 +1,10 @@
 //! Secret models
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// User secret (name only - values are not returned by list API)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Secret {
     /// Secret name
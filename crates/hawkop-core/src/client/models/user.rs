@@ -1,15 +1,41 @@
 //! User and team models
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use super::Organization;
+
 /// Organization member/user (wrapper for API response)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct User {
     /// User details from external field
     pub external: UserExternal,
 }
 
+impl User {
+    /// The user's role in an organization (e.g., "ADMIN", "MEMBER")
+    pub fn org_role(&self, org_id: &str) -> Option<&str> {
+        self.external
+            .organizations
+            .iter()
+            .find(|m| m.organization.id == org_id)
+            .and_then(|m| m.role.as_deref())
+    }
+}
+
+/// A user's membership in one organization
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UserOrganization {
+    /// The organization
+    pub organization: Organization,
+
+    /// Role in the organization (e.g., "OWNER", "ADMIN", "MEMBER")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+}
+
 // ============================================================================
 // Team Detail Models (for CRUD operations)
 // ============================================================================
@@ -18,7 +44,7 @@ pub struct User {
 ///
 /// This is returned by the get team endpoint and contains the complete
 /// team information with nested users and applications.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TeamDetail {
     /// Team ID (UUID)
@@ -41,7 +67,7 @@ pub struct TeamDetail {
 }
 
 /// A user who is a member of a team.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TeamUser {
     /// User ID (UUID)
@@ -61,7 +87,7 @@ pub struct TeamUser {
 }
 
 /// An application assigned to a team.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TeamApplication {
     /// Application ID (UUID)
@@ -81,7 +107,7 @@ pub struct TeamApplication {
 // ============================================================================
 
 /// Request to create a new team.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateTeamRequest {
     /// Team name
@@ -113,7 +139,7 @@ pub struct CreateTeamRequest {
 /// 3. PUT the entire state back
 ///
 /// See: .claude/skills/stackhawk-api-sherpa/api-quirks.md
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateTeamRequest {
     /// Team ID - REQUIRED despite OpenAPI saying "readOnly"
@@ -135,7 +161,7 @@ pub struct UpdateTeamRequest {
 }
 
 /// Request to assign an application to a team.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 #[allow(dead_code)]
 pub struct UpdateApplicationTeamRequest {
@@ -147,7 +173,7 @@ pub struct UpdateApplicationTeamRequest {
 }
 
 /// User details from the external field in API response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UserExternal {
     /// User ID
@@ -167,10 +193,13 @@ pub struct UserExternal {
     /// User's full name (optional)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub full_name: Option<String>,
+    /// Organization memberships with the user's role in each
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub organizations: Vec<UserOrganization>,
 }
 
 /// Organization team
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Team {
     /// Team ID
@@ -188,6 +217,25 @@ pub struct Team {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_user_org_role() {
+        let user: User = serde_json::from_value(serde_json::json!({
+            "external": {
+                "id": "user-1",
+                "email": "a@example.com",
+                "organizations": [
+                    {"organization": {"id": "org-1", "name": "One"}, "role": "ADMIN"},
+                    {"organization": {"id": "org-2", "name": "Two"}}
+                ]
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(user.org_role("org-1"), Some("ADMIN"));
+        assert_eq!(user.org_role("org-2"), None);
+        assert_eq!(user.org_role("org-3"), None);
+    }
+
     #[test]
     fn test_update_team_request_includes_all_fields() {
         // CRITICAL: All 5 fields must be sent to avoid data loss
@@ -7,12 +7,27 @@
 
 #![allow(dead_code)]
 
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 use serde::{Deserialize, Serialize};
 
 /// Maximum page size supported by StackHawk API.
 /// Using this as default minimizes API calls.
 pub const MAX_PAGE_SIZE: usize = 1000;
 
+static DEFAULT_PAGE_SIZE: AtomicUsize = AtomicUsize::new(MAX_PAGE_SIZE);
+
+/// Set the process-wide page size for requests that don't pick one
+/// (`preferences.page_size` / `HAWKOP_PAGE_SIZE`), capped at [`MAX_PAGE_SIZE`]
+pub fn set_default_page_size(size: usize) {
+    DEFAULT_PAGE_SIZE.store(size.clamp(1, MAX_PAGE_SIZE), Ordering::Relaxed);
+}
+
+/// Page size for requests that don't pick one (default [`MAX_PAGE_SIZE`])
+pub fn default_page_size() -> usize {
+    DEFAULT_PAGE_SIZE.load(Ordering::Relaxed)
+}
+
 /// Pagination parameters for API requests.
 ///
 /// Use the builder pattern to configure pagination options.
@@ -78,7 +93,8 @@ impl PaginationParams {
     ///
     /// Returns a vector of (key, value) pairs suitable for URL encoding.
     /// Uses StackHawk API parameter names:
-    /// - `pageSize`: number of elements per page (defaults to MAX_PAGE_SIZE to minimize API calls)
+    /// - `pageSize`: number of elements per page (defaults to [`default_page_size`], normally
+    ///   MAX_PAGE_SIZE to minimize API calls)
     /// - `pageToken`: page number to start at (0-indexed)
     /// - `sortField`: field to sort by
     /// - `sortDir`: 'asc' or 'desc'
@@ -86,7 +102,7 @@ impl PaginationParams {
         let mut params = Vec::new();
 
         // Always include pageSize, defaulting to max to minimize API calls
-        let size = self.page_size.unwrap_or(MAX_PAGE_SIZE);
+        let size = self.page_size.unwrap_or_else(default_page_size);
         params.push(("pageSize", size.to_string()));
 
         if let Some(page) = self.page {
@@ -271,6 +287,8 @@ pub struct ScanFilterParams {
     pub start: Option<i64>,
     /// End time filter (Unix timestamp in milliseconds)
     pub end: Option<i64>,
+    /// Tag filters in `name:value` form (parameter: tag, repeated for AND)
+    pub tags: Vec<String>,
 }
 
 impl ScanFilterParams {
@@ -299,7 +317,6 @@ impl ScanFilterParams {
     }
 
     /// Set start time filter.
-    #[allow(dead_code)]
     pub fn start(mut self, timestamp_ms: i64) -> Self {
         self.start = Some(timestamp_ms);
         self
@@ -312,6 +329,12 @@ impl ScanFilterParams {
         self
     }
 
+    /// Set tag filters (`name:value`; `|` for OR, `*` for wildcard).
+    pub fn tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
     /// Check if any filters are set.
     pub fn is_empty(&self) -> bool {
         self.app_ids.is_empty()
@@ -319,12 +342,13 @@ impl ScanFilterParams {
             && self.team_ids.is_empty()
             && self.start.is_none()
             && self.end.is_none()
+            && self.tags.is_empty()
     }
 
     /// Convert to query string parameters.
     ///
     /// Returns a vector of (key, value) pairs suitable for URL encoding.
-    /// Multi-value params (appIds, envs, teamIds) are repeated for each value.
+    /// Multi-value params (appIds, envs, teamIds, tag) are repeated for each value.
     pub fn to_query_params(&self) -> Vec<(&'static str, String)> {
         let mut params = Vec::new();
 
@@ -348,6 +372,10 @@ impl ScanFilterParams {
             params.push(("end", end.to_string()));
         }
 
+        for tag in &self.tags {
+            params.push(("tag", tag.clone()));
+        }
+
         params
     }
 }
@@ -483,6 +511,24 @@ mod tests {
         assert!(query.contains(&("envs", "staging".to_string())));
     }
 
+    #[test]
+    fn test_scan_filter_params_tags_repeat() {
+        let params = ScanFilterParams::new().tags(vec![
+            "branch:main|develop".to_string(),
+            "env:prod".to_string(),
+        ]);
+
+        assert!(!params.is_empty());
+        let query = params.to_query_params();
+        assert_eq!(
+            query,
+            vec![
+                ("tag", "branch:main|develop".to_string()),
+                ("tag", "env:prod".to_string()),
+            ]
+        );
+    }
+
     #[test]
     fn test_paged_response_total_pages() {
         // 250 items, 100 per page = 3 pages
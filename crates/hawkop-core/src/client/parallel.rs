@@ -0,0 +1,581 @@
+//! Parallel fetching utilities for paginated API requests.
+//!
+//! Provides helpers to fetch multiple pages in parallel using totalCount
+//! from the first response. Concurrency backs off when the API rate-limits
+//! (429) and recovers gradually as pages succeed. Every fetcher stops when
+//! cancellation is requested (see [`super::cancel`]).
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use futures::stream::{self, FuturesUnordered, StreamExt};
+use log::debug;
+
+use super::cancel;
+use super::pagination::PagedResponse;
+use super::rate_limit::rate_limit_hits;
+use crate::error::{ApiError, Error, Result};
+
+/// Default maximum concurrent page requests (`--max-concurrency`)
+pub const DEFAULT_MAX_CONCURRENCY: usize = 32;
+
+/// Times a page is re-queued after the client's own 429 retries are exhausted
+const MAX_PAGE_RETRIES: u32 = 3;
+
+/// Base delay before re-requesting a rate-limited page (doubles per attempt)
+const PAGE_RETRY_BASE_DELAY: Duration = Duration::from_secs(2);
+
+static MAX_CONCURRENCY: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_CONCURRENCY);
+
+/// Set the process-wide concurrency ceiling for parallel fetches
+pub fn set_max_concurrency(limit: usize) {
+    MAX_CONCURRENCY.store(limit.max(1), Ordering::Relaxed);
+}
+
+/// Concurrency ceiling for parallel fetches (`--max-concurrency`, default 32)
+pub fn max_concurrency() -> usize {
+    MAX_CONCURRENCY.load(Ordering::Relaxed)
+}
+
+/// Type alias for boxed futures used in parallel fetching
+type PageFuture<T> = Pin<Box<dyn Future<Output = (usize, u32, Result<Vec<T>>)> + Send>>;
+
+/// Additive-increase / multiplicative-decrease concurrency window.
+///
+/// Halves on every rate-limit signal and grows by one after a full window
+/// of successful pages, never exceeding the configured ceiling.
+#[derive(Debug)]
+struct AdaptiveLimit {
+    max: usize,
+    current: usize,
+    successes: usize,
+    seen_hits: usize,
+}
+
+impl AdaptiveLimit {
+    fn new(max: usize, seen_hits: usize) -> Self {
+        let max = max.max(1);
+        Self {
+            max,
+            current: max,
+            successes: 0,
+            seen_hits,
+        }
+    }
+
+    fn current(&self) -> usize {
+        self.current
+    }
+
+    fn throttle(&mut self) {
+        let reduced = (self.current / 2).max(1);
+        if reduced < self.current {
+            debug!(
+                "Rate limited: reducing concurrency {} -> {}",
+                self.current, reduced
+            );
+        }
+        self.current = reduced;
+        self.successes = 0;
+    }
+
+    fn success(&mut self) {
+        if self.current < self.max {
+            self.successes += 1;
+            if self.successes >= self.current {
+                self.current += 1;
+                self.successes = 0;
+            }
+        }
+    }
+
+    /// Throttle if the client has seen new 429s since the last check
+    fn observe_hits(&mut self, hits: usize) {
+        if hits > self.seen_hits {
+            self.seen_hits = hits;
+            self.throttle();
+        }
+    }
+}
+
+/// Fetch all remaining pages in parallel after the first page.
+///
+/// Uses the `remaining_pages` from the first response to spawn parallel requests
+/// for all remaining pages, up to `max_concurrent` at a time. Concurrency is
+/// halved whenever the client receives a 429, and a page that still fails
+/// with a rate-limit error is re-queued with jittered exponential backoff.
+///
+/// # Arguments
+///
+/// * `remaining_pages` - Page numbers to fetch (from `PagedResponse::remaining_pages()`)
+/// * `fetch_page` - Async function that fetches a single page by number
+/// * `max_concurrent` - Maximum number of concurrent requests (usually [`max_concurrency()`])
+///
+/// # Returns
+///
+/// A vector containing all items from all remaining pages, in arrival order.
+///
+/// # Example
+///
+/// ```ignore
+/// let first_page = client.list_scans_paged(&org_id, Some(&params), None).await?;
+/// let mut all_scans = first_page.items;
+///
+/// if first_page.has_more_pages() {
+///     let remaining = fetch_remaining_pages(
+///         first_page.remaining_pages(),
+///         |page| {
+///             let c = client.clone();
+///             let o = org_id.clone();
+///             async move {
+///                 let params = PaginationParams::new().page_size(100).page(page);
+///                 c.list_scans(&o, Some(&params), None).await
+///             }
+///         },
+///         max_concurrency(),
+///     ).await?;
+///     all_scans.extend(remaining);
+/// }
+/// ```
+pub async fn fetch_remaining_pages<T, F, Fut>(
+    remaining_pages: Vec<usize>,
+    fetch_page: F,
+    max_concurrent: usize,
+) -> Result<Vec<T>>
+where
+    T: Send + 'static,
+    F: Fn(usize) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<Vec<T>>> + Send + 'static,
+{
+    fetch_pages_adaptive(
+        remaining_pages,
+        fetch_page,
+        max_concurrent,
+        PAGE_RETRY_BASE_DELAY,
+    )
+    .await
+}
+
+/// Fetch every page of a listing, given its first page.
+///
+/// When the first response carries `total_count`, the remaining pages are
+/// fetched in parallel with [`fetch_remaining_pages`]. Endpoints that don't
+/// report a total are paged in order until a short page comes back.
+pub async fn fetch_all_pages<T, F, Fut>(
+    first: PagedResponse<T>,
+    fetch_page: F,
+    max_concurrent: usize,
+) -> Result<Vec<T>>
+where
+    T: Send + 'static,
+    F: Fn(usize) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<Vec<T>>> + Send + 'static,
+{
+    if first.total_count.is_some() {
+        let remaining_pages = first.remaining_pages();
+        let mut items = first.items;
+        items.extend(fetch_remaining_pages(remaining_pages, fetch_page, max_concurrent).await?);
+        return Ok(items);
+    }
+
+    debug!("No totalCount available, fetching pages until a short page");
+    let mut page = first.page_token;
+    let mut last_len = first.items.len();
+    let mut items = first.items;
+    while first.page_size > 0 && last_len >= first.page_size {
+        page += 1;
+        let next = tokio::select! {
+            next = fetch_page(page) => next?,
+            reason = cancel::requested() => return cancel::stop(items, reason, None),
+        };
+        last_len = next.len();
+        items.extend(next);
+    }
+    Ok(items)
+}
+
+/// Fetch every page of a listing in page order, given its first page.
+///
+/// Like [`fetch_all_pages`], but items keep the API's order and `fetch_page`
+/// may borrow from the caller, so a client method can page through a listing
+/// itself. Pages run on the caller's task, up to `max_concurrent` at a time.
+pub async fn fetch_pages_in_order<T, F, Fut>(
+    first: PagedResponse<T>,
+    fetch_page: F,
+    max_concurrent: usize,
+) -> Result<Vec<T>>
+where
+    F: Fn(usize) -> Fut,
+    Fut: Future<Output = Result<Vec<T>>>,
+{
+    if first.total_count.is_some() {
+        let remaining_pages = first.remaining_pages();
+        debug!(
+            "Fetching {} remaining pages in order with max {} concurrent",
+            remaining_pages.len(),
+            max_concurrent
+        );
+        let mut unfetched = remaining_pages.len();
+        let mut pages = stream::iter(remaining_pages)
+            .map(&fetch_page)
+            .buffered(max_concurrent.max(1));
+        let mut items = first.items;
+        loop {
+            tokio::select! {
+                next = pages.next() => match next {
+                    Some(page) => {
+                        items.extend(page?);
+                        unfetched -= 1;
+                    }
+                    None => return Ok(items),
+                },
+                reason = cancel::requested() => {
+                    return cancel::stop(items, reason, Some(unfetched));
+                }
+            }
+        }
+    }
+
+    debug!("No totalCount available, fetching pages until a short page");
+    let mut page = first.page_token;
+    let mut last_len = first.items.len();
+    let mut items = first.items;
+    while first.page_size > 0 && last_len >= first.page_size {
+        page += 1;
+        let next = tokio::select! {
+            next = fetch_page(page) => next?,
+            reason = cancel::requested() => return cancel::stop(items, reason, None),
+        };
+        last_len = next.len();
+        items.extend(next);
+    }
+    Ok(items)
+}
+
+async fn fetch_pages_adaptive<T, F, Fut>(
+    remaining_pages: Vec<usize>,
+    fetch_page: F,
+    max_concurrent: usize,
+    retry_base_delay: Duration,
+) -> Result<Vec<T>>
+where
+    T: Send + 'static,
+    F: Fn(usize) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<Vec<T>>> + Send + 'static,
+{
+    if remaining_pages.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    debug!(
+        "Fetching {} remaining pages with max {} concurrent",
+        remaining_pages.len(),
+        max_concurrent
+    );
+
+    let mut all_items = Vec::new();
+    let mut futures: FuturesUnordered<PageFuture<T>> = FuturesUnordered::new();
+    let mut pending: VecDeque<(usize, u32)> =
+        remaining_pages.into_iter().map(|page| (page, 0)).collect();
+    let mut limit = AdaptiveLimit::new(max_concurrent, rate_limit_hits());
+
+    // Helper to create a boxed future, delayed when retrying a page
+    let make_future = |page: usize, attempt: u32, f: &F| -> PageFuture<T> {
+        let fut = f(page);
+        let delay = retry_delay(retry_base_delay, attempt);
+        Box::pin(async move {
+            if !delay.is_zero() {
+                tokio::time::sleep(delay).await;
+            }
+            (page, attempt, fut.await)
+        })
+    };
+
+    loop {
+        // Top up to the current window
+        while futures.len() < limit.current() {
+            let Some((page, attempt)) = pending.pop_front() else {
+                break;
+            };
+            debug!("Spawning request for page {} (attempt {})", page, attempt);
+            futures.push(make_future(page, attempt, &fetch_page));
+        }
+
+        let next = tokio::select! {
+            next = futures.next() => next,
+            reason = cancel::requested() => {
+                let unfetched = pending.len() + futures.len();
+                return cancel::stop(all_items, reason, Some(unfetched));
+            }
+        };
+        let Some((page, attempt, result)) = next else {
+            break;
+        };
+        limit.observe_hits(rate_limit_hits());
+
+        match result {
+            Ok(items) => {
+                debug!("Page {} returned {} items", page, items.len());
+                limit.success();
+                all_items.extend(items);
+            }
+            Err(Error::Api(ApiError::RateLimited)) if attempt < MAX_PAGE_RETRIES => {
+                debug!("Page {} rate limited; re-queueing", page);
+                limit.throttle();
+                pending.push_back((page, attempt + 1));
+            }
+            Err(e) => return Err(e),
+        }
+    }
+
+    debug!(
+        "Fetched {} total items from remaining pages",
+        all_items.len()
+    );
+    Ok(all_items)
+}
+
+/// Zero for a first attempt, else `base * 2^(attempt-1)` plus up to 1s of jitter
+fn retry_delay(base: Duration, attempt: u32) -> Duration {
+    if attempt == 0 {
+        return Duration::ZERO;
+    }
+    // Jitter from the clock's sub-second nanos (avoids a rand dependency)
+    let jitter_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos() % 1000)
+        .unwrap_or(0) as u64;
+    let jitter = Duration::from_millis(jitter_ms).min(base);
+    base.saturating_mul(1 << (attempt - 1)) + jitter
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn test_fetch_remaining_pages_empty() {
+        let result: Result<Vec<String>> =
+            fetch_remaining_pages(vec![], |_page| async { Ok(vec![]) }, 10).await;
+
+        assert!(result.is_ok());
+        assert!(result.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_remaining_pages_single() {
+        let result: Result<Vec<String>> = fetch_remaining_pages(
+            vec![1],
+            |page| async move { Ok(vec![format!("item-{}", page)]) },
+            10,
+        )
+        .await;
+
+        assert!(result.is_ok());
+        let items = result.unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0], "item-1");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_remaining_pages_multiple() {
+        let result: Result<Vec<String>> = fetch_remaining_pages(
+            vec![1, 2, 3],
+            |page| async move { Ok(vec![format!("item-{}-a", page), format!("item-{}-b", page)]) },
+            10,
+        )
+        .await;
+
+        assert!(result.is_ok());
+        let items = result.unwrap();
+        assert_eq!(items.len(), 6); // 3 pages * 2 items each
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_pages_with_and_without_total() {
+        // 5 items in pages of 2: pages 0, 1, 2
+        let page_items =
+            |page: usize| -> Vec<usize> { (page * 2..(page * 2 + 2).min(5)).collect() };
+
+        let first = PagedResponse::new(page_items(0), Some(5), 2, 0);
+        let mut items = fetch_all_pages(first, move |p| async move { Ok(page_items(p)) }, 4)
+            .await
+            .unwrap();
+        items.sort();
+        assert_eq!(items, vec![0, 1, 2, 3, 4]);
+
+        let first = PagedResponse::new(page_items(0), None, 2, 0);
+        let items = fetch_all_pages(first, move |p| async move { Ok(page_items(p)) }, 4)
+            .await
+            .unwrap();
+        assert_eq!(items, vec![0, 1, 2, 3, 4]);
+
+        let first = PagedResponse::new(vec![0], None, 2, 0);
+        let items = fetch_all_pages(
+            first,
+            |_| async { Err(Error::Other("short first page".to_string())) },
+            4,
+        )
+        .await
+        .unwrap();
+        assert_eq!(items, vec![0]);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_pages_in_order_keeps_api_order() {
+        // Later pages finish first; items still come back in page order
+        let data: Vec<usize> = (0..7).collect();
+        let first = PagedResponse::new(data[..2].to_vec(), Some(7), 2, 0);
+        let items = fetch_pages_in_order(
+            first,
+            |page| {
+                let data = &data;
+                async move {
+                    tokio::time::sleep(Duration::from_millis(20 / page as u64)).await;
+                    Ok(data[page * 2..(page * 2 + 2).min(7)].to_vec())
+                }
+            },
+            4,
+        )
+        .await
+        .unwrap();
+        assert_eq!(items, data);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_remaining_pages_respects_concurrency() {
+        let concurrent_count = Arc::new(AtomicUsize::new(0));
+        let max_observed = Arc::new(AtomicUsize::new(0));
+
+        let cc = concurrent_count.clone();
+        let mo = max_observed.clone();
+
+        let result: Result<Vec<usize>> = fetch_remaining_pages(
+            vec![1, 2, 3, 4, 5],
+            move |page| {
+                let cc = cc.clone();
+                let mo = mo.clone();
+                async move {
+                    // Track concurrent requests
+                    let current = cc.fetch_add(1, Ordering::SeqCst) + 1;
+                    mo.fetch_max(current, Ordering::SeqCst);
+
+                    // Simulate some work
+                    tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+
+                    cc.fetch_sub(1, Ordering::SeqCst);
+                    Ok(vec![page])
+                }
+            },
+            2, // Only 2 concurrent
+        )
+        .await;
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().len(), 5);
+        // Max concurrent should not exceed our limit
+        assert!(max_observed.load(Ordering::SeqCst) <= 2);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_remaining_pages_propagates_errors() {
+        let result: Result<Vec<String>> = fetch_remaining_pages(
+            vec![1, 2, 3],
+            |page| async move {
+                if page == 2 {
+                    Err(crate::error::ApiError::ServerError("test error".to_string()).into())
+                } else {
+                    Ok(vec![format!("item-{}", page)])
+                }
+            },
+            10,
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_page_is_retried() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let c = calls.clone();
+
+        let result: Result<Vec<usize>> = fetch_pages_adaptive(
+            vec![1, 2, 3],
+            move |page| {
+                let c = c.clone();
+                async move {
+                    // Page 2 is rate limited on its first request only
+                    if page == 2 && c.fetch_add(1, Ordering::SeqCst) == 0 {
+                        Err(ApiError::RateLimited.into())
+                    } else {
+                        Ok(vec![page])
+                    }
+                }
+            },
+            4,
+            Duration::from_millis(1),
+        )
+        .await;
+
+        let mut items = result.unwrap();
+        items.sort_unstable();
+        assert_eq!(items, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_retries_are_bounded() {
+        let result: Result<Vec<usize>> = fetch_pages_adaptive(
+            vec![1],
+            |_page| async { Err(ApiError::RateLimited.into()) },
+            4,
+            Duration::from_millis(1),
+        )
+        .await;
+
+        assert!(matches!(result, Err(Error::Api(ApiError::RateLimited))));
+    }
+
+    #[test]
+    fn test_adaptive_limit_aimd() {
+        let mut limit = AdaptiveLimit::new(8, 0);
+        assert_eq!(limit.current(), 8);
+
+        limit.observe_hits(0);
+        assert_eq!(limit.current(), 8);
+        limit.observe_hits(3);
+        assert_eq!(limit.current(), 4);
+        limit.throttle();
+        limit.throttle();
+        limit.throttle();
+        assert_eq!(limit.current(), 1);
+
+        // One full window of successes grows the window by one
+        limit.success();
+        assert_eq!(limit.current(), 2);
+        limit.success();
+        limit.success();
+        assert_eq!(limit.current(), 3);
+
+        let mut capped = AdaptiveLimit::new(2, 0);
+        for _ in 0..10 {
+            capped.success();
+        }
+        assert_eq!(capped.current(), 2);
+    }
+
+    #[test]
+    fn test_retry_delay() {
+        let base = Duration::from_secs(2);
+        assert_eq!(retry_delay(base, 0), Duration::ZERO);
+        let first = retry_delay(base, 1);
+        assert!(first >= base && first < base * 2);
+        let second = retry_delay(base, 2);
+        assert!(second >= base * 2 && second < base * 3);
+    }
+}
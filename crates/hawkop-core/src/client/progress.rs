@@ -0,0 +1,316 @@
+//! Following a running scan as a stream of progress events.
+//!
+//! The StackHawk API has no streaming endpoint (SSE, WebSocket, or gRPC) for
+//! scan progress, so [`follow_scan`] polls the scan and turns the polls into
+//! a stream that only yields when something changed. Polling adapts: it
+//! starts fast, backs off while the scan is idle, and speeds up again as soon
+//! as progress moves, so changes show up within seconds without hammering
+//! the API through a long quiet crawl. Failed polls that are worth retrying
+//! (network errors, server errors, rate limits) are reported as
+//! [`ScanEvent::Reconnecting`] and retried with backoff instead of ending
+//! the stream.
+
+use std::future::Future;
+use std::time::Duration;
+
+use futures::stream::{self, Stream};
+use log::debug;
+
+use super::models::ScanResult;
+use crate::error::{ApiError, Error, Result};
+
+/// Scan statuses that mean the scan has not finished
+pub const RUNNING_STATUSES: &[&str] = &["STARTED", "RUNNING", "PENDING"];
+
+/// Whether `scan` is still running
+pub fn is_running(scan: &ScanResult) -> bool {
+    RUNNING_STATUSES.contains(&scan.scan.status.to_uppercase().as_str())
+}
+
+/// How [`follow_scan`] polls
+#[derive(Debug, Clone)]
+pub struct FollowOptions {
+    /// Poll at this fixed interval instead of adapting
+    pub interval: Option<Duration>,
+
+    /// Adaptive polling: the interval after a change
+    pub min_interval: Duration,
+
+    /// Adaptive polling: the longest interval while idle
+    pub max_interval: Duration,
+
+    /// Failed polls in a row before giving up
+    pub max_reconnects: u32,
+
+    /// First reconnect delay (doubles per attempt, up to `max_interval`)
+    pub reconnect_delay: Duration,
+}
+
+impl Default for FollowOptions {
+    fn default() -> Self {
+        Self {
+            interval: None,
+            min_interval: Duration::from_secs(2),
+            max_interval: Duration::from_secs(30),
+            max_reconnects: 10,
+            reconnect_delay: Duration::from_secs(2),
+        }
+    }
+}
+
+/// An event from [`follow_scan`]
+#[derive(Debug, Clone)]
+pub enum ScanEvent {
+    /// The scan's state: on the first poll, whenever its status, URL count,
+    /// or findings change, and finally once it stops running
+    Progress(Box<ScanResult>),
+
+    /// A poll failed; the next one is made after `retry_in`
+    Reconnecting {
+        /// Failed polls in a row, starting at 1
+        attempt: u32,
+        /// Why the poll failed
+        error: String,
+        /// Delay before the next poll
+        retry_in: Duration,
+    },
+}
+
+/// Follow a scan by calling `poll` until the scan stops running
+///
+/// The stream ends after the [`ScanEvent::Progress`] for a finished scan, or
+/// with an error when a poll fails in a way retrying won't fix (not found,
+/// unauthorized) or fails more than `max_reconnects` times in a row.
+pub fn follow_scan<F, Fut>(poll: F, options: FollowOptions) -> impl Stream<Item = Result<ScanEvent>>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<ScanResult>>,
+{
+    let state = Follow {
+        poll,
+        delay: None,
+        interval: options.interval.unwrap_or(options.min_interval),
+        options,
+        last: None,
+        failures: 0,
+        done: false,
+    };
+    stream::unfold(state, |mut state| async move {
+        let event = state.next().await?;
+        Some((event, state))
+    })
+}
+
+/// Polling state behind [`follow_scan`]
+struct Follow<F> {
+    poll: F,
+    options: FollowOptions,
+    /// Wait before the next poll (none before the first)
+    delay: Option<Duration>,
+    /// Current poll interval
+    interval: Duration,
+    /// Fingerprint of the last scan yielded
+    last: Option<String>,
+    /// Failed polls in a row
+    failures: u32,
+    done: bool,
+}
+
+impl<F, Fut> Follow<F>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<ScanResult>>,
+{
+    /// Poll until there is something to yield
+    async fn next(&mut self) -> Option<Result<ScanEvent>> {
+        loop {
+            if self.done {
+                return None;
+            }
+            if let Some(delay) = self.delay.take() {
+                tokio::time::sleep(delay).await;
+            }
+
+            match (self.poll)().await {
+                Ok(scan) => {
+                    self.failures = 0;
+                    let fingerprint = fingerprint(&scan);
+                    let changed = self.last.as_ref() != Some(&fingerprint);
+                    self.interval = next_interval(self.interval, changed, &self.options);
+                    self.delay = Some(self.interval);
+                    if !is_running(&scan) {
+                        self.done = true;
+                        return Some(Ok(ScanEvent::Progress(Box::new(scan))));
+                    }
+                    if changed {
+                        self.last = Some(fingerprint);
+                        return Some(Ok(ScanEvent::Progress(Box::new(scan))));
+                    }
+                    debug!("Scan unchanged; next poll in {:?}", self.interval);
+                }
+                Err(e) if is_transient(&e) && self.failures < self.options.max_reconnects => {
+                    self.failures += 1;
+                    let retry_in = self
+                        .options
+                        .reconnect_delay
+                        .saturating_mul(1 << (self.failures - 1).min(16))
+                        .min(self.options.max_interval);
+                    self.delay = Some(retry_in);
+                    return Some(Ok(ScanEvent::Reconnecting {
+                        attempt: self.failures,
+                        error: e.to_string(),
+                        retry_in,
+                    }));
+                }
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+/// The poll interval after a poll: back to the minimum when the scan
+/// changed, doubled (up to the maximum) when it did not
+fn next_interval(current: Duration, changed: bool, options: &FollowOptions) -> Duration {
+    if let Some(fixed) = options.interval {
+        return fixed;
+    }
+    if changed {
+        options.min_interval
+    } else {
+        current.saturating_mul(2).min(options.max_interval)
+    }
+}
+
+/// The parts of a scan that count as progress
+fn fingerprint(scan: &ScanResult) -> String {
+    format!(
+        "{}|{:?}|{}",
+        scan.scan.status,
+        scan.url_count,
+        serde_json::to_string(&scan.alert_stats).unwrap_or_default()
+    )
+}
+
+/// Errors a later poll may not hit
+fn is_transient(error: &Error) -> bool {
+    matches!(
+        error,
+        Error::Api(
+            ApiError::Network(_)
+                | ApiError::ServerError(_)
+                | ApiError::RateLimit(_)
+                | ApiError::RateLimited
+        )
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::fixtures::ScanResultBuilder;
+    use futures::StreamExt;
+    use std::collections::VecDeque;
+
+    fn scan(status: &str, urls: u32) -> ScanResult {
+        ScanResultBuilder::new("scan-1", "app-1")
+            .status(status)
+            .url_count(urls)
+            .build()
+    }
+
+    fn fast() -> FollowOptions {
+        FollowOptions {
+            min_interval: Duration::from_millis(1),
+            max_interval: Duration::from_millis(4),
+            reconnect_delay: Duration::from_millis(1),
+            ..Default::default()
+        }
+    }
+
+    /// Events from following a scan whose polls return `results` in order
+    async fn follow(
+        results: Vec<Result<ScanResult>>,
+        options: FollowOptions,
+    ) -> Vec<Result<ScanEvent>> {
+        let results = std::sync::Mutex::new(VecDeque::from(results));
+        let poll = || {
+            let next = results.lock().unwrap().pop_front();
+            async move { next.expect("polled after the scan finished") }
+        };
+        follow_scan(poll, options).collect().await
+    }
+
+    fn describe(events: &[Result<ScanEvent>]) -> Vec<String> {
+        events
+            .iter()
+            .map(|e| match e {
+                Ok(ScanEvent::Progress(s)) => {
+                    format!("{} {}", s.scan.status, s.url_count.unwrap_or(0))
+                }
+                Ok(ScanEvent::Reconnecting { attempt, .. }) => format!("reconnecting {}", attempt),
+                Err(e) => format!("error {}", e),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_follow_scan_yields_changes_until_finished() {
+        let events = follow(
+            vec![
+                Ok(scan("STARTED", 0)),
+                Ok(scan("STARTED", 0)),
+                Ok(scan("RUNNING", 10)),
+                Err(ApiError::Network("connection reset".to_string()).into()),
+                Ok(scan("RUNNING", 10)),
+                Ok(scan("COMPLETED", 10)),
+            ],
+            fast(),
+        )
+        .await;
+
+        assert_eq!(
+            describe(&events),
+            vec!["STARTED 0", "RUNNING 10", "reconnecting 1", "COMPLETED 10"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_follow_scan_stops_on_permanent_errors() {
+        let events = follow(
+            vec![Err(ApiError::NotFound("scan-1".to_string()).into())],
+            fast(),
+        )
+        .await;
+        assert_eq!(describe(&events), vec!["error Resource not found: scan-1"]);
+
+        let options = FollowOptions {
+            max_reconnects: 1,
+            ..fast()
+        };
+        let network = || Err(ApiError::Network("down".to_string()).into());
+        let events = follow(vec![network(), network()], options).await;
+        assert_eq!(
+            describe(&events),
+            vec!["reconnecting 1", "error Network error: down"]
+        );
+    }
+
+    #[test]
+    fn test_next_interval() {
+        let options = FollowOptions::default();
+        let secs = Duration::from_secs;
+
+        assert_eq!(next_interval(secs(2), false, &options), secs(4));
+        assert_eq!(next_interval(secs(16), false, &options), secs(30));
+        assert_eq!(next_interval(secs(30), true, &options), secs(2));
+
+        let fixed = FollowOptions {
+            interval: Some(secs(10)),
+            ..options
+        };
+        assert_eq!(next_interval(secs(10), true, &fixed), secs(10));
+    }
+}
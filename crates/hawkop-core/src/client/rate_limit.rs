@@ -1,18 +1,80 @@
 //! Per-endpoint rate limiting for StackHawk API
 //!
 //! Implements reactive rate limiting that only activates after receiving a 429.
-//! Different endpoint patterns have different rate limits.
+//! Different endpoint patterns have different rate limits; orgs with raised
+//! API limits can override them per category (`preferences.rate_limits`).
 
 use std::collections::HashMap;
 use std::num::NonZeroU32;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::path::Path;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU32, AtomicUsize, Ordering};
 
+use chrono::{DateTime, Utc};
 use governor::clock::DefaultClock;
+use governor::middleware::StateInformationMiddleware;
 use governor::state::{InMemoryState, NotKeyed};
 use governor::{Quota, RateLimiter};
 use log::debug;
+use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
 
+/// 429 responses received by any client in this process
+static RATE_LIMIT_HITS: AtomicUsize = AtomicUsize::new(0);
+
+/// Number of 429 responses received so far.
+///
+/// Parallel fetchers compare successive readings to back off concurrency even
+/// when the client's own retries eventually succeed.
+pub fn rate_limit_hits() -> usize {
+    RATE_LIMIT_HITS.load(Ordering::Relaxed)
+}
+
+/// Most recent 429 received by any client in this process
+static LAST_RATE_LIMIT: Mutex<Option<LastRateLimit>> = Mutex::new(None);
+
+/// A 429 response, as recorded for `hawkop status --verbose`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LastRateLimit {
+    /// When the 429 was received
+    pub at: DateTime<Utc>,
+    /// Endpoint category name (see [`EndpointCategory::name`])
+    pub category: String,
+    /// Quota in effect for the category (requests per minute)
+    pub quota_per_minute: u32,
+    /// 429s received by the command that recorded this
+    pub hits: usize,
+}
+
+impl LastRateLimit {
+    /// Load the record saved by an earlier command (None if absent or unreadable)
+    pub fn load(path: &Path) -> Option<Self> {
+        let content = std::fs::read_to_string(path).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// Save this record, replacing any earlier one
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        std::fs::write(path, json)
+    }
+}
+
+/// The most recent 429 received in this process, if any
+pub fn last_rate_limit() -> Option<LastRateLimit> {
+    LAST_RATE_LIMIT
+        .lock()
+        .ok()
+        .and_then(|last| last.clone())
+        .map(|last| LastRateLimit {
+            hits: rate_limit_hits(),
+            ..last
+        })
+}
+
 /// Categories of API endpoints with their rate limits.
 ///
 /// Rate limits are based on StackHawk API documentation:
@@ -85,6 +147,28 @@ impl EndpointCategory {
         EndpointCategory::Default
     }
 
+    /// Name used in `preferences.rate_limits` and status output
+    pub fn name(&self) -> &'static str {
+        match self {
+            EndpointCategory::Scan => "scan",
+            EndpointCategory::User => "user",
+            EndpointCategory::AppList => "app-list",
+            EndpointCategory::AppOrg => "app-org",
+            EndpointCategory::OrgInvite => "org-invite",
+            EndpointCategory::Default => "default",
+        }
+    }
+
+    /// Look up a category by its [`name`](Self::name)
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.name() == name)
+    }
+
+    /// Default quota for this category in requests per minute
+    pub fn default_per_minute(&self) -> u32 {
+        (self.rate_limit() * 60.0).round() as u32
+    }
+
     /// Get the rate limit for this category (requests per second).
     pub fn rate_limit(&self) -> f64 {
         match self {
@@ -100,37 +184,67 @@ impl EndpointCategory {
 
 /// Rate limiter state for a single endpoint category.
 pub struct EndpointRateLimiter {
-    limiter: RateLimiter<NotKeyed, InMemoryState, DefaultClock>,
+    limiter: RateLimiter<NotKeyed, InMemoryState, DefaultClock, StateInformationMiddleware>,
     active: AtomicBool,
     category: EndpointCategory,
+    per_minute: u32,
+    /// Burst capacity left after the last permitted request
+    remaining: AtomicU32,
+    /// Last 429 for this category (Unix epoch milliseconds, 0 = never)
+    last_429_ms: AtomicI64,
+}
+
+/// Point-in-time view of one category's limiter (for debug logs and status)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    pub category: EndpointCategory,
+    /// Configured quota in requests per minute
+    pub per_minute: u32,
+    /// Whether a 429 has switched this limiter on
+    pub active: bool,
+    /// Requests that can go out before the limiter makes callers wait
+    pub remaining: u32,
+    pub last_429: Option<DateTime<Utc>>,
 }
 
 impl EndpointRateLimiter {
     /// Create a new rate limiter for an endpoint category.
     pub fn new(category: EndpointCategory) -> Self {
-        let rate = category.rate_limit();
+        Self::with_quota(category, category.default_per_minute())
+    }
 
-        // Handle sub-1 rates by using per-minute quotas
-        let quota = if rate >= 1.0 {
-            Quota::per_second(NonZeroU32::new(rate as u32).unwrap_or(NonZeroU32::MIN))
+    /// Create a rate limiter allowing `per_minute` requests per minute.
+    ///
+    /// Whole per-second rates keep a one-second burst; other rates (such as
+    /// the 10/min invite limit) use a per-minute quota.
+    pub fn with_quota(category: EndpointCategory, per_minute: u32) -> Self {
+        let per_minute = per_minute.max(1);
+        let quota = if per_minute >= 60 && per_minute.is_multiple_of(60) {
+            Quota::per_second(NonZeroU32::new(per_minute / 60).unwrap_or(NonZeroU32::MIN))
         } else {
-            // For 0.167 req/sec = 10/min, use per_minute
-            let per_min = (rate * 60.0).round() as u32;
-            Quota::per_minute(NonZeroU32::new(per_min).unwrap_or(NonZeroU32::MIN))
+            Quota::per_minute(NonZeroU32::new(per_minute).unwrap_or(NonZeroU32::MIN))
         };
 
         Self {
-            limiter: RateLimiter::direct(quota),
+            limiter: RateLimiter::direct(quota).with_middleware::<StateInformationMiddleware>(),
             active: AtomicBool::new(false),
             category,
+            per_minute,
+            remaining: AtomicU32::new(quota.burst_size().get()),
+            last_429_ms: AtomicI64::new(0),
         }
     }
 
     /// Activate rate limiting for this category.
     pub fn activate(&self) {
+        self.last_429_ms
+            .store(Utc::now().timestamp_millis(), Ordering::SeqCst);
         let was_active = self.active.swap(true, Ordering::SeqCst);
         if !was_active {
-            debug!("Rate limiting activated for {:?}", self.category);
+            debug!(
+                "Rate limiting activated for {:?} ({}/min)",
+                self.category, self.per_minute
+            );
         }
     }
 
@@ -143,7 +257,29 @@ impl EndpointRateLimiter {
     pub async fn wait_if_active(&self) {
         if self.is_active() {
             debug!("Waiting for rate limiter {:?}", self.category);
-            self.limiter.until_ready().await;
+            let snapshot = self.limiter.until_ready().await;
+            let remaining = snapshot.remaining_burst_capacity();
+            self.remaining.store(remaining, Ordering::SeqCst);
+            debug!(
+                "Rate limiter {:?}: {} of {} requests left in burst",
+                self.category,
+                remaining,
+                snapshot.quota().burst_size()
+            );
+        }
+    }
+
+    /// Current state of this limiter
+    pub fn status(&self) -> RateLimitStatus {
+        let last_429_ms = self.last_429_ms.load(Ordering::SeqCst);
+        RateLimitStatus {
+            category: self.category,
+            per_minute: self.per_minute,
+            active: self.is_active(),
+            remaining: self.remaining.load(Ordering::SeqCst),
+            last_429: (last_429_ms > 0)
+                .then(|| DateTime::<Utc>::from_timestamp_millis(last_429_ms))
+                .flatten(),
         }
     }
 }
@@ -162,11 +298,21 @@ impl Default for RateLimiterSet {
 impl RateLimiterSet {
     /// Create a new set of rate limiters for all endpoint categories.
     pub fn new() -> Self {
+        Self::with_quotas(&HashMap::new())
+    }
+
+    /// Create rate limiters, overriding the default quota (requests per
+    /// minute) for the given categories.
+    pub fn with_quotas(quotas: &HashMap<EndpointCategory, u32>) -> Self {
         let mut map = HashMap::new();
 
         // Pre-create limiters for all categories
         for category in EndpointCategory::ALL {
-            map.insert(category, EndpointRateLimiter::new(category));
+            let limiter = match quotas.get(&category) {
+                Some(&per_minute) => EndpointRateLimiter::with_quota(category, per_minute),
+                None => EndpointRateLimiter::new(category),
+            };
+            map.insert(category, limiter);
         }
 
         Self {
@@ -184,9 +330,23 @@ impl RateLimiterSet {
 
     /// Activate rate limiting for a category (called on 429).
     pub async fn activate(&self, category: EndpointCategory) {
+        let hits = RATE_LIMIT_HITS.fetch_add(1, Ordering::Relaxed) + 1;
         let limiters = self.limiters.read().await;
         if let Some(limiter) = limiters.get(&category) {
             limiter.activate();
+            let status = limiter.status();
+            debug!(
+                "429 #{} on {:?} (quota {}/min, {} left in burst)",
+                hits, category, status.per_minute, status.remaining
+            );
+            if let Ok(mut last) = LAST_RATE_LIMIT.lock() {
+                *last = Some(LastRateLimit {
+                    at: Utc::now(),
+                    category: category.name().to_string(),
+                    quota_per_minute: status.per_minute,
+                    hits,
+                });
+            }
         }
     }
 }
@@ -313,6 +473,55 @@ mod tests {
         assert!(limiter.is_active());
     }
 
+    #[test]
+    fn test_category_names_round_trip() {
+        for category in EndpointCategory::ALL {
+            assert_eq!(EndpointCategory::from_name(category.name()), Some(category));
+        }
+        assert_eq!(EndpointCategory::from_name("nope"), None);
+        assert_eq!(EndpointCategory::Scan.default_per_minute(), 4800);
+        assert_eq!(EndpointCategory::OrgInvite.default_per_minute(), 10);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_set_quotas_and_status() {
+        let quotas = HashMap::from([(EndpointCategory::Default, 720)]);
+        let set = RateLimiterSet::with_quotas(&quotas);
+
+        set.activate(EndpointCategory::Default).await;
+        set.wait_for(EndpointCategory::Default).await;
+
+        let limiters = set.limiters.read().await;
+        let default = limiters[&EndpointCategory::Default].status();
+        assert_eq!(default.per_minute, 720);
+        assert!(default.active);
+        assert_eq!(default.remaining, 11);
+        assert!(default.last_429.is_some());
+
+        let scan = limiters[&EndpointCategory::Scan].status();
+        assert_eq!(scan.per_minute, 4800);
+        assert!(!scan.active && scan.last_429.is_none());
+
+        // Process-wide, so other tests may have recorded a later 429
+        assert!(last_rate_limit().is_some());
+    }
+
+    #[test]
+    fn test_last_rate_limit_save_load() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("state").join("rate_limit.json");
+        assert!(LastRateLimit::load(&path).is_none());
+
+        let record = LastRateLimit {
+            at: Utc::now(),
+            category: "scan".to_string(),
+            quota_per_minute: 4800,
+            hits: 2,
+        };
+        record.save(&path).unwrap();
+        assert_eq!(LastRateLimit::load(&path), Some(record));
+    }
+
     #[tokio::test]
     async fn test_rate_limiter_set_creation() {
         let set = RateLimiterSet::new();
@@ -0,0 +1,128 @@
+//! Response schema drift detection (`--strict-deserialize`)
+//!
+//! Response bodies are parsed through [`from_str`]. When the [`TARGET`] log
+//! target is enabled, parsing also records every JSON field the model type
+//! doesn't declare (serde drops them silently otherwise) and logs each one
+//! once per process, so changes in the StackHawk API show up before they
+//! turn into missing data.
+
+use std::collections::HashSet;
+use std::sync::{Mutex, OnceLock};
+
+use log::{Level, log_enabled, warn};
+use serde::de::DeserializeOwned;
+
+/// Log target for unmodeled response fields
+pub const TARGET: &str = "hawkop::schema";
+
+/// Fields already reported, by model type and field path
+static REPORTED: OnceLock<Mutex<HashSet<(String, String)>>> = OnceLock::new();
+
+/// Parse a JSON response body, logging fields `T` doesn't model
+///
+/// `url` only labels the log line. Without the [`TARGET`] log target this is
+/// plain `serde_json::from_str`.
+pub fn from_str<T: DeserializeOwned>(url: &str, body: &str) -> serde_json::Result<T> {
+    if !log_enabled!(target: TARGET, Level::Warn) {
+        return serde_json::from_str(body);
+    }
+
+    let mut ignored = Vec::new();
+    let mut deserializer = serde_json::Deserializer::from_str(body);
+    let value: T = serde_ignored::deserialize(&mut deserializer, |path| {
+        ignored.push(path.to_string());
+    })?;
+    deserializer.end()?;
+
+    let model = model_name::<T>();
+    for field in unreported(model, ignored) {
+        warn!(
+            target: TARGET,
+            "Unmodeled response field `{}` in {} (parsed as {})", field, url, model
+        );
+    }
+    Ok(value)
+}
+
+/// Model type name without its module path (`Vec<a::b::Scan>` -> `Scan`)
+fn model_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::")
+        .next()
+        .unwrap_or(name)
+        .trim_end_matches('>')
+}
+
+/// Normalized fields not reported before for `model`, in order (marks them
+/// reported)
+fn unreported(model: &str, fields: Vec<String>) -> Vec<String> {
+    let mut reported = REPORTED
+        .get_or_init(|| Mutex::new(HashSet::new()))
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    fields
+        .into_iter()
+        .map(|field| normalize(&field))
+        .filter(|field| reported.insert((model.to_string(), field.clone())))
+        .collect()
+}
+
+/// Collapse sequence indexes so `items.3.name` and `items.7.name` count once
+fn normalize(field: &str) -> String {
+    field
+        .split('.')
+        .map(|part| {
+            if part.chars().all(|c| c.is_ascii_digit()) {
+                "[]"
+            } else {
+                part
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Deserialize)]
+    #[allow(dead_code)]
+    struct Item {
+        name: String,
+    }
+
+    #[test]
+    fn test_ignored_fields_are_collected() {
+        let mut ignored = Vec::new();
+        let mut de = serde_json::Deserializer::from_str(
+            r#"{"items": [{"name": "a", "color": "red"}, {"name": "b", "color": "blue"}], "total": 2}"#,
+        );
+        #[derive(Deserialize)]
+        #[allow(dead_code)]
+        struct Page {
+            items: Vec<Item>,
+        }
+        let _: Page = serde_ignored::deserialize(&mut de, |p| ignored.push(p.to_string())).unwrap();
+
+        assert_eq!(ignored, vec!["items.0.color", "items.1.color", "total"]);
+        let fresh = unreported("Page", ignored);
+        assert_eq!(fresh, vec!["items.[].color", "total"]);
+        assert!(unreported("Page", vec!["items.4.color".to_string()]).is_empty());
+    }
+
+    #[test]
+    fn test_model_name() {
+        assert_eq!(model_name::<Item>(), "Item");
+        assert_eq!(model_name::<Vec<Item>>(), "Item");
+    }
+
+    #[test]
+    fn test_from_str_without_logging_is_plain_parse() {
+        let item: Item = from_str("/x", r#"{"name": "a", "extra": 1}"#).unwrap();
+        assert_eq!(item.name, "a");
+        assert!(from_str::<Item>("/x", "{").is_err());
+    }
+}
@@ -1,10 +1,12 @@
 //! StackHawk API client implementation
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-/// Maximum number of retries for rate-limited requests
-const MAX_RATE_LIMIT_RETRIES: u32 = 3;
+/// Default retries for rate-limited (429) and transient GET failures
+/// (`preferences.retries` overrides)
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
 
 use async_trait::async_trait;
 use chrono::Utc;
@@ -17,22 +19,27 @@ use serde::de::{self, Deserializer};
 
 use super::api::{
     AppApi, AuthApi, ConfigApi, EnvironmentApi, ListingApi, OASApi, PerchApi, RepoApi,
-    ScanDetailApi, TeamApi,
+    ScanDetailApi, TeamApi, TriageApi,
 };
 use super::models::{
     AlertMsgResponse, AlertResponse, Application, ApplicationAlert, AuditFilterParams, AuditRecord,
-    ConfigType, CreateApplicationRequest, CreateTeamRequest, CurrentFindingsResponse, Environment,
+    BulkTriageRequest, BulkTriageResponse, CLOCK_SKEW_WARN_SECS, ConfigType,
+    CreateApplicationRequest, CreateTeamRequest, CurrentFindingsResponse, Environment,
     EnvironmentConfigResponse, GetApplicationMappedOASResponse, GetHostedAssetResponse,
     GetPerchDeviceResponse, JwtToken, ListEnvironmentsResponse, NewEnvironmentRequest, OASAsset,
-    OrgPolicy, Organization, PerchCommand, PerchCommandRequest, PerchCommandResponse, PerchDevice,
-    RenameConfigurationRequest, ReplaceRepoAppMappingsRequest, ReplaceRepoAppMappingsResponse,
-    Repository, ScanAlertsResponse, ScanConfig, ScanResult, Secret, StackHawkPolicy, Team,
-    TeamDetail, UpdateApplicationTeamRequest, UpdateTeamRequest, UpsertScanConfigurationRequest,
-    User, ValidatedAssetResponse,
+    OrgFeature, OrgPolicy, Organization, PerchCommand, PerchCommandRequest, PerchCommandResponse,
+    PerchDevice, RenameConfigurationRequest, ReplaceRepoAppMappingsRequest,
+    ReplaceRepoAppMappingsResponse, Repository, ScanAlertsResponse, ScanConfig, ScanResult, Secret,
+    StackHawkPolicy, Team, TeamDetail, UpdateApplicationTeamRequest, UpdateTeamRequest,
+    UpsertScanConfigurationRequest, User, ValidatedAssetResponse, clock_skew_from_date_header,
+    describe_clock_skew, token_needs_refresh,
 };
-use super::pagination::{PagedResponse, PaginationParams};
+use super::pagination::{PagedResponse, PaginationParams, default_page_size};
+use super::parallel::{fetch_pages_in_order, max_concurrency};
 use super::rate_limit::{EndpointCategory, RateLimiterSet};
-use crate::error::{ApiError, Result};
+use super::{schema, trace};
+use crate::error::{ApiError, DryRunRequest, Result};
+use crate::warnings::{WarningKind, warn};
 
 /// Deserialize a string to usize.
 ///
@@ -86,6 +93,13 @@ fn base64_decode_url(input: &str) -> std::result::Result<Vec<u8>, String> {
 /// Default StackHawk API host
 const DEFAULT_API_HOST: &str = "https://api.stackhawk.com";
 
+/// Resolve the API host: explicit value > `HAWKOP_API_HOST` > default
+pub fn resolve_api_host(api_host: Option<String>) -> String {
+    api_host
+        .or_else(|| std::env::var("HAWKOP_API_HOST").ok())
+        .unwrap_or_else(|| DEFAULT_API_HOST.to_string())
+}
+
 /// Compute v1 and v2 base URLs from an API host.
 ///
 /// The host should be in the format "https://api.example.com" (no trailing slash).
@@ -95,6 +109,18 @@ fn compute_base_urls(host: &str) -> (String, String) {
     (format!("{}/api/v1", host), format!("{}/api/v2", host))
 }
 
+/// Treat an empty success body (e.g. 204) as JSON `null`
+///
+/// Lets `()`, `Option<T>`, and `serde_json::Value` responses parse it;
+/// other types still fail with a parse error as before.
+fn empty_body_as_null(body: String) -> String {
+    if body.trim().is_empty() {
+        "null".to_string()
+    } else {
+        body
+    }
+}
+
 /// Generate a helpful error message for bad requests.
 ///
 /// When the API returns an empty error body, we try to provide contextual
@@ -123,6 +149,69 @@ fn bad_request_message(path: &str, error_body: String) -> String {
     "Invalid request (no details provided by API)".to_string()
 }
 
+/// Server errors worth retrying: the request may succeed on another attempt
+fn is_transient_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::INTERNAL_SERVER_ERROR
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+/// Connection failures, resets, and timeouts (not malformed requests)
+fn is_transient_error(err: &reqwest::Error) -> bool {
+    err.is_connect() || err.is_timeout() || (err.is_request() && !err.is_builder())
+}
+
+/// `Retry-After` header in seconds, if present and numeric
+fn retry_after_secs(headers: &reqwest::header::HeaderMap) -> Option<u64> {
+    headers
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok())
+}
+
+/// Longest `Retry-After` honored, so a misbehaving server can't stall a command
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// Wait before retry `attempt` (0-based)
+///
+/// A `Retry-After` is honored as given (up to [`MAX_RETRY_AFTER`]); without
+/// one, the wait is 1s doubled per attempt plus 0-1000ms of jitter.
+fn retry_wait(retry_after: Option<u64>, attempt: u32) -> Duration {
+    if let Some(secs) = retry_after {
+        return Duration::from_secs(secs).min(MAX_RETRY_AFTER);
+    }
+    let backoff_secs = 1u64 << attempt.min(16);
+
+    // Jitter using nanosecond timestamp (avoids rand dependency)
+    let jitter_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos() % 1000)
+        .unwrap_or(0) as u64;
+
+    Duration::from_secs(backoff_secs) + Duration::from_millis(jitter_ms)
+}
+
+/// Total item count of a listing, or what has been fetched when the API
+/// reports neither a total nor a next page
+fn known_total(
+    total_count: Option<i64>,
+    next_page_token: Option<&str>,
+    fetched: usize,
+) -> Option<usize> {
+    match total_count {
+        Some(total) => Some(usize::try_from(total).unwrap_or(0).max(fetched)),
+        None => next_page_token.is_none_or(str::is_empty).then_some(fetched),
+    }
+}
+
+/// Callback invoked with the new token whenever the client re-authenticates
+/// mid-command (expiry or a 401), so callers can persist it
+pub type TokenRefreshHook = Arc<dyn Fn(&JwtToken) + Send + Sync>;
+
 /// StackHawk API client
 pub struct StackHawkClient {
     http: HttpClient,
@@ -131,6 +220,13 @@ pub struct StackHawkClient {
     /// Per-endpoint rate limiters (only active after 429 for each category)
     rate_limiters: Arc<RateLimiterSet>,
     auth_state: Arc<RwLock<AuthState>>,
+    on_token_refresh: Option<TokenRefreshHook>,
+    /// Retries for 429s (any method) and 5xx/connection failures (GET only)
+    max_retries: u32,
+    /// Refuse every request (`--offline`); only cached data is available
+    offline: bool,
+    /// Withhold mutating requests (`--dry-run`); reads still go out
+    dry_run: bool,
 }
 
 /// Internal authentication state
@@ -139,12 +235,14 @@ struct AuthState {
     api_key: Option<String>,
     jwt: Option<String>,
     jwt_expires_at: Option<chrono::DateTime<Utc>>,
+    /// Server clock minus local clock (seconds), measured at login
+    jwt_clock_skew_secs: i64,
 }
 
 impl StackHawkClient {
     /// Create a new StackHawk API client with the default API host.
     ///
-    /// Uses the default production StackHawk API (https://api.stackhawk.com).
+    /// Uses the default production StackHawk API (`https://api.stackhawk.com`).
     /// For custom API hosts (e.g., development environments), use `with_host()`.
     pub fn new(api_key: Option<String>) -> Result<Self> {
         Self::with_host(api_key, None)
@@ -152,7 +250,7 @@ impl StackHawkClient {
 
     /// Create a new StackHawk API client with a custom API host.
     ///
-    /// The `api_host` should be in the format "https://api.example.com" (no trailing slash
+    /// The `api_host` should be in the format `https://api.example.com` (no trailing slash
     /// or path). If `None`, falls back to the `HAWKOP_API_HOST` environment variable,
     /// then to the default production API host.
     ///
@@ -162,6 +260,8 @@ impl StackHawkClient {
     ///
     /// # Examples
     /// ```
+    /// # use hawkop_core::StackHawkClient;
+    /// # fn main() -> hawkop_core::Result<()> {
     /// // Use default production API
     /// let client = StackHawkClient::new(Some("my-api-key".to_string()))?;
     ///
@@ -170,6 +270,8 @@ impl StackHawkClient {
     ///     Some("my-api-key".to_string()),
     ///     Some("http://localhost:8080".to_string())
     /// )?;
+    /// # Ok(())
+    /// # }
     /// ```
     pub fn with_host(api_key: Option<String>, api_host: Option<String>) -> Result<Self> {
         let http = HttpClient::builder()
@@ -177,10 +279,7 @@ impl StackHawkClient {
             .build()
             .map_err(|e| ApiError::Network(e.to_string()))?;
 
-        // Resolve API host: explicit param > env var > default
-        let host = api_host
-            .or_else(|| std::env::var("HAWKOP_API_HOST").ok())
-            .unwrap_or_else(|| DEFAULT_API_HOST.to_string());
+        let host = resolve_api_host(api_host);
 
         let (base_url_v1, base_url_v2) = compute_base_urls(&host);
 
@@ -193,8 +292,75 @@ impl StackHawkClient {
                 api_key,
                 jwt: None,
                 jwt_expires_at: None,
+                jwt_clock_skew_secs: 0,
             })),
+            on_token_refresh: None,
+            max_retries: DEFAULT_MAX_RETRIES,
+            offline: false,
+            dry_run: false,
+        })
+    }
+
+    /// Register a callback for tokens obtained by automatic refresh
+    pub fn with_token_refresh_hook(mut self, hook: TokenRefreshHook) -> Self {
+        self.on_token_refresh = Some(hook);
+        self
+    }
+
+    /// Set how many times a request is retried after a 429 or transient failure
+    pub fn with_max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// Override rate limit quotas (requests per minute) per endpoint category
+    pub fn with_rate_limits(mut self, quotas: &HashMap<EndpointCategory, u32>) -> Self {
+        self.rate_limiters = Arc::new(RateLimiterSet::with_quotas(quotas));
+        self
+    }
+
+    /// Refuse all network requests (`--offline`)
+    pub fn with_offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Withhold mutating requests instead of sending them (`--dry-run`)
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Fail fast in offline mode, before touching the network
+    fn ensure_online(&self, path: &str) -> Result<()> {
+        if self.offline {
+            return Err(ApiError::Offline(path.to_string()).into());
+        }
+        Ok(())
+    }
+
+    /// In dry-run mode, stop a mutating request before it is sent and report
+    /// what it would have been
+    fn ensure_not_dry_run(
+        &self,
+        method: &reqwest::Method,
+        base_url: &str,
+        path: &str,
+        payload: Option<serde_json::Value>,
+    ) -> Result<()> {
+        if !self.dry_run || *method == reqwest::Method::GET {
+            return Ok(());
+        }
+        let url = format!("{}{}", base_url, path);
+        let endpoint = reqwest::Url::parse(&url)
+            .map(|u| u.path().to_string())
+            .unwrap_or(url);
+        Err(ApiError::DryRun(DryRunRequest {
+            method: method.to_string(),
+            endpoint,
+            payload,
         })
+        .into())
     }
 
     /// Get the base URL v1 (useful for debugging/display)
@@ -203,39 +369,92 @@ impl StackHawkClient {
         &self.base_url_v1
     }
 
+    /// Make an authenticated request to any API path (`hawkop api`)
+    ///
+    /// `path` may carry the version prefix (`/api/v2/...`) and a query
+    /// string; without a prefix it is taken as a v1 path. Goes through the
+    /// same JWT refresh, rate limiting, retries, and `--offline`/`--dry-run`
+    /// checks as the typed endpoints. An empty response body is `null`.
+    pub async fn raw_request(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<&serde_json::Value>,
+    ) -> Result<serde_json::Value> {
+        let (base_url, path) = self.resolve_raw_path(path);
+        match body {
+            Some(body) => self.request_with_body(method, base_url, &path, body).await,
+            None => self.request_inner(method, base_url, &path).await,
+        }
+    }
+
+    /// Full URL a `raw_request` path resolves to
+    pub fn raw_url(&self, path: &str) -> String {
+        let (base_url, path) = self.resolve_raw_path(path);
+        format!("{}{}", base_url, path)
+    }
+
+    /// Base URL and remaining path for a `raw_request` path
+    fn resolve_raw_path<'a>(&'a self, path: &str) -> (&'a str, String) {
+        let path = path.trim();
+        let path = if path.starts_with('/') {
+            path.to_string()
+        } else {
+            format!("/{}", path)
+        };
+        for (prefix, base_url) in [
+            ("/api/v2", &self.base_url_v2),
+            ("/api/v1", &self.base_url_v1),
+        ] {
+            if let Some(rest) = path.strip_prefix(prefix)
+                && (rest.is_empty() || rest.starts_with(['/', '?']))
+            {
+                return (base_url, rest.to_string());
+            }
+        }
+        (&self.base_url_v1, path)
+    }
+
     /// Set the JWT token and expiry
     pub async fn set_jwt(&self, token: JwtToken) {
         let mut state = self.auth_state.write().await;
         state.jwt = Some(token.token);
         state.jwt_expires_at = Some(token.expires_at);
+        state.jwt_clock_skew_secs = token.clock_skew_secs;
     }
 
-    /// Check if JWT is expired or will expire soon (within 5 minutes)
+    /// Check if JWT is expired or will expire soon (within 5 minutes of server time)
     async fn is_jwt_expired(&self) -> bool {
         let state = self.auth_state.read().await;
         match state.jwt_expires_at {
             None => true,
             Some(expires_at) => {
-                let now = Utc::now();
-                let buffer = chrono::Duration::minutes(5);
-                expires_at - buffer < now
+                token_needs_refresh(expires_at, state.jwt_clock_skew_secs, Utc::now())
             }
         }
     }
 
+    /// Re-authenticate with the API key and replace the current JWT.
+    ///
+    /// Notifies the refresh hook (if any) so the new token can be persisted.
+    async fn refresh_jwt(&self) -> Result<()> {
+        let api_key = {
+            let state = self.auth_state.read().await;
+            state.api_key.clone().ok_or(ApiError::Unauthorized)?
+        };
+
+        let jwt_token = self.authenticate(&api_key).await?;
+        if let Some(hook) = &self.on_token_refresh {
+            hook(&jwt_token);
+        }
+        self.set_jwt(jwt_token).await;
+        Ok(())
+    }
+
     /// Get the current JWT token, refreshing if necessary
     async fn get_valid_jwt(&self) -> Result<String> {
-        // Check if we need to refresh
         if self.is_jwt_expired().await {
-            // Get API key
-            let api_key = {
-                let state = self.auth_state.read().await;
-                state.api_key.clone().ok_or(ApiError::Unauthorized)?
-            };
-
-            // Refresh JWT
-            let jwt_token = self.authenticate(&api_key).await?;
-            self.set_jwt(jwt_token).await;
+            self.refresh_jwt().await?;
         }
 
         // Return current JWT
@@ -249,6 +468,10 @@ impl StackHawkClient {
     /// the user's role from the /api/v1/user endpoint but returns None on any
     /// failure rather than propagating errors.
     async fn get_current_user_role(&self) -> Option<String> {
+        if self.offline {
+            return None;
+        }
+
         // Response structure for /api/v1/user endpoint
         #[derive(Deserialize)]
         struct UserResponse {
@@ -268,12 +491,12 @@ impl StackHawkClient {
         };
 
         let url = format!("{}/user", self.base_url_v1);
-        let response = match self
-            .http
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", jwt))
-            .send()
-            .await
+        let response = match trace::send(
+            self.http
+                .get(&url)
+                .header("Authorization", format!("Bearer {}", jwt)),
+        )
+        .await
         {
             Ok(resp) if resp.status().is_success() => resp,
             _ => return None,
@@ -315,6 +538,59 @@ impl StackHawkClient {
         self.request_with_query(method, base_url, path, &[]).await
     }
 
+    /// One page of a scan's alerts (`GET /scan/{scanId}/alerts`)
+    async fn scan_alerts_page(
+        &self,
+        scan_id: &str,
+        params: &PaginationParams,
+    ) -> Result<PagedResponse<ApplicationAlert>> {
+        let path = format!("/scan/{}/alerts", scan_id);
+        let response: ScanAlertsResponse = self
+            .request_with_query(
+                reqwest::Method::GET,
+                &self.base_url_v1,
+                &path,
+                &params.to_query_params(),
+            )
+            .await?;
+
+        // API returns array with single element containing the alerts list
+        let alerts = response
+            .application_scan_results
+            .into_iter()
+            .next()
+            .map(|r| r.application_alerts)
+            .unwrap_or_default();
+        let total = known_total(
+            response.total_count,
+            response.next_page_token.as_deref(),
+            alerts.len(),
+        );
+        Ok(PagedResponse::new(
+            alerts,
+            total,
+            params.page_size.unwrap_or_else(default_page_size),
+            params.page.unwrap_or(0),
+        ))
+    }
+
+    /// One page of an alert's paths (`GET /scan/{scanId}/alert/{pluginId}`)
+    async fn alert_paths_page(
+        &self,
+        scan_id: &str,
+        plugin_id: &str,
+        params: &PaginationParams,
+    ) -> Result<AlertResponse> {
+        let path = format!("/scan/{}/alert/{}", scan_id, plugin_id);
+        self.request_with_query(
+            reqwest::Method::GET,
+            &self.base_url_v1,
+            &path,
+            &params.to_query_params(),
+        )
+        .await
+    }
+
     /// Internal request implementation with query parameters
     async fn request_with_query<T: for<'de> Deserialize<'de>>(
         &self,
@@ -357,6 +633,9 @@ impl StackHawkClient {
         T: for<'de> Deserialize<'de>,
         B: serde::Serialize + Send + Sync,
     {
+        self.ensure_online(path)?;
+        self.ensure_not_dry_run(&method, base_url, path, serde_json::to_value(body).ok())?;
+
         // Categorize this endpoint for rate limiting
         let category = EndpointCategory::from_request(path, &method);
 
@@ -375,15 +654,15 @@ impl StackHawkClient {
             debug!("Request body: {}", body_json);
         }
 
-        let response = self
-            .http
-            .request(method.clone(), &url)
-            .header("Authorization", format!("Bearer {}", jwt))
-            .header("Content-Type", "application/json")
-            .json(body)
-            .send()
-            .await
-            .map_err(ApiError::from)?;
+        let response = trace::send(
+            self.http
+                .request(method.clone(), &url)
+                .header("Authorization", format!("Bearer {}", jwt))
+                .header("Content-Type", "application/json")
+                .json(body),
+        )
+        .await
+        .map_err(ApiError::from)?;
 
         // Handle response status
         let status = response.status();
@@ -394,12 +673,14 @@ impl StackHawkClient {
         );
 
         match status {
-            StatusCode::OK | StatusCode::CREATED => {
+            StatusCode::OK | StatusCode::CREATED | StatusCode::NO_CONTENT => {
                 let body_text = response.text().await.map_err(|e| {
                     ApiError::InvalidResponse(format!("Failed to read response body: {}", e))
                 })?;
+                trace::response_body(&url, &body_text);
+                let body_text = empty_body_as_null(body_text);
 
-                let data: T = serde_json::from_str(&body_text).map_err(|e| {
+                let data: T = schema::from_str(&url, &body_text).map_err(|e| {
                     let preview = if body_text.len() > 500 {
                         format!("{}...", &body_text[..500])
                     } else {
@@ -430,21 +711,12 @@ impl StackHawkClient {
                 }
 
                 debug!("Received 401, attempting token refresh");
-                let api_key = {
-                    let state = self.auth_state.read().await;
-                    state.api_key.clone()
-                };
-
-                if let Some(api_key) = api_key {
-                    let jwt_token = self.authenticate(&api_key).await?;
-                    self.set_jwt(jwt_token).await;
-                    debug!("Token refreshed, retrying request");
-                    return Box::pin(
-                        self.request_with_body_retry(method, base_url, path, body, attempt, true),
-                    )
-                    .await;
-                }
-                Err(ApiError::Unauthorized.into())
+                self.refresh_jwt().await?;
+                debug!("Token refreshed, retrying request");
+                return Box::pin(
+                    self.request_with_body_retry(method, base_url, path, body, attempt, true),
+                )
+                .await;
             }
             StatusCode::FORBIDDEN => Err(ApiError::Forbidden.into()),
             StatusCode::NOT_FOUND => {
@@ -457,30 +729,16 @@ impl StackHawkClient {
             StatusCode::TOO_MANY_REQUESTS => {
                 self.rate_limiters.activate(category).await;
 
-                if attempt >= MAX_RATE_LIMIT_RETRIES {
+                if attempt >= self.max_retries {
                     return Err(ApiError::RateLimited.into());
                 }
 
-                let base_wait_secs = response
-                    .headers()
-                    .get("retry-after")
-                    .and_then(|v| v.to_str().ok())
-                    .and_then(|v| v.parse::<u64>().ok())
-                    .unwrap_or(1);
-
-                let backoff_secs = base_wait_secs.saturating_mul(1 << attempt);
-                let jitter_ms = SystemTime::now()
-                    .duration_since(UNIX_EPOCH)
-                    .map(|d| d.subsec_nanos() % 1000)
-                    .unwrap_or(0) as u64;
-
-                let total_wait =
-                    Duration::from_secs(backoff_secs) + Duration::from_millis(jitter_ms);
+                let total_wait = retry_wait(retry_after_secs(response.headers()), attempt);
 
                 debug!(
                     "Rate limited (429), attempt {}/{}, waiting {:?} before retry",
                     attempt + 1,
-                    MAX_RATE_LIMIT_RETRIES,
+                    self.max_retries,
                     total_wait
                 );
 
@@ -531,7 +789,9 @@ impl StackHawkClient {
         attempt: u32,
         token_refreshed: bool,
     ) -> Result<()> {
+        self.ensure_online(path)?;
         let method = reqwest::Method::DELETE;
+        self.ensure_not_dry_run(&method, base_url, path, None)?;
 
         // Categorize this endpoint for rate limiting
         let category = EndpointCategory::from_request(path, &method);
@@ -546,13 +806,13 @@ impl StackHawkClient {
         let url = format!("{}{}", base_url, path);
         debug!("API request: {} {} (category: {:?})", method, url, category);
 
-        let response = self
-            .http
-            .request(method.clone(), &url)
-            .header("Authorization", format!("Bearer {}", jwt))
-            .send()
-            .await
-            .map_err(ApiError::from)?;
+        let response = trace::send(
+            self.http
+                .request(method.clone(), &url)
+                .header("Authorization", format!("Bearer {}", jwt)),
+        )
+        .await
+        .map_err(ApiError::from)?;
 
         let status = response.status();
         debug!(
@@ -577,19 +837,10 @@ impl StackHawkClient {
                 }
 
                 debug!("Received 401, attempting token refresh");
-                let api_key = {
-                    let state = self.auth_state.read().await;
-                    state.api_key.clone()
-                };
-
-                if let Some(api_key) = api_key {
-                    let jwt_token = self.authenticate(&api_key).await?;
-                    self.set_jwt(jwt_token).await;
-                    debug!("Token refreshed, retrying request");
-                    return Box::pin(self.request_delete_with_retry(base_url, path, attempt, true))
-                        .await;
-                }
-                Err(ApiError::Unauthorized.into())
+                self.refresh_jwt().await?;
+                debug!("Token refreshed, retrying request");
+                return Box::pin(self.request_delete_with_retry(base_url, path, attempt, true))
+                    .await;
             }
             StatusCode::FORBIDDEN => Err(ApiError::Forbidden.into()),
             StatusCode::NOT_FOUND => {
@@ -602,30 +853,16 @@ impl StackHawkClient {
             StatusCode::TOO_MANY_REQUESTS => {
                 self.rate_limiters.activate(category).await;
 
-                if attempt >= MAX_RATE_LIMIT_RETRIES {
+                if attempt >= self.max_retries {
                     return Err(ApiError::RateLimited.into());
                 }
 
-                let base_wait_secs = response
-                    .headers()
-                    .get("retry-after")
-                    .and_then(|v| v.to_str().ok())
-                    .and_then(|v| v.parse::<u64>().ok())
-                    .unwrap_or(1);
-
-                let backoff_secs = base_wait_secs.saturating_mul(1 << attempt);
-                let jitter_ms = SystemTime::now()
-                    .duration_since(UNIX_EPOCH)
-                    .map(|d| d.subsec_nanos() % 1000)
-                    .unwrap_or(0) as u64;
-
-                let total_wait =
-                    Duration::from_secs(backoff_secs) + Duration::from_millis(jitter_ms);
+                let total_wait = retry_wait(retry_after_secs(response.headers()), attempt);
 
                 debug!(
                     "Rate limited (429), attempt {}/{}, waiting {:?} before retry",
                     attempt + 1,
-                    MAX_RATE_LIMIT_RETRIES,
+                    self.max_retries,
                     total_wait
                 );
 
@@ -672,19 +909,21 @@ impl StackHawkClient {
     /// - If the response is 200 with JSON body → parse presigned URL, fetch content
     /// - If reqwest followed a redirect to S3 → return the content directly
     async fn fetch_hosted_asset(&self, path: &str) -> Result<String> {
+        self.ensure_online(path)?;
+
         // Get valid JWT
         let jwt = self.get_valid_jwt().await?;
 
         let url = format!("{}{}", self.base_url_v1, path);
         debug!("Fetching hosted asset: GET {}", url);
 
-        let response = self
-            .http
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", jwt))
-            .send()
-            .await
-            .map_err(ApiError::from)?;
+        let response = trace::send(
+            self.http
+                .get(&url)
+                .header("Authorization", format!("Bearer {}", jwt)),
+        )
+        .await
+        .map_err(ApiError::from)?;
 
         let final_url = response.url().clone();
         let status = response.status();
@@ -714,6 +953,7 @@ impl StackHawkClient {
             .text()
             .await
             .map_err(|e| ApiError::Network(e.to_string()))?;
+        trace::response_body(&url, &body);
 
         // Check if reqwest followed a redirect to S3 (the content IS the asset)
         let was_redirected = final_url.host_str() != Some(url.split('/').nth(2).unwrap_or(""));
@@ -723,40 +963,39 @@ impl StackHawkClient {
         }
 
         // No redirect — parse the JSON envelope to get the presigned URL
-        let asset_response: GetHostedAssetResponse = serde_json::from_str(&body).map_err(|e| {
-            debug!("Failed to parse hosted asset response: {}", e);
-            ApiError::InvalidResponse(format!(
-                "Failed to parse response: {} (line {}, col {})",
-                e,
-                e.line(),
-                e.column()
-            ))
-        })?;
+        let asset_response: GetHostedAssetResponse =
+            schema::from_str(&url, &body).map_err(|e| {
+                debug!("Failed to parse hosted asset response: {}", e);
+                ApiError::InvalidResponse(format!(
+                    "Failed to parse response: {} (line {}, col {})",
+                    e,
+                    e.line(),
+                    e.column()
+                ))
+            })?;
 
         let download_url = asset_response
             .presigned_download_url
             .ok_or_else(|| ApiError::InvalidResponse("No download URL in response".to_string()))?;
 
         // Fetch the actual content from the presigned URL (no auth needed)
-        let content = self
-            .http
-            .get(&download_url)
-            .send()
+        let content = trace::send(self.http.get(&download_url))
             .await
             .map_err(|e| ApiError::Network(e.to_string()))?
             .text()
             .await
             .map_err(|e| ApiError::Network(e.to_string()))?;
+        trace::response_body(&download_url, &content);
 
         Ok(content)
     }
 
-    /// Internal request implementation with retry support for rate limiting
+    /// Internal request implementation with retry support
     ///
-    /// Uses exponential backoff with jitter for 429 responses:
-    /// - Base wait from retry-after header (default 1s)
-    /// - Exponential: base * 2^attempt
-    /// - Jitter: 0-1000ms random offset to prevent thundering herd
+    /// Retries 429 responses for any method, and transient failures (500, 502,
+    /// 503, 504, connection errors, timeouts) for GET requests only, since
+    /// those are safe to repeat. The wait between attempts comes from
+    /// [`retry_wait`].
     async fn request_with_retry<T: for<'de> Deserialize<'de>>(
         &self,
         method: reqwest::Method,
@@ -766,6 +1005,9 @@ impl StackHawkClient {
         attempt: u32,
         token_refreshed: bool,
     ) -> Result<T> {
+        self.ensure_online(path)?;
+        self.ensure_not_dry_run(&method, base_url, path, None)?;
+
         // Categorize this endpoint for rate limiting
         let category = EndpointCategory::from_request(path, &method);
 
@@ -792,7 +1034,32 @@ impl StackHawkClient {
             request = request.query(query_params);
         }
 
-        let response = request.send().await.map_err(ApiError::from)?;
+        let retryable = method == reqwest::Method::GET && attempt < self.max_retries;
+
+        let response = match trace::send(request).await {
+            Ok(response) => response,
+            Err(e) if retryable && is_transient_error(&e) => {
+                let wait = retry_wait(None, attempt);
+                debug!(
+                    "Transient error ({}), attempt {}/{}, waiting {:?} before retry",
+                    e,
+                    attempt + 1,
+                    self.max_retries,
+                    wait
+                );
+                tokio::time::sleep(wait).await;
+                return Box::pin(self.request_with_retry(
+                    method,
+                    base_url,
+                    path,
+                    query_params,
+                    attempt + 1,
+                    token_refreshed,
+                ))
+                .await;
+            }
+            Err(e) => return Err(ApiError::from(e).into()),
+        };
 
         // Handle response status
         let status = response.status();
@@ -803,14 +1070,36 @@ impl StackHawkClient {
         );
 
         match status {
-            StatusCode::OK => {
+            status if retryable && is_transient_status(status) => {
+                let wait = retry_wait(retry_after_secs(response.headers()), attempt);
+                debug!(
+                    "Transient {} response, attempt {}/{}, waiting {:?} before retry",
+                    status.as_u16(),
+                    attempt + 1,
+                    self.max_retries,
+                    wait
+                );
+                tokio::time::sleep(wait).await;
+                Box::pin(self.request_with_retry(
+                    method,
+                    base_url,
+                    path,
+                    query_params,
+                    attempt + 1,
+                    token_refreshed,
+                ))
+                .await
+            }
+            StatusCode::OK | StatusCode::CREATED | StatusCode::NO_CONTENT => {
                 // Get response body as text first for better error messages
                 let body = response.text().await.map_err(|e| {
                     ApiError::InvalidResponse(format!("Failed to read response body: {}", e))
                 })?;
+                trace::response_body(&url, &body);
+                let body = empty_body_as_null(body);
 
                 // Parse JSON with detailed error reporting
-                let data: T = serde_json::from_str(&body).map_err(|e| {
+                let data: T = schema::from_str(&url, &body).map_err(|e| {
                     // Log part of the response body for debugging
                     let preview = if body.len() > 500 {
                         format!("{}...", &body[..500])
@@ -842,29 +1131,19 @@ impl StackHawkClient {
                 }
 
                 debug!("Received 401, attempting token refresh");
-                // Try to refresh token once
-                let api_key = {
-                    let state = self.auth_state.read().await;
-                    state.api_key.clone()
-                };
-
-                if let Some(api_key) = api_key {
-                    let jwt_token = self.authenticate(&api_key).await?;
-                    self.set_jwt(jwt_token).await;
-                    debug!("Token refreshed, retrying request");
-
-                    // Retry request with same query params - box the recursive call
-                    return Box::pin(self.request_with_retry(
-                        method,
-                        base_url,
-                        path,
-                        query_params,
-                        attempt,
-                        true, // Mark that we've refreshed the token
-                    ))
-                    .await;
-                }
-                Err(ApiError::Unauthorized.into())
+                self.refresh_jwt().await?;
+                debug!("Token refreshed, retrying request");
+
+                // Retry request with same query params - box the recursive call
+                return Box::pin(self.request_with_retry(
+                    method,
+                    base_url,
+                    path,
+                    query_params,
+                    attempt,
+                    true, // Mark that we've refreshed the token
+                ))
+                .await;
             }
             StatusCode::FORBIDDEN => Err(ApiError::Forbidden.into()),
             StatusCode::NOT_FOUND => {
@@ -879,7 +1158,7 @@ impl StackHawkClient {
                 self.rate_limiters.activate(category).await;
 
                 // Check if we've exceeded max retries
-                if attempt >= MAX_RATE_LIMIT_RETRIES {
+                if attempt >= self.max_retries {
                     debug!(
                         "Rate limit retry exhausted after {} attempts for {:?}",
                         attempt, category
@@ -887,31 +1166,13 @@ impl StackHawkClient {
                     return Err(ApiError::RateLimited.into());
                 }
 
-                // Parse retry-after header (default 1 second for backoff calculation)
-                let base_wait_secs = response
-                    .headers()
-                    .get("retry-after")
-                    .and_then(|v| v.to_str().ok())
-                    .and_then(|v| v.parse::<u64>().ok())
-                    .unwrap_or(1);
-
-                // Exponential backoff: base * 2^attempt
-                let backoff_secs = base_wait_secs.saturating_mul(1 << attempt);
-
-                // Jitter: 0-1000ms using nanosecond timestamp (avoids rand dependency)
-                let jitter_ms = SystemTime::now()
-                    .duration_since(UNIX_EPOCH)
-                    .map(|d| d.subsec_nanos() % 1000)
-                    .unwrap_or(0) as u64;
-
-                let total_wait =
-                    Duration::from_secs(backoff_secs) + Duration::from_millis(jitter_ms);
+                let total_wait = retry_wait(retry_after_secs(response.headers()), attempt);
 
                 debug!(
                     "Rate limited (429) for {:?}, attempt {}/{}, waiting {:?} before retry",
                     category,
                     attempt + 1,
-                    MAX_RATE_LIMIT_RETRIES,
+                    self.max_retries,
                     total_wait
                 );
 
@@ -958,6 +1219,8 @@ impl StackHawkClient {
 #[async_trait]
 impl AuthApi for StackHawkClient {
     async fn authenticate(&self, api_key: &str) -> Result<JwtToken> {
+        self.ensure_online("/auth/login")?;
+
         // Wait if rate limiting is active for the default category (auth endpoint)
         let category = EndpointCategory::Default;
         self.rate_limiters.wait_for(category).await;
@@ -976,11 +1239,7 @@ impl AuthApi for StackHawkClient {
         debug!("Authenticating with API key");
 
         // Use GET with X-ApiKey header
-        let response = self
-            .http
-            .get(&url)
-            .header("X-ApiKey", api_key)
-            .send()
+        let response = trace::send(self.http.get(&url).header("X-ApiKey", api_key))
             .await
             .map_err(ApiError::from)?;
 
@@ -991,11 +1250,31 @@ impl AuthApi for StackHawkClient {
             return Err(ApiError::Unauthorized.into());
         }
 
+        // Measure clock skew from the server's Date header so expiry checks
+        // work on machines with drifted clocks
+        let clock_skew_secs = response
+            .headers()
+            .get(reqwest::header::DATE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|date| clock_skew_from_date_header(date, Utc::now()))
+            .unwrap_or(0);
+        debug!("Clock skew (server - local): {}s", clock_skew_secs);
+        if clock_skew_secs.abs() > CLOCK_SKEW_WARN_SECS {
+            warn(
+                WarningKind::ClockSkew,
+                format!(
+                    "{}; token expiry has been adjusted. Consider syncing your system clock (NTP).",
+                    describe_clock_skew(clock_skew_secs)
+                ),
+            );
+        }
+
         // Get response text for debugging
         let response_text = response
             .text()
             .await
             .map_err(|e| ApiError::InvalidResponse(format!("Failed to read response: {}", e)))?;
+        trace::response_body(&url, &response_text);
 
         let login_response: LoginResponse = serde_json::from_str(&response_text).map_err(|e| {
             ApiError::InvalidResponse(format!(
@@ -1029,6 +1308,7 @@ impl AuthApi for StackHawkClient {
         Ok(JwtToken {
             token: login_response.token,
             expires_at,
+            clock_skew_secs,
         })
     }
 }
@@ -1043,6 +1323,8 @@ impl ListingApi for StackHawkClient {
         #[derive(Deserialize)]
         struct UserOrganization {
             organization: Organization,
+            #[serde(default)]
+            features: Vec<OrgFeature>,
         }
 
         #[derive(Deserialize)]
@@ -1066,7 +1348,15 @@ impl ListingApi for StackHawkClient {
             .external
             .organizations
             .into_iter()
-            .map(|uo| uo.organization)
+            .map(|uo| {
+                // Membership features are the effective flags; the org's own
+                // list only holds overrides to its plan
+                let mut org = uo.organization;
+                if !uo.features.is_empty() {
+                    org.features = uo.features;
+                }
+                org
+            })
             .collect())
     }
 
@@ -1566,28 +1856,24 @@ impl ScanDetailApi for StackHawkClient {
         scan_id: &str,
         pagination: Option<&super::PaginationParams>,
     ) -> Result<Vec<ApplicationAlert>> {
-        let path = format!("/scan/{}/alerts", scan_id);
-
-        // Build query params from pagination
-        let query_params: Vec<(&str, String)> =
-            pagination.map(|p| p.to_query_params()).unwrap_or_default();
-
-        let response: ScanAlertsResponse = self
-            .request_with_query(
-                reqwest::Method::GET,
-                &self.base_url_v1,
-                &path,
-                &query_params,
-            )
-            .await?;
+        if let Some(params) = pagination {
+            return Ok(self.scan_alerts_page(scan_id, params).await?.items);
+        }
 
-        // API returns array with single element containing the alerts list
-        Ok(response
-            .application_scan_results
-            .into_iter()
-            .next()
-            .map(|r| r.application_alerts)
-            .unwrap_or_default())
+        // No pagination: every page, in API order
+        let first_params = PaginationParams::new().page_size(default_page_size());
+        let first = self.scan_alerts_page(scan_id, &first_params).await?;
+        fetch_pages_in_order(
+            first,
+            |page| async move {
+                let params = PaginationParams::new()
+                    .page_size(default_page_size())
+                    .page(page);
+                Ok(self.scan_alerts_page(scan_id, &params).await?.items)
+            },
+            max_concurrency(),
+        )
+        .await
     }
 
     async fn get_alert_with_paths(
@@ -1596,21 +1882,39 @@ impl ScanDetailApi for StackHawkClient {
         plugin_id: &str,
         pagination: Option<&super::PaginationParams>,
     ) -> Result<AlertResponse> {
-        let path = format!("/scan/{}/alert/{}", scan_id, plugin_id);
-
-        // Build query params from pagination
-        let query_params: Vec<(&str, String)> =
-            pagination.map(|p| p.to_query_params()).unwrap_or_default();
+        if let Some(params) = pagination {
+            return self.alert_paths_page(scan_id, plugin_id, params).await;
+        }
 
-        let response: AlertResponse = self
-            .request_with_query(
-                reqwest::Method::GET,
-                &self.base_url_v1,
-                &path,
-                &query_params,
-            )
+        // No pagination: every path, in API order
+        let first_params = PaginationParams::new().page_size(default_page_size());
+        let mut response = self
+            .alert_paths_page(scan_id, plugin_id, &first_params)
             .await?;
-
+        let uris = std::mem::take(&mut response.application_scan_alert_uris);
+        let total = known_total(
+            response.total_count,
+            response.next_page_token.as_deref(),
+            uris.len(),
+        );
+        let first = PagedResponse::new(uris, total, default_page_size(), 0);
+
+        response.application_scan_alert_uris = fetch_pages_in_order(
+            first,
+            |page| async move {
+                let params = PaginationParams::new()
+                    .page_size(default_page_size())
+                    .page(page);
+                Ok(self
+                    .alert_paths_page(scan_id, plugin_id, &params)
+                    .await?
+                    .application_scan_alert_uris)
+            },
+            max_concurrency(),
+        )
+        .await?;
+        response.total_count = Some(response.application_scan_alert_uris.len() as i64);
+        response.next_page_token = None;
         Ok(response)
     }
 
@@ -2145,9 +2449,72 @@ impl AppApi for StackHawkClient {
     }
 }
 
+// ============================================================================
+// TriageApi Implementation
+// ============================================================================
+
+#[async_trait]
+impl TriageApi for StackHawkClient {
+    async fn bulk_triage_findings(&self, request: BulkTriageRequest) -> Result<BulkTriageResponse> {
+        let path = format!(
+            "/org/{}/app/{}/env/{}/findings/triage",
+            request.organization_id, request.application_id, request.environment_id
+        );
+
+        self.request_with_body(reqwest::Method::POST, &self.base_url_v1, &path, &request)
+            .await
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::Error;
+
+    #[test]
+    fn test_retry_wait_backoff() {
+        let first = retry_wait(None, 0);
+        assert!(first >= Duration::from_secs(1) && first < Duration::from_secs(2));
+        let third = retry_wait(None, 2);
+        assert!(third >= Duration::from_secs(4) && third < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_retry_wait_honors_retry_after() {
+        assert_eq!(retry_wait(Some(3), 0), Duration::from_secs(3));
+        assert_eq!(retry_wait(Some(3), 4), Duration::from_secs(3));
+        assert_eq!(retry_wait(Some(0), 2), Duration::ZERO);
+        assert_eq!(retry_wait(Some(3600), 0), MAX_RETRY_AFTER);
+    }
+
+    #[test]
+    fn test_retry_after_and_transient_status() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        assert_eq!(retry_after_secs(&headers), None);
+        headers.insert(reqwest::header::RETRY_AFTER, "5".parse().unwrap());
+        assert_eq!(retry_after_secs(&headers), Some(5));
+        headers.insert(
+            reqwest::header::RETRY_AFTER,
+            "Wed, 21 Oct 2015 07:28:00 GMT".parse().unwrap(),
+        );
+        assert_eq!(retry_after_secs(&headers), None);
+
+        assert!(is_transient_status(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(is_transient_status(StatusCode::BAD_GATEWAY));
+        assert!(!is_transient_status(StatusCode::NOT_IMPLEMENTED));
+        assert!(!is_transient_status(StatusCode::NOT_FOUND));
+    }
+
+    #[test]
+    fn test_known_total() {
+        assert_eq!(known_total(Some(250), Some("1"), 100), Some(250));
+        // Never less than what already came back
+        assert_eq!(known_total(Some(0), None, 3), Some(3));
+        // No total: the last page is the one without a next page token
+        assert_eq!(known_total(None, None, 40), Some(40));
+        assert_eq!(known_total(None, Some(""), 40), Some(40));
+        assert_eq!(known_total(None, Some("1"), 100), None);
+    }
 
     #[test]
     fn test_compute_base_urls() {
@@ -2195,6 +2562,7 @@ mod tests {
             .set_jwt(JwtToken {
                 token: "test".to_string(),
                 expires_at: Utc::now() - chrono::Duration::hours(1),
+                clock_skew_secs: 0,
             })
             .await;
         assert!(client.is_jwt_expired().await);
@@ -2204,6 +2572,7 @@ mod tests {
             .set_jwt(JwtToken {
                 token: "test".to_string(),
                 expires_at: Utc::now() + chrono::Duration::hours(1),
+                clock_skew_secs: 0,
             })
             .await;
         assert!(!client.is_jwt_expired().await);
@@ -2213,8 +2582,54 @@ mod tests {
             .set_jwt(JwtToken {
                 token: "test".to_string(),
                 expires_at: Utc::now() + chrono::Duration::minutes(2),
+                clock_skew_secs: 0,
             })
             .await;
         assert!(client.is_jwt_expired().await);
     }
+
+    #[test]
+    fn test_resolve_raw_path() {
+        let client =
+            StackHawkClient::with_host(None, Some("https://api.example.com".to_string())).unwrap();
+        let resolve = |path: &str| client.raw_url(path);
+        assert_eq!(
+            resolve("/org/o1/apps"),
+            "https://api.example.com/api/v1/org/o1/apps"
+        );
+        assert_eq!(resolve("user"), "https://api.example.com/api/v1/user");
+        assert_eq!(
+            resolve("/api/v2/org/o1/repos?pageSize=5"),
+            "https://api.example.com/api/v2/org/o1/repos?pageSize=5"
+        );
+        assert_eq!(
+            resolve("/api/v1/app/a1"),
+            "https://api.example.com/api/v1/app/a1"
+        );
+        // Only a whole segment counts as the version prefix
+        assert_eq!(
+            resolve("/api/v10/x"),
+            "https://api.example.com/api/v1/api/v10/x"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_withholds_mutations() {
+        let client = StackHawkClient::with_host(None, Some("http://127.0.0.1:9".to_string()))
+            .unwrap()
+            .with_dry_run(true);
+
+        let err = client.update_app("app-1", "renamed").await.unwrap_err();
+        let Error::Api(ApiError::DryRun(request)) = err else {
+            panic!("expected a dry-run error, got {err:?}");
+        };
+        assert_eq!(request.to_string(), "POST /api/v1/app/app-1");
+        assert_eq!(
+            request.payload,
+            Some(serde_json::json!({"name": "renamed"}))
+        );
+
+        let err = client.delete_app("app-1").await.unwrap_err();
+        assert!(matches!(err, Error::Api(ApiError::DryRun(ref r)) if r.method == "DELETE"));
+    }
 }
@@ -0,0 +1,101 @@
+//! Request metrics for `--stats`
+//!
+//! [`super::trace::send`] counts every API request with its size and time to
+//! response headers, response payloads are counted as they are read, and
+//! [`crate::cache::CachedStackHawkClient`] counts every cache lookup. Like the
+//! rate limit counters, these are process-wide, so [`snapshot`] covers every
+//! client a command created.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use super::rate_limit::rate_limit_hits;
+
+static API_CALLS: AtomicU64 = AtomicU64::new(0);
+static FAILED_CALLS: AtomicU64 = AtomicU64::new(0);
+static BYTES_SENT: AtomicU64 = AtomicU64::new(0);
+static BYTES_RECEIVED: AtomicU64 = AtomicU64::new(0);
+static REQUEST_MICROS: AtomicU64 = AtomicU64::new(0);
+static CACHE_HITS: AtomicU64 = AtomicU64::new(0);
+static CACHE_MISSES: AtomicU64 = AtomicU64::new(0);
+
+/// Totals for this process so far
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// Requests sent, including retries
+    pub api_calls: u64,
+    /// Requests that got no response (connection errors, timeouts)
+    pub failed_calls: u64,
+    /// Requests answered with 429 Too Many Requests
+    pub rate_limited: u64,
+    /// Request payload bytes sent
+    pub bytes_sent: u64,
+    /// Response payload bytes received
+    pub bytes_received: u64,
+    /// Time spent waiting for responses, summed over parallel requests
+    pub request_time: Duration,
+    /// Cache lookups answered from the cache
+    pub cache_hits: u64,
+    /// Cache lookups that went to the API
+    pub cache_misses: u64,
+}
+
+/// Count a request with `sent` payload bytes that took `elapsed` to answer
+pub(crate) fn record_request(sent: usize, elapsed: Duration, answered: bool) {
+    API_CALLS.fetch_add(1, Ordering::Relaxed);
+    if !answered {
+        FAILED_CALLS.fetch_add(1, Ordering::Relaxed);
+    }
+    BYTES_SENT.fetch_add(sent as u64, Ordering::Relaxed);
+    REQUEST_MICROS.fetch_add(elapsed.as_micros() as u64, Ordering::Relaxed);
+}
+
+/// Count a response payload of `bytes` once it has been read
+pub(crate) fn record_received(bytes: usize) {
+    BYTES_RECEIVED.fetch_add(bytes as u64, Ordering::Relaxed);
+}
+
+/// Count a cache lookup
+pub(crate) fn record_cache(hit: bool) {
+    let counter = if hit { &CACHE_HITS } else { &CACHE_MISSES };
+    counter.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Totals so far
+pub fn snapshot() -> Stats {
+    Stats {
+        api_calls: API_CALLS.load(Ordering::Relaxed),
+        failed_calls: FAILED_CALLS.load(Ordering::Relaxed),
+        rate_limited: rate_limit_hits() as u64,
+        bytes_sent: BYTES_SENT.load(Ordering::Relaxed),
+        bytes_received: BYTES_RECEIVED.load(Ordering::Relaxed),
+        request_time: Duration::from_micros(REQUEST_MICROS.load(Ordering::Relaxed)),
+        cache_hits: CACHE_HITS.load(Ordering::Relaxed),
+        cache_misses: CACHE_MISSES.load(Ordering::Relaxed),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_counts_records() {
+        // Other tests may record concurrently, so compare at least-deltas
+        let before = snapshot();
+        record_request(10, Duration::from_millis(5), true);
+        record_request(0, Duration::from_millis(1), false);
+        record_received(100);
+        record_cache(true);
+        record_cache(false);
+        let after = snapshot();
+
+        assert!(after.api_calls >= before.api_calls + 2);
+        assert!(after.failed_calls > before.failed_calls);
+        assert!(after.bytes_sent >= before.bytes_sent + 10);
+        assert!(after.bytes_received >= before.bytes_received + 100);
+        assert!(after.request_time >= before.request_time + Duration::from_millis(6));
+        assert!(after.cache_hits > before.cache_hits);
+        assert!(after.cache_misses > before.cache_misses);
+    }
+}
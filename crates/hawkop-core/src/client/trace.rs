@@ -0,0 +1,84 @@
+//! HTTP request tracing (`--trace-http`, `--trace-bodies`)
+//!
+//! Every API call goes through [`send`], which logs the method, full URL,
+//! status, and time to response headers under the [`TARGET`] log target.
+//! Request and response payloads are logged under [`BODY_TARGET`], which is
+//! only enabled by `--trace-bodies`. Both go through the logging subsystem,
+//! so they honor `--log-file` and `--log-format` and are redacted. Requests
+//! and payloads are also counted for `--stats` (see [`super::stats`]).
+
+use std::time::Instant;
+
+use log::{Level, log_enabled, trace};
+use reqwest::{RequestBuilder, Response};
+
+use super::stats;
+
+/// Log target for request/response lines
+pub const TARGET: &str = "hawkop::http";
+
+/// Log target for request and response payloads
+pub const BODY_TARGET: &str = "hawkop::http::body";
+
+/// Send a request, tracing it when `--trace-http` is on
+pub async fn send(builder: RequestBuilder) -> reqwest::Result<Response> {
+    let (client, request) = builder.build_split();
+    let request = request?;
+    let sent = request
+        .body()
+        .and_then(|b| b.as_bytes())
+        .map_or(0, <[u8]>::len);
+
+    if !log_enabled!(target: TARGET, Level::Trace) {
+        let started = Instant::now();
+        let result = client.execute(request).await;
+        stats::record_request(sent, started.elapsed(), result.is_ok());
+        return result;
+    }
+
+    let method = request.method().clone();
+    let url = request.url().clone();
+    if log_enabled!(target: BODY_TARGET, Level::Trace)
+        && let Some(body) = request.body().and_then(|b| b.as_bytes())
+    {
+        trace!(
+            target: BODY_TARGET,
+            "{} {} request body: {}",
+            method,
+            url,
+            String::from_utf8_lossy(body)
+        );
+    }
+
+    let started = Instant::now();
+    let result = client.execute(request).await;
+    let elapsed = started.elapsed();
+    stats::record_request(sent, elapsed, result.is_ok());
+    let elapsed_ms = elapsed.as_millis();
+    match &result {
+        Ok(response) => trace!(
+            target: TARGET,
+            "{} {} -> {} {} ({} ms)",
+            method,
+            url,
+            response.status().as_u16(),
+            response.status().canonical_reason().unwrap_or(""),
+            elapsed_ms
+        ),
+        Err(e) => trace!(
+            target: TARGET,
+            "{} {} -> failed after {} ms: {}",
+            method,
+            url,
+            elapsed_ms,
+            e
+        ),
+    }
+    result
+}
+
+/// Trace and count a response payload once it has been read
+pub fn response_body(url: &str, body: &str) {
+    stats::record_received(body.len());
+    trace!(target: BODY_TARGET, "{} response body: {}", url, body);
+}
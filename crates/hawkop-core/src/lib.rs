@@ -0,0 +1,46 @@
+//! StackHawk API client with response caching and rate limiting
+//!
+//! `hawkop-core` is the library behind the `hawkop` CLI. It talks to the
+//! StackHawk API through [`StackHawkClient`], which authenticates with an API
+//! key, refreshes JWTs, retries transient failures, and throttles each
+//! endpoint category once the API starts returning 429s. Wrap it in
+//! [`CachedStackHawkClient`] to serve repeated reads from a local SQLite
+//! cache.
+//!
+//! The API surface is split into traits by resource ([`ListingApi`],
+//! [`ScanDetailApi`], [`AppApi`], ...), implemented by both clients, so code
+//! can be written against the traits and tested with the mock client from the
+//! `test-util` feature.
+//!
+//! ```no_run
+//! use hawkop_core::{AuthApi, CachedStackHawkClient, ListingApi, StackHawkClient};
+//!
+//! # async fn run() -> hawkop_core::Result<()> {
+//! let api_key = std::env::var("HAWK_API_KEY").unwrap();
+//! let client = StackHawkClient::new(Some(api_key.clone()))?;
+//! let jwt = client.authenticate(&api_key).await?;
+//! client.set_jwt(jwt).await;
+//!
+//! let client = CachedStackHawkClient::new(client, true);
+//! for org in client.list_orgs().await? {
+//!     println!("{} {}", org.id, org.name);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Caveats reported by the client (stale cache entries, skipped pages) go
+//! through [`warnings`]; a binary can install a handler to print them.
+
+pub mod cache;
+pub mod client;
+pub mod error;
+pub mod redact;
+pub mod warnings;
+
+pub use cache::CachedStackHawkClient;
+pub use client::{
+    AppApi, AuthApi, ConfigApi, EnvironmentApi, ListingApi, OASApi, PaginationParams, PerchApi,
+    RepoApi, ScanDetailApi, ScanFilterParams, StackHawkClient, TeamApi, TriageApi,
+};
+pub use error::{ApiError, DryRunRequest, Error, Result};
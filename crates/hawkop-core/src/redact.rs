@@ -0,0 +1,349 @@
+//! Credential redaction shared by logging, the cache, and `scan get`
+//!
+//! One list of sensitive names drives every scanner: `Name: value` headers
+//! ([`redact_headers`]), `name=value` pairs and JSON `"name": "value"` fields
+//! ([`redact_params`]), and free text such as log lines
+//! ([`redact_credentials`], which adds `Bearer`/`Basic` credentials and
+//! JWTs). Values are replaced with [`REDACTED`]; names are kept so the text
+//! still reads correctly.
+
+use std::borrow::Cow;
+
+/// Replacement text for redacted values
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Header, parameter, and field names whose values are credentials
+/// (lowercase)
+const SENSITIVE_NAMES: &[&str] = &[
+    "access_token",
+    "api-key",
+    "api_key",
+    "apikey",
+    "auth_token",
+    "authorization",
+    "client_secret",
+    "cookie",
+    "id_token",
+    "jsessionid",
+    "jwt",
+    "passwd",
+    "password",
+    "phpsessid",
+    "proxy-authorization",
+    "refresh_token",
+    "secret",
+    "session",
+    "session_id",
+    "sessionid",
+    "set-cookie",
+    "token",
+    "x-amz-security-token",
+    "x-amz-signature",
+    "x-api-key",
+    "x-apikey",
+    "x-auth-token",
+];
+
+/// Auth schemes whose credential follows a space (`Bearer <token>`)
+const AUTH_SCHEMES: &[&str] = &["bearer", "basic"];
+
+/// Redact every credential this module recognizes in free text
+///
+/// Applies [`redact_headers`] and [`redact_params`], then redacts the
+/// credential after a bare `Bearer`/`Basic` scheme and any JWT. Returns the
+/// text unchanged (borrowed) when there was nothing to redact.
+pub fn redact_credentials(text: &str) -> Cow<'_, str> {
+    let redacted = redact_jwts(&redact_schemes(&redact_params(&redact_headers(text))));
+    if redacted == text {
+        Cow::Borrowed(text)
+    } else {
+        Cow::Owned(redacted)
+    }
+}
+
+/// Redact the values of sensitive `Name: value` headers in `text`
+///
+/// A value runs to the end of its line or to a closing quote, so both raw
+/// header blocks and curl `-H '...'` arguments are covered. For
+/// `Authorization`, the scheme (`Bearer`, `Basic`) is kept.
+pub fn redact_headers(text: &str) -> String {
+    let lower = text.to_ascii_lowercase();
+    let mut out = String::with_capacity(text.len());
+    let mut pos = 0;
+
+    while pos < text.len() {
+        let Some((start, name)) = next_sensitive_header(&lower, pos) else {
+            break;
+        };
+        let value_start = start + name.len() + 1;
+        let value_end = text[value_start..]
+            .find(['\r', '\n', '\'', '"'])
+            .map_or(text.len(), |i| value_start + i);
+
+        out.push_str(&text[pos..value_start]);
+        let value = &text[value_start..value_end];
+        let leading = value.len() - value.trim_start().len();
+        out.push_str(&value[..leading]);
+        if name.ends_with("authorization")
+            && let Some((scheme, _)) = value.trim_start().split_once(' ')
+        {
+            out.push_str(scheme);
+            out.push(' ');
+        }
+        if !value.trim().is_empty() {
+            out.push_str(REDACTED);
+        }
+        pos = value_end;
+    }
+    out.push_str(&text[pos..]);
+    out
+}
+
+/// Earliest sensitive `name:` at or after `from` that starts a header
+fn next_sensitive_header(lower: &str, from: usize) -> Option<(usize, &'static str)> {
+    SENSITIVE_NAMES
+        .iter()
+        .filter_map(|name| {
+            let pattern = format!("{}:", name);
+            let mut search = from;
+            while let Some(i) = lower[search..].find(&pattern) {
+                let start = search + i;
+                // Must begin a header: not part of a longer name like x-cookie
+                let boundary = lower[..start]
+                    .chars()
+                    .next_back()
+                    .is_none_or(|c| matches!(c, '\n' | '\r' | '\'' | '"' | ' ' | '\t'));
+                if boundary {
+                    return Some((start, *name));
+                }
+                search = start + pattern.len();
+            }
+            None
+        })
+        .min_by_key(|(start, _)| *start)
+}
+
+/// Redact sensitive parameter values in `text`
+///
+/// Handles `name=value` pairs (query strings, form bodies, cookies), where
+/// the value runs to the next `&`, `;`, quote, or whitespace, and JSON
+/// `"name": "value"` fields, whose string value is replaced. Header lines
+/// are left to [`redact_headers`].
+pub fn redact_params(text: &str) -> String {
+    let lower = text.to_ascii_lowercase();
+    let bytes = lower.as_bytes();
+    let mut out = String::with_capacity(text.len());
+    let mut copied = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if i > 0 && is_name_byte(bytes[i - 1]) {
+            i += 1;
+            continue;
+        }
+        let Some((start, end)) = param_value(text, bytes, i) else {
+            i += 1;
+            continue;
+        };
+        if end > start && &text[start..end] != REDACTED {
+            out.push_str(&text[copied..start]);
+            out.push_str(REDACTED);
+            copied = end;
+        }
+        i = end.max(i + 1);
+    }
+
+    out.push_str(&text[copied..]);
+    out
+}
+
+/// Byte range of the value if a sensitive parameter name starts at `i`
+fn param_value(text: &str, lower: &[u8], i: usize) -> Option<(usize, usize)> {
+    let name = SENSITIVE_NAMES.iter().find(|name| {
+        lower[i..].starts_with(name.as_bytes())
+            && !lower.get(i + name.len()).copied().is_some_and(is_name_byte)
+    })?;
+    let mut j = i + name.len();
+
+    match lower.get(j) {
+        Some(b'=') => {
+            let start = j + 1;
+            let len = text[start..]
+                .find(|c: char| matches!(c, '&' | ';' | '\'' | '"' | '#') || c.is_whitespace())
+                .unwrap_or(text.len() - start);
+            Some((start, start + len))
+        }
+        // JSON: the name is quoted, then `:` and a string value
+        Some(b'"') if i > 0 && lower[i - 1] == b'"' => {
+            j = skip_whitespace(lower, j + 1);
+            if lower.get(j) != Some(&b':') {
+                return None;
+            }
+            j = skip_whitespace(lower, j + 1);
+            if lower.get(j) != Some(&b'"') {
+                return None;
+            }
+            let start = j + 1;
+            let mut end = start;
+            while end < lower.len() && lower[end] != b'"' {
+                end += if lower[end] == b'\\' { 2 } else { 1 };
+            }
+            Some((start, end.min(lower.len())))
+        }
+        _ => None,
+    }
+}
+
+/// Redact the credential after a `Bearer` or `Basic` scheme
+fn redact_schemes(text: &str) -> String {
+    let lower = text.to_ascii_lowercase();
+    let bytes = lower.as_bytes();
+    let mut out = String::with_capacity(text.len());
+    let mut copied = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let scheme = (i == 0 || !is_name_byte(bytes[i - 1]))
+            .then(|| {
+                AUTH_SCHEMES
+                    .iter()
+                    .find(|s| bytes[i..].starts_with(s.as_bytes()))
+            })
+            .flatten();
+        let Some(scheme) = scheme.filter(|s| bytes.get(i + s.len()) == Some(&b' ')) else {
+            i += 1;
+            continue;
+        };
+
+        let start = skip_whitespace(bytes, i + scheme.len());
+        let end = text[start..]
+            .find(|c: char| {
+                matches!(c, '"' | '\'' | ',' | ';' | '&' | '}' | ')') || c.is_whitespace()
+            })
+            .map_or(text.len(), |len| start + len);
+        if end > start && &text[start..end] != REDACTED {
+            out.push_str(&text[copied..start]);
+            out.push_str(REDACTED);
+            copied = end;
+        }
+        i = end.max(i + 1);
+    }
+
+    out.push_str(&text[copied..]);
+    out
+}
+
+/// Redact JWTs (`eyJ...` with three dot-separated parts)
+fn redact_jwts(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(pos) = rest.find("eyJ") {
+        let token_len = rest[pos..]
+            .bytes()
+            .take_while(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'-' | b'_'))
+            .count();
+        let token = &rest[pos..pos + token_len];
+        out.push_str(&rest[..pos]);
+        if token.split('.').filter(|part| !part.is_empty()).count() == 3 {
+            out.push_str(REDACTED);
+        } else {
+            out.push_str(token);
+        }
+        rest = &rest[pos + token_len..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn skip_whitespace(bytes: &[u8], mut i: usize) -> usize {
+    while bytes.get(i).is_some_and(|b| b.is_ascii_whitespace()) {
+        i += 1;
+    }
+    i
+}
+
+fn is_name_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || matches!(b, b'_' | b'-' | b'.')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_header_block() {
+        let headers = "GET /api HTTP/1.1\r\nHost: example.com\r\nAuthorization: Bearer abc.def\r\n\
+                       Cookie: session=123; theme=dark\r\nX-Cookie-Hint: keep\r\n";
+        let redacted = redact_headers(headers);
+        assert_eq!(
+            redacted,
+            "GET /api HTTP/1.1\r\nHost: example.com\r\nAuthorization: Bearer [REDACTED]\r\n\
+             Cookie: [REDACTED]\r\nX-Cookie-Hint: keep\r\n"
+        );
+    }
+
+    #[test]
+    fn test_redact_curl_command() {
+        let curl = "curl -X GET 'https://example.com/api' \\\n  -H 'authorization: Basic dXNlcg==' \\\n  -H \"X-Api-Key: k1\" -H 'Accept: */*'";
+        assert_eq!(
+            redact_headers(curl),
+            "curl -X GET 'https://example.com/api' \\\n  -H 'authorization: Basic [REDACTED]' \\\n  -H \"X-Api-Key: [REDACTED]\" -H 'Accept: */*'"
+        );
+    }
+
+    #[test]
+    fn test_redact_headers_leaves_other_text() {
+        let text = "HTTP/1.1 200 OK\nContent-Type: text/html\n\nno credentials: here";
+        assert_eq!(redact_headers(text), text);
+    }
+
+    #[test]
+    fn test_redact_query_and_form_params() {
+        assert_eq!(
+            redact_params("GET /api/items?page=2&api_key=abc123&sort=name HTTP/1.1"),
+            "GET /api/items?page=2&api_key=[REDACTED]&sort=name HTTP/1.1"
+        );
+        assert_eq!(
+            redact_params("username=alice&password=hunter2"),
+            "username=alice&password=[REDACTED]"
+        );
+        // Longer names that merely contain a sensitive one are kept
+        assert_eq!(
+            redact_params("csrf_token=abc&tokens=2&page_token=x"),
+            "csrf_token=abc&tokens=2&page_token=x"
+        );
+    }
+
+    #[test]
+    fn test_redact_json_fields() {
+        let body = r#"{"user": "alice", "password": "p\"w", "Access_Token":"eyJ", "token": 5}"#;
+        assert_eq!(
+            redact_params(body),
+            r#"{"user": "alice", "password": "[REDACTED]", "Access_Token":"[REDACTED]", "token": 5}"#
+        );
+        // Prose mentioning a name is not a parameter
+        assert_eq!(
+            redact_params("the session: expired"),
+            "the session: expired"
+        );
+    }
+
+    #[test]
+    fn test_redact_credentials_in_free_text() {
+        assert_eq!(
+            redact_credentials("sent Bearer tok123, then basic dXNlcg=="),
+            "sent Bearer [REDACTED], then basic [REDACTED]"
+        );
+        assert_eq!(
+            redact_credentials("jwt eyJhbGciOi.eyJzdWIiOiJ4In0.sig-_1 ok"),
+            "jwt [REDACTED] ok"
+        );
+        for text in ["eyJonly.two", "tokens: 5", "token refresh"] {
+            assert!(
+                matches!(redact_credentials(text), Cow::Borrowed(_)),
+                "{}",
+                text
+            );
+        }
+    }
+}
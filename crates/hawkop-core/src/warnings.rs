@@ -0,0 +1,224 @@
+//! Structured warnings channel
+//!
+//! Commands report caveats (partial results, truncated output, ignored or
+//! deprecated flags) through [`warn`] instead of ad-hoc `eprintln!` lines.
+//! Each warning is passed to the installed handler (see [`set_handler`]) and
+//! recorded so that JSON output can surface it under `meta.warnings`, and so
+//! `--warnings-as-errors` can fail the command once it finishes. Without a
+//! handler, warnings are logged at `warn` level.
+
+use std::sync::{Mutex, OnceLock};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Category of a warning, stable for scripts matching on `meta.warnings[].kind`
+#[allow(dead_code)] // Deprecated is reserved for flag changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WarningKind {
+    /// Data was served from cache after it expired
+    StaleCache,
+    /// Some requests failed; results are incomplete
+    PartialResults,
+    /// Output was truncated or capped
+    Truncated,
+    /// A deprecated flag or command was used
+    Deprecated,
+    /// A flag was accepted but has no effect in this context
+    IgnoredFlag,
+    /// Output is unusually large
+    LargeOutput,
+    /// Local clock differs noticeably from server time
+    ClockSkew,
+    /// A `--notify` notification or `--post-to` delivery failed
+    NotificationFailed,
+}
+
+impl std::fmt::Display for WarningKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::StaleCache => write!(f, "stale_cache"),
+            Self::PartialResults => write!(f, "partial_results"),
+            Self::Truncated => write!(f, "truncated"),
+            Self::Deprecated => write!(f, "deprecated"),
+            Self::IgnoredFlag => write!(f, "ignored_flag"),
+            Self::LargeOutput => write!(f, "large_output"),
+            Self::ClockSkew => write!(f, "clock_skew"),
+            Self::NotificationFailed => write!(f, "notification_failed"),
+        }
+    }
+}
+
+/// A single warning raised during command execution
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Warning {
+    /// Warning category
+    pub kind: WarningKind,
+
+    /// Human-readable message
+    pub message: String,
+}
+
+/// Ordered collection of warnings raised by a command
+#[derive(Debug, Default)]
+pub struct WarningLog {
+    entries: Vec<Warning>,
+}
+
+impl WarningLog {
+    /// Create an empty log
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Record a warning, ignoring exact duplicates
+    ///
+    /// Returns `true` if the warning was new.
+    pub fn push(&mut self, warning: Warning) -> bool {
+        if self.entries.contains(&warning) {
+            return false;
+        }
+        self.entries.push(warning);
+        true
+    }
+
+    /// All recorded warnings in emission order
+    pub fn entries(&self) -> &[Warning] {
+        &self.entries
+    }
+
+    /// Number of recorded warnings
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no warnings have been recorded
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Process-wide warning log (one CLI invocation per process)
+static WARNINGS: Mutex<WarningLog> = Mutex::new(WarningLog::new());
+
+/// Called once for each new warning
+static HANDLER: OnceLock<fn(&Warning)> = OnceLock::new();
+
+/// Install the function that shows new warnings (e.g. prints to stderr)
+///
+/// Only the first handler installed in a process is kept.
+pub fn set_handler(handler: fn(&Warning)) {
+    let _ = HANDLER.set(handler);
+}
+
+/// Emit a warning: show it through the handler and record it for JSON meta /
+/// strict mode.
+///
+/// Repeated identical warnings are shown and recorded only once.
+pub fn warn(kind: WarningKind, message: impl Into<String>) {
+    let warning = Warning {
+        kind,
+        message: message.into(),
+    };
+
+    let is_new = match WARNINGS.lock() {
+        Ok(mut log) => log.push(warning.clone()),
+        Err(_) => true,
+    };
+
+    if is_new {
+        match HANDLER.get() {
+            Some(handler) => {
+                log::debug!("Warning ({}): {}", warning.kind, warning.message);
+                handler(&warning);
+            }
+            None => log::warn!("{}", warning.message),
+        }
+    }
+}
+
+/// Snapshot of all warnings recorded so far
+pub fn collected() -> Vec<Warning> {
+    WARNINGS
+        .lock()
+        .map(|log| log.entries().to_vec())
+        .unwrap_or_default()
+}
+
+/// Number of warnings recorded so far
+pub fn count() -> usize {
+    WARNINGS.lock().map(|log| log.len()).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn warning(kind: WarningKind, message: &str) -> Warning {
+        Warning {
+            kind,
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_warning_log_preserves_order() {
+        let mut log = WarningLog::new();
+        assert!(log.is_empty());
+
+        log.push(warning(WarningKind::Truncated, "first"));
+        log.push(warning(WarningKind::PartialResults, "second"));
+
+        assert_eq!(log.len(), 2);
+        assert_eq!(log.entries()[0].message, "first");
+        assert_eq!(log.entries()[1].message, "second");
+    }
+
+    #[test]
+    fn test_warning_log_skips_duplicates() {
+        let mut log = WarningLog::new();
+        assert!(log.push(warning(WarningKind::Truncated, "same")));
+        assert!(!log.push(warning(WarningKind::Truncated, "same")));
+        // Same message with a different kind is a distinct warning
+        assert!(log.push(warning(WarningKind::IgnoredFlag, "same")));
+        assert_eq!(log.len(), 2);
+    }
+
+    #[test]
+    fn test_warning_kind_serializes_snake_case() {
+        let json = serde_json::to_string(&warning(WarningKind::PartialResults, "x")).unwrap();
+        assert_eq!(json, r#"{"kind":"partial_results","message":"x"}"#);
+    }
+
+    #[test]
+    fn test_warning_kind_display_matches_serde() {
+        for kind in [
+            WarningKind::StaleCache,
+            WarningKind::PartialResults,
+            WarningKind::Truncated,
+            WarningKind::Deprecated,
+            WarningKind::IgnoredFlag,
+            WarningKind::LargeOutput,
+            WarningKind::ClockSkew,
+        ] {
+            let json = serde_json::to_string(&kind).unwrap();
+            assert_eq!(json, format!("\"{}\"", kind));
+        }
+    }
+
+    #[test]
+    fn test_warn_records_globally() {
+        let before = count();
+        warn(WarningKind::Truncated, "test_warn_records_globally");
+        assert!(count() > before);
+        assert!(
+            collected()
+                .iter()
+                .any(|w| w.message == "test_warn_records_globally")
+        );
+    }
+}
@@ -0,0 +1,79 @@
+//! Raw API requests (`hawkop api`)
+
+use std::io::Read;
+use std::time::Duration;
+
+use log::debug;
+
+use crate::cli::args::GlobalOptions;
+use crate::cli::{ApiMethod, CommandContext, OutputFormat};
+use crate::error::{Error, Result};
+use crate::output::hints::hint;
+use crate::output::warnings::{WarningKind, warn};
+
+/// Send a request to `path` and print the JSON response
+///
+/// Uses the profile's credentials and API host through the same client as
+/// every other command (JWT refresh, rate limiting, retries). Responses only
+/// go through the cache with `--cache-ttl`, and only for GET requests.
+pub async fn request(
+    opts: &GlobalOptions,
+    method: ApiMethod,
+    path: &str,
+    body: Option<&str>,
+    cache_ttl: Option<u64>,
+) -> Result<()> {
+    let body = body.map(read_body).transpose()?;
+    let method = reqwest::Method::from(method);
+
+    let ttl = cache_ttl.filter(|_| {
+        if opts.no_cache {
+            warn(
+                WarningKind::IgnoredFlag,
+                "--cache-ttl is ignored with --no-cache",
+            );
+            false
+        } else if method != reqwest::Method::GET || body.is_some() {
+            warn(
+                WarningKind::IgnoredFlag,
+                "--cache-ttl only caches GET requests without --body",
+            );
+            false
+        } else {
+            true
+        }
+    });
+
+    let ctx = CommandContext::new(opts).await?;
+    debug!("Raw API request: {} {}", method, path);
+    let response = ctx
+        .client
+        .raw_request(
+            method.clone(),
+            path,
+            body.as_ref(),
+            ttl.map(Duration::from_secs),
+        )
+        .await?;
+
+    if response.is_null() && ctx.format != OutputFormat::Json {
+        hint!("{} {}: no response body", method, path);
+        return Ok(());
+    }
+    println!("{}", crate::output::json::format_raw_json(&response)?);
+    Ok(())
+}
+
+/// Read and parse the `--body` file (`-` for stdin)
+fn read_body(file: &str) -> Result<serde_json::Value> {
+    let text = if file == "-" {
+        let mut input = String::new();
+        std::io::stdin().read_to_string(&mut input)?;
+        input
+    } else {
+        std::fs::read_to_string(file)
+            .map_err(|e| Error::Other(format!("Failed to read {}: {}", file, e)))?
+    };
+    serde_json::from_str(&text)
+        .map_err(|e| Error::Other(format!("--body {} is not valid JSON: {}", file, e)))
+}
@@ -1,35 +1,91 @@
 //! Application management commands
 
+use std::collections::HashMap;
+use std::sync::Arc;
+
 use colored::Colorize;
 use log::debug;
 
+use crate::cache::CachedStackHawkClient;
 use crate::cli::OutputFormat;
 use crate::cli::args::GlobalOptions;
+use crate::cli::args::{AppStatus, AppView};
+use crate::cli::org::for_all_orgs;
 use crate::cli::{CommandContext, PaginationArgs};
 use crate::client::models::{Application, CreateApplicationRequest};
-use crate::client::{AppApi, ListingApi, PaginationParams, fetch_remaining_pages};
-use crate::error::Result;
+use crate::client::{
+    AppApi, ListingApi, PaginationParams, StackHawkClient, fetch_remaining_pages, max_concurrency,
+};
+use crate::error::{PromptResult, Result};
 use crate::git;
 use crate::models::AppDisplay;
+use crate::models::display::OrgScoped;
 use crate::output::Formattable;
+use crate::output::hints::hint;
 
 /// Page size for apps endpoint
 const APP_API_PAGE_SIZE: usize = 100;
 
-/// Max concurrent requests for parallel fetching
-const PARALLEL_FETCH_LIMIT: usize = 32;
-
 /// Run the app list command
+///
+/// With `all_orgs`, every accessible organization is listed (see
+/// [`for_all_orgs`]), each row tagged with its org.
 pub async fn list(
     opts: &GlobalOptions,
     app_type: Option<&str>,
+    status: Option<AppStatus>,
     pagination: &PaginationArgs,
+    all_orgs: bool,
 ) -> Result<()> {
     let ctx = CommandContext::new(opts).await?;
+    let limit = pagination.limit.unwrap_or(usize::MAX);
+
+    if all_orgs {
+        let fetched = for_all_orgs(&ctx, opts, |org| fetch_apps(&ctx.client, org.id, 0)).await?;
+        let display_apps: Vec<OrgScoped<AppDisplay>> = fetched
+            .into_iter()
+            .flat_map(|(org, apps)| {
+                filter_by_status(filter_by_type(apps, app_type), status)
+                    .into_iter()
+                    .map(move |app| OrgScoped::new(&org, AppDisplay::from(app)))
+            })
+            .take(limit)
+            .collect();
+        display_apps.print(ctx.format)?;
+        return Ok(());
+    }
+
     let org_id = ctx.require_org_id()?;
+    let all_apps = fetch_apps(
+        &ctx.client,
+        org_id.to_string(),
+        pagination.page.unwrap_or(0),
+    )
+    .await?;
+
+    // Apply type and status filters if specified
+    let filtered_apps = filter_by_status(filter_by_type(all_apps, app_type), status);
+    debug!("Apps after filters: {}", filtered_apps.len());
+
+    let display_apps: Vec<AppDisplay> = filtered_apps
+        .into_iter()
+        .take(limit)
+        .map(AppDisplay::from)
+        .collect();
+    display_apps.print(ctx.format)?;
+
+    Ok(())
+}
 
-    // Fetch apps using totalCount-based parallel pagination
-    let start_page = pagination.page.unwrap_or(0);
+/// Fetch an org's apps from `start_page` on
+///
+/// Uses totalCount-based parallel pagination: the first page reports how
+/// many apps there are, and the remaining pages are fetched in parallel.
+pub(crate) async fn fetch_apps(
+    client: &Arc<CachedStackHawkClient<StackHawkClient>>,
+    org_id: String,
+    start_page: usize,
+) -> Result<Vec<Application>> {
     let first_params = PaginationParams::new()
         .page_size(APP_API_PAGE_SIZE)
         .page(start_page);
@@ -39,10 +95,7 @@ pub async fn list(
         start_page, APP_API_PAGE_SIZE
     );
 
-    let first_response = ctx
-        .client
-        .list_apps_paged(org_id, Some(&first_params))
-        .await?;
+    let first_response = client.list_apps_paged(&org_id, Some(&first_params)).await?;
 
     let mut all_apps = first_response.items;
     debug!(
@@ -64,14 +117,13 @@ pub async fn list(
                     remaining_pages.len()
                 );
 
-                let client = ctx.client.clone();
-                let org = org_id.to_string();
+                let client = client.clone();
 
                 let remaining_apps = fetch_remaining_pages(
                     remaining_pages,
                     move |page| {
                         let c = client.clone();
-                        let o = org.clone();
+                        let o = org_id.clone();
                         async move {
                             let params = PaginationParams::new()
                                 .page_size(APP_API_PAGE_SIZE)
@@ -79,7 +131,7 @@ pub async fn list(
                             c.list_apps(&o, Some(&params)).await
                         }
                     },
-                    PARALLEL_FETCH_LIMIT,
+                    max_concurrency(),
                 )
                 .await?;
 
@@ -89,22 +141,7 @@ pub async fn list(
     }
 
     debug!("Total apps fetched: {}", all_apps.len());
-
-    // Apply type filter if specified
-    let filtered_apps = filter_by_type(all_apps, app_type);
-    debug!("Apps after type filter: {}", filtered_apps.len());
-
-    // Apply limit if specified
-    let limited_apps = if let Some(limit) = pagination.limit {
-        filtered_apps.into_iter().take(limit).collect()
-    } else {
-        filtered_apps
-    };
-
-    let display_apps: Vec<AppDisplay> = limited_apps.into_iter().map(AppDisplay::from).collect();
-    display_apps.print(ctx.format)?;
-
-    Ok(())
+    Ok(all_apps)
 }
 
 /// Run the app create command
@@ -119,7 +156,6 @@ pub async fn create(
     team_id: Option<&str>,
     repo_name: Option<&str>,
     repo_id: Option<&str>,
-    dry_run: bool,
 ) -> Result<()> {
     let ctx = CommandContext::new(opts).await?;
     let org_id = ctx.require_org_id()?;
@@ -160,7 +196,7 @@ pub async fn create(
 
     let wants_repo_link = repo_name.is_some() || repo_id.is_some();
 
-    if dry_run {
+    if ctx.dry_run {
         eprintln!("{}", "DRY RUN - no changes will be made".yellow());
         eprintln!();
         eprintln!("Would create application:");
@@ -267,7 +303,7 @@ pub async fn create(
                 "data": data,
                 "meta": meta
             });
-            println!("{}", serde_json::to_string_pretty(&output)?);
+            println!("{}", crate::output::json::format_raw_json(&output)?);
         }
         _ => {
             // stdout: just the app ID (pipeable)
@@ -305,27 +341,27 @@ pub async fn create(
                 Some(Err(e)) => {
                     eprintln!();
                     eprintln!("{} Could not link to repository: {}", "⚠".yellow(), e);
-                    eprintln!("→ hawkop repo link --app-id {} --repo <name>", app.id);
+                    hint!("→ hawkop repo link --app-id {} --repo <name>", app.id);
                 }
                 None => {}
             }
 
-            eprintln!();
+            hint!();
 
             // Smart nudge: suggest repo link if not already linking
             if link_result.is_none() {
                 if let Some(ref repo) = detected_repo {
-                    eprintln!("💡 Detected git repo: {}", repo.full_name());
-                    eprintln!(
+                    hint!("💡 Detected git repo: {}", repo.full_name());
+                    hint!(
                         "→ hawkop repo link --repo {} --app-id {}",
                         repo.full_name(),
                         app.id
                     );
                 } else {
-                    eprintln!("→ hawkop repo link --repo-id <uuid> --app-id {}", app.id);
+                    hint!("→ hawkop repo link --repo-id <uuid> --app-id {}", app.id);
                 }
             }
-            eprintln!("→ hawkop app list");
+            hint!("→ hawkop app list");
         }
     }
 
@@ -394,18 +430,22 @@ pub async fn get(opts: &GlobalOptions, app_id: Option<&str>, name: Option<&str>)
 
     match ctx.format {
         OutputFormat::Json => {
-            let output = serde_json::json!({
-                "data": app,
-                "meta": {
-                    "version": env!("CARGO_PKG_VERSION"),
-                    "timestamp": chrono::Utc::now().to_rfc3339()
-                }
-            });
-            println!("{}", serde_json::to_string_pretty(&output)?);
+            println!("{}", crate::output::json::format_json(&app)?);
         }
         _ => {
             let display = AppDetailDisplay::from(&app);
             vec![display].print(ctx.format)?;
+            if let Some(target) = &app.cloud_scan_target {
+                hint!(
+                    "\nScan target: {} (domain {})",
+                    target.target_url.as_deref().unwrap_or("--"),
+                    if target.is_domain_verified {
+                        "verified"
+                    } else {
+                        "not verified"
+                    }
+                );
+            }
         }
     }
 
@@ -413,7 +453,7 @@ pub async fn get(opts: &GlobalOptions, app_id: Option<&str>, name: Option<&str>)
 }
 
 /// Run the app update command
-pub async fn update(opts: &GlobalOptions, app_id: &str, name: &str, dry_run: bool) -> Result<()> {
+pub async fn update(opts: &GlobalOptions, app_id: &str, name: &str) -> Result<()> {
     use crate::cli::CommandContext;
 
     let ctx = CommandContext::new(opts).await?;
@@ -425,7 +465,7 @@ pub async fn update(opts: &GlobalOptions, app_id: &str, name: &str, dry_run: boo
         ));
     }
 
-    if dry_run {
+    if ctx.dry_run {
         // Fetch current app for display
         let current = ctx.client.get_app(app_id).await?;
         eprintln!("{}", "DRY RUN - no changes will be made".yellow());
@@ -443,14 +483,7 @@ pub async fn update(opts: &GlobalOptions, app_id: &str, name: &str, dry_run: boo
 
     match ctx.format {
         OutputFormat::Json => {
-            let output = serde_json::json!({
-                "data": app,
-                "meta": {
-                    "version": env!("CARGO_PKG_VERSION"),
-                    "timestamp": chrono::Utc::now().to_rfc3339()
-                }
-            });
-            println!("{}", serde_json::to_string_pretty(&output)?);
+            println!("{}", crate::output::json::format_json(&app)?);
         }
         _ => {
             eprintln!(
@@ -459,8 +492,8 @@ pub async fn update(opts: &GlobalOptions, app_id: &str, name: &str, dry_run: boo
                 app.name,
                 app.id
             );
-            eprintln!();
-            eprintln!("→ hawkop app get {}", app.id);
+            hint!();
+            hint!("→ hawkop app get {}", app.id);
         }
     }
 
@@ -489,7 +522,8 @@ pub async fn delete(opts: &GlobalOptions, app_id: &str, yes: bool) -> Result<()>
         let confirmed = Confirm::new()
             .with_prompt("Continue?")
             .default(false)
-            .interact()?;
+            .interact()
+            .prompt_result()?;
 
         if !confirmed {
             eprintln!("Cancelled.");
@@ -503,14 +537,8 @@ pub async fn delete(opts: &GlobalOptions, app_id: &str, yes: bool) -> Result<()>
 
     match ctx.format {
         OutputFormat::Json => {
-            let output = serde_json::json!({
-                "data": { "deleted": true, "applicationId": app_id },
-                "meta": {
-                    "version": env!("CARGO_PKG_VERSION"),
-                    "timestamp": chrono::Utc::now().to_rfc3339()
-                }
-            });
-            println!("{}", serde_json::to_string_pretty(&output)?);
+            let data = serde_json::json!({ "deleted": true, "applicationId": app_id });
+            println!("{}", crate::output::json::format_json(&data)?);
         }
         _ => {
             eprintln!(
@@ -519,14 +547,317 @@ pub async fn delete(opts: &GlobalOptions, app_id: &str, yes: bool) -> Result<()>
                 app.name,
                 app_id
             );
-            eprintln!();
-            eprintln!("→ hawkop app list");
+            hint!();
+            hint!("→ hawkop app list");
         }
     }
 
     Ok(())
 }
 
+/// Recent scans fetched per environment for `app view findings`
+const VIEW_SCANS_PER_ENV: usize = 25;
+
+/// Run the app view command
+///
+/// Summarizes each environment's most recent completed scan: open findings
+/// by severity and the change since the previous completed scan. Scans for
+/// each environment are fetched in parallel.
+pub async fn view(opts: &GlobalOptions, app: &str, view: AppView) -> Result<()> {
+    use futures::stream::{self, StreamExt};
+
+    use crate::client::{EnvironmentApi, ScanFilterParams};
+    use crate::models::display::app_findings::AppEnvFindings;
+    use crate::output::warnings::{WarningKind, warn};
+
+    let AppView::Findings = view;
+
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?;
+    let application = crate::cli::env::resolve_app(&ctx.client, org_id, app).await?;
+    let envs = ctx.client.list_environments(&application.id, None).await?;
+    debug!(
+        "Fetching recent scans for {} environment(s) of {}",
+        envs.len(),
+        application.id
+    );
+
+    let results: Vec<_> = stream::iter(envs.iter().map(|env| {
+        let client = ctx.client.clone();
+        let org = org_id.to_string();
+        let filters = ScanFilterParams::new()
+            .app_ids(vec![application.id.clone()])
+            .envs(vec![env.environment_name.clone()]);
+        let name = env.environment_name.clone();
+        async move {
+            let params = PaginationParams::new()
+                .page_size(VIEW_SCANS_PER_ENV)
+                .page(0);
+            let scans = client.list_scans(&org, Some(&params), Some(&filters)).await;
+            (name, scans)
+        }
+    }))
+    .buffered(max_concurrency())
+    .collect()
+    .await;
+
+    let mut rows = Vec::new();
+    let mut failed = Vec::new();
+    for (env, scans) in results {
+        match scans {
+            Ok(scans) => rows.push(AppEnvFindings::from_scans(&env, &scans)),
+            Err(e) => {
+                debug!("Failed to fetch scans for env {}: {}", env, e);
+                failed.push(env);
+            }
+        }
+    }
+    if !failed.is_empty() {
+        warn(
+            WarningKind::PartialResults,
+            format!(
+                "Scans could not be fetched for environment(s): {}",
+                failed.join(", ")
+            ),
+        );
+    }
+
+    match ctx.format {
+        OutputFormat::Json => rows.print(ctx.format)?,
+        OutputFormat::Pretty | OutputFormat::Table => {
+            println!("App: {} ({})", application.name.bold(), application.id);
+            println!();
+            rows.print(ctx.format)?;
+            println!();
+            println!(
+                "{}",
+                "Open = new + triaged findings in the latest completed scan; TREND compares with the previous one."
+                    .dimmed()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Run the app duplicates command
+///
+/// Groups applications with identical or near-identical names/hosts and
+/// suggests which one to keep. Team assignments are shown for context.
+pub async fn duplicates(opts: &GlobalOptions, csv_path: Option<&str>) -> Result<()> {
+    use crate::cli::team::fetch_all_apps;
+    use crate::models::display::app_duplicates::{
+        DuplicateCandidate, DuplicateRow, find_duplicates,
+    };
+
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?;
+
+    let (apps, teams_by_app) = tokio::try_join!(
+        fetch_all_apps(ctx.client.clone(), org_id),
+        fetch_teams_by_app(&ctx, org_id),
+    )?;
+    debug!("Checking {} apps for duplicates", apps.len());
+
+    let candidates: Vec<DuplicateCandidate> = apps
+        .iter()
+        .map(|app| {
+            let mut team_names = teams_by_app.get(&app.id).cloned().unwrap_or_default();
+            team_names.sort();
+            DuplicateCandidate::from_app(app, team_names)
+        })
+        .collect();
+
+    let groups = find_duplicates(candidates);
+    let rows: Vec<DuplicateRow> = groups.iter().flat_map(|g| g.rows()).collect();
+
+    if let Some(path) = csv_path {
+        std::fs::write(path, crate::output::csv::format_csv(&rows))?;
+        eprintln!(
+            "{} Wrote {} application(s) in {} group(s) to {}",
+            "✓".green(),
+            rows.len(),
+            groups.len(),
+            path
+        );
+    }
+
+    match ctx.format {
+        OutputFormat::Json => {
+            println!("{}", crate::output::json::format_json(&groups)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            if groups.is_empty() {
+                println!("No duplicate applications found ({} checked).", apps.len());
+                return Ok(());
+            }
+
+            rows.print(ctx.format)?;
+            println!();
+            println!(
+                "Found {} duplicate group(s) covering {} of {} applications.",
+                groups.len(),
+                rows.len(),
+                apps.len()
+            );
+            for group in &groups {
+                println!(
+                    "  {} {}",
+                    format!("[{}]", group.group).bold(),
+                    group.suggestion
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Team names assigned to each app, keyed by app ID
+///
+/// Team detail is the only source of assignments, so every team is fetched.
+/// Teams that fail to load are left out with a warning.
+async fn fetch_teams_by_app(
+    ctx: &CommandContext,
+    org_id: &str,
+) -> Result<HashMap<String, Vec<String>>> {
+    use futures::stream::{FuturesUnordered, StreamExt};
+
+    use crate::cli::team::fetch_all_teams;
+    use crate::client::TeamApi;
+    use crate::output::warnings::{WarningKind, warn};
+
+    let teams = fetch_all_teams(ctx.client.clone(), org_id).await?;
+    let mut futures: FuturesUnordered<_> = teams
+        .iter()
+        .map(|team| {
+            let c = ctx.client.clone();
+            let org = org_id.to_string();
+            let team_id = team.id.clone();
+            async move { c.get_team(&org, &team_id).await }
+        })
+        .collect();
+
+    let mut teams_by_app: HashMap<String, Vec<String>> = HashMap::new();
+    let mut failed = 0usize;
+    while let Some(result) = futures.next().await {
+        match result {
+            Ok(detail) => {
+                for app in &detail.applications {
+                    teams_by_app
+                        .entry(app.application_id.clone())
+                        .or_default()
+                        .push(detail.name.clone());
+                }
+            }
+            Err(e) => {
+                debug!("Failed to fetch team detail: {}", e);
+                failed += 1;
+            }
+        }
+    }
+    if failed > 0 {
+        warn(
+            WarningKind::PartialResults,
+            format!(
+                "Failed to fetch details for {} of {} teams; team assignments may be incomplete",
+                failed,
+                teams.len()
+            ),
+        );
+    }
+    Ok(teams_by_app)
+}
+
+/// Window `app export` fetches scans for in one pass when `--since` is not
+/// given
+pub const EXPORT_WINDOW: &str = "90d";
+
+/// Run the app export command
+///
+/// Joins apps, team assignments, and scans since `since` (fetched in
+/// parallel) into one inventory row per app. Apps not scanned in the window
+/// get their last scan looked up individually. With `output`, the rows are
+/// written to that file as CSV, or as JSON when it ends in `.json`.
+pub async fn export(opts: &GlobalOptions, output: Option<&str>, since: &str) -> Result<()> {
+    use std::collections::HashSet;
+
+    use crate::cli::scan::{fetch_all_scans, fetch_last_scans};
+    use crate::cli::team::fetch_all_apps;
+    use crate::client::ScanFilterParams;
+    use crate::models::display::app_inventory::AppInventoryRow;
+    use crate::output::warnings::{WarningKind, warn};
+
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?;
+    let filters = ScanFilterParams::new().start(crate::timeparse::parse_millis(since)?);
+
+    let (apps, teams_by_app, mut scans) = tokio::try_join!(
+        fetch_all_apps(ctx.client.clone(), org_id),
+        fetch_teams_by_app(&ctx, org_id),
+        fetch_all_scans(ctx.client.clone(), org_id, Some(filters)),
+    )?;
+    debug!(
+        "App export: {} apps, {} scans since {}",
+        apps.len(),
+        scans.len(),
+        since
+    );
+
+    let scanned: HashSet<&str> = scans
+        .iter()
+        .map(|s| s.scan.application_id.as_str())
+        .collect();
+    let mut unseen: Vec<&str> = apps
+        .iter()
+        .map(|app| app.id.as_str())
+        .filter(|id| !scanned.contains(id))
+        .collect();
+    unseen.sort_unstable();
+    unseen.dedup();
+    if !unseen.is_empty() {
+        hint!("Checking last scan for {} apps...", unseen.len());
+    }
+    let total = unseen.len();
+    let (last, failed) = fetch_last_scans(ctx.client.clone(), org_id, unseen, None).await;
+    if !failed.is_empty() {
+        warn(
+            WarningKind::PartialResults,
+            format!(
+                "Failed to fetch the last scan for {} of {} apps; they are shown as never scanned",
+                failed.len(),
+                total
+            ),
+        );
+    }
+    scans.extend(last.into_values());
+
+    let rows = AppInventoryRow::build(&apps, &teams_by_app, scans);
+
+    if let Some(path) = output {
+        let contents = if path.to_lowercase().ends_with(".json") {
+            crate::output::json::format_json(&rows)?
+        } else {
+            crate::output::csv::format_csv(&rows)
+        };
+        std::fs::write(path, contents)?;
+        eprintln!(
+            "{} Wrote {} application(s) to {}",
+            "✓".green(),
+            rows.len(),
+            path
+        );
+        return Ok(());
+    }
+
+    rows.print(ctx.format)?;
+    if ctx.format != OutputFormat::Json {
+        hint!("\nOpen = new + triaged findings in each environment's latest completed scan.");
+        hint!("→ hawkop app export -o apps.csv    # Save as CSV");
+    }
+    Ok(())
+}
+
 /// Filter applications by type (cloud or standard)
 fn filter_by_type(apps: Vec<Application>, app_type: Option<&str>) -> Vec<Application> {
     match app_type {
@@ -547,6 +878,17 @@ fn filter_by_type(apps: Vec<Application>, app_type: Option<&str>) -> Vec<Applica
     }
 }
 
+/// Filter applications by lifecycle status
+fn filter_by_status(apps: Vec<Application>, status: Option<AppStatus>) -> Vec<Application> {
+    match status {
+        Some(status) => apps
+            .into_iter()
+            .filter(|app| status.matches(app.status.as_deref()))
+            .collect(),
+        None => apps,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -674,4 +1016,28 @@ mod tests {
         let result = filter_by_type(apps, Some("cloud"));
         assert!(result.is_empty());
     }
+
+    // ========================================================================
+    // filter_by_status tests
+    // ========================================================================
+
+    #[test]
+    fn test_filter_by_status() {
+        let mut incomplete = make_app("2", "New App", None);
+        incomplete.status = Some("env_incomplete".to_string());
+        let mut unknown = make_app("3", "Unknown", None);
+        unknown.status = None;
+        let apps = vec![make_app("1", "App", None), incomplete, unknown];
+
+        assert_eq!(filter_by_status(apps.clone(), None).len(), 3);
+
+        let result = filter_by_status(apps.clone(), Some(AppStatus::EnvIncomplete));
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].id, "2");
+
+        // Apps without a status match neither filter
+        let result = filter_by_status(apps, Some(AppStatus::Active));
+        let ids: Vec<&str> = result.iter().map(|a| a.id.as_str()).collect();
+        assert_eq!(ids, ["1"]);
+    }
 }
@@ -20,3 +20,403 @@ pub enum OutputFormat {
     /// JSON format - structured for scripts/APIs
     Json,
 }
+
+/// Values accepted by `--format`
+///
+/// The output formats plus three report formats: JUnit XML, which only
+/// `scan gate` renders, and HAR and markdown, which only `scan get` renders.
+/// Handlers read [`OutputFormat`] from `GlobalOptions`, which records JUnit
+/// separately.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum FormatArg {
+    /// Pretty format - human-optimized rich formatting
+    Pretty,
+    /// Table format - machine-parseable, one row per entry
+    #[default]
+    Table,
+    /// JSON format - structured for scripts/APIs
+    Json,
+    /// JUnit XML - findings as test cases for CI (scan gate, gate release)
+    Junit,
+    /// HAR 1.2 - HTTP archive of a finding's request/response (scan get -m only)
+    Har,
+    /// GitHub-flavored markdown for PRs and issues (scan get only)
+    Markdown,
+}
+
+impl FormatArg {
+    /// The equivalent output format (`None` for JUnit, HAR, and markdown)
+    pub fn output_format(self) -> Option<OutputFormat> {
+        match self {
+            Self::Pretty => Some(OutputFormat::Pretty),
+            Self::Table => Some(OutputFormat::Table),
+            Self::Json => Some(OutputFormat::Json),
+            Self::Junit | Self::Har | Self::Markdown => None,
+        }
+    }
+}
+
+/// Dimension to group the report by
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    schemars::JsonSchema,
+    clap::ValueEnum,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportGroupBy {
+    /// One row per application
+    #[default]
+    App,
+    /// One row per severity level
+    Severity,
+    /// One row per plugin (vulnerability type)
+    Plugin,
+}
+
+impl std::fmt::Display for ReportGroupBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::App => write!(f, "app"),
+            Self::Severity => write!(f, "severity"),
+            Self::Plugin => write!(f, "plugin"),
+        }
+    }
+}
+
+/// Rollup for `scan list --group-by`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ScanGroupBy {
+    /// One row per application
+    App,
+}
+
+/// Sections of the alerts table in `scan get`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum AlertGroupBy {
+    /// Vulnerability category (e.g. Injection)
+    Category,
+    /// Severity level
+    Severity,
+    /// CWE identifier
+    Cwe,
+}
+
+/// Sort order for an alert's paths in `scan get --plugin-id`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum PathSortBy {
+    /// Path, then HTTP method
+    Uri,
+    /// HTTP method, then path
+    Method,
+    /// Triage status (new, triaged, false positive, accepted), then path
+    Status,
+}
+
+/// Finding fields `scan search` looks in
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, schemars::JsonSchema, clap::ValueEnum,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchField {
+    /// Alert (plugin) name
+    Name,
+    /// Path of the finding
+    Uri,
+    /// Evidence from the finding's HTTP message
+    Evidence,
+    /// Other info from the finding's HTTP message
+    OtherInfo,
+}
+
+impl SearchField {
+    /// Whether the field is in the finding's HTTP message (fetched separately)
+    pub fn in_message(self) -> bool {
+        matches!(self, Self::Evidence | Self::OtherInfo)
+    }
+}
+
+impl std::fmt::Display for SearchField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Name => write!(f, "name"),
+            Self::Uri => write!(f, "uri"),
+            Self::Evidence => write!(f, "evidence"),
+            Self::OtherInfo => write!(f, "other_info"),
+        }
+    }
+}
+
+/// Authentication block in a `config generate` template
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum AuthTemplate {
+    /// HTML form login with a session cookie
+    Form,
+    /// JSON login returning a bearer token
+    Json,
+    /// Bearer token supplied to the scan
+    Token,
+}
+
+/// Aggregated view shown by `app view`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum AppView {
+    /// Open findings per environment from the latest scans
+    #[default]
+    Findings,
+}
+
+/// Severity level a findings threshold or filter applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum ThresholdSeverity {
+    /// High severity findings
+    High,
+    /// Medium severity findings
+    Medium,
+    /// Low severity findings
+    Low,
+}
+
+impl ThresholdSeverity {
+    /// Severity name as used in the API's `severityStats`
+    pub fn api_name(self) -> &'static str {
+        match self {
+            Self::High => "High",
+            Self::Medium => "Medium",
+            Self::Low => "Low",
+        }
+    }
+}
+
+impl std::fmt::Display for ThresholdSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::High => write!(f, "high"),
+            Self::Medium => write!(f, "medium"),
+            Self::Low => write!(f, "low"),
+        }
+    }
+}
+
+/// Log verbosity for `--log-level`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    /// Debug plus every dependency's trace output
+    Trace,
+}
+
+impl LogLevel {
+    pub fn filter(self) -> log::LevelFilter {
+        match self {
+            Self::Error => log::LevelFilter::Error,
+            Self::Warn => log::LevelFilter::Warn,
+            Self::Info => log::LevelFilter::Info,
+            Self::Debug => log::LevelFilter::Debug,
+            Self::Trace => log::LevelFilter::Trace,
+        }
+    }
+}
+
+/// Application status, for `app list --status`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum AppStatus {
+    /// Set up and ready to scan
+    Active,
+    /// Created but missing an environment
+    EnvIncomplete,
+}
+
+impl AppStatus {
+    /// The API's `applicationStatus` value
+    pub fn as_api_str(self) -> &'static str {
+        match self {
+            Self::Active => "ACTIVE",
+            Self::EnvIncomplete => "ENV_INCOMPLETE",
+        }
+    }
+
+    /// Whether an app with API status `status` (`applicationStatus`) matches
+    pub fn matches(self, status: Option<&str>) -> bool {
+        status.is_some_and(|s| s.eq_ignore_ascii_case(self.as_api_str()))
+    }
+}
+
+/// Triage status of a finding path, as named on the command line
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum TriageStatus {
+    /// Not yet triaged
+    New,
+    /// Triaged / assigned for remediation
+    Triaged,
+    /// Marked as a false positive
+    FalsePositive,
+    /// Risk accepted
+    Accepted,
+}
+
+impl TriageStatus {
+    /// API status values (`ApplicationAlertUri.status`, `alertStatus`) this covers
+    pub fn api_names(self) -> &'static [&'static str] {
+        match self {
+            Self::New => &["UNKNOWN"],
+            Self::Triaged => &["PROMOTED"],
+            Self::FalsePositive => &["FALSE_POSITIVE"],
+            Self::Accepted => &["RISK_ACCEPTED", "ACCEPTED"],
+        }
+    }
+
+    /// Position of an API status value in triage order (unknown values last)
+    pub fn rank(api_status: &str) -> usize {
+        [
+            Self::New,
+            Self::Triaged,
+            Self::FalsePositive,
+            Self::Accepted,
+        ]
+        .iter()
+        .position(|s| {
+            s.api_names()
+                .iter()
+                .any(|n| n.eq_ignore_ascii_case(api_status))
+        })
+        .unwrap_or(usize::MAX)
+    }
+}
+
+/// Triage action applied by `finding triage-bulk`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TriageAction {
+    /// Mark the finding as a false positive
+    FalsePositive,
+    /// Accept the risk of the finding
+    Accepted,
+    /// Add a note without changing the triage status
+    Comment,
+}
+
+impl TriageAction {
+    /// Status value sent to the triage API
+    pub fn api_name(self) -> &'static str {
+        match self {
+            Self::FalsePositive => "FALSE_POSITIVE",
+            Self::Accepted => "RISK_ACCEPTED",
+            Self::Comment => "ADD_COMMENT",
+        }
+    }
+}
+
+impl std::fmt::Display for TriageAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FalsePositive => write!(f, "false-positive"),
+            Self::Accepted => write!(f, "accepted"),
+            Self::Comment => write!(f, "comment"),
+        }
+    }
+}
+
+impl std::str::FromStr for TriageAction {
+    type Err = String;
+
+    /// Accepts the CLI names and the API names, in any case
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_lowercase().replace('_', "-").as_str() {
+            "false-positive" => Ok(Self::FalsePositive),
+            "accepted" | "risk-accepted" => Ok(Self::Accepted),
+            "comment" | "add-comment" => Ok(Self::Comment),
+            other => Err(format!(
+                "unknown status '{}' (expected false-positive, accepted, or comment)",
+                other
+            )),
+        }
+    }
+}
+
+/// One `--fail-on` entry: fail when `severity` findings exceed `count`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FindingsThreshold {
+    pub severity: ThresholdSeverity,
+    pub count: u32,
+}
+
+impl std::str::FromStr for FindingsThreshold {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (severity, count) = s
+            .split_once(':')
+            .ok_or_else(|| format!("expected SEVERITY:COUNT (e.g. high:0), got '{}'", s))?;
+
+        let severity = match severity.trim().to_lowercase().as_str() {
+            "high" => ThresholdSeverity::High,
+            "medium" => ThresholdSeverity::Medium,
+            "low" => ThresholdSeverity::Low,
+            other => {
+                return Err(format!(
+                    "unknown severity '{}' (expected high, medium, or low)",
+                    other
+                ));
+            }
+        };
+        let count: u32 = count
+            .trim()
+            .parse()
+            .map_err(|_| format!("invalid count '{}' for {}", count.trim(), severity))?;
+
+        Ok(Self { severity, count })
+    }
+}
+
+/// HTTP methods accepted by `hawkop api`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ApiMethod {
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+}
+
+impl From<ApiMethod> for reqwest::Method {
+    fn from(method: ApiMethod) -> Self {
+        match method {
+            ApiMethod::Get => Self::GET,
+            ApiMethod::Post => Self::POST,
+            ApiMethod::Put => Self::PUT,
+            ApiMethod::Patch => Self::PATCH,
+            ApiMethod::Delete => Self::DELETE,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_findings_threshold() {
+        let t: FindingsThreshold = "High:1".parse().unwrap();
+        assert_eq!(t.severity, ThresholdSeverity::High);
+        assert_eq!(t.count, 1);
+        assert_eq!(
+            " medium : 10 ".parse::<FindingsThreshold>().unwrap().count,
+            10
+        );
+
+        assert!("high".parse::<FindingsThreshold>().is_err());
+        assert!("critical:1".parse::<FindingsThreshold>().is_err());
+        assert!("low:x".parse::<FindingsThreshold>().is_err());
+        assert_eq!("low:0".parse::<FindingsThreshold>().unwrap().count, 0);
+    }
+}
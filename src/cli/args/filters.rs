@@ -3,6 +3,8 @@
 use clap::Args;
 
 use super::SortDir;
+use super::common::{PathSortBy, ThresholdSeverity, TriageStatus};
+use crate::client::models::{ApplicationAlert, ApplicationAlertUri, ScanResult};
 
 /// Filter arguments for audit list command.
 #[derive(Args, Debug, Clone)]
@@ -23,11 +25,11 @@ pub struct AuditFilterArgs {
     #[arg(long)]
     pub email: Option<String>,
 
-    /// Start date (ISO or relative: 7d, 30d)
+    /// Start time (relative: 48h, 7d, 2w; ISO date/datetime; or epoch)
     #[arg(long)]
     pub since: Option<String>,
 
-    /// End date (ISO or relative: 7d, 30d)
+    /// End time (relative: 48h, 7d, 2w; ISO date/datetime; or epoch)
     #[arg(long)]
     pub until: Option<String>,
 
@@ -65,4 +67,349 @@ pub struct ScanFilterArgs {
     /// Filter by status (running, complete, failed)
     #[arg(long, short = 's')]
     pub status: Option<String>,
+
+    /// Only scans started at or after this time (48h, 7d, 2w, ISO, or epoch)
+    #[arg(long)]
+    pub since: Option<String>,
+
+    /// Only scans started at or before this time (48h, 7d, 2w, ISO, or epoch)
+    #[arg(long)]
+    pub until: Option<String>,
+
+    /// Filter by scan tag (`|` for OR, `*` wildcard; repeat for AND)
+    #[arg(long, value_name = "NAME=VALUE")]
+    pub tag: Vec<TagFilter>,
+}
+
+/// One `--tag` entry: a tag name and a value pattern
+///
+/// The pattern uses the API's syntax: `|` separates alternatives and `*`
+/// matches any run of characters (`branch=main|release-*`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagFilter {
+    pub name: String,
+    pub value: String,
+}
+
+impl TagFilter {
+    /// The `tag` query parameter value (`name:value`)
+    pub fn api_param(&self) -> String {
+        format!("{}:{}", self.name, self.value)
+    }
+
+    /// Whether a scan has a matching tag (in `tags` or `metadata.tags`)
+    ///
+    /// Case-insensitive, so it never drops a scan the API matched.
+    pub fn matches(&self, scan: &ScanResult) -> bool {
+        let matches = |name: &str, value: &str| {
+            name.eq_ignore_ascii_case(&self.name)
+                && self
+                    .value
+                    .split('|')
+                    .any(|pattern| glob_match(&pattern.to_lowercase(), &value.to_lowercase()))
+        };
+        scan.tags.iter().any(|t| matches(&t.name, &t.value))
+            || scan
+                .metadata
+                .as_ref()
+                .is_some_and(|m| m.tags.iter().any(|(k, v)| matches(k, v)))
+    }
+}
+
+impl std::str::FromStr for TagFilter {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (name, value) = s
+            .split_once('=')
+            .or_else(|| s.split_once(':'))
+            .ok_or_else(|| format!("expected NAME=VALUE (e.g. branch=main), got '{}'", s))?;
+        let (name, value) = (name.trim(), value.trim());
+        if name.is_empty() || value.is_empty() {
+            return Err(format!(
+                "expected NAME=VALUE (e.g. branch=main), got '{}'",
+                s
+            ));
+        }
+        Ok(Self {
+            name: name.to_string(),
+            value: value.to_string(),
+        })
+    }
+}
+
+/// Match `text` against a pattern where `*` matches any run of characters
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        // No wildcard: exact match
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(i) => rest = &rest[i + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
+/// Severity and triage-status filters for scan findings.
+///
+/// The alerts API has no filter parameters, so these are applied client-side
+/// to the alerts and paths it returns.
+#[derive(Args, Debug, Default, Clone)]
+pub struct AlertFilterArgs {
+    /// Only show findings of these severities (high, medium, low)
+    #[arg(long, value_enum, value_delimiter = ',', value_name = "SEVERITY")]
+    pub severity: Vec<ThresholdSeverity>,
+
+    /// Only show findings with paths in these triage states
+    /// (new, triaged, false-positive, accepted)
+    #[arg(long, value_enum, value_delimiter = ',', value_name = "STATUS")]
+    pub status: Vec<TriageStatus>,
+}
+
+impl AlertFilterArgs {
+    /// True when no filter was given
+    pub fn is_empty(&self) -> bool {
+        self.severity.is_empty() && self.status.is_empty()
+    }
+
+    /// Whether an API severity ("High", "Medium", "Low") passes `--severity`
+    pub fn matches_severity(&self, severity: &str) -> bool {
+        self.severity.is_empty()
+            || self
+                .severity
+                .iter()
+                .any(|s| s.api_name().eq_ignore_ascii_case(severity))
+    }
+
+    /// Whether an API triage status ("UNKNOWN", "PROMOTED", ...) passes `--status`
+    pub fn matches_status(&self, status: &str) -> bool {
+        self.status.is_empty()
+            || self
+                .status
+                .iter()
+                .any(|s| s.api_names().iter().any(|n| n.eq_ignore_ascii_case(status)))
+    }
+
+    /// Whether an alert passes: its severity matches and, with `--status`,
+    /// at least one of its paths is in a selected triage state
+    pub fn matches_alert(&self, alert: &ApplicationAlert) -> bool {
+        self.matches_severity(&alert.severity)
+            && (self.status.is_empty()
+                || alert
+                    .alert_status_stats
+                    .iter()
+                    .any(|s| s.total_count > 0 && self.matches_status(&s.alert_status)))
+    }
+
+    /// Keep only the alerts that pass the filters
+    pub fn filter_alerts(&self, alerts: Vec<ApplicationAlert>) -> Vec<ApplicationAlert> {
+        alerts
+            .into_iter()
+            .filter(|a| self.matches_alert(a))
+            .collect()
+    }
+
+    /// Keep only the paths whose triage status passes `--status`
+    pub fn filter_uris(&self, uris: &mut Vec<ApplicationAlertUri>) {
+        uris.retain(|u| self.matches_status(&u.status));
+    }
+}
+
+/// Method filter and sort order for an alert's paths (`scan get --plugin-id`)
+///
+/// Like `--status`, these apply client-side, so every path of the alert is
+/// fetched first and `--limit`/`--page` then page through the matching paths.
+#[derive(Args, Debug, Default, Clone)]
+pub struct AlertPathArgs {
+    /// Sort paths by uri, method, or status (default: API order)
+    #[arg(
+        long = "sort-by",
+        value_enum,
+        value_name = "FIELD",
+        requires = "plugin_id"
+    )]
+    pub sort_by: Option<PathSortBy>,
+
+    /// Only show paths requested with these HTTP methods (e.g. GET,POST)
+    #[arg(
+        long = "method",
+        value_delimiter = ',',
+        value_name = "METHOD",
+        requires = "plugin_id"
+    )]
+    pub methods: Vec<String>,
+}
+
+impl AlertPathArgs {
+    /// True when neither a method filter nor a sort order was given
+    pub fn is_empty(&self) -> bool {
+        self.sort_by.is_none() && self.methods.is_empty()
+    }
+
+    /// Keep the paths whose method passes `--method`, in `--sort-by` order
+    pub fn apply(&self, uris: &mut Vec<ApplicationAlertUri>) {
+        if !self.methods.is_empty() {
+            uris.retain(|u| {
+                self.methods
+                    .iter()
+                    .any(|m| m.trim().eq_ignore_ascii_case(&u.request_method))
+            });
+        }
+        let path = |u: &ApplicationAlertUri| (u.uri.clone(), u.request_method.to_uppercase());
+        match self.sort_by {
+            None => {}
+            Some(PathSortBy::Uri) => uris.sort_by_key(path),
+            Some(PathSortBy::Method) => uris.sort_by_key(|u| {
+                let (uri, method) = path(u);
+                (method, uri)
+            }),
+            Some(PathSortBy::Status) => {
+                uris.sort_by_key(|u| (TriageStatus::rank(&u.status), path(u)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::models::AlertStatusStats;
+
+    fn alert(severity: &str, statuses: &[(&str, u32)]) -> ApplicationAlert {
+        ApplicationAlert {
+            plugin_id: "40012".to_string(),
+            name: "Test".to_string(),
+            description: String::new(),
+            severity: severity.to_string(),
+            cwe_id: None,
+            references: vec![],
+            uri_count: 1,
+            alert_status_stats: statuses
+                .iter()
+                .map(|(status, count)| AlertStatusStats {
+                    alert_status: status.to_string(),
+                    total_count: *count,
+                    severity_stats: Default::default(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_alert_filters() {
+        let filters = AlertFilterArgs {
+            severity: vec![ThresholdSeverity::High, ThresholdSeverity::Medium],
+            status: vec![TriageStatus::New],
+        };
+        assert!(filters.matches_alert(&alert("High", &[("UNKNOWN", 2)])));
+        assert!(filters.matches_alert(&alert("Medium", &[("PROMOTED", 1), ("UNKNOWN", 1)])));
+        assert!(!filters.matches_alert(&alert("Low", &[("UNKNOWN", 2)])));
+        assert!(!filters.matches_alert(&alert("High", &[("PROMOTED", 3), ("UNKNOWN", 0)])));
+
+        let accepted = AlertFilterArgs {
+            status: vec![TriageStatus::Accepted],
+            ..Default::default()
+        };
+        assert!(accepted.matches_status("RISK_ACCEPTED"));
+        assert!(accepted.matches_status("ACCEPTED"));
+        assert!(!accepted.matches_status("FALSE_POSITIVE"));
+
+        let none = AlertFilterArgs::default();
+        assert!(none.is_empty());
+        assert!(none.matches_alert(&alert("Low", &[])));
+    }
+
+    #[test]
+    fn test_alert_path_args() {
+        let uri = |method: &str, path: &str, status: &str| -> ApplicationAlertUri {
+            serde_json::from_value(serde_json::json!({
+                "alertUriId": path, "uri": path, "requestMethod": method,
+                "msgId": "1", "pluginId": "40012", "status": status
+            }))
+            .unwrap()
+        };
+        let uris = vec![
+            uri("POST", "/b", "PROMOTED"),
+            uri("GET", "/c", "RISK_ACCEPTED"),
+            uri("GET", "/a", "UNKNOWN"),
+            uri("PUT", "/a", "UNKNOWN"),
+        ];
+        let order = |args: AlertPathArgs| {
+            let mut uris = uris.clone();
+            args.apply(&mut uris);
+            uris.iter()
+                .map(|u| format!("{} {}", u.request_method, u.uri))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            order(AlertPathArgs::default()),
+            ["POST /b", "GET /c", "GET /a", "PUT /a"]
+        );
+        assert_eq!(
+            order(AlertPathArgs {
+                sort_by: Some(PathSortBy::Uri),
+                methods: vec![]
+            }),
+            ["GET /a", "PUT /a", "POST /b", "GET /c"]
+        );
+        assert_eq!(
+            order(AlertPathArgs {
+                sort_by: Some(PathSortBy::Method),
+                methods: vec!["get".to_string(), "post".to_string()]
+            }),
+            ["GET /a", "GET /c", "POST /b"]
+        );
+        assert_eq!(
+            order(AlertPathArgs {
+                sort_by: Some(PathSortBy::Status),
+                methods: vec![]
+            }),
+            ["GET /a", "PUT /a", "POST /b", "GET /c"]
+        );
+    }
+
+    #[test]
+    fn test_tag_filter_parse_and_match() {
+        let filter: TagFilter = "branch=main|release-*".parse().unwrap();
+        assert_eq!(filter.api_param(), "branch:main|release-*");
+        assert_eq!(
+            "url=http://x".parse::<TagFilter>().unwrap().value,
+            "http://x"
+        );
+        assert!("branch".parse::<TagFilter>().is_err());
+        assert!("branch=".parse::<TagFilter>().is_err());
+
+        let scan = |name: &str, value: &str| {
+            let mut scan = crate::client::fixtures::ScanResultBuilder::new("s1", "a1").build();
+            scan.tags = vec![crate::client::models::ScanTag {
+                name: name.to_string(),
+                value: value.to_string(),
+            }];
+            scan
+        };
+        assert!(filter.matches(&scan("branch", "main")));
+        assert!(filter.matches(&scan("Branch", "release-1.2")));
+        assert!(!filter.matches(&scan("branch", "mainline")));
+        assert!(!filter.matches(&scan("tag", "main")));
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("feat*", "feature"));
+        assert!(glob_match("*-prod", "eu-prod"));
+        assert!(glob_match("a*b*c", "axxbyyc"));
+        assert!(glob_match("*", ""));
+        assert!(!glob_match("a*a", "a"));
+        assert!(!glob_match("main", "main2"));
+    }
 }
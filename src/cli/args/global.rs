@@ -3,7 +3,7 @@
 //! This module provides a centralized struct for global CLI options, eliminating
 //! the need to thread 6+ parameters through every command handler.
 
-use crate::cli::{Cli, OutputFormat};
+use crate::cli::{Cli, FormatArg, OutputFormat};
 
 /// Global CLI options passed to all command handlers.
 ///
@@ -21,10 +21,14 @@ pub struct GlobalOptions {
     /// Output format (pretty, table, json)
     pub format: OutputFormat,
 
+    /// `--format junit` was given; only `scan gate` and `gate release` render
+    /// it (`format` is then `Table`)
+    pub junit: bool,
+
     /// Organization ID override (bypasses config file)
     pub org: Option<String>,
 
-    /// Custom config file path (defaults to ~/.hawkop/config.yaml)
+    /// Custom config file path (defaults to the platform config directory)
     pub config: Option<String>,
 
     /// Profile name override (bypasses active_profile in config)
@@ -33,8 +37,20 @@ pub struct GlobalOptions {
     /// Bypass cache and fetch fresh data from API
     pub no_cache: bool,
 
+    /// Serve only cached data, ignoring TTLs; never contact the API
+    pub offline: bool,
+
+    /// Preview mutating commands without sending their API calls
+    pub dry_run: bool,
+
     /// Custom API host for development/testing
     pub api_host: Option<String>,
+
+    /// Ceiling for parallel page fetches (None uses the default)
+    pub max_concurrency: Option<usize>,
+
+    /// Seconds before the command is cancelled (None for no limit)
+    pub timeout: Option<u64>,
 }
 
 impl GlobalOptions {
@@ -43,12 +59,26 @@ impl GlobalOptions {
     /// This is the primary constructor, called once in main.rs after parsing.
     pub fn from_cli(cli: &Cli) -> Self {
         Self {
-            format: cli.format,
+            // --query filters JSON output, --post-to sends it, and
+            // --template renders it, so all three select JSON
+            format: if cli.json_query.is_some() || cli.post_to.is_some() || cli.template.is_some() {
+                OutputFormat::Json
+            } else {
+                cli.format.output_format().unwrap_or_default()
+            },
+            junit: cli.json_query.is_none()
+                && cli.post_to.is_none()
+                && cli.template.is_none()
+                && cli.format == FormatArg::Junit,
             org: cli.org.clone(),
             config: cli.config.clone(),
             profile: cli.profile.clone(),
             no_cache: cli.no_cache,
+            offline: cli.offline,
+            dry_run: cli.dry_run,
             api_host: cli.api_host.clone(),
+            max_concurrency: cli.max_concurrency.map(usize::from),
+            timeout: cli.timeout,
         }
     }
 
@@ -81,11 +111,16 @@ mod tests {
     fn test_global_options_accessors() {
         let opts = GlobalOptions {
             format: OutputFormat::Json,
+            junit: false,
             org: Some("org-123".to_string()),
             config: Some("/custom/path".to_string()),
             profile: Some("prod".to_string()),
             no_cache: true,
+            offline: false,
+            dry_run: false,
             api_host: Some("http://localhost:8080".to_string()),
+            max_concurrency: Some(8),
+            timeout: Some(30),
         };
 
         assert_eq!(opts.org_ref(), Some("org-123"));
@@ -99,11 +134,16 @@ mod tests {
     fn test_global_options_none_accessors() {
         let opts = GlobalOptions {
             format: OutputFormat::Pretty,
+            junit: false,
             org: None,
             config: None,
             profile: None,
             no_cache: false,
+            offline: false,
+            dry_run: false,
             api_host: None,
+            max_concurrency: None,
+            timeout: None,
         };
 
         assert_eq!(opts.org_ref(), None);
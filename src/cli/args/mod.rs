@@ -8,7 +8,11 @@ mod filters;
 mod global;
 mod pagination;
 
-pub use common::{OutputFormat, SortDir};
-pub use filters::{AuditFilterArgs, ScanFilterArgs};
+pub use common::{
+    AlertGroupBy, ApiMethod, AppStatus, AppView, AuthTemplate, FindingsThreshold, FormatArg,
+    LogLevel, OutputFormat, ReportGroupBy, ScanGroupBy, SearchField, SortDir, TriageAction,
+};
+pub(crate) use filters::glob_match;
+pub use filters::{AlertFilterArgs, AlertPathArgs, AuditFilterArgs, ScanFilterArgs};
 pub use global::GlobalOptions;
 pub use pagination::PaginationArgs;
@@ -33,6 +33,10 @@ pub struct PaginationArgs {
     /// Sort direction (asc, desc)
     #[arg(long, value_enum, hide_possible_values = true)]
     pub sort_dir: Option<SortDir>,
+
+    /// Fetch every page instead of stopping at the first
+    #[arg(long, conflicts_with_all = ["limit", "page"])]
+    pub all: bool,
 }
 
 impl PaginationArgs {
@@ -46,7 +50,7 @@ impl PaginationArgs {
         if let Some(limit) = self.limit {
             params = params.page_size(limit);
         }
-        // If no limit specified, PaginationParams defaults to MAX_PAGE_SIZE
+        // If no limit specified, PaginationParams uses the default page size
 
         if let Some(page) = self.page {
             params = params.page(page);
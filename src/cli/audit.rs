@@ -1,14 +1,22 @@
 //! Audit log management commands
 
-use chrono::{Duration, Utc};
+use chrono::{Local, Utc};
+use colored::Colorize;
+use log::debug;
 
 use crate::cli::args::GlobalOptions;
-use crate::cli::{AuditFilterArgs, CommandContext, SortDir};
+use crate::cli::{AuditFilterArgs, CommandContext, OutputFormat, SortDir};
 use crate::client::ListingApi;
 use crate::client::models::AuditFilterParams;
 use crate::error::Result;
 use crate::models::AuditDisplay;
+use crate::models::display::audit_summary::{AuditSummary, BusinessHours};
 use crate::output::Formattable;
+use crate::output::warnings::{WarningKind, warn};
+use crate::timeparse;
+
+/// Most audit records the API returns in one request
+pub(crate) const AUDIT_PAGE_MAX: usize = 1000;
 
 /// Run the audit list command
 pub async fn list(opts: &GlobalOptions, filters: &AuditFilterArgs) -> Result<()> {
@@ -26,6 +34,68 @@ pub async fn list(opts: &GlobalOptions, filters: &AuditFilterArgs) -> Result<()>
     Ok(())
 }
 
+/// Run the audit summary command
+///
+/// Reads the audit log for the window in one request and rolls it up by
+/// user and activity type, flagging unusual activity along the way.
+pub async fn summary(
+    opts: &GlobalOptions,
+    since: &str,
+    until: Option<&str>,
+    hours: &BusinessHours,
+    utc: bool,
+) -> Result<()> {
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?;
+
+    let mut filters = AuditFilterParams::new();
+    (filters.start, filters.end) = timeparse::parse_range(Some(since), until)?;
+    filters.sort_dir = Some("desc".to_string());
+    filters.page_size = Some(AUDIT_PAGE_MAX);
+
+    let records = ctx.client.list_audit(org_id, Some(&filters)).await?;
+    if records.len() >= AUDIT_PAGE_MAX {
+        warn(
+            WarningKind::Truncated,
+            format!(
+                "Only the latest {} audit records were read; use a shorter --since to see all activity",
+                AUDIT_PAGE_MAX
+            ),
+        );
+    }
+    debug!("Audit summary: {} records since {}", records.len(), since);
+
+    let summary = if utc {
+        AuditSummary::build(&records, since, hours, &Utc, "UTC")
+    } else {
+        AuditSummary::build(&records, since, hours, &Local, "local")
+    };
+
+    match ctx.format {
+        OutputFormat::Json => {
+            println!("{}", crate::output::json::format_json(&summary)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            println!(
+                "Audit activity since {} | {} records, {} users | business hours {} Mon-Fri ({})",
+                summary.since,
+                summary.records,
+                summary.users,
+                summary.business_hours,
+                summary.time_zone
+            );
+            println!("\n{} ({})", "Anomalies".bold(), summary.anomalies.len());
+            summary.anomalies.print(ctx.format)?;
+            println!("\n{}", "Activity by user".bold());
+            summary.by_user.print(ctx.format)?;
+            println!("\n{}", "Activity by type".bold());
+            summary.by_type.print(ctx.format)?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Convert CLI filter args to API filter params
 fn build_filter_params(args: &AuditFilterArgs) -> Result<AuditFilterParams> {
     let mut params = AuditFilterParams::new();
@@ -50,15 +120,9 @@ fn build_filter_params(args: &AuditFilterArgs) -> Result<AuditFilterParams> {
         params.email = Some(email.clone());
     }
 
-    // Parse start date (since)
-    if let Some(ref since) = args.since {
-        params.start = Some(parse_date_to_millis(since)?);
-    }
-
-    // Parse end date (until)
-    if let Some(ref until) = args.until {
-        params.end = Some(parse_date_to_millis(until)?);
-    }
+    // Parse date range (since / until)
+    (params.start, params.end) =
+        timeparse::parse_range(args.since.as_deref(), args.until.as_deref())?;
 
     // Sort direction
     params.sort_dir = Some(match args.sort_dir {
@@ -76,141 +140,9 @@ fn build_filter_params(args: &AuditFilterArgs) -> Result<AuditFilterParams> {
     Ok(params)
 }
 
-/// Parse date string to milliseconds timestamp.
-///
-/// Supports:
-/// - Relative: "7d" (7 days ago), "30d" (30 days ago), "1w" (1 week ago)
-/// - ISO date: "2024-01-15"
-/// - ISO datetime: "2024-01-15T10:30:00Z"
-fn parse_date_to_millis(date_str: &str) -> Result<i64> {
-    let now = Utc::now();
-
-    // Try relative format first (e.g., "7d", "30d", "1w")
-    if let Some(stripped) = date_str.strip_suffix('d')
-        && let Ok(days) = stripped.parse::<i64>()
-    {
-        let target = now - Duration::days(days);
-        return Ok(target.timestamp_millis());
-    }
-
-    if let Some(stripped) = date_str.strip_suffix('w')
-        && let Ok(weeks) = stripped.parse::<i64>()
-    {
-        let target = now - Duration::weeks(weeks);
-        return Ok(target.timestamp_millis());
-    }
-
-    if let Some(stripped) = date_str.strip_suffix('h')
-        && let Ok(hours) = stripped.parse::<i64>()
-    {
-        let target = now - Duration::hours(hours);
-        return Ok(target.timestamp_millis());
-    }
-
-    // Try ISO date format (YYYY-MM-DD)
-    if date_str.len() == 10
-        && date_str.chars().nth(4) == Some('-')
-        && let Ok(date) = chrono::NaiveDate::parse_from_str(date_str, "%Y-%m-%d")
-    {
-        let datetime = date.and_hms_opt(0, 0, 0).expect("valid time").and_utc();
-        return Ok(datetime.timestamp_millis());
-    }
-
-    // Try ISO datetime format
-    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(date_str) {
-        return Ok(dt.timestamp_millis());
-    }
-
-    // Try without timezone (assume UTC)
-    if let Ok(dt) = chrono::NaiveDateTime::parse_from_str(date_str, "%Y-%m-%dT%H:%M:%S") {
-        return Ok(dt.and_utc().timestamp_millis());
-    }
-
-    Err(crate::error::Error::Other(format!(
-        "Invalid date format: '{}'. Use relative (7d, 30d, 1w) or ISO format (YYYY-MM-DD)",
-        date_str
-    )))
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::TimeZone;
-
-    // ========================================================================
-    // parse_date_to_millis tests
-    // ========================================================================
-
-    #[test]
-    fn test_parse_date_relative_days() {
-        let now = Utc::now();
-
-        // 7 days ago
-        let result = parse_date_to_millis("7d").unwrap();
-        let expected = (now - Duration::days(7)).timestamp_millis();
-        // Allow 1 second tolerance for test timing
-        assert!((result - expected).abs() < 1000);
-
-        // 30 days ago
-        let result = parse_date_to_millis("30d").unwrap();
-        let expected = (now - Duration::days(30)).timestamp_millis();
-        assert!((result - expected).abs() < 1000);
-    }
-
-    #[test]
-    fn test_parse_date_relative_weeks() {
-        let now = Utc::now();
-
-        let result = parse_date_to_millis("1w").unwrap();
-        let expected = (now - Duration::weeks(1)).timestamp_millis();
-        assert!((result - expected).abs() < 1000);
-
-        let result = parse_date_to_millis("4w").unwrap();
-        let expected = (now - Duration::weeks(4)).timestamp_millis();
-        assert!((result - expected).abs() < 1000);
-    }
-
-    #[test]
-    fn test_parse_date_relative_hours() {
-        let now = Utc::now();
-
-        let result = parse_date_to_millis("24h").unwrap();
-        let expected = (now - Duration::hours(24)).timestamp_millis();
-        assert!((result - expected).abs() < 1000);
-    }
-
-    #[test]
-    fn test_parse_date_iso_date() {
-        let result = parse_date_to_millis("2024-01-15").unwrap();
-        let expected = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
-        assert_eq!(result, expected.timestamp_millis());
-    }
-
-    #[test]
-    fn test_parse_date_iso_datetime_with_tz() {
-        let result = parse_date_to_millis("2024-01-15T10:30:00Z").unwrap();
-        let expected = Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 0).unwrap();
-        assert_eq!(result, expected.timestamp_millis());
-    }
-
-    #[test]
-    fn test_parse_date_iso_datetime_without_tz() {
-        let result = parse_date_to_millis("2024-01-15T10:30:00").unwrap();
-        let expected = Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 0).unwrap();
-        assert_eq!(result, expected.timestamp_millis());
-    }
-
-    #[test]
-    fn test_parse_date_invalid_format() {
-        let result = parse_date_to_millis("invalid");
-        assert!(result.is_err());
-
-        let result = parse_date_to_millis("yesterday");
-        assert!(result.is_err());
-
-        let result = parse_date_to_millis("01-15-2024"); // wrong order
-        assert!(result.is_err());
-    }
 
     // ========================================================================
     // build_filter_params tests
@@ -0,0 +1,285 @@
+//! Authentication commands (`hawkop auth`)
+//!
+//! Manage the active profile's cached JWT explicitly. Normal commands
+//! refresh tokens automatically; these are for scripting and troubleshooting.
+//! Tokens are kept in the [`TokenStore`], not in the config file.
+
+use colored::Colorize;
+use dialoguer::{Password, theme::ColorfulTheme};
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use crate::cli::OutputFormat;
+use crate::cli::args::GlobalOptions;
+use crate::client::{AuthApi, StackHawkClient};
+use crate::config::{JwtToken, ProfileConfig, ProfiledConfig, TokenStore, is_zero};
+use crate::error::{ConfigError, PromptResult, Result};
+
+/// Token state reported by `auth status`
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct AuthStatus {
+    profile: String,
+    api_key_configured: bool,
+    token_cached: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expires_in_secs: Option<i64>,
+    needs_refresh: bool,
+    #[serde(skip_serializing_if = "is_zero")]
+    clock_skew_secs: i64,
+}
+
+/// Run the auth login command
+///
+/// Authenticates with `api_key` (or the profile's key, prompting if neither
+/// is set) and stores the resulting JWT. A new key is only saved once it has
+/// authenticated successfully.
+pub async fn login(opts: &GlobalOptions, api_key: Option<&str>) -> Result<()> {
+    let mut config = ProfiledConfig::load_at(opts.config_ref())?;
+    let (profile_name, profile) = config.resolve_profile(opts.profile_ref())?;
+
+    let key = match api_key
+        .map(str::to_string)
+        .or_else(|| profile.api_key.clone())
+    {
+        Some(key) => key,
+        None => Password::with_theme(&ColorfulTheme::default())
+            .with_prompt("Enter your StackHawk API key")
+            .interact()
+            .prompt_result()?,
+    };
+
+    let jwt = authenticate(opts, profile.api_host.clone(), &key).await?;
+
+    let stored = config.get_profile_mut(&profile_name)?;
+    if stored.api_key.as_deref() != Some(key.as_str()) {
+        stored.api_key = Some(key);
+        config.save_at(opts.config_ref())?;
+    }
+    let profile = config.get_profile(&profile_name)?;
+    token_store(opts, &config, profile)?.save(&jwt)?;
+
+    report_token(opts.format, &profile_name, "Logged in", &jwt)
+}
+
+/// Run the auth refresh command
+///
+/// Re-authenticates with the stored API key regardless of token expiry.
+pub async fn refresh(opts: &GlobalOptions) -> Result<()> {
+    let config = ProfiledConfig::load_at(opts.config_ref())?;
+    let (profile_name, profile) = config.resolve_profile(opts.profile_ref())?;
+    let key = profile.api_key.clone().ok_or(ConfigError::MissingApiKey)?;
+
+    let jwt = authenticate(opts, profile.api_host.clone(), &key).await?;
+    token_store(opts, &config, profile)?.save(&jwt)?;
+
+    report_token(opts.format, &profile_name, "Token refreshed", &jwt)
+}
+
+/// Run the auth logout command
+///
+/// Removes the cached JWT; with `forget_key`, also removes the API key.
+pub fn logout(opts: &GlobalOptions, forget_key: bool) -> Result<()> {
+    let mut config = ProfiledConfig::load_at(opts.config_ref())?;
+    let (profile_name, profile) = config.resolve_profile(opts.profile_ref())?;
+    let profile_name = profile_name.to_string();
+
+    let had_token = match token_store(opts, &config, profile) {
+        Ok(tokens) => tokens.remove()?,
+        Err(_) => false,
+    };
+    let had_key = forget_key
+        && config
+            .get_profile_mut(&profile_name)?
+            .api_key
+            .take()
+            .is_some();
+    if had_key {
+        config.save_at(opts.config_ref())?;
+    }
+
+    match opts.format {
+        OutputFormat::Json => {
+            let result = serde_json::json!({
+                "profile": profile_name,
+                "token_removed": had_token,
+                "api_key_removed": had_key,
+            });
+            println!("{}", crate::output::json::format_json(&result)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            if had_token {
+                println!(
+                    "{} Removed cached token for profile '{}'",
+                    "✓".green(),
+                    profile_name
+                );
+            } else {
+                println!("No cached token for profile '{}'", profile_name);
+            }
+            if had_key {
+                println!("{} Removed API key", "✓".green());
+                println!("  → Run 'hawkop auth login' to sign in again");
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Run the auth status command
+pub fn status(opts: &GlobalOptions) -> Result<()> {
+    let config = ProfiledConfig::load_at(opts.config_ref())?;
+    let (profile_name, profile) = config.resolve_profile(opts.profile_ref())?;
+
+    let jwt = token_store(opts, &config, profile)
+        .ok()
+        .and_then(|tokens| tokens.load());
+    let jwt = jwt.as_ref();
+    let status = AuthStatus {
+        profile: profile_name.to_string(),
+        api_key_configured: profile.api_key.is_some(),
+        token_cached: jwt.is_some(),
+        expires_at: jwt.map(|t| t.expires_at),
+        expires_in_secs: jwt.map(|t| t.remaining().num_seconds()),
+        needs_refresh: jwt.is_none_or(JwtToken::needs_refresh),
+        clock_skew_secs: jwt.map_or(0, |t| t.clock_skew_secs),
+    };
+
+    match opts.format {
+        OutputFormat::Json => {
+            println!("{}", crate::output::json::format_json(&status)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            println!("Profile: {}", status.profile.bold());
+            if status.api_key_configured {
+                println!("{} API key configured", "✓".green());
+            } else {
+                println!("{} API key not configured", "✗".red());
+                println!("  → Run 'hawkop auth login' to configure");
+            }
+
+            match jwt {
+                None => println!(
+                    "{} No cached token (will authenticate on next command)",
+                    "○".dimmed()
+                ),
+                Some(token) => {
+                    let remaining = token.remaining().num_seconds();
+                    let expires = format_expiry(token);
+                    if remaining <= 0 {
+                        println!(
+                            "{} Token expired {} ago ({})",
+                            "✗".red(),
+                            format_countdown(-remaining),
+                            expires
+                        );
+                    } else if status.needs_refresh {
+                        println!(
+                            "{} Token expires in {} ({}); will refresh on next command",
+                            "⚠".yellow(),
+                            format_countdown(remaining),
+                            expires
+                        );
+                    } else {
+                        println!(
+                            "{} Token valid, expires in {} ({})",
+                            "✓".green(),
+                            format_countdown(remaining),
+                            expires
+                        );
+                    }
+                    if token.clock_skew_secs != 0 {
+                        println!(
+                            "  {}",
+                            crate::client::models::describe_clock_skew(token.clock_skew_secs)
+                                .dimmed()
+                        );
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Token store for the profile's API key and host (or the `--api-host`
+/// override), as commands use it
+fn token_store(
+    opts: &GlobalOptions,
+    config: &ProfiledConfig,
+    profile: &ProfileConfig,
+) -> Result<TokenStore> {
+    TokenStore::for_profile(config, profile, opts.api_host_ref())
+        .ok_or_else(|| ConfigError::MissingApiKey.into())
+}
+
+/// Authenticate against the profile's API host (or the `--api-host` override)
+async fn authenticate(
+    opts: &GlobalOptions,
+    profile_host: Option<String>,
+    api_key: &str,
+) -> Result<JwtToken> {
+    let host = opts.api_host_ref().map(str::to_string).or(profile_host);
+    let client = StackHawkClient::with_host(Some(api_key.to_string()), host)?;
+    let jwt = client.authenticate(api_key).await?;
+    Ok((&jwt).into())
+}
+
+fn report_token(format: OutputFormat, profile: &str, action: &str, jwt: &JwtToken) -> Result<()> {
+    match format {
+        OutputFormat::Json => {
+            let result = serde_json::json!({
+                "profile": profile,
+                "expires_at": jwt.expires_at,
+                "expires_in_secs": jwt.remaining().num_seconds(),
+            });
+            println!("{}", crate::output::json::format_json(&result)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            println!(
+                "{} {} (profile '{}'); token expires in {} ({})",
+                "✓".green(),
+                action,
+                profile,
+                format_countdown(jwt.remaining().num_seconds()),
+                format_expiry(jwt)
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Local wall-clock time the token expires (skew-corrected)
+fn format_expiry(jwt: &JwtToken) -> String {
+    let local_expiry = jwt.expires_at - chrono::Duration::seconds(jwt.clock_skew_secs);
+    local_expiry
+        .with_timezone(&chrono::Local)
+        .format("%Y-%m-%d %H:%M:%S")
+        .to_string()
+}
+
+/// Compact countdown ("1h 5m", "4m 10s", "30s")
+fn format_countdown(secs: i64) -> String {
+    let secs = secs.max(0);
+    if secs >= 3600 {
+        format!("{}h {}m", secs / 3600, (secs % 3600) / 60)
+    } else if secs >= 60 {
+        format!("{}m {}s", secs / 60, secs % 60)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_countdown() {
+        assert_eq!(format_countdown(3900), "1h 5m");
+        assert_eq!(format_countdown(250), "4m 10s");
+        assert_eq!(format_countdown(30), "30s");
+        assert_eq!(format_countdown(-5), "0s");
+    }
+}
@@ -1,8 +1,17 @@
 //! Cache management commands
 
+use futures::stream::{self, StreamExt};
+
 use crate::cache::CacheStorage;
-use crate::cli::OutputFormat;
-use crate::error::Result;
+use crate::cli::args::GlobalOptions;
+use crate::cli::completions::CompletionWarmer;
+use crate::cli::{CommandContext, OutputFormat};
+use crate::client::parallel::max_concurrency;
+use crate::client::{ListingApi, PaginationParams, ScanDetailApi};
+use crate::error::{Error, Result};
+use crate::output::formatters::format_size;
+use crate::output::warnings::{WarningKind, warn};
+use crate::timeparse;
 
 /// Show cache status/statistics
 pub fn status(format: OutputFormat) -> Result<()> {
@@ -19,13 +28,14 @@ pub fn status(format: OutputFormat) -> Result<()> {
                 "expired_entries": stats.expired_entries,
                 "total_size_bytes": stats.total_size_bytes,
                 "total_size_human": format_size(stats.total_size_bytes),
+                "max_size_bytes": cache.max_size(),
                 "oldest_entry_timestamp": stats.oldest_entry,
                 "newest_entry_timestamp": stats.newest_entry,
                 "path": CacheStorage::cache_dir()
                     .map(|p| p.display().to_string())
                     .unwrap_or_else(|_| "unknown".to_string()),
             });
-            println!("{}", serde_json::to_string_pretty(&json)?);
+            println!("{}", crate::output::json::format_raw_json(&json)?);
         }
         _ => {
             let path = CacheStorage::cache_dir()
@@ -38,6 +48,9 @@ pub fn status(format: OutputFormat) -> Result<()> {
             println!("Valid entries:  {}", stats.valid_entries);
             println!("Expired:        {}", stats.expired_entries);
             println!("Total size:     {}", format_size(stats.total_size_bytes));
+            if let Some(max_size) = cache.max_size() {
+                println!("Size limit:     {}", format_size(max_size as usize));
+            }
 
             if let Some(oldest) = stats.oldest_entry {
                 let dt = chrono::DateTime::from_timestamp(oldest, 0)
@@ -79,7 +92,7 @@ pub fn clear(format: OutputFormat) -> Result<()> {
                 "entries_removed": stats.entries_removed,
                 "success": true,
             });
-            println!("{}", serde_json::to_string_pretty(&json)?);
+            println!("{}", crate::output::json::format_raw_json(&json)?);
         }
         _ => {
             if stats.entries_removed > 0 {
@@ -100,19 +113,126 @@ pub fn path() -> Result<()> {
     Ok(())
 }
 
-/// Format bytes as human-readable size
-fn format_size(bytes: usize) -> String {
-    const KB: usize = 1024;
-    const MB: usize = KB * 1024;
-    const GB: usize = MB * 1024;
-
-    if bytes >= GB {
-        format!("{:.2} GB", bytes as f64 / GB as f64)
-    } else if bytes >= MB {
-        format!("{:.2} MB", bytes as f64 / MB as f64)
-    } else if bytes >= KB {
-        format!("{:.2} KB", bytes as f64 / KB as f64)
-    } else {
-        format!("{} bytes", bytes)
+/// Pre-fetch the data shell completions use and store it in the completion
+/// cache
+///
+/// With no selection, warms everything. `--scans` covers recent scan IDs and
+/// the plugin IDs of those scans. Each kind is fetched in parallel; a kind
+/// that fails is reported and skipped.
+pub async fn warm(
+    opts: &GlobalOptions,
+    scans: bool,
+    apps: bool,
+    teams: bool,
+    ttl: Option<&str>,
+) -> Result<()> {
+    let ttl = ttl.map(timeparse::parse_duration).transpose()?;
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?;
+    let client = ctx.client.inner();
+    let warmer = CompletionWarmer::open(ttl)
+        .ok_or_else(|| Error::Other("Could not open the completion cache".to_string()))?;
+    let all = !(scans || apps || teams);
+
+    let warm_scans = async {
+        if !(all || scans) {
+            return Ok(None);
+        }
+        let pagination = PaginationParams::new().page_size(CompletionWarmer::scan_limit());
+        let recent = client.list_scans(org_id, Some(&pagination), None).await?;
+        let scan_count = warmer.scans(org_id, &recent);
+
+        let plugins: Vec<(String, Result<_>)> = stream::iter(&recent)
+            .map(|scan| async move {
+                let alerts = client.list_scan_alerts(&scan.scan.id, None).await;
+                (scan.scan.id.clone(), alerts)
+            })
+            .buffer_unordered(max_concurrency())
+            .collect()
+            .await;
+        let mut plugin_scans = 0;
+        for (scan_id, alerts) in plugins {
+            match alerts {
+                Ok(alerts) => {
+                    warmer.plugins(&scan_id, alerts);
+                    plugin_scans += 1;
+                }
+                Err(e) => warn(
+                    WarningKind::PartialResults,
+                    format!("Plugin IDs for scan {} not cached: {}", scan_id, e),
+                ),
+            }
+        }
+        Ok::<_, Error>(Some((scan_count, plugin_scans)))
+    };
+    let warm_apps = async {
+        if !(all || apps) {
+            return Ok(None);
+        }
+        let list = client.list_apps(org_id, None).await?;
+        Ok::<_, Error>(Some(warmer.apps(org_id, &list)))
+    };
+    let warm_teams = async {
+        if !(all || teams) {
+            return Ok(None);
+        }
+        let list = client.list_teams(org_id, None).await?;
+        Ok::<_, Error>(Some(warmer.teams(org_id, &list)))
+    };
+    let (scans, apps, teams) = tokio::join!(warm_scans, warm_apps, warm_teams);
+
+    let mut failed = Vec::new();
+    let scans = warmed("scans", scans, &mut failed);
+    let apps = warmed("apps", apps, &mut failed);
+    let teams = warmed("teams", teams, &mut failed);
+    if scans.is_none() && apps.is_none() && teams.is_none() {
+        return Err(Error::Other(
+            "Cache warm failed; no completions were cached".to_string(),
+        ));
     }
+
+    match opts.format {
+        OutputFormat::Json => {
+            let json = serde_json::json!({
+                "org_id": org_id,
+                "scans": scans.map(|(s, _)| s),
+                "plugin_scans": scans.map(|(_, p)| p),
+                "apps": apps,
+                "teams": teams,
+                "failed": failed,
+            });
+            println!("{}", crate::output::json::format_raw_json(&json)?);
+        }
+        _ => {
+            let mut parts = Vec::new();
+            if let Some((scans, plugin_scans)) = scans {
+                parts.push(format!("{} scans (plugin IDs for {})", scans, plugin_scans));
+            }
+            if let Some(apps) = apps {
+                parts.push(format!("{} apps", apps));
+            }
+            if let Some(teams) = teams {
+                parts.push(format!("{} teams", teams));
+            }
+            println!("Cached completions: {}", parts.join(", "));
+        }
+    }
+
+    Ok(())
+}
+
+/// What one kind of warming cached, warning (and noting the kind) on failure
+fn warmed<T>(
+    kind: &'static str,
+    result: Result<Option<T>>,
+    failed: &mut Vec<&'static str>,
+) -> Option<T> {
+    result.unwrap_or_else(|e| {
+        warn(
+            WarningKind::PartialResults,
+            format!("Completions for {} not cached: {}", kind, e),
+        );
+        failed.push(kind);
+        None
+    })
 }
@@ -1,22 +1,29 @@
 //! Dynamic shell completions for HawkOp CLI
 //!
-//! Provides TAB completion for scan IDs, app names, plugin IDs, and URI IDs
-//! by querying the StackHawk API at completion time. Results are cached locally
+//! Provides TAB completion for scan IDs, app names, plugin IDs, URI IDs, teams,
+//! users, repos, org IDs, and scan config names by querying the StackHawk API
+//! at completion time. Results are cached locally
 //! to improve responsiveness.
 //!
 //! Shell support:
 //! - Fish/Zsh: Full support with descriptions
-//! - Bash: Values only (no description display)
+//! - Bash: Values only (no description display), through `COMPLETE=bash` or
+//!   the `complete -C` script from `hawkop completion bash --dynamic`
 
+use std::io::Write;
 use std::sync::Arc;
 use std::time::Duration;
 
+use clap_complete::Shell;
 use clap_complete::engine::{ArgValueCandidates, CompletionCandidate};
 
 use crate::cache::{CacheStorage, CacheTtl, cache_key};
-use crate::client::models::JwtToken;
+use crate::client::models::{
+    Application, ApplicationAlert, JwtToken, ScanResult, Team, severity_rank,
+};
 use crate::client::{AuthApi, ListingApi, PaginationParams, ScanDetailApi, StackHawkClient};
-use crate::config::Config;
+use crate::config::crypto::PASSPHRASE_ENV;
+use crate::config::{Config, ProfileConfig, ProfiledConfig, TokenStore};
 
 /// Maximum number of completion candidates to return
 const MAX_COMPLETIONS: usize = 10;
@@ -38,27 +45,42 @@ fn blocking_runtime() -> Option<tokio::runtime::Runtime> {
 
 /// Load config and create authenticated client for completions.
 ///
+/// Uses the profile selected by `HAWKOP_CONFIG` / `HAWKOP_PROFILE` (else the
+/// active profile), with its API host. An encrypted config is only read
+/// with `HAWKOP_PASSPHRASE` set, since a completion can't prompt.
+///
 /// Returns None if config is missing or authentication fails.
 /// Completions should never break the shell, so all errors are silent.
-fn completion_context() -> Option<(Config, Arc<StackHawkClient>)> {
-    let config = Config::load().ok()?;
+fn completion_context() -> Option<(ProfileConfig, Arc<StackHawkClient>)> {
+    let path = std::env::var("HAWKOP_CONFIG").ok();
+    let sealed = ProfiledConfig::load_sealed_at(path.as_deref()).ok()?;
+    if sealed.is_encrypted() && std::env::var_os(PASSPHRASE_ENV).is_none() {
+        return None;
+    }
+    let profiled = ProfiledConfig::load_at(path.as_deref()).ok()?;
+    let profile_name = std::env::var("HAWKOP_PROFILE").ok();
+    let (_, config) = profiled.resolve_profile(profile_name.as_deref()).ok()?;
+    let config = config.clone();
     config.api_key.as_ref()?; // Require API key
 
-    let client = Arc::new(StackHawkClient::new(config.api_key.clone()).ok()?);
+    let client =
+        Arc::new(StackHawkClient::with_host(config.api_key.clone(), config.api_host.clone()).ok()?);
+
+    // Share the token other hawkop processes use for this key and host
+    let tokens = TokenStore::for_profile(&profiled, &config, None)?;
 
     // Set JWT if cached and valid
-    if !config.is_token_expired() {
-        if let Some(ref jwt) = config.jwt {
-            let rt = blocking_runtime()?;
-            let client_clone = client.clone();
-            let token = JwtToken {
-                token: jwt.token.clone(),
-                expires_at: jwt.expires_at,
-            };
-            rt.block_on(async move {
-                client_clone.set_jwt(token).await;
-            });
-        }
+    if let Some(jwt) = tokens.load().filter(|jwt| !jwt.needs_refresh()) {
+        let rt = blocking_runtime()?;
+        let client_clone = client.clone();
+        let token = JwtToken {
+            token: jwt.token,
+            expires_at: jwt.expires_at,
+            clock_skew_secs: jwt.clock_skew_secs,
+        };
+        rt.block_on(async move {
+            client_clone.set_jwt(token).await;
+        });
     } else {
         // Need to authenticate - do it with timeout
         let rt = blocking_runtime()?;
@@ -70,6 +92,7 @@ fn completion_context() -> Option<(Config, Arc<StackHawkClient>)> {
 
         match result {
             Ok(Ok(jwt)) => {
+                let _ = tokens.save(&(&jwt).into());
                 let rt = blocking_runtime()?;
                 let client_clone = client.clone();
                 rt.block_on(async move {
@@ -83,9 +106,12 @@ fn completion_context() -> Option<(Config, Arc<StackHawkClient>)> {
     Some((config, client))
 }
 
-/// Get or create cache storage for completions.
+/// Get or create cache storage for completions, with the config's cache
+/// settings applied.
 /// Returns None if cache cannot be opened (completions will work without caching).
 fn completion_cache() -> Option<CacheStorage> {
+    static CACHE_SETTINGS: std::sync::Once = std::sync::Once::new();
+    CACHE_SETTINGS.call_once(|| crate::cli::context::apply_cache_settings(None));
     CacheStorage::open().ok()
 }
 
@@ -112,6 +138,18 @@ fn set_cached<T: serde::Serialize>(
     }
 }
 
+/// Complete the `latest` alias followed by recent scan IDs.
+///
+/// Used by commands that resolve `latest` to the most recent scan.
+pub fn complete_scan_ids_or_latest() -> Vec<CompletionCandidate> {
+    let mut candidates = vec![
+        CompletionCandidate::new("latest")
+            .help(Some("Most recent scan (narrow with --app/--env)".into())),
+    ];
+    candidates.extend(complete_scan_ids());
+    candidates
+}
+
 /// Complete scan IDs with rich metadata.
 ///
 /// Format: `{scan_id}` with help `{app} | {env} | {status} | {date}`
@@ -131,7 +169,7 @@ pub fn complete_scan_ids() -> Vec<CompletionCandidate> {
     let cache = completion_cache();
     // Note: Shell completions don't have access to the API host configuration,
     // so we pass None. This creates a separate cache namespace for completions.
-    let cache_key = cache_key("complete_scan_ids", None, Some(org_id), &[]);
+    let cache_key = scan_ids_key(org_id);
 
     if let Some(ref c) = cache {
         let cached: Option<Vec<(String, String)>> = get_cached(c, &cache_key);
@@ -168,28 +206,7 @@ pub fn complete_scan_ids() -> Vec<CompletionCandidate> {
     };
 
     // Build completion data and cache it
-    let completion_data: Vec<(String, String)> = scans
-        .iter()
-        .map(|scan_result| {
-            let scan = &scan_result.scan;
-            let help = format!(
-                "{} | {} | {} | {}",
-                if scan.application_name.is_empty() {
-                    "--"
-                } else {
-                    &scan.application_name
-                },
-                if scan.env.is_empty() { "--" } else { &scan.env },
-                if scan.status.is_empty() {
-                    "--"
-                } else {
-                    &scan.status
-                },
-                format_timestamp(&scan.timestamp)
-            );
-            (scan.id.clone(), help)
-        })
-        .collect();
+    let completion_data = scan_completions(&scans);
 
     // Cache the results
     if let Some(ref c) = cache {
@@ -199,7 +216,7 @@ pub fn complete_scan_ids() -> Vec<CompletionCandidate> {
             &completion_data,
             "scans",
             Some(org_id),
-            CacheTtl::SCAN_LIST,
+            CacheTtl::SCAN_LIST.duration(),
         );
     }
 
@@ -226,7 +243,7 @@ pub fn complete_app_names() -> Vec<CompletionCandidate> {
 
     // Try cache first
     let cache = completion_cache();
-    let cache_key = cache_key("complete_app_names", None, Some(org_id), &[]);
+    let cache_key = app_names_key(org_id);
 
     if let Some(ref c) = cache {
         let cached: Option<Vec<(String, String)>> = get_cached(c, &cache_key);
@@ -257,18 +274,7 @@ pub fn complete_app_names() -> Vec<CompletionCandidate> {
     };
 
     // Build completion data and cache it
-    let completion_data: Vec<(String, String)> = apps
-        .into_iter()
-        .take(MAX_COMPLETIONS)
-        .map(|app| {
-            let help = format!(
-                "{} | {}",
-                app.env.as_deref().unwrap_or("--"),
-                app.status.as_deref().unwrap_or("--")
-            );
-            (app.name, help)
-        })
-        .collect();
+    let completion_data = app_name_completions(&apps);
 
     // Cache the results
     if let Some(ref c) = cache {
@@ -278,7 +284,7 @@ pub fn complete_app_names() -> Vec<CompletionCandidate> {
             &completion_data,
             "apps",
             Some(org_id),
-            CacheTtl::APPS,
+            CacheTtl::APPS.duration(),
         );
     }
 
@@ -339,12 +345,7 @@ pub fn complete_plugin_ids() -> Vec<CompletionCandidate> {
 
     // Try cache first (keyed by scan_id)
     let cache = completion_cache();
-    let cache_key = cache_key(
-        "complete_plugin_ids",
-        None,
-        None,
-        &[("scan_id", scan_id.as_str())],
-    );
+    let cache_key = plugin_ids_key(&scan_id);
 
     if let Some(ref c) = cache {
         let cached: Option<Vec<(String, String)>> = get_cached(c, &cache_key);
@@ -373,35 +374,12 @@ pub fn complete_plugin_ids() -> Vec<CompletionCandidate> {
         tokio::time::timeout(COMPLETION_TIMEOUT, client.list_scan_alerts(&scan_id, None)).await
     });
 
-    let mut alerts = match result {
+    let alerts = match result {
         Ok(Ok(alerts)) => alerts,
         _ => return vec![],
     };
 
-    // Sort by severity: High → Medium → Low (most critical first)
-    alerts.sort_by(|a, b| severity_rank(&a.severity).cmp(&severity_rank(&b.severity)));
-
-    // Build completion data with aligned columns
-    let completion_data: Vec<(String, String)> = alerts
-        .into_iter()
-        .take(MAX_COMPLETIONS)
-        .map(|alert| {
-            let path_word = if alert.uri_count == 1 {
-                "path"
-            } else {
-                "paths"
-            };
-            // Format: severity (padded) │ name │ count
-            let help = format!(
-                "{:6} │ {} │ {} {}",
-                alert.severity,
-                truncate_str(&alert.name, 32),
-                alert.uri_count,
-                path_word
-            );
-            (alert.plugin_id, help)
-        })
-        .collect();
+    let completion_data = plugin_completions(alerts);
 
     // Cache for 4 hours (scan findings are stable)
     if let Some(ref c) = cache {
@@ -411,7 +389,7 @@ pub fn complete_plugin_ids() -> Vec<CompletionCandidate> {
             &completion_data,
             "scan_alerts",
             None,
-            CacheTtl::COMPLETION_ALERTS,
+            CacheTtl::COMPLETION_ALERTS.duration(),
         );
     }
 
@@ -421,17 +399,6 @@ pub fn complete_plugin_ids() -> Vec<CompletionCandidate> {
         .collect()
 }
 
-/// Rank severity for sorting (lower = more severe)
-fn severity_rank(severity: &str) -> u8 {
-    match severity {
-        "High" => 0,
-        "Medium" => 1,
-        "Low" => 2,
-        "Informational" | "Info" => 3,
-        _ => 4,
-    }
-}
-
 /// Complete URI IDs for a specific scan.
 ///
 /// Parses command line to extract scan ID and optionally plugin ID,
@@ -533,7 +500,7 @@ pub fn complete_uri_ids() -> Vec<CompletionCandidate> {
                 &completion_data,
                 "scan_uri_ids",
                 None,
-                CacheTtl::COMPLETION_ALERTS,
+                CacheTtl::COMPLETION_ALERTS.duration(),
             );
         }
 
@@ -555,7 +522,7 @@ pub fn complete_uri_ids() -> Vec<CompletionCandidate> {
             &completion_data,
             "scan_uri_ids",
             None,
-            CacheTtl::COMPLETION_ALERTS,
+            CacheTtl::COMPLETION_ALERTS.duration(),
         );
     }
 
@@ -593,7 +560,7 @@ async fn complete_all_uri_ids_cached(
     }
 
     // Sort alerts by severity so we fetch high-severity first
-    alerts.sort_by(|a, b| severity_rank(&a.severity).cmp(&severity_rank(&b.severity)));
+    alerts.sort_by_key(|a| severity_rank(&a.severity));
 
     // Create streaming futures for each plugin (up to 10), preserving severity order
     let mut futures: FuturesUnordered<_> = alerts
@@ -700,11 +667,224 @@ fn format_timestamp(timestamp: &str) -> String {
         .unwrap_or_else(|| "--".to_string())
 }
 
+// ─── Completion data ─────────────────────────────────────────────────────
+//
+// Cache keys and `(value, help)` builders shared by the completers and
+// `hawkop cache warm`, so warmed entries are exactly what a completer reads.
+// Shell completions don't know the API host, so keys leave it out.
+
+fn scan_ids_key(org_id: &str) -> String {
+    cache_key("complete_scan_ids", None, Some(org_id), &[])
+}
+
+fn app_names_key(org_id: &str) -> String {
+    cache_key("complete_app_names", None, Some(org_id), &[])
+}
+
+fn app_ids_key(org_id: &str) -> String {
+    cache_key("complete_app_ids", None, Some(org_id), &[])
+}
+
+fn plugin_ids_key(scan_id: &str) -> String {
+    cache_key("complete_plugin_ids", None, None, &[("scan_id", scan_id)])
+}
+
+fn team_names_key(org_id: &str) -> String {
+    cache_key("complete_team_names", None, Some(org_id), &[])
+}
+
+/// Scan IDs with `{app} | {env} | {status} | {date}` help
+fn scan_completions(scans: &[ScanResult]) -> Vec<(String, String)> {
+    scans
+        .iter()
+        .map(|scan_result| {
+            let scan = &scan_result.scan;
+            let help = format!(
+                "{} | {} | {} | {}",
+                if scan.application_name.is_empty() {
+                    "--"
+                } else {
+                    &scan.application_name
+                },
+                if scan.env.is_empty() { "--" } else { &scan.env },
+                if scan.status.is_empty() {
+                    "--"
+                } else {
+                    &scan.status
+                },
+                format_timestamp(&scan.timestamp)
+            );
+            (scan.id.clone(), help)
+        })
+        .collect()
+}
+
+/// App names with `{env} | {status}` help
+fn app_name_completions(apps: &[Application]) -> Vec<(String, String)> {
+    apps.iter()
+        .take(MAX_COMPLETIONS)
+        .map(|app| {
+            let help = format!(
+                "{} | {}",
+                app.env.as_deref().unwrap_or("--"),
+                app.status.as_deref().unwrap_or("--")
+            );
+            (app.name.clone(), help)
+        })
+        .collect()
+}
+
+/// App IDs with `{name} | {env}` help
+fn app_id_completions(apps: &[Application]) -> Vec<(String, String)> {
+    apps.iter()
+        .take(MAX_COMPLETIONS)
+        .map(|app| {
+            let help = format!("{} | {}", app.name, app.env.as_deref().unwrap_or("--"));
+            (app.id.clone(), help)
+        })
+        .collect()
+}
+
+/// Plugin IDs, most severe first, with `{severity} │ {name} │ {count} paths` help
+fn plugin_completions(mut alerts: Vec<ApplicationAlert>) -> Vec<(String, String)> {
+    // Sort by severity: High → Medium → Low (most critical first)
+    alerts.sort_by_key(|a| severity_rank(&a.severity));
+
+    alerts
+        .into_iter()
+        .take(MAX_COMPLETIONS)
+        .map(|alert| {
+            let path_word = if alert.uri_count == 1 {
+                "path"
+            } else {
+                "paths"
+            };
+            // Format: severity (padded) │ name │ count
+            let help = format!(
+                "{:6} │ {} │ {} {}",
+                alert.severity,
+                truncate_str(&alert.name, 32),
+                alert.uri_count,
+                path_word
+            );
+            (alert.plugin_id, help)
+        })
+        .collect()
+}
+
+/// Team names with their ID as help, and team IDs with their name as help
+fn team_completions(teams: &[Team]) -> Vec<(String, String)> {
+    let mut completion_data = Vec::new();
+    for team in teams.iter().take(MAX_COMPLETIONS) {
+        completion_data.push((team.name.clone(), format!("ID: {}", team.id)));
+        completion_data.push((team.id.clone(), team.name.clone()));
+    }
+    completion_data
+}
+
+// ─── Cache warming ───────────────────────────────────────────────────────
+
+/// Writes completion entries fetched ahead of time by `hawkop cache warm`
+///
+/// Entries expire after the completer's own TTL unless `ttl` overrides it.
+pub struct CompletionWarmer {
+    cache: CacheStorage,
+    ttl: Option<Duration>,
+}
+
+impl CompletionWarmer {
+    /// Open the completion cache, with the config's cache settings applied
+    pub fn open(ttl: Option<Duration>) -> Option<Self> {
+        completion_cache().map(|cache| Self { cache, ttl })
+    }
+
+    /// How many recent scans the scan ID completer offers
+    pub fn scan_limit() -> usize {
+        MAX_COMPLETIONS
+    }
+
+    fn store(
+        &self,
+        key: &str,
+        data: &[(String, String)],
+        endpoint: &str,
+        org_id: Option<&str>,
+        ttl: CacheTtl,
+    ) -> usize {
+        set_cached(
+            &self.cache,
+            key,
+            &data,
+            endpoint,
+            org_id,
+            self.ttl.unwrap_or_else(|| ttl.duration()),
+        );
+        data.len()
+    }
+
+    /// Cache scan ID completions; returns the number of candidates
+    pub fn scans(&self, org_id: &str, scans: &[ScanResult]) -> usize {
+        self.store(
+            &scan_ids_key(org_id),
+            &scan_completions(scans),
+            "scans",
+            Some(org_id),
+            CacheTtl::SCAN_LIST,
+        )
+    }
+
+    /// Cache app name and app ID completions; returns the number of apps
+    pub fn apps(&self, org_id: &str, apps: &[Application]) -> usize {
+        self.store(
+            &app_ids_key(org_id),
+            &app_id_completions(apps),
+            "completions",
+            Some(org_id),
+            CacheTtl::APPS,
+        );
+        self.store(
+            &app_names_key(org_id),
+            &app_name_completions(apps),
+            "apps",
+            Some(org_id),
+            CacheTtl::APPS,
+        )
+    }
+
+    /// Cache plugin ID completions for a scan; returns the number of plugins
+    pub fn plugins(&self, scan_id: &str, alerts: Vec<ApplicationAlert>) -> usize {
+        self.store(
+            &plugin_ids_key(scan_id),
+            &plugin_completions(alerts),
+            "scan_alerts",
+            None,
+            CacheTtl::COMPLETION_ALERTS,
+        )
+    }
+
+    /// Cache team completions; returns the number of teams
+    pub fn teams(&self, org_id: &str, teams: &[Team]) -> usize {
+        self.store(
+            &team_names_key(org_id),
+            &team_completions(teams),
+            "teams",
+            Some(org_id),
+            CacheTtl::TEAMS,
+        );
+        teams.len().min(MAX_COMPLETIONS)
+    }
+}
+
 /// Create completion candidates for scan IDs.
 pub fn scan_id_candidates() -> ArgValueCandidates {
     ArgValueCandidates::new(complete_scan_ids)
 }
 
+/// Create completion candidates for scan IDs plus the `latest` alias.
+pub fn scan_id_or_latest_candidates() -> ArgValueCandidates {
+    ArgValueCandidates::new(complete_scan_ids_or_latest)
+}
+
 /// Create completion candidates for app names.
 pub fn app_name_candidates() -> ArgValueCandidates {
     ArgValueCandidates::new(complete_app_names)
@@ -738,7 +918,7 @@ pub fn complete_team_names() -> Vec<CompletionCandidate> {
 
     // Try cache first
     let cache = completion_cache();
-    let cache_key = cache_key("complete_team_names", None, Some(org_id), &[]);
+    let cache_key = team_names_key(org_id);
 
     if let Some(ref c) = cache {
         let cached: Option<Vec<(String, String)>> = get_cached(c, &cache_key);
@@ -768,14 +948,7 @@ pub fn complete_team_names() -> Vec<CompletionCandidate> {
         _ => return vec![],
     };
 
-    // Build completion data - offer both name and ID as completable values
-    let mut completion_data: Vec<(String, String)> = Vec::new();
-    for team in teams.iter().take(MAX_COMPLETIONS) {
-        // Team name with ID as help
-        completion_data.push((team.name.clone(), format!("ID: {}", team.id)));
-        // Team ID with name as help
-        completion_data.push((team.id.clone(), team.name.clone()));
-    }
+    let completion_data = team_completions(&teams);
 
     // Cache the results
     if let Some(ref c) = cache {
@@ -785,7 +958,7 @@ pub fn complete_team_names() -> Vec<CompletionCandidate> {
             &completion_data,
             "teams",
             Some(org_id),
-            CacheTtl::TEAMS,
+            CacheTtl::TEAMS.duration(),
         );
     }
 
@@ -863,7 +1036,7 @@ pub fn complete_user_emails() -> Vec<CompletionCandidate> {
             &completion_data,
             "users",
             Some(org_id),
-            CacheTtl::USERS,
+            CacheTtl::USERS.duration(),
         );
     }
 
@@ -897,7 +1070,7 @@ pub fn complete_app_ids() -> Vec<CompletionCandidate> {
     };
 
     let cache = completion_cache();
-    let cache_key = cache_key("complete_app_ids", None, Some(org_id), &[]);
+    let cache_key = app_ids_key(org_id);
 
     if let Some(ref c) = cache {
         let cached: Option<Vec<(String, String)>> = get_cached(c, &cache_key);
@@ -926,14 +1099,7 @@ pub fn complete_app_ids() -> Vec<CompletionCandidate> {
         _ => return vec![],
     };
 
-    let completion_data: Vec<(String, String)> = apps
-        .into_iter()
-        .take(MAX_COMPLETIONS)
-        .map(|app| {
-            let help = format!("{} | {}", app.name, app.env.as_deref().unwrap_or("--"));
-            (app.id, help)
-        })
-        .collect();
+    let completion_data = app_id_completions(&apps);
 
     if let Some(ref c) = cache {
         set_cached(
@@ -942,7 +1108,7 @@ pub fn complete_app_ids() -> Vec<CompletionCandidate> {
             &completion_data,
             "completions",
             Some(org_id),
-            CacheTtl::APPS,
+            CacheTtl::APPS.duration(),
         );
     }
 
@@ -1017,7 +1183,7 @@ pub fn complete_repo_ids() -> Vec<CompletionCandidate> {
             &completion_data,
             "completions",
             Some(org_id),
-            CacheTtl::REPOS,
+            CacheTtl::REPOS.duration(),
         );
     }
 
@@ -1086,7 +1252,7 @@ pub fn complete_repo_names() -> Vec<CompletionCandidate> {
             &completion_data,
             "completions",
             Some(org_id),
-            CacheTtl::REPOS,
+            CacheTtl::REPOS.duration(),
         );
     }
 
@@ -1105,3 +1271,325 @@ pub fn repo_id_candidates() -> ArgValueCandidates {
 pub fn repo_name_candidates() -> ArgValueCandidates {
     ArgValueCandidates::new(complete_repo_names)
 }
+
+// ─── Org ID completions ──────────────────────────────────────────────────
+
+/// Complete organization IDs with the org name as help text.
+///
+/// Organizations are per user, not per org, so the cache entry has no org ID.
+pub fn complete_org_ids() -> Vec<CompletionCandidate> {
+    if Config::load().is_err() {
+        return vec![];
+    }
+
+    let cache = completion_cache();
+    let cache_key = cache_key("complete_org_ids", None, None, &[]);
+
+    if let Some(ref c) = cache {
+        let cached: Option<Vec<(String, String)>> = get_cached(c, &cache_key);
+        if let Some(data) = cached {
+            return data
+                .into_iter()
+                .map(|(id, help)| CompletionCandidate::new(id).help(Some(help.into())))
+                .collect();
+        }
+    }
+
+    let Some((_, client)) = completion_context() else {
+        return vec![];
+    };
+
+    let Some(rt) = blocking_runtime() else {
+        return vec![];
+    };
+
+    let result =
+        rt.block_on(async { tokio::time::timeout(COMPLETION_TIMEOUT, client.list_orgs()).await });
+
+    let orgs = match result {
+        Ok(Ok(orgs)) => orgs,
+        _ => return vec![],
+    };
+
+    let completion_data: Vec<(String, String)> = orgs
+        .into_iter()
+        .take(MAX_COMPLETIONS)
+        .map(|org| (org.id, org.name))
+        .collect();
+
+    if let Some(ref c) = cache {
+        set_cached(
+            c,
+            &cache_key,
+            &completion_data,
+            "completions",
+            None,
+            CacheTtl::ORGS.duration(),
+        );
+    }
+
+    completion_data
+        .into_iter()
+        .map(|(id, help)| CompletionCandidate::new(id).help(Some(help.into())))
+        .collect()
+}
+
+/// Create completion candidates for org IDs.
+pub fn org_id_candidates() -> ArgValueCandidates {
+    ArgValueCandidates::new(complete_org_ids)
+}
+
+// ─── Scan config completions ─────────────────────────────────────────────
+
+/// Complete scan configuration names with the description as help text.
+pub fn complete_config_names() -> Vec<CompletionCandidate> {
+    let config = match Config::load().ok() {
+        Some(c) => c,
+        None => return vec![],
+    };
+
+    let Some(org_id) = config.org_id.as_ref() else {
+        return vec![];
+    };
+
+    let cache = completion_cache();
+    let cache_key = cache_key("complete_config_names", None, Some(org_id), &[]);
+
+    if let Some(ref c) = cache {
+        let cached: Option<Vec<(String, String)>> = get_cached(c, &cache_key);
+        if let Some(data) = cached {
+            return data
+                .into_iter()
+                .map(|(name, help)| CompletionCandidate::new(name).help(Some(help.into())))
+                .collect();
+        }
+    }
+
+    let Some((_, client)) = completion_context() else {
+        return vec![];
+    };
+
+    let Some(rt) = blocking_runtime() else {
+        return vec![];
+    };
+
+    let result = rt.block_on(async {
+        tokio::time::timeout(COMPLETION_TIMEOUT, client.list_scan_configs(org_id, None)).await
+    });
+
+    let configs = match result {
+        Ok(Ok(configs)) => configs,
+        _ => return vec![],
+    };
+
+    let completion_data: Vec<(String, String)> = configs
+        .into_iter()
+        .take(MAX_COMPLETIONS)
+        .map(|cfg| {
+            let help = cfg
+                .description
+                .as_deref()
+                .map(|d| truncate_str(d, 40))
+                .unwrap_or_else(|| "--".to_string());
+            (cfg.name, help)
+        })
+        .collect();
+
+    if let Some(ref c) = cache {
+        set_cached(
+            c,
+            &cache_key,
+            &completion_data,
+            "completions",
+            Some(org_id),
+            CacheTtl::SCAN_CONFIGS.duration(),
+        );
+    }
+
+    completion_data
+        .into_iter()
+        .map(|(name, help)| CompletionCandidate::new(name).help(Some(help.into())))
+        .collect()
+}
+
+/// Create completion candidates for scan config names.
+pub fn config_name_candidates() -> ArgValueCandidates {
+    ArgValueCandidates::new(complete_config_names)
+}
+
+// ============================================================================
+// Installable completion scripts (`hawkop completion <shell> --dynamic`)
+// ============================================================================
+
+/// Environment variable set by the bash `complete -C` wrapper
+const BASH_COMMAND_ENV: &str = "HAWKOP_COMPLETE_C";
+
+/// Write a dynamic completion script for `shell`
+///
+/// Bash gets a `complete -C` wrapper that calls back into hawkop with the
+/// command line, so org IDs, app names, scan IDs, and enum values complete
+/// as they do in fish and zsh. Other shells get clap's `COMPLETE=<shell>`
+/// registration script.
+pub fn write_dynamic_script(shell: Shell, buf: &mut dyn Write) -> std::io::Result<()> {
+    use clap_complete::env::{Elvish, EnvCompleter, Fish, Powershell, Zsh};
+
+    let bin = std::env::current_exe()
+        .ok()
+        .map(|path| path.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "hawkop".to_string());
+
+    let completer: &dyn EnvCompleter = match shell {
+        Shell::Bash => return write_bash_command_script(&bin, buf),
+        Shell::Zsh => &Zsh,
+        Shell::Fish => &Fish,
+        Shell::Elvish => &Elvish,
+        Shell::PowerShell => &Powershell,
+        _ => {
+            return Err(std::io::Error::other(format!(
+                "dynamic completions are not supported for {}",
+                shell
+            )));
+        }
+    };
+    completer.write_registration("COMPLETE", "hawkop", &bin, &bin, buf)
+}
+
+fn write_bash_command_script(bin: &str, buf: &mut dyn Write) -> std::io::Result<()> {
+    let bin = bin.replace('\'', r"'\''");
+    writeln!(
+        buf,
+        "\
+# hawkop dynamic completion for bash
+#
+# Candidates (org IDs, app names, scan IDs, enum values) are produced by
+# hawkop itself from the command line bash passes in COMP_LINE/COMP_POINT.
+_hawkop_complete_command() {{
+    {env}=bash '{bin}' \"$@\" 2>/dev/null
+}}
+complete -o nosort -o bashdefault -o default -C _hawkop_complete_command hawkop",
+        env = BASH_COMMAND_ENV,
+        bin = bin
+    )
+}
+
+/// Answer a bash `complete -C` request and exit, if this is one
+///
+/// Must run before the tokio runtime starts, like `CompleteEnv::complete`.
+pub fn complete_bash_command(cmd: impl FnOnce() -> clap::Command) {
+    if std::env::var_os(BASH_COMMAND_ENV).is_none() {
+        return;
+    }
+    let line = std::env::var("COMP_LINE").unwrap_or_default();
+    let point = std::env::var("COMP_POINT")
+        .ok()
+        .and_then(|p| p.parse().ok())
+        .unwrap_or(line.len());
+
+    let words = split_command_line(&line, point);
+    let index = words.len().saturating_sub(1);
+    let args = words.into_iter().map(std::ffi::OsString::from).collect();
+    let current_dir = std::env::current_dir().ok();
+
+    let mut cmd = cmd();
+    if let Ok(candidates) =
+        clap_complete::engine::complete(&mut cmd, args, index, current_dir.as_deref())
+    {
+        let mut stdout = std::io::stdout().lock();
+        for candidate in candidates.iter().filter(|c| !c.is_hide_set()) {
+            let _ = writeln!(stdout, "{}", candidate.get_value().to_string_lossy());
+        }
+    }
+    std::process::exit(0);
+}
+
+/// Split the command line up to the cursor into words
+///
+/// Quotes and backslash escapes are honored; a trailing space starts a new
+/// (empty) word, which is the one being completed.
+fn split_command_line(line: &str, point: usize) -> Vec<String> {
+    let line: String = line.chars().take(point).collect();
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut in_word = false;
+    let mut quote = None;
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some('"'), '\\') | (None, '\\') => {
+                if let Some(next) = chars.next() {
+                    word.push(next);
+                }
+                in_word = true;
+            }
+            (Some(_), c) => word.push(c),
+            (None, '\'' | '"') => {
+                quote = Some(c);
+                in_word = true;
+            }
+            (None, c) if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+            }
+            (None, c) => {
+                word.push(c);
+                in_word = true;
+            }
+        }
+    }
+    // The word under the cursor, possibly empty
+    words.push(word);
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plugin_completions_most_severe_first() {
+        let alerts: Vec<ApplicationAlert> = serde_json::from_value(serde_json::json!([
+            {"pluginId": "10020", "name": "Missing Header", "severity": "Low", "uriCount": 1},
+            {"pluginId": "40018", "name": "SQL Injection", "severity": "High", "uriCount": 3}
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            plugin_completions(alerts),
+            vec![
+                (
+                    "40018".to_string(),
+                    "High   │ SQL Injection │ 3 paths".to_string()
+                ),
+                (
+                    "10020".to_string(),
+                    "Low    │ Missing Header │ 1 path".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_command_line() {
+        assert_eq!(
+            split_command_line("hawkop app list --o", 19),
+            vec!["hawkop", "app", "list", "--o"]
+        );
+        assert_eq!(
+            split_command_line("hawkop scan get ", 16),
+            vec!["hawkop", "scan", "get", ""]
+        );
+        assert_eq!(
+            split_command_line("hawkop app get --app 'My App' --env x", 30),
+            vec!["hawkop", "app", "get", "--app", "My App", ""]
+        );
+        // Only the text before the cursor counts
+        assert_eq!(
+            split_command_line("hawkop org set abc", 10),
+            vec!["hawkop", "org"]
+        );
+    }
+}
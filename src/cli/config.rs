@@ -1,7 +1,8 @@
 //! Scan configuration management commands
 //!
-//! Provides list, get, set, delete, rename, and validate operations for
-//! organization scan configurations.
+//! Provides list, get, set, pull, push, delete, rename, and validate
+//! operations for organization scan configurations, and generates starter
+//! configurations for applications.
 
 use std::fs;
 use std::path::Path;
@@ -10,13 +11,22 @@ use colored::Colorize;
 use dialoguer::Confirm;
 
 use crate::cli::args::GlobalOptions;
+use crate::cli::context::lint_settings;
+use crate::cli::env::resolve_app;
 use crate::cli::handlers::run_list_command;
-use crate::cli::{CommandContext, OutputFormat, PaginationArgs};
-use crate::client::models::{ConfigType, ScanConfig, ValidatedAssetResponse};
-use crate::client::{ConfigApi, ListingApi};
-use crate::error::Result;
+use crate::cli::{AuthTemplate, CommandContext, OutputFormat, PaginationArgs};
+use crate::client::models::{ConfigType, ScanConfig, ValidatedAssetResponse, ValidationMarker};
+use crate::client::{ConfigApi, EnvironmentApi, ListingApi};
+use crate::config::lint::{ConfigIssue, IssueKind};
+use crate::config::scan_sync::ScanSync;
+use crate::config::scan_template::ScanTemplate;
+use crate::config::transfer::{Secrets, read_export};
+use crate::config::{Config, ProfiledConfig, migrate_config_dir};
+use crate::error::{ApiError, ConfigError, Error, PromptResult, Result};
 use crate::models::ConfigDisplay;
+use crate::output::hints::hint;
 use crate::output::json::format_json;
+use crate::validation;
 
 // ============================================================================
 // List Command
@@ -44,7 +54,7 @@ pub async fn get(opts: &GlobalOptions, name: &str, output: Option<&str>) -> Resu
     let ctx = CommandContext::new(opts).await?;
     let org_id = ctx.require_org_id()?;
 
-    eprintln!("{} Fetching configuration '{}'...", "→".blue(), name);
+    hint!("{} Fetching configuration '{}'...", "→".blue(), name);
 
     let content = ctx.client.get_scan_config(org_id, name).await?;
 
@@ -63,7 +73,7 @@ pub async fn get(opts: &GlobalOptions, name: &str, output: Option<&str>) -> Resu
                         "name": name,
                         "content": content
                     });
-                    println!("{}", serde_json::to_string_pretty(&wrapper)?);
+                    println!("{}", crate::output::json::format_raw_json(&wrapper)?);
                 }
                 _ => {
                     // Print raw YAML content
@@ -82,44 +92,18 @@ pub async fn get(opts: &GlobalOptions, name: &str, output: Option<&str>) -> Resu
 
 /// Create or update a configuration from a file
 pub async fn set(opts: &GlobalOptions, name: &str, file: &str) -> Result<()> {
-    let ctx = CommandContext::new(opts).await?;
-    let org_id = ctx.require_org_id()?;
-
-    // Read the file content
-    let path = Path::new(file);
-    if !path.exists() {
-        return Err(crate::error::Error::Other(format!(
-            "File not found: {}",
-            file
-        )));
-    }
-
-    let content = fs::read_to_string(path)?;
-
-    // Validate before uploading
-    eprintln!(
-        "{} Validating configuration '{}'...",
-        "→".blue(),
-        path.display()
-    );
+    let content = read_config_file(file)?;
 
-    let validation = ctx.client.validate_scan_config(org_id, &content).await?;
+    // Catch structural problems locally before touching the API
+    let local = check_local(&content, file)?;
 
-    if !validation.is_valid() {
-        print_validation_results(&validation, Some(file));
-        return Err(crate::error::Error::Other(
-            "Configuration has validation errors. Fix errors before uploading.".to_string(),
-        ));
-    }
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?;
 
-    // Show warnings if any
-    if !validation.warnings().is_empty() {
-        print_validation_results(&validation, Some(file));
-        eprintln!();
-    }
+    check_remote(&ctx, org_id, &content, file, local).await?;
 
     // Upload the configuration
-    eprintln!("{} Uploading configuration '{}'...", "→".blue(), name);
+    hint!("{} Uploading configuration '{}'...", "→".blue(), name);
 
     ctx.client
         .set_scan_config(org_id, name, &content, ConfigType::Org)
@@ -130,7 +114,7 @@ pub async fn set(opts: &GlobalOptions, name: &str, file: &str) -> Result<()> {
         "✓".green(),
         name
     );
-    eprintln!(
+    hint!(
         "{}",
         format!("→ Reference in stackhawk.yml with: hawk://{}", name).dimmed()
     );
@@ -138,6 +122,205 @@ pub async fn set(opts: &GlobalOptions, name: &str, file: &str) -> Result<()> {
     Ok(())
 }
 
+// ============================================================================
+// Pull / Push Commands
+// ============================================================================
+
+/// Download a configuration to a local file and record it for `config push`
+///
+/// Refuses to overwrite a file with changes that were never pushed, or one
+/// that wasn't pulled from this config, unless `force` is set.
+pub async fn pull(
+    opts: &GlobalOptions,
+    name: &str,
+    output: Option<&str>,
+    force: bool,
+) -> Result<()> {
+    let file = output
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("{}.yml", name));
+    let path = Path::new(&file);
+    let sync = ScanSync::open(opts.config_ref())?;
+
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?;
+
+    hint!("{} Fetching configuration '{}'...", "→".blue(), name);
+    let remote = ctx.client.inner().get_scan_config(org_id, name).await?;
+
+    let local = path.exists().then(|| read_config_file(&file)).transpose()?;
+    if let Some(local) = local.as_deref().filter(|local| *local != remote) {
+        let base = sync
+            .get(path)?
+            .filter(|r| r.name == name && r.org_id == org_id);
+        let unpushed = match &base {
+            Some(record) => local != record.base,
+            None => true,
+        };
+        if unpushed && !force {
+            let message = match &base {
+                Some(record) => {
+                    print_diff(
+                        "Local changes not pushed",
+                        &record.base,
+                        local,
+                        "pulled",
+                        &file,
+                    );
+                    format!(
+                        "{} has changes that were never pushed; push them with `hawkop config push {}`, or pull with --force to discard them",
+                        file, file
+                    )
+                }
+                None => {
+                    print_diff("Pulling would change", local, &remote, &file, name);
+                    format!(
+                        "{} exists and wasn't pulled from '{}'; pull with --force to replace it",
+                        file, name
+                    )
+                }
+            };
+            return Err(Error::Other(message));
+        }
+    }
+
+    let updated = local.as_deref() != Some(remote.as_str());
+    if updated {
+        fs::write(path, &remote)?;
+    }
+    sync.record(path, name, org_id, &remote)?;
+
+    match opts.format {
+        OutputFormat::Json => {
+            let json = serde_json::json!({
+                "name": name,
+                "file": file,
+                "org_id": org_id,
+                "updated": updated,
+            });
+            println!("{}", crate::output::json::format_raw_json(&json)?);
+        }
+        _ if updated => {
+            eprintln!("{} Configuration '{}' saved to {}", "✓".green(), name, file)
+        }
+        _ => eprintln!("{} {} is up to date with '{}'", "✓".green(), file, name),
+    }
+    Ok(())
+}
+
+/// Upload a local file as a configuration
+///
+/// The name defaults to the config the file was pulled from. When the
+/// platform's copy changed since the last pull, the three versions are
+/// diffed and the push is refused unless `force` is set.
+pub async fn push(opts: &GlobalOptions, file: &str, name: Option<&str>, force: bool) -> Result<()> {
+    let content = read_config_file(file)?;
+    let path = Path::new(file);
+    let sync = ScanSync::open(opts.config_ref())?;
+    let record = sync.get(path)?;
+    let name = match name.or(record.as_ref().map(|r| r.name.as_str())) {
+        Some(name) => name.to_string(),
+        None => {
+            return Err(Error::Other(format!(
+                "No configuration name for {}: it wasn't pulled with `hawkop config pull`; pass --name",
+                file
+            )));
+        }
+    };
+
+    let local = check_local(&content, file)?;
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?;
+    let base = record
+        .filter(|r| r.name == name && r.org_id == org_id)
+        .map(|r| r.base);
+
+    hint!("{} Fetching configuration '{}'...", "→".blue(), name);
+    let remote = match ctx.client.inner().get_scan_config(org_id, &name).await {
+        Ok(remote) => Some(remote),
+        Err(Error::Api(ApiError::NotFound(_))) => None,
+        Err(e) => return Err(e),
+    };
+
+    let pushed = if remote.as_deref() == Some(content.as_str()) {
+        false
+    } else {
+        match (&base, &remote) {
+            (Some(base), Some(remote)) if remote != base => {
+                print_diff(
+                    "Changed on the platform since pull",
+                    base,
+                    remote,
+                    "pulled",
+                    &name,
+                );
+                print_diff("Local changes", base, &content, "pulled", file);
+                if !force {
+                    return Err(Error::Other(format!(
+                        "'{}' changed on the platform since {} was pulled; pull it again and reapply your changes, or push with --force to overwrite",
+                        name, file
+                    )));
+                }
+            }
+            (None, Some(remote)) if !force => {
+                print_diff("Pushing would change", remote, &content, &name, file);
+                return Err(Error::Other(format!(
+                    "'{}' already exists and {} wasn't pulled from it; review the diff and push with --force to replace it",
+                    name, file
+                )));
+            }
+            (_, Some(remote)) => print_diff("Pushing", remote, &content, &name, file),
+            (_, None) => {}
+        }
+
+        check_remote(&ctx, org_id, &content, file, local).await?;
+        hint!("{} Uploading configuration '{}'...", "→".blue(), name);
+        ctx.client
+            .set_scan_config(org_id, &name, &content, ConfigType::Org)
+            .await?;
+        true
+    };
+    sync.record(path, &name, org_id, &content)?;
+
+    match opts.format {
+        OutputFormat::Json => {
+            let json = serde_json::json!({
+                "name": name,
+                "file": file,
+                "org_id": org_id,
+                "created": remote.is_none(),
+                "pushed": pushed,
+            });
+            println!("{}", crate::output::json::format_raw_json(&json)?);
+        }
+        _ if !pushed => eprintln!("{} '{}' is up to date with {}", "✓".green(), name, file),
+        _ if remote.is_none() => {
+            eprintln!(
+                "{} Configuration '{}' created from {}",
+                "✓".green(),
+                name,
+                file
+            )
+        }
+        _ => eprintln!(
+            "{} Configuration '{}' updated from {}",
+            "✓".green(),
+            name,
+            file
+        ),
+    }
+    Ok(())
+}
+
+/// Print a titled diff to stderr (nothing when the sides are the same)
+fn print_diff(title: &str, old: &str, new: &str, old_label: &str, new_label: &str) {
+    if let Some(diff) = crate::output::diff::unified(old, new, old_label, new_label) {
+        eprintln!("{}", title.bold());
+        eprint!("{}", diff);
+        eprintln!();
+    }
+}
+
 // ============================================================================
 // Delete Command
 // ============================================================================
@@ -155,7 +338,8 @@ pub async fn delete(opts: &GlobalOptions, name: &str, yes: bool) -> Result<()> {
                 name
             ))
             .default(false)
-            .interact()?;
+            .interact()
+            .prompt_result()?;
 
         if !confirmed {
             eprintln!("{}", "Cancelled".yellow());
@@ -163,7 +347,7 @@ pub async fn delete(opts: &GlobalOptions, name: &str, yes: bool) -> Result<()> {
         }
     }
 
-    eprintln!("{} Deleting configuration '{}'...", "→".blue(), name);
+    hint!("{} Deleting configuration '{}'...", "→".blue(), name);
 
     ctx.client.delete_scan_config(org_id, name).await?;
 
@@ -181,7 +365,7 @@ pub async fn rename(opts: &GlobalOptions, old_name: &str, new_name: &str) -> Res
     let ctx = CommandContext::new(opts).await?;
     let org_id = ctx.require_org_id()?;
 
-    eprintln!(
+    hint!(
         "{} Renaming configuration '{}' to '{}'...",
         "→".blue(),
         old_name,
@@ -198,7 +382,7 @@ pub async fn rename(opts: &GlobalOptions, old_name: &str, new_name: &str) -> Res
         old_name,
         new_name
     );
-    eprintln!(
+    hint!(
         "{}",
         format!(
             "→ Update references from hawk://{} to hawk://{}",
@@ -215,28 +399,28 @@ pub async fn rename(opts: &GlobalOptions, old_name: &str, new_name: &str) -> Res
 // ============================================================================
 
 /// Validate a configuration (either stored or local file)
-pub async fn validate(opts: &GlobalOptions, name: Option<&str>, file: Option<&str>) -> Result<()> {
-    let ctx = CommandContext::new(opts).await?;
-    let org_id = ctx.require_org_id()?;
+pub async fn validate(
+    opts: &GlobalOptions,
+    name: Option<&str>,
+    file: Option<&str>,
+    local_only: bool,
+) -> Result<()> {
+    // A local file checked with --local needs no credentials or network
+    let ctx = match (name, local_only) {
+        (None, true) => None,
+        _ => Some(CommandContext::new(opts).await?),
+    };
 
     // Get content from either stored config or local file
     let (content, source) = match (name, file) {
         (Some(config_name), None) => {
-            eprintln!("{} Fetching configuration '{}'...", "→".blue(), config_name);
+            let ctx = ctx.as_ref().expect("context for stored config");
+            let org_id = ctx.require_org_id()?;
+            hint!("{} Fetching configuration '{}'...", "→".blue(), config_name);
             let content = ctx.client.get_scan_config(org_id, config_name).await?;
             (content, config_name.to_string())
         }
-        (None, Some(file_path)) => {
-            let path = Path::new(file_path);
-            if !path.exists() {
-                return Err(crate::error::Error::Other(format!(
-                    "File not found: {}",
-                    file_path
-                )));
-            }
-            let content = fs::read_to_string(path)?;
-            (content, file_path.to_string())
-        }
+        (None, Some(file_path)) => (read_config_file(file_path)?, file_path.to_string()),
         _ => {
             return Err(crate::error::Error::Other(
                 "Specify either a configuration name or --file".to_string(),
@@ -244,9 +428,19 @@ pub async fn validate(opts: &GlobalOptions, name: Option<&str>, file: Option<&st
         }
     };
 
-    eprintln!("{} Validating '{}'...", "→".blue(), source);
-
-    let validation = ctx.client.validate_scan_config(org_id, &content).await?;
+    hint!("{} Validating '{}'...", "→".blue(), source);
+
+    // Local schema check first; only ask the API when it passes
+    let mut validation = validation::validate(&content);
+    if validation.is_valid()
+        && !local_only
+        && let Some(ctx) = &ctx
+    {
+        let org_id = ctx.require_org_id()?;
+        let local_markers = std::mem::take(&mut validation.markers);
+        validation = ctx.client.validate_scan_config(org_id, &content).await?;
+        validation.markers.extend(local_markers);
+    }
 
     match opts.format {
         OutputFormat::Json => {
@@ -268,10 +462,391 @@ pub async fn validate(opts: &GlobalOptions, name: Option<&str>, file: Option<&st
     Ok(())
 }
 
+// ============================================================================
+// Generate Command
+// ============================================================================
+
+/// Environment used when the app has none yet
+const DEFAULT_ENV: &str = "Development";
+
+/// Write a starter HawkScan configuration for an app
+///
+/// The app is resolved by name or ID. Without `env`, the app's only
+/// environment is used; an app with several must be told which.
+#[allow(clippy::too_many_arguments)]
+pub async fn generate(
+    opts: &GlobalOptions,
+    app: &str,
+    host: &str,
+    env: Option<&str>,
+    oas: Option<&str>,
+    auth: Option<AuthTemplate>,
+    output: Option<&str>,
+    force: bool,
+) -> Result<()> {
+    if !(host.starts_with("http://") || host.starts_with("https://")) {
+        return Err(
+            ApiError::BadRequest(format!("--host must be an http(s) URL, got '{}'", host)).into(),
+        );
+    }
+    if let Some(spec) = oas
+        && (spec.starts_with("http://") || spec.starts_with("https://"))
+    {
+        return Err(ApiError::BadRequest(format!(
+            "--oas takes a path on the host (e.g. /openapi.json) or a local file, got '{}'",
+            spec
+        ))
+        .into());
+    }
+    if let Some(path) = output
+        && !force
+        && Path::new(path).exists()
+    {
+        return Err(Error::Other(format!(
+            "{} already exists; use --force to overwrite it",
+            path
+        )));
+    }
+
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?;
+    let application = resolve_app(&ctx.client, org_id, app).await?;
+
+    let env = match env {
+        Some(env) => env.to_string(),
+        None => {
+            let envs = ctx.client.list_environments(&application.id, None).await?;
+            match envs.as_slice() {
+                [] => DEFAULT_ENV.to_string(),
+                [env] => env.environment_name.clone(),
+                _ => {
+                    let names: Vec<&str> =
+                        envs.iter().map(|e| e.environment_name.as_str()).collect();
+                    return Err(Error::Other(format!(
+                        "Application '{}' has several environments ({}); pick one with --env",
+                        application.name,
+                        names.join(", ")
+                    )));
+                }
+            }
+        }
+    };
+
+    let content = ScanTemplate {
+        app_name: &application.name,
+        app_id: &application.id,
+        env: &env,
+        host,
+        oas,
+        auth,
+    }
+    .render();
+
+    match output {
+        Some(path) => {
+            fs::write(path, &content)?;
+            eprintln!(
+                "{} Configuration for '{}' ({}) written to {}",
+                "✓".green(),
+                application.name,
+                env,
+                path
+            );
+            hint!("→ Check it: hawkop config validate -f {}", path);
+            if oas.is_some_and(|spec| !spec.starts_with('/') && !Path::new(spec).exists()) {
+                hint!("→ The OpenAPI file is read relative to where HawkScan runs");
+            }
+        }
+        None => match opts.format {
+            OutputFormat::Json => {
+                let wrapper = serde_json::json!({
+                    "app_id": application.id,
+                    "env": env,
+                    "content": content
+                });
+                println!("{}", crate::output::json::format_raw_json(&wrapper)?);
+            }
+            _ => print!("{}", content),
+        },
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Migrate Command
+// ============================================================================
+
+/// Run the config migrate command
+///
+/// Moves hawkop's local files (CLI config, history, rate limit state) from
+/// the legacy `~/.hawkop` directory to the platform config directory.
+pub fn migrate(dry_run: bool, force: bool) -> Result<()> {
+    let legacy = Config::legacy_dir()?;
+    let Some(platform) = Config::platform_dir() else {
+        println!(
+            "No platform config directory on this system; keeping {}",
+            legacy.display()
+        );
+        return Ok(());
+    };
+    if !legacy.is_dir() {
+        println!(
+            "Nothing to migrate: {} does not exist. Config is stored in {}",
+            legacy.display(),
+            platform.display()
+        );
+        return Ok(());
+    }
+
+    if dry_run {
+        println!("{}", "DRY RUN - no changes will be made".yellow());
+        println!();
+    }
+
+    let files = migrate_config_dir(&legacy, &platform, dry_run, force)?;
+    let verb = if dry_run { "Would move" } else { "Moved" };
+    for file in &files {
+        let name = file.file_name().unwrap_or_default().to_string_lossy();
+        println!(
+            "  {} {} → {}",
+            verb,
+            file.display(),
+            platform.join(&*name).display()
+        );
+    }
+    if files.is_empty() {
+        println!("Nothing to migrate: {} is empty.", legacy.display());
+    } else if !dry_run {
+        println!();
+        println!(
+            "{} Migrated {} file(s) to {}",
+            "✓".green(),
+            files.len(),
+            platform.display()
+        );
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Export / Import Commands
+// ============================================================================
+
+/// Run the config export command
+///
+/// Reads the config without decrypting it, since no secret is written out.
+pub fn export(opts: &GlobalOptions, redact: bool, output: Option<&str>) -> Result<()> {
+    let config = ProfiledConfig::load_sealed_at(opts.config_ref())?;
+    let secrets = if redact {
+        Secrets::Redact
+    } else {
+        Secrets::Exclude
+    };
+    let export = config.export(secrets);
+
+    let content = match opts.format {
+        OutputFormat::Json => crate::output::json::format_raw_json(&export)?,
+        _ => format!(
+            "# hawkop settings; import with `hawkop config import <file>`\n{}",
+            serde_yaml::to_string(&export).map_err(|e| Error::Other(e.to_string()))?
+        ),
+    };
+    match output {
+        Some(path) => {
+            fs::write(path, &content)?;
+            eprintln!(
+                "{} Exported {} profile(s) to {}",
+                "✓".green(),
+                export.profiles.len(),
+                path
+            );
+        }
+        None => println!("{}", content.trim_end()),
+    }
+    Ok(())
+}
+
+/// Run the config import command
+pub fn import(opts: &GlobalOptions, file: &str) -> Result<()> {
+    if !Path::new(file).exists() {
+        return Err(Error::Other(format!("File not found: {}", file)));
+    }
+    let incoming = read_export(file.into())?;
+
+    // A missing local config starts empty, so the file's active profile is used
+    let mut config = match ProfiledConfig::load_at(opts.config_ref()) {
+        Ok(config) => config,
+        Err(Error::Config(ConfigError::NotFound)) => {
+            let mut config = ProfiledConfig::default();
+            config.profiles.clear();
+            config
+        }
+        Err(e) => return Err(e),
+    };
+    let imported = config.import(incoming);
+    config.save_at(opts.config_ref())?;
+
+    if opts.format == OutputFormat::Json {
+        println!("{}", format_json(&imported)?);
+        return Ok(());
+    }
+    for entry in &imported {
+        let verb = if entry.created { "Created" } else { "Updated" };
+        println!("  {} profile {}", verb, entry.profile.bold());
+    }
+    println!(
+        "{} Imported {} profile(s) into {}",
+        "✓".green(),
+        imported.len(),
+        ProfiledConfig::resolve_path(opts.config_ref())?.display()
+    );
+    for entry in imported.iter().filter(|e| e.needs_key) {
+        println!(
+            "  Profile {} has no API key; run {}",
+            entry.profile.bold(),
+            format!("hawkop init --profile {}", entry.profile).cyan()
+        );
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Lint Command
+// ============================================================================
+
+/// Run the config lint command
+///
+/// Checks hawkop's own config file (not a scan configuration) for syntax
+/// errors, wrong value types, unknown keys, and deprecated settings. Values
+/// that only make sense against the CLI (preference flags, rate limit
+/// categories, cache TTL names) are checked once the file parses.
+pub fn lint(opts: &GlobalOptions, file: Option<&str>) -> Result<()> {
+    let path = match file {
+        Some(file) => Path::new(file).to_path_buf(),
+        None => Config::resolve_path(opts.config_ref())?,
+    };
+    let source = path.display().to_string();
+    let text = read_config_file(&source)?;
+
+    let mut issues = crate::config::lint::lint(&text);
+    if !issues.iter().any(|i| i.kind.is_error())
+        && let Ok(config) = ProfiledConfig::load_sealed_at(Some(&source))
+    {
+        issues.extend(lint_settings(&text, &config));
+    }
+    let legacy = Config::legacy_dir().is_ok_and(|dir| path.starts_with(dir));
+    if legacy && Config::platform_dir().is_some() {
+        issues.push(ConfigIssue {
+            kind: IssueKind::Deprecated,
+            key: String::new(),
+            line: None,
+            message: "Config is stored in the legacy ~/.hawkop directory".to_string(),
+            fix: Some("Run `hawkop config migrate`".to_string()),
+        });
+    }
+    let valid = !issues.iter().any(|i| i.kind.is_error());
+
+    match opts.format {
+        OutputFormat::Json => {
+            let report = serde_json::json!({
+                "file": source,
+                "valid": valid,
+                "issues": issues,
+            });
+            println!("{}", format_json(&report)?);
+        }
+        _ => {
+            let markers = issues.iter().map(issue_marker).collect();
+            print_validation_results(&ValidatedAssetResponse { markers }, Some(&source));
+        }
+    }
+
+    if !valid {
+        return Err(crate::error::Error::Other(
+            "Configuration validation failed".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Present a lint issue as a validation marker, with the fix as its code line
+fn issue_marker(issue: &ConfigIssue) -> ValidationMarker {
+    let severity = if issue.kind.is_error() {
+        "error"
+    } else {
+        "warning"
+    };
+    ValidationMarker {
+        code: issue.fix.as_ref().map(|fix| format!("fix: {}", fix)),
+        end_column: None,
+        end_line_number: issue.line.map(|l| l as i32),
+        message: Some(issue.message.clone()),
+        owner: Some("hawkop".to_string()),
+        resource: None,
+        severity: Some(severity.to_string()),
+        start_column: None,
+        start_line_number: issue.line.map(|l| l as i32),
+    }
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
+/// Read a local configuration file, with a friendly error if it is missing
+fn read_config_file(file: &str) -> Result<String> {
+    let path = Path::new(file);
+    if !path.exists() {
+        return Err(crate::error::Error::Other(format!(
+            "File not found: {}",
+            file
+        )));
+    }
+    Ok(fs::read_to_string(path)?)
+}
+
+/// Validate a config locally before upload, printing the errors if any
+///
+/// Returns the results so they can be shown with the API's.
+fn check_local(content: &str, file: &str) -> Result<ValidatedAssetResponse> {
+    let local = validation::validate(content);
+    if !local.is_valid() {
+        print_validation_results(&local, Some(file));
+        return Err(Error::Other(
+            "Configuration has validation errors. Fix errors before uploading.".to_string(),
+        ));
+    }
+    Ok(local)
+}
+
+/// Validate a config with the API before upload, printing errors and warnings
+async fn check_remote(
+    ctx: &CommandContext,
+    org_id: &str,
+    content: &str,
+    file: &str,
+    local: ValidatedAssetResponse,
+) -> Result<()> {
+    hint!("{} Validating configuration '{}'...", "→".blue(), file);
+
+    let mut validation = ctx.client.validate_scan_config(org_id, content).await?;
+    validation.markers.extend(local.markers);
+
+    if !validation.is_valid() {
+        print_validation_results(&validation, Some(file));
+        return Err(Error::Other(
+            "Configuration has validation errors. Fix errors before uploading.".to_string(),
+        ));
+    }
+
+    // Show warnings if any
+    if !validation.warnings().is_empty() {
+        print_validation_results(&validation, Some(file));
+        eprintln!();
+    }
+    Ok(())
+}
+
 /// Print validation results in a human-readable format
 fn print_validation_results(validation: &ValidatedAssetResponse, source: Option<&str>) {
     let errors = validation.errors();
@@ -3,15 +3,24 @@
 //! Provides a unified context for command execution, eliminating boilerplate
 //! for config loading, authentication validation, and client initialization.
 
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 
-use crate::cache::CachedStackHawkClient;
-use crate::cli::OutputFormat;
+use clap::{ArgMatches, Command, CommandFactory};
+
+use crate::cache::{CacheTtl, CachedStackHawkClient};
 use crate::cli::args::GlobalOptions;
-use crate::client::models::JwtToken;
-use crate::client::{AuthApi, StackHawkClient};
-use crate::config::{ProfileConfig, ProfiledConfig};
-use crate::error::Result;
+use crate::cli::{Cli, OutputFormat};
+use crate::client::models::{Application, JwtToken};
+use crate::client::parallel::DEFAULT_MAX_CONCURRENCY;
+use crate::client::rate_limit::EndpointCategory;
+use crate::client::stackhawk::{DEFAULT_MAX_RETRIES, resolve_api_host};
+use crate::client::{AuthApi, StackHawkClient, TokenRefreshHook};
+use crate::config::lint::{ConfigIssue, IssueKind, closest, preference_fields};
+use crate::config::{Preferences, ProfileConfig, ProfiledConfig, TokenStore};
+use crate::error::{ConfigError, DryRunRequest, Error, Result};
+use crate::history::HISTORY_ENV;
+use crate::output::warnings::{WarningKind, warn};
 
 /// Context for command execution containing config, client, and runtime options.
 ///
@@ -19,6 +28,7 @@ use crate::error::Result;
 /// - Loaded configuration with resolved profile
 /// - Authenticated API client with JWT set (wrapped in Arc for parallel requests)
 /// - Output format preference
+/// - Whether mutating calls are previewed rather than sent (`--dry-run`)
 /// - Current profile information
 /// - Resolved API host (for display in status command)
 pub struct CommandContext {
@@ -32,6 +42,12 @@ pub struct CommandContext {
     pub client: Arc<CachedStackHawkClient<StackHawkClient>>,
     /// Output format preference
     pub format: OutputFormat,
+    /// Preview mutating API calls instead of sending them (`--dry-run`).
+    ///
+    /// The client withholds every non-GET request regardless, so a command
+    /// that doesn't check this still stops at its first change and reports
+    /// it; checking it first lets a command preview the whole operation.
+    pub dry_run: bool,
     /// Resolved API host (for display purposes, e.g., in status command)
     #[allow(dead_code)]
     pub api_host: Option<String>,
@@ -57,7 +73,7 @@ impl CommandContext {
     /// # Errors
     /// Returns error if config cannot be loaded or authentication is invalid.
     pub async fn new(opts: &GlobalOptions) -> Result<Self> {
-        let mut profiled_config = ProfiledConfig::load_at(opts.config_ref())?;
+        let profiled_config = ProfiledConfig::load_at(opts.config_ref())?;
 
         // Resolve which profile to use
         let (profile_name, _profile_ref) = profiled_config.resolve_profile(opts.profile_ref())?;
@@ -65,9 +81,14 @@ impl CommandContext {
 
         // Get a mutable copy of the profile for modifications
         let mut profile = profiled_config.get_profile(&profile_name)?.clone();
+        apply_env_overrides(&mut profile.preferences)?;
+        crate::client::set_default_page_size(profile.preferences.page_size);
 
         // Validate authentication
         profile.validate_auth()?;
+        if let Some(api_key) = &profile.api_key {
+            crate::logging::register_secret(api_key);
+        }
 
         // Apply org override if provided
         if let Some(org) = opts.org_ref() {
@@ -80,49 +101,60 @@ impl CommandContext {
             .map(|s| s.to_string())
             .or_else(|| profile.api_host.clone());
 
-        // Create the raw client first (need to set JWT before wrapping)
+        // Create the raw client first (need to set JWT before wrapping).
+        // Tokens refreshed mid-command are stored so the next run reuses them.
+        let api_key = profile.api_key.as_deref().expect("validated above");
+        let tokens = TokenStore::new(
+            profiled_config.token_dir(),
+            api_key,
+            resolved_api_host.as_deref(),
+            profiled_config.encryption.as_ref(),
+        );
         let raw_client =
-            StackHawkClient::with_host(profile.api_key.clone(), resolved_api_host.clone())?;
-
-        // Use cached JWT if valid, otherwise authenticate and cache
-        if !profile.is_token_expired() {
-            // Use cached token
-            if let Some(ref jwt) = profile.jwt {
-                raw_client
-                    .set_jwt(JwtToken {
-                        token: jwt.token.clone(),
-                        expires_at: jwt.expires_at,
-                    })
-                    .await;
-            }
+            StackHawkClient::with_host(profile.api_key.clone(), resolved_api_host.clone())?
+                .with_token_refresh_hook(persist_refreshed_token(tokens.clone()));
+        let raw_client = match profile.preferences.retries {
+            Some(retries) => raw_client.with_max_retries(retries),
+            None => raw_client,
+        };
+        let raw_client = if profile.preferences.rate_limits.is_empty() {
+            raw_client
         } else {
-            // Authenticate and cache the new token
-            let api_key = profile.api_key.as_ref().expect("validated above");
-            let jwt = raw_client.authenticate(api_key).await?;
-
-            // Save to profile for future runs
-            profile.jwt = Some(crate::config::JwtToken {
-                token: jwt.token.clone(),
-                expires_at: jwt.expires_at,
-            });
+            raw_client.with_rate_limits(&rate_limit_quotas(&profile.preferences))
+        };
+        let raw_client = raw_client
+            .with_offline(opts.offline)
+            .with_dry_run(opts.dry_run);
 
-            // Update the profile in the config and save
-            if let Ok(stored_profile) = profiled_config.get_profile_mut(&profile_name) {
-                stored_profile.jwt = profile.jwt.clone();
+        // Use the stored JWT if valid, otherwise authenticate and store it.
+        // Offline, no request is made, so an expired token doesn't matter.
+        if opts.offline {
+            log::debug!("Offline: skipping authentication");
+        } else if let Some(jwt) = tokens.load().filter(|jwt| !jwt.needs_refresh()) {
+            raw_client
+                .set_jwt(JwtToken {
+                    token: jwt.token,
+                    expires_at: jwt.expires_at,
+                    clock_skew_secs: jwt.clock_skew_secs,
+                })
+                .await;
+        } else {
+            // Authenticate and store the new token for future runs
+            let jwt = raw_client.authenticate(api_key).await?;
+            if let Err(e) = tokens.save(&(&jwt).into()) {
+                log::debug!("Could not store token: {}", e);
             }
-            profiled_config.save_at(opts.config_ref())?;
-
-            // Set on client
             raw_client.set_jwt(jwt).await;
         }
 
-        // Wrap with caching layer (disabled if --no-cache)
+        // Wrap with caching layer (disabled by --no-cache or `cache.enabled`)
         // Pass API host to cache layer to prevent cross-environment cache hits
-        let client = Arc::new(CachedStackHawkClient::with_host(
-            raw_client,
-            !opts.no_cache,
-            resolved_api_host.clone(),
-        ));
+        let use_cache = !opts.no_cache && profiled_config.cache.is_enabled();
+        let client = Arc::new(
+            CachedStackHawkClient::with_host(raw_client, use_cache, resolved_api_host.clone())
+                .with_offline(opts.offline)
+                .with_redact_sensitive(profiled_config.cache.redact_sensitive),
+        );
 
         Ok(Self {
             profiled_config,
@@ -130,6 +162,7 @@ impl CommandContext {
             profile_name,
             client,
             format: opts.format,
+            dry_run: opts.dry_run,
             api_host: resolved_api_host,
             config_path: opts.config.clone(),
         })
@@ -142,7 +175,7 @@ impl CommandContext {
         self.profile
             .org_id
             .as_deref()
-            .ok_or_else(|| crate::error::ConfigError::MissingOrgId.into())
+            .ok_or_else(|| ConfigError::MissingOrgId.into())
     }
 
     /// Get the organization ID if set.
@@ -155,4 +188,863 @@ impl CommandContext {
     pub fn save_config(&self) -> Result<()> {
         self.profiled_config.save_at(self.config_path.as_deref())
     }
+
+    /// Resolve `--app` values (names or UUIDs) to app IDs in the current org.
+    ///
+    /// See [`resolve_app_ids`].
+    pub async fn resolve_app_ids(&self, identifiers: &[String]) -> Result<Vec<String>> {
+        resolve_app_ids(&self.client, self.require_org_id()?, identifiers).await
+    }
+}
+
+/// Report a mutating API call withheld by `--dry-run`
+///
+/// Called with the request the client refused to send; the command ends
+/// there, successfully, since nothing after it could run without the result.
+pub fn print_dry_run(format: OutputFormat, request: &DryRunRequest) -> Result<()> {
+    match format {
+        OutputFormat::Json => {
+            let data = serde_json::json!({ "dry_run": true, "request": request });
+            println!("{}", crate::output::json::format_json(&data)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            println!("Dry run: would {}", request);
+            if let Some(serde_json::Value::Object(fields)) = &request.payload {
+                for (key, value) in fields {
+                    println!("  {}: {}", key, payload_summary(value));
+                }
+            } else if let Some(payload) = &request.payload {
+                println!("  {}", payload_summary(payload));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// One-line summary of a payload value: long lists are counted and long
+/// values truncated
+fn payload_summary(value: &serde_json::Value) -> String {
+    const MAX_LEN: usize = 80;
+    let text = match value {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Array(items) if items.len() > 5 => format!("[{} items]", items.len()),
+        other => other.to_string(),
+    };
+    if text.chars().count() > MAX_LEN {
+        let truncated: String = text.chars().take(MAX_LEN - 3).collect();
+        format!("{}...", truncated)
+    } else {
+        text
+    }
+}
+
+/// Simple UUID format check (8-4-4-4-12 hex pattern)
+pub(crate) fn looks_like_uuid(s: &str) -> bool {
+    let parts: Vec<&str> = s.split('-').collect();
+    parts.len() == 5
+        && parts
+            .iter()
+            .all(|p| !p.is_empty() && p.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Resolve application identifiers (name or UUID) to app IDs.
+///
+/// UUIDs pass through without a lookup. Names are matched case-insensitively
+/// against the org's apps, fetched once through the response cache. A name
+/// shared by several apps is an error listing their IDs.
+pub(crate) async fn resolve_app_ids(
+    client: &Arc<CachedStackHawkClient<StackHawkClient>>,
+    org_id: &str,
+    identifiers: &[String],
+) -> Result<Vec<String>> {
+    if identifiers.iter().all(|id| looks_like_uuid(id)) {
+        return Ok(identifiers.to_vec());
+    }
+    let apps = crate::cli::team::fetch_all_apps(client.clone(), org_id).await?;
+    match_app_ids(&apps, identifiers)
+}
+
+/// Match identifiers against a list of apps (see [`resolve_app_ids`])
+fn match_app_ids(apps: &[Application], identifiers: &[String]) -> Result<Vec<String>> {
+    identifiers
+        .iter()
+        .map(|id| {
+            if looks_like_uuid(id) {
+                return Ok(id.clone());
+            }
+
+            let matches: Vec<_> = apps
+                .iter()
+                .filter(|a| a.name.eq_ignore_ascii_case(id))
+                .collect();
+            match matches.as_slice() {
+                [] => Err(Error::Other(format!(
+                    "Application not found: {}\n\n→ Use 'hawkop app list' to see available applications.",
+                    id
+                ))),
+                [app] => {
+                    log::debug!("Resolved app '{}' to ID '{}'", id, app.id);
+                    Ok(app.id.clone())
+                }
+                _ => {
+                    let app_list = matches
+                        .iter()
+                        .map(|a| format!("  • {} (ID: {})", a.name, a.id))
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    Err(Error::Other(format!(
+                        "Multiple applications found with name \"{}\". Use the app ID instead:\n\n{}",
+                        id, app_list
+                    )))
+                }
+            }
+        })
+        .collect()
+}
+
+/// Rate limit overrides from `preferences.rate_limits`, keyed by category
+///
+/// Unknown category names are skipped with a warning.
+pub(crate) fn rate_limit_quotas(prefs: &Preferences) -> HashMap<EndpointCategory, u32> {
+    prefs
+        .rate_limits
+        .iter()
+        .filter_map(
+            |(name, &per_minute)| match EndpointCategory::from_name(name) {
+                Some(category) => Some((category, per_minute)),
+                None => {
+                    warn(
+                        WarningKind::IgnoredFlag,
+                        format!(
+                            "Unknown rate limit category '{}' in config (expected one of: {})",
+                            name,
+                            EndpointCategory::ALL.map(|c| c.name()).join(", ")
+                        ),
+                    );
+                    None
+                }
+            },
+        )
+        .collect()
+}
+
+/// Apply the config's `cache:` section: TTL overrides and the size limit
+///
+/// Runs before any command touches the cache. Unknown data types and
+/// unparseable values are skipped with a warning.
+pub fn apply_cache_settings(config_path: Option<&str>) {
+    let Ok(config) = ProfiledConfig::load_sealed_at(config_path) else {
+        return;
+    };
+    let settings = &config.cache;
+
+    let mut overrides = Vec::new();
+    for (name, value) in &settings.ttl {
+        let Some(ttl) = CacheTtl::from_name(name) else {
+            warn(
+                WarningKind::IgnoredFlag,
+                format!(
+                    "Unknown cache TTL '{}' in config (expected one of: {})",
+                    name,
+                    CacheTtl::ALL.map(|t| t.name()).join(", ")
+                ),
+            );
+            continue;
+        };
+        match scalar_string(value).map(|v| crate::timeparse::parse_duration(&v)) {
+            Some(Ok(duration)) => overrides.push((ttl, duration)),
+            _ => warn(
+                WarningKind::IgnoredFlag,
+                format!(
+                    "Cache TTL '{}' in config must be a duration like 10m or 4h; ignored",
+                    name
+                ),
+            ),
+        }
+    }
+    CacheTtl::set_overrides(overrides);
+
+    if let Some(value) = &settings.max_size {
+        match scalar_string(value).and_then(|v| parse_size(&v)) {
+            Some(bytes) => crate::cache::set_max_size(Some(bytes)),
+            None => warn(
+                WarningKind::IgnoredFlag,
+                "Cache max_size in config must be a size like 500MB or 2GB; ignored",
+            ),
+        }
+    }
+}
+
+/// Problems with settings that the structural check accepts: preference
+/// keys that match no command flag, unknown rate limit categories and cache
+/// TTL names, and values that cannot be used
+///
+/// `source` is the config file's text, used to locate keys. These are the
+/// settings that are otherwise skipped with a warning when applied.
+pub(crate) fn lint_settings(source: &str, config: &ProfiledConfig) -> Vec<ConfigIssue> {
+    let legacy = serde_yaml::from_str::<serde_yaml::Value>(source)
+        .ok()
+        .is_some_and(|root| root.get("profiles").is_none());
+    let mut issues = Vec::new();
+    let mut push = |path: &[&str], message: String, fix: String| {
+        issues.push(ConfigIssue::at(
+            source,
+            path,
+            IssueKind::Value,
+            message,
+            Some(fix),
+        ));
+    };
+
+    for name in config.list_profiles() {
+        let prefs = &config.profiles[name].preferences;
+        let base = if legacy {
+            vec!["preferences"]
+        } else {
+            vec!["profiles", name, "preferences"]
+        };
+
+        for (key, value) in &prefs.command_defaults {
+            let path = [base.as_slice(), &[key.as_str()]].concat();
+            if default_strings(value).is_none() {
+                push(
+                    &path,
+                    format!("Preference `{}` must be a scalar or a list of scalars", key),
+                    "Use a value like `50`, `json`, or `[prod, staging]`".to_string(),
+                );
+            } else if !is_flag_default_key(key) {
+                let fix = match closest(key, preference_fields()) {
+                    Some(field) => format!("Did you mean `{}`?", field),
+                    None => "Name a command path and flag, e.g. `scan.list.limit`".to_string(),
+                };
+                push(
+                    &path,
+                    format!("Preference `{}` does not match a command flag", key),
+                    fix,
+                );
+            }
+        }
+        for category in prefs.rate_limits.keys() {
+            if EndpointCategory::from_name(category).is_none() {
+                push(
+                    &[base.as_slice(), &["rate_limits", category.as_str()]].concat(),
+                    format!("Unknown rate limit category `{}`", category),
+                    format!(
+                        "Use one of: {}",
+                        EndpointCategory::ALL.map(|c| c.name()).join(", ")
+                    ),
+                );
+            }
+        }
+    }
+
+    for (name, value) in &config.cache.ttl {
+        let path = ["cache", "ttl", name.as_str()];
+        let names = CacheTtl::ALL.map(|t| t.name());
+        if CacheTtl::from_name(name).is_none() {
+            let fix = match closest(name, names.into_iter()) {
+                Some(known) => format!("Did you mean `{}`?", known),
+                None => format!("Use one of: {}", names.join(", ")),
+            };
+            push(&path, format!("Unknown cache TTL `{}`", name), fix);
+        } else if scalar_string(value).is_none_or(|v| crate::timeparse::parse_duration(&v).is_err())
+        {
+            push(
+                &path,
+                format!("Cache TTL `{}` is not a duration", name),
+                "Use a duration like 10m or 4h".to_string(),
+            );
+        }
+    }
+    if let Some(value) = &config.cache.max_size
+        && scalar_string(value).and_then(|v| parse_size(&v)).is_none()
+    {
+        push(
+            &["cache", "max_size"],
+            "Cache `max_size` is not a size".to_string(),
+            "Use a size like 500MB or 2GB".to_string(),
+        );
+    }
+    issues
+}
+
+/// String form of a YAML scalar (numbers and strings)
+fn scalar_string(value: &serde_yaml::Value) -> Option<String> {
+    match value {
+        serde_yaml::Value::String(s) => Some(s.clone()),
+        serde_yaml::Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/// Parse a byte size: `1048576`, `512KB`, `500MB`, `2GB` (1024-based)
+fn parse_size(value: &str) -> Option<u64> {
+    let value = value.trim().to_ascii_uppercase();
+    let digits = value.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+    let multiplier = match value[digits.len()..].trim_end_matches('B') {
+        "" => 1,
+        "K" => 1024,
+        "M" => 1024 * 1024,
+        "G" => 1024 * 1024 * 1024,
+        _ => return None,
+    };
+    digits.trim().parse::<u64>().ok()?.checked_mul(multiplier)
+}
+
+/// Build a hook that stores a refreshed JWT in the token store.
+///
+/// Failures are logged, not fatal: the token is still valid for the current
+/// process.
+fn persist_refreshed_token(tokens: TokenStore) -> TokenRefreshHook {
+    Arc::new(move |jwt: &JwtToken| match tokens.save(&jwt.into()) {
+        Ok(()) => log::debug!("Stored refreshed token"),
+        Err(e) => log::debug!("Could not store refreshed token: {}", e),
+    })
+}
+
+// ============================================================================
+// Environment Overrides
+// ============================================================================
+
+/// Page size for list requests (`preferences.page_size`)
+pub const PAGE_SIZE_ENV: &str = "HAWKOP_PAGE_SIZE";
+
+/// Retries for rate-limited and transient failures (`preferences.retries`)
+pub const RETRIES_ENV: &str = "HAWKOP_RETRIES";
+
+/// Rate limit overrides as `category=per_minute,...` (`preferences.rate_limits`)
+pub const RATE_LIMITS_ENV: &str = "HAWKOP_RATE_LIMITS";
+
+/// Where the effective value of a setting came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingSource {
+    /// Command-line flag
+    Flag,
+    /// `HAWKOP_*` environment variable
+    Env,
+    /// Profile in the config file
+    Config,
+    /// Built-in default
+    Default,
+}
+
+/// Effective value of a global setting and where it came from
+#[derive(Debug, Clone)]
+pub struct ResolvedSetting {
+    /// Setting name (config key where there is one)
+    pub name: &'static str,
+    /// Effective value
+    pub value: String,
+    /// Origin of the value
+    pub source: SettingSource,
+    /// Environment variable that sets it
+    pub env: &'static str,
+    /// Flag that sets it, if any
+    pub flag: Option<&'static str>,
+}
+
+/// Apply `HAWKOP_*` variables on top of the profile's preferences
+///
+/// Flag-backed settings (`HAWKOP_FORMAT`, `HAWKOP_NO_CACHE`, ...) are read by
+/// clap; this covers the config-only ones. Rate limits from the environment
+/// replace configured values of the same category.
+pub fn apply_env_overrides(prefs: &mut Preferences) -> Result<()> {
+    if let Some(value) = env_value(PAGE_SIZE_ENV) {
+        prefs.page_size = parse_env(PAGE_SIZE_ENV, &value)?;
+    }
+    if let Some(value) = env_value(RETRIES_ENV) {
+        prefs.retries = Some(parse_env(RETRIES_ENV, &value)?);
+    }
+    if let Some(value) = env_value(RATE_LIMITS_ENV) {
+        prefs.rate_limits.extend(parse_rate_limits(&value)?);
+    }
+    if let Some(enabled) = crate::history::env_override() {
+        prefs.history = enabled;
+    }
+    Ok(())
+}
+
+/// Effective global settings of `profile` with their sources
+///
+/// `args` are the raw command-line arguments, used to tell a flag from the
+/// environment variable clap reads for it.
+pub fn resolve_settings(
+    opts: &GlobalOptions,
+    profile: &ProfileConfig,
+    args: &[String],
+) -> Result<Vec<ResolvedSetting>> {
+    let configured = &profile.preferences;
+    let mut prefs = configured.clone();
+    apply_env_overrides(&mut prefs)?;
+    let defaults = Preferences::default();
+
+    let setting = |name, env, flag: Option<&'static str>, configured: bool, value: String| {
+        let source = if flag.is_some_and(|f| flag_given(args, f)) {
+            SettingSource::Flag
+        } else if env_value(env).is_some() {
+            SettingSource::Env
+        } else if configured {
+            SettingSource::Config
+        } else {
+            SettingSource::Default
+        };
+        ResolvedSetting {
+            name,
+            value,
+            source,
+            env,
+            flag,
+        }
+    };
+    let or_none = |value: Option<String>| value.unwrap_or_else(|| "(none)".to_string());
+
+    Ok(vec![
+        setting(
+            "format",
+            "HAWKOP_FORMAT",
+            Some("--format"),
+            configured.format.is_some(),
+            format!("{:?}", opts.format).to_lowercase(),
+        ),
+        setting(
+            "org_id",
+            "HAWKOP_ORG_ID",
+            Some("--org"),
+            profile.org_id.is_some(),
+            or_none(opts.org.clone().or_else(|| profile.org_id.clone())),
+        ),
+        setting(
+            "api_host",
+            "HAWKOP_API_HOST",
+            Some("--api-host"),
+            profile.api_host.is_some(),
+            opts.api_host
+                .clone()
+                .or_else(|| profile.api_host.clone())
+                .unwrap_or_else(|| resolve_api_host(None)),
+        ),
+        setting(
+            "page_size",
+            PAGE_SIZE_ENV,
+            None,
+            configured.page_size != defaults.page_size,
+            prefs.page_size.to_string(),
+        ),
+        setting(
+            "retries",
+            RETRIES_ENV,
+            None,
+            configured.retries.is_some(),
+            prefs.retries.unwrap_or(DEFAULT_MAX_RETRIES).to_string(),
+        ),
+        setting(
+            "rate_limits",
+            RATE_LIMITS_ENV,
+            None,
+            !configured.rate_limits.is_empty(),
+            or_none((!prefs.rate_limits.is_empty()).then(|| {
+                prefs
+                    .rate_limits
+                    .iter()
+                    .map(|(k, v)| format!("{}={}", k, v))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            })),
+        ),
+        setting(
+            "history",
+            HISTORY_ENV,
+            None,
+            configured.history,
+            prefs.history.to_string(),
+        ),
+        setting(
+            "max_concurrency",
+            "HAWKOP_MAX_CONCURRENCY",
+            Some("--max-concurrency"),
+            false,
+            opts.max_concurrency
+                .unwrap_or(DEFAULT_MAX_CONCURRENCY)
+                .to_string(),
+        ),
+        setting(
+            "timeout",
+            "HAWKOP_TIMEOUT",
+            Some("--timeout"),
+            false,
+            or_none(opts.timeout.map(|secs| format!("{}s", secs))),
+        ),
+        setting(
+            "no_cache",
+            "HAWKOP_NO_CACHE",
+            Some("--no-cache"),
+            false,
+            opts.no_cache.to_string(),
+        ),
+        setting(
+            "offline",
+            "HAWKOP_OFFLINE",
+            Some("--offline"),
+            false,
+            opts.offline.to_string(),
+        ),
+    ])
+}
+
+/// Non-empty value of an environment variable
+fn env_value(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|v| !v.trim().is_empty())
+}
+
+fn parse_env<T: std::str::FromStr>(name: &str, value: &str) -> Result<T> {
+    value.trim().parse().map_err(|_| {
+        ConfigError::Invalid(format!("{}='{}' is not a valid number", name, value)).into()
+    })
+}
+
+/// Parse `category=per_minute,...` (e.g. `scan=120,user=60`)
+fn parse_rate_limits(value: &str) -> Result<BTreeMap<String, u32>> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let (category, per_minute) = entry.split_once('=').ok_or_else(|| {
+                ConfigError::Invalid(format!(
+                    "{} entry '{}' must be category=requests_per_minute",
+                    RATE_LIMITS_ENV, entry
+                ))
+            })?;
+            Ok((
+                category.trim().to_string(),
+                parse_env(RATE_LIMITS_ENV, per_minute)?,
+            ))
+        })
+        .collect()
+}
+
+/// Whether a long flag appears in unparsed arguments (`--flag`, `--flag=v`)
+fn flag_given(args: &[String], long: &str) -> bool {
+    args.iter()
+        .take_while(|arg| *arg != "--")
+        .any(|arg| arg == long || arg.strip_prefix(long).is_some_and(|v| v.starts_with('=')))
+}
+
+// ============================================================================
+// Config Defaults Bridge
+// ============================================================================
+
+/// Build the clap command with the active profile's flag defaults applied.
+///
+/// Preference keys name a command path and a flag (`scan.list.limit`,
+/// `audit.since`); `format` and other bare flags apply to every command. A
+/// path naming a command group covers each subcommand under it with that
+/// flag. Values become clap defaults, so flags on the command line and
+/// `HAWKOP_*` variables still win. Unknown keys are warned about and skipped.
+pub fn command_with_config_defaults(args: &[String]) -> Command {
+    let cmd = Cli::command();
+    match load_preferences(args) {
+        Some(prefs) => apply_config_defaults(cmd, &prefs),
+        None => cmd,
+    }
+}
+
+/// Subcommand path of the parsed arguments, e.g. `scan list`
+pub fn command_name(matches: &ArgMatches) -> String {
+    let mut names = Vec::new();
+    let mut current = matches;
+    while let Some((name, sub)) = current.subcommand() {
+        names.push(name);
+        current = sub;
+    }
+    names.join(" ")
+}
+
+/// Preferences of the profile selected by `--config` / `--profile` (or env)
+fn load_preferences(args: &[String]) -> Option<Preferences> {
+    let config_path =
+        raw_flag_value(args, "--config", None).or_else(|| std::env::var("HAWKOP_CONFIG").ok());
+    let profile = raw_flag_value(args, "--profile", Some("-P"))
+        .or_else(|| std::env::var("HAWKOP_PROFILE").ok());
+
+    let config = ProfiledConfig::load_sealed_at(config_path.as_deref()).ok()?;
+    let (_, profile) = config.resolve_profile(profile.as_deref()).ok()?;
+    Some(profile.preferences.clone())
+}
+
+/// Value of a flag in unparsed arguments (`--flag v`, `--flag=v`, `-F v`)
+fn raw_flag_value(args: &[String], long: &str, short: Option<&str>) -> Option<String> {
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--" {
+            break;
+        }
+        if arg == long || Some(arg.as_str()) == short {
+            return iter.next().cloned();
+        }
+        if let Some(value) = arg.strip_prefix(long).and_then(|v| v.strip_prefix('=')) {
+            return Some(value.to_string());
+        }
+    }
+    None
+}
+
+fn apply_config_defaults(mut cmd: Command, prefs: &Preferences) -> Command {
+    let mut entries: Vec<(String, Vec<String>)> = Vec::new();
+    if let Some(ref format) = prefs.format {
+        entries.push(("format".to_string(), vec![format.clone()]));
+    }
+    for (key, value) in &prefs.command_defaults {
+        match default_strings(value) {
+            Some(values) => entries.push((key.clone(), values)),
+            None => warn(
+                WarningKind::IgnoredFlag,
+                format!(
+                    "Config preference '{}' must be a scalar or a list of scalars; ignored",
+                    key
+                ),
+            ),
+        }
+    }
+
+    for (key, values) in entries {
+        let mut path: Vec<&str> = key.split('.').collect();
+        let flag = path.pop().unwrap_or_default();
+        // clap keeps defaults as &'static str without its `string` feature;
+        // these are leaked once per process
+        let values: Vec<&'static str> = values
+            .into_iter()
+            .map(|v| &*Box::leak(v.into_boxed_str()))
+            .collect();
+
+        let mut applied = false;
+        cmd = set_flag_default(cmd, &path, flag, &values, &mut applied);
+        if !applied {
+            warn(
+                WarningKind::IgnoredFlag,
+                format!(
+                    "Config preference '{}' does not match a command flag; ignored",
+                    key
+                ),
+            );
+        }
+    }
+    cmd
+}
+
+/// Render a YAML preference value as clap default values
+fn default_strings(value: &serde_yaml::Value) -> Option<Vec<String>> {
+    fn scalar(value: &serde_yaml::Value) -> Option<String> {
+        match value {
+            serde_yaml::Value::String(s) => Some(s.clone()),
+            serde_yaml::Value::Number(n) => Some(n.to_string()),
+            serde_yaml::Value::Bool(b) => Some(b.to_string()),
+            _ => None,
+        }
+    }
+
+    match value {
+        serde_yaml::Value::Sequence(items) => items.iter().map(scalar).collect(),
+        other => scalar(other).map(|s| vec![s]),
+    }
+}
+
+/// Whether a preference key (`scan.list.limit`, `audit.since`, `format`)
+/// names a flag that [`command_with_config_defaults`] would apply
+pub(crate) fn is_flag_default_key(key: &str) -> bool {
+    let mut path: Vec<&str> = key.split('.').collect();
+    let flag = path.pop().unwrap_or_default();
+    has_flag(&Cli::command(), &path, flag)
+}
+
+/// Whether the command at `path`, or any subcommand below it, has `flag`
+fn has_flag(cmd: &Command, path: &[&str], flag: &str) -> bool {
+    if let [name, rest @ ..] = path {
+        return cmd
+            .find_subcommand(name)
+            .is_some_and(|sub| has_flag(sub, rest, flag));
+    }
+    cmd.get_arguments()
+        .any(|a| a.get_long() == Some(flag) || a.get_id() == flag.replace('-', "_").as_str())
+        || cmd.get_subcommands().any(|sub| has_flag(sub, &[], flag))
+}
+
+/// Set `flag`'s default on the command at `path` and every subcommand below it
+fn set_flag_default(
+    cmd: Command,
+    path: &[&str],
+    flag: &str,
+    values: &[&'static str],
+    applied: &mut bool,
+) -> Command {
+    if let [name, rest @ ..] = path {
+        if cmd.find_subcommand(name).is_none() {
+            return cmd;
+        }
+        return cmd.mut_subcommand(name, |sub| {
+            set_flag_default(sub, rest, flag, values, applied)
+        });
+    }
+
+    let id = cmd
+        .get_arguments()
+        .find(|a| a.get_long() == Some(flag) || a.get_id() == flag.replace('-', "_").as_str())
+        .map(|a| a.get_id().clone());
+    let mut cmd = match id {
+        Some(id) => {
+            *applied = true;
+            cmd.mut_arg(id, |arg| {
+                let values: Vec<&'static str> = match (arg.get_value_delimiter(), values) {
+                    (Some(delim), [single]) => single.split(delim).collect(),
+                    _ => values.to_vec(),
+                };
+                arg.default_values(values).required(false)
+            })
+        }
+        None => cmd,
+    };
+
+    let names: Vec<String> = cmd
+        .get_subcommands()
+        .map(|s| s.get_name().to_string())
+        .collect();
+    for name in names {
+        cmd = cmd.mut_subcommand(name, |sub| {
+            set_flag_default(sub, &[], flag, values, applied)
+        });
+    }
+    cmd
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::fixtures::ApplicationBuilder;
+
+    fn app(id: &str, name: &str) -> Application {
+        ApplicationBuilder::new(id).name(name).build()
+    }
+
+    #[test]
+    fn test_match_app_ids_names_and_uuids() {
+        let uuid = "3f2b1c4d-0000-4000-8000-00000000000a";
+        let apps = vec![app("a1", "Web"), app("a2", "api"), app("a3", "API")];
+
+        let ids = match_app_ids(&apps, &["web".to_string(), uuid.to_string()]).unwrap();
+        assert_eq!(ids, vec!["a1", uuid]);
+
+        let err = match_app_ids(&apps, &["Api".to_string()]).unwrap_err();
+        assert!(err.to_string().contains("(ID: a2)"));
+        assert!(err.to_string().contains("(ID: a3)"));
+
+        assert!(match_app_ids(&apps, &["missing".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_lint_settings() {
+        let source = "version: 2\nactive_profile: default\nprofiles:\n  default:\n    preferences:\n      scan.list.limt: 50\n      pagesize: 10\n      audit.since: 30d\n      rate_limits:\n        scanz: 10\ncache:\n  ttl:\n    scan_lst: 5m\n    apps: soon\n";
+        let config: ProfiledConfig = serde_yaml::from_str(source).unwrap();
+
+        let issues = lint_settings(source, &config);
+
+        let found: Vec<_> = issues
+            .iter()
+            .map(|i| (i.key.as_str(), i.line, i.fix.as_deref().unwrap_or("")))
+            .collect();
+        assert_eq!(found.len(), 5, "{:?}", found);
+        assert!(found.contains(&(
+            "profiles.default.preferences.pagesize",
+            Some(7),
+            "Did you mean `page_size`?"
+        )));
+        assert!(
+            found
+                .iter()
+                .any(|f| f.0.ends_with("scan.list.limt") && f.1 == Some(6))
+        );
+        assert!(found.iter().any(|f| f.0.ends_with("rate_limits.scanz")));
+        assert!(found.contains(&("cache.ttl.scan_lst", Some(13), "Did you mean `scan_list`?")));
+        assert!(found.iter().any(|f| f.0 == "cache.ttl.apps"));
+    }
+
+    #[test]
+    fn test_apply_config_defaults() {
+        let prefs: Preferences = serde_yaml::from_str(
+            "format: json\nscan.list.limit: 50\naudit.since: 30d\nscan.list.env: [prod, staging]",
+        )
+        .unwrap();
+        let cmd = apply_config_defaults(Cli::command(), &prefs);
+
+        let matches = cmd
+            .clone()
+            .try_get_matches_from(["hawkop", "scan", "list"])
+            .unwrap();
+        let (_, scan) = matches.subcommand().unwrap();
+        let (_, list) = scan.subcommand().unwrap();
+        assert_eq!(list.get_one::<usize>("limit"), Some(&50));
+        let envs: Vec<&String> = list.get_many("env").unwrap().collect();
+        assert_eq!(envs, ["prod", "staging"]);
+
+        let matches = cmd
+            .clone()
+            .try_get_matches_from(["hawkop", "scan", "list", "--limit", "5"])
+            .unwrap();
+        let cli = <Cli as clap::FromArgMatches>::from_arg_matches(&matches).unwrap();
+        assert_eq!(cli.format, crate::cli::args::FormatArg::Json);
+
+        let matches = cmd
+            .try_get_matches_from(["hawkop", "audit", "list"])
+            .unwrap();
+        let (_, audit) = matches.subcommand().unwrap();
+        let (_, list) = audit.subcommand().unwrap();
+        assert_eq!(
+            list.get_one::<String>("since").map(String::as_str),
+            Some("30d")
+        );
+    }
+
+    #[test]
+    fn test_parse_size() {
+        assert_eq!(parse_size("1048576"), Some(1_048_576));
+        assert_eq!(parse_size("512KB"), Some(512 * 1024));
+        assert_eq!(parse_size("500mb"), Some(500 * 1024 * 1024));
+        assert_eq!(parse_size("2 GB"), Some(2 * 1024 * 1024 * 1024));
+        assert_eq!(parse_size("2G"), Some(2 * 1024 * 1024 * 1024));
+        assert_eq!(parse_size("lots"), None);
+        assert_eq!(parse_size("5TB"), None);
+    }
+
+    #[test]
+    fn test_parse_rate_limits_and_flags() {
+        let limits = parse_rate_limits("scan=120, user=60,").unwrap();
+        assert_eq!(limits.get("scan"), Some(&120));
+        assert_eq!(limits.get("user"), Some(&60));
+        assert!(parse_rate_limits("scan").is_err());
+        assert!(parse_rate_limits("scan=fast").is_err());
+
+        let args: Vec<String> = ["--no-cache", "scan", "list", "--", "--org=x"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert!(flag_given(&args, "--no-cache"));
+        assert!(!flag_given(&args, "--no"));
+        assert!(!flag_given(&args, "--org"));
+    }
+
+    #[test]
+    fn test_raw_flag_value() {
+        let args: Vec<String> = ["scan", "list", "-P", "work", "--config=/tmp/c.yaml"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(
+            raw_flag_value(&args, "--profile", Some("-P")).as_deref(),
+            Some("work")
+        );
+        assert_eq!(
+            raw_flag_value(&args, "--config", None).as_deref(),
+            Some("/tmp/c.yaml")
+        );
+        assert_eq!(raw_flag_value(&args, "--org", None), None);
+    }
 }
@@ -0,0 +1,351 @@
+//! Background cache refresher commands
+//!
+//! `daemon run` is the process `daemon start` spawns. It keeps the hot
+//! entries warm: the first page of `scan list`, the app lists behind
+//! `app list` and `--app` name resolution, and the scan, app, and plugin ID
+//! completions built from them. Each is refetched shortly before its cache
+//! TTL runs out, so interactive commands and completions find it cached.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use log::debug;
+
+use crate::cache::{CacheStorage, CacheTtl, CachedStackHawkClient};
+use crate::cli::args::GlobalOptions;
+use crate::cli::completions::CompletionWarmer;
+use crate::cli::scan::SCAN_API_PAGE_SIZE;
+use crate::cli::{CommandContext, OutputFormat};
+use crate::client::{ListingApi, PaginationParams, ScanDetailApi, StackHawkClient};
+use crate::daemon::{self, DaemonStatus, HEARTBEAT};
+use crate::error::{Error, Result};
+
+type Client = Arc<CachedStackHawkClient<StackHawkClient>>;
+
+/// How long `start` and `stop` wait for the daemon to respond
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Start the refresher in the background
+pub async fn start(opts: &GlobalOptions, interval: u64) -> Result<()> {
+    if let Some(status) = daemon::running()? {
+        return Err(Error::Other(format!(
+            "Daemon already running (pid {}); stop it with `hawkop daemon stop`",
+            status.pid
+        )));
+    }
+
+    let mut child = daemon::spawn(opts, interval)?;
+    let log = daemon::log_path()?;
+    let deadline = Instant::now() + RESPONSE_TIMEOUT;
+    let status = loop {
+        if let Some(exit) = child.try_wait()? {
+            return Err(Error::Other(format!(
+                "Daemon exited at startup ({}); see {}",
+                exit,
+                log.display()
+            )));
+        }
+        if let Some(status) = daemon::running()?.filter(|s| s.pid == child.id()) {
+            break status;
+        }
+        if Instant::now() >= deadline {
+            return Err(Error::Other(format!(
+                "Daemon (pid {}) did not report in within {}s; see {}",
+                child.id(),
+                RESPONSE_TIMEOUT.as_secs(),
+                log.display()
+            )));
+        }
+        tokio::time::sleep(Duration::from_millis(200)).await;
+    };
+
+    match opts.format {
+        OutputFormat::Json => print_status_json(Some(&status))?,
+        _ => {
+            println!(
+                "Daemon started (pid {}), refreshing org {} every {}s",
+                status.pid, status.org_id, status.interval_secs
+            );
+            println!("Log: {}", log.display());
+        }
+    }
+    Ok(())
+}
+
+/// Ask the running refresher to stop and wait for it to exit
+pub async fn stop(format: OutputFormat) -> Result<()> {
+    let Some(status) = daemon::running()? else {
+        match format {
+            OutputFormat::Json => print_status_json(None)?,
+            _ => println!("Daemon is not running"),
+        }
+        return Ok(());
+    };
+
+    daemon::request_stop(status.pid)?;
+    let deadline = Instant::now() + RESPONSE_TIMEOUT;
+    while daemon::read_pid() == Some(status.pid) {
+        if Instant::now() >= deadline {
+            return Err(Error::Other(format!(
+                "Daemon (pid {}) did not stop within {}s",
+                status.pid,
+                RESPONSE_TIMEOUT.as_secs()
+            )));
+        }
+        tokio::time::sleep(Duration::from_millis(200)).await;
+    }
+
+    match format {
+        OutputFormat::Json => print_status_json(None)?,
+        _ => println!("Daemon stopped (pid {})", status.pid),
+    }
+    Ok(())
+}
+
+/// Show whether the refresher is running and what it last did
+pub fn status(format: OutputFormat) -> Result<()> {
+    let status = daemon::running()?;
+    if format == OutputFormat::Json {
+        return print_status_json(status.as_ref());
+    }
+
+    let Some(status) = status else {
+        println!("Daemon: not running");
+        return Ok(());
+    };
+    let local = |t: chrono::DateTime<Utc>| {
+        t.with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string()
+    };
+
+    println!("Daemon Status");
+    println!("────────────────────────────────────────");
+    println!("PID:            {}", status.pid);
+    println!("Organization:   {}", status.org_id);
+    println!("Started:        {}", local(status.started_at));
+    println!("Interval:       {}s", status.interval_secs);
+    println!("Refreshes:      {}", status.refreshes);
+    println!(
+        "Last refresh:   {}",
+        status.last_refresh.map_or_else(|| "--".to_string(), local)
+    );
+    if let Some(error) = &status.last_error {
+        println!("Last error:     {}", error);
+    }
+    println!("Log:            {}", daemon::log_path()?.display());
+    Ok(())
+}
+
+fn print_status_json(status: Option<&DaemonStatus>) -> Result<()> {
+    let mut json = serde_json::json!({ "running": status.is_some() });
+    if let Some(status) = status {
+        json["status"] = serde_json::to_value(status)?;
+    }
+    json["log_path"] = daemon::log_path()?.display().to_string().into();
+    println!("{}", crate::output::json::format_raw_json(&json)?);
+    Ok(())
+}
+
+/// The daemon process: refresh hot entries until asked to stop
+pub async fn run(opts: &GlobalOptions, interval: u64) -> Result<()> {
+    if let Some(status) = daemon::running()?
+        && status.pid != std::process::id()
+    {
+        return Err(Error::Other(format!(
+            "Daemon already running (pid {})",
+            status.pid
+        )));
+    }
+
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?.to_string();
+    let cache = CacheStorage::open().map_err(|e| Error::Other(e.to_string()))?;
+    let interval = Duration::from_secs(interval);
+
+    let now = Utc::now();
+    let mut status = DaemonStatus {
+        pid: std::process::id(),
+        started_at: now,
+        org_id: org_id.clone(),
+        interval_secs: interval.as_secs(),
+        last_refresh: None,
+        refreshes: 0,
+        last_error: None,
+        heartbeat: now,
+    };
+    // Heartbeat first: a pid file without one looks left over
+    daemon::write_status(&cache, &status)?;
+    daemon::write_pid()?;
+    log_line(&format!(
+        "started (pid {}), refreshing org {} every {}s",
+        status.pid,
+        org_id,
+        interval.as_secs()
+    ));
+
+    let mut refresher = Refresher::new(Arc::new(ctx.client.refreshing()), org_id, interval);
+    loop {
+        let (refreshed, errors) = tokio::select! {
+            outcome = refresher.refresh() => outcome,
+            _ = keep_alive(&cache, &status) => break,
+        };
+        if refreshed > 0 || !errors.is_empty() {
+            status.refreshes += 1;
+            status.last_refresh = Some(Utc::now());
+            status.last_error = (!errors.is_empty()).then(|| errors.join("; "));
+        }
+        for error in &errors {
+            log_line(error);
+        }
+
+        tokio::select! {
+            _ = tokio::time::sleep(interval) => {}
+            _ = keep_alive(&cache, &status) => break,
+        }
+    }
+
+    daemon::remove_pid();
+    log_line("stopped");
+    Ok(())
+}
+
+/// Write the heartbeat every few seconds; returns once the daemon is asked
+/// to stop
+async fn keep_alive(cache: &CacheStorage, status: &DaemonStatus) {
+    let mut last_beat: Option<Instant> = None;
+    loop {
+        if daemon::should_stop(cache) {
+            return;
+        }
+        if last_beat.is_none_or(|t| t.elapsed() >= HEARTBEAT) {
+            let beat = DaemonStatus {
+                heartbeat: Utc::now(),
+                ..status.clone()
+            };
+            if let Err(e) = daemon::write_status(cache, &beat) {
+                log_line(&format!("heartbeat not written: {}", e));
+            }
+            last_beat = Some(Instant::now());
+        }
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+}
+
+/// Timestamped line in the daemon log (the daemon's stderr)
+fn log_line(message: &str) {
+    eprintln!(
+        "{} {}",
+        Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        message
+    );
+}
+
+/// Refetches each hot entry when its TTL would run out before the next check
+struct Refresher {
+    client: Client,
+    org_id: String,
+    interval: Duration,
+    warmer: Option<CompletionWarmer>,
+    scans_at: Option<Instant>,
+    apps_at: Option<Instant>,
+    /// When each recent scan's plugin ID completions were cached
+    plugins_at: HashMap<String, Instant>,
+}
+
+impl Refresher {
+    fn new(client: Client, org_id: String, interval: Duration) -> Self {
+        Self {
+            client,
+            org_id,
+            interval,
+            warmer: CompletionWarmer::open(None),
+            scans_at: None,
+            apps_at: None,
+            plugins_at: HashMap::new(),
+        }
+    }
+
+    /// Whether an entry refreshed at `last` expires before the next check
+    fn due(&self, last: Option<Instant>, ttl: CacheTtl) -> bool {
+        last.is_none_or(|t| t.elapsed() + self.interval >= ttl.duration())
+    }
+
+    /// Refresh whatever is due; returns how many lists were refreshed, and
+    /// the errors
+    async fn refresh(&mut self) -> (usize, Vec<String>) {
+        let mut refreshed = 0;
+        let mut errors = Vec::new();
+        if self.due(self.scans_at, CacheTtl::SCAN_LIST) {
+            match self.refresh_scans().await {
+                Ok(()) => {
+                    self.scans_at = Some(Instant::now());
+                    refreshed += 1;
+                }
+                Err(e) => errors.push(format!("scan list refresh failed: {}", e)),
+            }
+        }
+        if self.due(self.apps_at, CacheTtl::APPS) {
+            match self.refresh_apps().await {
+                Ok(()) => {
+                    self.apps_at = Some(Instant::now());
+                    refreshed += 1;
+                }
+                Err(e) => errors.push(format!("app list refresh failed: {}", e)),
+            }
+        }
+        (refreshed, errors)
+    }
+
+    /// The first page `scan list` fetches, plus scan and plugin ID completions
+    async fn refresh_scans(&mut self) -> Result<()> {
+        let params = PaginationParams::new()
+            .page_size(SCAN_API_PAGE_SIZE)
+            .page(0);
+        let scans = self
+            .client
+            .list_scans_paged(&self.org_id, Some(&params), None)
+            .await?
+            .items;
+        debug!("Daemon refreshed {} scans", scans.len());
+
+        let Some(warmer) = &self.warmer else {
+            return Ok(());
+        };
+        let recent = &scans[..scans.len().min(CompletionWarmer::scan_limit())];
+        warmer.scans(&self.org_id, recent);
+        self.plugins_at
+            .retain(|id, _| recent.iter().any(|s| &s.scan.id == id));
+        for scan in recent {
+            let last = self.plugins_at.get(&scan.scan.id).copied();
+            if !self.due(last, CacheTtl::COMPLETION_ALERTS) {
+                continue;
+            }
+            let alerts = self
+                .client
+                .inner()
+                .list_scan_alerts(&scan.scan.id, None)
+                .await?;
+            warmer.plugins(&scan.scan.id, alerts);
+            self.plugins_at.insert(scan.scan.id.clone(), Instant::now());
+        }
+        Ok(())
+    }
+
+    /// The app lists behind `app list` and `--app` name resolution, plus app
+    /// completions
+    async fn refresh_apps(&mut self) -> Result<()> {
+        let (listed, _) = tokio::try_join!(
+            crate::cli::app::fetch_apps(&self.client, self.org_id.clone(), 0),
+            crate::cli::team::fetch_all_apps(self.client.clone(), &self.org_id),
+        )?;
+        debug!("Daemon refreshed {} apps", listed.len());
+
+        if let Some(warmer) = &self.warmer {
+            warmer.apps(&self.org_id, &listed);
+        }
+        Ok(())
+    }
+}
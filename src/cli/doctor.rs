@@ -0,0 +1,424 @@
+//! Doctor command implementation
+//!
+//! Runs a series of local and remote checks (config, credentials, network,
+//! cache, completions) and prints pass/fail per check with a remediation
+//! hint, so setup problems can be diagnosed without reading debug logs.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use colored::Colorize;
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use crate::cache::CacheStorage;
+use crate::cli::OutputFormat;
+use crate::cli::args::GlobalOptions;
+use crate::client::{AuthApi, StackHawkClient, resolve_api_host};
+use crate::config::{JwtToken, ProfileConfig, ProfiledConfig, TokenStore};
+use crate::error::{ApiError, ConfigError, Error, Result};
+
+/// Timeout for the host reachability probe
+const REACHABILITY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Outcome of a single check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+    Skip,
+}
+
+/// A single diagnostic check result
+#[derive(Debug, Serialize, JsonSchema)]
+struct Check {
+    name: &'static str,
+    status: CheckStatus,
+    detail: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hint: Option<String>,
+}
+
+impl Check {
+    fn new(name: &'static str, status: CheckStatus, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status,
+            detail: detail.into(),
+            hint: None,
+        }
+    }
+
+    fn hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+}
+
+/// Full report emitted by `hawkop doctor`
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct DoctorReport {
+    checks: Vec<Check>,
+    passed: usize,
+    warnings: usize,
+    failed: usize,
+}
+
+/// Run the doctor command
+///
+/// Exits non-zero when any check fails; warnings and skipped checks do not
+/// affect the exit code.
+pub async fn run(opts: &GlobalOptions) -> Result<()> {
+    let mut checks = Vec::new();
+
+    let config_path = ProfiledConfig::resolve_path(opts.config_ref())?;
+    checks.push(check_config_file(&config_path));
+
+    let mut jwt = None;
+    let profile = match ProfiledConfig::load_at(opts.config_ref()) {
+        Ok(config) => match config.resolve_profile(opts.profile_ref()) {
+            Ok((_, profile)) => {
+                jwt = TokenStore::for_profile(&config, profile, opts.api_host_ref())
+                    .and_then(|tokens| tokens.load());
+                Some(profile.clone())
+            }
+            Err(e) => {
+                checks.push(
+                    Check::new("Profile", CheckStatus::Fail, e.to_string())
+                        .hint("Run 'hawkop profile list' to see available profiles"),
+                );
+                None
+            }
+        },
+        Err(e) => {
+            if config_path.exists() {
+                let hint = if matches!(e, Error::Config(ConfigError::Encryption(_))) {
+                    "Set HAWKOP_PASSPHRASE or run interactively to enter the passphrase"
+                } else {
+                    "Fix the YAML syntax or re-run 'hawkop init'"
+                };
+                checks
+                    .push(Check::new("Config parse", CheckStatus::Fail, e.to_string()).hint(hint));
+            }
+            None
+        }
+    };
+
+    let host = resolve_api_host(
+        opts.api_host_ref()
+            .map(str::to_string)
+            .or_else(|| profile.as_ref().and_then(|p| p.api_host.clone())),
+    );
+    let reachable = check_host(&host).await;
+    let host_ok = reachable.status == CheckStatus::Pass;
+    checks.push(reachable);
+
+    checks.push(check_api_key(profile.as_ref(), &host, host_ok).await);
+    checks.push(check_token(profile.as_ref(), jwt.as_ref()));
+    checks.push(check_cache());
+    checks.push(check_completions(dirs::home_dir().as_deref()));
+
+    let count = |status| checks.iter().filter(|c| c.status == status).count();
+    let report = DoctorReport {
+        passed: count(CheckStatus::Pass),
+        warnings: count(CheckStatus::Warn),
+        failed: count(CheckStatus::Fail),
+        checks,
+    };
+
+    match opts.format {
+        OutputFormat::Json => println!("{}", crate::output::json::format_json(&report)?),
+        OutputFormat::Pretty | OutputFormat::Table => print_report(&report),
+    }
+
+    if report.failed > 0 {
+        return Err(Error::Other(format!(
+            "{} doctor check(s) failed",
+            report.failed
+        )));
+    }
+    Ok(())
+}
+
+fn print_report(report: &DoctorReport) {
+    println!("{}\n", "HawkOp Doctor".bold());
+
+    let width = report
+        .checks
+        .iter()
+        .map(|c| c.name.len())
+        .max()
+        .unwrap_or(0);
+    for check in &report.checks {
+        let marker = match check.status {
+            CheckStatus::Pass => "✓".green(),
+            CheckStatus::Warn => "⚠".yellow(),
+            CheckStatus::Fail => "✗".red(),
+            CheckStatus::Skip => "○".dimmed(),
+        };
+        println!(
+            "{} {:<width$}  {}",
+            marker,
+            check.name,
+            check.detail,
+            width = width
+        );
+        if let Some(hint) = &check.hint {
+            println!("  → {}", hint);
+        }
+    }
+
+    println!();
+    println!(
+        "{} passed, {} warning(s), {} failed",
+        report.passed, report.warnings, report.failed
+    );
+}
+
+/// Config file exists and is only readable by its owner
+fn check_config_file(path: &Path) -> Check {
+    const NAME: &str = "Config file";
+
+    let metadata = match std::fs::metadata(path) {
+        Ok(metadata) => metadata,
+        Err(_) => {
+            return Check::new(
+                NAME,
+                CheckStatus::Fail,
+                format!("{} not found", path.display()),
+            )
+            .hint("Run 'hawkop init' to create a configuration");
+        }
+    };
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        check_permissions(path, metadata.permissions().mode())
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = metadata;
+        Check::new(NAME, CheckStatus::Pass, path.display().to_string())
+    }
+}
+
+/// The config holds the API key, so group/other access is a failure
+#[cfg(unix)]
+fn check_permissions(path: &Path, mode: u32) -> Check {
+    const NAME: &str = "Config file";
+
+    let mode = mode & 0o777;
+    if mode & 0o077 == 0 {
+        Check::new(
+            NAME,
+            CheckStatus::Pass,
+            format!("{} (mode {:o})", path.display(), mode),
+        )
+    } else {
+        Check::new(
+            NAME,
+            CheckStatus::Fail,
+            format!(
+                "{} is accessible by other users (mode {:o})",
+                path.display(),
+                mode
+            ),
+        )
+        .hint(format!("Run 'chmod 600 {}'", path.display()))
+    }
+}
+
+/// Any HTTP response from the API host counts as reachable
+async fn check_host(host: &str) -> Check {
+    const NAME: &str = "API host";
+
+    let http = match reqwest::Client::builder()
+        .timeout(REACHABILITY_TIMEOUT)
+        .build()
+    {
+        Ok(http) => http,
+        Err(e) => return Check::new(NAME, CheckStatus::Fail, e.to_string()),
+    };
+
+    let started = Instant::now();
+    match http.get(host).send().await {
+        Ok(_) => Check::new(
+            NAME,
+            CheckStatus::Pass,
+            format!("{} reachable ({} ms)", host, started.elapsed().as_millis()),
+        ),
+        Err(e) => Check::new(
+            NAME,
+            CheckStatus::Fail,
+            format!("{} unreachable: {}", host, ApiError::from(e)),
+        )
+        .hint("Check network access, proxy settings, and --api-host / HAWKOP_API_HOST"),
+    }
+}
+
+/// API key is configured and accepted by the API
+async fn check_api_key(profile: Option<&ProfileConfig>, host: &str, host_ok: bool) -> Check {
+    const NAME: &str = "API key";
+
+    let Some(profile) = profile else {
+        return Check::new(NAME, CheckStatus::Skip, "no configuration loaded");
+    };
+    let Some(key) = profile.api_key.as_deref() else {
+        return Check::new(NAME, CheckStatus::Fail, "not configured")
+            .hint("Run 'hawkop auth login' to configure");
+    };
+    if !host_ok {
+        return Check::new(NAME, CheckStatus::Skip, "API host unreachable");
+    }
+
+    let client = match StackHawkClient::with_host(Some(key.to_string()), Some(host.to_string())) {
+        Ok(client) => client.with_max_retries(0),
+        Err(e) => return Check::new(NAME, CheckStatus::Fail, e.to_string()),
+    };
+    match client.authenticate(key).await {
+        Ok(_) => Check::new(NAME, CheckStatus::Pass, "valid"),
+        Err(Error::Api(ApiError::Unauthorized)) => {
+            Check::new(NAME, CheckStatus::Fail, "rejected by the API")
+                .hint("Generate a new key in StackHawk and run 'hawkop auth login'")
+        }
+        Err(e) => Check::new(NAME, CheckStatus::Fail, e.to_string()),
+    }
+}
+
+/// Cached JWT state; an expired token is refreshed automatically
+fn check_token(profile: Option<&ProfileConfig>, jwt: Option<&JwtToken>) -> Check {
+    const NAME: &str = "JWT token";
+
+    if profile.is_none() {
+        return Check::new(NAME, CheckStatus::Skip, "no configuration loaded");
+    }
+    let Some(jwt) = jwt else {
+        return Check::new(
+            NAME,
+            CheckStatus::Skip,
+            "not cached (will authenticate on next command)",
+        );
+    };
+
+    let remaining = jwt.remaining();
+    if jwt.needs_refresh() {
+        Check::new(
+            NAME,
+            CheckStatus::Warn,
+            "expired or expiring soon (will refresh on next command)",
+        )
+        .hint("Run 'hawkop auth refresh' to refresh now")
+    } else {
+        Check::new(
+            NAME,
+            CheckStatus::Pass,
+            format!(
+                "valid (expires in {}h {}m)",
+                remaining.num_hours(),
+                remaining.num_minutes() % 60
+            ),
+        )
+    }
+}
+
+/// Cache database opens and can be queried
+fn check_cache() -> Check {
+    const NAME: &str = "Cache";
+
+    match CacheStorage::open().and_then(|cache| cache.stats()) {
+        Ok(stats) => Check::new(
+            NAME,
+            CheckStatus::Pass,
+            format!(
+                "{} valid, {} expired entries",
+                stats.valid_entries, stats.expired_entries
+            ),
+        ),
+        Err(e) => Check::new(NAME, CheckStatus::Fail, e.to_string())
+            .hint("Run 'hawkop cache clear' or remove the cache directory"),
+    }
+}
+
+/// Shell completions are installed (statically or via `COMPLETE=`)
+fn check_completions(home: Option<&Path>) -> Check {
+    const NAME: &str = "Completions";
+
+    let Some(home) = home else {
+        return Check::new(NAME, CheckStatus::Skip, "home directory not found");
+    };
+    match find_completions(home) {
+        Some(path) => Check::new(NAME, CheckStatus::Pass, path.display().to_string()),
+        None => Check::new(NAME, CheckStatus::Warn, "not installed")
+            .hint("See 'hawkop completion --help' for setup"),
+    }
+}
+
+/// First completion script or shell rc file that loads hawkop completions
+pub(crate) fn find_completions(home: &Path) -> Option<PathBuf> {
+    let scripts = [
+        home.join(".local/share/bash-completion/completions/hawkop"),
+        home.join(".zfunc/_hawkop"),
+        home.join(".config/fish/completions/hawkop.fish"),
+        PathBuf::from("/etc/bash_completion.d/hawkop"),
+        PathBuf::from("/usr/share/bash-completion/completions/hawkop"),
+    ];
+    if let Some(script) = scripts.into_iter().find(|p| p.is_file()) {
+        return Some(script);
+    }
+
+    let rc_files = [
+        home.join(".bashrc"),
+        home.join(".zshrc"),
+        home.join(".config/fish/config.fish"),
+    ];
+    rc_files.into_iter().find(|path| {
+        std::fs::read_to_string(path)
+            .map(|content| {
+                content
+                    .lines()
+                    .any(|line| line.contains("COMPLETE=") && line.contains("hawkop"))
+            })
+            .unwrap_or(false)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn test_check_permissions() {
+        let path = Path::new("/tmp/config.yaml");
+        assert_eq!(check_permissions(path, 0o100600).status, CheckStatus::Pass);
+        assert_eq!(check_permissions(path, 0o400).status, CheckStatus::Pass);
+
+        let loose = check_permissions(path, 0o100644);
+        assert_eq!(loose.status, CheckStatus::Fail);
+        assert!(loose.detail.contains("mode 644"));
+        assert!(loose.hint.unwrap().contains("chmod 600"));
+    }
+
+    #[test]
+    fn test_find_completions_in_rc_file() {
+        let home = tempfile::tempdir().unwrap();
+        assert!(find_completions(home.path()).is_none());
+
+        std::fs::write(home.path().join(".zshrc"), "export PATH=$PATH\n").unwrap();
+        assert!(find_completions(home.path()).is_none());
+
+        std::fs::write(
+            home.path().join(".zshrc"),
+            "source <(COMPLETE=zsh hawkop)\n",
+        )
+        .unwrap();
+        assert_eq!(
+            find_completions(home.path()),
+            Some(home.path().join(".zshrc"))
+        );
+    }
+}
@@ -10,28 +10,26 @@ use dialoguer::Confirm;
 
 use crate::cache::CachedStackHawkClient;
 use crate::cli::args::GlobalOptions;
+use crate::cli::context::looks_like_uuid;
+use crate::cli::handlers::fetch_listing;
 use crate::cli::{CommandContext, OutputFormat, PaginationArgs};
 use crate::client::models::{Application, Environment};
 use crate::client::{EnvironmentApi, ListingApi, StackHawkClient};
-use crate::error::Result;
+use crate::error::{PromptResult, Result};
 use crate::models::EnvDisplay;
+use crate::output::Formattable;
+use crate::output::hints::hint;
 use crate::output::json::format_json;
-use crate::output::table::format_table;
 
 /// Type alias for the Arc-wrapped cached client
 type Client = Arc<CachedStackHawkClient<StackHawkClient>>;
 
-/// Simple UUID format check (8-4-4-4-12 hex pattern)
-fn looks_like_uuid(s: &str) -> bool {
-    let parts: Vec<&str> = s.split('-').collect();
-    parts.len() == 5
-        && parts
-            .iter()
-            .all(|p| p.chars().all(|c| c.is_ascii_hexdigit()))
-}
-
 /// Resolve an app identifier (name or UUID) to an Application
-async fn resolve_app(client: &Client, org_id: &str, identifier: &str) -> Result<Application> {
+pub(crate) async fn resolve_app(
+    client: &Client,
+    org_id: &str,
+    identifier: &str,
+) -> Result<Application> {
     // If it looks like a UUID, try to find by ID
     if looks_like_uuid(identifier) {
         let apps = client.list_apps(org_id, None).await?;
@@ -45,11 +43,13 @@ async fn resolve_app(client: &Client, org_id: &str, identifier: &str) -> Result<
         .into());
     }
 
-    // Otherwise, search by name (case-insensitive)
+    // Otherwise, search by name (case-insensitive). The list can hold one
+    // entry per environment, so entries with the same ID are one app.
     let apps = client.list_apps(org_id, None).await?;
+    let mut seen = std::collections::HashSet::new();
     let matches: Vec<_> = apps
         .into_iter()
-        .filter(|a| a.name.eq_ignore_ascii_case(identifier))
+        .filter(|a| a.name.eq_ignore_ascii_case(identifier) && seen.insert(a.id.clone()))
         .collect();
 
     match matches.len() {
@@ -126,10 +126,14 @@ pub async fn list(opts: &GlobalOptions, app: &str, pagination: &PaginationArgs)
 
     // Resolve app name/ID to full application
     let application = resolve_app(&client, org_id, app).await?;
-    let app_id = &application.id;
 
-    let params = pagination.to_params();
-    let envs = client.list_environments(app_id, Some(&params)).await?;
+    let app_id = application.id.clone();
+    let envs = fetch_listing(pagination, "environments", move |params| {
+        let c = client.clone();
+        let a = app_id.clone();
+        async move { c.list_environments(&a, Some(&params)).await }
+    })
+    .await?;
 
     if envs.is_empty() {
         eprintln!(
@@ -137,7 +141,7 @@ pub async fn list(opts: &GlobalOptions, app: &str, pagination: &PaginationArgs)
             "Info:".blue(),
             application.name
         );
-        eprintln!(
+        hint!(
             "{}",
             format!("→ Use `hawkop env create --app {}` to create one", app).dimmed()
         );
@@ -152,8 +156,7 @@ pub async fn list(opts: &GlobalOptions, app: &str, pagination: &PaginationArgs)
         }
         OutputFormat::Table | OutputFormat::Pretty => {
             let displays: Vec<EnvDisplay> = envs.iter().map(EnvDisplay::from).collect();
-            let table = format_table(&displays);
-            println!("{}", table);
+            displays.print(opts.format)?;
         }
     }
 
@@ -179,7 +182,7 @@ pub async fn config(
     let application = resolve_app(&client, org_id, app).await?;
     let environment = resolve_env(&client, &application.id, env).await?;
 
-    eprintln!(
+    hint!(
         "{} Fetching default config for '{}/{}'...",
         "→".blue(),
         application.name,
@@ -205,7 +208,7 @@ pub async fn config(
                         "env": environment.environment_name,
                         "content": content
                     });
-                    println!("{}", serde_json::to_string_pretty(&wrapper)?);
+                    println!("{}", crate::output::json::format_raw_json(&wrapper)?);
                 }
                 _ => {
                     println!("{}", content);
@@ -230,7 +233,7 @@ pub async fn create(opts: &GlobalOptions, app: &str, name: &str) -> Result<()> {
     // Resolve app name/ID
     let application = resolve_app(&client, org_id, app).await?;
 
-    eprintln!(
+    hint!(
         "{} Creating environment '{}' for '{}'...",
         "→".blue(),
         name,
@@ -245,7 +248,7 @@ pub async fn create(opts: &GlobalOptions, app: &str, name: &str) -> Result<()> {
         name,
         application.name
     );
-    eprintln!(
+    hint!(
         "{}",
         format!(
             "→ Use `hawkop env config --app {} {}` to get a starter config",
@@ -287,7 +290,8 @@ pub async fn delete(opts: &GlobalOptions, app: &str, env: &str, yes: bool) -> Re
                 environment.environment_name
             ))
             .default(false)
-            .interact()?;
+            .interact()
+            .prompt_result()?;
 
         if !confirmed {
             eprintln!("{}", "Cancelled".yellow());
@@ -295,7 +299,7 @@ pub async fn delete(opts: &GlobalOptions, app: &str, env: &str, yes: bool) -> Re
         }
     }
 
-    eprintln!(
+    hint!(
         "{} Deleting environment '{}'...",
         "→".blue(),
         environment.environment_name
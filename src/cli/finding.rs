@@ -0,0 +1,560 @@
+//! Finding commands (cross-scan triage)
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::io::Read;
+
+use colored::Colorize;
+use futures::stream::{self, StreamExt};
+use log::debug;
+
+use crate::cli::args::GlobalOptions;
+use crate::cli::org::for_all_orgs;
+use crate::cli::{AlertFilterArgs, CommandContext, OutputFormat, PaginationArgs, SortDir};
+use crate::client::models::{BulkTriageRequest, CurrentFindingRow, FindingTriageAction};
+use crate::client::{EnvironmentApi, ScanDetailApi, TriageApi, max_concurrency};
+use crate::error::{ApiError, Error, Result};
+use crate::integrations::jira::{DEFAULT_ISSUE_TYPE, Jira};
+use crate::integrations::{FindingTicket, Tracker};
+use crate::models::display::OrgScoped;
+use crate::models::display::finding_inventory::{
+    FindingRow, finding_status, sort_findings, sort_org_findings,
+};
+use crate::models::display::finding_triage::{TriageOutcome, TriageRow};
+use crate::output::Formattable;
+use crate::output::hints::hint;
+use crate::output::warnings::{WarningKind, warn};
+use crate::suppress::Suppressions;
+
+/// Findings shown without `--limit` or `--all`
+const DEFAULT_FINDING_LIMIT: usize = 100;
+
+/// Rows requested per page of the org findings report
+const FINDINGS_PAGE_SIZE: usize = 500;
+
+/// Run the finding list command
+///
+/// Pages through the organization findings report (the current findings of
+/// every app environment) for `apps` and keeps those matching `envs` and the
+/// severity/status filters. The report has no sort or filter parameters, so
+/// every page is fetched before sorting and paging client-side. With
+/// `all_orgs`, the reports of every accessible organization are merged (see
+/// [`for_all_orgs`]). Findings suppressed in the local suppressions file are
+/// left out.
+pub async fn list(
+    opts: &GlobalOptions,
+    apps: &[String],
+    envs: &[String],
+    filters: &AlertFilterArgs,
+    pagination: &PaginationArgs,
+    all_orgs: bool,
+) -> Result<()> {
+    let ctx = CommandContext::new(opts).await?;
+    let descending = matches!(pagination.sort_dir, Some(SortDir::Desc));
+    let sort_by = pagination.sort_by.as_deref();
+    let suppressions = Suppressions::discover()?;
+
+    if all_orgs {
+        let fetched = for_all_orgs(&ctx, opts, |org| {
+            fetch_findings(&ctx, org.id, Vec::new(), envs, filters)
+        })
+        .await?;
+        let mut findings: Vec<OrgScoped<CurrentFindingRow>> = fetched
+            .into_iter()
+            .flat_map(|(org, rows)| rows.into_iter().map(move |row| OrgScoped::new(&org, row)))
+            .collect();
+        let before = findings.len();
+        if let Some(suppressions) = &suppressions {
+            findings.retain(|f| !suppressions.suppresses_finding(&f.row));
+        }
+        let suppressed = before - findings.len();
+        sort_org_findings(&mut findings, sort_by, descending);
+        let shown = page_findings(findings, pagination);
+
+        match ctx.format {
+            OutputFormat::Json => println!("{}", crate::output::json::format_json(&shown)?),
+            OutputFormat::Pretty | OutputFormat::Table => {
+                let rows: Vec<OrgScoped<FindingRow>> = shown
+                    .iter()
+                    .map(|f| OrgScoped {
+                        org: f.org.clone(),
+                        org_id: f.org_id.clone(),
+                        row: FindingRow::from(&f.row),
+                    })
+                    .collect();
+                rows.print(ctx.format)?;
+            }
+        }
+        hint_suppressed(suppressions.as_ref(), suppressed);
+        return Ok(());
+    }
+
+    let org_id = ctx.require_org_id()?;
+    let app_ids = ctx.resolve_app_ids(apps).await?;
+    let mut findings = fetch_findings(&ctx, org_id.to_string(), app_ids, envs, filters).await?;
+    let before = findings.len();
+    if let Some(suppressions) = &suppressions {
+        findings.retain(|row| !suppressions.suppresses_finding(row));
+    }
+    let suppressed = before - findings.len();
+    sort_findings(&mut findings, sort_by, descending);
+    let shown = page_findings(findings, pagination);
+
+    match ctx.format {
+        OutputFormat::Json => println!("{}", crate::output::json::format_json(&shown)?),
+        OutputFormat::Pretty | OutputFormat::Table => {
+            let rows: Vec<FindingRow> = shown.iter().map(FindingRow::from).collect();
+            rows.print(ctx.format)?;
+        }
+    }
+    hint_suppressed(suppressions.as_ref(), suppressed);
+    Ok(())
+}
+
+/// Note how many findings local suppressions left out
+fn hint_suppressed(suppressions: Option<&Suppressions>, count: usize) {
+    if let Some(suppressions) = suppressions.filter(|_| count > 0) {
+        hint!(
+            "{} suppressed finding(s) hidden ({})",
+            count,
+            suppressions.path.display()
+        );
+    }
+}
+
+/// Every page of an org's findings report, keeping rows that match `envs`
+/// and the severity/status filters
+async fn fetch_findings(
+    ctx: &CommandContext,
+    org_id: String,
+    app_ids: Vec<String>,
+    envs: &[String],
+    filters: &AlertFilterArgs,
+) -> Result<Vec<CurrentFindingRow>> {
+    let matches = |row: &CurrentFindingRow| {
+        filters.matches_severity(row.finding_risk.as_deref().unwrap_or_default())
+            && filters.matches_status(finding_status(row))
+            && (envs.is_empty()
+                || envs.iter().any(|env| {
+                    row.environment_name
+                        .as_deref()
+                        .is_some_and(|name| name.eq_ignore_ascii_case(env))
+                }))
+    };
+
+    let mut findings = Vec::new();
+    let mut page_token = None;
+    loop {
+        let response = ctx
+            .client
+            .list_org_findings(&org_id, &app_ids, Some(FINDINGS_PAGE_SIZE), page_token)
+            .await?;
+        debug!(
+            "Findings page {:?}: {} rows, total {:?}",
+            page_token,
+            response.findings.len(),
+            response.total_findings
+        );
+        findings.extend(response.findings.into_iter().filter(|row| matches(row)));
+        page_token = response
+            .next_page_token
+            .as_deref()
+            .and_then(|token| token.parse::<usize>().ok());
+        if page_token.is_none() {
+            break;
+        }
+    }
+    Ok(findings)
+}
+
+/// The page of sorted findings to show, warning when the default limit
+/// leaves some out
+fn page_findings<T>(findings: Vec<T>, pagination: &PaginationArgs) -> Vec<T> {
+    let total = findings.len();
+    let shown: Vec<T> = if pagination.all {
+        findings
+    } else {
+        let limit = pagination.limit.unwrap_or(DEFAULT_FINDING_LIMIT);
+        let skip = pagination.page.unwrap_or(0) * limit;
+        findings.into_iter().skip(skip).take(limit).collect()
+    };
+    if pagination.limit.is_none() && pagination.page.is_none() && total > shown.len() {
+        warn(
+            WarningKind::Truncated,
+            format!(
+                "Showing the first {} of {} findings (use --limit, --page, or --all)",
+                shown.len(),
+                total
+            ),
+        );
+    }
+    shown
+}
+
+/// Where a triage row points: the app environment and finding hash
+struct Target {
+    app_id: String,
+    env_id: String,
+    finding_hash: String,
+}
+
+/// Run the finding triage-bulk command
+///
+/// Reads `scan_id,plugin_id,uri_id,status[,note]` rows from `file` or stdin,
+/// resolves each path to its finding hash and app environment, then applies
+/// the actions in batches of up to 100 per environment. Rows that fail do
+/// not stop the rest; the command fails if any row failed.
+pub async fn triage_bulk(opts: &GlobalOptions, file: Option<&str>) -> Result<()> {
+    let input = match file {
+        Some(path) => std::fs::read_to_string(path)
+            .map_err(|e| Error::Other(format!("Cannot read {}: {}", path, e)))?,
+        None => {
+            let mut input = String::new();
+            std::io::stdin().read_to_string(&mut input)?;
+            input
+        }
+    };
+    let records = crate::output::csv::parse_csv(&input)
+        .map_err(|e| Error::Other(format!("Invalid triage input: {}", e)))?;
+    let rows = TriageRow::parse_all(&records)?;
+    if rows.is_empty() {
+        return Err(Error::Other("No triage rows in input.".to_string()));
+    }
+
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?;
+
+    hint!("Resolving {} findings...", rows.len());
+    let targets = resolve_targets(&ctx, org_id, &rows).await;
+
+    let mut outcomes: Vec<Option<TriageOutcome>> = vec![None; rows.len()];
+    let mut batches: BTreeMap<(String, String), Vec<usize>> = BTreeMap::new();
+    for (i, (row, target)) in rows.iter().zip(&targets).enumerate() {
+        match target {
+            Ok(target) if ctx.dry_run => {
+                outcomes[i] = Some(TriageOutcome::new(
+                    row,
+                    Some(target.finding_hash.clone()),
+                    Ok("would triage"),
+                ));
+            }
+            Ok(target) => batches
+                .entry((target.app_id.clone(), target.env_id.clone()))
+                .or_default()
+                .push(i),
+            Err(e) => outcomes[i] = Some(TriageOutcome::new(row, None, Err(e.clone()))),
+        }
+    }
+
+    if !batches.is_empty() {
+        let requests: Vec<(Vec<usize>, BulkTriageRequest)> = batches
+            .into_iter()
+            .flat_map(|((app_id, env_id), indices)| {
+                indices
+                    .chunks(BulkTriageRequest::MAX_ACTIONS)
+                    .map(|chunk| {
+                        let actions = chunk
+                            .iter()
+                            .map(|&i| FindingTriageAction {
+                                finding_hash: target_hash(&targets[i]),
+                                status: rows[i].action.api_name().to_string(),
+                                note: rows[i].note.clone(),
+                            })
+                            .collect();
+                        let request = BulkTriageRequest {
+                            organization_id: org_id.to_string(),
+                            application_id: app_id.clone(),
+                            environment_id: env_id.clone(),
+                            actions,
+                        };
+                        (chunk.to_vec(), request)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        hint!(
+            "Applying {} triage actions in {} requests...",
+            requests.iter().map(|(i, _)| i.len()).sum::<usize>(),
+            requests.len()
+        );
+
+        let responses: Vec<_> = stream::iter(requests)
+            .map(|(indices, request)| {
+                let client = ctx.client.clone();
+                async move { (indices, client.bulk_triage_findings(request).await) }
+            })
+            .buffer_unordered(max_concurrency())
+            .collect()
+            .await;
+
+        for (indices, response) in responses {
+            match response {
+                Ok(response) => {
+                    let mut results = response.results.into_iter();
+                    for i in indices {
+                        let hash = Some(target_hash(&targets[i]));
+                        let outcome = match results.next() {
+                            Some(r) if r.success => Ok("triaged"),
+                            Some(r) => Err(r.error.unwrap_or_else(|| "rejected".to_string())),
+                            None => Err("no result returned".to_string()),
+                        };
+                        outcomes[i] = Some(TriageOutcome::new(&rows[i], hash, outcome));
+                    }
+                }
+                Err(e) => {
+                    debug!("Bulk triage request failed: {}", e);
+                    for i in indices {
+                        let hash = Some(target_hash(&targets[i]));
+                        outcomes[i] = Some(TriageOutcome::new(&rows[i], hash, Err(e.to_string())));
+                    }
+                }
+            }
+        }
+    }
+
+    let outcomes: Vec<TriageOutcome> = outcomes.into_iter().flatten().collect();
+    let failed = outcomes.iter().filter(|o| !o.success).count();
+    let total = outcomes.len();
+
+    if ctx.dry_run {
+        eprintln!("{}", "DRY RUN - no changes will be made".yellow());
+        eprintln!();
+    }
+    match ctx.format {
+        OutputFormat::Json => println!("{}", crate::output::json::format_json(&outcomes)?),
+        _ => outcomes.print(ctx.format)?,
+    }
+
+    let succeeded = total - failed;
+    if ctx.dry_run {
+        eprintln!(
+            "Would triage {} of {} findings ({} unresolved)",
+            succeeded, total, failed
+        );
+    } else if failed == 0 {
+        eprintln!("{} Triaged {} findings", "✓".green(), total);
+    } else {
+        eprintln!(
+            "{} Triaged {} of {} findings ({} failed)",
+            "✗".red(),
+            succeeded,
+            total,
+            failed
+        );
+    }
+
+    if failed > 0 {
+        return Err(Error::Other(format!(
+            "{} of {} triage rows failed",
+            failed, total
+        )));
+    }
+    Ok(())
+}
+
+fn target_hash(target: &std::result::Result<Target, String>) -> String {
+    target
+        .as_ref()
+        .map(|t| t.finding_hash.clone())
+        .unwrap_or_default()
+}
+
+/// Resolve each row to its app, environment ID, and finding hash
+///
+/// Scans, plugin paths, and environment lists are each fetched once with
+/// bounded concurrency; lookup failures become per-row errors.
+async fn resolve_targets(
+    ctx: &CommandContext,
+    org_id: &str,
+    rows: &[TriageRow],
+) -> Vec<std::result::Result<Target, String>> {
+    let scan_ids: BTreeSet<&str> = rows.iter().map(|r| r.scan_id.as_str()).collect();
+    let scans: HashMap<&str, _> = stream::iter(scan_ids)
+        .map(|scan_id| {
+            let client = ctx.client.clone();
+            async move { (scan_id, client.get_scan(org_id, scan_id).await) }
+        })
+        .buffer_unordered(max_concurrency())
+        .map(|(id, r)| (id, r.map_err(|e| e.to_string())))
+        .collect()
+        .await;
+
+    let plugins: BTreeSet<(&str, &str)> = rows
+        .iter()
+        .filter(|r| scans.get(r.scan_id.as_str()).is_some_and(|s| s.is_ok()))
+        .map(|r| (r.scan_id.as_str(), r.plugin_id.as_str()))
+        .collect();
+    let paths: HashMap<(&str, &str), _> = stream::iter(plugins)
+        .map(|(scan_id, plugin_id)| {
+            let client = ctx.client.clone();
+            async move {
+                let result = client.get_alert_with_paths(scan_id, plugin_id, None).await;
+                ((scan_id, plugin_id), result.map_err(|e| e.to_string()))
+            }
+        })
+        .buffer_unordered(max_concurrency())
+        .collect()
+        .await;
+
+    let app_ids: BTreeSet<&str> = scans
+        .values()
+        .filter_map(|s| s.as_ref().ok())
+        .map(|s| s.scan.application_id.as_str())
+        .collect();
+    let envs: HashMap<&str, _> = stream::iter(app_ids)
+        .map(|app_id| {
+            let client = ctx.client.clone();
+            async move {
+                let result = client.list_environments(app_id, None).await;
+                (app_id, result.map_err(|e| e.to_string()))
+            }
+        })
+        .buffer_unordered(max_concurrency())
+        .collect()
+        .await;
+
+    rows.iter()
+        .map(|row| {
+            let scan = scans[row.scan_id.as_str()]
+                .as_ref()
+                .map_err(|e| format!("scan: {}", e))?;
+            let alert = paths[&(row.scan_id.as_str(), row.plugin_id.as_str())]
+                .as_ref()
+                .map_err(|e| format!("plugin: {}", e))?;
+            let uri = alert
+                .application_scan_alert_uris
+                .iter()
+                .find(|u| u.alert_uri_id == row.uri_id)
+                .ok_or_else(|| {
+                    format!("path {} not found in plugin {}", row.uri_id, row.plugin_id)
+                })?;
+            let finding_hash = uri
+                .finding_hash
+                .clone()
+                .ok_or_else(|| "path has no finding hash".to_string())?;
+
+            let app_id = &scan.scan.application_id;
+            let env_id = envs[app_id.as_str()]
+                .as_ref()
+                .map_err(|e| format!("environments: {}", e))?
+                .iter()
+                .find(|e| e.environment_name == scan.scan.env)
+                .map(|e| e.environment_id.clone())
+                .ok_or_else(|| format!("environment {} not found", scan.scan.env))?;
+
+            Ok(Target {
+                app_id: app_id.clone(),
+                env_id,
+                finding_hash,
+            })
+        })
+        .collect()
+}
+
+/// Run the finding ticket command
+///
+/// Gathers the plugin's details and every affected path, plus the evidence
+/// and curl reproduction of `uri_id` (or the first path), and files them as
+/// a Jira issue. With `--dry-run` the issue is printed instead, and no Jira
+/// credentials are needed.
+pub async fn ticket(
+    opts: &GlobalOptions,
+    scan_id: &str,
+    plugin_id: &str,
+    uri_id: Option<&str>,
+    project: Option<String>,
+    issue_type: Option<String>,
+) -> Result<()> {
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?;
+
+    let settings = ctx.profiled_config.integrations.jira.as_ref();
+    let project = project
+        .or_else(|| settings.and_then(|s| s.project.clone()))
+        .ok_or_else(|| {
+            Error::Other(
+                "A Jira project is required: pass --jira-project or set \
+                 integrations.jira.project in the hawkop config"
+                    .to_string(),
+            )
+        })?;
+    let issue_type = issue_type
+        .or_else(|| settings.and_then(|s| s.issue_type.clone()))
+        .unwrap_or_else(|| DEFAULT_ISSUE_TYPE.to_string());
+    let jira = Jira::new(settings, project, issue_type);
+
+    hint!("Fetching plugin {} in scan {}...", plugin_id, scan_id);
+    let scan = ctx.client.get_scan(org_id, scan_id).await?;
+    let response = ctx
+        .client
+        .get_alert_with_paths(scan_id, plugin_id, None)
+        .await?;
+    let example = match uri_id {
+        Some(uri_id) => Some(
+            response
+                .application_scan_alert_uris
+                .iter()
+                .find(|u| u.alert_uri_id == uri_id)
+                .ok_or_else(|| {
+                    ApiError::NotFound(format!(
+                        "Path '{}' not found in plugin {}. Use 'hawkop scan get {} --plugin-id {}' to see its paths.",
+                        uri_id, plugin_id, scan_id, plugin_id
+                    ))
+                })?,
+        ),
+        None => response.application_scan_alert_uris.first(),
+    };
+    let message = match example {
+        Some(uri) => Some(
+            ctx.client
+                .get_alert_message(scan_id, &uri.alert_uri_id, &uri.msg_id, true)
+                .await?,
+        ),
+        None => None,
+    };
+    let finding = FindingTicket::new(&scan, &response, example.zip(message.as_ref()));
+
+    if ctx.dry_run {
+        let body = jira.preview(&finding);
+        match ctx.format {
+            OutputFormat::Json => println!("{}", crate::output::json::format_raw_json(&body)?),
+            _ => {
+                eprintln!("{}", "DRY RUN - no issue will be created".yellow());
+                eprintln!();
+                let fields = &body["fields"];
+                println!(
+                    "Project:  {}",
+                    fields["project"]["key"].as_str().unwrap_or_default()
+                );
+                println!(
+                    "Type:     {}",
+                    fields["issuetype"]["name"].as_str().unwrap_or_default()
+                );
+                println!(
+                    "Summary:  {}",
+                    fields["summary"].as_str().unwrap_or_default()
+                );
+                println!();
+                println!("{}", fields["description"].as_str().unwrap_or_default());
+            }
+        }
+        return Ok(());
+    }
+
+    hint!("Creating Jira issue...");
+    let created = jira.create(&finding).await?;
+    match ctx.format {
+        OutputFormat::Json => println!("{}", crate::output::json::format_json(&created)?),
+        _ => {
+            eprintln!(
+                "{} Created {} for plugin {} in scan {}",
+                "✓".green(),
+                created.key,
+                plugin_id,
+                scan_id
+            );
+            println!("{}", created.url);
+        }
+    }
+    Ok(())
+}
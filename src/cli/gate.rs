@@ -0,0 +1,83 @@
+//! Gate commands (thresholds across applications)
+
+use colored::Colorize;
+use log::debug;
+
+use crate::cli::args::GlobalOptions;
+use crate::cli::{CommandContext, FindingsThreshold, OutputFormat};
+use crate::client::ScanFilterParams;
+use crate::error::{Error, Result};
+use crate::models::display::release_gate::{ReleaseApp, ReleaseGate};
+use crate::output::Formattable;
+
+/// Run the gate release command
+///
+/// Fetches the scans of every app in `apps` (limited to `envs` when given)
+/// in one paginated listing, evaluates the latest completed scan of each
+/// app/env against `thresholds`, and fails with the threshold exit code if
+/// any app/env failed or has no completed scan.
+pub async fn release(
+    opts: &GlobalOptions,
+    apps: &[String],
+    envs: &[String],
+    thresholds: &[FindingsThreshold],
+    include_triaged: bool,
+) -> Result<()> {
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?;
+
+    let app_ids = ctx.resolve_app_ids(apps).await?;
+    let release_apps: Vec<ReleaseApp> = apps
+        .iter()
+        .zip(&app_ids)
+        .map(|(label, id)| ReleaseApp {
+            label: label.clone(),
+            id: id.clone(),
+        })
+        .collect();
+
+    let filters = ScanFilterParams::new().app_ids(app_ids).envs(envs.to_vec());
+    let scans =
+        crate::cli::scan::fetch_all_scans(ctx.client.clone(), org_id, Some(filters)).await?;
+
+    let gate = ReleaseGate::evaluate(&release_apps, envs, &scans, thresholds, include_triaged);
+    debug!(
+        "Release gate over {} app/envs: passed={}",
+        gate.entries.len(),
+        gate.passed
+    );
+
+    if opts.junit {
+        println!(
+            "{}",
+            crate::output::junit::format_junit("hawkop gate release", &gate.junit_suites())
+        );
+    } else {
+        match ctx.format {
+            OutputFormat::Json => {
+                println!("{}", crate::output::json::format_json(&gate)?);
+            }
+            OutputFormat::Pretty | OutputFormat::Table => {
+                println!(
+                    "Release gate: {} app/envs | counting {} findings",
+                    gate.entries.len(),
+                    gate.counted
+                );
+                println!();
+                gate.rows().print(ctx.format)?;
+                println!();
+                if gate.passed {
+                    println!("{} Release gate passed", "✓".green());
+                } else {
+                    println!("{} Release gate failed", "✗".red());
+                }
+            }
+        }
+    }
+
+    if gate.passed {
+        Ok(())
+    } else {
+        Err(Error::ThresholdExceeded(gate.failure_summary()))
+    }
+}
@@ -18,9 +18,13 @@ use tabled::Tabled;
 use crate::cache::CachedStackHawkClient;
 use crate::cli::args::GlobalOptions;
 use crate::cli::{CommandContext, PaginationArgs};
-use crate::client::{PaginationParams, StackHawkClient};
+use crate::client::{
+    PagedResponse, PaginationParams, StackHawkClient, default_page_size, fetch_all_pages,
+    max_concurrency,
+};
 use crate::error::Result;
 use crate::output::Formattable;
+use crate::output::warnings::{WarningKind, warn};
 
 /// Run a standard list command with the common fetch → limit → display → print pattern.
 ///
@@ -60,16 +64,24 @@ pub async fn run_list_command<T, D, Fut, F>(
 where
     T: 'static,
     D: From<T> + Tabled + Serialize,
-    Fut: Future<Output = Result<Vec<T>>>,
-    F: FnOnce(Arc<CachedStackHawkClient<StackHawkClient>>, String, PaginationParams) -> Fut,
+    T: Send,
+    Fut: Future<Output = Result<Vec<T>>> + Send + 'static,
+    F: Fn(Arc<CachedStackHawkClient<StackHawkClient>>, String, PaginationParams) -> Fut
+        + Send
+        + Sync
+        + 'static,
 {
     let ctx = CommandContext::new(opts).await?;
     let org_id = ctx.require_org_id()?;
 
     debug!("Fetching {} for org {}", resource_name, org_id);
 
-    let params = pagination.to_params();
-    let items = fetcher(ctx.client.clone(), org_id.to_string(), params).await?;
+    let client = ctx.client.clone();
+    let org = org_id.to_string();
+    let items = fetch_listing(pagination, resource_name, move |params| {
+        fetcher(client.clone(), org.clone(), params)
+    })
+    .await?;
 
     debug!("Fetched {} {}", items.len(), resource_name);
 
@@ -87,6 +99,49 @@ where
     Ok(())
 }
 
+/// Fetch a listing page, or every page with `--all`.
+///
+/// Without `--all` only the requested page is fetched. If it comes back full
+/// and neither `--limit` nor `--page` was given, a truncation warning points
+/// at `--all`.
+pub async fn fetch_listing<T, Fut, F>(
+    pagination: &PaginationArgs,
+    resource_name: &str,
+    fetch_page: F,
+) -> Result<Vec<T>>
+where
+    T: Send + 'static,
+    Fut: Future<Output = Result<Vec<T>>> + Send + 'static,
+    F: Fn(PaginationParams) -> Fut + Send + Sync + 'static,
+{
+    let params = pagination.to_params();
+    let page_size = params.page_size.unwrap_or_else(default_page_size);
+    let page = params.page.unwrap_or(0);
+    let items = fetch_page(params.clone()).await?;
+
+    if pagination.all {
+        let first = PagedResponse::new(items, None, page_size, page);
+        return fetch_all_pages(
+            first,
+            move |page| fetch_page(params.clone().page(page)),
+            max_concurrency(),
+        )
+        .await;
+    }
+
+    if pagination.limit.is_none() && pagination.page.is_none() && items.len() >= page_size {
+        warn(
+            WarningKind::Truncated,
+            format!(
+                "Showing the first {} {}; more may exist (use --all to fetch every page)",
+                items.len(),
+                resource_name
+            ),
+        );
+    }
+    Ok(items)
+}
+
 #[cfg(test)]
 mod tests {
     // Tests would require mocking CommandContext which is complex.
@@ -2,4 +2,4 @@
 
 mod list;
 
-pub use list::run_list_command;
+pub use list::{fetch_listing, run_list_command};
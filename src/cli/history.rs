@@ -0,0 +1,123 @@
+//! Command history commands (`history`, `rerun`)
+
+use colored::Colorize;
+
+use crate::cli::OutputFormat;
+use crate::error::{Error, Result};
+use crate::history::{self, History, HistoryEntry};
+use crate::models::HistoryDisplay;
+use crate::output::Formattable;
+use crate::output::hints::hint;
+
+/// Default number of entries shown by `history list`
+const DEFAULT_HISTORY_LIMIT: usize = 20;
+
+/// List recent history entries (most recent last, like shell history)
+pub fn list(format: OutputFormat, limit: Option<usize>) -> Result<()> {
+    let entries = History::open()?.entries()?;
+    let limit = limit.unwrap_or(DEFAULT_HISTORY_LIMIT);
+    let start = entries.len().saturating_sub(limit);
+    print_entries(format, &entries[start..])
+}
+
+/// Search history by resource (e.g. "scans") and optional text
+pub fn search(
+    format: OutputFormat,
+    resource: &str,
+    query: Option<&str>,
+    limit: Option<usize>,
+) -> Result<()> {
+    let entries = History::open()?.entries()?;
+    let matches: Vec<HistoryEntry> = entries
+        .into_iter()
+        .filter(|e| e.matches_resource(resource))
+        .filter(|e| query.is_none_or(|q| e.matches_query(q)))
+        .collect();
+
+    let limit = limit.unwrap_or(DEFAULT_HISTORY_LIMIT);
+    let start = matches.len().saturating_sub(limit);
+    print_entries(format, &matches[start..])
+}
+
+/// Remove all recorded history
+pub fn clear(format: OutputFormat) -> Result<()> {
+    let removed = History::open()?.clear()?;
+
+    match format {
+        OutputFormat::Json => {
+            let json = serde_json::json!({
+                "entries_removed": removed,
+                "success": true,
+            });
+            println!("{}", crate::output::json::format_raw_json(&json)?);
+        }
+        _ => {
+            if removed > 0 {
+                println!("Cleared {} history entries", removed);
+            } else {
+                println!("History was already empty");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Show history file path
+pub fn path() -> Result<()> {
+    println!("{}", History::open()?.path().display());
+    Ok(())
+}
+
+/// Re-execute a recorded command (`rerun <n>` or `rerun --last`)
+///
+/// The command runs as a child process with the recorded arguments so its
+/// output and exit code are exactly those of the original invocation.
+pub fn rerun(id: Option<usize>) -> Result<()> {
+    let entries = History::open()?.entries()?;
+
+    let entry = match id {
+        Some(n) => entries.iter().find(|e| e.id == n).ok_or_else(|| {
+            Error::Other(format!(
+                "No history entry #{}. Run `hawkop history list` to see entries.",
+                n
+            ))
+        })?,
+        None => entries.last().ok_or_else(|| {
+            Error::Other(format!(
+                "No command history recorded. Enable it with `preferences.history: true` \
+                 in your config or {}=1.",
+                history::HISTORY_ENV
+            ))
+        })?,
+    };
+
+    hint!("{} hawkop {}", "→".cyan(), entry.command_line());
+
+    let exe = std::env::current_exe()?;
+    let mut command = std::process::Command::new(exe);
+    command.args(&entry.args);
+    if let Some(ref profile) = entry.profile {
+        command.env("HAWKOP_PROFILE", profile);
+    }
+    if let Some(ref org) = entry.org_id {
+        command.env("HAWKOP_ORG_ID", org);
+    }
+
+    let status = command.status()?;
+    if !status.success() {
+        std::process::exit(status.code().unwrap_or(1));
+    }
+
+    Ok(())
+}
+
+fn print_entries(format: OutputFormat, entries: &[HistoryEntry]) -> Result<()> {
+    if entries.is_empty() && format != OutputFormat::Json && !crate::output::columns::ids_only() {
+        println!("No matching history entries.");
+        return Ok(());
+    }
+
+    let rows: Vec<HistoryDisplay> = entries.iter().map(HistoryDisplay::from).collect();
+    rows.print(format)
+}
@@ -1,23 +1,61 @@
 //! Init command implementation
 
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
 use colored::Colorize;
 use dialoguer::{Confirm, Password, Select, theme::ColorfulTheme};
 
 use crate::cli::args::GlobalOptions;
+use crate::cli::doctor::find_completions;
+use crate::client::models::JwtToken;
 use crate::client::{AppApi, AuthApi, ListingApi, StackHawkClient};
-use crate::config::{ProfileConfig, ProfiledConfig};
-use crate::error::Result;
+use crate::config::crypto::read_passphrase;
+use crate::config::{ProfileConfig, ProfiledConfig, TokenStore};
+use crate::error::{ApiError, ConfigError, Error, PromptResult, Result};
 use crate::git;
+use crate::output::hints::hint;
 
 /// Run the init command
 ///
+/// A wizard: the API key is checked live (and asked for again if rejected),
+/// then the default organization is picked, completions are offered for the
+/// detected shell, and the response cache is turned on or off. The config
+/// is written once, after the last answer, so cancelling midway leaves it
+/// untouched.
+///
 /// During interactive setup, the default production API is used. Custom API
 /// hosts can be configured manually in the config file or via environment
 /// variables after initialization.
-pub async fn run(opts: &GlobalOptions) -> Result<()> {
+///
+/// With `--encrypt` on an already configured profile, the existing config is
+/// encrypted in place instead of running setup again; `--decrypt` turns
+/// encryption off.
+pub async fn run(opts: &GlobalOptions, encrypt: bool, decrypt: bool) -> Result<()> {
     // Determine which profile to initialize
     let profile_name = opts.profile.as_deref().unwrap_or("default");
 
+    // An unreadable config must not be replaced by a fresh one
+    let existing = match ProfiledConfig::load_at(opts.config_ref()) {
+        Ok(config) => Some(config),
+        Err(Error::Config(ConfigError::NotFound)) => None,
+        Err(e) => return Err(e),
+    };
+
+    if decrypt {
+        return disable_encryption(opts, existing);
+    }
+    if encrypt
+        && let Some(config) = existing.as_ref().filter(|c| {
+            c.profiles
+                .get(profile_name)
+                .is_some_and(|p| p.api_key.is_some())
+        })
+    {
+        return enable_encryption(opts, config.clone());
+    }
+
     println!("{}", "Welcome to HawkOp!".bold().green());
     if profile_name != "default" {
         println!("Setting up profile: {}\n", profile_name.bold());
@@ -25,63 +63,100 @@ pub async fn run(opts: &GlobalOptions) -> Result<()> {
         println!("Let's set up your StackHawk configuration.\n");
     }
 
-    // Prompt for API key
-    let api_key: String = Password::with_theme(&ColorfulTheme::default())
-        .with_prompt("Enter your StackHawk API key")
-        .interact()?;
-
-    // Authenticate and get JWT (uses custom API host if provided)
-    println!("\n{}", "Authenticating...".cyan());
-    let client = StackHawkClient::with_host(Some(api_key.clone()), opts.api_host.clone())?;
-    let jwt_token = client.authenticate(&api_key).await?;
+    let mut profiled_config = existing.unwrap_or_default();
+    let current = profiled_config.profiles.get(profile_name).cloned();
+    let theme = ColorfulTheme::default();
 
-    println!("{}", "✓ Authentication successful!".green());
+    // Step 1: API key, checked against the API before anything is saved
+    let (api_key, client, jwt) = prompt_api_key(&theme, opts.api_host.clone()).await?;
 
-    // Get organizations
+    // Step 2: default organization
     println!("\n{}", "Fetching your organizations...".cyan());
-    client.set_jwt(jwt_token.clone()).await;
     let orgs = client.list_orgs().await?;
-
-    // Prompt for default organization
+    let current_org = current.as_ref().and_then(|p| p.org_id.as_deref());
     let org_id = if orgs.is_empty() {
         println!("{}", "⚠ No organizations found.".yellow());
         None
     } else if orgs.len() == 1 {
         let org = &orgs[0];
-        println!("Found organization: {}", org.name.bold());
-        let use_org = dialoguer::Confirm::with_theme(&ColorfulTheme::default())
+        println!("Found organization: {} ({})", org.name.bold(), org.id);
+        let use_org = Confirm::with_theme(&theme)
             .with_prompt("Set this as your default organization?")
             .default(true)
-            .interact()?;
+            .interact()
+            .prompt_result()?;
 
         if use_org { Some(org.id.clone()) } else { None }
     } else {
-        let org_names: Vec<String> = orgs.iter().map(|o| o.name.clone()).collect();
+        let org_names: Vec<String> = orgs
+            .iter()
+            .map(|o| format!("{} ({})", o.name, o.id))
+            .collect();
+        let default = orgs
+            .iter()
+            .position(|o| Some(o.id.as_str()) == current_org)
+            .unwrap_or(0);
 
         println!("Found {} organizations.", orgs.len());
-        let selection = Select::with_theme(&ColorfulTheme::default())
-            .with_prompt("Select your default organization")
+        let selection = Select::with_theme(&theme)
+            .with_prompt("Select your default organization (Esc for none)")
             .items(&org_names)
-            .default(0)
-            .interact_opt()?;
+            .default(default)
+            .interact_opt()
+            .prompt_result()?;
 
         selection.map(|idx| orgs[idx].id.clone())
     };
 
+    // Step 3: shell completions, unless already set up
+    let completions = match (std::env::var("SHELL"), dirs::home_dir()) {
+        (Ok(shell), Some(home)) if find_completions(&home).is_none() => {
+            CompletionSetup::for_shell(&shell, &home)
+        }
+        _ => None,
+    };
+    let install_completions = match &completions {
+        Some(setup) => Confirm::with_theme(&theme)
+            .with_prompt(format!(
+                "Install {} completions in {}?",
+                setup.shell,
+                setup.rc_file.display()
+            ))
+            .default(true)
+            .interact()
+            .prompt_result()?,
+        None => false,
+    };
+
+    // Step 4: response cache
+    let cache = &mut profiled_config.cache;
+    let cache_enabled = Confirm::with_theme(&theme)
+        .with_prompt("Cache API responses locally for faster repeat commands?")
+        .default(cache.is_enabled())
+        .interact()
+        .prompt_result()?;
+    cache.enabled = (!cache_enabled).then_some(false);
+    if cache_enabled {
+        cache.redact_sensitive = Confirm::with_theme(&theme)
+            .with_prompt("Redact credentials in cached scan messages?")
+            .default(cache.redact_sensitive)
+            .interact()
+            .prompt_result()?;
+    }
+
     // Create the profile config
     let profile = ProfileConfig {
-        api_key: Some(api_key),
+        api_key: Some(api_key.clone()),
         org_id,
         api_host: opts.api_host.clone(),
-        jwt: Some(crate::config::JwtToken {
-            token: jwt_token.token,
-            expires_at: jwt_token.expires_at,
-        }),
-        preferences: Default::default(),
+        jwt: None,
+        preferences: current.map(|p| p.preferences).unwrap_or_default(),
     };
 
-    // Load or create profiled config
-    let mut profiled_config = ProfiledConfig::load_at(opts.config_ref()).unwrap_or_default();
+    if encrypt && !profiled_config.is_encrypted() {
+        let passphrase = read_passphrase("Choose a config passphrase", true)?;
+        profiled_config.enable_encryption(&passphrase)?;
+    }
 
     // Add/update the profile
     if profiled_config.profiles.contains_key(profile_name) {
@@ -97,9 +172,21 @@ pub async fn run(opts: &GlobalOptions) -> Result<()> {
         profiled_config.set_active_profile(profile_name)?;
     }
 
+    // Everything is written in one pass, once every answer is in
     profiled_config.save_at(opts.config_ref())?;
 
+    // Store the token so the first command doesn't authenticate again
     let config_path = ProfiledConfig::resolve_path(opts.config_ref())?;
+    let tokens = TokenStore::new(
+        TokenStore::dir_for_config(&config_path),
+        &api_key,
+        opts.api_host.as_deref(),
+        profiled_config.encryption.as_ref(),
+    );
+    if let Err(e) = tokens.save(&(&jwt).into()) {
+        log::debug!("Could not store token: {}", e);
+    }
+
     println!(
         "\n{} Configuration saved to: {}",
         "✓".green(),
@@ -113,6 +200,24 @@ pub async fn run(opts: &GlobalOptions) -> Result<()> {
     if let Some(org_id) = &profiled_config.get_profile(profile_name)?.org_id {
         println!("  Default organization: {}", org_id.bold());
     }
+    println!(
+        "  Response cache: {}",
+        if cache_enabled { "on" } else { "off" }
+    );
+
+    if let Some(setup) = completions.filter(|_| install_completions) {
+        match setup.install() {
+            Ok(()) => println!(
+                "{} Completions added to {} (open a new shell to use them)",
+                "✓".green(),
+                setup.rc_file.display()
+            ),
+            Err(e) => {
+                eprintln!("{} Could not install completions: {}", "⚠".yellow(), e);
+                hint!("→ See 'hawkop completion --help' for manual setup");
+            }
+        }
+    }
 
     println!("\n{}", "You're all set! Try running:".bold());
     println!("  {} - Show configuration status", "hawkop status".cyan());
@@ -126,6 +231,137 @@ pub async fn run(opts: &GlobalOptions) -> Result<()> {
     Ok(())
 }
 
+/// Attempts at entering an API key before init gives up
+const MAX_KEY_ATTEMPTS: usize = 3;
+
+/// Prompt for an API key until the API accepts it
+///
+/// Returns the key with a client already authenticated by it, and the JWT
+/// it got. Errors other than a rejected key end the wizard, as does a key
+/// rejected [`MAX_KEY_ATTEMPTS`] times.
+async fn prompt_api_key(
+    theme: &ColorfulTheme,
+    api_host: Option<String>,
+) -> Result<(String, StackHawkClient, JwtToken)> {
+    let mut attempt = 1;
+    loop {
+        let api_key: String = Password::with_theme(theme)
+            .with_prompt("Enter your StackHawk API key")
+            .interact()
+            .prompt_result()?;
+        let api_key = api_key.trim().to_string();
+
+        println!("\n{}", "Authenticating...".cyan());
+        let client = StackHawkClient::with_host(Some(api_key.clone()), api_host.clone())?;
+        match client.authenticate(&api_key).await {
+            Ok(jwt) => {
+                println!("{}", "✓ Authentication successful!".green());
+                client.set_jwt(jwt.clone()).await;
+                return Ok((api_key, client, jwt));
+            }
+            Err(Error::Api(ApiError::Unauthorized)) if attempt < MAX_KEY_ATTEMPTS => {
+                println!(
+                    "{} That API key was not accepted; check it and try again.\n",
+                    "✗".red()
+                );
+                attempt += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Where `init` installs dynamic completions for the user's shell
+#[derive(Debug, PartialEq)]
+struct CompletionSetup {
+    /// Shell name
+    shell: &'static str,
+    /// Startup file the loading line is appended to
+    rc_file: PathBuf,
+    /// Line that loads hawkop completions, as in `hawkop completion --help`
+    line: &'static str,
+}
+
+impl CompletionSetup {
+    /// Setup for the shell at `shell_path` (`$SHELL`); None for shells
+    /// other than bash, zsh, and fish
+    fn for_shell(shell_path: &str, home: &Path) -> Option<Self> {
+        let name = Path::new(shell_path).file_name()?.to_str()?;
+        let (shell, rc_file, line) = match name {
+            "bash" => ("bash", ".bashrc", "source <(COMPLETE=bash hawkop)"),
+            "zsh" => ("zsh", ".zshrc", "source <(COMPLETE=zsh hawkop)"),
+            "fish" => (
+                "fish",
+                ".config/fish/config.fish",
+                "COMPLETE=fish hawkop | source",
+            ),
+            _ => return None,
+        };
+        Some(Self {
+            shell,
+            rc_file: home.join(rc_file),
+            line,
+        })
+    }
+
+    /// Append the loading line to the rc file
+    fn install(&self) -> std::io::Result<()> {
+        if let Some(parent) = self.rc_file.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.rc_file)?;
+        writeln!(file, "\n# hawkop shell completions\n{}", self.line)
+    }
+}
+
+/// Encrypt the secrets of an existing (plaintext or encrypted) config
+///
+/// Re-running on an encrypted config changes the passphrase.
+fn enable_encryption(opts: &GlobalOptions, mut config: ProfiledConfig) -> Result<()> {
+    let passphrase = read_passphrase("Choose a config passphrase", true)?;
+    let rekey = config.is_encrypted();
+    config.enable_encryption(&passphrase)?;
+    config.save_at(opts.config_ref())?;
+
+    let config_path = ProfiledConfig::resolve_path(opts.config_ref())?;
+    println!(
+        "{} {} {}",
+        "✓".green(),
+        if rekey {
+            "Passphrase changed for"
+        } else {
+            "API keys and tokens encrypted in"
+        },
+        config_path.display()
+    );
+    hint!(
+        "→ Set {} for non-interactive use, or enter the passphrase when prompted",
+        crate::config::crypto::PASSPHRASE_ENV
+    );
+    Ok(())
+}
+
+/// Store secrets in plaintext again
+fn disable_encryption(opts: &GlobalOptions, config: Option<ProfiledConfig>) -> Result<()> {
+    let Some(mut config) = config.filter(ProfiledConfig::is_encrypted) else {
+        println!("Configuration is not encrypted; nothing to do.");
+        return Ok(());
+    };
+    config.disable_encryption();
+    config.save_at(opts.config_ref())?;
+
+    let config_path = ProfiledConfig::resolve_path(opts.config_ref())?;
+    println!(
+        "{} Encryption removed; secrets in {} are stored in plaintext",
+        "✓".green(),
+        config_path.display()
+    );
+    Ok(())
+}
+
 /// After init completes, check if the user is in a git repo and offer to
 /// create an app + link it. This drives API Discovery adoption by reducing
 /// the gap between "set up auth" and "first scan."
@@ -253,7 +489,7 @@ async fn offer_create_and_link(
         }
         Err(e) => {
             eprintln!("  {} Could not link to repository: {}", "⚠".yellow(), e);
-            eprintln!(
+            hint!(
                 "  → hawkop repo link --app-id {} --repo {}",
                 app.id,
                 local_repo.full_name()
@@ -266,3 +502,29 @@ async fn offer_create_and_link(
     println!("  App ID for stackhawk.yml: {}", app.id.bold());
     println!("  → {} to run your first scan", "hawk scan".cyan());
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_completion_setup_for_shell() {
+        let home = Path::new("/home/u");
+        let zsh = CompletionSetup::for_shell("/bin/zsh", home).unwrap();
+        assert_eq!(zsh.rc_file, home.join(".zshrc"));
+        assert_eq!(zsh.line, "source <(COMPLETE=zsh hawkop)");
+
+        let fish = CompletionSetup::for_shell("/usr/local/bin/fish", home).unwrap();
+        assert_eq!(fish.rc_file, home.join(".config/fish/config.fish"));
+        assert!(CompletionSetup::for_shell("/bin/tcsh", home).is_none());
+        assert!(CompletionSetup::for_shell("", home).is_none());
+    }
+
+    #[test]
+    fn test_completion_install_is_found_by_doctor() {
+        let home = tempfile::tempdir().unwrap();
+        let setup = CompletionSetup::for_shell("/bin/fish", home.path()).unwrap();
+        setup.install().unwrap();
+        assert_eq!(find_completions(home.path()), Some(setup.rc_file));
+    }
+}
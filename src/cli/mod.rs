@@ -4,41 +4,60 @@ use clap::{Parser, Subcommand};
 pub use clap_complete::Shell;
 
 use completions::{
-    app_id_candidates, app_name_candidates, plugin_id_candidates, repo_id_candidates,
-    repo_name_candidates, scan_id_candidates, team_name_candidates, uri_id_candidates,
-    user_email_candidates,
+    app_id_candidates, app_name_candidates, config_name_candidates, org_id_candidates,
+    plugin_id_candidates, repo_id_candidates, repo_name_candidates, scan_id_candidates,
+    scan_id_or_latest_candidates, team_name_candidates, uri_id_candidates, user_email_candidates,
 };
 
+pub mod api;
 pub mod app;
 pub mod args;
 pub mod audit;
+pub mod auth;
 pub mod cache;
 pub mod completions;
 pub mod config;
 pub mod context;
+pub mod daemon;
+pub mod doctor;
 pub mod env;
+pub mod finding;
+pub mod gate;
 pub mod handlers;
+pub mod history;
 pub mod init;
 pub mod oas;
 pub mod org;
 pub mod policy;
 pub mod profile;
+pub mod query;
 pub mod repo;
+pub mod report;
 pub mod run;
 pub mod scan;
+pub mod schema;
 pub mod secret;
 pub mod status;
+pub mod suppress;
+pub mod surface;
 pub mod team;
 pub mod user;
 
-pub use args::{AuditFilterArgs, OutputFormat, PaginationArgs, ScanFilterArgs, SortDir};
+pub use args::{
+    AlertFilterArgs, AlertGroupBy, AlertPathArgs, ApiMethod, AppStatus, AppView, AuditFilterArgs,
+    AuthTemplate, FindingsThreshold, FormatArg, LogLevel, OutputFormat, PaginationArgs,
+    ReportGroupBy, ScanFilterArgs, ScanGroupBy, SearchField, SortDir, TriageAction,
+};
 use clap::Args;
 
+use crate::models::display::audit_summary::BusinessHours;
+use crate::notify::NotifyTarget;
+
 /// Team list filters for narrowing down results
 #[derive(Debug, Clone, Args, Default)]
 pub struct TeamFilterArgs {
     /// Filter by team name (substring match, case-insensitive)
-    #[arg(long)]
+    #[arg(long, add = team_name_candidates())]
     pub name: Option<String>,
 
     /// Filter by member email (teams containing this user)
@@ -50,7 +69,7 @@ pub struct TeamFilterArgs {
     pub app: Option<String>,
 }
 
-pub use context::CommandContext;
+pub use context::{CommandContext, command_name, command_with_config_defaults};
 
 /// HawkOp CLI - Professional companion for the StackHawk DAST platform
 #[derive(Parser, Debug)]
@@ -61,7 +80,7 @@ pub struct Cli {
     #[command(subcommand)]
     pub command: Commands,
 
-    /// Output format (pretty, table, json)
+    /// Output format (pretty, table, json; junit for scan gate, har and markdown for scan get)
     #[arg(
         long,
         global = true,
@@ -70,7 +89,7 @@ pub struct Cli {
         hide_env = true,
         hide_possible_values = true
     )]
-    pub format: OutputFormat,
+    pub format: FormatArg,
 
     /// Override default organization
     #[arg(long, global = true, env = "HAWKOP_ORG_ID", hide_env = true)]
@@ -90,14 +109,206 @@ pub struct Cli {
     )]
     pub profile: Option<String>,
 
-    /// Enable debug logging
+    /// Enable debug logging (same as --log-level debug)
     #[arg(long, global = true, env = "HAWKOP_DEBUG", hide_env = true)]
     pub debug: bool,
 
+    /// Log verbosity (default: debug when --log-file is set, otherwise off)
+    #[arg(
+        long,
+        global = true,
+        env = "HAWKOP_LOG_LEVEL",
+        hide_env = true,
+        value_enum,
+        value_name = "LEVEL"
+    )]
+    pub log_level: Option<LogLevel>,
+
+    /// Append logs to a file instead of stderr
+    #[arg(
+        long,
+        global = true,
+        env = "HAWKOP_LOG_FILE",
+        hide_env = true,
+        value_name = "PATH"
+    )]
+    pub log_file: Option<std::path::PathBuf>,
+
+    /// Log line format: text, or one JSON object per line
+    #[arg(
+        long,
+        global = true,
+        env = "HAWKOP_LOG_FORMAT",
+        hide_env = true,
+        value_enum,
+        default_value_t = crate::logging::LogFormat::Text,
+        value_name = "FORMAT"
+    )]
+    pub log_format: crate::logging::LogFormat,
+
+    /// Log every API call's method, URL, status, and timing
+    #[arg(long, global = true, env = "HAWKOP_TRACE_HTTP", hide_env = true)]
+    pub trace_http: bool,
+
+    /// Also log request and response payloads (implies --trace-http)
+    #[arg(long, global = true, env = "HAWKOP_TRACE_BODIES", hide_env = true)]
+    pub trace_bodies: bool,
+
+    /// After the command, print API calls, cache hits and misses, bytes
+    /// transferred, and time taken to stderr
+    #[arg(long, global = true, env = "HAWKOP_STATS", hide_env = true)]
+    pub stats: bool,
+
+    /// Log API response fields hawkop doesn't model (schema drift)
+    #[arg(
+        long,
+        global = true,
+        env = "HAWKOP_STRICT_DESERIALIZE",
+        hide_env = true
+    )]
+    pub strict_deserialize: bool,
+
     /// Bypass cache, fetch fresh data from API
     #[arg(long, global = true, env = "HAWKOP_NO_CACHE", hide_env = true)]
     pub no_cache: bool,
 
+    /// Use only cached data (ignoring TTLs) and never contact the API
+    #[arg(
+        long,
+        global = true,
+        env = "HAWKOP_OFFLINE",
+        hide_env = true,
+        conflicts_with = "no_cache"
+    )]
+    pub offline: bool,
+
+    /// Preview mutating commands: print each change's API call (method,
+    /// endpoint, payload) instead of sending it
+    #[arg(
+        long,
+        short = 'N',
+        global = true,
+        env = "HAWKOP_DRY_RUN",
+        hide_env = true
+    )]
+    pub dry_run: bool,
+
+    /// Maximum concurrent API requests when fetching pages in parallel
+    /// (default: 32; reduced automatically when rate limited)
+    #[arg(
+        long,
+        global = true,
+        env = "HAWKOP_MAX_CONCURRENCY",
+        hide_env = true,
+        value_name = "N",
+        value_parser = clap::value_parser!(u16).range(1..=256)
+    )]
+    pub max_concurrency: Option<u16>,
+
+    /// Stop the command after this many seconds (exit code 124)
+    #[arg(
+        long,
+        global = true,
+        env = "HAWKOP_TIMEOUT",
+        hide_env = true,
+        value_name = "SECS",
+        value_parser = clap::value_parser!(u64).range(1..)
+    )]
+    pub timeout: Option<u64>,
+
+    /// On Ctrl-C or --timeout, print what was fetched so far (with a
+    /// partial_results warning) instead of nothing
+    #[arg(long, global = true, env = "HAWKOP_PARTIAL", hide_env = true)]
+    pub partial: bool,
+
+    /// Exit non-zero if any warning is emitted (for CI)
+    #[arg(
+        long,
+        global = true,
+        env = "HAWKOP_WARNINGS_AS_ERRORS",
+        hide_env = true
+    )]
+    pub warnings_as_errors: bool,
+
+    /// Columns to show in table output, comma-separated (any field from
+    /// JSON output, e.g. id,app,status)
+    #[arg(
+        long,
+        global = true,
+        env = "HAWKOP_COLUMNS",
+        hide_env = true,
+        value_delimiter = ',',
+        value_name = "COLS",
+        conflicts_with = "wide"
+    )]
+    pub columns: Vec<String>,
+
+    /// Show every available field as a column in table output
+    #[arg(long, global = true, env = "HAWKOP_WIDE", hide_env = true)]
+    pub wide: bool,
+
+    /// Filter JSON output with a JSONPath expression (e.g. '$[*].id');
+    /// implies --format json
+    #[arg(
+        long = "query",
+        global = true,
+        env = "HAWKOP_QUERY",
+        hide_env = true,
+        value_name = "EXPR"
+    )]
+    pub json_query: Option<String>,
+
+    /// POST the command's JSON output to an http(s) URL once it finishes;
+    /// implies --format json
+    #[arg(
+        long,
+        global = true,
+        env = "HAWKOP_POST_TO",
+        hide_env = true,
+        value_name = "URL"
+    )]
+    pub post_to: Option<String>,
+
+    /// Render the command's JSON output through a Jinja2 template file
+    /// (variables: data, meta); implies --format json
+    #[arg(
+        long,
+        global = true,
+        env = "HAWKOP_TEMPLATE",
+        hide_env = true,
+        value_name = "FILE",
+        conflicts_with = "json_query"
+    )]
+    pub template: Option<String>,
+
+    /// Print the JSON Schema of the command's --format json output instead
+    /// of running it
+    #[arg(long, global = true, env = "HAWKOP_SCHEMA", hide_env = true)]
+    pub schema: bool,
+
+    /// Print only each row's ID, one per line, for list commands (for xargs)
+    #[arg(
+        long,
+        global = true,
+        env = "HAWKOP_IDS_ONLY",
+        hide_env = true,
+        conflicts_with_all = ["columns", "wide", "json_query", "template"]
+    )]
+    pub ids_only: bool,
+
+    /// Disable colored output (also off with NO_COLOR or when piping)
+    #[arg(long, global = true, env = "HAWKOP_NO_COLOR", hide_env = true)]
+    pub no_color: bool,
+
+    /// Print long tables directly instead of paging them
+    #[arg(long, global = true, env = "HAWKOP_NO_PAGER", hide_env = true)]
+    pub no_pager: bool,
+
+    /// Suppress progress and hint messages on stderr (warnings and errors
+    /// are still shown)
+    #[arg(short, long, global = true, env = "HAWKOP_QUIET", hide_env = true)]
+    pub quiet: bool,
+
     /// Custom API host for development/testing (hidden developer option)
     ///
     /// Overrides the default StackHawk API host. The v1 and v2 paths are
@@ -111,10 +322,45 @@ pub struct Cli {
 #[derive(Subcommand, Debug)]
 pub enum Commands {
     /// Initialize HawkOp configuration
-    Init,
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop init                     # Interactive setup\n  \
+            hawkop init --encrypt           # Setup, or encrypt an existing config\n  \
+            hawkop init --decrypt           # Store secrets in plaintext again\n\n\
+        With --encrypt, API keys and JWTs are encrypted with a passphrase. It is\n\
+        read from HAWKOP_PASSPHRASE or prompted for whenever the config is loaded.")]
+    Init {
+        /// Encrypt API keys and JWTs in the config file with a passphrase
+        #[arg(long, conflicts_with = "decrypt")]
+        encrypt: bool,
+
+        /// Remove config encryption, storing secrets in plaintext
+        #[arg(long)]
+        decrypt: bool,
+    },
 
     /// Show authentication and configuration status
-    Status,
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop status                   # Profile, credentials, organization\n  \
+            hawkop status --verbose         # Plus rate limit quotas and the last 429")]
+    Status {
+        /// Also show rate limit quotas and the most recent rate limiting
+        #[arg(long, short = 'v')]
+        verbose: bool,
+    },
+
+    /// Diagnose configuration, credentials, connectivity, and cache health
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop doctor                   # Run all checks\n  \
+            hawkop doctor --profile prod    # Check another profile\n  \
+            hawkop doctor --format json     # Machine-readable results\n\n\
+        Checks config file permissions, API key validity, JWT expiry, API host\n\
+        reachability, cache database health, and shell completion installation.\n\
+        Exits non-zero if any check fails.")]
+    Doctor,
+
+    /// Manage the stored authentication token
+    #[command(subcommand)]
+    Auth(AuthCommands),
 
     /// Display version information
     Version,
@@ -131,6 +377,14 @@ pub enum Commands {
     #[command(subcommand)]
     Scan(ScanCommands),
 
+    /// Triage findings across scans
+    #[command(subcommand)]
+    Finding(FindingCommands),
+
+    /// Check findings thresholds across applications (release gates)
+    #[command(subcommand)]
+    Gate(GateCommands),
+
     /// Run hosted scans (start, stop, status)
     #[command(subcommand)]
     Run(RunCommands),
@@ -155,6 +409,10 @@ pub enum Commands {
     #[command(subcommand)]
     Oas(OasCommands),
 
+    /// Attack surface overview across repositories, apps, and API specs
+    #[command(subcommand)]
+    Surface(SurfaceCommands),
+
     /// List scan configurations
     #[command(subcommand)]
     Config(ConfigCommands),
@@ -167,6 +425,66 @@ pub enum Commands {
     #[command(subcommand)]
     Audit(AuditCommands),
 
+    /// Manage accepted findings in the local .hawkop-suppressions.yaml
+    #[command(subcommand)]
+    Suppress(SuppressCommands),
+
+    /// Run an ad-hoc query across apps, scans, and teams
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop query --tables\n  \
+            hawkop query \"select name, env from apps where name like '%api%'\"\n  \
+            hawkop query \"select team, count(*) as apps, sum(latest_scan.high) as high \\\n    \
+               from apps join team_apps left join latest_scan group by team order by high desc\"\n  \
+            hawkop query \"select name from apps left join latest_scan where latest_scan.id is null\"\n  \
+            hawkop query --since 7d \"select app, count(*) from scans group by app order by 2 desc\"\n\n\
+        Supports SELECT / FROM / [LEFT] JOIN [ON] / WHERE / GROUP BY / HAVING / ORDER BY / LIMIT\n\
+        with count, sum, min, max, avg. Text comparisons are case-insensitive.")]
+    Query {
+        /// Query text (SQL subset)
+        #[arg(required_unless_present = "tables")]
+        sql: Option<String>,
+
+        /// List queryable tables and columns
+        #[arg(long)]
+        tables: bool,
+
+        /// Only load scans started since this time (e.g. 7d, 30d, 2026-01-01)
+        #[arg(long)]
+        since: Option<String>,
+    },
+
+    /// Make an authenticated request to any StackHawk API endpoint
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop api get /user\n  \
+            hawkop api get '/org/<org-id>/apps?pageSize=5'\n  \
+            hawkop api get /api/v2/org/<org-id>/repos\n  \
+            hawkop api post /app/<app-id> --body rename.json\n  \
+            hawkop api delete /app/<app-id> --dry-run\n  \
+            hawkop api get /org/<org-id>/apps --cache-ttl 300   # Reuse for 5 minutes\n\n\
+        Paths are relative to /api/v1 unless they start with /api/v2 (or /api/v1).\n\
+        The response is printed as JSON; --query and --template apply to it.")]
+    Api {
+        /// HTTP method
+        #[arg(value_enum, ignore_case = true)]
+        method: ApiMethod,
+
+        /// API path, optionally with a query string (e.g. /org/<org-id>/apps)
+        path: String,
+
+        /// JSON file to send as the request body (- for stdin)
+        #[arg(long, value_name = "FILE")]
+        body: Option<String>,
+
+        /// Cache GET responses for this many seconds and reuse them until
+        /// they expire (off by default; --no-cache skips the cache)
+        #[arg(long, value_name = "SECS")]
+        cache_ttl: Option<u64>,
+    },
+
+    /// Org-wide summary reports
+    #[command(subcommand)]
+    Report(ReportCommands),
+
     /// Manage application environments
     #[command(subcommand)]
     Env(EnvCommands),
@@ -175,18 +493,43 @@ pub enum Commands {
     #[command(subcommand)]
     Cache(CacheCommands),
 
+    /// Keep hot cache entries fresh with a background refresher
+    #[command(subcommand)]
+    Daemon(DaemonCommands),
+
     /// Manage configuration profiles (for different orgs, users, or API keys)
     #[command(subcommand, visible_alias = "profiles")]
     Profile(ProfileCommands),
 
-    /// Generate shell completions (static)
+    /// Search locally recorded command history (opt-in)
+    #[command(subcommand)]
+    History(HistoryCommands),
+
+    /// Re-run a command from history
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop rerun           # Re-run the most recent command\n  \
+            hawkop rerun --last    # Same as above\n  \
+            hawkop rerun 42        # Re-run history entry #42\n\n\
+        Enable recording with `preferences.history: true` in your profile or HAWKOP_HISTORY=1.")]
+    Rerun {
+        /// History entry number (from `hawkop history list`)
+        #[arg(conflicts_with = "last")]
+        id: Option<usize>,
+
+        /// Re-run the most recent command (default when no number is given)
+        #[arg(long)]
+        last: bool,
+    },
+
+    /// Generate shell completions
     #[command(after_help = "\
 Static completions (subcommands/flags only):
   bash:   hawkop completion bash > /etc/bash_completion.d/hawkop
   zsh:    hawkop completion zsh > \"${fpath[1]}/_hawkop\"
   fish:   hawkop completion fish > ~/.config/fish/completions/hawkop.fish
 
-Dynamic completions (includes scan IDs, app names via API):
+Dynamic completions (includes org IDs, scan IDs, app names via API):
+  bash:   hawkop completion bash --dynamic > ~/.local/share/bash-completion/completions/hawkop
   bash:   echo 'source <(COMPLETE=bash hawkop)' >> ~/.bashrc
   zsh:    echo 'source <(COMPLETE=zsh hawkop)' >> ~/.zshrc
   fish:   echo 'COMPLETE=fish hawkop | source' >> ~/.config/fish/config.fish
@@ -194,9 +537,14 @@ Dynamic completions (includes scan IDs, app names via API):
 Note: Dynamic completions query the StackHawk API when you press TAB.
 Re-source completions after upgrading hawkop.")]
     Completion {
-        /// Shell to generate completions for (static only)
+        /// Shell to generate completions for
         #[arg(value_enum)]
         shell: Shell,
+
+        /// Generate a script that completes values (orgs, apps, scans, enum
+        /// values) by calling hawkop on TAB; bash uses `complete -C`
+        #[arg(long)]
+        dynamic: bool,
     },
 }
 
@@ -209,24 +557,59 @@ pub enum OrgCommands {
     /// Set default organization
     Set {
         /// Organization ID to set as default
+        #[arg(add = org_id_candidates())]
         org_id: String,
     },
 
-    /// Show current default organization
-    Get,
+    /// Show organization plan, quotas, and features
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop org get                      # Default organization\n  \
+            hawkop org get <org-id>             # Another accessible organization\n  \
+            hawkop org get --format json        # Plan, subscription, settings, usage")]
+    Get {
+        /// Organization ID (defaults to the configured organization)
+        #[arg(add = org_id_candidates())]
+        org_id: Option<String>,
+    },
+
+    /// Show scans run, apps scanned, and seat usage per month
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop org usage                    # Last 90 days, by month\n  \
+            hawkop org usage --since 2026-01-01 # Year to date\n  \
+            hawkop org usage --format json      # Monthly series for dashboards\n\n\
+        Counts come from the org's scans; months are calendar months in UTC.\n\
+        ACTIVE USERS counts users who started scans, not all members.")]
+    Usage {
+        /// Include scans started since this time (e.g. 30d, 90d, 2026-01-01)
+        #[arg(long, default_value = "90d")]
+        since: String,
+    },
 }
 
 /// Application management subcommands
 #[derive(Subcommand, Debug)]
 pub enum AppCommands {
     /// List all applications in the current organization
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop app list\n  \
+            hawkop app list --type cloud\n  \
+            hawkop app list --status env-incomplete    # Apps missing an environment\n  \
+            hawkop app list --all-orgs                 # Every org you can access")]
     List {
         /// Filter by application type (cloud, standard)
         #[arg(long = "type", short = 't')]
         app_type: Option<String>,
 
+        /// Filter by application status
+        #[arg(long, value_enum)]
+        status: Option<AppStatus>,
+
         #[command(flatten)]
         pagination: PaginationArgs,
+
+        /// List every organization you can access, with an ORG column
+        #[arg(long, conflicts_with = "page")]
+        all_orgs: bool,
     },
 
     /// Create a new application in the current organization
@@ -272,10 +655,6 @@ pub enum AppCommands {
         /// Link to a repository by ID (UUID)
         #[arg(long = "repo-id", id = "create_repo_id", add = repo_id_candidates())]
         repo_id: Option<String>,
-
-        /// Preview without creating
-        #[arg(long, short = 'N')]
-        dry_run: bool,
     },
 
     /// Get application details by ID or name
@@ -293,6 +672,24 @@ pub enum AppCommands {
         name: Option<String>,
     },
 
+    /// Summarize an application's latest findings per environment
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop app view my-api                  # Findings per environment\n  \
+            hawkop app view <app-id> findings\n  \
+            hawkop app view my-api --format json | jq '.data[] | select(.high > 0)'\n\n\
+        For each environment: status of the latest scan, open (new + triaged)\n\
+        high/medium/low findings in the latest completed scan, and the change\n\
+        in open findings since the previous completed scan.")]
+    View {
+        /// Application ID or name
+        #[arg(add = app_name_candidates())]
+        app: String,
+
+        /// What to show
+        #[arg(value_enum, default_value_t)]
+        view: AppView,
+    },
+
     /// Rename an existing application
     #[command(after_help = "EXAMPLES:\n  \
             hawkop app update <app-id> --name new-name\n  \
@@ -305,10 +702,6 @@ pub enum AppCommands {
         /// New application name
         #[arg(long, short = 'n')]
         name: String,
-
-        /// Preview without making changes
-        #[arg(long, short = 'N')]
-        dry_run: bool,
     },
 
     /// Delete an application (destructive)
@@ -324,6 +717,40 @@ pub enum AppCommands {
         #[arg(long, short = 'y')]
         yes: bool,
     },
+
+    /// Find applications with identical or near-identical names or hosts
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop app duplicates                        # Table of duplicate groups\n  \
+            hawkop app duplicates --format json          # Groups with suggestions\n  \
+            hawkop app duplicates --csv cleanup.csv      # Export for cleanup planning\n\n\
+        Apps are grouped by same name, normalized name (case, punctuation,\n\
+        env/copy suffixes ignored), same scan target host, or a one-character\n\
+        name difference.")]
+    Duplicates {
+        /// Write results to a CSV file
+        #[arg(long, value_name = "FILE")]
+        csv: Option<String>,
+    },
+
+    /// Export an inventory of every application with its scan posture
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop app export -o apps.csv            # CSV for compliance evidence\n  \
+            hawkop app export -o apps.json           # JSON rows\n  \
+            hawkop app export --since 30d            # Fetch scans of the last 30 days\n  \
+            hawkop app export --format json | jq '.data[] | select(.high > 0)'\n\n\
+        One row per app: environments, type, status, risk level, teams, last\n\
+        scan, and open (new + triaged) high/medium findings summed over each\n\
+        environment's latest completed scan. Scans since --since are fetched\n\
+        in one pass; apps without one get their last scan looked up.")]
+    Export {
+        /// Write the inventory to FILE: CSV, or JSON for a .json file
+        #[arg(long, short = 'o', value_name = "FILE")]
+        output: Option<String>,
+
+        /// Fetch scans started since this time (e.g. 30d, 2026-01-01)
+        #[arg(long, default_value = crate::cli::app::EXPORT_WINDOW)]
+        since: String,
+    },
 }
 
 /// Scan management subcommands
@@ -336,18 +763,57 @@ pub enum ScanCommands {
 
         #[command(flatten)]
         pagination: PaginationArgs,
+
+        /// Redraw the list every SECONDS (default: 120, the scan list cache TTL),
+        /// marking new and changed scans
+        #[arg(long, value_name = "SECONDS", num_args = 0..=1)]
+        watch: Option<Option<u64>>,
+
+        /// List every organization you can access, with an ORG column
+        #[arg(long, conflicts_with_all = ["app", "page", "watch"])]
+        all_orgs: bool,
+
+        /// One row per application with scan count and open findings
+        /// (scans since --since, default the last 30 days)
+        #[arg(long, value_enum, conflicts_with_all = ["page", "watch", "all_orgs"])]
+        group_by: Option<ScanGroupBy>,
+
+        /// With --group-by, show each application's scans underneath it
+        #[arg(long, requires = "group_by")]
+        expand: bool,
+
+        /// Mark scans whose new findings exceed any SEVERITY:COUNT rule
+        /// (comma-separated, as for 'scan gate --fail-on')
+        #[arg(
+            long,
+            value_delimiter = ',',
+            value_name = "SEVERITY:COUNT",
+            conflicts_with = "group_by"
+        )]
+        highlight_threshold: Vec<FindingsThreshold>,
     },
 
     /// Get scan details with optional drill-down
     #[command(
-        visible_alias = "g",
+        visible_aliases = ["g", "view"],
         after_help = "EXAMPLES:\n  \
             hawkop scan get                          # Latest scan (overview + alerts)\n  \
+            hawkop scan view latest --app myapp --env prod   # Latest for app + env\n  \
             hawkop scan get --app myapp              # Latest for app (by name)\n  \
             hawkop scan get --app-id <uuid>          # Latest for app (by ID)\n  \
             hawkop scan get abc123                   # Specific scan\n  \
             hawkop scan get abc123 --plugin-id 40012 # Plugin detail\n  \
+            hawkop scan get abc123 -p 40012 --plain  # Description as written\n  \
+            hawkop scan get abc123 -p 40012 --limit 50 --page 1   # Second 50 paths\n  \
+            hawkop scan get abc123 -p 40012 --status new --method POST --sort-by uri\n  \
             hawkop scan get abc123 --uri-id xyz -m   # Finding with HTTP message\n  \
+            hawkop scan get abc123 --uri-id xyz --curl-only   # Just the curl command\n  \
+            hawkop scan get abc123 --uri-id xyz -m --no-redact   # Keep credentials\n  \
+            hawkop scan get abc123 --uri-id xyz -o har > finding.har\n  \
+            hawkop scan get abc123 -p 40012 -o markdown | pbcopy   # Paste into a PR\n  \
+            hawkop scan get --severity high --status new   # Untriaged highs only\n  \
+            hawkop scan view abc123 --group-by category    # Alerts in sections\n  \
+            hawkop scan get --owasp                  # Add OWASP Top 10 categories\n  \
             hawkop scan get --detail full --format json    # Full detail for AI agents\n  \
             hawkop scan get --app myapp --detail full --max-findings 10\n\n\
         DETAIL LEVELS:\n  \
@@ -358,10 +824,10 @@ pub enum ScanCommands {
     )]
     Get {
         /// Scan ID (UUID) or "latest" - defaults to latest if omitted
-        #[arg(default_value = "latest", add = scan_id_candidates())]
+        #[arg(default_value = "latest", add = scan_id_or_latest_candidates())]
         scan_id: String,
 
-        /// Filter by application name (only with "latest")
+        /// Filter by application name or ID (only with "latest")
         #[arg(long, short = 'a', conflicts_with = "app_id", add = app_name_candidates())]
         app: Option<String>,
 
@@ -389,6 +855,14 @@ pub enum ScanCommands {
         #[arg(long = "plugin-id", short = 'p', add = plugin_id_candidates())]
         plugin_id: Option<String>,
 
+        /// Paths per page for --plugin-id (default: all paths)
+        #[arg(long, requires = "plugin_id")]
+        limit: Option<usize>,
+
+        /// Page of paths for --plugin-id (0-indexed; requires --limit)
+        #[arg(long, requires = "limit")]
+        page: Option<usize>,
+
         /// Show detail for specific URI/finding (unique within scan)
         #[arg(long = "uri-id", short = 'u', add = uri_id_candidates())]
         uri_id: Option<String>,
@@ -397,9 +871,368 @@ pub enum ScanCommands {
         #[arg(long, short = 'm', requires = "uri_id")]
         message: bool,
 
-        /// Output format: pretty (default), table, json
+        /// Show message bodies as captured (no decoding or JSON pretty-printing)
+        #[arg(long, requires = "uri_id")]
+        raw: bool,
+
+        /// Print only the curl validation command (implies -m)
+        #[arg(long, requires = "uri_id")]
+        curl_only: bool,
+
+        /// Show HTTP messages as captured, without redacting credential
+        /// headers, cookies, and parameters
+        #[arg(long)]
+        no_redact: bool,
+
+        /// Print the plugin description as written, without rendering its
+        /// markdown (with --plugin-id)
+        #[arg(long, requires = "plugin_id")]
+        plain: bool,
+
+        #[command(flatten)]
+        alert_filters: AlertFilterArgs,
+
+        #[command(flatten)]
+        path_args: AlertPathArgs,
+
+        /// Split the alerts table into sections with subtotals
+        #[arg(
+            long = "group-by",
+            value_enum,
+            conflicts_with_all = ["plugin_id", "uri_id", "detail"]
+        )]
+        group_by: Option<AlertGroupBy>,
+
+        /// Add an OWASP Top 10 (2021) column to the alerts table
+        #[arg(long, conflicts_with_all = ["plugin_id", "uri_id", "detail"])]
+        owasp: bool,
+
+        /// Output format: pretty (default), table, json, markdown; har with --uri-id
         #[arg(long, short = 'o', default_value = "pretty")]
-        format: OutputFormat,
+        format: FormatArg,
+    },
+
+    /// Compare findings between two scans (new, fixed, persistent)
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop scan diff <base-id> <head-id>              # Table of changes\n  \
+            hawkop scan diff <base-id> <head-id> --format json\n  \
+            hawkop scan diff <base-id> <head-id> --markdown   # For PR comments\n\n\
+        Findings are matched by plugin ID + HTTP method + URI.")]
+    Diff {
+        /// Base (older) scan ID
+        #[arg(add = scan_id_candidates())]
+        base: String,
+
+        /// Head (newer) scan ID
+        #[arg(add = scan_id_candidates())]
+        head: String,
+
+        /// Render the comparison as Markdown
+        #[arg(long)]
+        markdown: bool,
+    },
+
+    /// Search a scan's findings for text in alert names, URIs, evidence, and other info
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop scan search <scan-id> '<script>'      # Payload echoed anywhere\n  \
+            hawkop scan search <scan-id> /api/users --field uri\n  \
+            hawkop scan search latest --app my-api jsessionid --field evidence,other-info\n  \
+            hawkop scan search <scan-id> password --severity high --format json\n\n\
+        Matching is case-insensitive unless --case-sensitive. Searching evidence or\n\
+        other info fetches each finding's HTTP message, --max-concurrency at a time.")]
+    Search {
+        /// Scan ID (UUID) or "latest"
+        #[arg(add = scan_id_or_latest_candidates())]
+        scan_id: String,
+
+        /// Text to search for
+        pattern: String,
+
+        /// Application name or ID (only with "latest")
+        #[arg(long, short = 'a', add = app_name_candidates())]
+        app: Option<String>,
+
+        /// Environment name (only with "latest")
+        #[arg(long, short = 'e')]
+        env: Option<String>,
+
+        /// Fields to search (default: all)
+        #[arg(long, value_enum, value_delimiter = ',', value_name = "FIELD")]
+        field: Vec<SearchField>,
+
+        /// Match case exactly
+        #[arg(long)]
+        case_sensitive: bool,
+
+        #[command(flatten)]
+        alert_filters: AlertFilterArgs,
+    },
+
+    /// Map a scan's findings onto the operations of an OpenAPI spec
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop scan map-oas <scan-id> --oas openapi.yaml   # Findings per operation\n  \
+            hawkop scan map-oas <scan-id> --oas swagger.json --format json\n\n\
+        Findings are matched by HTTP method and path template; the query string and\n\
+        any base path from `servers` (or Swagger 2 `basePath`) are ignored.")]
+    MapOas {
+        /// Scan ID (UUID)
+        #[arg(add = scan_id_candidates())]
+        scan_id: String,
+
+        /// OpenAPI 3.x or Swagger 2.0 spec file (YAML or JSON)
+        #[arg(long)]
+        oas: String,
+    },
+
+    /// Follow a scan until it finishes, with a live progress line
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop scan watch <scan-id>                 # Follow until done\n  \
+            hawkop scan watch <scan-id> --format json   # Final scan result as JSON\n  \
+            hawkop scan watch <scan-id> --notify desktop --notify slack:$SLACK_WEBHOOK\n  \
+            hawkop scan watch <scan-id> -i 60           # Poll every 60s\n\n\
+        Exits non-zero if the scan ends in any state other than completed.\n\
+        Polls every 2s while the scan is changing, backing off to 30s while it\n\
+        is idle, and reconnect after network errors.")]
+    Watch {
+        /// Scan ID (UUID)
+        #[arg(add = scan_id_candidates())]
+        scan_id: String,
+
+        /// Seconds between polls (default: adaptive, 2-30s)
+        #[arg(long, short = 'i')]
+        interval: Option<u64>,
+
+        /// Notify when the scan ends: desktop or slack:<webhook-url> (repeatable)
+        #[arg(long, value_name = "TARGET")]
+        notify: Vec<NotifyTarget>,
+    },
+
+    /// Check a scan's findings against thresholds (CI quality gate)
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop scan gate <scan-id> --fail-on high:0              # Any new High fails\n  \
+            hawkop scan gate <scan-id> --fail-on high:0,medium:10\n  \
+            hawkop scan gate <scan-id> --fail-on high:0 --include-triaged\n  \
+            hawkop scan gate <scan-id> --fail-on high:0 --format junit > stackhawk-junit.xml\n  \
+            hawkop scan gate latest --app my-api --env prod --fail-on high:0\n\n\
+        A rule SEVERITY:N allows at most N findings of that severity; more fail the gate.\n\
+        Only new findings are counted unless --include-triaged is set; risk-accepted\n\
+        and false-positive findings are never counted, nor are findings suppressed in\n\
+        .hawkop-suppressions.yaml (see 'hawkop suppress').\n\n\
+        EXIT CODES:\n  \
+            0  All thresholds passed\n  \
+            1  Error (including scans that are still running or failed)\n  \
+            2  Findings exceed a threshold")]
+    Gate {
+        /// Scan ID (UUID) or "latest"
+        #[arg(add = scan_id_or_latest_candidates())]
+        scan_id: String,
+
+        /// Application name or ID (only with "latest")
+        #[arg(long, short = 'a', add = app_name_candidates())]
+        app: Option<String>,
+
+        /// Environment name (only with "latest")
+        #[arg(long, short = 'e')]
+        env: Option<String>,
+
+        /// Comma-separated SEVERITY:COUNT rules (severities: high, medium, low)
+        #[arg(
+            long,
+            required = true,
+            value_delimiter = ',',
+            value_name = "SEVERITY:COUNT"
+        )]
+        fail_on: Vec<FindingsThreshold>,
+
+        /// Also count triaged (assigned) findings, not just new ones
+        #[arg(long)]
+        include_triaged: bool,
+    },
+
+    /// Upload a scan's findings to GitHub code scanning as SARIF
+    #[command(
+        name = "upload-github",
+        after_help = "EXAMPLES:\n  \
+            hawkop scan upload-github <scan-id> --repo acme/web --ref refs/heads/main --token $TOKEN\n  \
+            hawkop scan upload-github <scan-id>              # In GitHub Actions (repo, ref, sha, token from env)\n  \
+            hawkop scan upload-github <scan-id> --dry-run > stackhawk.sarif\n\n\
+        Outside GitHub Actions, --repo, --ref, and --sha default to the local git\n\
+        checkout (origin remote, current branch, HEAD). The token needs the\n\
+        security_events scope (security-events: write in Actions).\n\
+        Risk-accepted and false-positive findings are skipped unless --include-triaged.\n\
+        Findings suppressed in .hawkop-suppressions.yaml are always skipped."
+    )]
+    UploadGithub {
+        /// Scan ID (UUID)
+        #[arg(add = scan_id_candidates())]
+        scan_id: String,
+
+        /// Repository as owner/name (default: GITHUB_REPOSITORY or origin remote)
+        #[arg(long, env = "GITHUB_REPOSITORY", hide_env = true)]
+        repo: Option<String>,
+
+        /// Full git ref, e.g. refs/heads/main (default: GITHUB_REF or current branch)
+        #[arg(long = "ref", env = "GITHUB_REF", hide_env = true, value_name = "REF")]
+        git_ref: Option<String>,
+
+        /// Commit SHA the results belong to (default: GITHUB_SHA or HEAD)
+        #[arg(long, env = "GITHUB_SHA", hide_env = true)]
+        sha: Option<String>,
+
+        /// GitHub token with security_events scope
+        #[arg(long, env = "GITHUB_TOKEN", hide_env_values = true)]
+        token: Option<String>,
+
+        /// GitHub API URL (for GitHub Enterprise Server)
+        #[arg(
+            long,
+            env = "GITHUB_API_URL",
+            hide_env = true,
+            default_value = crate::client::github::DEFAULT_GITHUB_API_URL
+        )]
+        api_url: String,
+
+        /// File every result is attributed to in the repository
+        #[arg(long, default_value = "stackhawk.yml")]
+        location: String,
+
+        /// Include risk-accepted and false-positive findings
+        #[arg(long)]
+        include_triaged: bool,
+    },
+}
+
+/// Finding subcommands
+#[derive(Subcommand, Debug)]
+pub enum FindingCommands {
+    /// List current findings across the organization's latest scans
+    #[command(
+        visible_alias = "ls",
+        after_help = "EXAMPLES:\n  \
+            hawkop finding list                              # Highest severity first\n  \
+            hawkop finding list --app web --severity high    # One app's high findings\n  \
+            hawkop finding list --status new --env prod      # Untriaged findings in prod\n  \
+            hawkop finding list --all --format json > findings.json\n  \
+            hawkop finding list --sort-by first-seen         # Oldest findings first\n  \
+            hawkop finding list --all-orgs --severity high   # Highs in every org\n\n\
+        Findings are those seen in the latest scan of each app environment, one row\n\
+        per path. Sort fields: severity (default), org, app, env, plugin, path, status,\n\
+        first-seen, last-seen. Findings suppressed in .hawkop-suppressions.yaml are\n\
+        left out."
+    )]
+    List {
+        /// Filter by application name or ID (repeat or comma-separate)
+        #[arg(long, short = 'a', value_delimiter = ',', add = app_name_candidates())]
+        app: Vec<String>,
+
+        /// Filter by environment (repeat or comma-separate)
+        #[arg(long, short = 'e', value_delimiter = ',')]
+        env: Vec<String>,
+
+        #[command(flatten)]
+        filters: AlertFilterArgs,
+
+        #[command(flatten)]
+        pagination: PaginationArgs,
+
+        /// List every organization you can access, with an ORG column
+        #[arg(long, conflicts_with = "app")]
+        all_orgs: bool,
+    },
+
+    /// Triage many findings from a CSV file or stdin
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop finding triage-bulk --file triage.csv\n  \
+            hawkop finding triage-bulk --file triage.csv --dry-run\n  \
+            cat triage.csv | hawkop finding triage-bulk --stdin\n\n\
+        Each row is scan_id,plugin_id,uri_id,status[,note] (a header row is optional).\n\
+        status is false-positive, accepted, or comment (comment requires a note).\n\
+        Rows are resolved to finding hashes and applied per app environment, so a\n\
+        triage also covers future scans. Exits 1 if any row failed.")]
+    TriageBulk {
+        /// CSV file of triage rows
+        #[arg(
+            long,
+            short = 'f',
+            value_name = "PATH",
+            required_unless_present = "stdin"
+        )]
+        file: Option<String>,
+        /// Read CSV rows from stdin
+        #[arg(long, conflicts_with = "file")]
+        stdin: bool,
+    },
+
+    /// File a Jira issue for a finding
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop finding ticket abc123 40018 --jira-project SEC\n  \
+            hawkop finding ticket abc123 40018 --uri 9f8e7d --jira-project SEC\n  \
+            hawkop finding ticket abc123 40018 --dry-run      # Preview the issue\n\n\
+        The issue describes the finding, its evidence and curl reproduction (for\n\
+        --uri, or the first affected path), and every affected path. The Jira site\n\
+        and credentials come from integrations.jira in the hawkop config.")]
+    Ticket {
+        /// Scan ID
+        scan_id: String,
+
+        /// Plugin ID of the finding
+        plugin_id: String,
+
+        /// Affected path (URI ID) to show evidence for
+        #[arg(long, value_name = "URI_ID")]
+        uri: Option<String>,
+
+        /// Jira project key (default: integrations.jira.project)
+        #[arg(long, value_name = "KEY")]
+        jira_project: Option<String>,
+
+        /// Jira issue type (default: integrations.jira.issue_type, or Bug)
+        #[arg(long, value_name = "TYPE")]
+        issue_type: Option<String>,
+    },
+}
+
+/// Multi-application gate subcommands
+#[derive(Subcommand, Debug)]
+pub enum GateCommands {
+    /// Gate a release on the latest completed scan of every app/env
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop gate release --apps web,api,worker --fail-on high:0\n  \
+            hawkop gate release --apps web,api --env prod --fail-on high:0,medium:10\n  \
+            hawkop gate release --apps web,api --fail-on high:0 --format junit > release-junit.xml\n\n\
+        Each app is checked in every environment it has completed scans in, or only\n\
+        in --env. Rules work as in `scan gate`. An app/env without a completed scan fails.\n\n\
+        EXIT CODES:\n  \
+            0  Every app/env passed\n  \
+            1  Error\n  \
+            2  An app/env exceeded a threshold or has no completed scan")]
+    Release {
+        /// Comma-separated application names or IDs
+        #[arg(
+            long,
+            required = true,
+            value_delimiter = ',',
+            value_name = "APPS",
+            add = app_name_candidates()
+        )]
+        apps: Vec<String>,
+
+        /// Only check these environments (comma-separated)
+        #[arg(long, short = 'e', value_delimiter = ',')]
+        env: Vec<String>,
+
+        /// Comma-separated SEVERITY:COUNT rules (severities: high, medium, low)
+        #[arg(
+            long,
+            required = true,
+            value_delimiter = ',',
+            value_name = "SEVERITY:COUNT"
+        )]
+        fail_on: Vec<FindingsThreshold>,
+
+        /// Also count triaged (assigned) findings, not just new ones
+        #[arg(long)]
+        include_triaged: bool,
     },
 }
 
@@ -411,6 +1244,7 @@ pub enum RunCommands {
             hawkop run start --app myapp              # Start scan for app by name\n  \
             hawkop run start --app <uuid>             # Start scan for app by ID\n  \
             hawkop run start --app myapp --watch      # Start and watch progress\n  \
+            hawkop run start --app myapp -w --notify desktop   # Notify when it ends\n  \
             hawkop run start --app myapp --env prod   # Scan specific environment\n  \
             hawkop run start --app myapp --config ci  # Use specific scan config")]
     Start {
@@ -429,6 +1263,10 @@ pub enum RunCommands {
         /// Watch scan progress after starting
         #[arg(long, short = 'w')]
         watch: bool,
+
+        /// Notify when the scan ends: desktop or slack:<webhook-url> (repeatable)
+        #[arg(long, value_name = "TARGET", requires = "watch")]
+        notify: Vec<NotifyTarget>,
     },
 
     /// Stop a running hosted scan
@@ -469,9 +1307,17 @@ pub enum RunCommands {
 #[derive(Subcommand, Debug)]
 pub enum UserCommands {
     /// List organization members
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop user list                       # Members with org roles\n  \
+            hawkop user list --teams               # Add team memberships and roles\n  \
+            hawkop user list --all --format json   # Every member as JSON")]
     List {
         #[command(flatten)]
         pagination: PaginationArgs,
+
+        /// Include each member's teams and team roles (fetches every team)
+        #[arg(long)]
+        teams: bool,
     },
 }
 
@@ -502,12 +1348,22 @@ pub enum TeamCommands {
         after_help = "EXAMPLES:\n  \
             hawkop team get \"Security Team\"   # By name\n  \
             hawkop team get abc123              # By ID\n  \
-            hawkop team get abc123 --format json | jq '.data.users'"
+            hawkop team get abc123 --format json | jq '.data.users'\n  \
+            hawkop team get \"Security Team\" --members-only --format table\n  \
+            hawkop team get abc123 --apps-only --format json"
     )]
     Get {
         /// Team ID or name
         #[arg(add = team_name_candidates())]
         team: String,
+
+        /// Show only team members
+        #[arg(long, conflicts_with = "apps_only")]
+        members_only: bool,
+
+        /// Show only assigned applications
+        #[arg(long)]
+        apps_only: bool,
     },
 
     /// Create a new team
@@ -528,9 +1384,6 @@ pub enum TeamCommands {
         /// Initial applications (name or ID), comma-separated or repeated
         #[arg(long, short = 'a', value_delimiter = ',', add = app_name_candidates())]
         apps: Option<Vec<String>>,
-        /// Preview without creating
-        #[arg(long, short = 'n')]
-        dry_run: bool,
         /// Allow duplicate app assignments (not recommended - can cause API issues)
         #[arg(long, short = 'f')]
         force: bool,
@@ -548,9 +1401,6 @@ pub enum TeamCommands {
         /// Skip confirmation prompt
         #[arg(long, short = 'y')]
         yes: bool,
-        /// Preview without deleting
-        #[arg(long, short = 'n')]
-        dry_run: bool,
     },
 
     /// Rename a team
@@ -563,9 +1413,6 @@ pub enum TeamCommands {
         current: String,
         /// New team name
         new_name: String,
-        /// Preview without renaming
-        #[arg(long, short = 'n')]
-        dry_run: bool,
     },
 
     /// Add users to a team
@@ -587,9 +1434,6 @@ pub enum TeamCommands {
         /// Read users from stdin (one per line)
         #[arg(long)]
         stdin: bool,
-        /// Preview without adding
-        #[arg(long, short = 'n')]
-        dry_run: bool,
     },
 
     /// Remove users from a team
@@ -611,9 +1455,6 @@ pub enum TeamCommands {
         /// Read users from stdin (one per line)
         #[arg(long)]
         stdin: bool,
-        /// Preview without removing
-        #[arg(long, short = 'n')]
-        dry_run: bool,
     },
 
     /// Replace all team members (for SCIM sync)
@@ -635,9 +1476,6 @@ pub enum TeamCommands {
         /// Read users from stdin (one per line)
         #[arg(long)]
         stdin: bool,
-        /// Preview changes without applying
-        #[arg(long, short = 'n')]
-        dry_run: bool,
         /// Skip confirmation prompt
         #[arg(long, short = 'y')]
         yes: bool,
@@ -662,9 +1500,6 @@ SAFETY:\n  \
         /// Read apps from stdin (one per line)
         #[arg(long)]
         stdin: bool,
-        /// Preview without assigning
-        #[arg(long, short = 'n')]
-        dry_run: bool,
         /// Allow duplicate app assignments (not recommended - can cause API issues)
         #[arg(long, short = 'f')]
         force: bool,
@@ -686,9 +1521,6 @@ SAFETY:\n  \
         /// Read apps from stdin (one per line)
         #[arg(long)]
         stdin: bool,
-        /// Preview without removing
-        #[arg(long, short = 'n')]
-        dry_run: bool,
     },
 
     /// Replace all team application assignments
@@ -713,9 +1545,6 @@ SAFETY:\n  \
         /// Read apps from stdin (one per line)
         #[arg(long)]
         stdin: bool,
-        /// Preview changes without applying
-        #[arg(long, short = 'n')]
-        dry_run: bool,
         /// Skip confirmation prompt
         #[arg(long, short = 'y')]
         yes: bool,
@@ -723,6 +1552,81 @@ SAFETY:\n  \
         #[arg(long, short = 'f')]
         force: bool,
     },
+
+    /// Create and update teams from a CSV or YAML manifest
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop team import --file teams.csv --dry-run   # Preview changes\n  \
+            hawkop team import --file teams.yaml\n  \
+            hawkop team import --file teams.csv --yes        # No confirmation\n  \
+            scim-export | hawkop team import --stdin\n\n\
+        CSV rows are team,members,apps with ';'-separated lists (a header row is\n\
+        optional; rows for the same team are merged). YAML manifests list\n\
+        teams: [{name, members, apps}]. Members are emails or user IDs, apps are\n\
+        names or IDs. A listed side replaces the team's current members or apps;\n\
+        an omitted side (empty CSV cell) is left as it is. Teams not in the\n\
+        manifest are never changed.")]
+    Import {
+        /// Manifest file (.yaml/.yml is read as YAML, anything else as CSV)
+        #[arg(
+            long,
+            short = 'f',
+            value_name = "PATH",
+            required_unless_present = "stdin"
+        )]
+        file: Option<String>,
+        /// Read the manifest from stdin (YAML if it starts with "teams:")
+        #[arg(long, conflicts_with = "file")]
+        stdin: bool,
+        /// Skip confirmation prompt
+        #[arg(long, short = 'y')]
+        yes: bool,
+        /// Allow duplicate app assignments (not recommended - can cause API issues)
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Compare team memberships with an IdP group export (read-only)
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop team audit --file idp-groups.csv\n  \
+            hawkop team audit --file teams.yaml --format json | jq '.data[] | select(.drift == \"extra_member\")'\n  \
+            idp-export | hawkop team audit --stdin\n\n\
+        The export is read like a team import manifest: CSV rows of\n\
+        team,members (or group,email, one member per row) or a YAML manifest.\n\
+        Reports missing members, extra members, missing teams, and listed users\n\
+        not in the organization. Teams not in the export are not audited, and\n\
+        nothing is changed; apply the export with 'hawkop team import'.")]
+    Audit {
+        /// Export file (.yaml/.yml is read as YAML, anything else as CSV)
+        #[arg(
+            long,
+            short = 'f',
+            value_name = "PATH",
+            required_unless_present = "stdin"
+        )]
+        file: Option<String>,
+        /// Read the export from stdin (YAML if it starts with "teams:")
+        #[arg(long, conflicts_with = "file")]
+        stdin: bool,
+    },
+
+    /// Show recent scans, config changes, and membership churn for a team
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop team report \"Security Team\"              # Last 30 days\n  \
+            hawkop team report abc123 --since 7d\n  \
+            hawkop team report \"Security Team\" -f json | jq '.data.member_activity'\n\n\
+        NOTES:\n  \
+            Config and membership changes come from the audit log and are matched\n  \
+            to the team by the app and team names or IDs they mention. Reading\n  \
+            the audit log needs an org admin API key; without it only scans are shown.")]
+    Report {
+        /// Team ID or name
+        #[arg(add = team_name_candidates())]
+        team: String,
+
+        /// Include activity since this time (e.g. 7d, 30d, 2026-01-01)
+        #[arg(long, default_value = "30d")]
+        since: String,
+    },
 }
 
 /// Policy management subcommands
@@ -744,6 +1648,17 @@ pub enum RepoCommands {
         pagination: PaginationArgs,
     },
 
+    /// Show repository detail: insights, frameworks, sensitive data, mapped apps
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop repo get my-repo                 # By name (case-insensitive)\n  \
+            hawkop repo get <uuid>                  # By ID\n  \
+            hawkop repo get my-repo --format json")]
+    Get {
+        /// Repository name or ID
+        #[arg(add = repo_name_candidates())]
+        repo: String,
+    },
+
     /// Link an application to a repository (additive, preserves existing mappings)
     #[command(after_help = "EXAMPLES:\n  \
             hawkop repo link --repo-id <uuid> --app-id <uuid>         # Link existing app\n  \
@@ -771,10 +1686,40 @@ pub enum RepoCommands {
         /// Environment for new app (only with --app-name)
         #[arg(long, short = 'e', default_value = "Development")]
         env: String,
+    },
+
+    /// Map an existing application to a repository
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop repo map my-repo --app my-api         # Both by name\n  \
+            hawkop repo map <repo-id> --app <app-id>     # Both by ID\n  \
+            hawkop repo map my-repo --app my-api -N      # Preview\n\n\
+        Other mappings on the repository are kept. Use 'repo link --app-name'\n\
+        to create a new application and link it in one step.")]
+    Map {
+        /// Repository name or ID
+        #[arg(add = repo_name_candidates())]
+        repo: String,
 
-        /// Preview without making changes
-        #[arg(long, short = 'N')]
-        dry_run: bool,
+        /// Application name or ID
+        #[arg(long, short = 'a', add = app_name_candidates())]
+        app: String,
+    },
+
+    /// Remove an application's mapping from a repository
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop repo unmap my-repo --app my-api\n  \
+            hawkop repo unmap my-repo --app <app-id> -N  # Preview\n\n\
+        Other mappings on the repository are kept, and the application itself\n\
+        is not changed. --app also matches mappings to deleted applications\n\
+        by the ID or name recorded on the repository.")]
+    Unmap {
+        /// Repository name or ID
+        #[arg(add = repo_name_candidates())]
+        repo: String,
+
+        /// Application name or ID
+        #[arg(long, short = 'a', add = app_name_candidates())]
+        app: String,
     },
 
     /// Replace all application mappings for a repository (full replacement)
@@ -794,10 +1739,6 @@ pub enum RepoCommands {
         /// Skip confirmation prompt
         #[arg(long, short = 'y')]
         yes: bool,
-
-        /// Preview without making changes
-        #[arg(long, short = 'N')]
-        dry_run: bool,
     },
 }
 
@@ -835,6 +1776,19 @@ pub enum OasCommands {
     },
 }
 
+/// Attack surface subcommands
+#[derive(Subcommand, Debug)]
+pub enum SurfaceCommands {
+    /// Summarize repositories, app mappings, and API specs in one view
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop surface summary\n  \
+            hawkop surface summary --format json | jq '.data.unmapped_repos[].name'\n\n\
+        Counts repositories, those in the attack surface, and those with\n\
+        sensitive data, then lists attack surface repos with no mapped app\n\
+        and those with no hosted or generated OpenAPI spec.")]
+    Summary,
+}
+
 /// Configuration management subcommands
 #[derive(Subcommand, Debug)]
 pub enum ConfigCommands {
@@ -855,6 +1809,7 @@ pub enum ConfigCommands {
             hawkop config get myconfig -o out.yml # Save to file")]
     Get {
         /// Configuration name
+        #[arg(add = config_name_candidates())]
         name: String,
 
         /// Output file path (optional, prints to stdout if not specified)
@@ -867,6 +1822,7 @@ pub enum ConfigCommands {
             hawkop config set myconfig -f config.yml  # Create/update config")]
     Set {
         /// Configuration name
+        #[arg(add = config_name_candidates())]
         name: String,
 
         /// YAML configuration file to upload
@@ -874,12 +1830,54 @@ pub enum ConfigCommands {
         file: String,
     },
 
+    /// Download a scan configuration to a file to version it locally
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop config pull myconfig                  # Save to myconfig.yml\n  \
+            hawkop config pull myconfig -o stackhawk.yml # Save to stackhawk.yml\n\n\
+        The file is recorded so `config push` can tell when the platform copy\n\
+        changed since. Pull refuses to overwrite local changes that were never\n\
+        pushed unless --force is given.")]
+    Pull {
+        /// Configuration name
+        #[arg(add = config_name_candidates())]
+        name: String,
+
+        /// File to write (default: <name>.yml)
+        #[arg(long, short = 'o')]
+        output: Option<String>,
+
+        /// Overwrite local changes that were never pushed
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Upload a local file to the scan configuration it was pulled from
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop config push stackhawk.yml                # Push to the config it was pulled from\n  \
+            hawkop config push stackhawk.yml --name staging # Push to (or create) 'staging'\n\n\
+        The diff against the platform copy is shown before uploading. If the\n\
+        platform copy changed since the file was pulled, the base, remote and\n\
+        local changes are shown and the push is refused unless --force is given.")]
+    Push {
+        /// YAML configuration file to upload
+        file: String,
+
+        /// Configuration name (default: the config the file was pulled from)
+        #[arg(long, add = config_name_candidates())]
+        name: Option<String>,
+
+        /// Overwrite remote changes made since the file was pulled
+        #[arg(long)]
+        force: bool,
+    },
+
     /// Delete a scan configuration
     #[command(after_help = "EXAMPLES:\n  \
             hawkop config delete myconfig         # Delete with confirmation\n  \
             hawkop config delete myconfig --yes   # Delete without confirmation")]
     Delete {
         /// Configuration name
+        #[arg(add = config_name_candidates())]
         name: String,
 
         /// Skip confirmation prompt
@@ -892,6 +1890,7 @@ pub enum ConfigCommands {
             hawkop config rename oldname newname  # Rename config")]
     Rename {
         /// Current configuration name
+        #[arg(add = config_name_candidates())]
         old_name: String,
 
         /// New configuration name
@@ -900,16 +1899,118 @@ pub enum ConfigCommands {
 
     /// Validate a scan configuration
     #[command(after_help = "EXAMPLES:\n  \
-            hawkop config validate -f stackhawk.yml  # Validate local file\n  \
-            hawkop config validate myconfig          # Validate stored config")]
+            hawkop config validate -f stackhawk.yml          # Validate local file\n  \
+            hawkop config validate -f stackhawk.yml --local  # Schema check only, offline\n  \
+            hawkop config validate myconfig                  # Validate stored config\n\n\
+        The file is first checked locally (YAML syntax, required app fields, key\n\
+        types); the API validator runs only when the local check passes.")]
     Validate {
         /// Configuration name (to validate stored config)
-        #[arg(conflicts_with = "file")]
+        #[arg(conflicts_with = "file", add = config_name_candidates())]
         name: Option<String>,
 
         /// YAML file to validate (local file)
         #[arg(long, short = 'f', conflicts_with = "name")]
         file: Option<String>,
+
+        /// Only run the client-side schema check (no API validation)
+        #[arg(long)]
+        local: bool,
+    },
+
+    /// Write a starter stackhawk.yml for an application
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop config generate --app billing --host https://localhost:8443\n  \
+            hawkop config generate --app billing --host https://staging.example.com \\\n    \
+            --env Staging --oas /v3/api-docs --auth json -o stackhawk.yml\n\n\
+        The file has the app ID, environment, and host filled in, an OpenAPI\n\
+        block when --oas is given (a path starting with / is fetched from the\n\
+        host, anything else is a local file), and an authentication block with\n\
+        ${SCAN_USERNAME}-style placeholders for --auth. Without --env, the app's\n\
+        only environment is used (Development if it has none).")]
+    Generate {
+        /// Application name or ID
+        #[arg(long, short = 'a', add = app_name_candidates())]
+        app: String,
+
+        /// URL of the running application to scan
+        #[arg(long, value_name = "URL")]
+        host: String,
+
+        /// Environment name (default: the app's only environment)
+        #[arg(long)]
+        env: Option<String>,
+
+        /// OpenAPI spec: a path on the host (/openapi.json) or a local file
+        #[arg(long, value_name = "PATH")]
+        oas: Option<String>,
+
+        /// Add an authentication block with placeholders
+        #[arg(long, value_enum)]
+        auth: Option<AuthTemplate>,
+
+        /// Output file path (optional, prints to stdout if not specified)
+        #[arg(long, short = 'o')]
+        output: Option<String>,
+
+        /// Overwrite the output file if it exists
+        #[arg(long, requires = "output")]
+        force: bool,
+    },
+
+    /// Move hawkop's own config files from ~/.hawkop to the platform config directory
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop config migrate --dry-run    # Show what would move\n  \
+            hawkop config migrate              # Move config, history, and state files\n\n\
+        The platform directory is $XDG_CONFIG_HOME/hawkop (~/.config/hawkop) on\n\
+        Linux, %APPDATA%\\hawkop on Windows, and ~/Library/Application Support/hawkop\n\
+        on macOS. Until migrated, an existing ~/.hawkop/config.yaml keeps being used.")]
+    Migrate {
+        /// Replace files that already exist in the platform directory
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Write hawkop's settings (profiles and preferences) without credentials
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop config export                        # Print settings as YAML\n  \
+            hawkop config export -o team.yaml           # Write to a file to share\n  \
+            hawkop config export --redact -o team.yaml  # Mark where API keys belong\n\n\
+        API keys are left out (masked with --redact) and tokens are never exported.\n\
+        Load the file on another machine with `hawkop config import`.")]
+    Export {
+        /// Keep api_key entries with a placeholder value instead of leaving them out
+        #[arg(long)]
+        redact: bool,
+
+        /// Output file path (optional, prints to stdout if not specified)
+        #[arg(long, short = 'o')]
+        output: Option<String>,
+    },
+
+    /// Merge settings from a `config export` file into the local config
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop config import team.yaml     # Create or update profiles from the file\n\n\
+        Each profile in the file replaces the org, API host, and preferences of\n\
+        the local profile with the same name, or is created. Local API keys and\n\
+        tokens are kept; run `hawkop init --profile <NAME>` for profiles without one.")]
+    Import {
+        /// File written by `hawkop config export`
+        file: String,
+    },
+
+    /// Check hawkop's config.yaml for unknown keys, bad values, and deprecated settings
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop config lint                       # Check the active config file\n  \
+            hawkop config lint -f ./config.yaml      # Check another file\n  \
+            hawkop config lint --format json         # Issues as JSON\n\n\
+        Each issue is reported with its line number and a suggested fix. Exits\n\
+        non-zero when the file has errors (invalid YAML or wrong value types);\n\
+        unknown keys and deprecated settings are warnings.")]
+    Lint {
+        /// Config file to check (default: the active hawkop config)
+        #[arg(long, short = 'f')]
+        file: Option<String>,
     },
 }
 
@@ -918,6 +2019,207 @@ pub enum ConfigCommands {
 pub enum SecretCommands {
     /// List user secrets (names only)
     List,
+
+    /// List the scan configurations that reference a secret
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop secret usages API_KEY\n  \
+            hawkop secret usages API_KEY --format json | jq -r '.data[].config'\n\n\
+        Downloads every scan configuration in the org and matches ${NAME},\n\
+        ${NAME:default}, and ${secret.NAME} placeholders outside comments.\n\
+        Check this before deleting a secret.")]
+    Usages {
+        /// Secret name (case-sensitive)
+        name: String,
+    },
+}
+
+/// Command history subcommands
+#[derive(Subcommand, Debug)]
+pub enum HistoryCommands {
+    /// List recent commands
+    List {
+        /// Number of entries to show (default: 20)
+        #[arg(long, short = 'n')]
+        limit: Option<usize>,
+    },
+
+    /// Search history by resource and optional text
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop history search scans           # All recorded scan commands\n  \
+            hawkop history search scans webstore  # Scan commands mentioning 'webstore'\n  \
+            hawkop history search team --limit 5")]
+    Search {
+        /// Resource/command to match (e.g. scans, apps, team)
+        resource: String,
+
+        /// Text to match within the command's arguments (case-insensitive)
+        query: Option<String>,
+
+        /// Number of entries to show (default: 20)
+        #[arg(long, short = 'n')]
+        limit: Option<usize>,
+    },
+
+    /// Delete all recorded history
+    Clear,
+
+    /// Print history file path
+    Path,
+}
+
+/// Suppression file subcommands
+#[derive(Subcommand, Debug)]
+pub enum SuppressCommands {
+    /// Accept a finding so gates, listings, and exports leave it out
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop suppress add 10038 --reason \"CSP is set by the CDN\"\n  \
+            hawkop suppress add 40012 --path '/legacy/*' --path /search \\\n    \
+               --app web --env prod --expires 90d --reason \"Retired with legacy UI\"\n\n\
+        Entries are added to the nearest .hawkop-suppressions.yaml between the\n\
+        working directory and the repository root (created at the root if there\n\
+        is none), or to the file named by HAWKOP_SUPPRESSIONS. Commit the file so\n\
+        suppressions are code-reviewed. In --path patterns, * matches any run of\n\
+        characters. --expires takes a date (2026-12-31) or a length of time (90d).")]
+    Add {
+        /// Plugin ID of the finding (e.g. 40012)
+        plugin: String,
+
+        /// Path pattern to suppress (repeatable; default: every path)
+        #[arg(long = "path", value_name = "PATTERN")]
+        paths: Vec<String>,
+
+        /// Only suppress in this application (name or ID)
+        #[arg(long, short = 'a', add = app_name_candidates())]
+        app: Option<String>,
+
+        /// Only suppress in this environment
+        #[arg(long, short = 'e')]
+        env: Option<String>,
+
+        /// Last day the suppression applies (date, or a length of time like 90d)
+        #[arg(long)]
+        expires: Option<String>,
+
+        /// Why the finding is accepted
+        #[arg(long, short = 'r')]
+        reason: String,
+    },
+
+    /// List suppressions and whether they are still active
+    #[command(visible_alias = "ls")]
+    List,
+
+    /// Remove expired suppressions from the file
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop suppress prune -N     # Show what would be removed\n  \
+            hawkop suppress prune")]
+    Prune,
+}
+
+/// Authentication subcommands
+#[derive(Subcommand, Debug)]
+pub enum AuthCommands {
+    /// Authenticate and store a fresh token
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop auth login                    # Use the profile's API key\n  \
+            hawkop auth login --api-key <KEY>    # Replace the API key after verifying it\n  \
+            hawkop --profile prod auth login")]
+    Login {
+        /// API key to use (saved to the profile once it authenticates)
+        #[arg(long, env = "HAWKOP_API_KEY", hide_env_values = true)]
+        api_key: Option<String>,
+    },
+
+    /// Re-authenticate now, regardless of token expiry
+    Refresh,
+
+    /// Remove the stored token
+    Logout {
+        /// Also remove the API key from the profile
+        #[arg(long)]
+        forget_key: bool,
+    },
+
+    /// Show token expiry countdown
+    Status,
+}
+
+/// Report subcommands
+#[derive(Subcommand, Debug)]
+pub enum ReportCommands {
+    /// Summarize findings across the latest scan of each app/environment
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop report findings                       # Last 30 days, grouped by app\n  \
+            hawkop report findings --since 7d\n  \
+            hawkop report findings --group-by plugin     # Most widespread findings\n  \
+            hawkop report findings --group-by severity -f json\n  \
+            hawkop report findings --app my-api,billing-api --group-by plugin")]
+    Findings {
+        /// Include scans started since this time (e.g. 7d, 30d, 2026-01-01)
+        #[arg(long, default_value = "30d")]
+        since: String,
+
+        /// Only include these applications (names or IDs, comma-separated)
+        #[arg(long, short = 'a', value_delimiter = ',', add = app_name_candidates())]
+        app: Vec<String>,
+
+        /// Group results by app, severity, or plugin
+        #[arg(long, value_enum, default_value_t = ReportGroupBy::App)]
+        group_by: ReportGroupBy,
+    },
+
+    /// Chart new vs triaged findings per scan over time
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop report trend --app my-api              # Last 90 days, every environment\n  \
+            hawkop report trend --app my-api --env prod --since 30d\n  \
+            hawkop report trend --app my-api -f json      # Series for dashboards")]
+    Trend {
+        /// Application name or ID
+        #[arg(long, short = 'a', add = app_name_candidates())]
+        app: String,
+
+        /// Only include this environment
+        #[arg(long, short = 'e')]
+        env: Option<String>,
+
+        /// Include scans started since this time (e.g. 30d, 90d, 2026-01-01)
+        #[arg(long, default_value = "90d")]
+        since: String,
+    },
+
+    /// Show how much of an app a scan reached, optionally against an OpenAPI spec
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop report coverage <scan-id>                    # URLs and paths with findings\n  \
+            hawkop report coverage <scan-id> --oas openapi.yaml # Spec operations covered\n  \
+            hawkop report coverage <scan-id> --oas openapi.yaml -f json\n\n\
+        The API reports how many URLs a scan visited, not which ones, so an operation\n\
+        counts as covered when a finding maps onto it. Coverage is a lower bound.")]
+    Coverage {
+        /// Scan ID (UUID)
+        #[arg(add = scan_id_candidates())]
+        scan_id: String,
+
+        /// OpenAPI 3.x or Swagger 2.0 spec file (YAML or JSON)
+        #[arg(long)]
+        oas: Option<String>,
+    },
+
+    /// List applications not scanned recently, or never scanned
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop report stale-apps                     # No scan in the last 30 days\n  \
+            hawkop report stale-apps --threshold 7d\n  \
+            hawkop report stale-apps --env prod          # Only count production scans\n  \
+            hawkop report stale-apps -f json\n\n\
+        Any scan counts, whatever its status; the table shows how the last one ended.")]
+    StaleApps {
+        /// Apps whose latest scan started before this are stale (e.g. 7d, 30d, 2026-01-01)
+        #[arg(long, default_value = "30d")]
+        threshold: String,
+
+        /// Only count scans of this environment
+        #[arg(long, short = 'e')]
+        env: Option<String>,
+    },
 }
 
 /// Cache management subcommands
@@ -929,6 +2231,60 @@ pub enum CacheCommands {
     Clear,
     /// Print cache directory path
     Path,
+    /// Pre-fetch the data shell completions use (recent scans, apps, teams)
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop cache warm                 # Warm scans, plugin IDs, apps, and teams\n  \
+            hawkop cache warm --scans         # Recent scan IDs and their plugin IDs only\n  \
+            hawkop cache warm --ttl 1h        # Keep warmed entries for an hour\n\n\
+        From cron or a shell login hook:\n  \
+            */30 * * * * hawkop cache warm --ttl 1h >/dev/null 2>&1\n  \
+            (hawkop cache warm >/dev/null 2>&1 &)   # in ~/.bashrc or ~/.zshrc\n\n\
+        With no selection flags, everything is warmed.")]
+    Warm {
+        /// Recent scan IDs, and plugin IDs for those scans
+        #[arg(long)]
+        scans: bool,
+
+        /// Application names and IDs
+        #[arg(long)]
+        apps: bool,
+
+        /// Team names and IDs
+        #[arg(long)]
+        teams: bool,
+
+        /// How long warmed entries stay fresh, e.g. 30m or 4h (default: each completion's usual TTL)
+        #[arg(long, value_name = "DURATION")]
+        ttl: Option<String>,
+    },
+}
+
+/// Background refresher subcommands
+#[derive(Subcommand, Debug)]
+pub enum DaemonCommands {
+    /// Start the refresher in the background
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop daemon start                # Refresh the default org every 60s\n  \
+            hawkop daemon start --interval 30  # Check every 30 seconds\n  \
+            hawkop --org <ID> daemon start     # Refresh another org\n\n\
+        Refreshes the first page of `scan list`, the app list, and the completions\n\
+        built from them shortly before they expire. Stop it with `hawkop daemon stop`.")]
+    Start {
+        /// Seconds between refresh checks (minimum 10)
+        #[arg(long, short = 'i', default_value = "60", value_parser = clap::value_parser!(u64).range(10..))]
+        interval: u64,
+    },
+    /// Stop the running refresher
+    Stop,
+    /// Show whether the refresher is running and when it last refreshed
+    Status,
+    /// Run the refresher in the foreground (what `daemon start` spawns)
+    #[command(hide = true)]
+    Run {
+        /// Seconds between refresh checks
+        #[arg(long, short = 'i', default_value = "60", value_parser = clap::value_parser!(u64).range(10..))]
+        interval: u64,
+    },
 }
 
 /// Audit log subcommands
@@ -944,6 +2300,33 @@ Examples:
         #[command(flatten)]
         filters: AuditFilterArgs,
     },
+
+    /// Summarize audit activity by user and type, and flag unusual activity
+    #[command(after_help = "EXAMPLES:\n  \
+            hawkop audit summary                           # Last 30 days\n  \
+            hawkop audit summary --since 7d --business-hours 9-17\n  \
+            hawkop audit summary --format json | jq '.data.anomalies'\n\n\
+        Flags activity from a user's new IP address, 5 or more removals by one\n\
+        user within an hour, and user actions outside business hours (Mon-Fri,\n\
+        local time unless --utc; scan events are not flagged). Reads at most\n\
+        1000 audit records. Needs an org admin API key.")]
+    Summary {
+        /// Start of the window (e.g. 7d, 30d, 2026-01-01)
+        #[arg(long, default_value = "30d")]
+        since: String,
+
+        /// End of the window (default: now)
+        #[arg(long)]
+        until: Option<String>,
+
+        /// Business hours, Mon-Fri, as START-END (e.g. 8-18, 09:30-17:30)
+        #[arg(long, default_value = "8-18")]
+        business_hours: BusinessHours,
+
+        /// Judge business hours and show times in UTC instead of local time
+        #[arg(long)]
+        utc: bool,
+    },
 }
 
 /// Environment management subcommands
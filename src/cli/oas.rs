@@ -8,27 +8,20 @@ use colored::Colorize;
 use crate::cache::CachedStackHawkClient;
 use crate::cli::PaginationArgs;
 use crate::cli::args::GlobalOptions;
+use crate::cli::context::looks_like_uuid;
 use crate::cli::handlers::run_list_command;
 use crate::cli::{CommandContext, OutputFormat};
 use crate::client::models::{Application, OASAsset};
 use crate::client::{ListingApi, OASApi, StackHawkClient};
 use crate::error::Result;
 use crate::models::OASDisplay;
+use crate::output::Formattable;
+use crate::output::hints::hint;
 use crate::output::json::format_json;
-use crate::output::table::format_table;
 
 /// Type alias for the Arc-wrapped cached client
 type Client = Arc<CachedStackHawkClient<StackHawkClient>>;
 
-/// Simple UUID format check (8-4-4-4-12 hex pattern)
-fn looks_like_uuid(s: &str) -> bool {
-    let parts: Vec<&str> = s.split('-').collect();
-    parts.len() == 5
-        && parts
-            .iter()
-            .all(|p| p.chars().all(|c| c.is_ascii_hexdigit()))
-}
-
 /// Resolve an app identifier (name or UUID) to an Application
 async fn resolve_app(client: &Client, org_id: &str, identifier: &str) -> Result<Application> {
     // If it looks like a UUID, try to find by ID
@@ -92,7 +85,7 @@ pub async fn get(opts: &GlobalOptions, oas_id: &str, output: Option<&str>) -> Re
     let org_id = ctx.require_org_id()?;
     let client = ctx.client.clone();
 
-    eprintln!("{} Fetching OAS '{}'...", "→".blue(), oas_id);
+    hint!("{} Fetching OAS '{}'...", "→".blue(), oas_id);
 
     let content = client.get_oas(org_id, oas_id).await?;
 
@@ -106,8 +99,13 @@ pub async fn get(opts: &GlobalOptions, oas_id: &str, output: Option<&str>) -> Re
             // Print to stdout
             match opts.format {
                 OutputFormat::Json => {
-                    // Content is already JSON, just print it
-                    println!("{}", content);
+                    // Content is already JSON; reformat only so --query can apply
+                    match serde_json::from_str::<serde_json::Value>(&content) {
+                        Ok(parsed) => {
+                            println!("{}", crate::output::json::format_raw_json(&parsed)?)
+                        }
+                        Err(_) => println!("{}", content),
+                    }
                 }
                 _ => {
                     // Pretty-print the JSON
@@ -145,7 +143,7 @@ pub async fn mappings(opts: &GlobalOptions, app: &str) -> Result<()> {
             "Info:".blue(),
             application.name
         );
-        eprintln!(
+        hint!(
             "{}",
             "→ Map OAS specs to your app in the StackHawk UI".dimmed()
         );
@@ -160,8 +158,7 @@ pub async fn mappings(opts: &GlobalOptions, app: &str) -> Result<()> {
         }
         OutputFormat::Table | OutputFormat::Pretty => {
             let displays: Vec<OASDisplay> = oas_assets.iter().map(OASDisplay::from).collect();
-            let table = format_table(&displays);
-            println!("{}", table);
+            displays.print(opts.format)?;
         }
     }
 
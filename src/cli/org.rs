@@ -1,13 +1,93 @@
 //! Organization command implementations
 
+use std::future::Future;
+
 use colored::Colorize;
+use futures::stream::{self, StreamExt};
+use log::debug;
 
 use crate::cli::args::GlobalOptions;
+use crate::cli::scan::fetch_all_scans;
 use crate::cli::{CommandContext, OutputFormat};
-use crate::client::ListingApi;
+use crate::client::models::Organization;
+use crate::client::{ListingApi, PaginationParams, ScanFilterParams, max_concurrency};
 use crate::error::Result;
 use crate::models::OrgDisplay;
+use crate::models::display::usage_report::{Quota, UsageReport};
+use crate::models::display::{OrgDetail, OrgUsage, format_org_detail, format_quota, timestamp_ms};
+use crate::output::hints::hint;
+use crate::output::warnings::{WarningKind, warn};
 use crate::output::{Formattable, json};
+use crate::timeparse;
+
+/// Organizations fetched at once by `--all-orgs`
+///
+/// Each org's own pages are fetched in parallel too, so this stays small.
+const MAX_PARALLEL_ORGS: usize = 4;
+
+/// Run `fetch` for every organization the API key can access (`--all-orgs`)
+///
+/// Orgs are fetched a few at a time and returned in name order, each with
+/// what `fetch` returned for it. An org that fails is left out with a
+/// `partial_results` warning; if every org fails, the first error is
+/// returned.
+pub(crate) async fn for_all_orgs<T, F, Fut>(
+    ctx: &CommandContext,
+    opts: &GlobalOptions,
+    fetch: F,
+) -> Result<Vec<(Organization, T)>>
+where
+    F: Fn(Organization) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    if opts.org_ref().is_some() {
+        warn(WarningKind::IgnoredFlag, "--org is ignored with --all-orgs");
+    }
+
+    let mut orgs = ctx.client.list_orgs().await?;
+    orgs.sort_by_key(|o| o.name.to_lowercase());
+    hint!("Fetching from {} organizations...", orgs.len());
+
+    let mut results: Vec<(Organization, Result<T>)> = stream::iter(orgs)
+        .map(|org| {
+            let fetched = fetch(org.clone());
+            async move { (org, fetched.await) }
+        })
+        .buffer_unordered(MAX_PARALLEL_ORGS.min(max_concurrency()))
+        .collect()
+        .await;
+    results.sort_by_key(|(org, _)| org.name.to_lowercase());
+
+    let total = results.len();
+    let mut fetched = Vec::with_capacity(total);
+    let mut failed = Vec::new();
+    let mut first_error = None;
+    for (org, result) in results {
+        match result {
+            Ok(value) => fetched.push((org, value)),
+            Err(e) => {
+                debug!("Failed to fetch from org {} ({}): {}", org.name, org.id, e);
+                failed.push(org.name);
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+    if let Some(e) = first_error {
+        if fetched.is_empty() {
+            return Err(e);
+        }
+        warn(
+            WarningKind::PartialResults,
+            format!(
+                "Failed to fetch from {} of {} organizations ({}); they are left out",
+                failed.len(),
+                total,
+                failed.join(", ")
+            ),
+        );
+    }
+    Ok(fetched)
+}
 
 /// Run the org list command
 pub async fn list(opts: &GlobalOptions) -> Result<()> {
@@ -53,29 +133,116 @@ pub async fn set(opts: &GlobalOptions, org_id: String) -> Result<()> {
 }
 
 /// Run the org get command
-pub async fn get(opts: &GlobalOptions) -> Result<()> {
+///
+/// Shows the organization's plan, subscription, and features, with current
+/// application and member counts measured against the subscription's limits.
+pub async fn get(opts: &GlobalOptions, org_id: Option<&str>) -> Result<()> {
     let ctx = CommandContext::new(opts).await?;
-    let org_id = ctx.require_org_id()?;
+    let org_id = match org_id {
+        Some(id) => id,
+        None => ctx.require_org_id()?,
+    };
 
-    // Get all orgs and find the one matching our configured org_id
     let orgs = ctx.client.list_orgs().await?;
-    let org = orgs.iter().find(|o| o.id == org_id).ok_or_else(|| {
+    let org = orgs.into_iter().find(|o| o.id == org_id).ok_or_else(|| {
         crate::error::ApiError::NotFound(format!(
             "Organization {} not found or you don't have access to it",
             org_id
         ))
     })?;
 
+    // Only the total counts are needed, so request a single item from each list
+    let count_page = PaginationParams::new().page_size(1).page(0);
+    let (apps, users) = tokio::join!(
+        ctx.client.list_apps_paged(org_id, Some(&count_page)),
+        ctx.client.list_users_paged(org_id, Some(&count_page)),
+    );
+    let usage = OrgUsage {
+        apps: apps.ok().and_then(|page| page.total_count),
+        users: users.ok().and_then(|page| page.total_count),
+    };
+    if usage.apps.is_none() || usage.users.is_none() {
+        warn(
+            WarningKind::PartialResults,
+            "Could not count applications or users; usage is incomplete",
+        );
+    }
+
+    let detail = OrgDetail { org, usage };
     match ctx.format {
         OutputFormat::Pretty | OutputFormat::Table => {
-            println!("{}", "Current Default Organization".bold());
-            println!();
-            println!("  ID:   {}", org.id);
-            println!("  Name: {}", org.name);
+            print!("{}", format_org_detail(&detail));
         }
         OutputFormat::Json => {
-            let output = json::format_json(&org)?;
-            println!("{}", output);
+            println!("{}", json::format_json(&detail)?);
+        }
+    }
+
+    Ok(())
+}
+
+/// Run the org usage command
+///
+/// Counts consumption per month from the org's scans in the window, with
+/// current member and application counts against the subscription limits.
+pub async fn usage(opts: &GlobalOptions, since: &str) -> Result<()> {
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?;
+    let start_ms = timeparse::parse_millis(since)?;
+
+    let count_page = PaginationParams::new().page_size(1).page(0);
+    let (orgs, scans, apps, users) = tokio::join!(
+        ctx.client.list_orgs(),
+        fetch_all_scans(
+            ctx.client.clone(),
+            org_id,
+            Some(ScanFilterParams::new().start(start_ms))
+        ),
+        ctx.client.list_apps_paged(org_id, Some(&count_page)),
+        ctx.client.list_users_paged(org_id, Some(&count_page)),
+    );
+    let org = orgs?.into_iter().find(|o| o.id == org_id).ok_or_else(|| {
+        crate::error::ApiError::NotFound(format!(
+            "Organization {} not found or you don't have access to it",
+            org_id
+        ))
+    })?;
+    let mut scans = scans?;
+    // Client-side guard in case the API ignores the start filter
+    scans.retain(|s| timestamp_ms(s) >= start_ms);
+    debug!("Usage: {} scans since {}", scans.len(), since);
+
+    let sub = org.subscription.as_ref();
+    let seats = Quota {
+        used: users.ok().and_then(|page| page.total_count),
+        limit: sub.and_then(|s| s.user_count),
+    };
+    let app_quota = Quota {
+        used: apps.ok().and_then(|page| page.total_count),
+        limit: sub.and_then(|s| s.app_count),
+    };
+    if seats.used.is_none() || app_quota.used.is_none() {
+        warn(
+            WarningKind::PartialResults,
+            "Could not count applications or users; current usage is incomplete",
+        );
+    }
+
+    let report = UsageReport::build(&org.name, since, seats, app_quota, &scans);
+    match ctx.format {
+        OutputFormat::Json => {
+            println!("{}", json::format_json(&report)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            println!(
+                "Usage for {} since {} | Seats: {} | Applications: {}",
+                report.org,
+                since,
+                format_quota(report.seats.used, report.seats.limit),
+                format_quota(report.apps.used, report.apps.limit)
+            );
+            println!();
+            report.rows().print(ctx.format)?;
         }
     }
 
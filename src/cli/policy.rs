@@ -3,6 +3,7 @@
 use log::debug;
 
 use crate::cli::args::GlobalOptions;
+use crate::cli::handlers::fetch_listing;
 use crate::cli::{CommandContext, PaginationArgs};
 use crate::client::ListingApi;
 use crate::error::Result;
@@ -23,10 +24,15 @@ pub async fn list(opts: &GlobalOptions, pagination: &PaginationArgs) -> Result<(
         org_id
     );
 
-    let params = pagination.to_params();
+    let client = ctx.client.clone();
+    let org = org_id.to_string();
     let (stackhawk_result, org_result) = tokio::join!(
         ctx.client.list_stackhawk_policies(),
-        ctx.client.list_org_policies(org_id, Some(&params))
+        fetch_listing(pagination, "org policies", move |params| {
+            let c = client.clone();
+            let o = org.clone();
+            async move { c.list_org_policies(&o, Some(&params)).await }
+        })
     );
 
     let stackhawk_policies = stackhawk_result?;
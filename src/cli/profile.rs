@@ -10,8 +10,8 @@ use serde::Serialize;
 use crate::cli::OutputFormat;
 use crate::cli::args::GlobalOptions;
 use crate::client::{AuthApi, ListingApi, StackHawkClient};
-use crate::config::{ProfileConfig, ProfiledConfig};
-use crate::error::Result;
+use crate::config::{ProfileConfig, ProfiledConfig, TokenStore};
+use crate::error::{PromptResult, Result};
 use crate::output::table::format_table;
 
 /// Display model for profile list output
@@ -66,7 +66,7 @@ pub fn list(opts: &GlobalOptions) -> Result<()> {
                 "active_profile": active,
                 "profiles": items,
             });
-            println!("{}", serde_json::to_string_pretty(&output)?);
+            println!("{}", crate::output::json::format_raw_json(&output)?);
         }
         _ => {
             println!("{}", "Configuration Profiles".bold());
@@ -155,7 +155,8 @@ pub async fn create(name: &str, from: Option<&str>, opts: &GlobalOptions) -> Res
         let api_key: String = Password::with_theme(&ColorfulTheme::default())
             .with_prompt("Enter your StackHawk API key (or leave empty)")
             .allow_empty_password(true)
-            .interact()?;
+            .interact()
+            .prompt_result()?;
 
         let api_key = if api_key.is_empty() {
             None
@@ -167,12 +168,14 @@ pub async fn create(name: &str, from: Option<&str>, opts: &GlobalOptions) -> Res
         let use_custom_host = Confirm::with_theme(&ColorfulTheme::default())
             .with_prompt("Use a custom API host? (for dev/test environments)")
             .default(false)
-            .interact()?;
+            .interact()
+            .prompt_result()?;
 
         let api_host = if use_custom_host {
             let host: String = dialoguer::Input::with_theme(&ColorfulTheme::default())
                 .with_prompt("Enter API host (e.g., http://localhost:8080)")
-                .interact_text()?;
+                .interact_text()
+                .prompt_result()?;
             Some(host)
         } else {
             None
@@ -201,7 +204,8 @@ pub async fn create(name: &str, from: Option<&str>, opts: &GlobalOptions) -> Res
                                 .with_prompt("Select default organization for this profile")
                                 .items(&org_names)
                                 .default(0)
-                                .interact_opt()?;
+                                .interact_opt()
+                                .prompt_result()?;
 
                             selection.map(|idx| orgs[idx].id.clone())
                         }
@@ -265,7 +269,8 @@ pub fn delete(name: &str, yes: bool, opts: &GlobalOptions) -> Result<()> {
         let confirmed = Confirm::with_theme(&ColorfulTheme::default())
             .with_prompt(format!("Delete profile '{}'?", name))
             .default(false)
-            .interact()?;
+            .interact()
+            .prompt_result()?;
 
         if !confirmed {
             println!("Cancelled.");
@@ -289,6 +294,9 @@ pub fn show(name: Option<&str>, opts: &GlobalOptions) -> Result<()> {
     let profile = config.get_profile(profile_name)?;
 
     let is_active = profile_name == config.active_profile;
+    let jwt_valid = TokenStore::for_profile(&config, profile, None)
+        .and_then(|tokens| tokens.load())
+        .is_some_and(|jwt| !jwt.needs_refresh());
 
     match opts.format {
         OutputFormat::Json => {
@@ -298,13 +306,14 @@ pub fn show(name: Option<&str>, opts: &GlobalOptions) -> Result<()> {
                 "api_key_configured": profile.api_key.is_some(),
                 "api_host": profile.api_host,
                 "org_id": profile.org_id,
-                "jwt_valid": !profile.is_token_expired(),
+                "jwt_valid": jwt_valid,
                 "preferences": {
                     "format": profile.preferences.format,
                     "page_size": profile.preferences.page_size,
+                    "command_defaults": profile.preferences.command_defaults,
                 }
             });
-            println!("{}", serde_json::to_string_pretty(&output)?);
+            println!("{}", crate::output::json::format_raw_json(&output)?);
         }
         _ => {
             println!(
@@ -352,7 +361,7 @@ pub fn show(name: Option<&str>, opts: &GlobalOptions) -> Result<()> {
             }
 
             // JWT status
-            if profile.is_token_expired() {
+            if !jwt_valid {
                 println!(
                     "  {} JWT token expired or missing (will refresh on next command)",
                     "⚠".yellow()
@@ -367,6 +376,10 @@ pub fn show(name: Option<&str>, opts: &GlobalOptions) -> Result<()> {
             if let Some(ref fmt) = profile.preferences.format {
                 println!("  Default format: {}", fmt);
             }
+            for (key, value) in &profile.preferences.command_defaults {
+                let value = serde_yaml::to_string(value).unwrap_or_default();
+                println!("  {}: {}", key, value.trim_end());
+            }
         }
     }
 
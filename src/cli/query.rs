@@ -0,0 +1,187 @@
+//! Ad-hoc cross-resource queries (`hawkop query`)
+
+use futures::stream::{FuturesUnordered, StreamExt};
+use log::debug;
+
+use crate::cli::args::GlobalOptions;
+use crate::cli::scan::fetch_all_scans;
+use crate::cli::team::{fetch_all_apps, fetch_all_teams};
+use crate::cli::{CommandContext, OutputFormat};
+use crate::client::models::TeamDetail;
+use crate::client::{ScanFilterParams, TeamApi};
+use crate::error::{QueryError, Result};
+use crate::models::display::timestamp_ms;
+use crate::output::warnings::{WarningKind, warn};
+use crate::query::{self, Dataset, schema};
+use crate::timeparse;
+
+/// Run a query
+///
+/// Only the tables the query references are fetched (through the response
+/// cache), so `select ... from apps` costs a single listing.
+pub async fn run(opts: &GlobalOptions, sql: &str, since: Option<&str>) -> Result<()> {
+    let parsed = query::parse(sql)?;
+
+    let tables = parsed.tables();
+    for name in &tables {
+        if schema::table_info(name).is_none() {
+            let known: Vec<&str> = schema::TABLES.iter().map(|t| t.name).collect();
+            return Err(QueryError::Invalid(format!(
+                "Unknown table '{}'. Available tables: {} (see `hawkop query --tables`)",
+                name,
+                known.join(", ")
+            ))
+            .into());
+        }
+    }
+    let needs = |names: &[&str]| tables.iter().any(|t| names.contains(t));
+
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?;
+
+    let start_ms = since.map(timeparse::parse_millis).transpose()?;
+    if start_ms.is_some() && !needs(&["scans", "latest_scan"]) {
+        warn(
+            WarningKind::IgnoredFlag,
+            "--since only applies to the scans and latest_scan tables",
+        );
+    }
+
+    let apps_fut = async {
+        if needs(&["apps"]) {
+            fetch_all_apps(ctx.client.clone(), org_id).await.map(Some)
+        } else {
+            Ok(None)
+        }
+    };
+    let scans_fut = async {
+        if needs(&["scans", "latest_scan"]) {
+            let filters = start_ms.map(|ms| ScanFilterParams::new().start(ms));
+            fetch_all_scans(ctx.client.clone(), org_id, filters)
+                .await
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    };
+    let teams_fut = async {
+        if needs(&["teams", "team_apps", "team_users"]) {
+            fetch_team_details(&ctx, org_id).await.map(Some)
+        } else {
+            Ok(None)
+        }
+    };
+    let (apps, scans, teams) = tokio::try_join!(apps_fut, scans_fut, teams_fut)?;
+
+    let mut data = Dataset::new();
+    if let Some(apps) = apps {
+        data.insert("apps", schema::apps_table(&apps));
+    }
+    if let Some(mut scans) = scans {
+        if let Some(ms) = start_ms {
+            // Client-side guard in case the API ignores the start filter
+            scans.retain(|s| timestamp_ms(s) >= ms);
+        }
+        data.insert("scans", schema::scans_table(&scans));
+        data.insert("latest_scan", schema::latest_scan_table(&scans));
+    }
+    if let Some(teams) = teams {
+        let (teams, team_apps, team_users) = schema::team_tables(&teams);
+        data.insert("teams", teams);
+        data.insert("team_apps", team_apps);
+        data.insert("team_users", team_users);
+    }
+
+    let result = query::execute(&parsed, &data)?;
+    debug!("Query returned {} rows", result.rows.len());
+
+    match ctx.format {
+        OutputFormat::Json => {
+            println!("{}", crate::output::json::format_json(&result)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            let rows: Vec<Vec<String>> = result
+                .rows
+                .iter()
+                .map(|r| r.iter().map(|v| v.to_string()).collect())
+                .collect();
+            println!(
+                "{}",
+                crate::output::table::format_rows(&result.columns, &rows)
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Print the queryable tables, their columns, and implicit join keys
+pub fn tables(format: OutputFormat) -> Result<()> {
+    match format {
+        OutputFormat::Json => {
+            let tables: Vec<_> = schema::TABLES
+                .iter()
+                .map(|t| {
+                    serde_json::json!({
+                        "name": t.name,
+                        "description": t.description,
+                        "columns": t.columns,
+                    })
+                })
+                .collect();
+            println!("{}", crate::output::json::format_json(&tables)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            for t in schema::TABLES {
+                println!("{} — {}", t.name, t.description);
+                println!("  {}", t.columns.join(", "));
+            }
+            println!();
+            println!("Implicit joins (when ON is omitted):");
+            for (a, ac, b, bc) in schema::RELATIONSHIPS {
+                println!("  {}.{} = {}.{}", a, ac, b, bc);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Fetch all teams with their members and app assignments
+async fn fetch_team_details(ctx: &CommandContext, org_id: &str) -> Result<Vec<TeamDetail>> {
+    let teams = fetch_all_teams(ctx.client.clone(), org_id).await?;
+
+    let mut futures: FuturesUnordered<_> = teams
+        .iter()
+        .map(|team| {
+            let c = ctx.client.clone();
+            let org = org_id.to_string();
+            let team_id = team.id.clone();
+            async move { c.get_team(&org, &team_id).await }
+        })
+        .collect();
+
+    let mut details = Vec::with_capacity(teams.len());
+    let mut failed = 0usize;
+    while let Some(result) = futures.next().await {
+        match result {
+            Ok(detail) => details.push(detail),
+            Err(e) => {
+                debug!("Failed to fetch team detail: {}", e);
+                failed += 1;
+            }
+        }
+    }
+    if failed > 0 {
+        warn(
+            WarningKind::PartialResults,
+            format!(
+                "Failed to fetch details for {} of {} teams; they are omitted",
+                failed,
+                teams.len()
+            ),
+        );
+    }
+
+    details.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(details)
+}
@@ -7,10 +7,14 @@ use crate::cli::OutputFormat;
 use crate::cli::PaginationArgs;
 use crate::cli::args::GlobalOptions;
 use crate::cli::handlers::run_list_command;
-use crate::client::models::{ReplaceRepoAppMappingsRequest, RepoAppInfoWrite, Repository};
+use crate::client::models::{
+    ReplaceRepoAppMappingsRequest, RepoAppInfo, RepoAppInfoWrite, Repository,
+};
 use crate::client::{ListingApi, RepoApi};
-use crate::error::Result;
+use crate::error::{PromptResult, Result};
 use crate::models::RepoDisplay;
+use crate::models::display::format_repo_detail;
+use crate::output::hints::hint;
 
 /// Run the repo list command
 ///
@@ -25,6 +29,29 @@ pub async fn list(opts: &GlobalOptions, pagination: &PaginationArgs) -> Result<(
     .await
 }
 
+/// Run the repo get command
+///
+/// Shows full detail for one repository, identified by ID or name.
+pub async fn get(opts: &GlobalOptions, identifier: &str) -> Result<()> {
+    use crate::cli::CommandContext;
+
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?;
+
+    let repo = resolve_repo_ref(&*ctx.client, org_id, identifier).await?;
+
+    match ctx.format {
+        OutputFormat::Json => {
+            println!("{}", crate::output::json::format_json(&repo)?);
+        }
+        _ => {
+            print!("{}", format_repo_detail(&repo));
+        }
+    }
+
+    Ok(())
+}
+
 /// Run the repo link command (additive: read-merge-write)
 ///
 /// Links an application to a repository while preserving existing mappings.
@@ -40,7 +67,6 @@ pub async fn link(
     app_id: Option<&str>,
     app_name: Option<&str>,
     env: &str,
-    dry_run: bool,
 ) -> Result<()> {
     use crate::cli::CommandContext;
 
@@ -79,18 +105,8 @@ pub async fn link(
         if is_already_linked(&existing, link_id) {
             match ctx.format {
                 OutputFormat::Json => {
-                    let output = serde_json::json!({
-                        "data": {
-                            "alreadyLinked": true,
-                            "repoId": resolved_repo_id,
-                            "appId": link_id
-                        },
-                        "meta": {
-                            "version": env!("CARGO_PKG_VERSION"),
-                            "timestamp": chrono::Utc::now().to_rfc3339()
-                        }
-                    });
-                    println!("{}", serde_json::to_string_pretty(&output)?);
+                    let data = serde_json::json!({ "alreadyLinked": true, "repoId": resolved_repo_id, "appId": link_id });
+                    println!("{}", crate::output::json::format_json(&data)?);
                 }
                 _ => {
                     eprintln!(
@@ -105,7 +121,7 @@ pub async fn link(
         }
     }
 
-    if dry_run {
+    if ctx.dry_run {
         let existing_count = resolved_repo.app_infos.len();
         eprintln!("{}", "DRY RUN - no changes will be made".yellow());
         eprintln!();
@@ -137,18 +153,8 @@ pub async fn link(
             total_mappings,
         } => match ctx.format {
             OutputFormat::Json => {
-                let output = serde_json::json!({
-                    "data": {
-                        "repoId": repo_id,
-                        "repoName": repo_name,
-                        "totalMappings": total_mappings,
-                    },
-                    "meta": {
-                        "version": env!("CARGO_PKG_VERSION"),
-                        "timestamp": chrono::Utc::now().to_rfc3339()
-                    }
-                });
-                println!("{}", serde_json::to_string_pretty(&output)?);
+                let data = serde_json::json!({ "repoId": repo_id, "repoName": repo_name, "totalMappings": total_mappings, });
+                println!("{}", crate::output::json::format_json(&data)?);
             }
             _ => {
                 eprintln!(
@@ -164,8 +170,8 @@ pub async fn link(
                     eprintln!("  Created + linked app: \"{}\"", name);
                 }
                 eprintln!("  Total app mappings: {}", total_mappings);
-                eprintln!();
-                eprintln!("→ hawkop repo list");
+                hint!();
+                hint!("→ hawkop repo list");
             }
         },
         // AlreadyLinked is handled above before dry-run; this branch is unreachable
@@ -183,6 +189,180 @@ pub async fn link(
     Ok(())
 }
 
+/// Run the repo map command
+///
+/// Maps an existing application, by name or ID, to a repository, by name or
+/// ID. Other mappings are kept; mapping an already-mapped app is a no-op.
+pub async fn map(opts: &GlobalOptions, repo: &str, app: &str) -> Result<()> {
+    use crate::cli::CommandContext;
+
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?;
+
+    let repo = resolve_repo_ref(&*ctx.client, org_id, repo).await?;
+    let repo_id = require_repo_id(&repo)?;
+    let app = crate::cli::env::resolve_app(&ctx.client, org_id, app).await?;
+
+    if find_mapping(&repo, &app.id).is_some() {
+        return print_mapping(&ctx, &repo, &app.id, &app.name, false, repo.app_infos.len());
+    }
+
+    if ctx.dry_run {
+        eprintln!("{}", "DRY RUN - no changes will be made".yellow());
+        eprintln!();
+        eprintln!(
+            "Would map application \"{}\" ({}) to repository \"{}\" ({})",
+            app.name, app.id, repo.name, repo_id
+        );
+        eprintln!(
+            "  Existing mappings: {} (will be preserved)",
+            repo.app_infos.len()
+        );
+        return Ok(());
+    }
+
+    let response = ctx.client.map_repo_app(org_id, &repo_id, &app.id).await?;
+    print_mapping(
+        &ctx,
+        &repo,
+        &app.id,
+        &app.name,
+        true,
+        response.app_infos.len(),
+    )
+}
+
+/// Run the repo unmap command
+///
+/// Removes one application's mapping from a repository. The app is matched
+/// against the repository's mappings first, so mappings to deleted apps can
+/// be removed too; otherwise it is resolved by name or ID as usual.
+pub async fn unmap(opts: &GlobalOptions, repo: &str, app: &str) -> Result<()> {
+    use crate::cli::CommandContext;
+
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?;
+
+    let repo = resolve_repo_ref(&*ctx.client, org_id, repo).await?;
+    let repo_id = require_repo_id(&repo)?;
+
+    let mapping = match find_mapping(&repo, app) {
+        Some(mapping) => mapping.clone(),
+        None => {
+            let app = crate::cli::env::resolve_app(&ctx.client, org_id, app).await?;
+            match find_mapping(&repo, &app.id) {
+                Some(mapping) => mapping.clone(),
+                None => {
+                    return print_unmapped(&ctx, &repo, &app.id, &app.name, false, &repo.app_infos);
+                }
+            }
+        }
+    };
+    let (app_id, app_name) = mapping_labels(&mapping);
+    let Some(ref mapped_id) = mapping.app_id else {
+        return Err(crate::error::Error::Other(format!(
+            "The mapping for \"{}\" has no application ID; use 'repo set-apps' to replace the repository's mappings.",
+            app_name
+        )));
+    };
+
+    if ctx.dry_run {
+        eprintln!("{}", "DRY RUN - no changes will be made".yellow());
+        eprintln!();
+        eprintln!(
+            "Would unmap application \"{}\" ({}) from repository \"{}\" ({})",
+            app_name, app_id, repo.name, repo_id
+        );
+        eprintln!(
+            "  Remaining mappings: {}",
+            repo.app_infos.len().saturating_sub(1)
+        );
+        return Ok(());
+    }
+
+    let response = ctx
+        .client
+        .unmap_repo_app(org_id, &repo_id, mapped_id)
+        .await?;
+    print_unmapped(&ctx, &repo, &app_id, &app_name, true, &response.app_infos)
+}
+
+/// Report the outcome of `repo map`
+fn print_mapping(
+    ctx: &crate::cli::CommandContext,
+    repo: &Repository,
+    app_id: &str,
+    app_name: &str,
+    changed: bool,
+    total_mappings: usize,
+) -> Result<()> {
+    match ctx.format {
+        OutputFormat::Json => {
+            let data = serde_json::json!({ "repoId": repo.id, "repoName": repo.name, "appId": app_id, "appName": app_name, "changed": changed, "totalMappings": total_mappings });
+            println!("{}", crate::output::json::format_json(&data)?);
+        }
+        _ if !changed => {
+            eprintln!(
+                "{} Application \"{}\" is already mapped to repository \"{}\".",
+                "ℹ".blue(),
+                app_name,
+                repo.name
+            );
+        }
+        _ => {
+            eprintln!(
+                "{} Mapped application \"{}\" to repository \"{}\"",
+                "✓".green(),
+                app_name,
+                repo.name
+            );
+            eprintln!("  Total app mappings: {}", total_mappings);
+            hint!();
+            hint!("→ hawkop repo get {}", repo.name);
+        }
+    }
+    Ok(())
+}
+
+/// Report the outcome of `repo unmap`
+fn print_unmapped(
+    ctx: &crate::cli::CommandContext,
+    repo: &Repository,
+    app_id: &str,
+    app_name: &str,
+    changed: bool,
+    remaining: &[RepoAppInfo],
+) -> Result<()> {
+    match ctx.format {
+        OutputFormat::Json => {
+            let data = serde_json::json!({ "repoId": repo.id, "repoName": repo.name, "appId": app_id, "appName": app_name, "changed": changed, "totalMappings": remaining.len() });
+            println!("{}", crate::output::json::format_json(&data)?);
+        }
+        _ if !changed => {
+            eprintln!(
+                "{} Application \"{}\" is not mapped to repository \"{}\".",
+                "ℹ".blue(),
+                app_name,
+                repo.name
+            );
+        }
+        _ => {
+            eprintln!(
+                "{} Unmapped application \"{}\" from repository \"{}\"",
+                "✓".green(),
+                app_name,
+                repo.name
+            );
+            eprintln!("  Total app mappings: {}", remaining.len());
+            if remaining.is_empty() {
+                hint!();
+                hint!("→ hawkop repo map {} --app <app>", repo.name);
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Run the repo set-apps command (full replacement)
 ///
 /// Replaces ALL application mappings for a repository. This is destructive —
@@ -192,7 +372,6 @@ pub async fn set_apps(
     repo_id: &str,
     app_ids: &[String],
     yes: bool,
-    dry_run: bool,
 ) -> Result<()> {
     use crate::cli::CommandContext;
 
@@ -210,7 +389,7 @@ pub async fn set_apps(
         })
         .collect();
 
-    if dry_run {
+    if ctx.dry_run {
         eprintln!("{}", "DRY RUN - no changes will be made".yellow());
         eprintln!();
         eprintln!(
@@ -255,7 +434,8 @@ pub async fn set_apps(
         let confirmed = Confirm::new()
             .with_prompt("Continue?")
             .default(false)
-            .interact()?;
+            .interact()
+            .prompt_result()?;
 
         if !confirmed {
             eprintln!("Cancelled.");
@@ -279,14 +459,7 @@ pub async fn set_apps(
 
     match ctx.format {
         OutputFormat::Json => {
-            let output = serde_json::json!({
-                "data": response,
-                "meta": {
-                    "version": env!("CARGO_PKG_VERSION"),
-                    "timestamp": chrono::Utc::now().to_rfc3339()
-                }
-            });
-            println!("{}", serde_json::to_string_pretty(&output)?);
+            println!("{}", crate::output::json::format_json(&response)?);
         }
         _ => {
             eprintln!(
@@ -295,8 +468,8 @@ pub async fn set_apps(
                 repo.name
             );
             eprintln!("  Total app mappings: {}", response.app_infos.len());
-            eprintln!();
-            eprintln!("→ hawkop repo list");
+            hint!();
+            hint!("→ hawkop repo list");
         }
     }
 
@@ -317,22 +490,7 @@ pub(crate) async fn resolve_repo(
         (Some(id), None) => client.get_repo(org_id, id).await,
         (None, Some(name)) => {
             let repos = client.list_repos(org_id, None).await?;
-            let matches: Vec<_> = repos
-                .into_iter()
-                .filter(|r| r.name.eq_ignore_ascii_case(name))
-                .collect();
-
-            match matches.len() {
-                0 => Err(crate::error::Error::Other(format!(
-                    "No repository found matching \"{}\".\n→ hawkop repo list",
-                    name
-                ))),
-                1 => Ok(matches.into_iter().next().unwrap()),
-                n => Err(crate::error::Error::Other(format!(
-                    "Ambiguous: {} repositories match \"{}\". Use --repo-id instead.\n→ hawkop repo list -o json | jq '.data[] | select(.name==\"{}\") | .id'",
-                    n, name, name
-                ))),
-            }
+            match_repo_by_name(repos, name)
         }
         _ => Err(crate::error::Error::Other(
             "Specify exactly one of --repo-id or --repo.\n\
@@ -343,6 +501,72 @@ pub(crate) async fn resolve_repo(
     }
 }
 
+/// Resolve a repository from a single identifier: an exact ID match wins,
+/// otherwise a case-insensitive name match.
+pub(crate) async fn resolve_repo_ref(
+    client: &(impl ListingApi + RepoApi),
+    org_id: &str,
+    identifier: &str,
+) -> Result<Repository> {
+    let repos = client.list_repos(org_id, None).await?;
+    if let Some(pos) = repos
+        .iter()
+        .position(|r| r.id.as_deref() == Some(identifier))
+    {
+        return Ok(repos.into_iter().nth(pos).unwrap());
+    }
+    match_repo_by_name(repos, identifier)
+}
+
+/// The repository's ID, which every mutation needs
+fn require_repo_id(repo: &Repository) -> Result<String> {
+    repo.id.clone().ok_or_else(|| {
+        crate::error::Error::Other("Repository has no ID (unexpected API response).".to_string())
+    })
+}
+
+/// The repository's mapping for `app`: an exact app ID match wins,
+/// otherwise a case-insensitive match on the recorded app name.
+fn find_mapping<'a>(repo: &'a Repository, app: &str) -> Option<&'a RepoAppInfo> {
+    repo.app_infos
+        .iter()
+        .find(|info| info.app_id.as_deref() == Some(app))
+        .or_else(|| {
+            repo.app_infos.iter().find(|info| {
+                info.app_name
+                    .as_deref()
+                    .is_some_and(|name| name.eq_ignore_ascii_case(app))
+            })
+        })
+}
+
+/// ID and display name of a mapping, falling back to each other
+fn mapping_labels(mapping: &RepoAppInfo) -> (String, String) {
+    let id = mapping.app_id.clone().unwrap_or_default();
+    let name = mapping.app_name.clone().unwrap_or_else(|| id.clone());
+    (id, name)
+}
+
+/// Pick the single repository whose name matches (case-insensitive).
+fn match_repo_by_name(repos: Vec<Repository>, name: &str) -> Result<Repository> {
+    let matches: Vec<_> = repos
+        .into_iter()
+        .filter(|r| r.name.eq_ignore_ascii_case(name))
+        .collect();
+
+    match matches.len() {
+        0 => Err(crate::error::Error::Other(format!(
+            "No repository found matching \"{}\".\n→ hawkop repo list",
+            name
+        ))),
+        1 => Ok(matches.into_iter().next().unwrap()),
+        n => Err(crate::error::Error::Other(format!(
+            "Ambiguous: {} repositories match \"{}\". Use the repository ID instead.\n→ hawkop repo list -o json | jq '.data[] | select(.name==\"{}\") | .id'",
+            n, name, name
+        ))),
+    }
+}
+
 /// Result of linking an app to a repo.
 #[derive(Debug)]
 pub(crate) enum LinkResult {
@@ -571,6 +795,22 @@ mod tests {
         assert!(err.to_string().contains("Ambiguous"));
     }
 
+    #[tokio::test]
+    async fn resolve_repo_ref_by_id_or_name() {
+        let client = MockStackHawkClient::new()
+            .with_repos(vec![
+                make_repo("r1", "my-repo", vec![]),
+                make_repo("r2", "r1", vec![]),
+            ])
+            .await;
+        // An exact ID match wins over a repo named like the ID
+        let repo = resolve_repo_ref(&client, "org1", "r1").await.unwrap();
+        assert_eq!(repo.name, "my-repo");
+        let repo = resolve_repo_ref(&client, "org1", "MY-REPO").await.unwrap();
+        assert_eq!(repo.id.as_deref(), Some("r1"));
+        assert!(resolve_repo_ref(&client, "org1", "missing").await.is_err());
+    }
+
     #[tokio::test]
     async fn resolve_repo_neither_selector() {
         let client = MockStackHawkClient::new();
@@ -578,6 +818,67 @@ mod tests {
         assert!(err.to_string().contains("Specify exactly one"));
     }
 
+    // ── find_mapping ─────────────────────────────────────────────────────
+
+    #[test]
+    fn find_mapping_by_id_or_name() {
+        let repo = make_repo(
+            "r1",
+            "my-repo",
+            vec![make_app_info("a1", "app-one"), make_app_info("a2", "a1")],
+        );
+        // An exact ID match wins over an app named like the ID
+        assert_eq!(
+            find_mapping(&repo, "a1").unwrap().app_name.as_deref(),
+            Some("app-one")
+        );
+        assert_eq!(
+            find_mapping(&repo, "APP-ONE").unwrap().app_id.as_deref(),
+            Some("a1")
+        );
+        assert!(find_mapping(&repo, "a3").is_none());
+    }
+
+    // ── map_repo_app / unmap_repo_app ────────────────────────────────────
+
+    #[tokio::test]
+    async fn map_repo_app_keeps_existing() {
+        let repo = make_repo("r1", "my-repo", vec![make_app_info("a1", "app-one")]);
+        let client = MockStackHawkClient::new().with_repos(vec![repo]).await;
+
+        let response = client.map_repo_app("org1", "r1", "a2").await.unwrap();
+        assert_eq!(response.app_infos.len(), 2);
+        // Already mapped: no duplicate
+        let response = client.map_repo_app("org1", "r1", "a2").await.unwrap();
+        assert_eq!(response.app_infos.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn unmap_repo_app_removes_only_that_app() {
+        let repo = make_repo(
+            "r1",
+            "my-repo",
+            vec![
+                make_app_info("a1", "app-one"),
+                make_app_info("a2", "app-two"),
+            ],
+        );
+        let client = MockStackHawkClient::new().with_repos(vec![repo]).await;
+
+        let response = client.unmap_repo_app("org1", "r1", "a1").await.unwrap();
+        let ids: Vec<_> = response
+            .app_infos
+            .iter()
+            .filter_map(|a| a.app_id.as_deref())
+            .collect();
+        assert_eq!(ids, ["a2"]);
+        let repo = client.get_repo("org1", "r1").await.unwrap();
+        assert_eq!(repo.app_infos.len(), 1);
+        // Not mapped: unchanged
+        let response = client.unmap_repo_app("org1", "r1", "a1").await.unwrap();
+        assert_eq!(response.app_infos.len(), 1);
+    }
+
     // ── link_app_to_repo ─────────────────────────────────────────────────
 
     #[tokio::test]
@@ -0,0 +1,376 @@
+//! Org-wide reports
+
+use std::collections::HashMap;
+
+use colored::Colorize;
+use futures::stream::{self, StreamExt};
+use log::debug;
+
+use crate::cli::args::GlobalOptions;
+use crate::cli::env::resolve_app;
+use crate::cli::scan::{fetch_alert_responses, fetch_all_scans, fetch_last_scans, spec_file_name};
+use crate::cli::team::fetch_all_apps;
+use crate::cli::{CommandContext, OutputFormat, ReportGroupBy};
+use crate::client::models::{Application, ScanResult};
+use crate::client::{ScanDetailApi, ScanFilterParams, max_concurrency};
+use crate::error::Result;
+use crate::models::display::coverage_report::CoverageReport;
+use crate::models::display::findings_report::{FindingsReport, ScanAlerts, latest_per_app_env};
+use crate::models::display::findings_trend::FindingsTrend;
+use crate::models::display::scan_diff::DiffFinding;
+use crate::models::display::stale_apps::StaleAppsReport;
+use crate::models::display::timestamp_ms;
+use crate::openapi::OpenApiSpec;
+use crate::output::Formattable;
+use crate::output::hints::hint;
+use crate::output::warnings::{WarningKind, warn};
+use crate::timeparse;
+
+/// Run the report findings command
+///
+/// Lists scans started within the window, keeps the latest completed scan
+/// per app + environment, fetches their alerts in parallel, and aggregates.
+pub async fn findings(
+    opts: &GlobalOptions,
+    since: &str,
+    apps: &[String],
+    group_by: ReportGroupBy,
+) -> Result<()> {
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?;
+
+    let start_ms = timeparse::parse_millis(since)?;
+    let app_ids = ctx.resolve_app_ids(apps).await?;
+    let filters = ScanFilterParams::new()
+        .start(start_ms)
+        .app_ids(app_ids.clone());
+
+    let mut scans = fetch_all_scans(ctx.client.clone(), org_id, Some(filters)).await?;
+    debug!("Report: {} scans since {}", scans.len(), since);
+
+    // Client-side guard in case the API ignores the start or app filters
+    scans.retain(|s| {
+        timestamp_ms(s) >= start_ms
+            && (app_ids.is_empty() || app_ids.contains(&s.scan.application_id))
+    });
+    let latest = latest_per_app_env(scans);
+
+    hint!("Fetching alerts for {} scans...", latest.len());
+
+    let results: Vec<_> = stream::iter(latest)
+        .map(|scan| {
+            let client = ctx.client.clone();
+            async move {
+                let alerts = client.list_scan_alerts(&scan.scan.id, None).await;
+                (scan, alerts)
+            }
+        })
+        .buffer_unordered(max_concurrency())
+        .collect()
+        .await;
+
+    let total = results.len();
+    let mut data = Vec::with_capacity(total);
+    let mut failed = 0usize;
+    for (scan, alerts) in results {
+        match alerts {
+            Ok(alerts) => data.push(ScanAlerts { scan, alerts }),
+            Err(e) => {
+                debug!("Failed to fetch alerts for scan {}: {}", scan.scan.id, e);
+                failed += 1;
+            }
+        }
+    }
+    if failed > 0 {
+        warn(
+            WarningKind::PartialResults,
+            format!(
+                "Failed to fetch alerts for {} of {} scans; they are excluded from the report",
+                failed, total
+            ),
+        );
+    }
+
+    let report = FindingsReport::aggregate(since, group_by, &data);
+
+    match ctx.format {
+        OutputFormat::Json => {
+            println!("{}", crate::output::json::format_json(&report)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            println!(
+                "Findings since {} | {} scans (latest per app/env) | High: {} | Medium: {} | Low: {} | Paths: {}",
+                since,
+                report.scans_analyzed,
+                report.totals.high,
+                report.totals.medium,
+                report.totals.low,
+                report.totals.paths
+            );
+            println!();
+            report.groups.print(ctx.format)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Run the report trend command
+///
+/// Lists an app's scans within the window and charts new vs triaged findings
+/// per completed scan. Scans listed without alert stats are fetched
+/// individually, in parallel.
+pub async fn trend(opts: &GlobalOptions, app: &str, env: Option<&str>, since: &str) -> Result<()> {
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?;
+
+    let start_ms = timeparse::parse_millis(since)?;
+    let application = resolve_app(&ctx.client, org_id, app).await?;
+    let mut filters = ScanFilterParams::new()
+        .start(start_ms)
+        .app_ids(vec![application.id.clone()]);
+    if let Some(env) = env {
+        filters = filters.envs(vec![env.to_string()]);
+    }
+
+    let mut scans = fetch_all_scans(ctx.client.clone(), org_id, Some(filters)).await?;
+    debug!("Trend: {} scans since {}", scans.len(), since);
+
+    // Client-side guard in case the API ignores the filters
+    scans.retain(|s| {
+        timestamp_ms(s) >= start_ms
+            && s.scan.application_id == application.id
+            && env.is_none_or(|e| s.scan.env.eq_ignore_ascii_case(e))
+            && s.scan.status.eq_ignore_ascii_case("COMPLETED")
+    });
+
+    let (mut complete, missing): (Vec<_>, Vec<_>) =
+        scans.into_iter().partition(|s| s.alert_stats.is_some());
+    if !missing.is_empty() {
+        hint!("Fetching details for {} scans...", missing.len());
+
+        let total = missing.len();
+        let results: Vec<_> = stream::iter(missing)
+            .map(|scan| {
+                let client = ctx.client.clone();
+                async move { client.get_scan(org_id, &scan.scan.id).await }
+            })
+            .buffer_unordered(max_concurrency())
+            .collect()
+            .await;
+
+        let mut failed = 0usize;
+        for result in results {
+            match result {
+                Ok(scan) => complete.push(scan),
+                Err(e) => {
+                    debug!("Failed to fetch scan details: {}", e);
+                    failed += 1;
+                }
+            }
+        }
+        if failed > 0 {
+            warn(
+                WarningKind::PartialResults,
+                format!(
+                    "Failed to fetch {} of {} scans; they are excluded from the trend",
+                    failed, total
+                ),
+            );
+        }
+    }
+
+    let trend = FindingsTrend::build(&application.name, &application.id, since, &complete);
+
+    match ctx.format {
+        OutputFormat::Json => {
+            println!("{}", crate::output::json::format_json(&trend)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            println!(
+                "Findings trend for {} since {} | {} completed scans",
+                trend.app, since, trend.scans_analyzed
+            );
+            if trend.series.is_empty() {
+                return Ok(());
+            }
+            println!();
+            let width = trend.series.iter().map(|s| s.env.len()).max().unwrap_or(0);
+            for series in &trend.series {
+                println!(
+                    "  {:<width$}  new {}  triaged {}",
+                    series.env,
+                    series.new_sparkline,
+                    series.triaged_sparkline,
+                    width = width
+                );
+            }
+            println!();
+            trend.rows().print(ctx.format)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Run the report coverage command
+///
+/// Reads the scan's URL count and every path-level finding (one request per
+/// plugin), and with `--oas` maps the findings onto the spec's operations.
+pub async fn coverage(opts: &GlobalOptions, scan_id: &str, oas: Option<&str>) -> Result<()> {
+    let spec = oas.map(OpenApiSpec::load).transpose()?;
+
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?;
+
+    let (scan, responses) = tokio::try_join!(
+        ctx.client.get_scan(org_id, scan_id),
+        fetch_alert_responses(&ctx, scan_id, "coverage may be understated"),
+    )?;
+    let findings: Vec<DiffFinding> = responses
+        .iter()
+        .flat_map(DiffFinding::from_alert_response)
+        .collect();
+    debug!("Coverage: {} findings for scan {}", findings.len(), scan_id);
+
+    let spec_name = oas.map(spec_file_name).unwrap_or_default();
+    let report = CoverageReport::build(
+        &scan,
+        &findings,
+        spec.as_ref().map(|spec| (spec_name.as_str(), spec)),
+    );
+
+    match ctx.format {
+        OutputFormat::Json => {
+            println!("{}", crate::output::json::format_json(&report)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            vec![report.row()].print(ctx.format)?;
+            if let Some(spec) = &report.spec {
+                println!(
+                    "\n{} in {} ({})",
+                    "Operations without findings".bold(),
+                    spec.spec,
+                    spec.uncovered
+                );
+                spec.uncovered_operations.print(ctx.format)?;
+            } else {
+                hint!();
+                hint!(
+                    "→ Compare with a spec: hawkop report coverage {} --oas <spec-file>",
+                    scan_id
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Run the report stale-apps command
+///
+/// Joins the app list with the scans started within the threshold; apps with
+/// none there get one more lookup (in parallel) for their latest scan ever,
+/// so the report can tell long-unscanned apps from never-scanned ones.
+pub async fn stale_apps(opts: &GlobalOptions, threshold: &str, env: Option<&str>) -> Result<()> {
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?;
+
+    let cutoff_ms = timeparse::parse_millis(threshold)?;
+    let mut filters = ScanFilterParams::new().start(cutoff_ms);
+    if let Some(env) = env {
+        filters = filters.envs(vec![env.to_string()]);
+    }
+
+    let (apps, scans) = tokio::try_join!(
+        fetch_all_apps(ctx.client.clone(), org_id),
+        fetch_all_scans(ctx.client.clone(), org_id, Some(filters)),
+    )?;
+    debug!(
+        "Stale apps: {} apps, {} scans since {}",
+        apps.len(),
+        scans.len(),
+        threshold
+    );
+
+    // Newest recent scan per app; client-side guard in case the API ignores
+    // the start or env filters
+    let mut latest: HashMap<String, ScanResult> = HashMap::new();
+    for scan in scans {
+        let started = timestamp_ms(&scan);
+        if started < cutoff_ms || !env.is_none_or(|e| scan.scan.env.eq_ignore_ascii_case(e)) {
+            continue;
+        }
+        let newer = latest
+            .get(&scan.scan.application_id)
+            .is_none_or(|s| timestamp_ms(s) < started);
+        if newer {
+            latest.insert(scan.scan.application_id.clone(), scan);
+        }
+    }
+
+    let unseen: Vec<&str> = apps
+        .iter()
+        .filter(|app| !latest.contains_key(&app.id))
+        .map(|app| app.id.as_str())
+        .collect();
+    if !unseen.is_empty() {
+        hint!("Checking last scan for {} apps...", unseen.len());
+    }
+
+    let total = unseen.len();
+    let (last, failed) = fetch_last_scans(ctx.client.clone(), org_id, unseen, env).await;
+    latest.extend(last);
+    if !failed.is_empty() {
+        warn(
+            WarningKind::PartialResults,
+            format!(
+                "Failed to fetch the last scan for {} of {} apps; they are excluded from the report",
+                failed.len(),
+                total
+            ),
+        );
+    }
+
+    let checked: Vec<Application> = apps
+        .iter()
+        .filter(|app| !failed.contains(app.id.as_str()))
+        .cloned()
+        .collect();
+    let report = StaleAppsReport::build(
+        threshold,
+        env,
+        &checked,
+        &latest,
+        cutoff_ms,
+        chrono::Utc::now().timestamp_millis(),
+    );
+
+    match ctx.format {
+        OutputFormat::Json => {
+            println!("{}", crate::output::json::format_json(&report)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            let scope = env.map(|e| format!(" in {}", e)).unwrap_or_default();
+            if report.stale.is_empty() {
+                println!(
+                    "All {} apps scanned{} since {}",
+                    report.apps_checked, scope, threshold
+                );
+                return Ok(());
+            }
+            println!(
+                "{} of {} apps not scanned{} since {} ({} never scanned)",
+                report.stale.len(),
+                report.apps_checked,
+                scope,
+                threshold,
+                report.never_scanned
+            );
+            println!();
+            report.stale.print(ctx.format)?;
+        }
+    }
+
+    Ok(())
+}
@@ -11,26 +11,22 @@ use log::debug;
 
 use crate::cache::CachedStackHawkClient;
 use crate::cli::args::GlobalOptions;
+use crate::cli::context::looks_like_uuid;
+use crate::cli::scan::resolve_latest_scan;
 use crate::cli::{CommandContext, OutputFormat};
 use crate::client::models::Application;
-use crate::client::{ListingApi, PerchApi, StackHawkClient};
-use crate::error::Result;
+use crate::client::{ListingApi, PerchApi, ScanDetailApi, StackHawkClient};
+use crate::error::{PromptResult, Result};
 use crate::models::display::{PrettyRunStatus, RunStatusDisplay};
+use crate::notify::{self, NotifyTarget, ScanNotification};
+use crate::output::hints::hint;
 use crate::output::json::format_json;
 use crate::output::table::format_table;
+use crate::output::warnings::{WarningKind, warn};
 
 /// Type alias for the Arc-wrapped cached client
 type Client = Arc<CachedStackHawkClient<StackHawkClient>>;
 
-/// Simple UUID format check (8-4-4-4-12 hex pattern)
-fn looks_like_uuid(s: &str) -> bool {
-    let parts: Vec<&str> = s.split('-').collect();
-    parts.len() == 5
-        && parts
-            .iter()
-            .all(|p| p.chars().all(|c| c.is_ascii_hexdigit()))
-}
-
 /// Resolve an app identifier (name or UUID) to an Application
 async fn resolve_app(client: &Client, org_id: &str, identifier: &str) -> Result<Application> {
     // If it looks like a UUID, try to find by ID
@@ -84,6 +80,7 @@ pub async fn start(
     env: Option<&str>,
     config: Option<&str>,
     watch: bool,
+    notify: &[NotifyTarget],
 ) -> Result<()> {
     let ctx = CommandContext::new(opts).await?;
     let org_id = ctx.require_org_id()?;
@@ -107,7 +104,7 @@ pub async fn start(
     }
 
     // Start the scan
-    eprintln!("{} Starting scan for '{}'...", "→".blue(), application.name);
+    hint!("{} Starting scan for '{}'...", "→".blue(), application.name);
 
     let response = client.start_scan(app_id, env, config).await?;
 
@@ -119,20 +116,23 @@ pub async fn start(
 
     // If watch mode, poll for status
     if watch {
-        eprintln!();
-        eprintln!(
+        hint!();
+        hint!(
             "{} Watching scan progress (Ctrl+C to stop watching)...",
             "→".blue()
         );
-        eprintln!();
+        hint!();
 
         watch_status(&client, app_id, Some(&application.name), opts.format, 5).await?;
+        if !notify.is_empty() {
+            notify_latest_scan(&ctx, org_id, app_id, env, notify).await;
+        }
     } else {
-        eprintln!(
+        hint!(
             "{}",
             format!("→ Use `hawkop run status --app {}` to check progress", app).dimmed()
         );
-        eprintln!(
+        hint!(
             "{}",
             format!(
                 "→ Use `hawkop run start --app {} --watch` to watch progress",
@@ -145,6 +145,33 @@ pub async fn start(
     Ok(())
 }
 
+/// Send `--notify` notifications for the app's latest scan
+///
+/// The hosted scan status does not carry findings, so the scan that just
+/// ended is looked up in the scan list.
+async fn notify_latest_scan(
+    ctx: &CommandContext,
+    org_id: &str,
+    app_id: &str,
+    env: Option<&str>,
+    notify: &[NotifyTarget],
+) {
+    let scan = async {
+        let scan_id = resolve_latest_scan(ctx, org_id, None, Some(app_id), env).await?;
+        ctx.client.get_scan(org_id, &scan_id).await
+    };
+    match scan.await {
+        Ok(result) => notify::send_all(notify, &ScanNotification::from_scan(&result)).await,
+        Err(e) => {
+            debug!("Could not look up the finished scan: {}", e);
+            warn(
+                WarningKind::NotificationFailed,
+                format!("Could not look up the finished scan to notify: {}", e),
+            );
+        }
+    }
+}
+
 // ============================================================================
 // Stop Command
 // ============================================================================
@@ -175,7 +202,8 @@ pub async fn stop(opts: &GlobalOptions, app: &str, yes: bool) -> Result<()> {
         let confirmed = Confirm::new()
             .with_prompt(format!("Stop the running scan for '{}'?", application.name))
             .default(false)
-            .interact()?;
+            .interact()
+            .prompt_result()?;
 
         if !confirmed {
             eprintln!("{}", "Cancelled".yellow());
@@ -186,7 +214,7 @@ pub async fn stop(opts: &GlobalOptions, app: &str, yes: bool) -> Result<()> {
     debug!("Stopping scan for app {} ({})", application.name, app_id);
 
     // Stop the scan
-    eprintln!("{} Stopping scan for '{}'...", "→".blue(), application.name);
+    hint!("{} Stopping scan for '{}'...", "→".blue(), application.name);
 
     client.stop_scan(app_id).await?;
 
@@ -194,7 +222,7 @@ pub async fn stop(opts: &GlobalOptions, app: &str, yes: bool) -> Result<()> {
         "{} Stop command sent. The scan will stop after completing its current operation.",
         "✓".green()
     );
-    eprintln!(
+    hint!(
         "{}",
         format!("→ Use `hawkop run status --app {}` to verify", app).dimmed()
     );
@@ -1,19 +1,59 @@
 //! Scan management commands
 
+use std::collections::{HashMap, HashSet};
+use std::io::{IsTerminal, Write};
+use std::sync::Arc;
+use std::time::Duration;
+
+use colored::Colorize;
+use futures::stream::{self, StreamExt};
 use log::debug;
+use serde::Serialize;
+use tabled::Tabled;
 
+use crate::cache::{CacheTtl, CachedStackHawkClient};
 use crate::cli::args::GlobalOptions;
-use crate::cli::{CommandContext, OutputFormat, PaginationArgs, ScanFilterArgs, SortDir};
-use crate::client::models::ScanResult;
+use crate::cli::org::for_all_orgs;
+use crate::cli::{
+    AlertFilterArgs, AlertGroupBy, AlertPathArgs, CommandContext, FindingsThreshold, FormatArg,
+    OutputFormat, PaginationArgs, ScanFilterArgs, SearchField, SortDir,
+};
+use crate::client::models::{
+    AlertMsgResponse, AlertResponse, ApplicationAlert, ApplicationAlertUri, Organization,
+    ScanResult, severity_rank,
+};
+use crate::client::progress::{
+    FollowOptions, RUNNING_STATUSES, ScanEvent, follow_scan, is_running,
+};
 use crate::client::{
-    ListingApi, PaginationParams, ScanDetailApi, ScanFilterParams, fetch_remaining_pages,
+    ListingApi, PaginationParams, ScanDetailApi, ScanFilterParams, StackHawkClient,
+    fetch_remaining_pages, max_concurrency,
 };
 use crate::error::Result;
+use crate::models::display::alert_groups::{AlertGroup, group_alerts};
+use crate::models::display::finding_search::{FindingMatch, FindingMatchDisplay, SearchPattern};
+use crate::models::display::oas_map::OasFindingsMap;
+use crate::models::display::redact::redact_message;
+use crate::models::display::scan_diff::{DiffFinding, DiffScanInfo, ScanDiff};
+use crate::models::display::scan_gate::GateResult;
+use crate::models::display::scan_group::{AppScanGroup, expanded_rows};
+use crate::models::display::visible_tags;
+use crate::models::display::{OrgScoped, or_dash, timestamp_ms};
+use crate::models::display::{RowChange, diff_rows};
 use crate::models::{
-    AlertDetail, AlertFindingDisplay, AlertMessageDetail, PrettyAlertDisplay, ScanDisplay,
+    AlertDetail, AlertFindingDisplay, AlertMessageDetail, OwaspAlertDisplay, PrettyAlertDisplay,
+    ScanDisplay,
 };
-use crate::output::Formattable;
+use crate::notify::{self, NotifyTarget, ScanNotification};
+use crate::openapi::OpenApiSpec;
 use crate::output::formatters::{format_duration_seconds, format_timestamp_local};
+use crate::output::har::HarMessage;
+use crate::output::hints::hint;
+use crate::output::markdown::{Markdown, escape, inline_code};
+use crate::output::warnings::{self, WarningKind, warn};
+use crate::output::{Formattable, columns, pager, render};
+use crate::suppress::Suppressions;
+use crate::timeparse;
 
 // ============================================================================
 // Scan Context for Banner Display
@@ -119,24 +159,341 @@ impl ScanContext {
 const DEFAULT_SCAN_LIMIT: usize = 10;
 
 /// Requested page size for scans endpoint
-const SCAN_API_PAGE_SIZE: usize = 100;
+pub(crate) const SCAN_API_PAGE_SIZE: usize = 100;
 
 /// Max scans to fetch when sorting (to avoid runaway queries)
 const MAX_SORT_FETCH: usize = 10_000;
 
-/// Max concurrent requests in the worker pool
-const PARALLEL_FETCH_LIMIT: usize = 32;
+/// Fetch every scan matching `filters` using parallel pagination.
+///
+/// Used by org-wide commands (`report findings`, `query`) that need the full
+/// scan history rather than a display page.
+pub(crate) async fn fetch_all_scans(
+    client: Arc<CachedStackHawkClient<StackHawkClient>>,
+    org_id: &str,
+    filters: Option<ScanFilterParams>,
+) -> Result<Vec<ScanResult>> {
+    let first_params = PaginationParams::new()
+        .page_size(SCAN_API_PAGE_SIZE)
+        .page(0);
+    let first_response = client
+        .list_scans_paged(org_id, Some(&first_params), filters.as_ref())
+        .await?;
+
+    let mut all_scans = first_response.items;
+    if let Some(total_count) = first_response.total_count {
+        let total_pages = total_count.div_ceil(SCAN_API_PAGE_SIZE);
+        if total_pages > 1 {
+            debug!(
+                "Fetching {} remaining scan pages in parallel (totalCount={})",
+                total_pages - 1,
+                total_count
+            );
+            let org = org_id.to_string();
+            let remaining = fetch_remaining_pages(
+                (1..total_pages).collect(),
+                move |page| {
+                    let c = client.clone();
+                    let o = org.clone();
+                    let f = filters.clone();
+                    async move {
+                        let params = PaginationParams::new()
+                            .page_size(SCAN_API_PAGE_SIZE)
+                            .page(page);
+                        c.list_scans(&o, Some(&params), f.as_ref()).await
+                    }
+                },
+                max_concurrency(),
+            )
+            .await?;
+            all_scans.extend(remaining);
+        }
+    }
+
+    debug!("Total scans fetched: {}", all_scans.len());
+    Ok(all_scans)
+}
+
+/// Each app's most recent scan (in `env` when given), one request per app
+///
+/// Returns the scans found, keyed by app ID, and the apps whose request
+/// failed. Apps that were never scanned are in neither.
+pub(crate) async fn fetch_last_scans<'a>(
+    client: Arc<CachedStackHawkClient<StackHawkClient>>,
+    org_id: &str,
+    app_ids: Vec<&'a str>,
+    env: Option<&str>,
+) -> (HashMap<String, ScanResult>, HashSet<&'a str>) {
+    let results: Vec<_> = stream::iter(app_ids)
+        .map(|app_id| {
+            let client = client.clone();
+            let mut filters = ScanFilterParams::new().app_ids(vec![app_id.to_string()]);
+            if let Some(env) = env {
+                filters = filters.envs(vec![env.to_string()]);
+            }
+            async move {
+                let pagination = PaginationParams::new().page_size(1).page(0);
+                let scans = client
+                    .list_scans(org_id, Some(&pagination), Some(&filters))
+                    .await;
+                (app_id, scans)
+            }
+        })
+        .buffer_unordered(max_concurrency())
+        .collect()
+        .await;
+
+    let mut latest = HashMap::new();
+    let mut failed = HashSet::new();
+    for (app_id, scans) in results {
+        match scans {
+            Ok(scans) => {
+                if let Some(scan) = scans.into_iter().find(|s| s.scan.application_id == app_id) {
+                    latest.insert(app_id.to_string(), scan);
+                }
+            }
+            Err(e) => {
+                debug!("Failed to fetch last scan for app {}: {}", app_id, e);
+                failed.insert(app_id);
+            }
+        }
+    }
+    (latest, failed)
+}
 
 /// Run the scan list command
+///
+/// With `watch`, the list is fetched again every `watch` seconds and redrawn
+/// with new and changed rows marked (see [`watch_list`]). With `all_orgs`,
+/// every accessible organization is listed (see [`list_all_orgs`]).
 pub async fn list(
     opts: &GlobalOptions,
     filters: &ScanFilterArgs,
     pagination: &PaginationArgs,
+    watch: Option<Option<u64>>,
+    all_orgs: bool,
+    highlight: &[FindingsThreshold],
+) -> Result<()> {
+    let ctx = CommandContext::new(opts).await?;
+
+    if let Some(interval_secs) = watch {
+        return watch_list(&ctx, opts, filters, pagination, interval_secs, highlight).await;
+    }
+    if all_orgs {
+        return list_all_orgs(&ctx, opts, filters, pagination, highlight).await;
+    }
+
+    let (display_scans, truncated) = fetch_scan_list(&ctx, filters, pagination, highlight).await?;
+    if truncated && pagination.limit.is_none() {
+        hint!(
+            "→ Showing the first {} scans. Use --limit N or --all to see more.",
+            display_scans.len()
+        );
+    }
+    let flags: Vec<bool> = display_scans
+        .iter()
+        .map(ScanDisplay::is_highlighted)
+        .collect();
+    print_highlighted(&display_scans, &flags, highlight, ctx.format)?;
+
+    Ok(())
+}
+
+/// Print scan rows, marking those that exceed a `--highlight-threshold` rule
+///
+/// Table rows that exceed a rule get a red `!` in front, followed by a count
+/// of them; JSON rows carry the rules in `exceeds` instead.
+fn print_highlighted<T: Tabled + Serialize>(
+    rows: &[T],
+    flags: &[bool],
+    highlight: &[FindingsThreshold],
+    format: OutputFormat,
+) -> Result<()> {
+    if highlight.is_empty() || format == OutputFormat::Json || columns::ids_only() {
+        return rows.print(format);
+    }
+    pager::print(&flag_rows(&rows.format(format)?, flags));
+
+    let rules: Vec<String> = highlight
+        .iter()
+        .map(|t| format!("{}:{}", t.severity, t.count))
+        .collect();
+    let count = flags.iter().filter(|flagged| **flagged).count();
+    hint!(
+        "{} {} of {} scans exceed --highlight-threshold {}",
+        "!".red().bold(),
+        count,
+        rows.len(),
+        rules.join(",")
+    );
+    if count > 0 {
+        hint!("→ hawkop scan gate <scan-id> --fail-on {}", rules.join(","));
+    }
+    Ok(())
+}
+
+/// Window `scan list --group-by app` covers when `--since` is not given
+const GROUP_WINDOW: &str = "30d";
+
+/// Run `scan list --group-by app`
+///
+/// Fetches every scan in the window (`--since`/`--until`, default the last
+/// [`GROUP_WINDOW`]) and rolls them up per application, most recently
+/// scanned first. `--limit` limits applications, not scans. With `expand`,
+/// table output nests each application's scans under it and JSON rows carry
+/// them in `scans`.
+pub async fn list_by_app(
+    opts: &GlobalOptions,
+    filters: &ScanFilterArgs,
+    pagination: &PaginationArgs,
+    expand: bool,
 ) -> Result<()> {
     let ctx = CommandContext::new(opts).await?;
     let org_id = ctx.require_org_id()?;
 
-    let display_limit = pagination.limit.unwrap_or(DEFAULT_SCAN_LIMIT);
+    let mut filters = filters.clone();
+    let default_window = filters.since.is_none();
+    if default_window {
+        filters.since = Some(GROUP_WINDOW.to_string());
+    }
+    let app_ids = ctx.resolve_app_ids(&filters.app).await?;
+    let every_page = PaginationArgs {
+        all: true,
+        ..Default::default()
+    };
+    let scans = fetch_org_scans(&ctx, org_id.to_string(), app_ids, &filters, &every_page).await?;
+    let scan_count = scans.len();
+
+    let mut groups = AppScanGroup::group(scans, expand);
+    if let Some(limit) = pagination.limit {
+        groups.truncate(limit);
+    }
+
+    if expand && ctx.format != OutputFormat::Json {
+        expanded_rows(&groups).print(ctx.format)?;
+    } else {
+        groups.print(ctx.format)?;
+    }
+
+    if default_window {
+        hint!(
+            "→ {} scans in the last {}. Use --since to change the window.",
+            scan_count,
+            GROUP_WINDOW
+        );
+    }
+
+    Ok(())
+}
+
+/// `scan list --all-orgs`: each org's scans, merged and tagged with the org
+///
+/// Every org is fetched as `scan list` would fetch one, then the lists are
+/// merged (newest first, or by `--sort-by`) and limited together.
+async fn list_all_orgs(
+    ctx: &CommandContext,
+    opts: &GlobalOptions,
+    filters: &ScanFilterArgs,
+    pagination: &PaginationArgs,
+    highlight: &[FindingsThreshold],
+) -> Result<()> {
+    let fetched = for_all_orgs(ctx, opts, |org| {
+        fetch_org_scans(ctx, org.id, Vec::new(), filters, pagination)
+    })
+    .await?;
+    let (orgs, lists): (Vec<Organization>, Vec<Vec<ScanResult>>) = fetched.into_iter().unzip();
+
+    let mut scans: Vec<(usize, ScanResult)> = lists
+        .into_iter()
+        .enumerate()
+        .flat_map(|(i, scans)| scans.into_iter().map(move |scan| (i, scan)))
+        .collect();
+    let descending = matches!(pagination.sort_dir, Some(SortDir::Desc));
+    scans.sort_by(|(_, a), (_, b)| match pagination.sort_by.as_deref() {
+        Some(field) => compare_scans(a, b, field, descending),
+        None => timestamp_ms(b).cmp(&timestamp_ms(a)),
+    });
+
+    let display_limit = display_limit(pagination);
+    let truncated = scans.len() > display_limit;
+    let display_scans: Vec<OrgScoped<ScanDisplay>> = scans
+        .into_iter()
+        .take(display_limit)
+        .map(|(i, scan)| OrgScoped::new(&orgs[i], scan_row(scan, highlight)))
+        .collect();
+    if truncated && pagination.limit.is_none() {
+        hint!(
+            "→ Showing the first {} scans. Use --limit N or --all to see more.",
+            display_scans.len()
+        );
+    }
+    let flags: Vec<bool> = display_scans
+        .iter()
+        .map(|scoped| scoped.row.is_highlighted())
+        .collect();
+    print_highlighted(&display_scans, &flags, highlight, ctx.format)?;
+
+    Ok(())
+}
+
+/// Scans shown by `scan list`: all with `--all`, else `--limit` or the default
+fn display_limit(pagination: &PaginationArgs) -> usize {
+    if pagination.all {
+        usize::MAX
+    } else {
+        pagination.limit.unwrap_or(DEFAULT_SCAN_LIMIT)
+    }
+}
+
+/// Fetch, filter, sort, and limit the scans for `scan list`
+///
+/// Returns the rows to show and whether more scans matched than the limit.
+async fn fetch_scan_list(
+    ctx: &CommandContext,
+    filters: &ScanFilterArgs,
+    pagination: &PaginationArgs,
+    highlight: &[FindingsThreshold],
+) -> Result<(Vec<ScanDisplay>, bool)> {
+    let org_id = ctx.require_org_id()?;
+    let app_ids = ctx.resolve_app_ids(&filters.app).await?;
+    let scans = fetch_org_scans(ctx, org_id.to_string(), app_ids, filters, pagination).await?;
+
+    // Apply display limit
+    let display_limit = display_limit(pagination);
+    let truncated = scans.len() > display_limit;
+
+    // Convert to display models
+    let display_scans: Vec<ScanDisplay> = scans
+        .into_iter()
+        .take(display_limit)
+        .map(|scan| scan_row(scan, highlight))
+        .collect();
+
+    Ok((display_scans, truncated))
+}
+
+/// Display row for a scan, evaluated against `--highlight-threshold` rules
+/// (new findings only, as `scan gate` counts them by default)
+fn scan_row(scan: ScanResult, highlight: &[FindingsThreshold]) -> ScanDisplay {
+    if highlight.is_empty() {
+        return ScanDisplay::from(scan);
+    }
+    let gate = GateResult::evaluate(&scan, highlight, false);
+    ScanDisplay::from(scan).with_gate(&gate)
+}
+
+/// Fetch one org's scans for `scan list`, filtered and sorted
+///
+/// Fetches enough scans to fill the display limit after filtering, or all
+/// of them when sorting; the caller applies the limit.
+async fn fetch_org_scans(
+    ctx: &CommandContext,
+    org_id: String,
+    app_ids: Vec<String>,
+    filters: &ScanFilterArgs,
+    pagination: &PaginationArgs,
+) -> Result<Vec<ScanResult>> {
+    let display_limit = display_limit(pagination);
 
     // Determine how many scans to fetch:
     // - Sorting requires all data (API doesn't support useful sort fields)
@@ -144,7 +501,9 @@ pub async fn list(
     // - Otherwise just fetch what we need to display
     let has_sort = pagination.sort_by.is_some();
     let has_status_filter = filters.status.is_some();
-    let target_count = if has_sort {
+    let target_count = if pagination.all {
+        usize::MAX
+    } else if has_sort {
         MAX_SORT_FETCH // Fetch all available for accurate sorting
     } else if has_status_filter {
         display_limit * 10
@@ -152,16 +511,16 @@ pub async fn list(
         display_limit
     };
 
-    // Build server-side filter params for app and env
-    let filter_params = if !filters.app.is_empty() || !filters.env.is_empty() {
-        Some(
-            ScanFilterParams::new()
-                .app_ids(filters.app.clone())
-                .envs(filters.env.clone()),
-        )
-    } else {
-        None
-    };
+    // Build server-side filter params for app, env, tags, and time range
+    let (start_ms, end_ms) =
+        timeparse::parse_range(filters.since.as_deref(), filters.until.as_deref())?;
+    let mut filter_params = ScanFilterParams::new()
+        .app_ids(app_ids)
+        .envs(filters.env.clone())
+        .tags(filters.tag.iter().map(|t| t.api_param()).collect());
+    filter_params.start = start_ms;
+    filter_params.end = end_ms;
+    let filter_params = (!filter_params.is_empty()).then_some(filter_params);
 
     // Fetch scans using totalCount-based parallel pagination
     // 1. First request gets totalCount
@@ -179,7 +538,7 @@ pub async fn list(
 
     let first_response = ctx
         .client
-        .list_scans_paged(org_id, Some(&first_params), filter_params.as_ref())
+        .list_scans_paged(&org_id, Some(&first_params), filter_params.as_ref())
         .await?;
 
     let mut all_scans = first_response.items;
@@ -207,7 +566,7 @@ pub async fn list(
                 );
 
                 let client = ctx.client.clone();
-                let org = org_id.to_string();
+                let org = org_id.clone();
                 let filters_clone = filter_params.clone();
 
                 let remaining_scans = fetch_remaining_pages(
@@ -223,7 +582,7 @@ pub async fn list(
                             c.list_scans(&o, Some(&params), f.as_ref()).await
                         }
                     },
-                    PARALLEL_FETCH_LIMIT,
+                    max_concurrency(),
                 )
                 .await?;
 
@@ -240,7 +599,7 @@ pub async fn list(
                 .page(page);
             let scans = ctx
                 .client
-                .list_scans(org_id, Some(&params), filter_params.as_ref())
+                .list_scans(&org_id, Some(&params), filter_params.as_ref())
                 .await?;
 
             if scans.is_empty() {
@@ -253,21 +612,135 @@ pub async fn list(
 
     debug!("Total scans fetched: {}", all_scans.len());
 
+    // Client-side guard in case the API ignores the time range or tags
+    all_scans
+        .retain(|s| in_time_range(s, start_ms, end_ms) && filters.tag.iter().all(|t| t.matches(s)));
+
     // Apply client-side filtering for status (not supported server-side)
     let filtered_scans = apply_status_filter(all_scans, filters);
 
     // Apply client-side sorting (API doesn't support useful sort fields)
-    let sorted_scans = apply_sort(filtered_scans, pagination);
+    Ok(apply_sort(filtered_scans, pagination))
+}
 
-    // Apply display limit
-    let limited_scans: Vec<_> = sorted_scans.into_iter().take(display_limit).collect();
+/// Redraw `scan list` every `interval_secs` until interrupted
+///
+/// Scan lists are cached for [`CacheTtl::SCAN_LIST`], which is also the
+/// default interval; shorter intervals only see new data with `--no-cache`.
+/// On a terminal the screen is cleared before each redraw, and rows that are
+/// new or whose status or findings changed since the previous refresh are
+/// marked `+` and `~`. JSON output prints one document per refresh.
+async fn watch_list(
+    ctx: &CommandContext,
+    opts: &GlobalOptions,
+    filters: &ScanFilterArgs,
+    pagination: &PaginationArgs,
+    interval_secs: Option<u64>,
+    highlight: &[FindingsThreshold],
+) -> Result<()> {
+    let cache_ttl = CacheTtl::SCAN_LIST.duration().as_secs();
+    let interval_secs = interval_secs.unwrap_or(cache_ttl).max(1);
+    if interval_secs < cache_ttl && !opts.no_cache {
+        warn(
+            WarningKind::IgnoredFlag,
+            format!(
+                "Scan lists are cached for {}s; refreshes inside that window reuse the cached \
+                 list. Add --no-cache to refresh every {}s",
+                cache_ttl, interval_secs
+            ),
+        );
+    }
+    let interval = Duration::from_secs(interval_secs);
+    let clear = ctx.format != OutputFormat::Json && std::io::stdout().is_terminal();
 
-    // Convert to display models
-    let display_scans: Vec<ScanDisplay> =
-        limited_scans.into_iter().map(ScanDisplay::from).collect();
-    display_scans.print(ctx.format)?;
+    let mut previous: Option<Vec<ScanDisplay>> = None;
+    loop {
+        let (rows, _) = fetch_scan_list(ctx, filters, pagination, highlight).await?;
 
-    Ok(())
+        if ctx.format == OutputFormat::Json {
+            println!("{}", crate::output::json::format_json(&rows)?);
+        } else {
+            let changes = match &previous {
+                Some(previous) => diff_rows(previous, &rows),
+                None => vec![RowChange::Unchanged; rows.len()],
+            };
+            let mut table = rows.format(ctx.format)?;
+            if !highlight.is_empty() {
+                let flags: Vec<bool> = rows.iter().map(ScanDisplay::is_highlighted).collect();
+                table = flag_rows(&table, &flags);
+            }
+            let table = mark_rows(&table, &changes);
+            if clear {
+                print!("\x1B[2J\x1B[1;1H");
+            }
+            println!("{}", table);
+            println!();
+            println!(
+                "{}",
+                format!(
+                    "Updated {} · refreshing every {}s · + new  ~ changed · Ctrl+C to stop",
+                    chrono::Local::now().format("%H:%M:%S"),
+                    interval_secs
+                )
+                .dimmed()
+            );
+        }
+        let _ = std::io::stdout().flush();
+
+        previous = Some(rows);
+        tokio::time::sleep(interval).await;
+    }
+}
+
+/// Prefix each table row with its change marker: `+` new, `~` changed
+fn mark_rows(table: &str, changes: &[RowChange]) -> String {
+    let markers: Vec<String> = changes
+        .iter()
+        .map(|change| match change {
+            RowChange::New => "+".green().bold().to_string(),
+            RowChange::Changed => "~".yellow().bold().to_string(),
+            RowChange::Unchanged => " ".to_string(),
+        })
+        .collect();
+    prefix_rows(table, &markers)
+}
+
+/// Prefix each flagged table row with a red `!`
+fn flag_rows(table: &str, flags: &[bool]) -> String {
+    let markers: Vec<String> = flags
+        .iter()
+        .map(|flagged| {
+            if *flagged {
+                "!".red().bold().to_string()
+            } else {
+                " ".to_string()
+            }
+        })
+        .collect();
+    prefix_rows(table, &markers)
+}
+
+/// Prefix the last `markers.len()` lines of `table` with one marker each
+///
+/// Header lines (all but the last `markers.len()` lines) get blank markers so
+/// the columns stay aligned.
+fn prefix_rows(table: &str, markers: &[String]) -> String {
+    let lines: Vec<&str> = table.lines().collect();
+    if lines.len() < markers.len() {
+        return table.to_string();
+    }
+    let header_lines = lines.len() - markers.len();
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let marker = i
+                .checked_sub(header_lines)
+                .map_or(" ", |row| markers[row].as_str());
+            format!("{} {}", marker, line)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 /// Check if a scan matches the status filter.
@@ -289,6 +762,14 @@ fn matches_status(scan: &ScanResult, status_filter: &str) -> bool {
     scan_status.to_lowercase().contains(&status_lower)
 }
 
+/// Check if a scan started within an optional `[start, end]` range (ms).
+fn in_time_range(scan: &ScanResult, start_ms: Option<i64>, end_ms: Option<i64>) -> bool {
+    let Ok(ts) = scan.scan.timestamp.parse::<i64>() else {
+        return true;
+    };
+    start_ms.is_none_or(|start| ts >= start) && end_ms.is_none_or(|end| ts <= end)
+}
+
 /// Apply client-side status filter to scan results.
 /// Status filtering is not supported server-side, so we filter here.
 fn apply_status_filter(scans: Vec<ScanResult>, filters: &ScanFilterArgs) -> Vec<ScanResult> {
@@ -309,51 +790,56 @@ fn apply_sort(mut scans: Vec<ScanResult>, pagination: &PaginationArgs) -> Vec<Sc
     };
 
     let descending = matches!(pagination.sort_dir, Some(SortDir::Desc));
-    let sort_by_lower = sort_by.to_lowercase();
-
-    scans.sort_by(|a, b| {
-        let cmp = match sort_by_lower.as_str() {
-            "app" | "application" | "appname" | "applicationname" => a
-                .scan
-                .application_name
-                .to_lowercase()
-                .cmp(&b.scan.application_name.to_lowercase()),
-            "env" | "environment" => a.scan.env.to_lowercase().cmp(&b.scan.env.to_lowercase()),
-            "status" => a
-                .scan
-                .status
-                .to_lowercase()
-                .cmp(&b.scan.status.to_lowercase()),
-            "started" | "timestamp" | "time" | "date" => a.scan.timestamp.cmp(&b.scan.timestamp),
-            "duration" => {
-                let a_dur: f64 = a
-                    .scan_duration
-                    .as_deref()
-                    .and_then(|s| s.parse().ok())
-                    .unwrap_or(0.0);
-                let b_dur: f64 = b
-                    .scan_duration
-                    .as_deref()
-                    .and_then(|s| s.parse().ok())
-                    .unwrap_or(0.0);
-                a_dur
-                    .partial_cmp(&b_dur)
-                    .unwrap_or(std::cmp::Ordering::Equal)
-            }
-            "findings" | "alerts" => {
-                // Sort by new findings: High first, then Medium, then Low
-                let a_findings = get_new_findings(a);
-                let b_findings = get_new_findings(b);
-                a_findings.cmp(&b_findings)
-            }
-            "id" => a.scan.id.cmp(&b.scan.id),
-            _ => std::cmp::Ordering::Equal,
-        };
+    scans.sort_by(|a, b| compare_scans(a, b, sort_by, descending));
+    scans
+}
 
-        if descending { cmp.reverse() } else { cmp }
-    });
+/// Order two scans by a `--sort-by` field (unknown fields compare equal)
+fn compare_scans(
+    a: &ScanResult,
+    b: &ScanResult,
+    sort_by: &str,
+    descending: bool,
+) -> std::cmp::Ordering {
+    let cmp = match sort_by.to_lowercase().as_str() {
+        "app" | "application" | "appname" | "applicationname" => a
+            .scan
+            .application_name
+            .to_lowercase()
+            .cmp(&b.scan.application_name.to_lowercase()),
+        "env" | "environment" => a.scan.env.to_lowercase().cmp(&b.scan.env.to_lowercase()),
+        "status" => a
+            .scan
+            .status
+            .to_lowercase()
+            .cmp(&b.scan.status.to_lowercase()),
+        "started" | "timestamp" | "time" | "date" => a.scan.timestamp.cmp(&b.scan.timestamp),
+        "duration" => {
+            let a_dur: f64 = a
+                .scan_duration
+                .as_deref()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(0.0);
+            let b_dur: f64 = b
+                .scan_duration
+                .as_deref()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(0.0);
+            a_dur
+                .partial_cmp(&b_dur)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        }
+        "findings" | "alerts" => {
+            // Sort by new findings: High first, then Medium, then Low
+            let a_findings = get_new_findings(a);
+            let b_findings = get_new_findings(b);
+            a_findings.cmp(&b_findings)
+        }
+        "id" => a.scan.id.cmp(&b.scan.id),
+        _ => std::cmp::Ordering::Equal,
+    };
 
-    scans
+    if descending { cmp.reverse() } else { cmp }
 }
 
 /// Extract new findings counts as (high, medium, low) tuple for sorting.
@@ -392,11 +878,15 @@ fn get_new_findings(scan: &ScanResult) -> (u32, u32, u32) {
 /// - `scan get <id> --plugin-id <p>` - Plugin detail with paths
 /// - `scan get <id> --uri-id <u>` - URI detail with evidence
 /// - `scan get <id> --uri-id <u> -m` - URI detail with HTTP message
+/// - `scan get <id> --uri-id <u> --curl-only` - Just the validation command
+/// - `scan get <id> --uri-id <u> -o har` - HTTP message as a HAR log
+/// - `scan get <id> [...] -o markdown` - Any of the views above as markdown
+/// - `scan get <id> --uri-id <u> -m --no-redact` - HTTP message with credentials
 /// - `scan get --detail full -o json` - Complete findings for AI agents
 #[allow(clippy::too_many_arguments)]
 pub async fn get(
     opts: &GlobalOptions,
-    format: OutputFormat,
+    format: FormatArg,
     scan_id: &str,
     app: Option<&str>,
     app_id: Option<&str>,
@@ -405,23 +895,45 @@ pub async fn get(
     max_findings: usize,
     max_body_size: usize,
     plugin_id: Option<&str>,
+    limit: Option<usize>,
+    page: Option<usize>,
     uri_id: Option<&str>,
     message: bool,
+    raw: bool,
+    curl_only: bool,
+    redact: bool,
+    plain: bool,
+    filters: &AlertFilterArgs,
+    paths: &AlertPathArgs,
+    group_by: Option<AlertGroupBy>,
+    owasp: bool,
 ) -> Result<()> {
+    let har = format == FormatArg::Har;
+    let markdown = format == FormatArg::Markdown;
+    if format == FormatArg::Junit {
+        return Err(crate::error::ApiError::BadRequest(
+            "--format junit is only supported by `scan gate` and `gate release`".to_string(),
+        )
+        .into());
+    }
+    if har && uri_id.is_none() {
+        return Err(crate::error::ApiError::BadRequest(
+            "--format har exports a finding's HTTP message; add --uri-id".to_string(),
+        )
+        .into());
+    }
+
     // For scan get, use the command-level format override (defaults to Pretty)
     let opts_with_format = GlobalOptions {
-        format,
-        org: opts.org.clone(),
-        config: opts.config.clone(),
-        profile: opts.profile.clone(),
-        no_cache: opts.no_cache,
-        api_host: opts.api_host.clone(),
+        format: format.output_format().unwrap_or_default(),
+        junit: false,
+        ..opts.clone()
     };
     let ctx = CommandContext::new(&opts_with_format).await?;
     let org_id = ctx.require_org_id()?;
 
     // Validate: can't use filters with specific scan ID
-    let is_latest = scan_id == "latest" || scan_id.is_empty();
+    let is_latest = scan_id.eq_ignore_ascii_case("latest") || scan_id.is_empty();
     if !is_latest && (app.is_some() || app_id.is_some() || env.is_some()) {
         return Err(crate::error::ApiError::BadRequest(
             "Cannot specify both scan ID and filters (--app, --app-id, --env). \
@@ -443,14 +955,34 @@ pub async fn get(
         resolved_id, detail, plugin_id, uri_id, message
     );
 
+    if markdown && detail.is_some() {
+        return Err(crate::error::ApiError::BadRequest(
+            "--format markdown renders the drill-down views; use --format json with --detail full"
+                .to_string(),
+        )
+        .into());
+    }
+
     // Route to --detail full handler if requested
     if let Some(detail_level) = detail {
         if detail_level.eq_ignore_ascii_case("full") {
             // Warn if drill-down flags are combined with --detail full (they're ignored)
-            if plugin_id.is_some() || uri_id.is_some() || message {
-                eprintln!("Warning: --plugin-id, --uri-id, and -m are ignored with --detail full");
+            if plugin_id.is_some() || uri_id.is_some() || message || curl_only {
+                warn(
+                    WarningKind::IgnoredFlag,
+                    "--plugin-id, --uri-id, -m, and --curl-only are ignored with --detail full",
+                );
             }
-            return show_full_detail(&ctx, org_id, &resolved_id, max_findings, max_body_size).await;
+            return show_full_detail(
+                &ctx,
+                org_id,
+                &resolved_id,
+                max_findings,
+                max_body_size,
+                filters,
+                redact,
+            )
+            .await;
         } else {
             return Err(crate::error::ApiError::BadRequest(format!(
                 "Unknown detail level '{}'. Supported: full",
@@ -460,12 +992,119 @@ pub async fn get(
         }
     }
 
+    // Only the pretty and markdown overviews have an alerts table
+    let alerts_table = markdown || ctx.format == OutputFormat::Pretty;
+    if !filters.is_empty() && uri_id.is_some() {
+        warn(
+            WarningKind::IgnoredFlag,
+            "--severity and --status are ignored with --uri-id",
+        );
+    } else if !filters.severity.is_empty() && plugin_id.is_some() {
+        warn(
+            WarningKind::IgnoredFlag,
+            "--severity is ignored with --plugin-id (a plugin has one severity)",
+        );
+    } else if !filters.is_empty() && plugin_id.is_none() && !alerts_table {
+        warn(
+            WarningKind::IgnoredFlag,
+            "--severity and --status only filter the alerts table in pretty and markdown \
+             formats (or with --detail full)",
+        );
+    }
+    if group_by.is_some() && !alerts_table {
+        warn(
+            WarningKind::IgnoredFlag,
+            "--group-by only applies to the alerts table in pretty and markdown formats",
+        );
+    }
+    if owasp && !alerts_table {
+        warn(
+            WarningKind::IgnoredFlag,
+            "--owasp only applies to the alerts table in pretty and markdown formats \
+             (JSON from --detail full always includes it)",
+        );
+    }
+    if markdown && curl_only {
+        warn(
+            WarningKind::IgnoredFlag,
+            "--curl-only is ignored with --format markdown (the finding includes the curl command)",
+        );
+    }
+
+    if markdown {
+        let paths_page = limit.map(|limit| {
+            PaginationParams::new()
+                .page_size(limit)
+                .page(page.unwrap_or(0))
+        });
+        let document = match (plugin_id, uri_id) {
+            (None, None) => {
+                markdown_overview(&ctx, org_id, &resolved_id, filters, group_by, owasp).await?
+            }
+            (Some(p), None) => {
+                markdown_alert_detail(
+                    &ctx,
+                    org_id,
+                    &resolved_id,
+                    p,
+                    paths_page.as_ref(),
+                    filters,
+                    paths,
+                )
+                .await?
+            }
+            (_, Some(u)) => {
+                markdown_finding(
+                    &ctx,
+                    org_id,
+                    &resolved_id,
+                    u,
+                    message || curl_only,
+                    raw,
+                    redact,
+                )
+                .await?
+            }
+        };
+        print!("{}", document);
+        return Ok(());
+    }
+
     // Determine detail level based on flags
-    match (plugin_id, uri_id, message) {
-        (None, None, false) => show_pretty_overview(&ctx, org_id, &resolved_id).await,
-        (Some(p), None, false) => show_alert_detail(&ctx, org_id, &resolved_id, p).await,
+    let message_view = if har {
+        MessageView::Har
+    } else if curl_only {
+        MessageView::CurlOnly
+    } else {
+        MessageView::Detail { raw }
+    };
+    match (plugin_id, uri_id, message || curl_only || har) {
+        (None, None, false) => {
+            show_pretty_overview(&ctx, org_id, &resolved_id, filters, group_by, owasp).await
+        }
+        (Some(p), None, false) => {
+            // Without --limit, every path is fetched
+            let paths_page = limit.map(|limit| {
+                PaginationParams::new()
+                    .page_size(limit)
+                    .page(page.unwrap_or(0))
+            });
+            show_alert_detail(
+                &ctx,
+                org_id,
+                &resolved_id,
+                p,
+                paths_page.as_ref(),
+                filters,
+                paths,
+                plain,
+            )
+            .await
+        }
         (_, Some(u), false) => show_uri_detail_by_id(&ctx, org_id, &resolved_id, u).await,
-        (_, Some(u), true) => show_message_by_uri(&ctx, org_id, &resolved_id, u).await,
+        (_, Some(u), true) => {
+            show_message_by_uri(&ctx, org_id, &resolved_id, u, message_view, redact).await
+        }
         _ => Err(crate::error::ApiError::BadRequest(
             "Invalid flag combination. Use --uri-id to show finding detail, add -m for HTTP message."
                 .to_string(),
@@ -477,9 +1116,9 @@ pub async fn get(
 /// Resolve "latest" scan ID with optional app/env filters
 ///
 /// Supports two ways to filter by application:
-/// - `app`: Filter by application name (looks up the app ID via API)
+/// - `app`: Filter by application name or ID (names are resolved via API)
 /// - `app_id`: Filter by application ID directly
-async fn resolve_latest_scan(
+pub async fn resolve_latest_scan(
     ctx: &CommandContext,
     org_id: &str,
     app: Option<&str>,
@@ -491,47 +1130,10 @@ async fn resolve_latest_scan(
         app, app_id, env
     );
 
-    // Resolve app name to app ID if provided
-    let resolved_app_id = if let Some(app_name) = app {
-        // Look up application by name (handle duplicates)
-        let apps = ctx.client.list_apps(org_id, None).await?;
-        let matching_apps: Vec<_> = apps
-            .iter()
-            .filter(|a| a.name.eq_ignore_ascii_case(app_name))
-            .collect();
-
-        match matching_apps.len() {
-            0 => {
-                return Err(crate::error::ApiError::NotFound(format!(
-                    "Application '{}' not found. Use 'hawkop app list' to see available applications.",
-                    app_name
-                ))
-                .into());
-            }
-            1 => {
-                debug!(
-                    "Resolved app '{}' to ID '{}'",
-                    app_name, matching_apps[0].id
-                );
-                Some(matching_apps[0].id.clone())
-            }
-            _ => {
-                // Multiple apps with same name - require --app-id for disambiguation
-                let mut msg = format!("Multiple applications match '{}':\n", app_name);
-                for app in &matching_apps {
-                    let env_info = app.env.as_deref().unwrap_or("--");
-                    let short_id = &app.id[..8.min(app.id.len())];
-                    msg.push_str(&format!(
-                        "  • {} ({}) - env: {}\n",
-                        app.name, short_id, env_info
-                    ));
-                }
-                msg.push_str("\nUse --app-id <uuid> to specify exactly which one.");
-                return Err(crate::error::ApiError::BadRequest(msg).into());
-            }
-        }
-    } else {
-        app_id.map(|s| s.to_string())
+    // Resolve app name (or ID) to app ID if provided
+    let resolved_app_id = match app {
+        Some(app) => ctx.resolve_app_ids(&[app.to_string()]).await?.pop(),
+        None => app_id.map(|s| s.to_string()),
     };
 
     // Build filter params if any filters specified
@@ -593,14 +1195,23 @@ async fn resolve_latest_scan(
 ///
 /// → Drill down: hawkop scan get <scan-id> --plugin-id <plugin-id>
 /// ```
-async fn show_pretty_overview(ctx: &CommandContext, org_id: &str, scan_id: &str) -> Result<()> {
+async fn show_pretty_overview(
+    ctx: &CommandContext,
+    org_id: &str,
+    scan_id: &str,
+    filters: &AlertFilterArgs,
+    group_by: Option<AlertGroupBy>,
+    owasp: bool,
+) -> Result<()> {
     debug!("Fetching pretty overview for {}", scan_id);
     let scan = ctx.client.get_scan(org_id, scan_id).await?;
 
     match ctx.format {
         OutputFormat::Pretty => {
-            // Fetch alerts for the table
-            let alerts = ctx.client.list_scan_alerts(scan_id, None).await?;
+            // Fetch alerts for the table (filtered client-side; the API has no filters)
+            let all_alerts = ctx.client.list_scan_alerts(scan_id, None).await?;
+            let total_alerts = all_alerts.len();
+            let alerts = filters.filter_alerts(all_alerts);
 
             // Extract userId from metadata.tags (preferred) or fallback to scan.external_user_id
             let user_id = scan
@@ -689,7 +1300,23 @@ async fn show_pretty_overview(ctx: &CommandContext, org_id: &str, scan_id: &str)
             println!("New: {} | Triaged: {}", new_summary, triaged_summary);
 
             // Alerts table with detailed triage columns
-            if !alerts.is_empty() {
+            if let Some(by) = group_by
+                && !alerts.is_empty()
+            {
+                let categories = if by == AlertGroupBy::Category {
+                    fetch_alert_categories(ctx, scan_id, &alerts).await
+                } else {
+                    HashMap::new()
+                };
+                let shown = alerts.len();
+                print_alert_groups(group_alerts(alerts, by, &categories), owasp)?;
+                if !filters.is_empty() {
+                    println!(
+                        "Showing {} of {} finding types (filtered)",
+                        shown, total_alerts
+                    );
+                }
+            } else if !alerts.is_empty() {
                 println!();
                 // Sort by severity (High → Medium → Low) then by plugin_id for stable ordering
                 let mut sorted_alerts = alerts;
@@ -704,49 +1331,32 @@ async fn show_pretty_overview(ctx: &CommandContext, org_id: &str, scan_id: &str)
                         .cmp(&severity_order(&b.severity))
                         .then_with(|| a.plugin_id.cmp(&b.plugin_id))
                 });
-                let display_alerts: Vec<PrettyAlertDisplay> = sorted_alerts
-                    .into_iter()
-                    .map(PrettyAlertDisplay::from)
-                    .collect();
-                display_alerts.print(OutputFormat::Table)?;
-            } else {
+                let shown = sorted_alerts.len();
+                print_alerts_table(sorted_alerts, owasp)?;
+                if !filters.is_empty() {
+                    println!(
+                        "Showing {} of {} finding types (filtered)",
+                        shown, total_alerts
+                    );
+                }
+            } else if !filters.is_empty() && total_alerts > 0 {
+                println!("\nNo findings match the filters ({} total).", total_alerts);
+            } else {
                 println!("\nNo findings.");
             }
 
             // Tags section - deduplicated and filtered
-            if !scan.tags.is_empty() {
-                // Deduplicate by tag name (keep first occurrence) and filter out:
-                // - Unexpanded env vars like ${RELEASE_TAG}
-                // - Empty values
-                let mut seen = std::collections::HashSet::new();
-                let filtered_tags: Vec<_> = scan
-                    .tags
-                    .iter()
-                    .filter(|tag| {
-                        // Skip duplicates
-                        if !seen.insert(&tag.name) {
-                            return false;
-                        }
-                        // Skip unexpanded env vars (contain ${...})
-                        if tag.value.contains("${") && tag.value.contains('}') {
-                            return false;
-                        }
-                        // Skip empty values
-                        !tag.value.is_empty()
-                    })
-                    .collect();
-
-                if !filtered_tags.is_empty() {
-                    println!("\nTags:");
-                    for tag in filtered_tags {
-                        println!("  {}: {}", tag.name, tag.value);
-                    }
+            let tags = visible_tags(&scan.tags);
+            if !tags.is_empty() {
+                println!("\nTags:");
+                for tag in tags {
+                    println!("  {}: {}", tag.name, tag.value);
                 }
             }
 
             // Navigation hint (use full scan ID)
-            eprintln!();
-            eprintln!(
+            hint!();
+            hint!(
                 "→ Drill down: hawkop scan get {} --plugin-id <plugin-id>",
                 scan_id
             );
@@ -757,7 +1367,7 @@ async fn show_pretty_overview(ctx: &CommandContext, org_id: &str, scan_id: &str)
             display_scans.print(ctx.format)?;
         }
         OutputFormat::Json => {
-            let json = serde_json::to_string_pretty(&scan)?;
+            let json = crate::output::json::format_raw_json(&scan)?;
             println!("{}", json);
         }
     }
@@ -765,6 +1375,95 @@ async fn show_pretty_overview(ctx: &CommandContext, org_id: &str, scan_id: &str)
     Ok(())
 }
 
+/// Category of each alert's plugin, fetched in parallel
+///
+/// Categories are only on the per-plugin alert endpoint, so this costs one
+/// request per finding type (one path each). Failed lookups leave the plugin
+/// uncategorized.
+async fn fetch_alert_categories(
+    ctx: &CommandContext,
+    scan_id: &str,
+    alerts: &[ApplicationAlert],
+) -> HashMap<String, String> {
+    let one_path = PaginationParams::new().page_size(1);
+    let results: Vec<_> = stream::iter(alerts.iter().map(|a| a.plugin_id.clone()))
+        .map(|plugin_id| {
+            let client = ctx.client.clone();
+            let one_path = &one_path;
+            async move {
+                let result = client
+                    .get_alert_with_paths(scan_id, &plugin_id, Some(one_path))
+                    .await;
+                (plugin_id, result)
+            }
+        })
+        .buffer_unordered(max_concurrency())
+        .collect()
+        .await;
+
+    let mut categories = HashMap::new();
+    let mut failed = 0;
+    for (plugin_id, result) in results {
+        match result {
+            Ok(response) => {
+                if let Some(category) = response.category {
+                    categories.insert(plugin_id, category);
+                }
+            }
+            Err(e) => {
+                debug!("Category lookup failed for plugin {}: {}", plugin_id, e);
+                failed += 1;
+            }
+        }
+    }
+    if failed > 0 {
+        warn(
+            WarningKind::PartialResults,
+            format!(
+                "Could not fetch the category for {} of {} finding types; \
+                 they are listed as Uncategorized",
+                failed,
+                alerts.len()
+            ),
+        );
+    }
+    categories
+}
+
+/// Print grouped alerts as titled sections, each with its own table
+fn print_alert_groups(groups: Vec<AlertGroup>, owasp: bool) -> Result<()> {
+    for group in groups {
+        let paths = group.paths();
+        println!(
+            "\n{} ({} finding {}, {} {})",
+            group.label.bold(),
+            group.alerts.len(),
+            if group.alerts.len() == 1 {
+                "type"
+            } else {
+                "types"
+            },
+            paths,
+            if paths == 1 { "path" } else { "paths" }
+        );
+        print_alerts_table(group.alerts, owasp)?;
+    }
+    Ok(())
+}
+
+/// Print the alerts table, with the OWASP column when `owasp` is set
+fn print_alerts_table(alerts: Vec<ApplicationAlert>, owasp: bool) -> Result<()> {
+    if owasp {
+        let rows: Vec<OwaspAlertDisplay> =
+            alerts.into_iter().map(OwaspAlertDisplay::from).collect();
+        rows.print(OutputFormat::Table)
+    } else {
+        let rows: Vec<PrettyAlertDisplay> =
+            alerts.into_iter().map(PrettyAlertDisplay::from).collect();
+        rows.print(OutputFormat::Table)
+    }
+}
+
 /// Look up user display name from Members API
 ///
 /// Returns the best available identifier in order of preference:
@@ -884,8 +1583,9 @@ async fn show_full_detail(
     scan_id: &str,
     max_findings: usize,
     max_body_size: usize,
+    filters: &AlertFilterArgs,
+    redact: bool,
 ) -> Result<()> {
-    use std::collections::HashMap;
     use std::time::Instant;
 
     use crate::models::display::scan_full::{
@@ -898,7 +1598,7 @@ async fn show_full_detail(
     let mut bodies_truncated = false;
 
     // Step 1: Fetch scan metadata + alerts in parallel
-    eprintln!("Fetching scan details...");
+    hint!("Fetching scan details...");
 
     let (scan_result, alerts) = tokio::try_join!(
         ctx.client.get_scan(org_id, scan_id),
@@ -911,7 +1611,7 @@ async fn show_full_detail(
     // Step 2: Fetch per-plugin paths AND org findings enrichment in parallel
     //
     // Sort alerts by severity (High → Medium → Low) and limit to max_findings plugins
-    let mut sorted_alerts = alerts;
+    let mut sorted_alerts = filters.filter_alerts(alerts);
     sorted_alerts.sort_by(|a, b| {
         let severity_order = |s: &str| match s.to_uppercase().as_str() {
             "HIGH" => 0,
@@ -935,7 +1635,7 @@ async fn show_full_detail(
     // Fetch paths per plugin in parallel + org findings enrichment concurrently
     let plugin_ids: Vec<String> = limited_alerts.iter().map(|a| a.plugin_id.clone()).collect();
 
-    eprintln!(
+    hint!(
         "Fetching {} plugin details and remediation data...",
         plugin_ids.len()
     );
@@ -981,9 +1681,12 @@ async fn show_full_detail(
                 .filter_map(|f| f.finding_hash.as_ref().map(|hash| (hash.clone(), f)))
                 .collect()
         } else {
-            debug!(
-                "Failed to fetch org findings for enrichment: {:?}",
-                findings_response.err()
+            if let Err(ref e) = findings_response {
+                debug!("Failed to fetch org findings for enrichment: {}", e);
+            }
+            warn(
+                WarningKind::PartialResults,
+                "Remediation advice unavailable: failed to fetch organization findings",
             );
             HashMap::new()
         };
@@ -991,10 +1694,13 @@ async fn show_full_detail(
     // Step 3: Collect all URI IDs that need HTTP messages fetched
     let mut uri_msg_requests: Vec<(String, String, String)> = Vec::new(); // (plugin_id, uri_id, msg_id)
     let mut alert_paths: HashMap<String, crate::client::models::AlertResponse> = HashMap::new();
+    let mut failed_plugins: usize = 0;
 
     for (pid, result) in &path_results {
         match result {
             Ok(alert_resp) => {
+                let mut alert_resp = alert_resp.clone();
+                filters.filter_uris(&mut alert_resp.application_scan_alert_uris);
                 for uri in &alert_resp.application_scan_alert_uris {
                     uri_msg_requests.push((
                         pid.clone(),
@@ -1002,18 +1708,30 @@ async fn show_full_detail(
                         uri.msg_id.clone(),
                     ));
                 }
-                alert_paths.insert(pid.clone(), alert_resp.clone());
+                alert_paths.insert(pid.clone(), alert_resp);
             }
             Err(e) => {
                 debug!("Failed to fetch paths for plugin {}: {}", pid, e);
+                failed_plugins += 1;
             }
         }
     }
 
+    if failed_plugins > 0 {
+        warn(
+            WarningKind::PartialResults,
+            format!(
+                "Failed to fetch paths for {} of {} plugins; their findings have no paths",
+                failed_plugins,
+                path_results.len()
+            ),
+        );
+    }
+
     // Step 4: Fetch HTTP messages in parallel (bounded concurrency)
     let total_messages = uri_msg_requests.len();
     if total_messages > 0 {
-        eprintln!("Fetching {} HTTP messages...", total_messages);
+        hint!("Fetching {} HTTP messages...", total_messages);
     }
 
     let msg_futures: FuturesUnordered<_> = uri_msg_requests
@@ -1037,6 +1755,7 @@ async fn show_full_detail(
     let msg_map: HashMap<String, crate::client::models::AlertMsgResponse> = msg_results
         .into_iter()
         .filter_map(|(uri_id, result)| match result {
+            Ok(msg) if redact => Some((uri_id, redact_message(&msg))),
             Ok(msg) => Some((uri_id, msg)),
             Err(e) => {
                 debug!("Failed to fetch message for uri {}: {}", uri_id, e);
@@ -1045,8 +1764,19 @@ async fn show_full_detail(
         })
         .collect();
 
+    let failed_messages = total_messages - msg_map.len();
+    if failed_messages > 0 {
+        warn(
+            WarningKind::PartialResults,
+            format!(
+                "Failed to fetch {} of {} HTTP messages; affected paths have no request/response",
+                failed_messages, total_messages
+            ),
+        );
+    }
+
     // Step 5: Assemble the composite output document
-    eprintln!("Assembling output...");
+    hint!("Assembling output...");
 
     // Build ScanInfo from scan_result
     let scan_timestamp = &scan_result.scan.timestamp;
@@ -1258,6 +1988,7 @@ async fn show_full_detail(
             plugin_name: alert.name.clone(),
             severity: alert.severity.clone(),
             cwe_id: alert.cwe_id.clone(),
+            owasp: crate::owasp::for_cwe(alert.cwe_id.as_deref()),
             description: alert.description.clone(),
             category: alert_resp.and_then(|r| r.category.clone()),
             references: alert.references.clone(),
@@ -1276,6 +2007,25 @@ async fn show_full_detail(
         urls_scanned: scan_result.url_count,
     };
 
+    if let Some(omitted) = findings_omitted {
+        warn(
+            WarningKind::Truncated,
+            format!(
+                "{} additional findings omitted (use --max-findings to increase)",
+                omitted
+            ),
+        );
+    }
+    if bodies_truncated {
+        warn(
+            WarningKind::Truncated,
+            format!(
+                "Some HTTP bodies were truncated to {} bytes (use --max-body-size to increase)",
+                max_body_size
+            ),
+        );
+    }
+
     let meta = OutputMeta {
         generated_at: chrono::Utc::now().to_rfc3339(),
         hawkop_version: env!("CARGO_PKG_VERSION").to_string(),
@@ -1283,9 +2033,10 @@ async fn show_full_detail(
         fetch_duration_ms: start.elapsed().as_millis() as u64,
         findings_omitted,
         bodies_truncated,
+        warnings: Vec::new(),
     };
 
-    let full_detail = ScanFullDetail {
+    let mut full_detail = ScanFullDetail {
         schema_version: "1.0".to_string(),
         scan: scan_info,
         summary,
@@ -1293,61 +2044,131 @@ async fn show_full_detail(
         meta,
     };
 
+    // Size check before output so the warning lands in meta.warnings too
+    let output_size = serde_json::to_string(&full_detail)
+        .map(|s| s.len())
+        .unwrap_or(0);
+    if output_size > 1_048_576 {
+        warn(
+            WarningKind::LargeOutput,
+            format!(
+                "output is {:.1}MB. Consider --max-findings or --max-body-size to reduce.",
+                output_size as f64 / 1_048_576.0
+            ),
+        );
+    }
+    full_detail.meta.warnings = warnings::collected();
+
     // Output
     match ctx.format {
         OutputFormat::Json => {
-            let json = serde_json::to_string_pretty(&full_detail)?;
+            let json = crate::output::json::format_raw_json(&full_detail)?;
             println!("{}", json);
         }
         OutputFormat::Pretty | OutputFormat::Table => {
             // For pretty/table, output JSON anyway (this is a machine-readable format)
             // but add a human-friendly header to stderr
-            eprintln!(
+            hint!(
                 "Scan: {} | App: {} | Env: {}",
                 full_detail.scan.id,
                 full_detail.scan.application_name,
                 full_detail.scan.environment
             );
-            eprintln!(
+            hint!(
                 "Findings: {} total ({} high, {} medium, {} low)",
                 full_detail.summary.total_findings,
                 full_detail.summary.by_severity.high,
                 full_detail.summary.by_severity.medium,
                 full_detail.summary.by_severity.low,
             );
-            if let Some(omitted) = full_detail.meta.findings_omitted {
-                eprintln!(
-                    "Note: {} additional findings omitted (use --max-findings to increase)",
-                    omitted
-                );
-            }
-            let json = serde_json::to_string_pretty(&full_detail)?;
+            let json = crate::output::json::format_raw_json(&full_detail)?;
             println!("{}", json);
-            eprintln!();
-            eprintln!("→ Tip: use --format json to suppress this header");
+            hint!();
+            hint!("→ Tip: use --format json to suppress this header");
         }
     }
 
-    // Size warning to stderr
-    let output_size = serde_json::to_string(&full_detail)
-        .map(|s| s.len())
-        .unwrap_or(0);
-    if output_size > 1_048_576 {
-        eprintln!(
-            "Warning: output is {:.1}MB. Consider --max-findings or --max-body-size to reduce.",
-            output_size as f64 / 1_048_576.0
-        );
+    Ok(())
+}
+
+/// Fetch a plugin's paths for `--plugin-id`, filtered, sorted, and paged
+///
+/// Without `--status`, `--method`, or `--sort-by`, `paths_page` is passed to
+/// the API. Otherwise every path is fetched and the filters, sort order, and
+/// page are applied client-side, with `total_count` set to the number of
+/// matching paths.
+async fn fetch_alert_paths(
+    ctx: &CommandContext,
+    scan_id: &str,
+    plugin_id: &str,
+    paths_page: Option<&PaginationParams>,
+    filters: &AlertFilterArgs,
+    paths: &AlertPathArgs,
+) -> Result<AlertResponse> {
+    if filters.status.is_empty() && paths.is_empty() {
+        return ctx
+            .client
+            .get_alert_with_paths(scan_id, plugin_id, paths_page)
+            .await;
     }
 
-    Ok(())
+    let mut response = ctx
+        .client
+        .get_alert_with_paths(scan_id, plugin_id, None)
+        .await?;
+    let uris = &mut response.application_scan_alert_uris;
+    filters.filter_uris(uris);
+    paths.apply(uris);
+    response.total_count = Some(uris.len() as i64);
+    if let Some(params) = paths_page {
+        let limit = params.page_size.unwrap_or(uris.len());
+        let skip = params.page.unwrap_or(0) * limit;
+        *uris = std::mem::take(uris)
+            .into_iter()
+            .skip(skip)
+            .take(limit)
+            .collect();
+    }
+    Ok(response)
+}
+
+/// The `--status`, `--method`, and `--sort-by` flags in effect, for
+/// next-page hints
+fn path_flags(filters: &AlertFilterArgs, paths: &AlertPathArgs) -> String {
+    fn name(value: &impl clap::ValueEnum) -> String {
+        value
+            .to_possible_value()
+            .map(|v| v.get_name().to_string())
+            .unwrap_or_default()
+    }
+
+    let mut flags = String::new();
+    if !filters.status.is_empty() {
+        let statuses: Vec<String> = filters.status.iter().map(name).collect();
+        flags.push_str(&format!(" --status {}", statuses.join(",")));
+    }
+    if !paths.methods.is_empty() {
+        flags.push_str(&format!(" --method {}", paths.methods.join(",")));
+    }
+    if let Some(sort_by) = &paths.sort_by {
+        flags.push_str(&format!(" --sort-by {}", name(sort_by)));
+    }
+    flags
 }
 
 /// Show alert detail with paths (scan get <id> --plugin-id <plugin>)
+///
+/// The description is rendered from markdown unless `plain`.
+#[allow(clippy::too_many_arguments)]
 async fn show_alert_detail(
     ctx: &CommandContext,
     org_id: &str,
     scan_id: &str,
     plugin_id: &str,
+    paths_page: Option<&PaginationParams>,
+    filters: &AlertFilterArgs,
+    paths: &AlertPathArgs,
+    plain: bool,
 ) -> Result<()> {
     debug!("Fetching alert {} for scan {}", plugin_id, scan_id);
 
@@ -1355,10 +2176,7 @@ async fn show_alert_detail(
     let scan = ctx.client.get_scan(org_id, scan_id).await?;
     let scan_context = ScanContext::from_scan_result(&scan);
 
-    let response = ctx
-        .client
-        .get_alert_with_paths(scan_id, plugin_id, None)
-        .await?;
+    let response = fetch_alert_paths(ctx, scan_id, plugin_id, paths_page, filters, paths).await?;
 
     match ctx.format {
         OutputFormat::Pretty | OutputFormat::Table => {
@@ -1368,8 +2186,13 @@ async fn show_alert_detail(
             // Print header
             let detail = AlertDetail::new(response.clone());
             println!("{}", detail.format_header());
+            let guidance = detail.format_guidance(plain, render::wrap_width());
+            if !guidance.is_empty() {
+                println!("{}\n", guidance);
+            }
 
             // Print paths table
+            let total_paths = response.total_count;
             let display_paths: Vec<AlertFindingDisplay> = response
                 .application_scan_alert_uris
                 .into_iter()
@@ -1378,17 +2201,42 @@ async fn show_alert_detail(
 
             display_paths.print(OutputFormat::Table)?;
 
+            // Position within the paths when paging with --limit/--page
+            if let (Some(params), Some(total)) = (paths_page, total_paths) {
+                let total = usize::try_from(total).unwrap_or(0);
+                let limit = params.page_size.unwrap_or(1).max(1);
+                let page = params.page.unwrap_or(0);
+                let start = (page * limit).min(total);
+                hint!();
+                hint!(
+                    "Paths {}-{} of {}",
+                    (start + 1).min(total),
+                    start + display_paths.len(),
+                    total
+                );
+                if (page + 1) * limit < total {
+                    hint!(
+                        "→ Next page: hawkop scan get {} --plugin-id {}{} --limit {} --page {}",
+                        scan_id,
+                        plugin_id,
+                        path_flags(filters, paths),
+                        limit,
+                        page + 1
+                    );
+                }
+            }
+
             // Navigation hint (use full scan ID for consistency)
             if !display_paths.is_empty() {
-                eprintln!();
-                eprintln!(
+                hint!();
+                hint!(
                     "→ Drill down: hawkop scan get {} --uri-id <uri-id>",
                     scan_id
                 );
             }
         }
         OutputFormat::Json => {
-            let json = serde_json::to_string_pretty(&response)?;
+            let json = crate::output::json::format_raw_json(&response)?;
             println!("{}", json);
         }
     }
@@ -1470,10 +2318,11 @@ async fn show_uri_detail_by_id(
                         }
                     }
 
-                    eprintln!();
-                    eprintln!(
+                    hint!();
+                    hint!(
                         "→ View HTTP message: hawkop scan get {} --uri-id {} -m",
-                        scan_id, uri_id
+                        scan_id,
+                        uri_id
                     );
                 }
                 OutputFormat::Json => {
@@ -1487,7 +2336,7 @@ async fn show_uri_detail_by_id(
                         "evidence": message.evidence,
                         "other_info": message.other_info,
                     });
-                    let json = serde_json::to_string_pretty(&combined)?;
+                    let json = crate::output::json::format_raw_json(&combined)?;
                     println!("{}", json);
                 }
             }
@@ -1503,19 +2352,28 @@ async fn show_uri_detail_by_id(
     .into())
 }
 
+/// How `scan get --uri-id <u> -m` renders the HTTP message
+#[derive(Debug, Clone, Copy)]
+enum MessageView {
+    /// Finding details plus request/response (bodies decoded unless `raw`)
+    Detail { raw: bool },
+    /// Only the curl validation command (`--curl-only`)
+    CurlOnly,
+    /// A HAR 1.2 log (`--format har`)
+    Har,
+}
+
 /// Show HTTP message by URI ID (scan get <id> --uri-id <uri-id> -m)
 async fn show_message_by_uri(
     ctx: &CommandContext,
     org_id: &str,
     scan_id: &str,
     uri_id: &str,
+    view: MessageView,
+    redact: bool,
 ) -> Result<()> {
     debug!("Fetching message for scan {} uri {}", scan_id, uri_id);
 
-    // Fetch scan for banner context
-    let scan = ctx.client.get_scan(org_id, scan_id).await?;
-    let scan_context = ScanContext::from_scan_result(&scan);
-
     // Get all alerts to find the one containing this URI
     let alerts = ctx.client.list_scan_alerts(scan_id, None).await?;
 
@@ -1536,18 +2394,57 @@ async fn show_message_by_uri(
                 .client
                 .get_alert_message(scan_id, uri_id, &path.msg_id, true)
                 .await?;
+            let message = if redact {
+                redact_message(&message)
+            } else {
+                message
+            };
+
+            let raw = match view {
+                MessageView::Detail { raw } => raw,
+                MessageView::CurlOnly => {
+                    let curl = message
+                        .validation_command
+                        .filter(|c| !c.is_empty())
+                        .ok_or_else(|| {
+                            crate::error::ApiError::NotFound(format!(
+                                "No validation command for URI '{}'",
+                                uri_id
+                            ))
+                        })?;
+                    println!("{}", curl);
+                    return Ok(());
+                }
+                MessageView::Har => {
+                    let scan = ctx.client.get_scan(org_id, scan_id).await?;
+                    let har = crate::output::har::from_messages(&[HarMessage {
+                        message: &message,
+                        timestamp: &scan.scan.timestamp,
+                        host: scan.app_host.as_deref(),
+                        comment: format!(
+                            "{} ({}) - {} - scan {}",
+                            response.alert.name, response.alert.severity, uri_id, scan_id
+                        ),
+                    }]);
+                    println!("{}", crate::output::json::format_raw_json(&har)?);
+                    return Ok(());
+                }
+            };
 
             match ctx.format {
                 OutputFormat::Pretty | OutputFormat::Table => {
                     // Display banner
+                    let scan = ctx.client.get_scan(org_id, scan_id).await?;
+                    let scan_context = ScanContext::from_scan_result(&scan);
                     println!("{}\n", scan_context.format_banner());
 
                     let detail = AlertMessageDetail::new(message)
-                        .with_context(&response.alert.name, &response.alert.severity);
+                        .with_context(&response.alert.name, &response.alert.severity)
+                        .with_raw(raw);
                     println!("{}", detail.format_text());
                 }
                 OutputFormat::Json => {
-                    let json = serde_json::to_string_pretty(&message)?;
+                    let json = crate::output::json::format_raw_json(&message)?;
                     println!("{}", json);
                 }
             }
@@ -1563,121 +2460,1320 @@ async fn show_message_by_uri(
     .into())
 }
 
-/// Format severity for display
-fn format_severity(severity: &str) -> String {
-    match severity.to_lowercase().as_str() {
-        "high" => "High".to_string(),
-        "medium" => "Medium".to_string(),
-        "low" => "Low".to_string(),
-        "informational" | "info" => "Info".to_string(),
-        other => other.to_string(),
-    }
-}
+// ============================================================================
+// Markdown (--format markdown)
+// ============================================================================
 
-/// Format triage status for display
-fn format_triage_status(status: &str) -> String {
-    match status {
-        "UNKNOWN" => "New".to_string(),
-        "PROMOTED" => "Triaged".to_string(),
-        "ACCEPTED" | "RISK_ACCEPTED" => "Accepted".to_string(),
-        "FALSE_POSITIVE" => "False Positive".to_string(),
-        other => other.to_string(),
-    }
-}
+/// Longest request body shown in markdown (characters)
+const MARKDOWN_REQUEST_BODY_CHARS: usize = 2000;
 
-/// Format scan status for display (normalize case, human-friendly)
-fn format_scan_status(status: &str) -> String {
-    match status.to_uppercase().as_str() {
-        "STARTED" => "Running".to_string(),
-        "COMPLETED" => "Complete".to_string(),
-        "ERROR" => "Failed".to_string(),
-        "UNKNOWN" => "Unknown".to_string(),
-        other => other.to_string(),
-    }
-}
+/// Longest response body shown in markdown (characters)
+const MARKDOWN_RESPONSE_BODY_CHARS: usize = 5000;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::client::models::{AlertStats, AlertStatusStats, Scan};
-    use std::collections::HashMap;
+/// Scan overview as markdown: scan fields, then the alerts table (filtered,
+/// optionally grouped into sections), then tags
+async fn markdown_overview(
+    ctx: &CommandContext,
+    org_id: &str,
+    scan_id: &str,
+    filters: &AlertFilterArgs,
+    group_by: Option<AlertGroupBy>,
+    owasp: bool,
+) -> Result<String> {
+    let (scan, all_alerts) = tokio::try_join!(
+        ctx.client.get_scan(org_id, scan_id),
+        ctx.client.list_scan_alerts(scan_id, None),
+    )?;
+    let total_alerts = all_alerts.len();
+    let mut alerts = filters.filter_alerts(all_alerts);
+    alerts.sort_by(|a, b| {
+        severity_rank(&a.severity)
+            .cmp(&severity_rank(&b.severity))
+            .then_with(|| a.plugin_id.cmp(&b.plugin_id))
+    });
 
-    // ========================================================================
-    // Test Fixtures
-    // ========================================================================
+    let mut md = Markdown::new();
+    md.heading(
+        2,
+        &format!(
+            "StackHawk scan: {} ({})",
+            or_dash(&scan.scan.application_name),
+            or_dash(&scan.scan.env)
+        ),
+    );
 
-    /// Create a minimal ScanResult for testing
-    fn make_scan(id: &str, app: &str, env: &str, status: &str) -> ScanResult {
-        ScanResult {
-            scan: Scan {
-                id: id.to_string(),
-                application_id: format!("app-{}", id),
-                application_name: app.to_string(),
-                env: env.to_string(),
-                status: status.to_string(),
-                timestamp: "1703721600000".to_string(), // 2023-12-28
-                version: "5.0.0".to_string(),
-                external_user_id: None,
-            },
-            scan_duration: Some("120".to_string()),
-            url_count: Some(50),
-            alert_stats: None,
-            severity_stats: None,
-            app_host: Some("https://example.com".to_string()),
-            policy_name: None,
-            tags: vec![],
-            metadata: None,
-        }
+    let policy = scan
+        .metadata
+        .as_ref()
+        .and_then(|m| {
+            m.tags
+                .get("policyDisplayName")
+                .or_else(|| m.tags.get("policyName"))
+                .cloned()
+        })
+        .or_else(|| scan.policy_name.clone())
+        .filter(|p| !p.is_empty());
+    let (new_summary, triaged_summary) = format_findings_summary(&scan);
+    let mut fields = vec![("Scan", inline_code(&scan.scan.id))];
+    if let Some(host) = scan.app_host.as_deref().filter(|h| !h.is_empty()) {
+        fields.push(("Host", escape(host)));
     }
-
-    /// Create a ScanResult with alert stats for testing findings
-    fn make_scan_with_findings(
-        id: &str,
-        high_new: u32,
-        medium_new: u32,
-        low_new: u32,
-    ) -> ScanResult {
-        let mut severity_stats = HashMap::new();
-        if high_new > 0 {
-            severity_stats.insert("High".to_string(), high_new);
+    fields.extend([
+        ("Completed", format_timestamp_local(&scan.scan.timestamp)),
+        (
+            "Duration",
+            scan.scan_duration
+                .as_deref()
+                .map_or_else(|| "--".to_string(), format_duration_seconds),
+        ),
+        ("Status", format_scan_status(&scan.scan.status)),
+        ("HawkScan", escape(or_dash(&scan.scan.version))),
+    ]);
+    if let Some(policy) = policy {
+        fields.push(("Policy", escape(&policy)));
+    }
+    fields.extend([("New", new_summary), ("Triaged", triaged_summary)]);
+    md.fields(&fields);
+
+    if alerts.is_empty() {
+        md.heading(3, "Findings");
+        if !filters.is_empty() && total_alerts > 0 {
+            md.paragraph(&format!(
+                "No findings match the filters ({} total).",
+                total_alerts
+            ));
+        } else {
+            md.paragraph("No findings.");
         }
-        if medium_new > 0 {
-            severity_stats.insert("Medium".to_string(), medium_new);
+    } else if let Some(by) = group_by {
+        let categories = if by == AlertGroupBy::Category {
+            fetch_alert_categories(ctx, scan_id, &alerts).await
+        } else {
+            HashMap::new()
+        };
+        for group in group_alerts(alerts, by, &categories) {
+            md.heading(
+                3,
+                &format!(
+                    "{} ({} finding {}, {} {})",
+                    group.label,
+                    group.alerts.len(),
+                    if group.alerts.len() == 1 {
+                        "type"
+                    } else {
+                        "types"
+                    },
+                    group.paths(),
+                    if group.paths() == 1 { "path" } else { "paths" }
+                ),
+            );
+            markdown_alerts_table(&mut md, &group.alerts, owasp);
         }
-        if low_new > 0 {
-            severity_stats.insert("Low".to_string(), low_new);
+    } else {
+        md.heading(3, "Findings");
+        markdown_alerts_table(&mut md, &alerts, owasp);
+        if !filters.is_empty() {
+            md.paragraph(&format!(
+                "_Showing {} of {} finding types (filtered)_",
+                alerts.len(),
+                total_alerts
+            ));
         }
+    }
 
-        let mut scan = make_scan(id, "TestApp", "prod", "COMPLETED");
-        scan.alert_stats = Some(AlertStats {
-            total_alerts: high_new + medium_new + low_new,
-            unique_alerts: high_new + medium_new + low_new,
-            alert_status_stats: vec![AlertStatusStats {
-                alert_status: "UNKNOWN".to_string(),
-                total_count: high_new + medium_new + low_new,
-                severity_stats,
-            }],
+    let tags = visible_tags(&scan.tags);
+    if !tags.is_empty() {
+        md.details(&format!("Tags ({})", tags.len()), |md| {
+            let rows: Vec<Vec<String>> = tags
+                .iter()
+                .map(|t| vec![t.name.clone(), t.value.clone()])
+                .collect();
+            md.table(&["NAME", "VALUE"], &rows);
         });
-        scan
     }
 
-    // ========================================================================
-    // format_scan_status tests
-    // ========================================================================
+    Ok(md.finish())
+}
 
-    #[test]
-    fn test_format_scan_status_started() {
-        assert_eq!(format_scan_status("STARTED"), "Running");
-        assert_eq!(format_scan_status("started"), "Running");
+/// Alerts table with full plugin names (and the OWASP column when `owasp`)
+fn markdown_alerts_table(md: &mut Markdown, alerts: &[ApplicationAlert], owasp: bool) {
+    if owasp {
+        let rows: Vec<OwaspAlertDisplay> = alerts
+            .iter()
+            .map(|alert| {
+                let mut row = OwaspAlertDisplay::from(alert.clone());
+                row.alert.name = alert.name.clone();
+                row
+            })
+            .collect();
+        md.tabled(&rows);
+    } else {
+        let rows: Vec<PrettyAlertDisplay> = alerts
+            .iter()
+            .map(|alert| {
+                let mut row = PrettyAlertDisplay::from(alert);
+                row.name = alert.name.clone();
+                row
+            })
+            .collect();
+        md.tabled(&rows);
     }
+}
 
-    #[test]
-    fn test_format_scan_status_completed() {
-        assert_eq!(format_scan_status("COMPLETED"), "Complete");
-        assert_eq!(format_scan_status("completed"), "Complete");
-    }
+/// Plugin detail as markdown: the finding type, its description, and the
+/// affected paths
+async fn markdown_alert_detail(
+    ctx: &CommandContext,
+    org_id: &str,
+    scan_id: &str,
+    plugin_id: &str,
+    paths_page: Option<&PaginationParams>,
+    filters: &AlertFilterArgs,
+    paths: &AlertPathArgs,
+) -> Result<String> {
+    let (scan, response) = tokio::try_join!(
+        ctx.client.get_scan(org_id, scan_id),
+        fetch_alert_paths(ctx, scan_id, plugin_id, paths_page, filters, paths),
+    )?;
+    let alert = &response.alert;
+
+    let mut md = Markdown::new();
+    md.heading(
+        2,
+        &format!(
+            "{} ({}) — {}",
+            alert.name,
+            alert.plugin_id,
+            format_severity(&alert.severity)
+        ),
+    );
+    let mut fields = markdown_scan_fields(&scan);
+    fields.extend(markdown_cwe_fields(alert.cwe_id.as_deref()));
+    fields.push(("Paths affected", alert.uri_count.to_string()));
+    md.fields(&fields);
 
-    #[test]
+    if !alert.description.trim().is_empty() {
+        md.paragraph(&escape(alert.description.trim()));
+    }
+    if !alert.references.is_empty() {
+        md.details(&format!("References ({})", alert.references.len()), |md| {
+            let list: Vec<String> = alert
+                .references
+                .iter()
+                .map(|r| format!("- {}", r))
+                .collect();
+            md.paragraph(&list.join("\n"));
+        });
+    }
+
+    md.heading(3, "Paths");
+    let rows: Vec<Vec<String>> = response
+        .application_scan_alert_uris
+        .iter()
+        .map(|uri| {
+            vec![
+                uri.request_method.clone(),
+                uri.uri.clone(),
+                format_triage_status(&uri.status),
+                uri.alert_uri_id.clone(),
+            ]
+        })
+        .collect();
+    md.table(&["METHOD", "PATH", "STATUS", "URI ID"], &rows);
+
+    if let (Some(params), Some(total)) = (paths_page, response.total_count) {
+        let total = usize::try_from(total).unwrap_or(0);
+        let start = (params.page.unwrap_or(0) * params.page_size.unwrap_or(1)).min(total);
+        md.paragraph(&format!(
+            "_Paths {}-{} of {}_",
+            (start + 1).min(total),
+            start + rows.len(),
+            total
+        ));
+    }
+
+    Ok(md.finish())
+}
+
+/// Finding detail as markdown: evidence in collapsible sections and, with
+/// `message`, the HTTP request and response in code fences plus the curl
+/// command that reproduces it
+async fn markdown_finding(
+    ctx: &CommandContext,
+    org_id: &str,
+    scan_id: &str,
+    uri_id: &str,
+    message: bool,
+    raw: bool,
+    redact: bool,
+) -> Result<String> {
+    let (scan, (response, path)) = tokio::try_join!(
+        ctx.client.get_scan(org_id, scan_id),
+        find_alert_uri(ctx, scan_id, uri_id),
+    )?;
+    let msg = ctx
+        .client
+        .get_alert_message(scan_id, uri_id, &path.msg_id, message)
+        .await?;
+    let msg = if redact { redact_message(&msg) } else { msg };
+    let alert = &response.alert;
+
+    let mut md = Markdown::new();
+    md.heading(
+        2,
+        &format!("{} — {}", alert.name, format_severity(&alert.severity)),
+    );
+    let mut fields = vec![
+        (
+            "Finding",
+            inline_code(&format!("{} {}", path.request_method, path.uri)),
+        ),
+        ("Status", format_triage_status(&path.status)),
+    ];
+    if let Some(param) = msg.param.as_deref().filter(|p| !p.is_empty()) {
+        fields.push(("Parameter", inline_code(param)));
+    }
+    fields.push(("Plugin", alert.plugin_id.clone()));
+    fields.extend(markdown_cwe_fields(alert.cwe_id.as_deref()));
+    fields.extend(markdown_scan_fields(&scan));
+    md.fields(&fields);
+
+    if let Some(evidence) = msg.evidence.as_deref().filter(|e| !e.is_empty()) {
+        md.details("Evidence", |md| {
+            md.code("text", evidence);
+        });
+    }
+    if let Some(other_info) = msg.other_info.as_deref().filter(|o| !o.is_empty()) {
+        md.details("Other info", |md| {
+            md.code("text", other_info);
+        });
+    }
+
+    if message {
+        let scan_message = &msg.scan_message;
+        md.heading(3, "Request");
+        md.code(
+            "http",
+            &http_message(
+                scan_message.request_header.as_deref(),
+                scan_message.request_body.as_deref(),
+                raw,
+                MARKDOWN_REQUEST_BODY_CHARS,
+            ),
+        );
+        md.details("Response", |md| {
+            md.code(
+                "http",
+                &http_message(
+                    scan_message.response_header.as_deref(),
+                    scan_message.response_body.as_deref(),
+                    raw,
+                    MARKDOWN_RESPONSE_BODY_CHARS,
+                ),
+            );
+        });
+        if let Some(curl) = msg.validation_command.as_deref().filter(|c| !c.is_empty()) {
+            md.heading(3, "Reproduce");
+            md.code("bash", curl.trim());
+        }
+    }
+
+    Ok(md.finish())
+}
+
+/// App, environment, and scan fields for the markdown drill-down views
+fn markdown_scan_fields(scan: &ScanResult) -> Vec<(&'static str, String)> {
+    vec![
+        (
+            "App",
+            format!(
+                "{} ({})",
+                escape(or_dash(&scan.scan.application_name)),
+                escape(or_dash(&scan.scan.env))
+            ),
+        ),
+        (
+            "Scan",
+            format!(
+                "{} ({})",
+                inline_code(&scan.scan.id),
+                format_timestamp_local(&scan.scan.timestamp)
+            ),
+        ),
+    ]
+}
+
+/// CWE (linked) and OWASP Top 10 fields, when the alert has a CWE
+fn markdown_cwe_fields(cwe: Option<&str>) -> Vec<(&'static str, String)> {
+    let mut fields = Vec::new();
+    if let Some(cwe) = cwe {
+        fields.push((
+            "CWE",
+            format!(
+                "[CWE-{0}](https://cwe.mitre.org/data/definitions/{0}.html)",
+                cwe
+            ),
+        ));
+    }
+    if let Some(category) = crate::owasp::for_cwe(cwe) {
+        fields.push(("OWASP Top 10", escape(&category.label())));
+    }
+    fields
+}
+
+/// Header block and body of one side of an HTTP message, the body decoded
+/// unless `raw` and cut to `max_body` characters
+fn http_message(header: Option<&str>, body: Option<&str>, raw: bool, max_body: usize) -> String {
+    let mut out = header.unwrap_or("").trim_end().to_string();
+    if let Some(body) = body.filter(|b| !b.is_empty()) {
+        let body = if raw {
+            body.to_string()
+        } else {
+            crate::output::body::decode_body(header, body)
+        };
+        out.push_str("\n\n");
+        let chars = body.chars().count();
+        if chars > max_body {
+            out.extend(body.chars().take(max_body));
+            out.push_str(&format!("\n... ({} more characters)", chars - max_body));
+        } else {
+            out.push_str(&body);
+        }
+    }
+    out.replace("\r\n", "\n")
+}
+
+/// The alert and path a URI ID belongs to, searching every alert in the scan
+async fn find_alert_uri(
+    ctx: &CommandContext,
+    scan_id: &str,
+    uri_id: &str,
+) -> Result<(AlertResponse, ApplicationAlertUri)> {
+    let alerts = ctx.client.list_scan_alerts(scan_id, None).await?;
+    for alert in &alerts {
+        let response = ctx
+            .client
+            .get_alert_with_paths(scan_id, &alert.plugin_id, None)
+            .await?;
+        if let Some(path) = response
+            .application_scan_alert_uris
+            .iter()
+            .find(|p| p.alert_uri_id == uri_id)
+            .cloned()
+        {
+            return Ok((response, path));
+        }
+    }
+
+    Err(crate::error::ApiError::NotFound(format!(
+        "URI '{}' not found in scan. Use 'hawkop scan get {} --plugin-id <id>' to see available URIs.",
+        uri_id, scan_id
+    ))
+    .into())
+}
+
+// ============================================================================
+// Scan Diff
+// ============================================================================
+
+/// Compare findings between two scans (scan diff <base> <head>)
+///
+/// Findings are matched by plugin ID + HTTP method + URI and reported as
+/// new (only in head), fixed (only in base), or persistent (in both).
+pub async fn diff(
+    opts: &GlobalOptions,
+    base_id: &str,
+    head_id: &str,
+    markdown: bool,
+) -> Result<()> {
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?;
+
+    debug!("Scan diff: base={}, head={}", base_id, head_id);
+
+    let (base_scan, head_scan) = tokio::try_join!(
+        ctx.client.get_scan(org_id, base_id),
+        ctx.client.get_scan(org_id, head_id),
+    )?;
+
+    let (base_findings, head_findings) = tokio::try_join!(
+        fetch_scan_findings(&ctx, base_id),
+        fetch_scan_findings(&ctx, head_id),
+    )?;
+
+    let result = ScanDiff::compute(
+        DiffScanInfo::from(&base_scan),
+        DiffScanInfo::from(&head_scan),
+        base_findings,
+        head_findings,
+    );
+
+    if markdown {
+        print!("{}", result.to_markdown());
+        return Ok(());
+    }
+
+    match ctx.format {
+        OutputFormat::Json => {
+            let json = crate::output::json::format_json(&result)?;
+            println!("{}", json);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            println!(
+                "Base: {} ({}) | Head: {} ({})",
+                result.base.id,
+                format_timestamp_local(&result.base.timestamp),
+                result.head.id,
+                format_timestamp_local(&result.head.timestamp),
+            );
+            println!(
+                "New: {} | Fixed: {} | Persistent: {}",
+                result.summary.new, result.summary.fixed, result.summary.persistent
+            );
+            println!();
+
+            let rows = result.rows();
+            if rows.is_empty() {
+                println!("No findings in either scan.");
+            } else {
+                rows.print(ctx.format)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// Scan Map OAS
+// ============================================================================
+
+/// Run the scan map-oas command
+///
+/// Reads the spec, fetches every path-level finding of the scan, and reports
+/// findings per API operation, operations without findings, and findings
+/// whose request matches no operation.
+pub async fn map_oas(opts: &GlobalOptions, scan_id: &str, oas_path: &str) -> Result<()> {
+    let spec = OpenApiSpec::load(oas_path)?;
+
+    let ctx = CommandContext::new(opts).await?;
+    let responses = fetch_alert_responses(&ctx, scan_id, "the map may be incomplete").await?;
+    let findings: Vec<DiffFinding> = responses
+        .iter()
+        .flat_map(DiffFinding::from_alert_response)
+        .collect();
+    debug!(
+        "Mapping {} findings onto {} operations",
+        findings.len(),
+        spec.operations.len()
+    );
+
+    let map = OasFindingsMap::build(scan_id, &spec_file_name(oas_path), &spec, &findings);
+
+    match ctx.format {
+        OutputFormat::Json => {
+            println!("{}", crate::output::json::format_json(&map)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            println!(
+                "Spec: {} | Scan: {} | {} of {} findings mapped | {} of {} operations with findings",
+                map.spec,
+                map.scan_id,
+                map.mapped,
+                findings.len(),
+                map.by_operation.len(),
+                map.operations
+            );
+            println!(
+                "\n{} ({})",
+                "Findings by operation".bold(),
+                map.by_operation.len()
+            );
+            map.by_operation.print(ctx.format)?;
+            println!(
+                "\n{} ({})",
+                "Operations without findings".bold(),
+                map.without_findings.len()
+            );
+            map.without_findings.print(ctx.format)?;
+            println!(
+                "\n{} ({})",
+                "Findings outside the spec".bold(),
+                map.unmapped.len()
+            );
+            map.unmapped.print(ctx.format)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// File name of a spec path, for specs without `info.title`
+pub fn spec_file_name(path: &str) -> String {
+    std::path::Path::new(path).file_name().map_or_else(
+        || path.to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    )
+}
+
+// ============================================================================
+// Scan Search
+// ============================================================================
+
+/// Run the scan search command
+///
+/// Alerts and their paths are fetched as for `scan diff`, then narrowed by
+/// `filters`. When evidence or other info is searched, each remaining
+/// finding's HTTP message is fetched too, [`max_concurrency`] at a time;
+/// findings whose message fails to load are searched by name and URI only.
+#[allow(clippy::too_many_arguments)]
+pub async fn search(
+    opts: &GlobalOptions,
+    scan_id: &str,
+    pattern: &str,
+    app: Option<&str>,
+    env: Option<&str>,
+    fields: &[SearchField],
+    case_sensitive: bool,
+    filters: &AlertFilterArgs,
+) -> Result<()> {
+    if pattern.is_empty() {
+        return Err(crate::error::Error::Other(
+            "The search pattern is empty".to_string(),
+        ));
+    }
+
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?;
+
+    let is_latest = scan_id.eq_ignore_ascii_case("latest");
+    if !is_latest && (app.is_some() || env.is_some()) {
+        return Err(crate::error::ApiError::BadRequest(
+            "Cannot specify both scan ID and filters (--app, --env). \
+             Use filters only with 'latest'."
+                .to_string(),
+        )
+        .into());
+    }
+    let resolved_id = if is_latest {
+        resolve_latest_scan(&ctx, org_id, app, None, env).await?
+    } else {
+        scan_id.to_string()
+    };
+    let scan_id = resolved_id.as_str();
+
+    // All fields by default, in a fixed order either way
+    let fields: Vec<SearchField> = <SearchField as clap::ValueEnum>::value_variants()
+        .iter()
+        .filter(|f| fields.is_empty() || fields.contains(f))
+        .copied()
+        .collect();
+    let search = SearchPattern::new(pattern, case_sensitive);
+
+    let mut responses =
+        fetch_alert_responses(&ctx, scan_id, "their findings were not searched").await?;
+    responses.retain(|r| filters.matches_severity(&r.alert.severity));
+    responses.sort_by(|a, b| {
+        severity_rank(&a.alert.severity)
+            .cmp(&severity_rank(&b.alert.severity))
+            .then_with(|| a.alert.plugin_id.cmp(&b.alert.plugin_id))
+    });
+    for response in &mut responses {
+        filters.filter_uris(&mut response.application_scan_alert_uris);
+    }
+    let findings: Vec<(&ApplicationAlert, &ApplicationAlertUri)> = responses
+        .iter()
+        .flat_map(|r| r.application_scan_alert_uris.iter().map(|u| (&r.alert, u)))
+        .collect();
+
+    let messages: HashMap<String, AlertMsgResponse> = if fields.iter().any(|f| f.in_message()) {
+        hint!("Searching {} findings...", findings.len());
+        let results: Vec<_> = stream::iter(findings.iter().map(|(_, path)| *path))
+            .map(|path| {
+                let client = ctx.client.clone();
+                async move {
+                    let result = client
+                        .get_alert_message(scan_id, &path.alert_uri_id, &path.msg_id, false)
+                        .await;
+                    (path.alert_uri_id.clone(), result)
+                }
+            })
+            .buffer_unordered(max_concurrency())
+            .collect()
+            .await;
+
+        let total = results.len();
+        let mut failed = 0;
+        let messages: HashMap<_, _> = results
+            .into_iter()
+            .filter_map(|(uri_id, result)| match result {
+                Ok(message) => Some((uri_id, message)),
+                Err(e) => {
+                    debug!("Failed to fetch message for uri {}: {}", uri_id, e);
+                    failed += 1;
+                    None
+                }
+            })
+            .collect();
+        if failed > 0 {
+            warn(
+                WarningKind::PartialResults,
+                format!(
+                    "{} of {} HTTP messages could not be fetched; their evidence and other info \
+                     were not searched",
+                    failed, total
+                ),
+            );
+        }
+        messages
+    } else {
+        HashMap::new()
+    };
+
+    let matches: Vec<FindingMatch> = findings
+        .iter()
+        .flat_map(|(alert, path)| {
+            let message = messages.get(&path.alert_uri_id);
+            FindingMatch::find_all(&search, &fields, alert, path, message)
+        })
+        .collect();
+
+    match ctx.format {
+        OutputFormat::Json => {
+            println!("{}", crate::output::json::format_json(&matches)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            if matches.is_empty() {
+                println!(
+                    "No findings match '{}' ({} searched).",
+                    pattern,
+                    findings.len()
+                );
+                return Ok(());
+            }
+            let rows: Vec<FindingMatchDisplay> = matches.iter().map(Into::into).collect();
+            rows.print(ctx.format)?;
+
+            let matched: HashSet<&str> = matches.iter().map(|m| m.uri_id.as_str()).collect();
+            hint!();
+            hint!(
+                "{} matches in {} of {} findings",
+                matches.len(),
+                matched.len(),
+                findings.len()
+            );
+            hint!(
+                "→ Drill down: hawkop scan get {} --uri-id <uri-id> -m",
+                scan_id
+            );
+        }
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// Scan Watch
+// ============================================================================
+
+/// Run the scan watch command
+///
+/// Follows the scan with [`follow_scan`] until it leaves the running state:
+/// polls adapt between 2s and 30s unless `interval_secs` fixes them, bypass
+/// the response cache, and survive dropped connections. Sends the
+/// `--notify` notifications once the scan ends. Returns an error if the scan
+/// finishes in any state other than COMPLETED.
+pub async fn watch(
+    opts: &GlobalOptions,
+    scan_id: &str,
+    interval_secs: Option<u64>,
+    notify: &[NotifyTarget],
+) -> Result<()> {
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?;
+
+    // Polls skip the response cache so each one sees the scan as it is now
+    let client = ctx.client.inner();
+    let options = FollowOptions {
+        interval: interval_secs.map(|secs| Duration::from_secs(secs.max(1))),
+        ..FollowOptions::default()
+    };
+    let mut events = Box::pin(follow_scan(|| client.get_scan(org_id, scan_id), options));
+
+    // Progress goes to stderr so stdout only carries the final result
+    let live = ctx.format == OutputFormat::Pretty && std::io::stderr().is_terminal();
+    let status_line = |line: &str| {
+        if live {
+            eprint!("\r\x1B[2K{}", line);
+            let _ = std::io::stderr().flush();
+        } else {
+            hint!("{}", line);
+        }
+    };
+
+    let mut finished = None;
+    while let Some(event) = events.next().await {
+        let event = match event {
+            Ok(event) => event,
+            Err(e) => {
+                if live {
+                    hint!();
+                }
+                return Err(e);
+            }
+        };
+        match event {
+            ScanEvent::Progress(result) => {
+                status_line(&watch_line(&result, chrono::Utc::now().timestamp_millis()));
+                if !is_running(&result) {
+                    finished = Some(*result);
+                }
+            }
+            ScanEvent::Reconnecting {
+                attempt,
+                error,
+                retry_in,
+            } => {
+                debug!("Poll {} failed: {}", attempt, error);
+                status_line(&format!(
+                    "Connection lost ({}); reconnecting in {}s",
+                    error,
+                    retry_in.as_secs().max(1)
+                ));
+            }
+        }
+    }
+    if live {
+        hint!();
+    }
+    let result = finished
+        .ok_or_else(|| crate::error::Error::Other("Scan progress ended early".to_string()))?;
+
+    match ctx.format {
+        OutputFormat::Json => {
+            println!("{}", crate::output::json::format_json(&result)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            vec![ScanDisplay::from(&result)].print(ctx.format)?;
+        }
+    }
+    notify::send_all(notify, &ScanNotification::from_scan(&result)).await;
+
+    if result.scan.status.eq_ignore_ascii_case("COMPLETED") {
+        Ok(())
+    } else {
+        Err(crate::error::Error::Other(format!(
+            "Scan {} finished with status {}",
+            result.scan.id,
+            format_scan_status(&result.scan.status)
+        )))
+    }
+}
+
+/// One-line progress summary: status, target, elapsed time, URLs, findings
+fn watch_line(result: &ScanResult, now_ms: i64) -> String {
+    let scan = &result.scan;
+
+    let elapsed_secs = result
+        .scan_duration
+        .as_deref()
+        .and_then(|d| d.parse::<f64>().ok())
+        .filter(|secs| *secs > 0.0)
+        .map(|secs| secs as i64)
+        .or_else(|| {
+            scan.timestamp
+                .parse::<i64>()
+                .ok()
+                .map(|started| (now_ms - started) / 1000)
+        })
+        .unwrap_or(0)
+        .max(0);
+    let urls = result
+        .url_count
+        .map_or_else(|| "--".to_string(), |n| n.to_string());
+
+    format!(
+        "{} | {} / {} | elapsed {} | URLs {} | findings {}",
+        format_scan_status(&scan.status),
+        scan.application_name,
+        scan.env,
+        format_duration_seconds(&elapsed_secs.to_string()),
+        urls,
+        ScanDisplay::from(result).findings
+    )
+}
+
+// ============================================================================
+// Scan Gate
+// ============================================================================
+
+/// Run the scan gate command
+///
+/// Prints the per-rule evaluation, then returns
+/// [`Error::ThresholdExceeded`](crate::error::Error::ThresholdExceeded)
+/// (exit code 2) if any rule failed. Scans that have not completed are an
+/// ordinary error (exit code 1). With `--format junit` the result is
+/// rendered as JUnit XML. When a suppressions file covers the scan's app and
+/// environment, the scan's paths are fetched so suppressed findings can be
+/// taken off the counts.
+pub async fn gate(
+    opts: &GlobalOptions,
+    scan_id: &str,
+    app: Option<&str>,
+    env: Option<&str>,
+    thresholds: &[FindingsThreshold],
+    include_triaged: bool,
+) -> Result<()> {
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?;
+
+    let is_latest = scan_id.eq_ignore_ascii_case("latest");
+    if !is_latest && (app.is_some() || env.is_some()) {
+        return Err(crate::error::ApiError::BadRequest(
+            "Cannot specify both scan ID and filters (--app, --env). \
+             Use filters only with 'latest'."
+                .to_string(),
+        )
+        .into());
+    }
+    let resolved_id = if is_latest {
+        resolve_latest_scan(&ctx, org_id, app, None, env).await?
+    } else {
+        scan_id.to_string()
+    };
+    let scan_id = resolved_id.as_str();
+
+    let result = ctx.client.get_scan(org_id, scan_id).await?;
+    let status = result.scan.status.to_uppercase();
+    if RUNNING_STATUSES.contains(&status.as_str()) {
+        return Err(crate::error::Error::Other(format!(
+            "Scan {} is still running. Use `hawkop scan watch {}` to wait for it",
+            scan_id, scan_id
+        )));
+    }
+    if status != "COMPLETED" {
+        return Err(crate::error::Error::Other(format!(
+            "Scan {} did not complete (status: {})",
+            scan_id,
+            format_scan_status(&result.scan.status)
+        )));
+    }
+
+    let mut gate = GateResult::evaluate(&result, thresholds, include_triaged);
+    let suppressions = Suppressions::discover()?;
+    if let Some(suppressions) = &suppressions {
+        let (app_id, app_name, env) = (
+            result.scan.application_id.as_str(),
+            result.scan.application_name.as_str(),
+            result.scan.env.as_str(),
+        );
+        if !suppressions.scoped(app_id, app_name, env).is_empty() {
+            let alerts =
+                fetch_alert_responses(&ctx, scan_id, "suppressions may not all apply").await?;
+            gate.suppress(&alerts, |plugin, uri| {
+                suppressions.suppresses(plugin, uri, app_id, app_name, env)
+            });
+        }
+    }
+    debug!("Scan gate for {}: passed={}", scan_id, gate.passed);
+
+    if opts.junit {
+        let alerts = ctx.client.list_scan_alerts(scan_id, None).await?;
+        let suites = gate.junit_suites(&alerts);
+        println!(
+            "{}",
+            crate::output::junit::format_junit("hawkop scan gate", &suites)
+        );
+    } else {
+        print_gate(&gate, ctx.format)?;
+    }
+    if let Some(suppressions) = suppressions.filter(|_| gate.suppressed > 0) {
+        hint!(
+            "{} finding(s) not counted: suppressed in {}",
+            gate.suppressed,
+            suppressions.path.display()
+        );
+    }
+
+    if gate.passed {
+        Ok(())
+    } else {
+        Err(crate::error::Error::ThresholdExceeded(
+            gate.failure_summary(),
+        ))
+    }
+}
+
+fn print_gate(gate: &GateResult, format: OutputFormat) -> Result<()> {
+    match format {
+        OutputFormat::Json => {
+            println!("{}", crate::output::json::format_json(&gate)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            println!(
+                "Scan: {} / {} ({}) | counting {} findings",
+                gate.app, gate.env, gate.scan_id, gate.counted
+            );
+            println!();
+            gate.rules.print(format)?;
+            println!();
+            if gate.passed {
+                println!("{} Gate passed", "✓".green());
+            } else {
+                println!("{} Gate failed", "✗".red());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Fetch every path-level finding for a scan (one request per plugin)
+async fn fetch_scan_findings(ctx: &CommandContext, scan_id: &str) -> Result<Vec<DiffFinding>> {
+    let responses = fetch_alert_responses(ctx, scan_id, "diff may be incomplete").await?;
+    Ok(responses
+        .iter()
+        .flat_map(DiffFinding::from_alert_response)
+        .collect())
+}
+
+/// Fetch each alert of a scan with its paths (one request per plugin)
+///
+/// Plugins that fail to load are skipped with a partial-results warning
+/// ending in `consequence`.
+pub async fn fetch_alert_responses(
+    ctx: &CommandContext,
+    scan_id: &str,
+    consequence: &str,
+) -> Result<Vec<crate::client::models::AlertResponse>> {
+    use futures::stream::{FuturesUnordered, StreamExt};
+
+    let alerts = ctx.client.list_scan_alerts(scan_id, None).await?;
+
+    let path_futures: FuturesUnordered<_> = alerts
+        .iter()
+        .map(|alert| {
+            let client = ctx.client.clone();
+            let sid = scan_id.to_string();
+            let pid = alert.plugin_id.clone();
+            async move {
+                let result = client.get_alert_with_paths(&sid, &pid, None).await;
+                (pid, result)
+            }
+        })
+        .collect();
+    let results: Vec<_> = path_futures.collect().await;
+
+    let mut responses = Vec::new();
+    let mut failed = 0;
+    for (pid, result) in results {
+        match result {
+            Ok(resp) => responses.push(resp),
+            Err(e) => {
+                debug!(
+                    "Failed to fetch paths for plugin {} in {}: {}",
+                    pid, scan_id, e
+                );
+                failed += 1;
+            }
+        }
+    }
+
+    if failed > 0 {
+        warn(
+            WarningKind::PartialResults,
+            format!(
+                "{} of {} plugin(s) could not be fetched for scan {}; {}",
+                failed,
+                alerts.len(),
+                scan_id,
+                consequence
+            ),
+        );
+    }
+
+    Ok(responses)
+}
+
+/// Where `scan upload-github` sends results
+///
+/// Unset fields fall back to the local git checkout (the token excepted).
+pub struct GitHubTarget {
+    /// Repository as owner/name
+    pub repo: Option<String>,
+    /// Full git ref (a bare branch name is expanded to `refs/heads/<name>`)
+    pub git_ref: Option<String>,
+    /// Commit SHA
+    pub sha: Option<String>,
+    /// GitHub token
+    pub token: Option<String>,
+    /// GitHub API base URL
+    pub api_url: String,
+}
+
+/// Run the scan upload-github command
+///
+/// Converts the scan's findings to SARIF and uploads them to GitHub code
+/// scanning, or prints the SARIF log with `--dry-run`.
+pub async fn upload_github(
+    opts: &GlobalOptions,
+    scan_id: &str,
+    target: GitHubTarget,
+    location: &str,
+    include_triaged: bool,
+) -> Result<()> {
+    use crate::error::Error;
+
+    // Resolve the destination first so a missing flag fails before any API calls
+    let destination = if opts.dry_run {
+        None
+    } else {
+        let token = target.token.clone().ok_or_else(|| {
+            Error::Other("A GitHub token is required: pass --token or set GITHUB_TOKEN".to_string())
+        })?;
+        let repo = target
+            .repo
+            .clone()
+            .or_else(|| {
+                crate::git::detect_local_repo()
+                    .filter(|r| r.provider == crate::git::GitProvider::GitHub)
+                    .map(|r| r.full_name())
+            })
+            .ok_or_else(|| {
+                Error::Other("--repo is required (no GitHub origin remote found)".to_string())
+            })?;
+        if repo.split('/').filter(|p| !p.is_empty()).count() != 2 {
+            return Err(Error::Other(format!(
+                "Invalid --repo '{}': expected owner/name",
+                repo
+            )));
+        }
+        let git_ref = target
+            .git_ref
+            .clone()
+            .or_else(crate::git::head_ref)
+            .map(|r| {
+                if r.starts_with("refs/") {
+                    r
+                } else {
+                    format!("refs/heads/{}", r)
+                }
+            })
+            .ok_or_else(|| {
+                Error::Other("--ref is required (no branch is checked out)".to_string())
+            })?;
+        let sha = target
+            .sha
+            .clone()
+            .or_else(crate::git::head_commit)
+            .ok_or_else(|| Error::Other("--sha is required (not in a git checkout)".to_string()))?;
+        Some((token, repo, git_ref, sha))
+    };
+
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?;
+
+    let result = ctx.client.get_scan(org_id, scan_id).await?;
+    hint!("Fetching findings for scan {}...", scan_id);
+    let mut alerts = fetch_alert_responses(&ctx, scan_id, "SARIF output may be incomplete").await?;
+    if let Some(suppressions) = Suppressions::discover()? {
+        let skipped = suppressions.strip_paths(
+            &mut alerts,
+            &result.scan.application_id,
+            &result.scan.application_name,
+            &result.scan.env,
+        );
+        if skipped > 0 {
+            hint!(
+                "Skipping {} finding(s) suppressed in {}",
+                skipped,
+                suppressions.path.display()
+            );
+        }
+    }
+    let sarif = crate::output::sarif::from_scan(&result, &alerts, include_triaged, location);
+    let result_count = sarif["runs"][0]["results"]
+        .as_array()
+        .map_or(0, |r| r.len());
+
+    let Some((token, repo, git_ref, sha)) = destination else {
+        println!("{}", serde_json::to_string_pretty(&sarif)?);
+        return Ok(());
+    };
+
+    hint!("Uploading {} result(s) to {}...", result_count, repo);
+    let github = crate::client::github::GitHubClient::new(&target.api_url, token)?;
+    let upload = github.upload_sarif(&repo, &sha, &git_ref, &sarif).await?;
+
+    match ctx.format {
+        OutputFormat::Json => {
+            let data = serde_json::json!({
+                "scan_id": scan_id,
+                "repo": repo,
+                "ref": git_ref,
+                "commit_sha": sha,
+                "results": result_count,
+                "upload_id": upload.id,
+                "upload_url": upload.url,
+            });
+            println!("{}", crate::output::json::format_json(&data)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            eprintln!(
+                "{} Uploaded {} result(s) from scan {} to {} ({})",
+                "✓".green(),
+                result_count,
+                scan_id,
+                repo,
+                git_ref
+            );
+            hint!("→ Processing status: {}", upload.url);
+        }
+    }
+
+    Ok(())
+}
+
+/// Format severity for display
+fn format_severity(severity: &str) -> String {
+    match severity.to_lowercase().as_str() {
+        "high" => "High".to_string(),
+        "medium" => "Medium".to_string(),
+        "low" => "Low".to_string(),
+        "informational" | "info" => "Info".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Format triage status for display
+fn format_triage_status(status: &str) -> String {
+    match status {
+        "UNKNOWN" => "New".to_string(),
+        "PROMOTED" => "Triaged".to_string(),
+        "ACCEPTED" | "RISK_ACCEPTED" => "Accepted".to_string(),
+        "FALSE_POSITIVE" => "False Positive".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Format scan status for display (normalize case, human-friendly)
+fn format_scan_status(status: &str) -> String {
+    match status.to_uppercase().as_str() {
+        "STARTED" => "Running".to_string(),
+        "COMPLETED" => "Complete".to_string(),
+        "ERROR" => "Failed".to_string(),
+        "UNKNOWN" => "Unknown".to_string(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::models::{AlertStats, AlertStatusStats, Scan};
+    use std::collections::HashMap;
+
+    // ========================================================================
+    // Test Fixtures
+    // ========================================================================
+
+    /// Create a minimal ScanResult for testing
+    fn make_scan(id: &str, app: &str, env: &str, status: &str) -> ScanResult {
+        ScanResult {
+            scan: Scan {
+                id: id.to_string(),
+                application_id: format!("app-{}", id),
+                application_name: app.to_string(),
+                env: env.to_string(),
+                status: status.to_string(),
+                timestamp: "1703721600000".to_string(), // 2023-12-28
+                version: "5.0.0".to_string(),
+                external_user_id: None,
+            },
+            scan_duration: Some("120".to_string()),
+            url_count: Some(50),
+            alert_stats: None,
+            severity_stats: None,
+            app_host: Some("https://example.com".to_string()),
+            policy_name: None,
+            tags: vec![],
+            metadata: None,
+        }
+    }
+
+    /// Create a ScanResult with alert stats for testing findings
+    fn make_scan_with_findings(
+        id: &str,
+        high_new: u32,
+        medium_new: u32,
+        low_new: u32,
+    ) -> ScanResult {
+        let mut severity_stats = HashMap::new();
+        if high_new > 0 {
+            severity_stats.insert("High".to_string(), high_new);
+        }
+        if medium_new > 0 {
+            severity_stats.insert("Medium".to_string(), medium_new);
+        }
+        if low_new > 0 {
+            severity_stats.insert("Low".to_string(), low_new);
+        }
+
+        let mut scan = make_scan(id, "TestApp", "prod", "COMPLETED");
+        scan.alert_stats = Some(AlertStats {
+            total_alerts: high_new + medium_new + low_new,
+            unique_alerts: high_new + medium_new + low_new,
+            alert_status_stats: vec![AlertStatusStats {
+                alert_status: "UNKNOWN".to_string(),
+                total_count: high_new + medium_new + low_new,
+                severity_stats,
+            }],
+        });
+        scan
+    }
+
+    // ========================================================================
+    // watch_line tests
+    // ========================================================================
+
+    #[test]
+    fn test_watch_line_running_uses_wall_clock_elapsed() {
+        let mut scan = make_scan_with_findings("s1", 2, 1, 0);
+        scan.scan.status = "STARTED".to_string();
+        scan.scan_duration = None;
+        let now = 1703721600000 + 95_000;
+
+        assert_eq!(
+            watch_line(&scan, now),
+            "Running | TestApp / prod | elapsed 1m 35s | URLs 50 | findings 2H0 1M0"
+        );
+    }
+
+    #[test]
+    fn test_watch_line_completed_uses_reported_duration() {
+        let scan = make_scan("s1", "TestApp", "prod", "COMPLETED");
+        let line = watch_line(&scan, i64::MAX);
+        assert!(line.starts_with("Complete | "));
+        assert!(line.contains("elapsed 2m 0s"));
+        assert!(line.ends_with("findings --"));
+    }
+
+    // ========================================================================
+    // format_scan_status tests
+    // ========================================================================
+
+    #[test]
+    fn test_format_scan_status_started() {
+        assert_eq!(format_scan_status("STARTED"), "Running");
+        assert_eq!(format_scan_status("started"), "Running");
+    }
+
+    #[test]
+    fn test_format_scan_status_completed() {
+        assert_eq!(format_scan_status("COMPLETED"), "Complete");
+        assert_eq!(format_scan_status("completed"), "Complete");
+    }
+
+    #[test]
     fn test_format_scan_status_error() {
         assert_eq!(format_scan_status("ERROR"), "Failed");
         assert_eq!(format_scan_status("error"), "Failed");
@@ -1767,6 +3863,24 @@ mod tests {
         assert_eq!(get_new_findings(&scan), (0, 0, 0));
     }
 
+    // ========================================================================
+    // in_time_range tests
+    // ========================================================================
+
+    #[test]
+    fn test_in_time_range() {
+        // make_scan timestamp is 1703721600000
+        let scan = make_scan("s1", "App", "prod", "COMPLETED");
+        assert!(in_time_range(&scan, None, None));
+        assert!(in_time_range(
+            &scan,
+            Some(1703721600000),
+            Some(1703721600000)
+        ));
+        assert!(!in_time_range(&scan, Some(1703721600001), None));
+        assert!(!in_time_range(&scan, None, Some(1703721599999)));
+    }
+
     // ========================================================================
     // apply_status_filter tests
     // ========================================================================
@@ -1781,6 +3895,7 @@ mod tests {
             app: vec![],
             env: vec![],
             status: None,
+            ..Default::default()
         };
 
         let result = apply_status_filter(scans.clone(), &filters);
@@ -1798,6 +3913,7 @@ mod tests {
             app: vec![],
             env: vec![],
             status: Some("running".to_string()),
+            ..Default::default()
         };
 
         let result = apply_status_filter(scans, &filters);
@@ -1816,6 +3932,7 @@ mod tests {
             app: vec![],
             env: vec![],
             status: Some("complete".to_string()),
+            ..Default::default()
         };
 
         let result = apply_status_filter(scans, &filters);
@@ -1837,6 +3954,7 @@ mod tests {
             page: None,
             sort_by: None,
             sort_dir: None,
+            all: false,
         };
 
         let result = apply_sort(scans.clone(), &pagination);
@@ -1857,6 +3975,7 @@ mod tests {
             page: None,
             sort_by: Some("app".to_string()),
             sort_dir: Some(SortDir::Asc),
+            all: false,
         };
 
         let result = apply_sort(scans, &pagination);
@@ -1877,6 +3996,7 @@ mod tests {
             page: None,
             sort_by: Some("app".to_string()),
             sort_dir: Some(SortDir::Desc),
+            all: false,
         };
 
         let result = apply_sort(scans, &pagination);
@@ -1897,6 +4017,7 @@ mod tests {
             page: None,
             sort_by: Some("env".to_string()),
             sort_dir: Some(SortDir::Asc),
+            all: false,
         };
 
         let result = apply_sort(scans, &pagination);
@@ -1917,6 +4038,7 @@ mod tests {
             page: None,
             sort_by: Some("status".to_string()),
             sort_dir: Some(SortDir::Asc),
+            all: false,
         };
 
         let result = apply_sort(scans, &pagination);
@@ -1937,6 +4059,7 @@ mod tests {
             page: None,
             sort_by: Some("findings".to_string()),
             sort_dir: Some(SortDir::Desc),
+            all: false,
         };
 
         let result = apply_sort(scans, &pagination);
@@ -1963,6 +4086,7 @@ mod tests {
             page: None,
             sort_by: Some("duration".to_string()),
             sort_dir: Some(SortDir::Asc),
+            all: false,
         };
 
         let result = apply_sort(scans, &pagination);
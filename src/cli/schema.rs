@@ -0,0 +1,506 @@
+//! `--schema`: JSON Schema of a command's output
+//!
+//! Maps a parsed command to the model its `--format json` output
+//! serializes, and prints that model's schema (see
+//! [`crate::output::schema`]) without running the command. The match is
+//! exhaustive, so a new command has to say what it prints; commands whose
+//! JSON isn't built from a model fail with the reason.
+
+use schemars::JsonSchema;
+use serde_json::Value;
+
+use super::auth::AuthStatus;
+use super::doctor::DoctorReport;
+use super::{
+    AppCommands, AuditCommands, AuthCommands, CacheCommands, Commands, ConfigCommands,
+    DaemonCommands, EnvCommands, FindingCommands, FormatArg, GateCommands, HistoryCommands,
+    OasCommands, OrgCommands, PolicyCommands, ProfileCommands, RepoCommands, ReportCommands,
+    RunCommands, ScanCommands, SecretCommands, SuppressCommands, SurfaceCommands, TeamCommands,
+    UserCommands,
+};
+use crate::client::models::{
+    AlertMsgResponse, AlertResponse, Application, CurrentFindingRow,
+    ReplaceRepoAppMappingsResponse, Repository, ScanResult, TeamDetail, ValidatedAssetResponse,
+};
+use crate::config::transfer::ImportedProfile;
+use crate::error::{Error, Result};
+use crate::integrations::CreatedTicket;
+use crate::models::display::app_duplicates::DuplicateGroup;
+use crate::models::display::app_findings::AppEnvFindings;
+use crate::models::display::app_inventory::AppInventoryRow;
+use crate::models::display::audit_summary::AuditSummary;
+use crate::models::display::coverage_report::CoverageReport;
+use crate::models::display::finding_search::FindingMatch;
+use crate::models::display::finding_triage::TriageOutcome;
+use crate::models::display::findings_report::FindingsReport;
+use crate::models::display::findings_trend::FindingsTrend;
+use crate::models::display::oas_map::OasFindingsMap;
+use crate::models::display::release_gate::ReleaseGate;
+use crate::models::display::scan_diff::ScanDiff;
+use crate::models::display::scan_full::ScanFullDetail;
+use crate::models::display::scan_gate::GateResult;
+use crate::models::display::scan_group::AppScanGroup;
+use crate::models::display::stale_apps::StaleAppsReport;
+use crate::models::display::surface::SurfaceSummary;
+use crate::models::display::team_audit::TeamDrift;
+use crate::models::display::team_import::TeamImportOutcome;
+use crate::models::display::team_report::TeamActivityReport;
+use crate::models::display::usage_report::UsageReport;
+use crate::models::display::{OrgDetail, OrgScoped, RunStatusDisplay, SuppressionDisplay};
+use crate::models::{
+    AppDisplay, AuditDisplay, ConfigDisplay, EnvDisplay, HistoryDisplay, OASDisplay, OrgDisplay,
+    PolicyDisplay, RepoDisplay, ScanDisplay, SecretDisplay, SecretUsageDisplay, TeamAppDisplay,
+    TeamListDisplay, TeamMemberDisplay, UserDisplay, UserTeamsDisplay,
+};
+use crate::output::schema;
+
+/// The command prints nothing on stdout in JSON
+const NO_JSON: &str = "it has no JSON output";
+
+/// The command builds its JSON in place rather than from a model
+const UNMODELED: &str = "its JSON output is not built from a model";
+
+/// Print the JSON Schema of `command`'s JSON output
+///
+/// `name` is the command's name as typed (e.g. `scan list`), for the title
+/// and error message.
+pub fn print(name: &str, command: &Commands) -> Result<()> {
+    let title = format!("hawkop {} --format json", name);
+    let document = for_command(&title, command).map_err(|reason| {
+        Error::Other(format!("No JSON schema for `hawkop {}`: {}", name, reason))
+    })?;
+    println!("{}", serde_json::to_string_pretty(&document)?);
+    Ok(())
+}
+
+/// Schema document of `command`'s output, or why it has none
+fn for_command(title: &str, command: &Commands) -> std::result::Result<Value, &'static str> {
+    let document = match command {
+        Commands::Doctor => data::<DoctorReport>(title),
+        Commands::Init { .. }
+        | Commands::Status { .. }
+        | Commands::Version
+        | Commands::Rerun { .. }
+        | Commands::Completion { .. } => return Err(NO_JSON),
+        Commands::Query { tables: true, .. } => return Err(UNMODELED),
+        Commands::Query { .. } => return Err("its columns depend on the query"),
+        Commands::Api { .. } => return Err("it prints the API response as returned"),
+
+        Commands::Auth(command) => match command {
+            AuthCommands::Status => data::<AuthStatus>(title),
+            AuthCommands::Login { .. } | AuthCommands::Refresh | AuthCommands::Logout { .. } => {
+                return Err(UNMODELED);
+            }
+        },
+
+        Commands::Org(command) => match command {
+            OrgCommands::List => rows::<OrgDisplay>(title, false),
+            OrgCommands::Get { .. } => data::<OrgDetail>(title),
+            OrgCommands::Usage { .. } => data::<UsageReport>(title),
+            OrgCommands::Set { .. } => return Err(NO_JSON),
+        },
+
+        Commands::App(command) => match command {
+            AppCommands::List { all_orgs, .. } => rows::<AppDisplay>(title, *all_orgs),
+            AppCommands::Get { .. } | AppCommands::Update { .. } => data::<Application>(title),
+            AppCommands::View { .. } => data::<Vec<AppEnvFindings>>(title),
+            AppCommands::Duplicates { .. } => data::<Vec<DuplicateGroup>>(title),
+            AppCommands::Export { output: None, .. } => data::<Vec<AppInventoryRow>>(title),
+            AppCommands::Export { .. } => return Err("it writes to the --output file"),
+            AppCommands::Create { .. } | AppCommands::Delete { .. } => return Err(UNMODELED),
+        },
+
+        Commands::Scan(command) => match command {
+            ScanCommands::List {
+                group_by: Some(_), ..
+            } => data::<Vec<AppScanGroup>>(title),
+            ScanCommands::List { all_orgs, .. } => rows::<ScanDisplay>(title, *all_orgs),
+            ScanCommands::Get {
+                format: FormatArg::Har | FormatArg::Markdown | FormatArg::Junit,
+                ..
+            }
+            | ScanCommands::Get {
+                curl_only: true, ..
+            } => return Err(NO_JSON),
+            ScanCommands::Get {
+                detail: Some(_), ..
+            } => schema::document::<ScanFullDetail>(title),
+            ScanCommands::Get {
+                uri_id: Some(_),
+                message: true,
+                ..
+            } => schema::document::<AlertMsgResponse>(title),
+            ScanCommands::Get {
+                uri_id: Some(_), ..
+            } => return Err(UNMODELED),
+            ScanCommands::Get {
+                plugin_id: Some(_), ..
+            } => schema::document::<AlertResponse>(title),
+            ScanCommands::Get { .. } => schema::document::<ScanResult>(title),
+            ScanCommands::Diff { markdown: true, .. } => return Err(NO_JSON),
+            ScanCommands::Diff { .. } => data::<ScanDiff>(title),
+            ScanCommands::Search { .. } => data::<Vec<FindingMatch>>(title),
+            ScanCommands::MapOas { .. } => data::<OasFindingsMap>(title),
+            ScanCommands::Watch { .. } => data::<ScanResult>(title),
+            ScanCommands::Gate { .. } => data::<GateResult>(title),
+            ScanCommands::UploadGithub { .. } => return Err(UNMODELED),
+        },
+
+        Commands::Finding(command) => match command {
+            FindingCommands::List { all_orgs, .. } => rows::<CurrentFindingRow>(title, *all_orgs),
+            FindingCommands::TriageBulk { .. } => data::<Vec<TriageOutcome>>(title),
+            FindingCommands::Ticket { .. } => data::<CreatedTicket>(title),
+        },
+
+        Commands::Gate(GateCommands::Release { .. }) => data::<ReleaseGate>(title),
+
+        Commands::Run(command) => match command {
+            RunCommands::Start { watch: true, .. } | RunCommands::Status { .. } => {
+                data::<RunStatusDisplay>(title)
+            }
+            RunCommands::Start { .. } | RunCommands::Stop { .. } => return Err(NO_JSON),
+        },
+
+        Commands::User(UserCommands::List { teams: false, .. }) => {
+            rows::<UserDisplay>(title, false)
+        }
+        Commands::User(UserCommands::List { teams: true, .. }) => {
+            rows::<UserTeamsDisplay>(title, false)
+        }
+
+        Commands::Team(command) => match command {
+            TeamCommands::List { .. } => rows::<TeamListDisplay>(title, false),
+            TeamCommands::Get {
+                members_only: true, ..
+            } => rows::<TeamMemberDisplay>(title, false),
+            TeamCommands::Get {
+                apps_only: true, ..
+            } => rows::<TeamAppDisplay>(title, false),
+            TeamCommands::Get { .. }
+            | TeamCommands::Create { .. }
+            | TeamCommands::Rename { .. } => data::<TeamDetail>(title),
+            TeamCommands::Import { .. } => data::<Vec<TeamImportOutcome>>(title),
+            TeamCommands::Audit { .. } => data::<Vec<TeamDrift>>(title),
+            TeamCommands::Report { .. } => data::<TeamActivityReport>(title),
+            TeamCommands::Delete { .. }
+            | TeamCommands::AddUser { .. }
+            | TeamCommands::RemoveUser { .. }
+            | TeamCommands::SetUsers { .. }
+            | TeamCommands::AddApp { .. }
+            | TeamCommands::RemoveApp { .. }
+            | TeamCommands::SetApps { .. } => return Err(UNMODELED),
+        },
+
+        Commands::Policy(PolicyCommands::List { .. }) => rows::<PolicyDisplay>(title, false),
+
+        Commands::Repo(command) => match command {
+            RepoCommands::List { .. } => rows::<RepoDisplay>(title, false),
+            RepoCommands::Get { .. } => data::<Repository>(title),
+            RepoCommands::SetApps { .. } => data::<ReplaceRepoAppMappingsResponse>(title),
+            RepoCommands::Link { .. } | RepoCommands::Map { .. } | RepoCommands::Unmap { .. } => {
+                return Err(UNMODELED);
+            }
+        },
+
+        Commands::Oas(command) => match command {
+            OasCommands::List { .. } | OasCommands::Mappings { .. } => {
+                rows::<OASDisplay>(title, false)
+            }
+            OasCommands::Get { .. } => return Err("it prints the OpenAPI spec as stored"),
+        },
+
+        Commands::Surface(SurfaceCommands::Summary) => data::<SurfaceSummary>(title),
+
+        Commands::Config(command) => match command {
+            ConfigCommands::List { .. } => rows::<ConfigDisplay>(title, false),
+            ConfigCommands::Validate { .. } => data::<ValidatedAssetResponse>(title),
+            ConfigCommands::Import { .. } => data::<Vec<ImportedProfile>>(title),
+            ConfigCommands::Export { .. } => return Err("it prints the hawkop config file"),
+            ConfigCommands::Get { .. }
+            | ConfigCommands::Pull { .. }
+            | ConfigCommands::Push { .. }
+            | ConfigCommands::Generate { .. }
+            | ConfigCommands::Lint { .. } => return Err(UNMODELED),
+            ConfigCommands::Set { .. }
+            | ConfigCommands::Delete { .. }
+            | ConfigCommands::Rename { .. }
+            | ConfigCommands::Migrate { .. } => return Err(NO_JSON),
+        },
+
+        Commands::Secret(SecretCommands::List) => rows::<SecretDisplay>(title, false),
+        Commands::Secret(SecretCommands::Usages { .. }) => rows::<SecretUsageDisplay>(title, false),
+
+        Commands::Audit(command) => match command {
+            AuditCommands::List { .. } => rows::<AuditDisplay>(title, false),
+            AuditCommands::Summary { .. } => data::<AuditSummary>(title),
+        },
+
+        Commands::Suppress(command) => match command {
+            SuppressCommands::List => rows::<SuppressionDisplay>(title, false),
+            SuppressCommands::Add { .. } | SuppressCommands::Prune => return Err(UNMODELED),
+        },
+
+        Commands::Report(command) => match command {
+            ReportCommands::Findings { .. } => data::<FindingsReport>(title),
+            ReportCommands::Trend { .. } => data::<FindingsTrend>(title),
+            ReportCommands::Coverage { .. } => data::<CoverageReport>(title),
+            ReportCommands::StaleApps { .. } => data::<StaleAppsReport>(title),
+        },
+
+        Commands::Env(command) => match command {
+            EnvCommands::List { .. } => rows::<EnvDisplay>(title, false),
+            EnvCommands::Config { .. } => return Err(UNMODELED),
+            EnvCommands::Create { .. } | EnvCommands::Delete { .. } => return Err(NO_JSON),
+        },
+
+        Commands::Cache(command) => match command {
+            CacheCommands::Status | CacheCommands::Clear | CacheCommands::Warm { .. } => {
+                return Err(UNMODELED);
+            }
+            CacheCommands::Path => return Err(NO_JSON),
+        },
+
+        Commands::Daemon(command) => match command {
+            DaemonCommands::Start { .. } | DaemonCommands::Stop | DaemonCommands::Status => {
+                return Err(UNMODELED);
+            }
+            DaemonCommands::Run { .. } => return Err(NO_JSON),
+        },
+
+        Commands::Profile(command) => match command {
+            ProfileCommands::List | ProfileCommands::Show { .. } => return Err(UNMODELED),
+            ProfileCommands::Use { .. }
+            | ProfileCommands::Create { .. }
+            | ProfileCommands::Delete { .. } => return Err(NO_JSON),
+        },
+
+        Commands::History(command) => match command {
+            HistoryCommands::List { .. } | HistoryCommands::Search { .. } => {
+                rows::<HistoryDisplay>(title, false)
+            }
+            HistoryCommands::Clear => return Err(UNMODELED),
+            HistoryCommands::Path => return Err(NO_JSON),
+        },
+    };
+    Ok(document)
+}
+
+/// `T` in the `{data, meta}` envelope
+fn data<T: JsonSchema>(title: &str) -> Value {
+    schema::envelope::<T>(title)
+}
+
+/// Enveloped list of `T` rows, tagged with their org for `--all-orgs`
+fn rows<T: JsonSchema>(title: &str, all_orgs: bool) -> Value {
+    if all_orgs {
+        data::<Vec<OrgScoped<T>>>(title)
+    } else {
+        data::<Vec<T>>(title)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+    use serde::Serialize;
+
+    use super::*;
+    use crate::cli::Cli;
+    use crate::client::fixtures::{ScanResultBuilder, test_app, test_org, test_user};
+    use crate::models::display::OrgUsage;
+    use crate::output::json::JsonOutput;
+    use crate::output::schema::check;
+    use crate::output::warnings::{Warning, WarningKind};
+
+    fn command(args: &str) -> Commands {
+        let args = std::iter::once("hawkop").chain(args.split_whitespace());
+        Cli::try_parse_from(args).unwrap().command
+    }
+
+    /// Errors from checking `rows` as `hawkop <args> --format json` output
+    fn check_rows<T: Serialize>(args: &str, rows: Vec<T>) -> Vec<String> {
+        let mut output = JsonOutput::new(rows);
+        output.meta.warnings = vec![Warning {
+            kind: WarningKind::PartialResults,
+            message: "1 page(s) not fetched".to_string(),
+        }];
+        let document = for_command("test", &command(args)).unwrap();
+        check(&document, &serde_json::to_value(output).unwrap())
+    }
+
+    fn assert_rows_match<T: Serialize>(args: &str, rows: Vec<T>) {
+        assert_eq!(
+            check_rows(args, rows),
+            Vec::<String>::new(),
+            "hawkop {}",
+            args
+        );
+    }
+
+    fn scan() -> ScanResult {
+        ScanResultBuilder::new("scan-1", "app-1")
+            .app_name("Web")
+            .timestamp("1700000000000")
+            .duration_seconds(90)
+            .with_findings(1, 2, 3)
+            .build()
+    }
+
+    #[test]
+    fn test_for_command_covers_models_and_explains_the_rest() {
+        for args in [
+            "app list",
+            "scan get latest --detail full",
+            "scan get latest -p 40012",
+            "team get Platform --members-only",
+            "report findings",
+            "doctor",
+            "auth status",
+        ] {
+            assert!(
+                for_command("test", &command(args)).is_ok(),
+                "{} has no schema",
+                args
+            );
+        }
+        assert_eq!(
+            for_command("test", &command("app delete app-1")),
+            Err(UNMODELED)
+        );
+        assert_eq!(for_command("test", &command("cache path")), Err(NO_JSON));
+        assert_eq!(
+            for_command("test", &command("scan diff a b --markdown")),
+            Err(NO_JSON)
+        );
+
+        let err = print("app delete", &command("app delete app-1")).unwrap_err();
+        assert!(err.to_string().contains(
+            "No JSON schema for `hawkop app delete`: its JSON output is not built from a model"
+        ));
+    }
+
+    #[test]
+    fn test_list_output_matches_schema() {
+        let org = test_org("org-1");
+        assert_rows_match("scan list", vec![ScanDisplay::from(scan())]);
+        assert_rows_match(
+            "scan list --all-orgs",
+            vec![OrgScoped::new(&org, ScanDisplay::from(scan()))],
+        );
+        assert_rows_match(
+            "app list --all-orgs",
+            vec![OrgScoped::new(&org, AppDisplay::from(test_app("app-1")))],
+        );
+        assert_rows_match(
+            "scan list --group-by app --expand",
+            AppScanGroup::group(vec![scan()], true),
+        );
+        assert_rows_match("org list", vec![OrgDisplay::from(&org)]);
+        assert_rows_match("user list", vec![UserDisplay::from(test_user("u-1"))]);
+        assert_rows_match(
+            "user list --teams",
+            vec![UserTeamsDisplay {
+                id: "u-1".to_string(),
+                email: "a@example.com".to_string(),
+                name: "A".to_string(),
+                role: "ADMIN".to_string(),
+                teams: "Platform (ADMIN)".to_string(),
+            }],
+        );
+        assert_rows_match(
+            "team list",
+            vec![TeamListDisplay {
+                id: "t-1".to_string(),
+                name: "Platform".to_string(),
+                users: 3,
+                apps: 2,
+            }],
+        );
+        assert_rows_match(
+            "secret list",
+            vec![SecretDisplay {
+                name: "TOKEN".to_string(),
+            }],
+        );
+        assert_rows_match(
+            "secret usages TOKEN",
+            vec![SecretUsageDisplay {
+                config: "web-prod".to_string(),
+                app: "Web".to_string(),
+                app_id: "app-1".to_string(),
+                env: "prod".to_string(),
+                lines: "7, 12".to_string(),
+            }],
+        );
+    }
+
+    #[test]
+    fn test_model_output_matches_schema() {
+        let check_data = |args: &str, data: Value| {
+            let document = for_command("test", &command(args)).unwrap();
+            let output = serde_json::to_value(JsonOutput::new(data)).unwrap();
+            assert_eq!(
+                check(&document, &output),
+                Vec::<String>::new(),
+                "hawkop {}",
+                args
+            );
+        };
+        let app = test_app("app-1");
+        check_data("app get app-1", serde_json::to_value(&app).unwrap());
+        check_data(
+            "org get",
+            serde_json::to_value(OrgDetail {
+                org: test_org("org-1"),
+                usage: OrgUsage {
+                    apps: Some(3),
+                    users: None,
+                },
+            })
+            .unwrap(),
+        );
+        check_data("scan watch latest", serde_json::to_value(scan()).unwrap());
+        check_data(
+            "scan list --group-by app",
+            serde_json::to_value(AppScanGroup::group(vec![scan()], false)).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_schema_catches_undeclared_fields() {
+        #[derive(Serialize)]
+        struct Extra {
+            name: String,
+            value: String,
+        }
+        let errors = check_rows(
+            "secret list",
+            vec![Extra {
+                name: "TOKEN".to_string(),
+                value: "x".to_string(),
+            }],
+        );
+        assert_eq!(errors, vec!["$.data[0].value: not declared in the schema"]);
+    }
+
+    #[test]
+    fn test_scan_get_output_matches_schema() {
+        let document = for_command("test", &command("scan view latest")).unwrap();
+        let mut scan = scan();
+        assert_eq!(
+            check(&document, &serde_json::to_value(&scan).unwrap()),
+            Vec::<String>::new()
+        );
+
+        // As the API returns it, with optional fields filled in
+        scan.scan.external_user_id = Some("u-1".to_string());
+        scan.policy_name = Some("DEFAULT_API".to_string());
+        scan.app_host = Some("https://example.com".to_string());
+        scan.metadata = Some(Default::default());
+        assert_eq!(
+            check(&document, &serde_json::to_value(&scan).unwrap()),
+            Vec::<String>::new()
+        );
+        assert_eq!(document["$schema"], schema::DIALECT);
+    }
+}
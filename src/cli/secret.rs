@@ -1,11 +1,20 @@
 //! User secret management commands
 
-use crate::cli::CommandContext;
+use std::collections::HashMap;
+
+use futures::stream::{self, StreamExt};
+use log::debug;
+
 use crate::cli::args::GlobalOptions;
-use crate::client::ListingApi;
+use crate::cli::handlers::fetch_listing;
+use crate::cli::{CommandContext, PaginationArgs};
+use crate::client::{ConfigApi, ListingApi, max_concurrency};
 use crate::error::Result;
-use crate::models::SecretDisplay;
+use crate::models::{SecretDisplay, SecretUsageDisplay};
 use crate::output::Formattable;
+use crate::output::hints::hint;
+use crate::output::warnings::{WarningKind, warn};
+use crate::validation::references::{find_references, scanned_app};
 
 /// Run the secret list command
 pub async fn list(opts: &GlobalOptions) -> Result<()> {
@@ -20,3 +29,103 @@ pub async fn list(opts: &GlobalOptions) -> Result<()> {
 
     Ok(())
 }
+
+/// Run the secret usages command
+///
+/// Downloads every scan configuration in the org (in parallel) and lists
+/// those with a placeholder naming the secret, with the app and environment
+/// each one scans. Configs that cannot be downloaded are left out with a
+/// `partial_results` warning.
+pub async fn usages(opts: &GlobalOptions, name: &str) -> Result<()> {
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?.to_string();
+
+    let client = ctx.client.clone();
+    let org = org_id.clone();
+    let all = PaginationArgs {
+        all: true,
+        ..Default::default()
+    };
+    let configs = fetch_listing(&all, "scan configs", move |params| {
+        let client = client.clone();
+        let org = org.clone();
+        async move { client.list_scan_configs(&org, Some(&params)).await }
+    })
+    .await?;
+    debug!("Checking {} scan configs for ${{{}}}", configs.len(), name);
+
+    let contents: Vec<_> = stream::iter(configs.into_iter().map(|config| {
+        let client = ctx.client.clone();
+        let org = org_id.clone();
+        async move {
+            let content = client.get_scan_config(&org, &config.name).await;
+            (config.name, content)
+        }
+    }))
+    .buffered(max_concurrency())
+    .collect()
+    .await;
+
+    let mut usages = Vec::new();
+    let mut failed = Vec::new();
+    for (config, content) in contents {
+        match content {
+            Ok(content) => {
+                let references = find_references(&content, name);
+                if references.is_empty() {
+                    continue;
+                }
+                let (app_id, env) = scanned_app(&content);
+                let lines: Vec<String> = references.iter().map(|r| r.line.to_string()).collect();
+                usages.push(SecretUsageDisplay {
+                    config,
+                    app: String::new(),
+                    app_id: app_id.unwrap_or_default(),
+                    env: env.unwrap_or_default(),
+                    lines: lines.join(", "),
+                });
+            }
+            Err(e) => {
+                debug!("Failed to fetch scan config {}: {}", config, e);
+                failed.push(config);
+            }
+        }
+    }
+    if !failed.is_empty() {
+        warn(
+            WarningKind::PartialResults,
+            format!(
+                "Scan config(s) could not be downloaded: {}",
+                failed.join(", ")
+            ),
+        );
+    }
+
+    if usages.iter().any(|usage| !usage.app_id.is_empty()) {
+        let apps: HashMap<String, String> =
+            crate::cli::app::fetch_apps(&ctx.client, org_id.clone(), 0)
+                .await?
+                .into_iter()
+                .map(|app| (app.id, app.name))
+                .collect();
+        for usage in &mut usages {
+            usage.app = apps
+                .get(&usage.app_id)
+                .cloned()
+                .unwrap_or_else(|| usage.app_id.clone());
+        }
+    }
+
+    let count = usages.len();
+    usages.print(ctx.format)?;
+
+    if count == 0 {
+        hint!("No scan configuration references secret {}.", name);
+    } else {
+        hint!();
+        hint!("Update these configs before deleting secret {}.", name);
+        hint!("→ hawkop config pull <config>");
+    }
+
+    Ok(())
+}
@@ -3,11 +3,20 @@
 use colored::Colorize;
 
 use crate::cli::args::GlobalOptions;
-use crate::config::ProfiledConfig;
-use crate::error::Result;
+use crate::cli::context::{
+    SettingSource, apply_env_overrides, rate_limit_quotas, resolve_settings,
+};
+use crate::client::rate_limit::{EndpointCategory, LastRateLimit};
+use crate::config::crypto::PASSPHRASE_ENV;
+use crate::config::{Config, Preferences, ProfileConfig, ProfiledConfig, TokenStore};
+use crate::error::{ConfigError, Error, Result};
+use crate::output::formatters::format_timestamp_local;
 
 /// Run the status command to display configuration status
-pub fn run(opts: &GlobalOptions) -> Result<()> {
+///
+/// With `verbose`, also shows rate limit quotas and the last 429 recorded
+/// by any command.
+pub fn run(opts: &GlobalOptions, verbose: bool) -> Result<()> {
     println!("{}\n", "HawkOp Configuration Status".bold());
 
     // Load profiled config
@@ -45,17 +54,17 @@ pub fn run(opts: &GlobalOptions) -> Result<()> {
             }
 
             // JWT token status
-            if let Some(ref jwt) = profile.jwt {
-                if profile.is_token_expired() {
+            let jwt = TokenStore::for_profile(&profiled_config, profile, opts.api_host_ref())
+                .and_then(|tokens| tokens.load());
+            if let Some(ref jwt) = jwt {
+                if jwt.needs_refresh() {
                     println!(
                         "{} JWT token expired (will refresh on next command)",
                         "⚠".yellow()
                     );
                 } else {
-                    // Calculate remaining time
-                    let now = chrono::Utc::now();
-                    let expires = jwt.expires_at;
-                    let remaining = expires.signed_duration_since(now);
+                    // Remaining time in server time (corrects for clock skew)
+                    let remaining = jwt.remaining();
                     let hours = remaining.num_hours();
                     let mins = remaining.num_minutes() % 60;
 
@@ -86,6 +95,16 @@ pub fn run(opts: &GlobalOptions) -> Result<()> {
                 println!("{} Custom API host: {}", "○".dimmed(), host.cyan());
             }
 
+            println!();
+            print_settings(opts, profile, verbose)?;
+
+            if verbose {
+                let mut prefs = profile.preferences.clone();
+                apply_env_overrides(&mut prefs)?;
+                println!();
+                print_rate_limits(&prefs);
+            }
+
             // Show other profiles
             let other_profiles: Vec<_> = profiled_config
                 .list_profiles()
@@ -100,6 +119,15 @@ pub fn run(opts: &GlobalOptions) -> Result<()> {
 
             println!();
         }
+        Err(Error::Config(ConfigError::Encryption(reason))) => {
+            println!("{} Cannot decrypt configuration: {}", "✗".red(), reason);
+            println!();
+            println!(
+                "Set {} or run interactively to enter the passphrase.",
+                PASSPHRASE_ENV.cyan()
+            );
+            println!();
+        }
         Err(_) => {
             println!("{} Configuration not found", "✗".red());
             println!();
@@ -113,3 +141,69 @@ pub fn run(opts: &GlobalOptions) -> Result<()> {
 
     Ok(())
 }
+
+/// Settings section: where overridden values came from (every setting with
+/// `verbose`)
+fn print_settings(opts: &GlobalOptions, profile: &ProfileConfig, verbose: bool) -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let settings = resolve_settings(opts, profile, &args)?;
+    let shown: Vec<_> = settings
+        .iter()
+        .filter(|s| verbose || s.source != SettingSource::Default)
+        .collect();
+    if shown.is_empty() {
+        println!("{} All settings at defaults", "○".dimmed());
+        return Ok(());
+    }
+
+    println!("Settings:");
+    for setting in shown {
+        let source = match setting.source {
+            SettingSource::Flag => format!("flag {}", setting.flag.unwrap_or_default()),
+            SettingSource::Env => format!("env {}", setting.env),
+            SettingSource::Config => "config".to_string(),
+            SettingSource::Default => "default".to_string(),
+        };
+        println!(
+            "  {:<16} {:<28} {}",
+            setting.name,
+            setting.value,
+            format!("({})", source).dimmed()
+        );
+    }
+    Ok(())
+}
+
+/// Rate limit section of `status --verbose`
+fn print_rate_limits(prefs: &Preferences) {
+    let overrides = rate_limit_quotas(prefs);
+
+    println!("Rate limits (requests/min, applied after the first 429):");
+    for category in EndpointCategory::ALL {
+        match overrides.get(&category) {
+            Some(per_minute) => println!(
+                "  {:<11} {:>6} {}",
+                category.name(),
+                per_minute,
+                format!("(override; default {})", category.default_per_minute()).dimmed()
+            ),
+            None => println!(
+                "  {:<11} {:>6}",
+                category.name(),
+                category.default_per_minute()
+            ),
+        }
+    }
+
+    match Config::rate_limit_path().and_then(|path| LastRateLimit::load(&path)) {
+        Some(last) => println!(
+            "{} Last rate limited {} on {} endpoints ({}/min quota, {} 429s in that command)",
+            "⚠".yellow(),
+            format_timestamp_local(&last.at.timestamp_millis().to_string()),
+            last.category,
+            last.quota_per_minute,
+            last.hits
+        ),
+        None => println!("{} No rate limiting recorded", "✓".green()),
+    }
+}
@@ -0,0 +1,179 @@
+//! Finding suppression commands (`.hawkop-suppressions.yaml`)
+
+use colored::Colorize;
+
+use crate::cli::OutputFormat;
+use crate::error::{Error, Result};
+use crate::models::display::SuppressionDisplay;
+use crate::output::Formattable;
+use crate::output::hints::hint;
+use crate::suppress::{SUPPRESSIONS_FILE, Suppression, Suppressions, parse_expiry};
+
+/// Add a suppression to the suppressions file, creating it if needed
+pub fn add(
+    format: OutputFormat,
+    plugin: &str,
+    paths: Vec<String>,
+    app: Option<String>,
+    env: Option<String>,
+    expires: Option<&str>,
+    reason: &str,
+) -> Result<()> {
+    let plugin = plugin.trim();
+    if plugin.is_empty() {
+        return Err(Error::Other("Plugin ID cannot be empty".to_string()));
+    }
+    let reason = reason.trim();
+    if reason.is_empty() {
+        return Err(Error::Other(
+            "--reason cannot be empty: say why the finding is accepted".to_string(),
+        ));
+    }
+
+    let mut suppressions = Suppressions::open()?;
+    let today = suppressions.today;
+    let expires = expires.map(|e| parse_expiry(e, today)).transpose()?;
+    if let Some(date) = expires.filter(|date| *date < today) {
+        return Err(Error::Other(format!("--expires {} is in the past", date)));
+    }
+
+    let suppression = Suppression {
+        plugin: plugin.to_string(),
+        paths,
+        app,
+        env,
+        expires,
+        reason: reason.to_string(),
+        added: Some(today),
+    };
+    suppressions.entries.push(suppression.clone());
+    suppressions.save()?;
+
+    match format {
+        OutputFormat::Json => {
+            let data = serde_json::json!({
+                "file": suppressions.path,
+                "suppression": suppression,
+                "total": suppressions.entries.len(),
+            });
+            println!("{}", crate::output::json::format_json(&data)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            let mut scope = Vec::new();
+            if !suppression.paths.is_empty() {
+                scope.push(format!("paths {}", suppression.paths.join(", ")));
+            }
+            if let Some(app) = &suppression.app {
+                scope.push(format!("app {}", app));
+            }
+            if let Some(env) = &suppression.env {
+                scope.push(format!("env {}", env));
+            }
+            if scope.is_empty() {
+                scope.push("every path, app, and environment".to_string());
+            }
+            let until = match suppression.expires {
+                Some(date) => format!("until {}", date),
+                None => "with no expiry".to_string(),
+            };
+            println!(
+                "{} Suppressed plugin {} for {} {}",
+                "✓".green(),
+                suppression.plugin,
+                scope.join("; "),
+                until
+            );
+            println!("  Saved to {}", suppressions.path.display());
+            hint!();
+            hint!(
+                "→ Commit {} so the suppression is reviewed",
+                SUPPRESSIONS_FILE
+            );
+        }
+    }
+    Ok(())
+}
+
+/// List the suppressions file's entries
+pub fn list(format: OutputFormat) -> Result<()> {
+    let Some(suppressions) = Suppressions::discover()? else {
+        if format != OutputFormat::Json {
+            println!(
+                "No {} found between here and the repository root",
+                SUPPRESSIONS_FILE
+            );
+            hint!();
+            hint!("→ hawkop suppress add <plugin-id> --reason <why>");
+            return Ok(());
+        }
+        return Vec::<SuppressionDisplay>::new().print(format);
+    };
+
+    let rows: Vec<SuppressionDisplay> = suppressions
+        .entries
+        .iter()
+        .map(|s| SuppressionDisplay::new(s, suppressions.today))
+        .collect();
+    rows.print(format)?;
+    if format != OutputFormat::Json {
+        hint!("\n{}", suppressions.path.display());
+        if rows.iter().any(|r| r.status == "expired") {
+            hint!("→ hawkop suppress prune    # Remove expired entries");
+        }
+    }
+    Ok(())
+}
+
+/// Remove expired suppressions from the file
+pub fn prune(format: OutputFormat, dry_run: bool) -> Result<()> {
+    let Some(mut suppressions) = Suppressions::discover()? else {
+        return Err(Error::Other(format!(
+            "No {} found between here and the repository root",
+            SUPPRESSIONS_FILE
+        )));
+    };
+    let expired = suppressions.prune();
+    if !dry_run && !expired.is_empty() {
+        suppressions.save()?;
+    }
+
+    match format {
+        OutputFormat::Json => {
+            let data = serde_json::json!({
+                "file": suppressions.path,
+                "removed": expired,
+                "remaining": suppressions.entries.len(),
+                "dry_run": dry_run,
+            });
+            println!("{}", crate::output::json::format_json(&data)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            if expired.is_empty() {
+                println!("No expired suppressions");
+                return Ok(());
+            }
+            let rows: Vec<SuppressionDisplay> = expired
+                .iter()
+                .map(|s| SuppressionDisplay::new(s, suppressions.today))
+                .collect();
+            rows.print(format)?;
+            println!();
+            if dry_run {
+                println!(
+                    "Would remove {} expired suppression(s) from {}",
+                    expired.len(),
+                    suppressions.path.display()
+                );
+            } else {
+                println!(
+                    "{} Removed {} expired suppression(s) from {} ({} remaining)",
+                    "✓".green(),
+                    expired.len(),
+                    suppressions.path.display(),
+                    suppressions.entries.len()
+                );
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,88 @@
+//! Attack surface commands
+
+use colored::Colorize;
+use log::debug;
+
+use crate::cli::app::fetch_apps;
+use crate::cli::args::GlobalOptions;
+use crate::cli::handlers::fetch_listing;
+use crate::cli::{CommandContext, OutputFormat, PaginationArgs};
+use crate::client::ListingApi;
+use crate::error::Result;
+use crate::models::display::surface::SurfaceSummary;
+use crate::output::Formattable;
+use crate::output::hints::hint;
+
+/// Run the surface summary command
+///
+/// Fetches every repository, application, and hosted spec in the org (the
+/// three listings in parallel) and summarizes how much of the attack
+/// surface is mapped to apps and described by specs.
+pub async fn summary(opts: &GlobalOptions) -> Result<()> {
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?;
+
+    let all = PaginationArgs {
+        all: true,
+        ..Default::default()
+    };
+    let (client, org) = (ctx.client.clone(), org_id.to_string());
+    let repos = fetch_listing(&all, "repositories", move |params| {
+        let (client, org) = (client.clone(), org.clone());
+        async move { client.list_repos(&org, Some(&params)).await }
+    });
+    let (client, org) = (ctx.client.clone(), org_id.to_string());
+    let specs = fetch_listing(&all, "OAS assets", move |params| {
+        let (client, org) = (client.clone(), org.clone());
+        async move { client.list_oas(&org, Some(&params)).await }
+    });
+    let apps = fetch_apps(&ctx.client, org_id.to_string(), 0);
+    let (repos, apps, specs) = tokio::try_join!(repos, apps, specs)?;
+    debug!(
+        "Surface: {} repos, {} apps, {} specs",
+        repos.len(),
+        apps.len(),
+        specs.len()
+    );
+
+    let summary = SurfaceSummary::build(&repos, &apps, &specs);
+
+    match ctx.format {
+        OutputFormat::Json => {
+            println!("{}", crate::output::json::format_json(&summary)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            vec![summary.row()].print(ctx.format)?;
+            if !summary.sensitive_data_tags.is_empty() {
+                let tags: Vec<String> = summary
+                    .sensitive_data_tags
+                    .iter()
+                    .map(|(tag, repos)| format!("{} {}", tag, repos))
+                    .collect();
+                println!("\n{} {}", "Sensitive data:".bold(), tags.join(", "));
+            }
+            if !summary.unmapped_repos.is_empty() {
+                println!(
+                    "\n{} ({})",
+                    "Attack surface repos without an app".bold(),
+                    summary.unmapped_repos.len()
+                );
+                summary.unmapped_repos.print(ctx.format)?;
+            }
+            if !summary.apis_without_specs.is_empty() {
+                println!(
+                    "\n{} ({})",
+                    "Attack surface repos without an API spec".bold(),
+                    summary.apis_without_specs.len()
+                );
+                summary.apis_without_specs.print(ctx.format)?;
+            }
+            if !summary.unmapped_repos.is_empty() {
+                hint!();
+                hint!("→ hawkop repo map <repo> --app <app>");
+            }
+        }
+    }
+
+    Ok(())
+}
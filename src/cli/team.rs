@@ -10,13 +10,25 @@ use log::debug;
 
 use crate::cache::CachedStackHawkClient;
 use crate::cli::args::GlobalOptions;
+use crate::cli::audit::AUDIT_PAGE_MAX;
+use crate::cli::context::{looks_like_uuid, resolve_app_ids};
+use crate::cli::scan::fetch_all_scans;
 use crate::cli::{CommandContext, OutputFormat, PaginationArgs, TeamFilterArgs};
 use crate::client::models::{
-    Application, CreateTeamRequest, Team, TeamDetail, UpdateTeamRequest, User,
+    Application, AuditFilterParams, CreateTeamRequest, Team, TeamDetail, UpdateTeamRequest, User,
 };
 use crate::client::pagination::PaginationParams;
-use crate::client::{ListingApi, StackHawkClient, TeamApi, fetch_remaining_pages};
-use crate::error::Result;
+use crate::client::{
+    ListingApi, ScanFilterParams, StackHawkClient, TeamApi, fetch_remaining_pages, max_concurrency,
+};
+use crate::error::{PromptResult, Result};
+use crate::models::display::team_audit::{DriftKind, TeamDrift};
+use crate::models::display::team_import::{SyncDiff, TeamImportOutcome, TeamManifest};
+use crate::models::display::team_report::TeamActivityReport;
+use crate::output::Formattable;
+use crate::output::hints::hint;
+use crate::output::warnings::{WarningKind, warn};
+use crate::timeparse;
 
 /// Type alias for the Arc-wrapped cached client used throughout this module
 type Client = Arc<CachedStackHawkClient<StackHawkClient>>;
@@ -24,22 +36,10 @@ type Client = Arc<CachedStackHawkClient<StackHawkClient>>;
 /// Page size for parallel fetching (API max is 1000)
 const RESOLUTION_PAGE_SIZE: usize = 1000;
 
-/// Max concurrent requests for parallel fetching
-const PARALLEL_FETCH_LIMIT: usize = 32;
-
 // ============================================================================
 // Identifier Resolution Helpers
 // ============================================================================
 
-/// Simple UUID format check (8-4-4-4-12 hex pattern)
-fn looks_like_uuid(s: &str) -> bool {
-    let parts: Vec<&str> = s.split('-').collect();
-    parts.len() == 5
-        && parts
-            .iter()
-            .all(|p| p.chars().all(|c| c.is_ascii_hexdigit()))
-}
-
 // ============================================================================
 // Parallel Fetching Helpers for Enterprise-Scale Data
 // ============================================================================
@@ -48,7 +48,7 @@ fn looks_like_uuid(s: &str) -> bool {
 ///
 /// Uses the paged API variant to get total_count, then fetches remaining
 /// pages in parallel for optimal performance with large organizations.
-async fn fetch_all_teams(client: Client, org_id: &str) -> Result<Vec<Team>> {
+pub(crate) async fn fetch_all_teams(client: Client, org_id: &str) -> Result<Vec<Team>> {
     let first_params = PaginationParams::new()
         .page_size(RESOLUTION_PAGE_SIZE)
         .page(0);
@@ -93,7 +93,7 @@ async fn fetch_all_teams(client: Client, org_id: &str) -> Result<Vec<Team>> {
                             c.list_teams(&o, Some(&params)).await
                         }
                     },
-                    PARALLEL_FETCH_LIMIT,
+                    max_concurrency(),
                 )
                 .await?;
 
@@ -110,7 +110,7 @@ async fn fetch_all_teams(client: Client, org_id: &str) -> Result<Vec<Team>> {
 ///
 /// Uses the paged API variant to get total_count, then fetches remaining
 /// pages in parallel for optimal performance with large organizations.
-async fn fetch_all_users(client: Client, org_id: &str) -> Result<Vec<User>> {
+pub(crate) async fn fetch_all_users(client: Client, org_id: &str) -> Result<Vec<User>> {
     let first_params = PaginationParams::new()
         .page_size(RESOLUTION_PAGE_SIZE)
         .page(0);
@@ -155,7 +155,7 @@ async fn fetch_all_users(client: Client, org_id: &str) -> Result<Vec<User>> {
                             c.list_users(&o, Some(&params)).await
                         }
                     },
-                    PARALLEL_FETCH_LIMIT,
+                    max_concurrency(),
                 )
                 .await?;
 
@@ -172,7 +172,7 @@ async fn fetch_all_users(client: Client, org_id: &str) -> Result<Vec<User>> {
 ///
 /// Uses the paged API variant to get total_count, then fetches remaining
 /// pages in parallel for optimal performance with large organizations.
-async fn fetch_all_apps(client: Client, org_id: &str) -> Result<Vec<Application>> {
+pub(crate) async fn fetch_all_apps(client: Client, org_id: &str) -> Result<Vec<Application>> {
     let first_params = PaginationParams::new()
         .page_size(RESOLUTION_PAGE_SIZE)
         .page(0);
@@ -217,7 +217,7 @@ async fn fetch_all_apps(client: Client, org_id: &str) -> Result<Vec<Application>
                             c.list_apps(&o, Some(&params)).await
                         }
                     },
-                    PARALLEL_FETCH_LIMIT,
+                    max_concurrency(),
                 )
                 .await?;
 
@@ -308,54 +308,6 @@ async fn resolve_users(
         .collect()
 }
 
-/// Resolve app identifiers (name or UUID) to UUIDs.
-/// Errors if name matches multiple apps (safety for mutations).
-async fn resolve_apps(client: Client, org_id: &str, identifiers: &[String]) -> Result<Vec<String>> {
-    if identifiers.is_empty() {
-        return Ok(vec![]);
-    }
-
-    // Fetch all applications with parallel pagination
-    let apps = fetch_all_apps(client, org_id).await?;
-
-    identifiers
-        .iter()
-        .map(|id| {
-            // If it's a UUID, use it directly
-            if looks_like_uuid(id) {
-                return Ok(id.clone());
-            }
-
-            // Find ALL apps with matching name (case-insensitive)
-            let matches: Vec<_> = apps
-                .iter()
-                .filter(|a| a.name.eq_ignore_ascii_case(id))
-                .collect();
-
-            match matches.len() {
-                0 => Err(crate::error::Error::Other(format!(
-                    "Application not found: {}\n\n→ Use 'hawkop app list' to see available applications.",
-                    id
-                ))),
-                1 => Ok(matches[0].id.clone()),
-                _ => {
-                    // Multiple apps with same name - error with IDs for disambiguation
-                    let app_list = matches
-                        .iter()
-                        .map(|a| format!("  • {} (ID: {})", a.name, a.id))
-                        .collect::<Vec<_>>()
-                        .join("\n");
-
-                    Err(crate::error::Error::Other(format!(
-                        "Multiple applications found with name \"{}\". Use app ID instead:\n\n{}\n\n→ Example: hawkop team add-app \"Team\" {}",
-                        id, app_list, matches[0].id
-                    )))
-                }
-            }
-        })
-        .collect()
-}
-
 /// Read identifiers from stdin (one per line)
 #[allow(clippy::lines_filter_map_ok)]
 fn read_stdin_lines() -> Result<Vec<String>> {
@@ -461,63 +413,103 @@ async fn setup_team_context(opts: &GlobalOptions) -> Result<(String, Client)> {
 // Display Helpers
 // ============================================================================
 
+/// Print a membership diff (dry runs), listing the IDs added and removed
+fn print_sync_diff(heading: &str, diff: &SyncDiff) {
+    eprintln!("{}", heading);
+    if !diff.to_add.is_empty() {
+        eprintln!("  {} Add: {}", "+".green(), diff.to_add.len());
+        for id in &diff.to_add {
+            eprintln!("    • {}", id);
+        }
+    }
+    if !diff.to_remove.is_empty() {
+        eprintln!("  {} Remove: {}", "-".red(), diff.to_remove.len());
+        for id in &diff.to_remove {
+            eprintln!("    • {}", id);
+        }
+    }
+    if !diff.unchanged.is_empty() {
+        eprintln!("  {} Unchanged: {}", "=".dimmed(), diff.unchanged.len());
+    }
+}
+
+/// Which sections of a team to show in `team get`
+#[derive(Debug, Clone, Copy)]
+pub struct TeamSections {
+    pub members: bool,
+    pub apps: bool,
+}
+
 /// Display team detail with members and apps
-fn display_team_detail(team: &TeamDetail, format: OutputFormat) -> Result<()> {
+fn display_team_detail(
+    team: &TeamDetail,
+    sections: TeamSections,
+    format: OutputFormat,
+) -> Result<()> {
+    use crate::models::{TeamAppDisplay, TeamMemberDisplay};
+    use crate::output::Formattable;
+
+    let members: Vec<TeamMemberDisplay> = team.users.iter().map(Into::into).collect();
+    let apps: Vec<TeamAppDisplay> = team.applications.iter().map(Into::into).collect();
+
     match format {
         OutputFormat::Pretty => {
             println!();
             println!("{}: {}", "Team".bold(), team.name);
             println!("{}: {}", "ID".dimmed(), team.id);
 
-            println!();
-            println!(
-                "{} ({}):",
-                "Members".bold(),
-                team.users.len().to_string().cyan()
-            );
-            if team.users.is_empty() {
-                println!("  {}", "(none)".dimmed());
-            } else {
-                for user in &team.users {
-                    let email = user.email.as_deref().unwrap_or("--");
-                    let name = user.user_name.as_deref().unwrap_or("--");
-                    let role = user.role.as_deref().unwrap_or("--");
-                    println!("  • {} ({}) [{}]", email, name, role.dimmed());
+            if sections.members {
+                println!();
+                println!(
+                    "{} ({}):",
+                    "Members".bold(),
+                    team.users.len().to_string().cyan()
+                );
+                if team.users.is_empty() {
+                    println!("  {}", "(none)".dimmed());
+                } else {
+                    for user in &team.users {
+                        let email = user.email.as_deref().unwrap_or("--");
+                        let name = user.user_name.as_deref().unwrap_or("--");
+                        let role = user.role.as_deref().unwrap_or("--");
+                        println!("  • {} ({}) [{}]", email, name, role.dimmed());
+                    }
                 }
             }
 
-            println!();
-            println!(
-                "{} ({}):",
-                "Applications".bold(),
-                team.applications.len().to_string().cyan()
-            );
-            if team.applications.is_empty() {
-                println!("  {}", "(none)".dimmed());
-            } else {
-                for app in &team.applications {
-                    let name = app.application_name.as_deref().unwrap_or("--");
-                    let envs = if app.environments.is_empty() {
-                        "--".to_string()
-                    } else {
-                        app.environments.join(", ")
-                    };
-                    println!("  • {} ({})", name, envs.dimmed());
+            if sections.apps {
+                println!();
+                println!(
+                    "{} ({}):",
+                    "Applications".bold(),
+                    team.applications.len().to_string().cyan()
+                );
+                if team.applications.is_empty() {
+                    println!("  {}", "(none)".dimmed());
+                } else {
+                    for app in &apps {
+                        println!("  • {} ({})", app.name, app.environments.dimmed());
+                    }
                 }
             }
             println!();
         }
-        OutputFormat::Table | OutputFormat::Json => {
-            // For table/json, output as JSON
-            let output = serde_json::json!({
-                "data": team,
-                "meta": {
-                    "version": env!("CARGO_PKG_VERSION"),
-                    "timestamp": chrono::Utc::now().to_rfc3339()
-                }
-            });
-            println!("{}", serde_json::to_string_pretty(&output)?);
-        }
+        OutputFormat::Table => match (sections.members, sections.apps) {
+            (true, false) => members.print(format)?,
+            (false, true) => apps.print(format)?,
+            _ => {
+                println!("{} ({})", "MEMBERS".bold(), members.len());
+                members.print(format)?;
+                println!();
+                println!("{} ({})", "APPLICATIONS".bold(), apps.len());
+                apps.print(format)?;
+            }
+        },
+        OutputFormat::Json => match (sections.members, sections.apps) {
+            (true, false) => members.print(format)?,
+            (false, true) => apps.print(format)?,
+            _ => println!("{}", crate::output::json::format_json(team)?),
+        },
     }
     Ok(())
 }
@@ -561,7 +553,7 @@ pub async fn list(
             empty.print(format)?;
         } else {
             eprintln!("No teams found.");
-            eprintln!("→ Create a team: hawkop team create <NAME>");
+            hint!("→ Create a team: hawkop team create <NAME>");
         }
         return Ok(());
     }
@@ -579,15 +571,27 @@ pub async fn list(
         .collect();
 
     let mut team_details: Vec<TeamDetail> = Vec::with_capacity(teams.len());
+    let mut failed = 0usize;
     while let Some(result) = futures.next().await {
         match result {
             Ok(detail) => team_details.push(detail),
             Err(e) => {
                 // Log error but continue with other teams
                 debug!("Failed to fetch team detail: {}", e);
+                failed += 1;
             }
         }
     }
+    if failed > 0 {
+        warn(
+            WarningKind::PartialResults,
+            format!(
+                "Failed to fetch details for {} of {} teams; they are omitted",
+                failed,
+                teams.len()
+            ),
+        );
+    }
 
     // Apply filters (client-side since API doesn't support them)
     let filtered_details: Vec<TeamDetail> = team_details
@@ -640,14 +644,14 @@ pub async fn list(
             eprintln!("No teams match the specified filters.");
         } else {
             eprintln!("No teams found.");
-            eprintln!("→ Create a team: hawkop team create <NAME>");
+            hint!("→ Create a team: hawkop team create <NAME>");
         }
         return Ok(());
     }
 
     // Sort by name for consistent output
     let mut sorted_details = filtered_details;
-    sorted_details.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+    sorted_details.sort_by_key(|a| a.name.to_lowercase());
 
     // Convert to display format
     let display_items: Vec<TeamListDisplay> = sorted_details
@@ -666,7 +670,11 @@ pub async fn list(
 // ============================================================================
 
 /// Get team details
-pub async fn get(opts: &GlobalOptions, team_identifier: &str) -> Result<()> {
+pub async fn get(
+    opts: &GlobalOptions,
+    team_identifier: &str,
+    sections: TeamSections,
+) -> Result<()> {
     let (org_id, client) = setup_team_context(opts).await?;
     let format = opts.format;
 
@@ -676,7 +684,7 @@ pub async fn get(opts: &GlobalOptions, team_identifier: &str) -> Result<()> {
     // Fetch team details
     let team = client.get_team(&org_id, &team_id).await?;
 
-    display_team_detail(&team, format)?;
+    display_team_detail(&team, sections, format)?;
 
     Ok(())
 }
@@ -691,7 +699,6 @@ pub async fn create(
     name: &str,
     users: Option<Vec<String>>,
     apps: Option<Vec<String>>,
-    dry_run: bool,
     force: bool,
 ) -> Result<()> {
     let (org_id, client) = setup_team_context(opts).await?;
@@ -734,7 +741,7 @@ pub async fn create(
 
     // Resolve app IDs if provided
     let app_ids = if let Some(ref app_list) = apps {
-        Some(resolve_apps(client.clone(), &org_id, app_list).await?)
+        Some(resolve_app_ids(&client, &org_id, app_list).await?)
     } else {
         None
     };
@@ -767,7 +774,7 @@ pub async fn create(
         }
     }
 
-    if dry_run {
+    if opts.dry_run {
         eprintln!("{}", "DRY RUN - no changes will be made".yellow());
         eprintln!();
         eprintln!("Would create team: {}", name.bold());
@@ -804,14 +811,7 @@ pub async fn create(
 
     match format {
         OutputFormat::Json => {
-            let output = serde_json::json!({
-                "data": team,
-                "meta": {
-                    "version": env!("CARGO_PKG_VERSION"),
-                    "timestamp": chrono::Utc::now().to_rfc3339()
-                }
-            });
-            println!("{}", serde_json::to_string_pretty(&output)?);
+            println!("{}", crate::output::json::format_json(&team)?);
         }
         _ => {
             eprintln!(
@@ -820,7 +820,7 @@ pub async fn create(
                 team.name,
                 team.id
             );
-            eprintln!("→ View team: hawkop team get {}", team.id);
+            hint!("→ View team: hawkop team get {}", team.id);
         }
     }
 
@@ -832,12 +832,7 @@ pub async fn create(
 // ============================================================================
 
 /// Rename a team
-pub async fn rename(
-    opts: &GlobalOptions,
-    team_identifier: &str,
-    new_name: &str,
-    dry_run: bool,
-) -> Result<()> {
+pub async fn rename(opts: &GlobalOptions, team_identifier: &str, new_name: &str) -> Result<()> {
     let (org_id, client) = setup_team_context(opts).await?;
     let format = opts.format;
 
@@ -877,7 +872,7 @@ pub async fn rename(
         }
     }
 
-    if dry_run {
+    if opts.dry_run {
         eprintln!("{}", "DRY RUN - no changes will be made".yellow());
         eprintln!();
         eprintln!(
@@ -919,14 +914,7 @@ pub async fn rename(
 
     match format {
         OutputFormat::Json => {
-            let output = serde_json::json!({
-                "data": team,
-                "meta": {
-                    "version": env!("CARGO_PKG_VERSION"),
-                    "timestamp": chrono::Utc::now().to_rfc3339()
-                }
-            });
-            println!("{}", serde_json::to_string_pretty(&output)?);
+            println!("{}", crate::output::json::format_json(&team)?);
         }
         _ => {
             eprintln!(
@@ -935,7 +923,7 @@ pub async fn rename(
                 current_team.name,
                 team.name
             );
-            eprintln!("→ View team: hawkop team get {}", team.id);
+            hint!("→ View team: hawkop team get {}", team.id);
         }
     }
 
@@ -947,12 +935,7 @@ pub async fn rename(
 // ============================================================================
 
 /// Delete a team
-pub async fn delete(
-    opts: &GlobalOptions,
-    team_identifier: &str,
-    yes: bool,
-    dry_run: bool,
-) -> Result<()> {
+pub async fn delete(opts: &GlobalOptions, team_identifier: &str, yes: bool) -> Result<()> {
     let (org_id, client) = setup_team_context(opts).await?;
     let format = opts.format;
 
@@ -960,7 +943,7 @@ pub async fn delete(
     let team_id = resolve_team(client.clone(), &org_id, team_identifier).await?;
     let team = client.get_team_fresh(&org_id, &team_id).await?;
 
-    if dry_run {
+    if opts.dry_run {
         eprintln!("{}", "DRY RUN - no changes will be made".yellow());
         eprintln!();
         eprintln!("Would delete team: \"{}\" (ID: {})", team.name, team.id);
@@ -995,7 +978,8 @@ pub async fn delete(
         let confirm = Confirm::new()
             .with_prompt("Confirm deletion?")
             .default(false)
-            .interact()?;
+            .interact()
+            .prompt_result()?;
 
         if !confirm {
             eprintln!("Cancelled.");
@@ -1007,18 +991,13 @@ pub async fn delete(
 
     match format {
         OutputFormat::Json => {
-            let output = serde_json::json!({
-                "data": { "deleted": true, "team_id": team_id, "team_name": team.name },
-                "meta": {
-                    "version": env!("CARGO_PKG_VERSION"),
-                    "timestamp": chrono::Utc::now().to_rfc3339()
-                }
-            });
-            println!("{}", serde_json::to_string_pretty(&output)?);
+            let data =
+                serde_json::json!({ "deleted": true, "team_id": team_id, "team_name": team.name });
+            println!("{}", crate::output::json::format_json(&data)?);
         }
         _ => {
             eprintln!("{} Team \"{}\" deleted", "✓".green(), team.name);
-            eprintln!("→ List teams: hawkop team list");
+            hint!("→ List teams: hawkop team list");
         }
     }
 
@@ -1035,7 +1014,6 @@ pub async fn add_user(
     team_identifier: &str,
     mut users: Vec<String>,
     stdin: bool,
-    dry_run: bool,
 ) -> Result<()> {
     let (org_id, client) = setup_team_context(opts).await?;
     let format = opts.format;
@@ -1057,7 +1035,7 @@ pub async fn add_user(
     let team = client.get_team_fresh(&org_id, &team_id).await?;
 
     // Early exit for dry-run: show preview without validating user existence
-    if dry_run {
+    if opts.dry_run {
         eprintln!("{}", "DRY RUN - no changes will be made".yellow());
         eprintln!();
         eprintln!(
@@ -1123,7 +1101,7 @@ pub async fn add_user(
                     "timestamp": chrono::Utc::now().to_rfc3339()
                 }
             });
-            println!("{}", serde_json::to_string_pretty(&output)?);
+            println!("{}", crate::output::json::format_raw_json(&output)?);
         }
         _ => {
             eprintln!(
@@ -1132,7 +1110,7 @@ pub async fn add_user(
                 users_to_add.len(),
                 updated.name
             );
-            eprintln!("→ View team: hawkop team get {}", updated.id);
+            hint!("→ View team: hawkop team get {}", updated.id);
         }
     }
 
@@ -1145,7 +1123,6 @@ pub async fn remove_user(
     team_identifier: &str,
     mut users: Vec<String>,
     stdin: bool,
-    dry_run: bool,
 ) -> Result<()> {
     let (org_id, client) = setup_team_context(opts).await?;
     let format = opts.format;
@@ -1167,7 +1144,7 @@ pub async fn remove_user(
     let team = client.get_team_fresh(&org_id, &team_id).await?;
 
     // Early exit for dry-run: show preview without validating user existence
-    if dry_run {
+    if opts.dry_run {
         eprintln!("{}", "DRY RUN - no changes will be made".yellow());
         eprintln!();
         eprintln!(
@@ -1232,7 +1209,7 @@ pub async fn remove_user(
                     "timestamp": chrono::Utc::now().to_rfc3339()
                 }
             });
-            println!("{}", serde_json::to_string_pretty(&output)?);
+            println!("{}", crate::output::json::format_raw_json(&output)?);
         }
         _ => {
             eprintln!(
@@ -1241,7 +1218,7 @@ pub async fn remove_user(
                 actually_removing,
                 updated.name
             );
-            eprintln!("→ View team: hawkop team get {}", updated.id);
+            hint!("→ View team: hawkop team get {}", updated.id);
         }
     }
 
@@ -1254,7 +1231,6 @@ pub async fn set_users(
     team_identifier: &str,
     mut users: Vec<String>,
     stdin: bool,
-    dry_run: bool,
     yes: bool,
 ) -> Result<()> {
     let (org_id, client) = setup_team_context(opts).await?;
@@ -1277,29 +1253,17 @@ pub async fn set_users(
 
     // Calculate diff
     let current_ids: HashSet<_> = team.users.iter().map(|u| u.user_id.clone()).collect();
-    let to_add: Vec<_> = new_user_ids.difference(&current_ids).cloned().collect();
-    let to_remove: Vec<_> = current_ids.difference(&new_user_ids).cloned().collect();
-    let unchanged: Vec<_> = new_user_ids.intersection(&current_ids).cloned().collect();
-
-    if dry_run {
+    let diff = SyncDiff::compute(&current_ids, &new_user_ids);
+    let SyncDiff {
+        to_add,
+        to_remove,
+        unchanged,
+    } = &diff;
+
+    if opts.dry_run {
         eprintln!("{}", "DRY RUN - no changes will be made".yellow());
         eprintln!();
-        eprintln!("Team \"{}\" member changes:", team.name);
-        if !to_add.is_empty() {
-            eprintln!("  {} Add: {}", "+".green(), to_add.len());
-            for id in &to_add {
-                eprintln!("    • {}", id);
-            }
-        }
-        if !to_remove.is_empty() {
-            eprintln!("  {} Remove: {}", "-".red(), to_remove.len());
-            for id in &to_remove {
-                eprintln!("    • {}", id);
-            }
-        }
-        if !unchanged.is_empty() {
-            eprintln!("  {} Unchanged: {}", "=".dimmed(), unchanged.len());
-        }
+        print_sync_diff(&format!("Team \"{}\" member changes:", team.name), &diff);
         return Ok(());
     }
 
@@ -1318,7 +1282,8 @@ pub async fn set_users(
         let confirm = Confirm::new()
             .with_prompt("Proceed with membership sync?")
             .default(false)
-            .interact()?;
+            .interact()
+            .prompt_result()?;
 
         if !confirm {
             eprintln!("Cancelled.");
@@ -1355,7 +1320,7 @@ pub async fn set_users(
                     "timestamp": chrono::Utc::now().to_rfc3339()
                 }
             });
-            println!("{}", serde_json::to_string_pretty(&output)?);
+            println!("{}", crate::output::json::format_raw_json(&output)?);
         }
         _ => {
             eprintln!(
@@ -1366,7 +1331,7 @@ pub async fn set_users(
                 to_remove.len(),
                 unchanged.len()
             );
-            eprintln!("→ View team: hawkop team get {}", updated.id);
+            hint!("→ View team: hawkop team get {}", updated.id);
         }
     }
 
@@ -1383,7 +1348,6 @@ pub async fn add_app(
     team_identifier: &str,
     mut apps: Vec<String>,
     stdin: bool,
-    dry_run: bool,
     force: bool,
 ) -> Result<()> {
     let (org_id, client) = setup_team_context(opts).await?;
@@ -1406,7 +1370,7 @@ pub async fn add_app(
     let team = client.get_team_fresh(&org_id, &team_id).await?;
 
     // Early exit for dry-run: show preview without validating app existence
-    if dry_run {
+    if opts.dry_run {
         eprintln!("{}", "DRY RUN - no changes will be made".yellow());
         eprintln!();
         eprintln!(
@@ -1421,7 +1385,7 @@ pub async fn add_app(
     }
 
     // Resolve app IDs (only when not dry-run)
-    let new_app_ids = resolve_apps(client.clone(), &org_id, &apps).await?;
+    let new_app_ids = resolve_app_ids(&client, &org_id, &apps).await?;
 
     // Get current app IDs
     let current_ids: HashSet<_> = team
@@ -1509,7 +1473,7 @@ pub async fn add_app(
                     "timestamp": chrono::Utc::now().to_rfc3339()
                 }
             });
-            println!("{}", serde_json::to_string_pretty(&output)?);
+            println!("{}", crate::output::json::format_raw_json(&output)?);
         }
         _ => {
             eprintln!(
@@ -1518,7 +1482,7 @@ pub async fn add_app(
                 apps_to_add.len(),
                 updated.name
             );
-            eprintln!("→ View team: hawkop team get {}", updated.id);
+            hint!("→ View team: hawkop team get {}", updated.id);
         }
     }
 
@@ -1531,7 +1495,6 @@ pub async fn remove_app(
     team_identifier: &str,
     mut apps: Vec<String>,
     stdin: bool,
-    dry_run: bool,
 ) -> Result<()> {
     let (org_id, client) = setup_team_context(opts).await?;
     let format = opts.format;
@@ -1553,7 +1516,7 @@ pub async fn remove_app(
     let team = client.get_team_fresh(&org_id, &team_id).await?;
 
     // Early exit for dry-run: show preview without validating app existence
-    if dry_run {
+    if opts.dry_run {
         eprintln!("{}", "DRY RUN - no changes will be made".yellow());
         eprintln!();
         eprintln!(
@@ -1568,7 +1531,7 @@ pub async fn remove_app(
     }
 
     // Resolve app IDs to remove (only when not dry-run)
-    let remove_ids: HashSet<_> = resolve_apps(client.clone(), &org_id, &apps)
+    let remove_ids: HashSet<_> = resolve_app_ids(&client, &org_id, &apps)
         .await?
         .into_iter()
         .collect();
@@ -1618,7 +1581,7 @@ pub async fn remove_app(
                     "timestamp": chrono::Utc::now().to_rfc3339()
                 }
             });
-            println!("{}", serde_json::to_string_pretty(&output)?);
+            println!("{}", crate::output::json::format_raw_json(&output)?);
         }
         _ => {
             eprintln!(
@@ -1627,7 +1590,7 @@ pub async fn remove_app(
                 actually_removing,
                 updated.name
             );
-            eprintln!("→ View team: hawkop team get {}", updated.id);
+            hint!("→ View team: hawkop team get {}", updated.id);
         }
     }
 
@@ -1640,7 +1603,6 @@ pub async fn set_apps(
     team_identifier: &str,
     mut apps: Vec<String>,
     stdin: bool,
-    dry_run: bool,
     yes: bool,
     force: bool,
 ) -> Result<()> {
@@ -1657,7 +1619,7 @@ pub async fn set_apps(
     let team = client.get_team_fresh(&org_id, &team_id).await?;
 
     // Resolve new app IDs
-    let new_app_ids: HashSet<_> = resolve_apps(client.clone(), &org_id, &apps)
+    let new_app_ids: HashSet<_> = resolve_app_ids(&client, &org_id, &apps)
         .await?
         .into_iter()
         .collect();
@@ -1668,14 +1630,17 @@ pub async fn set_apps(
         .iter()
         .map(|a| a.application_id.clone())
         .collect();
-    let to_add: Vec<_> = new_app_ids.difference(&current_ids).cloned().collect();
-    let to_remove: Vec<_> = current_ids.difference(&new_app_ids).cloned().collect();
-    let unchanged: Vec<_> = new_app_ids.intersection(&current_ids).cloned().collect();
+    let diff = SyncDiff::compute(&current_ids, &new_app_ids);
+    let SyncDiff {
+        to_add,
+        to_remove,
+        unchanged,
+    } = &diff;
 
     // Safety check: each app may only belong to one team at a time
     if !force && !to_add.is_empty() {
         let duplicates =
-            check_duplicate_app_assignments(client.clone(), &org_id, &team_id, &to_add).await?;
+            check_duplicate_app_assignments(client.clone(), &org_id, &team_id, to_add).await?;
 
         if !duplicates.is_empty() {
             let dup_list = duplicates
@@ -1700,25 +1665,13 @@ pub async fn set_apps(
         }
     }
 
-    if dry_run {
+    if opts.dry_run {
         eprintln!("{}", "DRY RUN - no changes will be made".yellow());
         eprintln!();
-        eprintln!("Team \"{}\" application changes:", team.name);
-        if !to_add.is_empty() {
-            eprintln!("  {} Add: {}", "+".green(), to_add.len());
-            for id in &to_add {
-                eprintln!("    • {}", id);
-            }
-        }
-        if !to_remove.is_empty() {
-            eprintln!("  {} Remove: {}", "-".red(), to_remove.len());
-            for id in &to_remove {
-                eprintln!("    • {}", id);
-            }
-        }
-        if !unchanged.is_empty() {
-            eprintln!("  {} Unchanged: {}", "=".dimmed(), unchanged.len());
-        }
+        print_sync_diff(
+            &format!("Team \"{}\" application changes:", team.name),
+            &diff,
+        );
         return Ok(());
     }
 
@@ -1737,7 +1690,8 @@ pub async fn set_apps(
         let confirm = Confirm::new()
             .with_prompt("Proceed with assignment sync?")
             .default(false)
-            .interact()?;
+            .interact()
+            .prompt_result()?;
 
         if !confirm {
             eprintln!("Cancelled.");
@@ -1778,7 +1732,7 @@ pub async fn set_apps(
                     "timestamp": chrono::Utc::now().to_rfc3339()
                 }
             });
-            println!("{}", serde_json::to_string_pretty(&output)?);
+            println!("{}", crate::output::json::format_raw_json(&output)?);
         }
         _ => {
             eprintln!(
@@ -1789,7 +1743,554 @@ pub async fn set_apps(
                 to_remove.len(),
                 unchanged.len()
             );
-            eprintln!("→ View team: hawkop team get {}", updated.id);
+            hint!("→ View team: hawkop team get {}", updated.id);
+        }
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// Import Command
+// ============================================================================
+
+/// One manifest team resolved against the organization
+struct ImportPlan {
+    name: String,
+    /// Current state of the team (None = create it)
+    existing: Option<TeamDetail>,
+    /// Desired member IDs (None = leave members as they are)
+    user_ids: Option<HashSet<String>>,
+    /// Desired application IDs (None = leave apps as they are)
+    app_ids: Option<HashSet<String>>,
+    members: Option<SyncDiff>,
+    apps: Option<SyncDiff>,
+}
+
+impl ImportPlan {
+    fn action(&self) -> &'static str {
+        match self.existing {
+            None => "create",
+            Some(_) if self.has_changes() => "update",
+            Some(_) => "unchanged",
+        }
+    }
+
+    fn has_changes(&self) -> bool {
+        self.members.as_ref().is_some_and(SyncDiff::has_changes)
+            || self.apps.as_ref().is_some_and(SyncDiff::has_changes)
+    }
+
+    fn removes(&self, diff: &Option<SyncDiff>) -> usize {
+        diff.as_ref().map_or(0, |d| d.to_remove.len())
+    }
+
+    fn outcome(&self, result: std::result::Result<&str, String>) -> TeamImportOutcome {
+        TeamImportOutcome::new(
+            &self.name,
+            self.action(),
+            self.members.as_ref(),
+            self.apps.as_ref(),
+            self.existing.as_ref().map(|t| t.id.clone()),
+            result,
+        )
+    }
+}
+
+/// Read a team manifest from `file`, or stdin when None
+///
+/// Files ending in .yaml/.yml and stdin starting with "teams:" are read as
+/// YAML, anything else as CSV.
+fn read_manifest(file: Option<&str>) -> Result<TeamManifest> {
+    use crate::error::Error;
+    use std::io::Read;
+
+    let (input, yaml) = match file {
+        Some(path) => {
+            let input = std::fs::read_to_string(path)
+                .map_err(|e| Error::Other(format!("Cannot read {}: {}", path, e)))?;
+            let lower = path.to_lowercase();
+            (input, lower.ends_with(".yaml") || lower.ends_with(".yml"))
+        }
+        None => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            let yaml = input.trim_start().starts_with("teams:");
+            (input, yaml)
+        }
+    };
+    let manifest = if yaml {
+        TeamManifest::from_yaml(&input)?
+    } else {
+        let records = crate::output::csv::parse_csv(&input)
+            .map_err(|e| Error::Other(format!("Invalid team manifest: {}", e)))?;
+        TeamManifest::from_csv(&records)?
+    };
+    if manifest.teams.is_empty() {
+        return Err(Error::Other("No teams in manifest.".to_string()));
+    }
+    Ok(manifest)
+}
+
+/// Create or update teams from a CSV or YAML manifest
+///
+/// The whole manifest is resolved and checked before anything changes:
+/// teams are matched by name, members and apps are resolved to IDs, and
+/// each managed side is diffed against the team's current state (the same
+/// reconciliation `set-users`/`set-apps` do for one team). Teams that lose
+/// apps are updated first so apps can move between teams in one import.
+pub async fn import(
+    opts: &GlobalOptions,
+    file: Option<&str>,
+    yes: bool,
+    force: bool,
+) -> Result<()> {
+    use crate::error::Error;
+
+    let manifest = read_manifest(file)?;
+    let (org_id, client) = setup_team_context(opts).await?;
+    let format = opts.format;
+
+    hint!("Resolving {} teams...", manifest.teams.len());
+    let existing_teams = fetch_all_teams(client.clone(), &org_id).await?;
+
+    let mut plans = Vec::with_capacity(manifest.teams.len());
+    for spec in &manifest.teams {
+        let in_team =
+            |e: crate::error::Error| Error::Other(format!("Team \"{}\": {}", spec.name, e));
+
+        let matches: Vec<_> = existing_teams
+            .iter()
+            .filter(|t| t.name.eq_ignore_ascii_case(&spec.name))
+            .collect();
+        let existing = match matches.as_slice() {
+            [] => None,
+            [team] => Some(client.get_team_fresh(&org_id, &team.id).await?),
+            _ => {
+                return Err(Error::Other(format!(
+                    "Multiple teams named \"{}\" exist. Rename or delete one before importing.",
+                    spec.name
+                )));
+            }
+        };
+
+        let user_ids: Option<HashSet<String>> = match &spec.members {
+            Some(members) => Some(
+                resolve_users(client.clone(), &org_id, members)
+                    .await
+                    .map_err(in_team)?
+                    .into_iter()
+                    .collect(),
+            ),
+            None => None,
+        };
+        let app_ids: Option<HashSet<String>> = match &spec.apps {
+            Some(apps) => Some(
+                resolve_app_ids(&client, &org_id, apps)
+                    .await
+                    .map_err(in_team)?
+                    .into_iter()
+                    .collect(),
+            ),
+            None => None,
+        };
+
+        let members = user_ids.as_ref().map(|desired| {
+            let current: HashSet<String> = existing
+                .iter()
+                .flat_map(|t| t.users.iter().map(|u| u.user_id.clone()))
+                .collect();
+            SyncDiff::compute(&current, desired)
+        });
+        let apps = app_ids.as_ref().map(|desired| {
+            let current: HashSet<String> = existing
+                .iter()
+                .flat_map(|t| t.applications.iter().map(|a| a.application_id.clone()))
+                .collect();
+            SyncDiff::compute(&current, desired)
+        });
+
+        plans.push(ImportPlan {
+            name: existing
+                .as_ref()
+                .map_or_else(|| spec.name.clone(), |t| t.name.clone()),
+            existing,
+            user_ids,
+            app_ids,
+            members,
+            apps,
+        });
+    }
+
+    // Safety check: each app may only belong to one team at a time
+    if !force {
+        let mut claimed: std::collections::HashMap<&str, &str> = std::collections::HashMap::new();
+        for plan in &plans {
+            for app_id in plan.app_ids.iter().flatten() {
+                if let Some(other) = claimed.insert(app_id, &plan.name) {
+                    return Err(Error::Other(format!(
+                        "App {} is listed for both \"{}\" and \"{}\".\n\n\
+                         Each app may only be assigned to one team at a time.",
+                        app_id, other, plan.name
+                    )));
+                }
+            }
+        }
+
+        let to_add: Vec<String> = plans
+            .iter()
+            .flat_map(|p| p.apps.iter().flat_map(|d| d.to_add.clone()))
+            .collect();
+        let duplicates = check_duplicate_app_assignments(client.clone(), &org_id, "", &to_add)
+            .await?
+            .into_iter()
+            // Apps leaving a team that is also in the manifest are free to move
+            .filter(|(_, app_id, other_team)| {
+                !plans.iter().any(|p| {
+                    p.name.eq_ignore_ascii_case(other_team)
+                        && p.app_ids.as_ref().is_some_and(|ids| !ids.contains(app_id))
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if !duplicates.is_empty() {
+            let dup_list = duplicates
+                .iter()
+                .map(|(app_name, app_id, other_team)| {
+                    format!(
+                        "  • \"{}\" (ID: {}) → already in team \"{}\"",
+                        app_name, app_id, other_team
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            return Err(Error::Other(format!(
+                "Cannot assign app(s) already in other teams:\n\n{}\n\n\
+                 Each app may only be assigned to one team at a time.\n\n\
+                 → Add the other team to the manifest without these apps\n\
+                 → Or use --force to override (not recommended)",
+                dup_list
+            )));
+        }
+    }
+
+    if opts.dry_run {
+        let outcomes: Vec<TeamImportOutcome> = plans
+            .iter()
+            .map(|plan| {
+                plan.outcome(Ok(match plan.action() {
+                    "create" => "would create",
+                    "update" => "would update",
+                    _ => "no changes",
+                }))
+            })
+            .collect();
+        eprintln!("{}", "DRY RUN - no changes will be made".yellow());
+        eprintln!();
+        match format {
+            OutputFormat::Json => println!("{}", crate::output::json::format_json(&outcomes)?),
+            _ => outcomes.print(format)?,
+        }
+        return Ok(());
+    }
+
+    // Confirmation if removing members or apps (unless --yes)
+    let removed_users: usize = plans.iter().map(|p| p.removes(&p.members)).sum();
+    let removed_apps: usize = plans.iter().map(|p| p.removes(&p.apps)).sum();
+    if !yes && removed_users + removed_apps > 0 {
+        eprintln!(
+            "{} This import removes {} member(s) and {} application(s) from existing teams.",
+            "⚠".yellow(),
+            removed_users,
+            removed_apps
+        );
+        eprintln!("  → Preview with --dry-run");
+        eprintln!();
+
+        let confirm = Confirm::new()
+            .with_prompt("Proceed with team import?")
+            .default(false)
+            .interact()
+            .prompt_result()?;
+
+        if !confirm {
+            eprintln!("Cancelled.");
+            return Ok(());
+        }
+    }
+
+    if force {
+        eprintln!(
+            "{} Using --force: bypassing duplicate app safety check",
+            "⚠".yellow()
+        );
+    }
+
+    // Teams giving up apps go first, then other updates, then new teams
+    let mut order: Vec<usize> = (0..plans.len()).collect();
+    order.sort_by_key(|&i| {
+        let plan = &plans[i];
+        match plan.existing {
+            Some(_) if plan.removes(&plan.apps) > 0 => 0,
+            Some(_) => 1,
+            None => 2,
+        }
+    });
+
+    let mut outcomes: Vec<Option<TeamImportOutcome>> = vec![None; plans.len()];
+    for i in order {
+        let plan = &plans[i];
+        let outcome = match &plan.existing {
+            Some(_) if !plan.has_changes() => plan.outcome(Ok("unchanged")),
+            Some(team) => {
+                let request = UpdateTeamRequest {
+                    team_id: team.id.clone(),
+                    organization_id: org_id.clone(),
+                    name: Some(team.name.clone()),
+                    user_ids: Some(match &plan.user_ids {
+                        Some(ids) => ids.iter().cloned().collect(),
+                        None => team.users.iter().map(|u| u.user_id.clone()).collect(),
+                    }),
+                    application_ids: Some(match &plan.app_ids {
+                        Some(ids) => ids.iter().cloned().collect(),
+                        None => team
+                            .applications
+                            .iter()
+                            .map(|a| a.application_id.clone())
+                            .collect(),
+                    }),
+                };
+                match client.update_team(&org_id, &team.id, request).await {
+                    Ok(_) => plan.outcome(Ok("updated")),
+                    Err(e) => plan.outcome(Err(e.to_string())),
+                }
+            }
+            None => {
+                let request = CreateTeamRequest {
+                    name: plan.name.clone(),
+                    organization_id: org_id.clone(),
+                    user_ids: plan
+                        .user_ids
+                        .as_ref()
+                        .map(|ids| ids.iter().cloned().collect()),
+                    application_ids: plan
+                        .app_ids
+                        .as_ref()
+                        .map(|ids| ids.iter().cloned().collect()),
+                };
+                match client.create_team(&org_id, request).await {
+                    Ok(team) => {
+                        let mut outcome = plan.outcome(Ok("created"));
+                        outcome.team_id = Some(team.id);
+                        outcome
+                    }
+                    Err(e) => plan.outcome(Err(e.to_string())),
+                }
+            }
+        };
+        outcomes[i] = Some(outcome);
+    }
+
+    let outcomes: Vec<TeamImportOutcome> = outcomes.into_iter().flatten().collect();
+    let failed = outcomes.iter().filter(|o| !o.success).count();
+    let total = outcomes.len();
+
+    match format {
+        OutputFormat::Json => println!("{}", crate::output::json::format_json(&outcomes)?),
+        _ => outcomes.print(format)?,
+    }
+
+    if failed == 0 {
+        eprintln!("{} Imported {} teams", "✓".green(), total);
+        hint!("→ View teams: hawkop team list");
+        Ok(())
+    } else {
+        eprintln!(
+            "{} Imported {} of {} teams ({} failed)",
+            "✗".red(),
+            total - failed,
+            total,
+            failed
+        );
+        Err(Error::Other(format!(
+            "{} of {} teams failed to import",
+            failed, total
+        )))
+    }
+}
+
+// ============================================================================
+// Audit Command
+// ============================================================================
+
+/// Compare team memberships with an IdP group export, changing nothing
+///
+/// The export is read like a `team import` manifest. Only the teams it
+/// lists are fetched; see [`TeamDrift::audit`] for what counts as drift.
+pub async fn audit(opts: &GlobalOptions, file: Option<&str>) -> Result<()> {
+    use crate::error::Error;
+    use futures::stream::{FuturesUnordered, StreamExt};
+
+    let manifest = read_manifest(file)?;
+    let (org_id, client) = setup_team_context(opts).await?;
+    let format = opts.format;
+
+    hint!("Auditing {} teams...", manifest.teams.len());
+    let existing_teams = fetch_all_teams(client.clone(), &org_id).await?;
+    let mut team_ids = Vec::new();
+    for spec in &manifest.teams {
+        let matches: Vec<&Team> = existing_teams
+            .iter()
+            .filter(|t| t.name.eq_ignore_ascii_case(&spec.name))
+            .collect();
+        match matches.as_slice() {
+            [] => {}
+            [team] => team_ids.push(team.id.clone()),
+            _ => {
+                return Err(Error::Other(format!(
+                    "Multiple teams named \"{}\" exist. Rename or delete one before auditing.",
+                    spec.name
+                )));
+            }
+        }
+    }
+
+    let users = fetch_all_users(client.clone(), &org_id).await?;
+    let mut futures: FuturesUnordered<_> = team_ids
+        .iter()
+        .map(|team_id| {
+            let c = client.clone();
+            let org = org_id.clone();
+            async move { c.get_team_fresh(&org, team_id).await }
+        })
+        .collect();
+    let mut teams: Vec<TeamDetail> = Vec::with_capacity(team_ids.len());
+    while let Some(result) = futures.next().await {
+        teams.push(result?);
+    }
+
+    let drift = TeamDrift::audit(&manifest, &teams, &users);
+    let count = |kind: DriftKind| drift.iter().filter(|d| d.drift == kind).count();
+
+    match format {
+        OutputFormat::Json => println!("{}", crate::output::json::format_json(&drift)?),
+        _ if drift.is_empty() => {}
+        _ => drift.print(format)?,
+    }
+
+    if drift.is_empty() {
+        eprintln!(
+            "{} {} teams match the export",
+            "✓".green(),
+            manifest.teams.len()
+        );
+    } else {
+        eprintln!(
+            "{} {} missing member(s), {} extra member(s), {} missing team(s), {} unknown user(s)",
+            "⚠".yellow(),
+            count(DriftKind::MissingMember),
+            count(DriftKind::ExtraMember),
+            count(DriftKind::MissingTeam),
+            count(DriftKind::UnknownUser)
+        );
+        let source = file.map_or_else(|| "--stdin".to_string(), |f| format!("--file {}", f));
+        hint!("→ Preview the fix: hawkop team import {} --dry-run", source);
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Report Command
+// ============================================================================
+
+/// Show a team's recent activity
+///
+/// Fetches the team, the scans of its applications, and the audit log for
+/// the window, then joins them in [`TeamActivityReport`]. The audit log is
+/// admin-only; if it can't be read the report still shows scans.
+pub async fn report(opts: &GlobalOptions, team_identifier: &str, since: &str) -> Result<()> {
+    let (org_id, client) = setup_team_context(opts).await?;
+    let start_ms = timeparse::parse_millis(since)?;
+
+    let team_id = resolve_team(client.clone(), &org_id, team_identifier).await?;
+    let team = client.get_team(&org_id, &team_id).await?;
+    let app_ids: Vec<String> = team
+        .applications
+        .iter()
+        .map(|a| a.application_id.clone())
+        .collect();
+
+    let mut audit_filters = AuditFilterParams::new();
+    audit_filters.start = Some(start_ms);
+    audit_filters.sort_dir = Some("desc".to_string());
+    audit_filters.page_size = Some(AUDIT_PAGE_MAX);
+
+    let scans = async {
+        if app_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let filters = ScanFilterParams::new().start(start_ms).app_ids(app_ids);
+        fetch_all_scans(client.clone(), &org_id, Some(filters)).await
+    };
+    let (scans, audit) = tokio::join!(scans, client.list_audit(&org_id, Some(&audit_filters)));
+    let scans = scans?;
+    let audit = match audit {
+        Ok(records) => {
+            if records.len() >= AUDIT_PAGE_MAX {
+                warn(
+                    WarningKind::Truncated,
+                    format!(
+                        "Only the latest {} audit records were read; use a shorter --since to see all changes",
+                        AUDIT_PAGE_MAX
+                    ),
+                );
+            }
+            records
+        }
+        Err(e) => {
+            debug!("Failed to read audit log: {}", e);
+            warn(
+                WarningKind::PartialResults,
+                "Could not read the audit log (needs an org admin API key); showing scans only",
+            );
+            Vec::new()
+        }
+    };
+    debug!(
+        "Team report: {} scans, {} audit records since {}",
+        scans.len(),
+        audit.len(),
+        since
+    );
+
+    let report = TeamActivityReport::build(&team, since, &scans, &audit);
+
+    match opts.format {
+        OutputFormat::Json => {
+            println!("{}", crate::output::json::format_json(&report)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            println!(
+                "Team activity for {} since {} | {} members, {} apps",
+                report.team, since, report.members, report.applications
+            );
+            println!("\n{} ({})", "Recent scans".bold(), report.scans.len());
+            report.scans.print(opts.format)?;
+            println!(
+                "\n{} ({})",
+                "Config changes".bold(),
+                report.config_changes.len()
+            );
+            report.config_changes.print(opts.format)?;
+            println!(
+                "\n{} ({})",
+                "Membership changes".bold(),
+                report.membership_changes.len()
+            );
+            report.membership_changes.print(opts.format)?;
+            println!("\n{}", "Member activity".bold());
+            report.member_activity.print(opts.format)?;
         }
     }
 
@@ -1854,7 +2355,7 @@ mod tests {
     // Integration test notes
     // ========================================================================
 
-    // The resolve_team, resolve_users, and resolve_apps functions require a full
+    // The resolve_team and resolve_users functions require a full
     // CachedStackHawkClient<StackHawkClient> which makes unit testing complex.
     // These functions are best tested through:
     // 1. Integration tests with the actual CLI
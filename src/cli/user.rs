@@ -1,20 +1,101 @@
 //! User management commands
 
-use crate::cli::PaginationArgs;
+use std::collections::HashMap;
+
+use log::debug;
+
 use crate::cli::args::GlobalOptions;
-use crate::cli::handlers::run_list_command;
-use crate::client::ListingApi;
-use crate::client::models::User;
+use crate::cli::handlers::fetch_listing;
+use crate::cli::team::fetch_all_teams;
+use crate::cli::{CommandContext, PaginationArgs};
+use crate::client::{ListingApi, TeamApi};
 use crate::error::Result;
-use crate::models::UserDisplay;
+use crate::models::{UserDisplay, UserTeamsDisplay};
+use crate::output::Formattable;
+use crate::output::warnings::{WarningKind, warn};
 
 /// Run the user list command
-pub async fn list(opts: &GlobalOptions, pagination: &PaginationArgs) -> Result<()> {
-    run_list_command::<User, UserDisplay, _, _>(
-        opts,
-        pagination,
-        "users",
-        |client, org_id, params| async move { client.list_users(&org_id, Some(&params)).await },
-    )
-    .await
+///
+/// Roles come from each member's organization memberships. With `--teams`,
+/// every team is fetched to show the member's teams and team roles.
+pub async fn list(opts: &GlobalOptions, pagination: &PaginationArgs, teams: bool) -> Result<()> {
+    use futures::stream::{FuturesUnordered, StreamExt};
+
+    let ctx = CommandContext::new(opts).await?;
+    let org_id = ctx.require_org_id()?.to_string();
+
+    debug!("Fetching users for org {}", org_id);
+    let client = ctx.client.clone();
+    let org = org_id.clone();
+    let mut users = fetch_listing(pagination, "users", move |params| {
+        let client = client.clone();
+        let org = org.clone();
+        async move { client.list_users(&org, Some(&params)).await }
+    })
+    .await?;
+    if let Some(limit) = pagination.limit {
+        users.truncate(limit);
+    }
+    debug!("Fetched {} users", users.len());
+
+    if !teams {
+        let display: Vec<UserDisplay> = users
+            .iter()
+            .map(|u| UserDisplay::for_org(u, &org_id))
+            .collect();
+        return display.print(ctx.format);
+    }
+
+    // Team detail is the only source of membership and team roles
+    let all_teams = fetch_all_teams(ctx.client.clone(), &org_id).await?;
+    let mut futures: FuturesUnordered<_> = all_teams
+        .iter()
+        .map(|team| {
+            let c = ctx.client.clone();
+            let org = org_id.clone();
+            let team_id = team.id.clone();
+            async move { c.get_team(&org, &team_id).await }
+        })
+        .collect();
+
+    let mut teams_by_user: HashMap<String, Vec<(String, Option<String>)>> = HashMap::new();
+    let mut failed = 0usize;
+    while let Some(result) = futures.next().await {
+        match result {
+            Ok(detail) => {
+                for member in &detail.users {
+                    teams_by_user
+                        .entry(member.user_id.clone())
+                        .or_default()
+                        .push((detail.name.clone(), member.role.clone()));
+                }
+            }
+            Err(e) => {
+                debug!("Failed to fetch team detail: {}", e);
+                failed += 1;
+            }
+        }
+    }
+    if failed > 0 {
+        warn(
+            WarningKind::PartialResults,
+            format!(
+                "Failed to fetch details for {} of {} teams; team memberships may be incomplete",
+                failed,
+                all_teams.len()
+            ),
+        );
+    }
+
+    let display: Vec<UserTeamsDisplay> = users
+        .iter()
+        .map(|u| {
+            let teams = teams_by_user
+                .get(&u.external.id)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            UserTeamsDisplay::new(UserDisplay::for_org(u, &org_id), teams)
+        })
+        .collect();
+    display.print(ctx.format)
 }
@@ -1,15 +0,0 @@
-//! Authentication models
-
-use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
-
-/// JWT authentication token
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct JwtToken {
-    /// The JWT token string
-    pub token: String,
-
-    /// Token expiration time
-    #[serde(rename = "expiresAt")]
-    pub expires_at: DateTime<Utc>,
-}
@@ -1,21 +0,0 @@
-//! Organization models
-
-use serde::{Deserialize, Serialize};
-
-/// Organization resource
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Organization {
-    /// Organization ID
-    pub id: String,
-
-    /// Organization name
-    pub name: String,
-
-    /// Number of users (optional, may not be in all responses)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_count: Option<usize>,
-
-    /// Number of applications (optional)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub app_count: Option<usize>,
-}
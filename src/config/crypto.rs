@@ -0,0 +1,244 @@
+//! Passphrase encryption for config secrets
+//!
+//! With `hawkop init --encrypt`, each profile's `api_key` and JWT are stored
+//! as `enc:v1:<base64(nonce | ciphertext)>`, sealed with ChaCha20-Poly1305
+//! under a key derived from a passphrase (PBKDF2-HMAC-SHA256). The salt and
+//! iteration count live in the config's `encryption` section. The field name
+//! is authenticated too, so a sealed value cannot be moved to another field.
+//!
+//! The passphrase comes from `HAWKOP_PASSPHRASE` or an interactive prompt;
+//! the derived key is cached for the rest of the process.
+
+use std::collections::HashMap;
+use std::io::IsTerminal;
+use std::num::NonZeroU32;
+use std::sync::{Mutex, OnceLock};
+
+use base64::{Engine as _, engine::general_purpose};
+use ring::aead::{Aad, CHACHA20_POLY1305, LessSafeKey, NONCE_LEN, Nonce, UnboundKey};
+use ring::pbkdf2;
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ConfigError, PromptResult, Result};
+
+/// Environment variable holding the config passphrase
+pub const PASSPHRASE_ENV: &str = "HAWKOP_PASSPHRASE";
+
+/// Prefix marking an encrypted value
+const PREFIX: &str = "enc:v1:";
+
+/// PBKDF2 iterations for new configs (OWASP 2023 recommendation)
+const DEFAULT_ITERATIONS: u32 = 600_000;
+
+const SALT_LEN: usize = 16;
+const KEY_LEN: usize = 32;
+
+/// Derived keys by salt, so each passphrase is asked for and stretched once
+static KEYS: OnceLock<Mutex<HashMap<String, [u8; KEY_LEN]>>> = OnceLock::new();
+
+/// Key derivation settings stored in the config file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EncryptionSettings {
+    /// Key derivation function (only `pbkdf2-sha256`)
+    pub kdf: String,
+    /// PBKDF2 iteration count
+    pub iterations: u32,
+    /// Base64 salt
+    pub salt: String,
+}
+
+impl EncryptionSettings {
+    /// Fresh settings with a random salt
+    pub fn generate() -> Result<Self> {
+        let mut salt = [0u8; SALT_LEN];
+        SystemRandom::new()
+            .fill(&mut salt)
+            .map_err(|_| ConfigError::Encryption("no secure random source".to_string()))?;
+        Ok(Self {
+            kdf: "pbkdf2-sha256".to_string(),
+            iterations: DEFAULT_ITERATIONS,
+            salt: general_purpose::STANDARD.encode(salt),
+        })
+    }
+
+    /// Derive and cache the key for these settings
+    pub fn unlock_with(&self, passphrase: &str) -> Result<()> {
+        if self.kdf != "pbkdf2-sha256" {
+            return Err(ConfigError::Encryption(format!("unsupported kdf '{}'", self.kdf)).into());
+        }
+        let salt = general_purpose::STANDARD
+            .decode(&self.salt)
+            .map_err(|e| ConfigError::Encryption(format!("invalid salt: {}", e)))?;
+        let iterations = NonZeroU32::new(self.iterations)
+            .ok_or_else(|| ConfigError::Encryption("iterations must be positive".to_string()))?;
+
+        let mut key = [0u8; KEY_LEN];
+        pbkdf2::derive(
+            pbkdf2::PBKDF2_HMAC_SHA256,
+            iterations,
+            &salt,
+            passphrase.as_bytes(),
+            &mut key,
+        );
+        keys().insert(self.salt.clone(), key);
+        Ok(())
+    }
+
+    /// Make sure a key is cached, asking for the passphrase if needed
+    pub fn unlock(&self) -> Result<()> {
+        if keys().contains_key(&self.salt) {
+            return Ok(());
+        }
+        let passphrase = read_passphrase("Config passphrase", false)?;
+        self.unlock_with(&passphrase)
+    }
+
+    /// Encrypt `plaintext` for `field` (values already sealed are kept)
+    pub fn seal(&self, field: &str, plaintext: &str) -> Result<String> {
+        if is_sealed(plaintext) {
+            return Ok(plaintext.to_string());
+        }
+        let key = self.key()?;
+
+        let mut nonce = [0u8; NONCE_LEN];
+        SystemRandom::new()
+            .fill(&mut nonce)
+            .map_err(|_| ConfigError::Encryption("no secure random source".to_string()))?;
+
+        let mut data = plaintext.as_bytes().to_vec();
+        key.seal_in_place_append_tag(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::from(field.as_bytes()),
+            &mut data,
+        )
+        .map_err(|_| ConfigError::Encryption("encryption failed".to_string()))?;
+
+        let mut out = nonce.to_vec();
+        out.extend_from_slice(&data);
+        Ok(format!(
+            "{}{}",
+            PREFIX,
+            general_purpose::STANDARD.encode(out)
+        ))
+    }
+
+    /// Decrypt a value sealed for `field` (plaintext values pass through)
+    pub fn open(&self, field: &str, value: &str) -> Result<String> {
+        let Some(encoded) = value.strip_prefix(PREFIX) else {
+            return Ok(value.to_string());
+        };
+        let key = self.key()?;
+
+        let bytes = general_purpose::STANDARD
+            .decode(encoded)
+            .map_err(|_| ConfigError::Encryption(format!("{} is corrupted", field)))?;
+        if bytes.len() < NONCE_LEN {
+            return Err(ConfigError::Encryption(format!("{} is corrupted", field)).into());
+        }
+        let (nonce, sealed) = bytes.split_at(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(nonce)
+            .map_err(|_| ConfigError::Encryption(format!("{} is corrupted", field)))?;
+
+        let mut data = sealed.to_vec();
+        let plaintext = key
+            .open_in_place(nonce, Aad::from(field.as_bytes()), &mut data)
+            .map_err(|_| {
+                // Drop the key so a retry asks again
+                keys().remove(&self.salt);
+                ConfigError::Encryption("wrong passphrase".to_string())
+            })?;
+        String::from_utf8(plaintext.to_vec())
+            .map_err(|_| ConfigError::Encryption(format!("{} is corrupted", field)).into())
+    }
+
+    fn key(&self) -> Result<LessSafeKey> {
+        let key = keys()
+            .get(&self.salt)
+            .copied()
+            .ok_or_else(|| ConfigError::Encryption("config is locked".to_string()))?;
+        let key = UnboundKey::new(&CHACHA20_POLY1305, &key)
+            .map_err(|_| ConfigError::Encryption("invalid key".to_string()))?;
+        Ok(LessSafeKey::new(key))
+    }
+}
+
+/// Whether a stored value is encrypted
+pub fn is_sealed(value: &str) -> bool {
+    value.starts_with(PREFIX)
+}
+
+/// Passphrase from `HAWKOP_PASSPHRASE`, or prompted on a terminal
+///
+/// `confirm` asks twice (for choosing a new passphrase).
+pub fn read_passphrase(prompt: &str, confirm: bool) -> Result<String> {
+    if let Ok(passphrase) = std::env::var(PASSPHRASE_ENV)
+        && !passphrase.is_empty()
+    {
+        return Ok(passphrase);
+    }
+    if !std::io::stdin().is_terminal() {
+        return Err(ConfigError::Encryption(format!(
+            "configuration is encrypted; set {} or run interactively",
+            PASSPHRASE_ENV
+        ))
+        .into());
+    }
+
+    let input = dialoguer::Password::new().with_prompt(prompt);
+    let input = if confirm {
+        input.with_confirmation("Confirm passphrase", "Passphrases don't match")
+    } else {
+        input
+    };
+    input.interact().prompt_result()
+}
+
+fn keys() -> std::sync::MutexGuard<'static, HashMap<String, [u8; KEY_LEN]>> {
+    KEYS.get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings() -> EncryptionSettings {
+        EncryptionSettings {
+            iterations: 10,
+            ..EncryptionSettings::generate().unwrap()
+        }
+    }
+
+    #[test]
+    fn test_seal_and_open_round_trip() {
+        let settings = settings();
+        settings.unlock_with("correct horse").unwrap();
+
+        let sealed = settings.seal("api_key", "hawk.secret.key").unwrap();
+        assert!(is_sealed(&sealed));
+        assert!(!sealed.contains("secret"));
+        assert_eq!(settings.seal("api_key", &sealed).unwrap(), sealed);
+        assert_eq!(
+            settings.open("api_key", &sealed).unwrap(),
+            "hawk.secret.key"
+        );
+
+        // Plaintext passes through (migration from unencrypted configs)
+        assert_eq!(settings.open("api_key", "plain").unwrap(), "plain");
+        // Sealed values are bound to their field
+        assert!(settings.open("jwt", &sealed).is_err());
+    }
+
+    #[test]
+    fn test_wrong_passphrase_fails() {
+        let settings = settings();
+        settings.unlock_with("right").unwrap();
+        let sealed = settings.seal("jwt", "token").unwrap();
+
+        settings.unlock_with("wrong").unwrap();
+        let err = settings.open("jwt", &sealed).unwrap_err().to_string();
+        assert!(err.contains("wrong passphrase"));
+    }
+}
@@ -0,0 +1,484 @@
+//! Structural checks for hawkop's own `config.yaml`
+//!
+//! serde ignores keys it does not know and reports type errors without
+//! saying which setting is wrong, so the file is checked against the config
+//! schema first: YAML syntax, unknown keys (with the closest known key as a
+//! suggested fix), value types, missing required keys, and the deprecated
+//! single-profile (v1) layout. Every issue carries the 1-based line of its
+//! key where it can be found in block-style YAML.
+//!
+//! Preference keys that are not fields are per-command flag defaults; whether
+//! they name a real flag is checked when they are applied (and by
+//! `hawkop config lint`), not here.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_yaml::Value;
+
+use crate::models::display::app_duplicates::edit_distance;
+use crate::validation::find_line;
+
+/// How serious a config issue is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueKind {
+    /// The file is not valid YAML
+    Syntax,
+    /// A value has the wrong type, or a required key is missing
+    Type,
+    /// A key hawkop does not read
+    UnknownKey,
+    /// A value hawkop reads but cannot use
+    Value,
+    /// A setting or layout that still works but is on its way out
+    Deprecated,
+}
+
+impl IssueKind {
+    /// Whether the config cannot be loaded with this issue
+    pub fn is_error(self) -> bool {
+        matches!(self, Self::Syntax | Self::Type)
+    }
+}
+
+/// One problem found in a config file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConfigIssue {
+    /// Issue category
+    pub kind: IssueKind,
+
+    /// Dotted key path (e.g. `profiles.default.preferences.page_size`)
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub key: String,
+
+    /// 1-based line of the key, when it can be located
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+
+    /// What is wrong
+    pub message: String,
+
+    /// Suggested fix
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix: Option<String>,
+}
+
+impl ConfigIssue {
+    /// Issue at `path`, located in `source`
+    pub fn at(
+        source: &str,
+        path: &[&str],
+        kind: IssueKind,
+        message: String,
+        fix: Option<String>,
+    ) -> Self {
+        Self {
+            kind,
+            key: path.join("."),
+            line: find_line(source, path).map(|line| line as usize),
+            message,
+            fix,
+        }
+    }
+}
+
+/// Expected type of a config value
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    String,
+    /// Non-negative whole number
+    Count,
+    /// Whole number, possibly negative
+    Int,
+    Bool,
+    /// RFC 3339 timestamp
+    Timestamp,
+    /// String or number (sizes, durations)
+    Scalar,
+    /// Mapping with the given fields
+    Fields(&'static [Field]),
+    /// Mapping of free-form names to values of one kind
+    MapOf(&'static Kind),
+}
+
+impl Kind {
+    fn describe(self) -> &'static str {
+        match self {
+            Kind::String => "a string",
+            Kind::Count => "a whole number of at least 0",
+            Kind::Int => "a whole number",
+            Kind::Bool => "true or false",
+            Kind::Timestamp => "an RFC 3339 timestamp",
+            Kind::Scalar => "a string or number",
+            Kind::Fields(_) | Kind::MapOf(_) => "a mapping",
+        }
+    }
+
+    fn matches(self, value: &Value) -> bool {
+        match self {
+            Kind::String => value.is_string(),
+            Kind::Count => value.is_u64(),
+            Kind::Int => value.is_i64() || value.is_u64(),
+            Kind::Bool => value.is_bool(),
+            Kind::Timestamp => value
+                .as_str()
+                .is_some_and(|s| s.parse::<DateTime<Utc>>().is_ok()),
+            Kind::Scalar => value.is_string() || value.is_number(),
+            Kind::Fields(_) | Kind::MapOf(_) => value.is_mapping(),
+        }
+    }
+}
+
+/// A known key of a config section
+#[derive(Debug)]
+struct Field {
+    key: &'static str,
+    kind: Kind,
+    required: bool,
+}
+
+const fn field(key: &'static str, kind: Kind) -> Field {
+    Field {
+        key,
+        kind,
+        required: false,
+    }
+}
+
+const fn required(key: &'static str, kind: Kind) -> Field {
+    Field {
+        key,
+        kind,
+        required: true,
+    }
+}
+
+const JWT: &[Field] = &[
+    required("token", Kind::String),
+    required("expires_at", Kind::Timestamp),
+    field("clock_skew_secs", Kind::Int),
+];
+
+const PREFERENCES: &[Field] = &[
+    field("format", Kind::String),
+    field("page_size", Kind::Count),
+    field("history", Kind::Bool),
+    field("retries", Kind::Count),
+    field("rate_limits", Kind::MapOf(&Kind::Count)),
+];
+
+const PROFILE: &[Field] = &[
+    field("api_key", Kind::String),
+    field("org_id", Kind::String),
+    field("api_host", Kind::String),
+    field("jwt", Kind::Fields(JWT)),
+    field("preferences", Kind::Fields(PREFERENCES)),
+];
+
+const CACHE: &[Field] = &[
+    field("enabled", Kind::Bool),
+    field("max_size", Kind::Scalar),
+    field("ttl", Kind::MapOf(&Kind::Scalar)),
+    field("redact_sensitive", Kind::Bool),
+];
+
+const ENCRYPTION: &[Field] = &[
+    required("kdf", Kind::String),
+    required("iterations", Kind::Count),
+    required("salt", Kind::String),
+];
+
+const JIRA: &[Field] = &[
+    required("url", Kind::String),
+    field("email", Kind::String),
+    field("api_token", Kind::String),
+    field("project", Kind::String),
+    field("issue_type", Kind::String),
+];
+
+const WEBHOOK: &[Field] = &[field("secret", Kind::String), field("retries", Kind::Count)];
+
+const INTEGRATIONS: &[Field] = &[
+    field("jira", Kind::Fields(JIRA)),
+    field("webhook", Kind::Fields(WEBHOOK)),
+];
+
+/// Top level of the profile-based (v2) layout
+const ROOT: &[Field] = &[
+    field("version", Kind::Count),
+    field("active_profile", Kind::String),
+    field("profiles", Kind::MapOf(&Kind::Fields(PROFILE))),
+    field("encryption", Kind::Fields(ENCRYPTION)),
+    field("cache", Kind::Fields(CACHE)),
+    field("integrations", Kind::Fields(INTEGRATIONS)),
+];
+
+/// Top level of the legacy single-profile (v1) layout
+const LEGACY_ROOT: &[Field] = &[
+    field("api_key", Kind::String),
+    field("org_id", Kind::String),
+    field("api_host", Kind::String),
+    field("jwt", Kind::Fields(JWT)),
+    field("preferences", Kind::Fields(PREFERENCES)),
+    field("cache", Kind::Fields(CACHE)),
+];
+
+/// Check a config file's structure
+pub fn lint(source: &str) -> Vec<ConfigIssue> {
+    let root: Value = match serde_yaml::from_str(source) {
+        Ok(value) => value,
+        Err(e) => {
+            return vec![ConfigIssue {
+                kind: IssueKind::Syntax,
+                key: String::new(),
+                line: e.location().map(|l| l.line()),
+                message: format!("Invalid YAML: {}", e),
+                fix: None,
+            }];
+        }
+    };
+    let Some(map) = root.as_mapping() else {
+        if root.is_null() {
+            return Vec::new();
+        }
+        return vec![ConfigIssue {
+            kind: IssueKind::Type,
+            key: String::new(),
+            line: Some(1),
+            message: "The config must be a YAML mapping".to_string(),
+            fix: Some("Run `hawkop init` to write a fresh config".to_string()),
+        }];
+    };
+
+    let mut issues = Vec::new();
+    let legacy_key = LEGACY_ROOT
+        .iter()
+        .filter(|f| f.key != "cache")
+        .find(|f| map.contains_key(f.key));
+    let is_legacy = !map.contains_key("profiles") && !map.contains_key("version");
+    match legacy_key {
+        Some(key) if is_legacy => {
+            issues.push(ConfigIssue::at(
+                source,
+                &[key.key],
+                IssueKind::Deprecated,
+                "Single-profile (v1) config layout".to_string(),
+                Some(
+                    "Move these settings under `profiles.default`; hawkop rewrites the file \
+                     this way the next time it saves it"
+                        .to_string(),
+                ),
+            ));
+            check_fields(source, &mut Vec::new(), map, LEGACY_ROOT, &mut issues);
+        }
+        _ => check_fields(source, &mut Vec::new(), map, ROOT, &mut issues),
+    }
+    issues
+}
+
+/// Check each key of `map` against `fields`, recursing into sections
+fn check_fields<'a>(
+    source: &str,
+    path: &mut Vec<&'a str>,
+    map: &'a serde_yaml::Mapping,
+    fields: &'static [Field],
+    issues: &mut Vec<ConfigIssue>,
+) {
+    // Preferences also hold per-command flag defaults under any other key
+    let open = path.last() == Some(&"preferences");
+
+    for (key, value) in map {
+        let Some(key) = key.as_str() else {
+            continue;
+        };
+        path.push(key);
+        match fields.iter().find(|f| f.key == key) {
+            Some(field) => check_value(source, path, value, field.kind, issues),
+            None if open => {}
+            None => {
+                let fix = closest(key, fields.iter().map(|f| f.key))
+                    .map(|known| format!("Did you mean `{}`?", known))
+                    .or_else(|| Some("Remove the key".to_string()));
+                issues.push(ConfigIssue::at(
+                    source,
+                    path,
+                    IssueKind::UnknownKey,
+                    format!("Unknown key `{}`; it is ignored", path.join(".")),
+                    fix,
+                ));
+            }
+        }
+        path.pop();
+    }
+
+    for field in fields.iter().filter(|f| f.required) {
+        if map.get(field.key).is_none_or(Value::is_null) {
+            issues.push(ConfigIssue::at(
+                source,
+                path,
+                IssueKind::Type,
+                format!(
+                    "Missing required key `{}`",
+                    path.iter()
+                        .chain([&field.key])
+                        .copied()
+                        .collect::<Vec<_>>()
+                        .join(".")
+                ),
+                None,
+            ));
+        }
+    }
+}
+
+fn check_value<'a>(
+    source: &str,
+    path: &mut Vec<&'a str>,
+    value: &'a Value,
+    kind: Kind,
+    issues: &mut Vec<ConfigIssue>,
+) {
+    // Unset optional values are the same as leaving the key out
+    if value.is_null() {
+        return;
+    }
+    if !kind.matches(value) {
+        issues.push(ConfigIssue::at(
+            source,
+            path,
+            IssueKind::Type,
+            format!(
+                "`{}` must be {} (got {})",
+                path.join("."),
+                kind.describe(),
+                describe_value(value)
+            ),
+            None,
+        ));
+        return;
+    }
+    match (kind, value.as_mapping()) {
+        (Kind::Fields(fields), Some(map)) => check_fields(source, path, map, fields, issues),
+        (Kind::MapOf(inner), Some(map)) => {
+            for (key, value) in map {
+                let Some(key) = key.as_str() else {
+                    continue;
+                };
+                path.push(key);
+                check_value(source, path, value, *inner, issues);
+                path.pop();
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Short description of a YAML value for type errors
+fn describe_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => format!("\"{}\"", s),
+        Value::Number(n) => n.to_string(),
+        Value::Bool(b) => b.to_string(),
+        Value::Sequence(_) => "a list".to_string(),
+        Value::Mapping(_) => "a mapping".to_string(),
+        Value::Null => "nothing".to_string(),
+        Value::Tagged(_) => "a tagged value".to_string(),
+    }
+}
+
+/// Names of the `preferences` fields (other keys are flag defaults)
+pub fn preference_fields() -> impl Iterator<Item = &'static str> {
+    PREFERENCES.iter().map(|f| f.key)
+}
+
+/// The candidate within two edits of `key`, if any
+pub fn closest<'a>(key: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    candidates
+        .map(|c| (edit_distance(key, c), c))
+        .filter(|(distance, _)| *distance <= 2)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, c)| c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID: &str = "\
+version: 2
+active_profile: default
+profiles:
+  default:
+    api_key: hawk.xxx
+    org_id: org-1
+    jwt:
+      token: eyJ
+      expires_at: 2026-01-01T00:00:00Z
+    preferences:
+      page_size: 500
+      scan.list.limit: 50
+cache:
+  ttl:
+    scan_list: 5m
+";
+
+    #[test]
+    fn test_valid_config_has_no_issues() {
+        assert_eq!(lint(VALID), Vec::new());
+        assert_eq!(lint(""), Vec::new());
+    }
+
+    #[test]
+    fn test_unknown_keys_suggest_fixes() {
+        let source = VALID
+            .replace("    org_id: org-1", "    org_idd: org-1")
+            .replace("  ttl:", "  ttls:");
+        let issues = lint(&source);
+
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].kind, IssueKind::UnknownKey);
+        assert_eq!(issues[0].key, "profiles.default.org_idd");
+        assert_eq!(issues[0].line, Some(6));
+        assert_eq!(issues[0].fix.as_deref(), Some("Did you mean `org_id`?"));
+        assert_eq!(issues[1].key, "cache.ttls");
+        assert_eq!(issues[1].line, Some(14));
+    }
+
+    #[test]
+    fn test_type_errors() {
+        let source = VALID
+            .replace("page_size: 500", "page_size: lots")
+            .replace("expires_at: 2026-01-01T00:00:00Z", "expires_at: soon");
+        let issues = lint(&source);
+
+        assert_eq!(issues.len(), 2);
+        assert!(issues.iter().all(|i| i.kind.is_error()));
+        assert_eq!(
+            issues[0].message,
+            "`profiles.default.jwt.expires_at` must be an RFC 3339 timestamp (got \"soon\")"
+        );
+        assert_eq!(issues[1].line, Some(11));
+
+        let issues = lint("profiles:\n  default:\n    jwt:\n      token: eyJ\n");
+        assert_eq!(
+            issues[0].message,
+            "Missing required key `profiles.default.jwt.expires_at`"
+        );
+        assert_eq!(issues[0].line, Some(3));
+    }
+
+    #[test]
+    fn test_legacy_layout_is_deprecated() {
+        let issues = lint("api_key: hawk.xxx\norg_id: org-1\npreferences:\n  format: json\n");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].kind, IssueKind::Deprecated);
+        assert_eq!(issues[0].line, Some(1));
+    }
+
+    #[test]
+    fn test_syntax_error_has_line() {
+        let issues = lint("profiles:\n  default: [unclosed\n");
+        assert_eq!(issues[0].kind, IssueKind::Syntax);
+        assert!(issues[0].line.is_some());
+    }
+}
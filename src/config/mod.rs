@@ -5,10 +5,24 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
 
+use crate::client::models::token_needs_refresh;
 use crate::error::{ConfigError, Result};
+use crate::output::warnings::{WarningKind, warn};
+
+pub mod crypto;
+pub mod lint;
+pub mod scan_sync;
+pub mod scan_template;
+pub mod tokens;
+pub mod transfer;
+pub mod write;
+
+pub use crypto::EncryptionSettings;
+pub use tokens::TokenStore;
+use write::{ConfigLock, write_atomic};
 
 /// Current config format version
 pub const CONFIG_VERSION: u32 = 2;
@@ -21,6 +35,84 @@ fn default_profile_name() -> String {
     "default".to_string()
 }
 
+/// Config file name inside the config directory
+const CONFIG_FILE: &str = "config.yaml";
+
+/// Pick the config directory: the platform one, unless only the legacy
+/// directory holds a config
+fn resolve_config_dir(platform: Option<PathBuf>, legacy: PathBuf) -> PathBuf {
+    match platform {
+        Some(dir) if dir.join(CONFIG_FILE).exists() || !legacy.join(CONFIG_FILE).exists() => dir,
+        _ => legacy,
+    }
+}
+
+/// Move every file from the legacy config directory into `to`
+///
+/// Returns the files moved (or, with `dry_run`, that would be moved).
+/// Existing files in `to` are only replaced with `force`. The legacy
+/// directory is removed once empty.
+pub fn migrate_config_dir(
+    from: &Path,
+    to: &Path,
+    dry_run: bool,
+    force: bool,
+) -> Result<Vec<PathBuf>> {
+    if !from.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut names = Vec::new();
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if target.exists() && !force {
+            return Err(ConfigError::Invalid(format!(
+                "{} already exists; use --force to replace it",
+                target.display()
+            ))
+            .into());
+        }
+        names.push(entry.file_name());
+    }
+    names.sort();
+    if dry_run {
+        return Ok(names.iter().map(|n| from.join(n)).collect());
+    }
+
+    std::fs::create_dir_all(to)?;
+    let mut moved = Vec::new();
+    for name in names {
+        let (source, target) = (from.join(&name), to.join(&name));
+        if target.is_dir() {
+            std::fs::remove_dir_all(&target)?;
+        }
+        move_path(&source, &target)?;
+        moved.push(source);
+    }
+    // Best effort: leave the directory if anything else appeared in it
+    let _ = std::fs::remove_dir(from);
+    Ok(moved)
+}
+
+/// Rename, falling back to copy and delete across filesystems
+fn move_path(source: &Path, target: &Path) -> Result<()> {
+    if std::fs::rename(source, target).is_ok() {
+        return Ok(());
+    }
+    if source.is_dir() {
+        std::fs::create_dir_all(target)?;
+        for entry in std::fs::read_dir(source)? {
+            let entry = entry?;
+            move_path(&entry.path(), &target.join(entry.file_name()))?;
+        }
+        std::fs::remove_dir(source)?;
+    } else {
+        std::fs::copy(source, target)?;
+        std::fs::remove_file(source)?;
+    }
+    Ok(())
+}
+
 /// Application configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
@@ -46,6 +138,120 @@ pub struct Config {
     /// User preferences
     #[serde(default)]
     pub preferences: Preferences,
+
+    /// Response cache settings
+    #[serde(default, skip_serializing_if = "CacheSettings::is_empty")]
+    pub cache: CacheSettings,
+}
+
+/// Response cache settings (`cache:` section, shared by all profiles)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CacheSettings {
+    /// Whether responses are cached (default: true); `false` turns the
+    /// cache off for every command, as `--no-cache` does for one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// Total cache size before least recently used entries are evicted
+    /// (bytes, or with a unit: `500MB`, `2GB`; default: unlimited)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_size: Option<serde_yaml::Value>,
+
+    /// TTL overrides keyed by data type (e.g. `scan_list: 5m`, `apps: 4h`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub ttl: BTreeMap<String, serde_yaml::Value>,
+
+    /// Redact `Authorization`, cookie, and API key headers in scan messages
+    /// before caching them
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub redact_sensitive: bool,
+}
+
+impl CacheSettings {
+    /// Whether nothing is overridden
+    pub fn is_empty(&self) -> bool {
+        self.enabled.is_none()
+            && self.max_size.is_none()
+            && self.ttl.is_empty()
+            && !self.redact_sensitive
+    }
+
+    /// Whether responses are cached
+    pub fn is_enabled(&self) -> bool {
+        self.enabled != Some(false)
+    }
+}
+
+/// Third-party services (`integrations:` section, shared by all profiles)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IntegrationSettings {
+    /// Jira site that `finding ticket` creates issues in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jira: Option<JiraSettings>,
+
+    /// Signing and retries for `--post-to`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<WebhookSettings>,
+}
+
+impl IntegrationSettings {
+    /// Whether no integration is configured
+    pub fn is_empty(&self) -> bool {
+        self.jira.is_none() && self.webhook.is_none()
+    }
+
+    /// Whether any secret is encrypted
+    fn any_sealed(&self) -> bool {
+        let jira = self.jira.as_ref().and_then(|j| j.api_token.as_deref());
+        let webhook = self.webhook.as_ref().and_then(|w| w.secret.as_deref());
+        jira.into_iter().chain(webhook).any(crypto::is_sealed)
+    }
+
+    /// Secrets with the field names they are sealed under
+    fn secrets_mut(&mut self) -> impl Iterator<Item = (&'static str, &mut String)> {
+        let jira = self.jira.as_mut().and_then(|j| j.api_token.as_mut());
+        let webhook = self.webhook.as_mut().and_then(|w| w.secret.as_mut());
+        jira.map(|s| ("jira_api_token", s))
+            .into_iter()
+            .chain(webhook.map(|s| ("webhook_secret", s)))
+    }
+}
+
+/// Jira connection (`integrations.jira`)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct JiraSettings {
+    /// Site URL (e.g. `https://acme.atlassian.net`)
+    pub url: String,
+
+    /// Account email; when set, the token is sent with basic auth (Jira
+    /// Cloud), otherwise as a bearer personal access token (Data Center)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    /// API token (Cloud) or personal access token (Data Center)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_token: Option<String>,
+
+    /// Project key used when `--jira-project` is not given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+
+    /// Issue type for new tickets (default: Bug)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_type: Option<String>,
+}
+
+/// Delivery settings for `--post-to` (`integrations.webhook`)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WebhookSettings {
+    /// Shared secret; when set, each body is signed with HMAC-SHA256 in the
+    /// `X-Hawkop-Signature` header
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+
+    /// Retries for failed posts (default: 3)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retries: Option<u32>,
 }
 
 /// JWT token with expiry
@@ -54,8 +260,41 @@ pub struct JwtToken {
     /// The JWT token string
     pub token: String,
 
-    /// Token expiration time
+    /// Token expiration time (server clock)
     pub expires_at: DateTime<Utc>,
+
+    /// Server clock minus local clock (seconds), measured at login
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub clock_skew_secs: i64,
+}
+
+/// `skip_serializing_if` helper for values that are zero by default
+pub(crate) fn is_zero(n: &i64) -> bool {
+    *n == 0
+}
+
+impl JwtToken {
+    /// Check if the token is expired or will expire soon (within 5 minutes),
+    /// correcting the local clock by the skew measured at login
+    pub fn needs_refresh(&self) -> bool {
+        token_needs_refresh(self.expires_at, self.clock_skew_secs, Utc::now())
+    }
+
+    /// Time until expiry in server time (negative once expired)
+    pub fn remaining(&self) -> chrono::Duration {
+        let server_now = Utc::now() + chrono::Duration::seconds(self.clock_skew_secs);
+        self.expires_at - server_now
+    }
+}
+
+impl From<&crate::client::models::JwtToken> for JwtToken {
+    fn from(jwt: &crate::client::models::JwtToken) -> Self {
+        Self {
+            token: jwt.token.clone(),
+            expires_at: jwt.expires_at,
+            clock_skew_secs: jwt.clock_skew_secs,
+        }
+    }
 }
 
 /// User preferences
@@ -68,6 +307,25 @@ pub struct Preferences {
     /// Default page size for API requests
     #[serde(default = "default_page_size")]
     pub page_size: usize,
+
+    /// Record executed commands locally for `hawkop history` / `hawkop rerun`
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub history: bool,
+
+    /// Retries for rate-limited and transient API failures (default: 3)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retries: Option<u32>,
+
+    /// Rate limit overrides in requests per minute, keyed by endpoint
+    /// category (`scan`, `user`, `app-list`, `app-org`, `org-invite`,
+    /// `default`), for orgs with raised API limits
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub rate_limits: BTreeMap<String, u32>,
+
+    /// Per-command flag defaults keyed by command path and flag
+    /// (e.g. `scan.list.limit: 50`, `audit.since: 30d`)
+    #[serde(flatten)]
+    pub command_defaults: BTreeMap<String, serde_yaml::Value>,
 }
 
 fn default_page_size() -> usize {
@@ -79,6 +337,10 @@ impl Default for Preferences {
         Self {
             format: None,
             page_size: default_page_size(),
+            history: false,
+            retries: None,
+            rate_limits: BTreeMap::new(),
+            command_defaults: BTreeMap::new(),
         }
     }
 }
@@ -86,12 +348,35 @@ impl Default for Preferences {
 #[allow(dead_code)]
 impl Config {
     /// Get the default config file path
+    ///
+    /// The platform config directory (`$XDG_CONFIG_HOME/hawkop` on Linux,
+    /// `%APPDATA%\hawkop` on Windows, `~/Library/Application Support/hawkop`
+    /// on macOS) is used unless only the legacy `~/.hawkop/config.yaml`
+    /// exists, so existing setups keep working until `hawkop config migrate`.
     pub fn default_path() -> Result<PathBuf> {
+        let dir = resolve_config_dir(Self::platform_dir(), Self::legacy_dir()?);
+        Ok(dir.join(CONFIG_FILE))
+    }
+
+    /// Last-429 record next to the config (`rate_limit.json`), read by
+    /// `status --verbose`
+    pub fn rate_limit_path() -> Option<PathBuf> {
+        let config_path = Self::default_path().ok()?;
+        Some(config_path.parent()?.join("rate_limit.json"))
+    }
+
+    /// Platform config directory for hawkop (None if the OS has none)
+    pub fn platform_dir() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("hawkop"))
+    }
+
+    /// Config directory used before platform directories (`~/.hawkop`)
+    pub fn legacy_dir() -> Result<PathBuf> {
         let home = dirs::home_dir().ok_or(ConfigError::Invalid(
             "Could not determine home directory".to_string(),
         ))?;
 
-        Ok(home.join(".hawkop").join("config.yaml"))
+        Ok(home.join(".hawkop"))
     }
 
     /// Resolve a config path, falling back to the default location
@@ -149,31 +434,14 @@ impl Config {
         let contents =
             serde_yaml::to_string(self).map_err(|e| ConfigError::SaveError(e.to_string()))?;
 
-        // Write to file
-        std::fs::write(&path, contents)?;
-
-        // Set file permissions to 600 on Unix systems
-        #[cfg(unix)]
-        {
-            use std::os::unix::fs::PermissionsExt;
-            let mut perms = std::fs::metadata(&path)?.permissions();
-            perms.set_mode(0o600);
-            std::fs::set_permissions(&path, perms)?;
-        }
-
-        Ok(())
+        // Replace the file (mode 600 on Unix) while no other process writes it
+        let _lock = ConfigLock::acquire(&path)?;
+        write_atomic(&path, &contents)
     }
 
     /// Check if the JWT token is expired or will expire soon (within 5 minutes)
     pub fn is_token_expired(&self) -> bool {
-        match &self.jwt {
-            None => true,
-            Some(jwt) => {
-                let now = Utc::now();
-                let buffer = chrono::Duration::minutes(5);
-                jwt.expires_at - buffer < now
-            }
-        }
+        self.jwt.as_ref().is_none_or(JwtToken::needs_refresh)
     }
 
     /// Validate that required configuration is present
@@ -194,6 +462,7 @@ impl Default for Config {
             api_host: None,
             jwt: None,
             preferences: Preferences::default(),
+            cache: CacheSettings::default(),
         }
     }
 }
@@ -221,7 +490,8 @@ pub struct ProfileConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub api_host: Option<String>,
 
-    /// Cached JWT token for this profile
+    /// JWT stored in the file by older versions; moved to the
+    /// [`TokenStore`] when the config is loaded
     #[serde(skip_serializing_if = "Option::is_none")]
     pub jwt: Option<JwtToken>,
 
@@ -231,18 +501,6 @@ pub struct ProfileConfig {
 }
 
 impl ProfileConfig {
-    /// Check if the JWT token is expired or will expire soon (within 5 minutes)
-    pub fn is_token_expired(&self) -> bool {
-        match &self.jwt {
-            None => true,
-            Some(jwt) => {
-                let now = Utc::now();
-                let buffer = chrono::Duration::minutes(5);
-                jwt.expires_at - buffer < now
-            }
-        }
-    }
-
     /// Validate that required configuration is present
     pub fn validate_auth(&self) -> Result<()> {
         if self.api_key.is_none() {
@@ -284,6 +542,34 @@ pub struct ProfiledConfig {
     /// Map of profile name to profile configuration
     #[serde(default)]
     pub profiles: HashMap<String, ProfileConfig>,
+
+    /// Passphrase encryption of API keys, JWTs, and integration secrets
+    /// (`hawkop init --encrypt`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encryption: Option<EncryptionSettings>,
+
+    /// Response cache settings
+    #[serde(default, skip_serializing_if = "CacheSettings::is_empty")]
+    pub cache: CacheSettings,
+
+    /// Third-party integrations
+    #[serde(default, skip_serializing_if = "IntegrationSettings::is_empty")]
+    pub integrations: IntegrationSettings,
+
+    /// The file as it was when this config was loaded
+    #[serde(skip)]
+    loaded: Option<LoadedFile>,
+}
+
+/// A config file as loaded, to spot writes by other processes before saving
+#[derive(Debug, Clone, Default)]
+struct LoadedFile {
+    /// Where the file is
+    path: PathBuf,
+    /// The file's contents
+    contents: String,
+    /// The config as loaded, secrets decrypted (None if they weren't)
+    values: Option<serde_yaml::Value>,
 }
 
 impl ProfiledConfig {
@@ -299,34 +585,143 @@ impl ProfiledConfig {
     }
 
     /// Load configuration from an optional path (or default), with auto-migration
+    ///
+    /// JWTs that older versions stored in the file are moved to the token
+    /// store (see [`ProfiledConfig::migrate_jwts`]).
     pub fn load_at(path: Option<&str>) -> Result<Self> {
         let path = Self::resolve_path(path)?;
-        Self::load_from(path)
+        let config = Self::load_from(path.clone())?;
+        if !config.profiles.values().any(|p| p.jwt.is_some()) {
+            return Ok(config);
+        }
+        match Self::migrate_jwts(&path) {
+            Ok(migrated) => Ok(migrated),
+            Err(e) => {
+                log::debug!("Could not move JWTs out of the config: {}", e);
+                Ok(config)
+            }
+        }
+    }
+
+    /// Move JWTs stored in the config file into the [`TokenStore`]
+    ///
+    /// Reads the file again under the config lock, so a JWT is moved by one
+    /// process only, and returns the config as saved. A profile's token
+    /// replaces the stored one unless that expires later. JWTs that can't be
+    /// stored stay in the file, to be moved on a later load.
+    fn migrate_jwts(path: &Path) -> Result<Self> {
+        let _lock = ConfigLock::acquire(path)?;
+        let mut config = Self::read_from(path.to_path_buf(), false)?;
+        config.decrypt_secrets()?;
+
+        let mut names: Vec<String> = config
+            .profiles
+            .iter()
+            .filter(|(_, p)| p.jwt.is_some())
+            .map(|(name, _)| name.clone())
+            .collect();
+        names.sort();
+        let mut moved = false;
+        for name in names {
+            let profile = &config.profiles[&name];
+            let Some(jwt) = profile.jwt.clone() else {
+                continue;
+            };
+            // Without an API key the token can't be used again; it is dropped
+            let stored = match TokenStore::for_profile(&config, profile, None) {
+                Some(store) if store.load().is_none_or(|s| s.expires_at < jwt.expires_at) => {
+                    store.save(&jwt)
+                }
+                _ => Ok(()),
+            };
+            match stored {
+                Ok(()) => {
+                    log::debug!("Moved JWT for profile '{}' to the token store", name);
+                    if let Some(profile) = config.profiles.get_mut(&name) {
+                        profile.jwt = None;
+                    }
+                    moved = true;
+                }
+                Err(e) => log::debug!("Could not move JWT for profile '{}': {}", name, e),
+            }
+        }
+        if moved {
+            let contents = config.write_locked(path)?;
+            let values = serde_yaml::to_value(&config).ok();
+            config.loaded = Some(LoadedFile {
+                path: path.to_path_buf(),
+                contents,
+                values,
+            });
+        }
+        Ok(config)
+    }
+
+    /// Cache directory for this config's tokens (None for the default
+    /// cache; see [`TokenStore::dir_for_config`])
+    pub fn token_dir(&self) -> Option<PathBuf> {
+        TokenStore::dir_for_config(&self.loaded.as_ref()?.path)
     }
 
     /// Load configuration from a specific path, with auto-migration from v1
+    ///
+    /// Encrypted secrets are decrypted, prompting for the passphrase (or
+    /// reading `HAWKOP_PASSPHRASE`) the first time in this process.
+    ///
+    /// Unknown keys and deprecated settings are reported as warnings (see
+    /// [`lint`]).
     pub fn load_from(path: PathBuf) -> Result<Self> {
+        let mut config = Self::read_from(path, true)?;
+        config.decrypt_secrets()?;
+        let values = serde_yaml::to_value(&config).ok();
+        if let Some(loaded) = &mut config.loaded {
+            loaded.values = values;
+        }
+        Ok(config)
+    }
+
+    /// Load configuration without decrypting secrets
+    ///
+    /// For reading preferences before arguments are parsed, where asking for
+    /// a passphrase would be premature. Encrypted `api_key` and JWT values
+    /// are left as stored.
+    pub fn load_sealed_at(path: Option<&str>) -> Result<Self> {
+        Self::load_sealed_from(Self::resolve_path(path)?)
+    }
+
+    fn load_sealed_from(path: PathBuf) -> Result<Self> {
+        Self::read_from(path, false)
+    }
+
+    /// Parse the config file, failing with the first structural error
+    fn read_from(path: PathBuf, warn_issues: bool) -> Result<Self> {
         if !path.exists() {
             return Err(ConfigError::NotFound.into());
         }
 
         let contents = std::fs::read_to_string(&path)?;
-
-        // Try v2 format first (has version and profiles fields)
-        #[allow(clippy::collapsible_if)]
-        if let Ok(config) = serde_yaml::from_str::<ProfiledConfig>(&contents) {
-            if config.version >= 2 && !config.profiles.is_empty() {
-                return Ok(config);
+        check_issues(&path, &contents, warn_issues)?;
+
+        // Try v2 format first (has version and profiles fields), falling
+        // back to v1 format and migrating
+        let mut config = match serde_yaml::from_str::<ProfiledConfig>(&contents) {
+            Ok(config) if config.version >= 2 && !config.profiles.is_empty() => config,
+            _ => {
+                let legacy: Config = serde_yaml::from_str(&contents).map_err(ConfigError::from)?;
+                Self::migrate_from_v1(legacy)
             }
-        }
-
-        // Fall back to v1 format and migrate
-        let legacy: Config = serde_yaml::from_str(&contents).map_err(ConfigError::from)?;
-        Ok(Self::migrate_from_v1(legacy))
+        };
+        config.loaded = Some(LoadedFile {
+            path,
+            contents,
+            values: None,
+        });
+        Ok(config)
     }
 
     /// Migrate from v1 (legacy) config format
-    fn migrate_from_v1(legacy: Config) -> Self {
+    fn migrate_from_v1(mut legacy: Config) -> Self {
+        let cache = std::mem::take(&mut legacy.cache);
         let profile = ProfileConfig::from(legacy);
         let mut profiles = HashMap::new();
         profiles.insert("default".to_string(), profile);
@@ -335,6 +730,10 @@ impl ProfiledConfig {
             version: CONFIG_VERSION,
             active_profile: "default".to_string(),
             profiles,
+            encryption: None,
+            cache,
+            integrations: IntegrationSettings::default(),
+            loaded: None,
         }
     }
 
@@ -418,32 +817,213 @@ impl ProfiledConfig {
     }
 
     /// Save configuration to a specific path
+    ///
+    /// Holds the config lock from reading the file to replacing it. If
+    /// another process wrote the file since this config was loaded, the file
+    /// is read again and only the settings this config changed are applied
+    /// to it (see [`ProfiledConfig::apply_to_current`]).
     pub fn save_to(&self, path: PathBuf) -> Result<()> {
         // Ensure parent directory exists
         if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent)?;
         }
 
-        // Serialize config
-        let contents =
-            serde_yaml::to_string(self).map_err(|e| ConfigError::SaveError(e.to_string()))?;
+        let _lock = ConfigLock::acquire(&path)?;
+        let current = self.apply_to_current(&path);
+        current.as_ref().unwrap_or(self).write_locked(&path)?;
+        Ok(())
+    }
+
+    /// Replace the file with this config; returns what was written
+    ///
+    /// The caller holds the path's [`ConfigLock`].
+    fn write_locked(&self, path: &Path) -> Result<String> {
+        // Serialize config, with secrets sealed if encryption is enabled
+        let contents = if self.encryption.is_some() {
+            let mut sealed = self.clone();
+            sealed.encrypt_secrets()?;
+            serde_yaml::to_string(&sealed)
+        } else {
+            serde_yaml::to_string(self)
+        }
+        .map_err(|e| ConfigError::SaveError(e.to_string()))?;
+
+        // Replace the file (mode 600 on Unix)
+        write_atomic(path, &contents)?;
+        Ok(contents)
+    }
+
+    /// The file as it is now, with this config's changes since it was
+    /// loaded applied
+    ///
+    /// None when the file is unchanged since the load, this config wasn't
+    /// loaded from a file, or the file can't be read again; this config is
+    /// then saved as it is. Each setting added, removed, or changed here
+    /// replaces the file's; everything else keeps what other processes wrote.
+    fn apply_to_current(&self, path: &Path) -> Option<Self> {
+        let loaded = self.loaded.as_ref()?;
+        let base = loaded.values.as_ref()?;
+        let contents = std::fs::read_to_string(path).ok()?;
+        if contents == loaded.contents {
+            return None;
+        }
+
+        let result = (|| -> Result<Self> {
+            let mut on_disk = Self::read_from(path.to_path_buf(), false)?;
+            on_disk.decrypt_secrets()?;
+            let to_value = |config: &Self| {
+                serde_yaml::to_value(config).map_err(|e| ConfigError::SaveError(e.to_string()))
+            };
+            let mut current = to_value(&on_disk)?;
+            apply_changes(base, &to_value(self)?, &mut current);
+            Ok(serde_yaml::from_value(current).map_err(ConfigError::from)?)
+        })();
+        match result {
+            Ok(config) => {
+                log::debug!("Applying changes to config written by another process");
+                Some(config)
+            }
+            Err(e) => {
+                log::debug!("Could not reload config before saving: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Whether secrets are encrypted at rest
+    pub fn is_encrypted(&self) -> bool {
+        self.encryption.is_some()
+    }
+
+    /// Encrypt secrets with `passphrase` from the next save on
+    ///
+    /// Secrets must already be decrypted (as after [`ProfiledConfig::load_from`]).
+    /// A new salt is generated, so this also changes the passphrase.
+    pub fn enable_encryption(&mut self, passphrase: &str) -> Result<()> {
+        let settings = EncryptionSettings::generate()?;
+        settings.unlock_with(passphrase)?;
+        self.encryption = Some(settings);
+        Ok(())
+    }
 
-        // Write to file
-        std::fs::write(&path, contents)?;
+    /// Store secrets in plaintext from the next save on
+    pub fn disable_encryption(&mut self) {
+        self.encryption = None;
+    }
 
-        // Set file permissions to 600 on Unix systems
-        #[cfg(unix)]
-        {
-            use std::os::unix::fs::PermissionsExt;
-            let mut perms = std::fs::metadata(&path)?.permissions();
-            perms.set_mode(0o600);
-            std::fs::set_permissions(&path, perms)?;
+    /// Decrypt each profile's API key and JWT, and integration secrets, in place
+    fn decrypt_secrets(&mut self) -> Result<()> {
+        let Some(settings) = &self.encryption else {
+            return Ok(());
+        };
+        let sealed = self.profiles.values().any(|p| {
+            p.api_key.as_deref().is_some_and(crypto::is_sealed)
+                || p.jwt.as_ref().is_some_and(|j| crypto::is_sealed(&j.token))
+        }) || self.integrations.any_sealed();
+        if !sealed {
+            return Ok(());
         }
 
+        settings.unlock()?;
+        for profile in self.profiles.values_mut() {
+            if let Some(key) = &mut profile.api_key {
+                *key = settings.open("api_key", key)?;
+            }
+            if let Some(jwt) = &mut profile.jwt {
+                jwt.token = settings.open("jwt", &jwt.token)?;
+            }
+        }
+        for (field, secret) in self.integrations.secrets_mut() {
+            *secret = settings.open(field, secret)?;
+        }
+        Ok(())
+    }
+
+    /// Encrypt each profile's API key and JWT, and integration secrets, in place
+    fn encrypt_secrets(&mut self) -> Result<()> {
+        let Some(settings) = &self.encryption else {
+            return Ok(());
+        };
+        settings.unlock()?;
+        for profile in self.profiles.values_mut() {
+            if let Some(key) = &mut profile.api_key {
+                *key = settings.seal("api_key", key)?;
+            }
+            if let Some(jwt) = &mut profile.jwt {
+                jwt.token = settings.seal("jwt", &jwt.token)?;
+            }
+        }
+        for (field, secret) in self.integrations.secrets_mut() {
+            *secret = settings.seal(field, secret)?;
+        }
         Ok(())
     }
 }
 
+/// Apply the changes from `base` to `ours` to `target`, key by key
+///
+/// Mappings are compared key by key; any other value (lists included) that
+/// differs from `base` replaces the target's whole.
+fn apply_changes(
+    base: &serde_yaml::Value,
+    ours: &serde_yaml::Value,
+    target: &mut serde_yaml::Value,
+) {
+    use serde_yaml::Value;
+
+    let (Value::Mapping(base), Value::Mapping(ours), Value::Mapping(target)) =
+        (base, ours, &mut *target)
+    else {
+        if base != ours {
+            *target = ours.clone();
+        }
+        return;
+    };
+    for (key, value) in ours {
+        match (base.get(key), target.get_mut(key)) {
+            (Some(old), _) if old == value => {}
+            (Some(old), Some(current)) => apply_changes(old, value, current),
+            _ => {
+                target.insert(key.clone(), value.clone());
+            }
+        }
+    }
+    for key in base.keys() {
+        if !ours.contains_key(key) {
+            target.remove(key);
+        }
+    }
+}
+
+/// Fail on the first structural error in a config file; with `warn_issues`,
+/// also warn about ignored keys and deprecated settings
+fn check_issues(path: &Path, contents: &str, warn_issues: bool) -> Result<()> {
+    let issues = lint::lint(contents);
+    let location = |issue: &lint::ConfigIssue| match issue.line {
+        Some(line) => format!("{}:{}", path.display(), line),
+        None => path.display().to_string(),
+    };
+
+    if let Some(error) = issues.iter().find(|i| i.kind.is_error()) {
+        return Err(ConfigError::ParseError(format!(
+            "{}: {}. Run `hawkop config lint` to see every problem",
+            location(error),
+            error.message
+        ))
+        .into());
+    }
+    if warn_issues {
+        for issue in &issues {
+            let kind = match issue.kind {
+                lint::IssueKind::Deprecated => WarningKind::Deprecated,
+                _ => WarningKind::IgnoredFlag,
+            };
+            warn(kind, format!("{}: {}", location(issue), issue.message));
+        }
+    }
+    Ok(())
+}
+
 impl Default for ProfiledConfig {
     fn default() -> Self {
         let mut profiles = HashMap::new();
@@ -453,6 +1033,10 @@ impl Default for ProfiledConfig {
             version: CONFIG_VERSION,
             active_profile: "default".to_string(),
             profiles,
+            encryption: None,
+            cache: CacheSettings::default(),
+            integrations: IntegrationSettings::default(),
+            loaded: None,
         }
     }
 }
@@ -482,6 +1066,7 @@ mod tests {
         config.jwt = Some(JwtToken {
             token: "test".to_string(),
             expires_at: Utc::now() - chrono::Duration::hours(1),
+            clock_skew_secs: 0,
         });
         assert!(config.is_token_expired());
 
@@ -489,6 +1074,7 @@ mod tests {
         config.jwt = Some(JwtToken {
             token: "test".to_string(),
             expires_at: Utc::now() + chrono::Duration::hours(1),
+            clock_skew_secs: 0,
         });
         assert!(!config.is_token_expired());
 
@@ -496,10 +1082,60 @@ mod tests {
         config.jwt = Some(JwtToken {
             token: "test".to_string(),
             expires_at: Utc::now() + chrono::Duration::minutes(2),
+            clock_skew_secs: 0,
         });
         assert!(config.is_token_expired());
     }
 
+    #[test]
+    fn test_token_expiry_accounts_for_clock_skew() {
+        // Local clock 2h behind the server: token with 1h "left" locally is expired
+        let mut config = Config {
+            jwt: Some(JwtToken {
+                token: "test".to_string(),
+                expires_at: Utc::now() + chrono::Duration::hours(1),
+                clock_skew_secs: 2 * 3600,
+            }),
+            ..Config::default()
+        };
+        assert!(config.is_token_expired());
+
+        // Local clock 2h ahead: token that looks expired locally is still valid
+        config.jwt = Some(JwtToken {
+            token: "test".to_string(),
+            expires_at: Utc::now() - chrono::Duration::hours(1),
+            clock_skew_secs: -2 * 3600,
+        });
+        assert!(!config.is_token_expired());
+    }
+
+    #[test]
+    fn test_token_remaining_uses_server_time() {
+        let jwt = JwtToken {
+            token: "test".to_string(),
+            expires_at: Utc::now() + chrono::Duration::hours(1),
+            clock_skew_secs: 1800,
+        };
+        let remaining = jwt.remaining().num_minutes();
+        assert!((29..=30).contains(&remaining), "remaining={}", remaining);
+    }
+
+    #[test]
+    fn test_jwt_clock_skew_omitted_when_zero() {
+        let jwt = JwtToken {
+            token: "test".to_string(),
+            expires_at: Utc::now(),
+            clock_skew_secs: 0,
+        };
+        let yaml = serde_yaml::to_string(&jwt).unwrap();
+        assert!(!yaml.contains("clock_skew_secs"));
+
+        // Existing configs without the field still load
+        let parsed: JwtToken =
+            serde_yaml::from_str("token: abc\nexpires_at: 2026-01-15T12:00:00Z\n").unwrap();
+        assert_eq!(parsed.clock_skew_secs, 0);
+    }
+
     // Profile-based config tests
 
     #[test]
@@ -518,6 +1154,7 @@ mod tests {
             api_host: Some("http://localhost:8080".to_string()),
             jwt: None,
             preferences: Preferences::default(),
+            cache: CacheSettings::default(),
         };
 
         let migrated = ProfiledConfig::migrate_from_v1(legacy);
@@ -663,17 +1300,160 @@ mod tests {
     }
 
     #[test]
-    fn test_profile_config_token_expiry() {
-        let mut profile = ProfileConfig::default();
+    fn test_save_keeps_changes_by_another_process() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.yaml");
 
-        // No token should be expired
-        assert!(profile.is_token_expired());
+        let mut config = ProfiledConfig::default();
+        config.get_profile_mut("default").unwrap().org_id = Some("org-1".to_string());
+        config.profiles.insert(
+            "work".to_string(),
+            ProfileConfig {
+                api_key: Some("hawk.work".to_string()),
+                ..Default::default()
+            },
+        );
+        config.save_to(path.clone()).unwrap();
+
+        // This process loads the config, then another changes it
+        let mut ours = ProfiledConfig::load_from(path.clone()).unwrap();
+        let mut theirs = ProfiledConfig::load_from(path.clone()).unwrap();
+        theirs.get_profile_mut("default").unwrap().org_id = Some("org-2".to_string());
+        theirs.get_profile_mut("work").unwrap().api_host = Some("https://a.test".to_string());
+        theirs.cache.redact_sensitive = true;
+        theirs.save_to(path.clone()).unwrap();
+
+        // Only the settings changed here replace theirs
+        ours.set_active_profile("work").unwrap();
+        ours.get_profile_mut("work").unwrap().api_key = None;
+        ours.save_to(path.clone()).unwrap();
+
+        let saved = ProfiledConfig::load_from(path).unwrap();
+        assert_eq!(saved.active_profile, "work");
+        assert_eq!(saved.profiles["default"].org_id.as_deref(), Some("org-2"));
+        assert_eq!(
+            saved.profiles["work"].api_host.as_deref(),
+            Some("https://a.test")
+        );
+        assert!(saved.profiles["work"].api_key.is_none());
+        assert!(saved.cache.redact_sensitive);
+    }
 
-        // Token expires in the future
+    #[test]
+    fn test_load_moves_jwts_to_token_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.yaml");
+        let mut config = ProfiledConfig::default();
+        let profile = config.get_profile_mut("default").unwrap();
+        profile.api_key = Some("hawk.test".to_string());
         profile.jwt = Some(JwtToken {
-            token: "test".to_string(),
+            token: "legacy-jwt".to_string(),
             expires_at: Utc::now() + chrono::Duration::hours(1),
+            clock_skew_secs: 0,
+        });
+        config.save_to(path.clone()).unwrap();
+
+        let loaded = ProfiledConfig::load_at(path.to_str()).unwrap();
+        assert!(loaded.profiles["default"].jwt.is_none());
+        assert!(
+            !std::fs::read_to_string(&path)
+                .unwrap()
+                .contains("legacy-jwt")
+        );
+
+        // Stored beside the config, not in the user's cache
+        assert_eq!(loaded.token_dir(), Some(dir.path().join("cache")));
+        let store = TokenStore::for_profile(&loaded, &loaded.profiles["default"], None).unwrap();
+        assert_eq!(store.load().unwrap().token, "legacy-jwt");
+    }
+
+    #[test]
+    fn test_encrypted_config_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.yaml");
+
+        let mut config = ProfiledConfig::default();
+        let profile = config.get_profile_mut("default").unwrap();
+        profile.api_key = Some("hawk.plaintextkey.value".to_string());
+        profile.jwt = Some(JwtToken {
+            token: "jwt-token-value".to_string(),
+            expires_at: Utc::now(),
+            clock_skew_secs: 0,
         });
-        assert!(!profile.is_token_expired());
+        let settings = EncryptionSettings {
+            iterations: 10,
+            ..EncryptionSettings::generate().unwrap()
+        };
+        settings.unlock_with("passphrase").unwrap();
+        config.encryption = Some(settings);
+        config.save_to(path.clone()).unwrap();
+
+        let raw = std::fs::read_to_string(&path).unwrap();
+        assert!(!raw.contains("plaintextkey"));
+        assert!(!raw.contains("jwt-token-value"));
+        assert!(raw.contains("enc:v1:"));
+
+        // Sealed load leaves secrets encrypted; full load decrypts them
+        let sealed = ProfiledConfig::load_sealed_at(path.to_str()).unwrap();
+        let key = sealed.profiles["default"].api_key.as_deref().unwrap();
+        assert!(crypto::is_sealed(key));
+
+        let loaded = ProfiledConfig::load_from(path).unwrap();
+        let profile = loaded.get_profile("default").unwrap();
+        assert_eq!(profile.api_key.as_deref(), Some("hawk.plaintextkey.value"));
+        assert_eq!(profile.jwt.as_ref().unwrap().token, "jwt-token-value");
+    }
+
+    #[test]
+    fn test_resolve_config_dir_prefers_platform_then_legacy() {
+        let dir = tempfile::tempdir().unwrap();
+        let platform = dir.path().join("platform");
+        let legacy = dir.path().join("legacy");
+
+        // New installs use the platform directory
+        assert_eq!(
+            resolve_config_dir(Some(platform.clone()), legacy.clone()),
+            platform
+        );
+
+        // An existing legacy config keeps being used
+        std::fs::create_dir_all(&legacy).unwrap();
+        std::fs::write(legacy.join(CONFIG_FILE), "version: 2").unwrap();
+        assert_eq!(
+            resolve_config_dir(Some(platform.clone()), legacy.clone()),
+            legacy
+        );
+        assert_eq!(resolve_config_dir(None, legacy.clone()), legacy);
+
+        // ...until the platform directory has one
+        std::fs::create_dir_all(&platform).unwrap();
+        std::fs::write(platform.join(CONFIG_FILE), "version: 2").unwrap();
+        assert_eq!(resolve_config_dir(Some(platform.clone()), legacy), platform);
+    }
+
+    #[test]
+    fn test_migrate_config_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let legacy = dir.path().join(".hawkop");
+        let platform = dir.path().join("config").join("hawkop");
+        std::fs::create_dir_all(&legacy).unwrap();
+        std::fs::write(legacy.join(CONFIG_FILE), "version: 2").unwrap();
+        std::fs::write(legacy.join("history.jsonl"), "{}").unwrap();
+
+        let planned = migrate_config_dir(&legacy, &platform, true, false).unwrap();
+        assert_eq!(planned.len(), 2);
+        assert!(legacy.join(CONFIG_FILE).exists());
+
+        let moved = migrate_config_dir(&legacy, &platform, false, false).unwrap();
+        assert_eq!(moved.len(), 2);
+        assert!(!legacy.exists());
+        assert!(platform.join(CONFIG_FILE).exists());
+        assert!(platform.join("history.jsonl").exists());
+
+        // Existing files are not replaced without force
+        std::fs::create_dir_all(&legacy).unwrap();
+        std::fs::write(legacy.join(CONFIG_FILE), "version: 2").unwrap();
+        assert!(migrate_config_dir(&legacy, &platform, false, false).is_err());
+        assert!(migrate_config_dir(&legacy, &platform, false, true).is_ok());
     }
 }
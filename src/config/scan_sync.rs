@@ -0,0 +1,127 @@
+//! What `config pull` last fetched, for `config push`
+//!
+//! Each pulled (or pushed) scan configuration is recorded with the content
+//! the local file and the platform last agreed on, its base. `config push`
+//! compares the platform's current content with the base to tell whether
+//! someone changed the config since, and shows a three-way diff if so.
+//! Records live in `scan_configs.json` next to hawkop's config file and are
+//! keyed by the local file's absolute path.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::Config;
+use crate::error::Result;
+
+/// A local file's last agreed-on copy of a scan configuration
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncRecord {
+    /// Absolute path of the local file
+    pub file: PathBuf,
+
+    /// Scan configuration name on the platform
+    pub name: String,
+
+    /// Organization the configuration belongs to
+    pub org_id: String,
+
+    /// When the file was last pulled or pushed
+    pub synced_at: DateTime<Utc>,
+
+    /// Content at that point
+    pub base: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SyncFile {
+    #[serde(default)]
+    configs: Vec<SyncRecord>,
+}
+
+/// Sync records file handle
+pub struct ScanSync {
+    path: PathBuf,
+}
+
+impl ScanSync {
+    /// Records next to the config file at `config_path` (or the default one)
+    pub fn open(config_path: Option<&str>) -> Result<Self> {
+        let config_path = Config::resolve_path(config_path)?;
+        let dir = config_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from("."));
+        Ok(Self::at(dir.join("scan_configs.json")))
+    }
+
+    /// Records at a specific path (for testing)
+    pub fn at(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    fn load(&self) -> Result<SyncFile> {
+        match fs::read_to_string(&self.path) {
+            Ok(contents) => Ok(serde_json::from_str(&contents).unwrap_or_default()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(SyncFile::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// The record for local `file`, if it was pulled or pushed before
+    pub fn get(&self, file: &Path) -> Result<Option<SyncRecord>> {
+        let file = absolute(file);
+        Ok(self.load()?.configs.into_iter().find(|r| r.file == file))
+    }
+
+    /// Record that `file` and config `name` in `org_id` now hold `content`
+    pub fn record(&self, file: &Path, name: &str, org_id: &str, content: &str) -> Result<()> {
+        let file = absolute(file);
+        let mut records = self.load()?;
+        records.configs.retain(|r| r.file != file);
+        records.configs.push(SyncRecord {
+            file,
+            name: name.to_string(),
+            org_id: org_id.to_string(),
+            synced_at: Utc::now(),
+            base: content.to_string(),
+        });
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.path, serde_json::to_string_pretty(&records)?)?;
+        Ok(())
+    }
+}
+
+/// `file` as an absolute path, resolving symlinks when it exists
+fn absolute(file: &Path) -> PathBuf {
+    fs::canonicalize(file)
+        .or_else(|_| std::path::absolute(file))
+        .unwrap_or_else(|_| file.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_replaces_previous_entry() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let sync = ScanSync::at(temp.path().join("scan_configs.json"));
+        let file = temp.path().join("stackhawk.yml");
+        fs::write(&file, "app: {}\n").unwrap();
+
+        assert_eq!(sync.get(&file).unwrap(), None);
+        sync.record(&file, "web", "org-1", "v1").unwrap();
+        sync.record(&file, "web", "org-1", "v2").unwrap();
+
+        let record = sync.get(&file).unwrap().unwrap();
+        assert_eq!(record.base, "v2");
+        assert_eq!(record.name, "web");
+        assert_eq!(sync.load().unwrap().configs.len(), 1);
+    }
+}
@@ -0,0 +1,156 @@
+//! Starter HawkScan configurations for `config generate`
+//!
+//! Templates are embedded in the binary from `templates/`. The base
+//! `stackhawk.yml` takes the app ID, environment, and host, plus an OpenAPI
+//! block and an authentication block. Without an OpenAPI spec or an
+//! authentication type, those blocks are commented-out pointers to the
+//! options, so the file still passes `config validate`.
+
+use crate::cli::AuthTemplate;
+
+const BASE: &str = include_str!("templates/stackhawk.yml");
+const OPENAPI_NONE: &str = include_str!("templates/openapi-none.yml");
+const AUTH_NONE: &str = include_str!("templates/auth-none.yml");
+const AUTH_FORM: &str = include_str!("templates/auth-form.yml");
+const AUTH_JSON: &str = include_str!("templates/auth-json.yml");
+const AUTH_TOKEN: &str = include_str!("templates/auth-token.yml");
+
+/// Values filled into the template
+#[derive(Debug, Clone)]
+pub struct ScanTemplate<'a> {
+    /// Application name (for the header comment)
+    pub app_name: &'a str,
+    /// Application ID
+    pub app_id: &'a str,
+    /// Environment name
+    pub env: &'a str,
+    /// URL of the running application
+    pub host: &'a str,
+    /// OpenAPI spec: a path on the host if it starts with `/`, else a file
+    pub oas: Option<&'a str>,
+    /// Authentication block, if any
+    pub auth: Option<AuthTemplate>,
+}
+
+impl ScanTemplate<'_> {
+    /// The configuration as YAML
+    pub fn render(&self) -> String {
+        let openapi = match self.oas {
+            Some(path) if path.starts_with('/') => {
+                format!("  openApiConf:\n    path: {}\n", scalar(path))
+            }
+            Some(file) => format!("  openApiConf:\n    filePath: {}\n", scalar(file)),
+            None => OPENAPI_NONE.to_string(),
+        };
+        let authentication = match self.auth {
+            None => AUTH_NONE,
+            Some(AuthTemplate::Form) => AUTH_FORM,
+            Some(AuthTemplate::Json) => AUTH_JSON,
+            Some(AuthTemplate::Token) => AUTH_TOKEN,
+        };
+
+        let app_id = scalar(self.app_id);
+        let env = scalar(self.env);
+        let host = scalar(self.host);
+
+        // One pass over the template, so a placeholder inside a value is
+        // left as written. Blocks replace their whole line, trailing newline
+        // included.
+        let mut out = String::with_capacity(BASE.len() + openapi.len() + authentication.len());
+        let mut rest = BASE;
+        while let Some(open) = rest.find("{{") {
+            let Some(close) = rest[open..].find("}}").map(|i| open + i) else {
+                break;
+            };
+            let (value, block) = match &rest[open + 2..close] {
+                "app_name" => (self.app_name, false),
+                "env_name" => (self.env, false),
+                "app_id" => (app_id.as_str(), false),
+                "env" => (env.as_str(), false),
+                "host" => (host.as_str(), false),
+                "openapi" => (openapi.as_str(), true),
+                "authentication" => (authentication, true),
+                _ => (&rest[open..close + 2], false),
+            };
+            out.push_str(&rest[..open]);
+            out.push_str(value);
+            rest = &rest[close + 2..];
+            if block {
+                rest = rest.strip_prefix('\n').unwrap_or(rest);
+            }
+        }
+        out.push_str(rest);
+        out
+    }
+}
+
+/// `value` as a YAML scalar, quoted only when it has to be
+fn scalar(value: &str) -> String {
+    serde_yaml::to_string(value)
+        .map(|s| s.trim_end().to_string())
+        .unwrap_or_else(|_| format!("{:?}", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation;
+
+    fn template(oas: Option<&'static str>, auth: Option<AuthTemplate>) -> ScanTemplate<'static> {
+        ScanTemplate {
+            app_name: "Billing API",
+            app_id: "3fa85f64-5717-4562-b3fc-2c963f66afa6",
+            env: "Pre-Prod: EU",
+            host: "https://billing.example.com",
+            oas,
+            auth,
+        }
+    }
+
+    #[test]
+    fn test_render_fills_values() {
+        let yaml = template(Some("/v3/api-docs"), None).render();
+        assert!(yaml.starts_with("# HawkScan configuration for Billing API (Pre-Prod: EU)\n"));
+        assert!(yaml.contains("  applicationId: 3fa85f64-5717-4562-b3fc-2c963f66afa6\n"));
+        assert!(yaml.contains("  env: 'Pre-Prod: EU'\n"));
+        assert!(yaml.contains("  openApiConf:\n    path: /v3/api-docs\n"));
+        assert!(!yaml.contains("{{"));
+
+        let yaml = template(Some("specs/openapi.yaml"), None).render();
+        assert!(yaml.contains("    filePath: specs/openapi.yaml\n"));
+    }
+
+    #[test]
+    fn test_render_leaves_placeholders_in_values() {
+        let yaml = ScanTemplate {
+            app_name: "Billing {{host}}",
+            env: "{{app_id}}",
+            ..template(None, None)
+        }
+        .render();
+        assert!(yaml.starts_with("# HawkScan configuration for Billing {{host}} ({{app_id}})\n"));
+        assert!(yaml.contains("  env: '{{app_id}}'\n"));
+        assert!(yaml.contains("  host: https://billing.example.com\n"));
+    }
+
+    #[test]
+    fn test_every_template_validates() {
+        let auths = [
+            None,
+            Some(AuthTemplate::Form),
+            Some(AuthTemplate::Json),
+            Some(AuthTemplate::Token),
+        ];
+        for oas in [None, Some("openapi.json")] {
+            for auth in auths {
+                let yaml = template(oas, auth).render();
+                let markers = validation::validate(&yaml).markers;
+                assert!(markers.is_empty(), "{:?} {:?}: {:?}", oas, auth, markers);
+
+                let parsed: serde_yaml::Value = serde_yaml::from_str(&yaml).unwrap();
+                assert_eq!(parsed["app"]["authentication"].is_mapping(), auth.is_some());
+                assert_eq!(parsed["app"]["openApiConf"].is_mapping(), oas.is_some());
+            }
+        }
+    }
+}
@@ -0,0 +1,186 @@
+//! JWTs shared between hawkop processes through the cache database
+//!
+//! Tokens are kept in the response cache's SQLite database, keyed by a hash
+//! of the API host and API key, rather than in `config.yaml`: refreshing a
+//! token no longer rewrites the config, and every profile (and shell
+//! completion) using the same key against the same host shares one token.
+//! With config encryption enabled, tokens are sealed with the config
+//! passphrase before they are stored.
+//!
+//! The default config uses the response cache's database. A config loaded
+//! from anywhere else (`--config`, `HAWKOP_CONFIG`) keeps its tokens in a
+//! `cache` directory beside it, so separate configs never share tokens.
+
+use std::path::{Path, PathBuf};
+
+use crate::cache::{CacheStorage, cache_key};
+use crate::client::models;
+use crate::client::resolve_api_host;
+use crate::config::{EncryptionSettings, JwtToken, ProfileConfig, ProfiledConfig, crypto};
+use crate::error::Result;
+
+/// Where the JWT for one API key and host is stored
+#[derive(Debug, Clone)]
+pub struct TokenStore {
+    /// Cache directory holding the database (None for the default cache)
+    cache_dir: Option<PathBuf>,
+    key: String,
+    encryption: Option<EncryptionSettings>,
+}
+
+impl TokenStore {
+    /// Store for `api_key` against `api_host` (None for the default host)
+    /// in the cache database under `cache_dir` (None for the default cache)
+    pub fn new(
+        cache_dir: Option<PathBuf>,
+        api_key: &str,
+        api_host: Option<&str>,
+        encryption: Option<&EncryptionSettings>,
+    ) -> Self {
+        let host = resolve_api_host(api_host.map(str::to_string));
+        Self {
+            cache_dir,
+            key: cache_key("jwt", Some(&host), None, &[("api_key", api_key)]),
+            encryption: encryption.cloned(),
+        }
+    }
+
+    /// Store for a profile of `config`, with the profile's API key and host
+    /// (or `api_host` when given, as with `--api-host`) and the config's
+    /// encryption; None without an API key
+    pub fn for_profile(
+        config: &ProfiledConfig,
+        profile: &ProfileConfig,
+        api_host: Option<&str>,
+    ) -> Option<Self> {
+        let api_key = profile.api_key.as_deref()?;
+        let host = api_host.or(profile.api_host.as_deref());
+        Some(Self::new(
+            config.token_dir(),
+            api_key,
+            host,
+            config.encryption.as_ref(),
+        ))
+    }
+
+    /// Cache directory for the tokens of the config at `config_path`
+    ///
+    /// None (the default cache) for the default config location, else a
+    /// `cache` directory beside the file.
+    pub fn dir_for_config(config_path: &Path) -> Option<PathBuf> {
+        if ProfiledConfig::default_path().is_ok_and(|default| default == config_path) {
+            return None;
+        }
+        let parent = config_path.parent().filter(|p| !p.as_os_str().is_empty());
+        Some(parent.unwrap_or(Path::new(".")).join("cache"))
+    }
+
+    fn open(&self) -> Result<CacheStorage> {
+        Ok(match &self.cache_dir {
+            Some(dir) => CacheStorage::open_at(dir)?,
+            None => CacheStorage::open()?,
+        })
+    }
+
+    /// The stored token, expired or not
+    ///
+    /// None if there is none or it can't be read (the caller then
+    /// authenticates again).
+    pub fn load(&self) -> Option<JwtToken> {
+        let stored = self
+            .open()
+            .and_then(|cache| Ok(cache.get_token(&self.key)?))
+            .map_err(|e| log::debug!("Could not read cached token: {}", e))
+            .ok()??;
+        let token = match &self.encryption {
+            _ if !crypto::is_sealed(&stored.token) => stored.token.clone(),
+            Some(settings) => settings
+                .unlock()
+                .and_then(|()| settings.open("jwt", &stored.token))
+                .map_err(|e| log::debug!("Could not decrypt cached token: {}", e))
+                .ok()?,
+            // Sealed before encryption was turned off
+            None => return None,
+        };
+        Some(JwtToken {
+            token,
+            ..JwtToken::from(&stored)
+        })
+    }
+
+    /// Store `jwt`, replacing any earlier token
+    pub fn save(&self, jwt: &JwtToken) -> Result<()> {
+        let token = match &self.encryption {
+            Some(settings) => {
+                settings.unlock()?;
+                settings.seal("jwt", &jwt.token)?
+            }
+            None => jwt.token.clone(),
+        };
+        let stored = models::JwtToken {
+            token,
+            expires_at: jwt.expires_at,
+            clock_skew_secs: jwt.clock_skew_secs,
+        };
+        self.open()?.put_token(&self.key, &stored)?;
+        Ok(())
+    }
+
+    /// Delete the stored token; returns whether there was one
+    pub fn remove(&self) -> Result<bool> {
+        Ok(self.open()?.delete_token(&self.key)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_depends_on_api_key_and_host() {
+        let store = |key, host| TokenStore::new(None, key, host, None).key;
+
+        assert_eq!(
+            store("hawk.a", Some("https://api.example.com")),
+            store("hawk.a", Some("https://api.example.com"))
+        );
+        assert_ne!(
+            store("hawk.a", Some("https://api.example.com")),
+            store("hawk.b", Some("https://api.example.com"))
+        );
+        assert_ne!(
+            store("hawk.a", Some("https://api.example.com")),
+            store("hawk.a", Some("https://api.test.example.com"))
+        );
+        // The key itself is never part of the stored key
+        assert!(!store("hawk.a", None).contains("hawk.a"));
+    }
+
+    #[test]
+    fn test_save_load_remove_in_cache_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = TokenStore::new(Some(dir.path().to_path_buf()), "hawk.a", None, None);
+        let jwt = JwtToken {
+            token: "token".to_string(),
+            expires_at: chrono::Utc::now() + chrono::Duration::hours(1),
+            clock_skew_secs: 0,
+        };
+
+        assert!(store.load().is_none());
+        store.save(&jwt).unwrap();
+        assert!(dir.path().join("cache.db").exists());
+        assert_eq!(store.load().unwrap().token, "token");
+        assert!(store.remove().unwrap());
+        assert!(store.load().is_none());
+    }
+
+    #[test]
+    fn test_dir_for_config() {
+        assert_eq!(
+            TokenStore::dir_for_config(Path::new("/tmp/ci/hawkop.yaml")),
+            Some(PathBuf::from("/tmp/ci/cache"))
+        );
+        let default = ProfiledConfig::default_path().unwrap();
+        assert_eq!(TokenStore::dir_for_config(&default), None);
+    }
+}
@@ -0,0 +1,260 @@
+//! Moving hawkop settings between machines
+//!
+//! `config export` writes a copy of the config without credentials, and
+//! `config import` merges such a copy into the local config. Profiles carry
+//! their org, API host, and preferences; API keys and integration secrets
+//! are left out (or masked with `--redact`) and JWTs are never exported,
+//! since they are short-lived and tied to the key that minted them.
+
+use std::path::PathBuf;
+
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use super::{ProfiledConfig, crypto};
+use crate::error::Result;
+
+/// Placeholder written in place of an API key by `config export --redact`
+pub const REDACTED: &str = "<redacted>";
+
+/// How exported API keys and integration secrets are handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Secrets {
+    /// Leave them out
+    Exclude,
+    /// Keep them with a placeholder value, to show where a secret belongs
+    Redact,
+}
+
+impl Secrets {
+    /// The exported form of `secret`
+    fn apply(self, secret: Option<String>) -> Option<String> {
+        match self {
+            Self::Redact => secret.map(|_| REDACTED.to_string()),
+            Self::Exclude => None,
+        }
+    }
+}
+
+/// What importing did to one profile
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ImportedProfile {
+    /// Profile name
+    pub profile: String,
+
+    /// True when the profile did not exist locally
+    pub created: bool,
+
+    /// True when the file provided an API key that was applied
+    pub api_key: bool,
+
+    /// True when the profile still has no API key after the import
+    pub needs_key: bool,
+}
+
+impl ProfiledConfig {
+    /// Copy of this config that is safe to share
+    ///
+    /// Encryption settings and JWTs are dropped; API keys are handled
+    /// per `secrets`.
+    pub fn export(&self, secrets: Secrets) -> Self {
+        let mut export = self.clone();
+        export.encryption = None;
+        for profile in export.profiles.values_mut() {
+            profile.jwt = None;
+            profile.api_key = secrets.apply(profile.api_key.take());
+        }
+        if let Some(jira) = &mut export.integrations.jira {
+            jira.api_token = secrets.apply(jira.api_token.take());
+        }
+        if let Some(webhook) = &mut export.integrations.webhook {
+            webhook.secret = secrets.apply(webhook.secret.take());
+        }
+        export
+    }
+
+    /// Merge an exported config into this one
+    ///
+    /// Each incoming profile replaces the org, API host, and preferences of
+    /// the local profile with the same name, or is created. Local API keys
+    /// and tokens are kept unless the file carries a plaintext key. Non-empty
+    /// cache settings replace the local ones, and Jira settings replace the
+    /// local ones except for the token, which is kept like an API key; the
+    /// same goes for webhook settings and their secret. The active profile
+    /// only changes when the local one no longer exists.
+    pub fn import(&mut self, incoming: ProfiledConfig) -> Vec<ImportedProfile> {
+        let active = incoming.active_profile.clone();
+        let mut names: Vec<String> = incoming.profiles.keys().cloned().collect();
+        names.sort();
+
+        let mut incoming_profiles = incoming.profiles;
+        let mut imported = Vec::new();
+        for name in names {
+            let from = incoming_profiles.remove(&name).unwrap_or_default();
+            let key = from.api_key.filter(|k| is_plaintext(k));
+            let created = !self.profiles.contains_key(&name);
+            let profile = self.profiles.entry(name.clone()).or_default();
+
+            if from.org_id.is_some() {
+                profile.org_id = from.org_id;
+            }
+            profile.api_host = from.api_host;
+            profile.preferences = from.preferences;
+            let api_key = key.is_some();
+            if let Some(key) = key {
+                // A token minted with another key is no longer valid
+                if profile.api_key.as_deref() != Some(key.as_str()) {
+                    profile.jwt = None;
+                }
+                profile.api_key = Some(key);
+            }
+
+            imported.push(ImportedProfile {
+                profile: name,
+                created,
+                api_key,
+                needs_key: profile.api_key.is_none(),
+            });
+        }
+
+        if !incoming.cache.is_empty() {
+            self.cache = incoming.cache;
+        }
+        let local = std::mem::take(&mut self.integrations);
+        if let Some(mut jira) = incoming.integrations.jira {
+            let token = jira.api_token.take().filter(|t| is_plaintext(t));
+            jira.api_token = token.or(local.jira.and_then(|j| j.api_token));
+            self.integrations.jira = Some(jira);
+        } else {
+            self.integrations.jira = local.jira;
+        }
+        if let Some(mut webhook) = incoming.integrations.webhook {
+            let secret = webhook.secret.take().filter(|s| is_plaintext(s));
+            webhook.secret = secret.or(local.webhook.and_then(|w| w.secret));
+            self.integrations.webhook = Some(webhook);
+        } else {
+            self.integrations.webhook = local.webhook;
+        }
+        if !self.profiles.contains_key(&self.active_profile) && self.profiles.contains_key(&active)
+        {
+            self.active_profile = active;
+        }
+        imported
+    }
+}
+
+/// Whether an imported secret can be used as is (not masked, and not
+/// sealed with another config's passphrase)
+fn is_plaintext(secret: &str) -> bool {
+    secret != REDACTED && !crypto::is_sealed(secret)
+}
+
+/// Read a file written by `config export`
+///
+/// The file is checked like a config file: the first error fails the
+/// import, and unknown keys are warned about. Legacy single-profile files
+/// are read as a `default` profile.
+pub fn read_export(path: PathBuf) -> Result<ProfiledConfig> {
+    ProfiledConfig::read_from(path, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{JiraSettings, JwtToken, Preferences, ProfileConfig};
+
+    fn preferences() -> Preferences {
+        serde_yaml::from_str(
+            "format: json\npage_size: 250\nhistory: true\nretries: 5\nrate_limits:\n  scan: 120\n  default: 720\nscan.list.limit: 50\nscan.list.env: [prod, staging]\naudit.since: 30d\n",
+        )
+        .unwrap()
+    }
+
+    fn source() -> ProfiledConfig {
+        let mut config = ProfiledConfig {
+            active_profile: "work".to_string(),
+            profiles: Default::default(),
+            ..Default::default()
+        };
+        config.profiles.insert(
+            "work".to_string(),
+            ProfileConfig {
+                api_key: Some("hawk_secret".to_string()),
+                org_id: Some("org-1".to_string()),
+                api_host: Some("https://api.example.com".to_string()),
+                jwt: Some(JwtToken {
+                    token: "jwt".to_string(),
+                    expires_at: chrono::Utc::now(),
+                    clock_skew_secs: 0,
+                }),
+                preferences: preferences(),
+            },
+        );
+        config.cache.max_size = Some("500MB".into());
+        config.integrations.jira = Some(JiraSettings {
+            url: "https://acme.atlassian.net".to_string(),
+            email: Some("me@acme.com".to_string()),
+            api_token: Some("jira_secret".to_string()),
+            project: Some("SEC".to_string()),
+            issue_type: None,
+        });
+        config
+    }
+
+    /// Export to YAML and read it back, as a file would travel
+    fn round_trip(config: &ProfiledConfig, secrets: Secrets) -> ProfiledConfig {
+        let yaml = serde_yaml::to_string(&config.export(secrets)).unwrap();
+        assert!(!yaml.contains("hawk_secret"));
+        assert!(!yaml.contains("jira_secret"));
+        assert!(!yaml.contains("jwt"));
+        serde_yaml::from_str(&yaml).unwrap()
+    }
+
+    #[test]
+    fn test_round_trip_keeps_every_preference() {
+        let exported = round_trip(&source(), Secrets::Exclude);
+        assert!(exported.profiles["work"].api_key.is_none());
+
+        let mut target = ProfiledConfig::default();
+        target.profiles.clear();
+        let imported = target.import(exported);
+
+        assert_eq!(imported.len(), 1);
+        assert!(imported[0].created && imported[0].needs_key);
+        assert_eq!(target.active_profile, "work");
+        let work = &target.profiles["work"];
+        assert_eq!(work.org_id.as_deref(), Some("org-1"));
+        assert_eq!(work.api_host.as_deref(), Some("https://api.example.com"));
+        assert_eq!(
+            serde_yaml::to_value(&work.preferences).unwrap(),
+            serde_yaml::to_value(preferences()).unwrap()
+        );
+        assert_eq!(target.cache.max_size, Some("500MB".into()));
+        let jira = target.integrations.jira.as_ref().unwrap();
+        assert_eq!(jira.project.as_deref(), Some("SEC"));
+        assert!(jira.api_token.is_none());
+    }
+
+    #[test]
+    fn test_import_keeps_local_credentials() {
+        let exported = round_trip(&source(), Secrets::Redact);
+        assert_eq!(exported.profiles["work"].api_key.as_deref(), Some(REDACTED));
+
+        let mut target = source();
+        target.active_profile = "default".to_string();
+        target
+            .profiles
+            .insert("default".to_string(), ProfileConfig::default());
+        target.profiles.get_mut("work").unwrap().preferences = Preferences::default();
+        let imported = target.import(exported);
+
+        assert!(!imported[0].created && !imported[0].api_key && !imported[0].needs_key);
+        assert_eq!(target.active_profile, "default");
+        let work = &target.profiles["work"];
+        assert_eq!(work.api_key.as_deref(), Some("hawk_secret"));
+        assert!(work.jwt.is_some());
+        assert_eq!(work.preferences.page_size, 250);
+        let jira = target.integrations.jira.as_ref().unwrap();
+        assert_eq!(jira.api_token.as_deref(), Some("jira_secret"));
+    }
+}
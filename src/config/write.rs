@@ -0,0 +1,110 @@
+//! Safe config file writes shared by concurrent hawkop processes
+//!
+//! Several hawkop processes can write the config at once, e.g. a shell
+//! completion refreshing its JWT while a command saves a profile change.
+//! Writers take an advisory lock on a `<config>.lock` file next to the
+//! config, and each write goes to a temporary file that is renamed over the
+//! config, so readers never see a half-written file. The lock file is
+//! separate because the rename replaces the config's inode.
+
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::error::Result;
+
+/// Exclusive lock on the config at a path, released when dropped
+#[derive(Debug)]
+pub struct ConfigLock {
+    _file: File,
+}
+
+impl ConfigLock {
+    /// Block until no other process holds the lock for `path`
+    pub fn acquire(path: &Path) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = private_options()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(sibling(path, "lock"))?;
+        file.lock()?;
+        Ok(Self { _file: file })
+    }
+}
+
+/// Replace the file at `path` with `contents` in one step
+///
+/// The caller should hold the path's [`ConfigLock`]. On Unix the file is
+/// created with mode 600, since it holds credentials.
+pub fn write_atomic(path: &Path, contents: &str) -> Result<()> {
+    let tmp = sibling(path, "tmp");
+    // A leftover from an interrupted write could have other permissions
+    let _ = std::fs::remove_file(&tmp);
+    let result = (|| {
+        let mut file = private_options()
+            .create(true)
+            .truncate(true)
+            .write(true)
+            .open(&tmp)?;
+        file.write_all(contents.as_bytes())?;
+        file.sync_all()?;
+        std::fs::rename(&tmp, path)
+    })();
+    if result.is_err() {
+        let _ = std::fs::remove_file(&tmp);
+    }
+    Ok(result?)
+}
+
+/// `<path>.<extension>`, e.g. `config.yaml.lock`
+fn sibling(path: &Path, extension: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".");
+    name.push(extension);
+    PathBuf::from(name)
+}
+
+/// Open options that create files readable only by the owner (Unix)
+pub fn private_options() -> OpenOptions {
+    let mut options = OpenOptions::new();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_atomic_replaces_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.yaml");
+        std::fs::write(&path, "old").unwrap();
+
+        let _lock = ConfigLock::acquire(&path).unwrap();
+        write_atomic(&path, "new").unwrap();
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "new");
+        assert!(!dir.path().join("config.yaml.tmp").exists());
+        assert!(dir.path().join("config.yaml.lock").exists());
+    }
+
+    #[test]
+    fn test_lock_is_exclusive() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.yaml");
+
+        let lock = ConfigLock::acquire(&path).unwrap();
+        let other = File::open(sibling(&path, "lock")).unwrap();
+        assert!(other.try_lock().is_err());
+        drop(lock);
+        assert!(other.try_lock().is_ok());
+    }
+}
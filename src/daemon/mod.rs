@@ -0,0 +1,267 @@
+//! Background cache refresher for `hawkop daemon`
+//!
+//! `daemon start` spawns a detached `hawkop daemon run` that refetches hot
+//! cache entries before they expire. The daemon and the commands that manage
+//! it talk through the SQLite cache: the daemon writes a heartbeat with its
+//! status every few seconds, and `daemon stop` writes a stop request that the
+//! daemon picks up within a second. A pid file in the cache directory marks
+//! the running daemon; removing it also stops the daemon.
+
+use std::fs::{self, OpenOptions};
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::cache::CacheStorage;
+use crate::cli::args::GlobalOptions;
+use crate::error::{Error, Result};
+
+/// Cache key of the daemon's heartbeat
+const STATUS_KEY: &str = "daemon:status";
+
+/// Cache key of a pending stop request (holds the pid to stop)
+const STOP_KEY: &str = "daemon:stop";
+
+/// Cache endpoint the daemon's entries are stored under
+const ENDPOINT: &str = "daemon";
+
+/// How often the daemon writes its heartbeat
+pub const HEARTBEAT: Duration = Duration::from_secs(5);
+
+/// A heartbeat older than this means the daemon is gone
+const HEARTBEAT_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// What the running daemon reports about itself
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DaemonStatus {
+    /// Daemon process ID
+    pub pid: u32,
+
+    /// When the daemon started
+    pub started_at: DateTime<Utc>,
+
+    /// Organization whose entries are refreshed
+    pub org_id: String,
+
+    /// Seconds between refresh checks
+    pub interval_secs: u64,
+
+    /// When the last refresh finished
+    #[serde(default)]
+    pub last_refresh: Option<DateTime<Utc>>,
+
+    /// Refreshes run so far
+    #[serde(default)]
+    pub refreshes: u64,
+
+    /// Error from the last refresh, if it failed
+    #[serde(default)]
+    pub last_error: Option<String>,
+
+    /// When this status was written
+    pub heartbeat: DateTime<Utc>,
+}
+
+impl DaemonStatus {
+    /// Whether the heartbeat is recent enough for the daemon to be alive
+    pub fn is_alive(&self, now: DateTime<Utc>) -> bool {
+        (now - self.heartbeat)
+            .to_std()
+            .is_ok_and(|age| age < HEARTBEAT_TIMEOUT)
+    }
+}
+
+/// Pid file of the running daemon
+pub fn pid_path() -> Result<PathBuf> {
+    Ok(cache_dir()?.join("daemon.pid"))
+}
+
+/// Log file the daemon's warnings and errors are appended to
+pub fn log_path() -> Result<PathBuf> {
+    Ok(cache_dir()?.join("daemon.log"))
+}
+
+fn cache_dir() -> Result<PathBuf> {
+    CacheStorage::cache_dir().map_err(|e| Error::Other(e.to_string()))
+}
+
+fn open_cache() -> Result<CacheStorage> {
+    CacheStorage::open().map_err(|e| Error::Other(e.to_string()))
+}
+
+/// Pid recorded in the pid file, if there is one
+pub fn read_pid() -> Option<u32> {
+    fs::read_to_string(pid_path().ok()?)
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Record this process as the running daemon
+pub fn write_pid() -> Result<()> {
+    fs::write(pid_path()?, std::process::id().to_string())?;
+    Ok(())
+}
+
+/// Remove the pid file if it still names this process
+pub fn remove_pid() {
+    if read_pid() == Some(std::process::id())
+        && let Ok(path) = pid_path()
+    {
+        let _ = fs::remove_file(path);
+    }
+}
+
+/// The running daemon's status, or `None` when no daemon is running
+///
+/// A pid file without a live heartbeat is left over from a daemon that was
+/// killed; it is removed.
+pub fn running() -> Result<Option<DaemonStatus>> {
+    let Some(pid) = read_pid() else {
+        return Ok(None);
+    };
+    let status = read_status(&open_cache()?).filter(|s| s.pid == pid);
+    match status {
+        Some(status) if status.is_alive(Utc::now()) => Ok(Some(status)),
+        _ => {
+            let _ = fs::remove_file(pid_path()?);
+            Ok(None)
+        }
+    }
+}
+
+/// The last heartbeat written, live or not
+pub fn read_status(cache: &CacheStorage) -> Option<DaemonStatus> {
+    let (data, _) = cache.get_any(STATUS_KEY).ok().flatten()?;
+    serde_json::from_slice(&data).ok()
+}
+
+/// Write the daemon's heartbeat
+pub fn write_status(cache: &CacheStorage, status: &DaemonStatus) -> Result<()> {
+    let data = serde_json::to_vec(status)?;
+    cache
+        .put(STATUS_KEY, &data, ENDPOINT, None, HEARTBEAT_TIMEOUT)
+        .map_err(|e| Error::Other(e.to_string()))
+}
+
+/// Ask the daemon with `pid` to stop
+pub fn request_stop(pid: u32) -> Result<()> {
+    open_cache()?
+        .put(
+            STOP_KEY,
+            pid.to_string().as_bytes(),
+            ENDPOINT,
+            None,
+            Duration::from_secs(60),
+        )
+        .map_err(|e| Error::Other(e.to_string()))
+}
+
+/// Whether this process should stop: a stop request names it, or the pid
+/// file no longer does
+pub fn should_stop(cache: &CacheStorage) -> bool {
+    let me = std::process::id();
+    if read_pid() != Some(me) {
+        return true;
+    }
+    let requested = cache
+        .get(STOP_KEY)
+        .ok()
+        .flatten()
+        .and_then(|data| String::from_utf8(data).ok())
+        .is_some_and(|pid| pid.trim() == me.to_string());
+    if requested {
+        let _ = cache.delete_by_key(STOP_KEY);
+        let _ = cache.delete_by_key(STATUS_KEY);
+    }
+    requested
+}
+
+/// Start `hawkop daemon run` as a detached process, with the global options
+/// that select the profile, org, and API host passed on
+pub fn spawn(opts: &GlobalOptions, interval: u64) -> Result<Child> {
+    let mut args: Vec<String> = Vec::new();
+    let global = [
+        ("--config", &opts.config),
+        ("--profile", &opts.profile),
+        ("--org", &opts.org),
+        ("--api-host", &opts.api_host),
+    ];
+    for (flag, value) in global {
+        if let Some(value) = value {
+            args.extend([flag.to_string(), value.clone()]);
+        }
+    }
+    args.extend([
+        "daemon".to_string(),
+        "run".to_string(),
+        "--interval".to_string(),
+        interval.to_string(),
+    ]);
+
+    fs::create_dir_all(cache_dir()?)?;
+    let log = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(log_path()?)?;
+    let mut command = Command::new(std::env::current_exe()?);
+    command
+        .args(&args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(log);
+    detach(&mut command);
+    Ok(command.spawn()?)
+}
+
+/// Keep the daemon out of the terminal's process group, so Ctrl-C in the
+/// shell that started it doesn't stop it
+#[cfg(unix)]
+fn detach(command: &mut Command) {
+    use std::os::unix::process::CommandExt;
+    command.process_group(0);
+}
+
+/// Run the daemon without a console window
+#[cfg(windows)]
+fn detach(command: &mut Command) {
+    use std::os::windows::process::CommandExt;
+    const DETACHED_PROCESS: u32 = 0x0000_0008;
+    command.creation_flags(DETACHED_PROCESS);
+}
+
+#[cfg(not(any(unix, windows)))]
+fn detach(_command: &mut Command) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_round_trip_and_liveness() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let cache = CacheStorage::open_at(dir.path()).unwrap();
+        let now = Utc::now();
+        let status = DaemonStatus {
+            pid: 42,
+            started_at: now,
+            org_id: "org-1".to_string(),
+            interval_secs: 60,
+            last_refresh: None,
+            refreshes: 0,
+            last_error: None,
+            heartbeat: now,
+        };
+
+        write_status(&cache, &status).unwrap();
+        let read = read_status(&cache).unwrap();
+
+        assert_eq!(read.pid, 42);
+        assert!(read.is_alive(now + chrono::Duration::seconds(5)));
+        assert!(!read.is_alive(now + chrono::Duration::seconds(60)));
+    }
+}
@@ -83,6 +83,29 @@ pub fn detect_local_repo() -> Option<LocalRepoInfo> {
     parse_remote_url(&url)
 }
 
+/// Commit SHA of `HEAD` in the current working directory.
+pub fn head_commit() -> Option<String> {
+    git_output(&["rev-parse", "HEAD"])
+}
+
+/// Full ref of the checked-out branch (e.g. `refs/heads/main`).
+///
+/// Returns `None` for a detached `HEAD` or outside a git repo.
+pub fn head_ref() -> Option<String> {
+    git_output(&["symbolic-ref", "-q", "HEAD"])
+}
+
+/// Trimmed stdout of a successful git command
+fn git_output(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    if !output.status.success() {
+        debug!("git {} failed", args.join(" "));
+        return None;
+    }
+    let text = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!text.is_empty()).then_some(text)
+}
+
 /// Parse a git remote URL into its components.
 ///
 /// Handles:
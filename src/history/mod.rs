@@ -0,0 +1,454 @@
+//! Local command history for `hawkop history` and `hawkop rerun`
+//!
+//! Recording is opt-in (`preferences.history: true` in the config profile, or
+//! `HAWKOP_HISTORY=1`). Each executed command is appended as one JSON line to
+//! `history.jsonl` in the config directory with its arguments, the
+//! profile/org it ran against, and a cache key identifying the query, so
+//! complex filtered queries can be found and repeated later.
+//!
+//! Commands given a credential on the command line (an API key, a token, or
+//! a webhook URL) are not recorded, and the file is readable only by its
+//! owner.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use clap::ArgMatches;
+use clap::parser::ValueSource;
+use serde::{Deserialize, Serialize};
+
+use crate::cache::cache_key;
+use crate::config::write::private_options;
+use crate::config::{Config, ProfiledConfig};
+use crate::error::Result;
+
+/// Environment variable that enables history recording regardless of config
+pub const HISTORY_ENV: &str = "HAWKOP_HISTORY";
+
+/// Maximum number of entries kept on disk (oldest are dropped first)
+const MAX_ENTRIES: usize = 1000;
+
+/// Top-level commands that are never recorded (they would only clutter history)
+const EXCLUDED_COMMANDS: &[&str] = &["history", "rerun", "completion", "help"];
+
+/// Arguments whose command-line values can carry credentials
+const SECRET_ARGS: &[&str] = &["api_key", "token", "post_to", "notify"];
+
+/// A single recorded command invocation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// 1-based position in the history file (assigned on load, not stored)
+    #[serde(skip)]
+    pub id: usize,
+
+    /// When the command was executed
+    pub timestamp: DateTime<Utc>,
+
+    /// Subcommand path, e.g. `scan list`
+    #[serde(default)]
+    pub command: String,
+
+    /// Command-line arguments, excluding the program name
+    pub args: Vec<String>,
+
+    /// Profile override in effect (None = active profile)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+
+    /// Organization override in effect (None = profile default)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub org_id: Option<String>,
+
+    /// Stable key identifying this query (same args + org + host = same key)
+    pub cache_key: String,
+
+    /// Whether the command completed successfully
+    pub success: bool,
+}
+
+impl HistoryEntry {
+    /// Build an entry for the given arguments
+    pub fn new(
+        command: String,
+        args: Vec<String>,
+        profile: Option<String>,
+        org_id: Option<String>,
+        api_host: Option<&str>,
+        success: bool,
+    ) -> Self {
+        let joined = args.join("\u{1f}");
+        let key = cache_key("history", api_host, org_id.as_deref(), &[("args", &joined)]);
+        Self {
+            id: 0,
+            timestamp: Utc::now(),
+            command,
+            args,
+            profile,
+            org_id,
+            cache_key: key,
+            success,
+        }
+    }
+
+    /// Top-level command name (e.g. "scan" for `hawkop scan list`)
+    pub fn command(&self) -> Option<&str> {
+        self.command
+            .split(' ')
+            .next()
+            .filter(|name| !name.is_empty())
+    }
+
+    /// Arguments rendered as a shell-like command line
+    pub fn command_line(&self) -> String {
+        self.args
+            .iter()
+            .map(|a| {
+                if a.is_empty() || a.contains(char::is_whitespace) {
+                    format!("'{}'", a.replace('\'', "'\\''"))
+                } else {
+                    a.clone()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Whether this entry belongs to a resource, accepting plural forms
+    /// (`scans` matches `hawkop scan ...`)
+    pub fn matches_resource(&self, resource: &str) -> bool {
+        let resource = resource.to_lowercase();
+        let singular = resource.strip_suffix('s').unwrap_or(&resource);
+        match self.command() {
+            Some(cmd) => {
+                let cmd = cmd.to_lowercase();
+                cmd == resource || cmd == singular
+            }
+            None => false,
+        }
+    }
+
+    /// Whether any argument contains the query (case-insensitive)
+    pub fn matches_query(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        self.args.iter().any(|a| a.to_lowercase().contains(&query))
+    }
+}
+
+/// History file handle
+pub struct History {
+    path: PathBuf,
+}
+
+impl History {
+    /// History file at the default location (next to the config file)
+    pub fn open() -> Result<Self> {
+        let config_path = Config::default_path()?;
+        let dir = config_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from("."));
+        Ok(Self::at(dir.join("history.jsonl")))
+    }
+
+    /// History file at a specific path (for testing)
+    pub fn at(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Location of the history file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Load all entries, oldest first, with 1-based IDs assigned.
+    ///
+    /// Malformed lines are skipped rather than failing the whole history.
+    pub fn entries(&self) -> Result<Vec<HistoryEntry>> {
+        let contents = match fs::read_to_string(&self.path) {
+            Ok(c) => c,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut entries: Vec<HistoryEntry> = contents
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .filter_map(|l| match serde_json::from_str(l) {
+                Ok(entry) => Some(entry),
+                Err(e) => {
+                    log::debug!("Skipping malformed history line: {}", e);
+                    None
+                }
+            })
+            .collect();
+
+        for (i, entry) in entries.iter_mut().enumerate() {
+            entry.id = i + 1;
+        }
+
+        Ok(entries)
+    }
+
+    /// Append an entry, trimming the file to the most recent entries
+    pub fn append(&self, entry: &HistoryEntry) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut file = private_options()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", serde_json::to_string(entry)?)?;
+        drop(file);
+
+        let entries = self.entries()?;
+        if entries.len() > MAX_ENTRIES {
+            self.write_all(&entries[entries.len() - MAX_ENTRIES..])?;
+        }
+
+        Ok(())
+    }
+
+    /// Remove all entries, returning how many were removed
+    pub fn clear(&self) -> Result<usize> {
+        let count = self.entries()?.len();
+        if self.path.exists() {
+            fs::remove_file(&self.path)?;
+        }
+        Ok(count)
+    }
+
+    fn write_all(&self, entries: &[HistoryEntry]) -> Result<()> {
+        let mut out = String::new();
+        for entry in entries {
+            out.push_str(&serde_json::to_string(entry)?);
+            out.push('\n');
+        }
+        let mut file = private_options()
+            .create(true)
+            .truncate(true)
+            .write(true)
+            .open(&self.path)?;
+        file.write_all(out.as_bytes())?;
+        Ok(())
+    }
+}
+
+/// Whether history recording is enabled for this invocation.
+///
+/// `HAWKOP_HISTORY` (1/true/0/false) takes precedence over the
+/// `preferences.history` setting of the resolved profile.
+pub fn is_enabled(config_path: Option<&str>, profile: Option<&str>) -> bool {
+    if let Some(enabled) = env_override() {
+        return enabled;
+    }
+
+    ProfiledConfig::load_sealed_at(config_path)
+        .ok()
+        .and_then(|config| {
+            config
+                .resolve_profile(profile)
+                .ok()
+                .map(|(_, p)| p.preferences.history)
+        })
+        .unwrap_or(false)
+}
+
+/// `HAWKOP_HISTORY` as a switch (1/true/yes/on enable), if set
+pub fn env_override() -> Option<bool> {
+    std::env::var(HISTORY_ENV)
+        .ok()
+        .map(|value| matches!(value.to_lowercase().as_str(), "1" | "true" | "yes" | "on"))
+}
+
+/// Whether a command (its subcommand path, e.g. `scan list`) should be recorded
+pub fn should_record(command: &str) -> bool {
+    match command.split(' ').next() {
+        Some(name) if !name.is_empty() => !EXCLUDED_COMMANDS.contains(&name),
+        _ => false,
+    }
+}
+
+/// Whether a credential was given on the command line (`--api-key`,
+/// `--token`, `--post-to`, or a Slack `--notify` target)
+///
+/// Values from the environment or config defaults are not in the recorded
+/// arguments, so only command-line values count.
+pub fn has_secret(matches: &ArgMatches) -> bool {
+    let mut current = Some(matches);
+    while let Some(matches) = current {
+        let given = SECRET_ARGS.iter().any(|id| {
+            matches.ids().any(|present| present == id)
+                && matches.value_source(id) == Some(ValueSource::CommandLine)
+                && matches.get_raw(id).is_some_and(|mut values| {
+                    values.any(|v| *id != "notify" || v.to_string_lossy().starts_with("slack:"))
+                })
+        });
+        if given {
+            return true;
+        }
+        current = matches.subcommand().map(|(_, sub)| sub);
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::Cli;
+    use clap::CommandFactory;
+    use tempfile::TempDir;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    /// Subcommand path clap parses from a command line
+    fn command(s: &str) -> String {
+        let matches = Cli::command()
+            .try_get_matches_from(std::iter::once("hawkop").chain(s.split_whitespace()))
+            .unwrap();
+        crate::cli::command_name(&matches)
+    }
+
+    fn entry(s: &str) -> HistoryEntry {
+        HistoryEntry::new(
+            command(s),
+            args(s),
+            None,
+            Some("org-1".to_string()),
+            None,
+            true,
+        )
+    }
+
+    #[test]
+    fn test_append_and_load_assigns_ids() {
+        let temp = TempDir::new().unwrap();
+        let history = History::at(temp.path().join("history.jsonl"));
+
+        history.append(&entry("scan list --app web")).unwrap();
+        history.append(&entry("app list")).unwrap();
+
+        let entries = history.entries().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].id, 1);
+        assert_eq!(entries[1].id, 2);
+        assert_eq!(entries[0].args, args("scan list --app web"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_history_file_is_private() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp = TempDir::new().unwrap();
+        let history = History::at(temp.path().join("history.jsonl"));
+        history.append(&entry("app list")).unwrap();
+
+        let mode = fs::metadata(history.path()).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[test]
+    fn test_entries_missing_file_is_empty() {
+        let temp = TempDir::new().unwrap();
+        let history = History::at(temp.path().join("none.jsonl"));
+        assert!(history.entries().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_entries_skips_malformed_lines() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("history.jsonl");
+        let good = serde_json::to_string(&entry("app list")).unwrap();
+        fs::write(&path, format!("not json\n{}\n", good)).unwrap();
+
+        let entries = History::at(path).entries().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].id, 1);
+    }
+
+    #[test]
+    fn test_clear_removes_file() {
+        let temp = TempDir::new().unwrap();
+        let history = History::at(temp.path().join("history.jsonl"));
+        history.append(&entry("app list")).unwrap();
+
+        assert_eq!(history.clear().unwrap(), 1);
+        assert!(history.entries().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_cache_key_stable_for_same_query() {
+        let a = entry("scan list --app web");
+        let b = entry("scan list --app web");
+        let c = entry("scan list --app api");
+        assert_eq!(a.cache_key, b.cache_key);
+        assert_ne!(a.cache_key, c.cache_key);
+    }
+
+    #[test]
+    fn test_matches_resource_plural() {
+        let e = entry("--format json scan list");
+        assert_eq!(e.command(), Some("scan"));
+        assert_eq!(entry("--timeout 30 version").command(), Some("version"));
+        assert!(e.matches_resource("scans"));
+        assert!(e.matches_resource("scan"));
+        assert!(!e.matches_resource("apps"));
+    }
+
+    #[test]
+    fn test_matches_query_case_insensitive() {
+        let e = entry("scan list --app WebStore");
+        assert!(e.matches_query("webstore"));
+        assert!(!e.matches_query("billing"));
+    }
+
+    #[test]
+    fn test_command_line_quotes_whitespace() {
+        let e = HistoryEntry::new(
+            "team create".to_string(),
+            vec!["team".into(), "create".into(), "My Team".into()],
+            None,
+            None,
+            None,
+            true,
+        );
+        assert_eq!(e.command_line(), "team create 'My Team'");
+    }
+
+    #[test]
+    fn test_should_record_excludes_meta_commands() {
+        assert!(should_record(&command("scan list")));
+        assert!(should_record(&command("--max-concurrency 4 scan list")));
+        assert!(!should_record(&command("history list")));
+        assert!(!should_record(&command("rerun --last")));
+        assert!(!should_record(&command("--log-level debug history list")));
+        assert!(!should_record(&command(
+            "--profile prod history search scans"
+        )));
+        assert!(!should_record(""));
+    }
+
+    #[test]
+    fn test_has_secret_checks_command_line_credentials() {
+        let secret = |line: &str| {
+            let matches = Cli::command()
+                .try_get_matches_from(line.split_whitespace())
+                .unwrap();
+            has_secret(&matches)
+        };
+        assert!(secret("hawkop auth login --api-key hawk.SECRET.KEY"));
+        assert!(secret("hawkop scan upload-github s1 --token=ghp_x"));
+        assert!(secret(
+            "hawkop scan watch s1 --notify slack:https://hooks/x"
+        ));
+        assert!(secret("hawkop --post-to https://h/x?token=y scan list"));
+        assert!(!secret("hawkop scan watch s1 --notify desktop"));
+        assert!(!secret("hawkop scan list --app web"));
+    }
+}
@@ -0,0 +1,300 @@
+//! Jira issues for findings
+//!
+//! Issues are created through the REST API v2, which Jira Cloud and Data
+//! Center both serve and which takes descriptions in wiki markup. Cloud
+//! authenticates with an account email and API token (basic auth); Data
+//! Center with a personal access token (bearer).
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use log::debug;
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use super::{CreatedTicket, FindingTicket, Tracker};
+use crate::config::JiraSettings;
+use crate::error::{ApiError, Error, Result};
+
+/// Issue type used when none is configured
+pub const DEFAULT_ISSUE_TYPE: &str = "Bug";
+
+/// Affected paths listed in the description before the rest are counted
+const MAX_LISTED_PATHS: usize = 50;
+
+/// Longest summary Jira accepts
+const MAX_SUMMARY_CHARS: usize = 255;
+
+/// How long an API request may take
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A Jira project to file findings in
+pub struct Jira {
+    url: String,
+    email: Option<String>,
+    api_token: Option<String>,
+    project: String,
+    issue_type: String,
+}
+
+impl Jira {
+    /// Target `project` on the configured site
+    ///
+    /// Credentials are only needed by [`Tracker::create`], so a preview
+    /// works without any `integrations.jira` settings.
+    pub fn new(settings: Option<&JiraSettings>, project: String, issue_type: String) -> Self {
+        Self {
+            url: settings.map_or_else(String::new, |s| s.url.trim_end_matches('/').to_string()),
+            email: settings.and_then(|s| s.email.clone()),
+            api_token: settings.and_then(|s| s.api_token.clone()),
+            project,
+            issue_type,
+        }
+    }
+}
+
+/// Jira's reply to an issue create
+#[derive(Debug, Deserialize)]
+struct CreatedIssue {
+    key: String,
+}
+
+#[async_trait]
+impl Tracker for Jira {
+    fn preview(&self, ticket: &FindingTicket) -> Value {
+        json!({
+            "fields": {
+                "project": {"key": self.project},
+                "issuetype": {"name": self.issue_type},
+                "summary": ticket.title().chars().take(MAX_SUMMARY_CHARS).collect::<String>(),
+                "description": description(ticket),
+                "labels": ["stackhawk", format!("stackhawk-plugin-{}", ticket.plugin_id)],
+            }
+        })
+    }
+
+    async fn create(&self, ticket: &FindingTicket) -> Result<CreatedTicket> {
+        if self.url.is_empty() {
+            return Err(Error::Other(
+                "Jira is not configured: add integrations.jira to the hawkop config".to_string(),
+            ));
+        }
+        let token = self.api_token.as_deref().ok_or_else(|| {
+            Error::Other("integrations.jira.api_token is not set in the hawkop config".to_string())
+        })?;
+
+        let http = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .user_agent(concat!("hawkop/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| ApiError::Network(e.to_string()))?;
+        let url = format!("{}/rest/api/2/issue", self.url);
+        debug!("POST {}", url);
+        let request = http.post(&url).json(&self.preview(ticket));
+        let request = match &self.email {
+            Some(email) => request.basic_auth(email, Some(token)),
+            None => request.bearer_auth(token),
+        };
+        let response = request.send().await.map_err(ApiError::from)?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let text = response.text().await.unwrap_or_default();
+            return Err(Error::Other(format!(
+                "Jira issue creation failed ({}): {}{}",
+                status.as_u16(),
+                error_message(&text),
+                create_hint(status.as_u16())
+            )));
+        }
+
+        let issue: CreatedIssue = response
+            .json()
+            .await
+            .map_err(|e| ApiError::InvalidResponse(e.to_string()))?;
+        Ok(CreatedTicket {
+            url: format!("{}/browse/{}", self.url, issue.key),
+            key: issue.key,
+        })
+    }
+}
+
+/// Issue description in wiki markup
+fn description(ticket: &FindingTicket) -> String {
+    let mut out = String::new();
+    let mut line = |text: String| {
+        out.push_str(&text);
+        out.push('\n');
+    };
+
+    line(format!("*Severity:* {}", ticket.severity));
+    line(format!("*Plugin:* {} {}", ticket.plugin_id, ticket.name));
+    line(format!("*Application:* {} ({})", ticket.app, ticket.env));
+    line(format!("*Scan:* {}", ticket.scan_id));
+    if let Some(cwe) = &ticket.cwe_id {
+        line(format!(
+            "*CWE:* [CWE-{0}|https://cwe.mitre.org/data/definitions/{0}.html]",
+            cwe
+        ));
+    }
+    if let Some(category) = &ticket.owasp {
+        line(format!("*OWASP Top 10:* {}", category.label()));
+    }
+
+    if !ticket.description.trim().is_empty() {
+        line(String::new());
+        line("h3. Description".to_string());
+        line(ticket.description.trim().to_string());
+    }
+
+    if let Some(example) = &ticket.example {
+        line(String::new());
+        line("h3. Evidence".to_string());
+        line(format!("*Path:* {{{{{}}}}}", example.path));
+        if let Some(param) = &example.param {
+            line(format!("*Parameter:* {{{{{}}}}}", param));
+        }
+        if let Some(evidence) = &example.evidence {
+            line(noformat(evidence));
+        }
+        if let Some(other_info) = &example.other_info {
+            line(noformat(other_info));
+        }
+        if let Some(curl) = &example.curl {
+            line(String::new());
+            line("h3. Reproduce".to_string());
+            line(format!("{{code:bash}}\n{}\n{{code}}", curl.trim()));
+        }
+    }
+
+    line(String::new());
+    line(format!("h3. Affected paths ({})", ticket.paths.len()));
+    let mut paths: Vec<&str> = ticket
+        .paths
+        .iter()
+        .take(MAX_LISTED_PATHS)
+        .map(String::as_str)
+        .collect();
+    let more = ticket.paths.len().saturating_sub(MAX_LISTED_PATHS);
+    let more_line = format!("... and {} more", more);
+    if more > 0 {
+        paths.push(&more_line);
+    }
+    line(noformat(&paths.join("\n")));
+
+    if !ticket.references.is_empty() {
+        line(String::new());
+        line("h3. References".to_string());
+        for reference in &ticket.references {
+            line(format!("* {}", reference));
+        }
+    }
+
+    line(String::new());
+    line(format!(
+        "_Filed from StackHawk with hawkop: hawkop scan get {} --plugin-id {}_",
+        ticket.scan_id, ticket.plugin_id
+    ));
+    out.trim_end().to_string()
+}
+
+/// Text shown verbatim, without wiki markup
+fn noformat(text: &str) -> String {
+    // A literal closing tag would end the block early
+    format!(
+        "{{noformat}}\n{}\n{{noformat}}",
+        text.trim().replace("{noformat}", "{ noformat}")
+    )
+}
+
+/// The messages from a Jira error body, or the body itself
+fn error_message(body: &str) -> String {
+    let Ok(value) = serde_json::from_str::<Value>(body) else {
+        return body.to_string();
+    };
+    let mut messages: Vec<String> = value["errorMessages"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|m| m.as_str().map(str::to_string))
+        .collect();
+    if let Some(errors) = value["errors"].as_object() {
+        messages.extend(
+            errors
+                .iter()
+                .map(|(field, m)| format!("{}: {}", field, m.as_str().unwrap_or_default())),
+        );
+    }
+    if messages.is_empty() {
+        body.to_string()
+    } else {
+        messages.join("; ")
+    }
+}
+
+fn create_hint(status: u16) -> &'static str {
+    match status {
+        400 => "\n→ Check --jira-project, --issue-type, and the project's required fields",
+        401 => "\n→ Check integrations.jira.email and api_token in the hawkop config",
+        403 => "\n→ The Jira account needs permission to create issues in the project",
+        404 => "\n→ Check integrations.jira.url in the hawkop config",
+        _ => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ticket(paths: usize) -> FindingTicket {
+        FindingTicket {
+            scan_id: "scan-1".to_string(),
+            app: "checkout".to_string(),
+            env: "prod".to_string(),
+            plugin_id: "40018".to_string(),
+            name: "SQL Injection".to_string(),
+            severity: "High".to_string(),
+            cwe_id: Some("89".to_string()),
+            owasp: crate::owasp::for_cwe(Some("89")),
+            description: "Attacker-controlled SQL.".to_string(),
+            references: vec!["https://owasp.org/sqli".to_string()],
+            paths: (0..paths).map(|i| format!("GET /items/{}", i)).collect(),
+            example: Some(super::super::PathEvidence {
+                path: "GET /items/0".to_string(),
+                param: Some("id".to_string()),
+                evidence: Some("syntax error {noformat}".to_string()),
+                other_info: None,
+                curl: Some("curl 'https://shop.example.com/items/0?id=1%27'".to_string()),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_preview_builds_issue_fields() {
+        let jira = Jira::new(None, "SEC".to_string(), DEFAULT_ISSUE_TYPE.to_string());
+        let body = jira.preview(&ticket(60));
+        let fields = &body["fields"];
+
+        assert_eq!(fields["project"]["key"], "SEC");
+        assert_eq!(fields["issuetype"]["name"], "Bug");
+        assert_eq!(fields["summary"], "High: SQL Injection in checkout (prod)");
+        assert_eq!(fields["labels"][1], "stackhawk-plugin-40018");
+
+        let text = fields["description"].as_str().unwrap();
+        assert!(text.contains("*OWASP Top 10:* A03:2021 Injection"));
+        assert!(
+            text.contains("{code:bash}\ncurl 'https://shop.example.com/items/0?id=1%27'\n{code}")
+        );
+        assert!(text.contains("syntax error { noformat}"));
+        assert!(text.contains("h3. Affected paths (60)"));
+        assert!(text.contains("GET /items/49\n... and 10 more\n{noformat}"));
+        assert!(!text.contains("GET /items/50"));
+    }
+
+    #[test]
+    fn test_error_message() {
+        let body = r#"{"errorMessages":[],"errors":{"issuetype":"Specify a valid issue type"}}"#;
+        assert_eq!(error_message(body), "issuetype: Specify a valid issue type");
+        assert_eq!(error_message("Bad Gateway"), "Bad Gateway");
+    }
+}
@@ -0,0 +1,132 @@
+//! Issue trackers that findings can be filed in
+//!
+//! `finding ticket` gathers a finding into a [`FindingTicket`] once, and a
+//! [`Tracker`] backend turns it into an issue in its own markup. Jira is the
+//! only backend so far ([`jira`]).
+
+use async_trait::async_trait;
+use schemars::JsonSchema;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::client::models::{AlertMsgResponse, AlertResponse, ApplicationAlertUri, ScanResult};
+use crate::error::Result;
+use crate::owasp::Category;
+
+pub mod jira;
+
+/// Everything a ticket says about one finding
+#[derive(Debug, Clone)]
+pub struct FindingTicket {
+    /// Scan the finding was reported in
+    pub scan_id: String,
+    /// Application name
+    pub app: String,
+    /// Environment name
+    pub env: String,
+    /// Plugin ID
+    pub plugin_id: String,
+    /// Plugin name, e.g. "Cross Site Scripting (Reflected)"
+    pub name: String,
+    /// Severity: High, Medium, Low
+    pub severity: String,
+    /// CWE ID, when the plugin has one
+    pub cwe_id: Option<String>,
+    /// OWASP Top 10 category derived from the CWE
+    pub owasp: Option<Category>,
+    /// Vulnerability description (markdown)
+    pub description: String,
+    /// Cheatsheet and reference URLs
+    pub references: Vec<String>,
+    /// Affected paths as "METHOD /path"
+    pub paths: Vec<String>,
+    /// The path shown in detail, with its evidence
+    pub example: Option<PathEvidence>,
+}
+
+/// Evidence and reproduction for one affected path
+#[derive(Debug, Clone)]
+pub struct PathEvidence {
+    /// "METHOD /path"
+    pub path: String,
+    /// Vulnerable parameter
+    pub param: Option<String>,
+    /// Evidence snippet from the response
+    pub evidence: Option<String>,
+    /// Additional information from the plugin
+    pub other_info: Option<String>,
+    /// curl command that reproduces the request
+    pub curl: Option<String>,
+}
+
+impl FindingTicket {
+    /// Gather a finding from its scan, alert, and (optionally) the message
+    /// of the path to show in detail
+    pub fn new(
+        scan: &ScanResult,
+        response: &AlertResponse,
+        example: Option<(&ApplicationAlertUri, &AlertMsgResponse)>,
+    ) -> Self {
+        let alert = &response.alert;
+        let present = |value: &Option<String>| value.clone().filter(|v| !v.trim().is_empty());
+        let references = response
+            .cheatsheet
+            .iter()
+            .chain(&alert.references)
+            .filter(|r| !r.is_empty())
+            .cloned()
+            .collect();
+        Self {
+            scan_id: scan.scan.id.clone(),
+            app: scan.scan.application_name.clone(),
+            env: scan.scan.env.clone(),
+            plugin_id: alert.plugin_id.clone(),
+            name: alert.name.clone(),
+            severity: alert.severity.clone(),
+            cwe_id: present(&alert.cwe_id),
+            owasp: crate::owasp::for_cwe(alert.cwe_id.as_deref()),
+            description: alert.description.clone(),
+            references,
+            paths: response
+                .application_scan_alert_uris
+                .iter()
+                .map(|uri| format!("{} {}", uri.request_method, uri.uri))
+                .collect(),
+            example: example.map(|(uri, message)| PathEvidence {
+                path: format!("{} {}", uri.request_method, uri.uri),
+                param: present(&message.param),
+                evidence: present(&message.evidence),
+                other_info: present(&message.other_info),
+                curl: present(&message.validation_command),
+            }),
+        }
+    }
+
+    /// One-line title, e.g. "High: SQL Injection in checkout (prod)"
+    pub fn title(&self) -> String {
+        format!(
+            "{}: {} in {} ({})",
+            self.severity, self.name, self.app, self.env
+        )
+    }
+}
+
+/// A ticket created in a tracker
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct CreatedTicket {
+    /// Tracker key, e.g. `SEC-123`
+    pub key: String,
+
+    /// Link to the ticket
+    pub url: String,
+}
+
+/// An issue tracker backend
+#[async_trait]
+pub trait Tracker: Send + Sync {
+    /// Request body that would create the ticket, for `--dry-run`
+    fn preview(&self, ticket: &FindingTicket) -> Value;
+
+    /// Create the ticket
+    async fn create(&self, ticket: &FindingTicket) -> Result<CreatedTicket>;
+}
@@ -0,0 +1,80 @@
+//! Ctrl-C and `--timeout` handling
+//!
+//! [`run`] races the command against Ctrl-C and the time limit. When either
+//! fires, the command is dropped, cancelling its in-flight requests, and the
+//! run fails with [`Error::Cancelled`] (exit 130 for Ctrl-C, 124 for a
+//! timeout). With `--partial`, the command's page fetchers return what they
+//! have instead (see [`crate::client::cancel`]) and the command gets a grace
+//! period to print it. A second Ctrl-C, or a command stuck past the grace
+//! period (e.g. at a prompt), exits the process straight away.
+
+use std::future::Future;
+use std::time::Duration;
+
+use crate::client::cancel::{self, CancelReason};
+use crate::error::{Error, Result};
+
+/// How long a cancelled `--partial` command gets to print what it has
+const PARTIAL_GRACE: Duration = Duration::from_secs(5);
+
+/// Run `command` until it finishes, Ctrl-C is pressed, or `timeout` passes
+pub async fn run<F>(command: F, timeout: Option<Duration>, partial: bool) -> Result<()>
+where
+    F: Future<Output = Result<()>>,
+{
+    cancel::set_partial(partial);
+    tokio::spawn(watch(timeout));
+
+    tokio::pin!(command);
+    let result = tokio::select! {
+        biased;
+        result = &mut command => result,
+        reason = cancel::requested() => {
+            if partial {
+                tokio::select! {
+                    result = &mut command => result,
+                    _ = tokio::time::sleep(PARTIAL_GRACE) => Err(reason.into()),
+                }
+            } else {
+                Err(reason.into())
+            }
+        }
+    };
+
+    // A command that finished after being cancelled only printed part of
+    // its output
+    match (result, cancel::reason()) {
+        (Ok(()), Some(reason)) => Err(reason.into()),
+        (result, _) => result,
+    }
+}
+
+/// Request cancellation on Ctrl-C or once `timeout` passes, then exit the
+/// process if the command doesn't stop in time
+async fn watch(timeout: Option<Duration>) {
+    let limit = async {
+        match timeout {
+            Some(limit) => {
+                tokio::time::sleep(limit).await;
+                limit
+            }
+            None => std::future::pending().await,
+        }
+    };
+    // Without signal handling, only the time limit applies
+    let reason = tokio::select! {
+        Ok(()) = tokio::signal::ctrl_c() => CancelReason::Interrupted,
+        limit = limit => CancelReason::TimedOut(limit),
+    };
+    cancel::request(reason);
+
+    let second_interrupt = async {
+        if tokio::signal::ctrl_c().await.is_err() {
+            std::future::pending::<()>().await;
+        }
+    };
+    let _ = tokio::time::timeout(PARTIAL_GRACE + Duration::from_secs(1), second_interrupt).await;
+    let err = Error::Cancelled(reason);
+    eprintln!("Error: {}", err);
+    std::process::exit(err.exit_code());
+}
@@ -0,0 +1,224 @@
+//! Logging setup and secret redaction
+//!
+//! Logging is off unless `--debug`, `--log-level`, or `--log-file` is given.
+//! Lines go to stderr or a file, as text or JSON (one object per line, for
+//! log collection agents). Every message is passed through [`redact`] first,
+//! so credentials never reach a log no matter which module wrote the line.
+
+use std::borrow::Cow;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+
+use env_logger::{Target, WriteStyle};
+use log::LevelFilter;
+
+use crate::client::{schema, trace};
+use crate::error::{Error, Result};
+use crate::redact::{REDACTED, redact_credentials};
+
+/// Exact secret values registered at runtime (e.g. the profile's API key)
+static SECRETS: OnceLock<Mutex<Vec<String>>> = OnceLock::new();
+
+/// Log line format
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum LogFormat {
+    /// `[timestamp LEVEL target] message`
+    #[default]
+    Text,
+    /// One JSON object per line: `timestamp`, `level`, `target`, `message`
+    Json,
+}
+
+/// What `--trace-http` / `--trace-bodies` turn on
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HttpTrace {
+    #[default]
+    Off,
+    /// Method, URL, status, and timing of every API call
+    Requests,
+    /// Requests plus request and response payloads
+    Bodies,
+}
+
+/// Install the global logger
+///
+/// `level` applies to hawkop's own modules; dependencies only log warnings
+/// and errors unless the level is `Trace`. HTTP tracing is enabled on its
+/// own log targets regardless of `level`, and so is schema drift reporting
+/// with `strict_deserialize` (it is also part of the debug log). Logs go to
+/// `file` (appended) when given, otherwise to stderr.
+pub fn init(
+    level: LevelFilter,
+    http_trace: HttpTrace,
+    strict_deserialize: bool,
+    format: LogFormat,
+    file: Option<&Path>,
+) -> Result<()> {
+    let mut builder = env_logger::Builder::new();
+    if level == LevelFilter::Trace {
+        builder.filter_level(LevelFilter::Trace);
+    } else {
+        builder
+            .filter_level(level.min(LevelFilter::Warn))
+            .filter_module("hawkop", level);
+    }
+    if http_trace != HttpTrace::Off {
+        builder.filter_module(trace::TARGET, LevelFilter::Trace);
+    }
+    builder.filter_module(
+        schema::TARGET,
+        if strict_deserialize || level >= LevelFilter::Debug {
+            LevelFilter::Warn
+        } else {
+            LevelFilter::Off
+        },
+    );
+    // Payloads are only logged when asked for, even at `--log-level trace`
+    builder.filter_module(
+        trace::BODY_TARGET,
+        if http_trace == HttpTrace::Bodies {
+            LevelFilter::Trace
+        } else {
+            LevelFilter::Off
+        },
+    );
+
+    if let Some(path) = file {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                Error::Other(format!(
+                    "Cannot create log directory {}: {}",
+                    parent.display(),
+                    e
+                ))
+            })?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| Error::Other(format!("Cannot open log file {}: {}", path.display(), e)))?;
+        builder
+            .target(Target::Pipe(Box::new(file)))
+            .write_style(WriteStyle::Never);
+    }
+
+    match format {
+        LogFormat::Text => builder.format(|buf, record| {
+            let style = buf.default_level_style(record.level());
+            writeln!(
+                buf,
+                "[{} {style}{:<5}{style:#} {}] {}",
+                buf.timestamp_millis(),
+                record.level(),
+                record.target(),
+                redact(&record.args().to_string())
+            )
+        }),
+        LogFormat::Json => builder.format(|buf, record| {
+            let line = serde_json::json!({
+                "timestamp": chrono::Utc::now()
+                    .to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+                "level": record.level().as_str(),
+                "target": record.target(),
+                "message": redact(&record.args().to_string()),
+            });
+            writeln!(buf, "{}", line)
+        }),
+    };
+
+    builder
+        .try_init()
+        .map_err(|e| Error::Other(format!("Cannot initialize logging: {}", e)))
+}
+
+/// Redact this exact value from all later log lines
+pub fn register_secret(secret: &str) {
+    // Short values would redact unrelated text
+    if secret.len() < 8 {
+        return;
+    }
+    let mut secrets = SECRETS
+        .get_or_init(|| Mutex::new(Vec::new()))
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    if !secrets.iter().any(|s| s == secret) {
+        secrets.push(secret.to_string());
+    }
+}
+
+/// Replace credentials in a log message with `[REDACTED]`
+///
+/// Covers registered secrets, plus everything
+/// [`redact_credentials`] recognizes: values of secret-named headers,
+/// JSON fields, and query parameters, `Bearer`/`Basic` credentials, and
+/// JWTs.
+pub fn redact(text: &str) -> Cow<'_, str> {
+    let mut out: Cow<str> = Cow::Borrowed(text);
+
+    if let Some(secrets) = SECRETS.get() {
+        let secrets = secrets.lock().unwrap_or_else(|e| e.into_inner());
+        for secret in secrets.iter() {
+            if out.contains(secret.as_str()) {
+                out = Cow::Owned(out.replace(secret.as_str(), REDACTED));
+            }
+        }
+    }
+
+    match redact_credentials(&out) {
+        Cow::Borrowed(_) => out,
+        Cow::Owned(redacted) => Cow::Owned(redacted),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redacts_headers_and_fields() {
+        assert_eq!(
+            redact("Authorization: Bearer abc.def.ghi"),
+            "Authorization: Bearer [REDACTED]"
+        );
+        assert_eq!(redact("X-ApiKey: hawk.k1.k2"), "X-ApiKey: [REDACTED]");
+        assert_eq!(
+            redact(r#"Request body: {"apiKey":"secret1","name":"web"}"#),
+            r#"Request body: {"apiKey":"[REDACTED]","name":"web"}"#
+        );
+        assert_eq!(
+            redact("GET /x?api_key=abc123&page=2"),
+            "GET /x?api_key=[REDACTED]&page=2"
+        );
+        assert_eq!(redact("sent Bearer tok123"), "sent Bearer [REDACTED]");
+    }
+
+    #[test]
+    fn test_leaves_ordinary_text_alone() {
+        for text in [
+            "Authentication successful, token expires at 2024-01-01",
+            "Received 401, attempting token refresh",
+            "API request: GET https://api.stackhawk.com/api/v1/org/o1/apps",
+            "tokens: 5",
+        ] {
+            assert!(matches!(redact(text), Cow::Borrowed(_)), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_redacts_jwts_and_registered_secrets() {
+        assert_eq!(
+            redact("jwt eyJhbGciOi.eyJzdWIiOiJ4In0.sig-_1 ok"),
+            "jwt [REDACTED] ok"
+        );
+        assert_eq!(redact("eyJonly.two"), "eyJonly.two");
+
+        register_secret("hawk.registeredsecretvalue");
+        assert_eq!(
+            redact("key hawk.registeredsecretvalue used"),
+            "key [REDACTED] used"
+        );
+    }
+}
@@ -1,23 +1,37 @@
 //! HawkOp CLI - Professional companion for the StackHawk DAST platform
 
-use clap::{CommandFactory, Parser};
+use clap::{CommandFactory, FromArgMatches};
 use clap_complete::env::CompleteEnv;
 use clap_complete::generate;
 
-mod cache;
 mod cli;
-mod client;
 mod config;
+mod daemon;
 mod error;
 mod git;
+mod history;
+mod integrations;
+mod interrupt;
+mod logging;
 mod models;
+mod notify;
+mod openapi;
 mod output;
+mod owasp;
+mod query;
+mod suppress;
+mod timeparse;
+mod validation;
+
+use hawkop_core::{cache, client, redact};
 
 use cli::args::GlobalOptions;
 use cli::{
-    AppCommands, AuditCommands, CacheCommands, Cli, Commands, ConfigCommands, EnvCommands,
-    OasCommands, OrgCommands, PolicyCommands, ProfileCommands, RepoCommands, RunCommands,
-    ScanCommands, SecretCommands, TeamCommands, UserCommands,
+    AppCommands, AuditCommands, AuthCommands, CacheCommands, Cli, Commands, ConfigCommands,
+    DaemonCommands, EnvCommands, FindingCommands, GateCommands, HistoryCommands, OasCommands,
+    OrgCommands, PolicyCommands, ProfileCommands, RepoCommands, ReportCommands, RunCommands,
+    ScanCommands, ScanGroupBy, SecretCommands, SuppressCommands, SurfaceCommands, TeamCommands,
+    UserCommands,
 };
 use error::Result;
 
@@ -25,26 +39,95 @@ fn main() {
     // Handle shell completion requests BEFORE starting tokio runtime.
     // CompleteEnv::complete() will exit if handling a completion request.
     // Our completion functions need their own runtime, which conflicts with tokio::main.
+    cli::completions::complete_bash_command(Cli::command);
     CompleteEnv::with_factory(Cli::command).complete();
 
     // Now start the async runtime for normal command execution
     let runtime = tokio::runtime::Runtime::new().expect("Failed to create runtime");
     if let Err(err) = runtime.block_on(run()) {
         eprintln!("Error: {}", err);
+        std::process::exit(err.exit_code());
+    }
+}
+
+/// Report a usage error and exit
+///
+/// Usage errors exit 1 rather than clap's default 2, which is reserved for
+/// `scan gate` threshold failures.
+fn exit_usage(err: clap::Error) -> ! {
+    if err.use_stderr() {
+        let _ = err.print();
         std::process::exit(1);
     }
+    err.exit()
 }
 
 async fn run() -> Result<()> {
-    let cli = Cli::parse();
+    let started = std::time::Instant::now();
+    output::warnings::set_handler(output::warnings::print);
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let matches = cli::command_with_config_defaults(&args)
+        .try_get_matches()
+        .unwrap_or_else(|err| exit_usage(err));
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|err| exit_usage(err));
     let debug = cli.debug;
+    let warnings_as_errors = cli.warnings_as_errors;
+    if !cli.columns.is_empty() {
+        output::columns::set_selection(output::columns::ColumnSelection::Columns(
+            cli.columns.clone(),
+        ));
+    } else if cli.wide {
+        output::columns::set_selection(output::columns::ColumnSelection::Wide);
+    } else if cli.ids_only {
+        output::columns::set_selection(output::columns::ColumnSelection::Ids);
+    }
+    output::hints::set_quiet(cli.quiet);
+    output::pager::set_disabled(cli.no_pager);
+    if cli.no_color {
+        colored::control::set_override(false);
+    }
+    if let Some(expr) = &cli.json_query {
+        output::json::set_query(output::jsonpath::JsonPath::parse(expr)?);
+    }
+    if let Some(path) = &cli.template {
+        output::template::load(path)?;
+    }
+    let post_to = cli.post_to.clone();
+    if let Some(url) = &post_to {
+        if !url.starts_with("https://") && !url.starts_with("http://") {
+            return Err(error::Error::Other(
+                "--post-to needs an http(s) URL".to_string(),
+            ));
+        }
+        output::json::start_capture();
+    }
 
-    // Initialize logging if debug mode is enabled
-    if debug {
-        env_logger::Builder::new()
-            .filter_level(log::LevelFilter::Debug)
-            .format_timestamp_millis()
-            .init();
+    // Initialize logging: --debug is shorthand for --log-level debug
+    let http_trace = if cli.trace_bodies {
+        logging::HttpTrace::Bodies
+    } else if cli.trace_http {
+        logging::HttpTrace::Requests
+    } else {
+        logging::HttpTrace::Off
+    };
+    let log_level = match (cli.log_level, debug, &cli.log_file) {
+        (Some(level), _, _) => Some(level.filter()),
+        // --trace-http alone logs only the trace lines
+        (None, false, None) if http_trace != logging::HttpTrace::Off || cli.strict_deserialize => {
+            Some(log::LevelFilter::Off)
+        }
+        (None, true, _) | (None, false, Some(_)) => Some(log::LevelFilter::Debug),
+        (None, false, None) => None,
+    };
+    if let Some(level) = log_level {
+        logging::init(
+            level,
+            http_trace,
+            cli.strict_deserialize,
+            cli.log_format,
+            cli.log_file.as_deref(),
+        )?;
 
         log::debug!("HawkOp v{}", env!("CARGO_PKG_VERSION"));
         log::debug!("Command: {:?}", cli.command);
@@ -55,35 +138,174 @@ async fn run() -> Result<()> {
         log::debug!("API host: {:?}", cli.api_host);
     }
 
+    // --schema describes the output without running the command
+    if cli.schema {
+        return cli::schema::print(&cli::command_name(&matches), &cli.command);
+    }
+
     // Create GlobalOptions once and pass to all handlers
     let opts = GlobalOptions::from_cli(&cli);
+    if let Some(limit) = opts.max_concurrency {
+        client::set_max_concurrency(limit);
+    }
+    cli::context::apply_cache_settings(opts.config_ref());
+    if !cli.ids_only
+        && output::columns::selection().is_some()
+        && opts.format == cli::OutputFormat::Json
+    {
+        output::warnings::warn(
+            output::warnings::WarningKind::IgnoredFlag,
+            "--columns and --wide apply to table output; ignored with --format json or --query",
+        );
+    }
+
+    if opts.junit
+        && !matches!(
+            cli.command,
+            Commands::Scan(ScanCommands::Gate { .. }) | Commands::Gate(_)
+        )
+    {
+        return Err(error::Error::Other(
+            "--format junit is only supported by `scan gate` and `gate release`".to_string(),
+        ));
+    }
+    if cli.format == cli::FormatArg::Har
+        && !matches!(cli.command, Commands::Scan(ScanCommands::Get { .. }))
+    {
+        return Err(error::Error::Other(
+            "--format har is only supported by `scan get --uri-id`".to_string(),
+        ));
+    }
+    if cli.format == cli::FormatArg::Markdown
+        && !matches!(cli.command, Commands::Scan(ScanCommands::Get { .. }))
+    {
+        return Err(error::Error::Other(
+            "--format markdown is only supported by `scan get`".to_string(),
+        ));
+    }
+
+    let timeout = opts.timeout.map(std::time::Duration::from_secs);
+    let result = interrupt::run(dispatch(cli.command, &opts), timeout, cli.partial).await;
+
+    // --dry-run: the client withheld a change the command didn't preview
+    // itself; report it as the command's result
+    let result = match result {
+        Err(error::Error::Api(error::ApiError::DryRun(request))) => {
+            cli::context::print_dry_run(opts.format, &request)
+        }
+        other => other,
+    };
+
+    if output::template::is_set() && output::template::rendered() == 0 && result.is_ok() {
+        output::warnings::warn(
+            output::warnings::WarningKind::IgnoredFlag,
+            "--template: no JSON output was printed, so nothing was rendered; add --format json",
+        );
+    }
+
+    // --post-to delivers the JSON output; failures are warnings only
+    if let Some(url) = &post_to {
+        let documents = output::json::take_captured();
+        if documents.is_empty() {
+            if result.is_ok() {
+                output::warnings::warn(
+                    output::warnings::WarningKind::IgnoredFlag,
+                    "--post-to: no JSON output was printed, so nothing was posted; add --format json",
+                );
+            }
+        } else {
+            let config = config::ProfiledConfig::load_at(opts.config_ref()).ok();
+            let settings = config
+                .as_ref()
+                .and_then(|c| c.integrations.webhook.as_ref());
+            output::webhook::post_all(url, settings, &cli::command_name(&matches), &documents)
+                .await;
+        }
+    }
+
+    if cli.stats {
+        output::stats::print(&client::stats::snapshot(), started.elapsed());
+    }
+
+    // Strict mode: a successful run that emitted warnings still fails
+    let result = match result {
+        Ok(()) if warnings_as_errors && output::warnings::count() > 0 => {
+            Err(error::Error::WarningsAsErrors(output::warnings::count()))
+        }
+        other => other,
+    };
+
+    // Remember the last 429 for `hawkop status --verbose`
+    if let Some(last) = client::rate_limit::last_rate_limit()
+        && let Some(path) = config::Config::rate_limit_path()
+        && let Err(e) = last.save(&path)
+    {
+        log::debug!("Failed to record rate limit state: {}", e);
+    }
+
+    // Opt-in command history (never fails the command itself)
+    let command = cli::command_name(&matches);
+    if history::should_record(&command)
+        && !history::has_secret(&matches)
+        && history::is_enabled(opts.config_ref(), opts.profile_ref())
+    {
+        let entry = history::HistoryEntry::new(
+            command,
+            args,
+            opts.profile.clone(),
+            opts.org.clone(),
+            opts.api_host_ref(),
+            result.is_ok(),
+        );
+        if let Err(e) = history::History::open().and_then(|h| h.append(&entry)) {
+            log::debug!("Failed to record command history: {}", e);
+        }
+    }
 
-    let result = match cli.command {
-        Commands::Init => cli::init::run(&opts).await,
-        Commands::Status => cli::status::run(&opts),
+    // Log debug info on completion
+    if log_level.is_some() {
+        if let Err(ref e) = result {
+            log::debug!("Error: {:?}", e);
+        } else {
+            log::debug!("Command completed successfully");
+        }
+    }
+
+    result
+}
+
+/// Run a parsed command
+async fn dispatch(command: Commands, opts: &GlobalOptions) -> Result<()> {
+    match command {
+        Commands::Init { encrypt, decrypt } => cli::init::run(opts, encrypt, decrypt).await,
+        Commands::Status { verbose } => cli::status::run(opts, verbose),
+        Commands::Doctor => cli::doctor::run(opts).await,
         Commands::Version => {
             println!("hawkop version {}", env!("CARGO_PKG_VERSION"));
             Ok(())
         }
         Commands::Profile(profile_cmd) => match profile_cmd {
-            ProfileCommands::List => cli::profile::list(&opts),
-            ProfileCommands::Use { name } => cli::profile::use_profile(&name, &opts),
+            ProfileCommands::List => cli::profile::list(opts),
+            ProfileCommands::Use { name } => cli::profile::use_profile(&name, opts),
             ProfileCommands::Create { name, from } => {
-                cli::profile::create(&name, from.as_deref(), &opts).await
+                cli::profile::create(&name, from.as_deref(), opts).await
             }
-            ProfileCommands::Delete { name, yes } => cli::profile::delete(&name, yes, &opts),
-            ProfileCommands::Show { name } => cli::profile::show(name.as_deref(), &opts),
+            ProfileCommands::Delete { name, yes } => cli::profile::delete(&name, yes, opts),
+            ProfileCommands::Show { name } => cli::profile::show(name.as_deref(), opts),
         },
         Commands::Org(org_cmd) => match org_cmd {
-            OrgCommands::List => cli::org::list(&opts).await,
-            OrgCommands::Set { org_id } => cli::org::set(&opts, org_id).await,
-            OrgCommands::Get => cli::org::get(&opts).await,
+            OrgCommands::List => cli::org::list(opts).await,
+            OrgCommands::Set { org_id } => cli::org::set(opts, org_id).await,
+            OrgCommands::Get { org_id } => cli::org::get(opts, org_id.as_deref()).await,
+            OrgCommands::Usage { since } => cli::org::usage(opts, &since).await,
         },
         Commands::App(app_cmd) => match app_cmd {
             AppCommands::List {
                 app_type,
+                status,
                 pagination,
-            } => cli::app::list(&opts, app_type.as_deref(), &pagination).await,
+                all_orgs,
+            } => cli::app::list(opts, app_type.as_deref(), status, &pagination, all_orgs).await,
             AppCommands::Create {
                 name,
                 env,
@@ -93,10 +315,9 @@ async fn run() -> Result<()> {
                 team_id,
                 repo,
                 repo_id,
-                dry_run,
             } => {
                 cli::app::create(
-                    &opts,
+                    opts,
                     &name,
                     &env,
                     &app_type,
@@ -105,25 +326,45 @@ async fn run() -> Result<()> {
                     team_id.as_deref(),
                     repo.as_deref(),
                     repo_id.as_deref(),
-                    dry_run,
                 )
                 .await
             }
             AppCommands::Get { app_id, name } => {
-                cli::app::get(&opts, app_id.as_deref(), name.as_deref()).await
+                cli::app::get(opts, app_id.as_deref(), name.as_deref()).await
+            }
+            AppCommands::View { app, view } => cli::app::view(opts, &app, view).await,
+            AppCommands::Update { app_id, name } => cli::app::update(opts, &app_id, &name).await,
+            AppCommands::Delete { app_id, yes } => cli::app::delete(opts, &app_id, yes).await,
+            AppCommands::Duplicates { csv } => cli::app::duplicates(opts, csv.as_deref()).await,
+            AppCommands::Export { output, since } => {
+                cli::app::export(opts, output.as_deref(), &since).await
             }
-            AppCommands::Update {
-                app_id,
-                name,
-                dry_run,
-            } => cli::app::update(&opts, &app_id, &name, dry_run).await,
-            AppCommands::Delete { app_id, yes } => cli::app::delete(&opts, &app_id, yes).await,
         },
         Commands::Scan(scan_cmd) => match scan_cmd {
             ScanCommands::List {
                 filters,
                 pagination,
-            } => cli::scan::list(&opts, &filters, &pagination).await,
+                watch,
+                all_orgs,
+                group_by,
+                expand,
+                highlight_threshold,
+            } => match group_by {
+                Some(ScanGroupBy::App) => {
+                    cli::scan::list_by_app(opts, &filters, &pagination, expand).await
+                }
+                None => {
+                    cli::scan::list(
+                        opts,
+                        &filters,
+                        &pagination,
+                        watch,
+                        all_orgs,
+                        &highlight_threshold,
+                    )
+                    .await
+                }
+            },
             ScanCommands::Get {
                 scan_id,
                 app,
@@ -133,13 +374,24 @@ async fn run() -> Result<()> {
                 max_findings,
                 max_body_size,
                 plugin_id,
+                limit,
+                page,
                 uri_id,
                 message,
+                raw,
+                curl_only,
+                no_redact,
+                plain,
+                alert_filters,
+                path_args,
+                group_by,
+                owasp,
                 format,
             } => {
-                // scan get has its own format override (defaults to pretty)
+                // scan get has its own format override (defaults to pretty);
+                // junit was rejected above, har and markdown are its own
                 cli::scan::get(
-                    &opts,
+                    opts,
                     format,
                     &scan_id,
                     app.as_deref(),
@@ -149,11 +401,126 @@ async fn run() -> Result<()> {
                     max_findings,
                     max_body_size,
                     plugin_id.as_deref(),
+                    limit,
+                    page,
                     uri_id.as_deref(),
                     message,
+                    raw,
+                    curl_only,
+                    !no_redact,
+                    plain,
+                    &alert_filters,
+                    &path_args,
+                    group_by,
+                    owasp,
                 )
                 .await
             }
+            ScanCommands::Diff {
+                base,
+                head,
+                markdown,
+            } => cli::scan::diff(opts, &base, &head, markdown).await,
+            ScanCommands::Search {
+                scan_id,
+                pattern,
+                app,
+                env,
+                field,
+                case_sensitive,
+                alert_filters,
+            } => {
+                cli::scan::search(
+                    opts,
+                    &scan_id,
+                    &pattern,
+                    app.as_deref(),
+                    env.as_deref(),
+                    &field,
+                    case_sensitive,
+                    &alert_filters,
+                )
+                .await
+            }
+            ScanCommands::MapOas { scan_id, oas } => cli::scan::map_oas(opts, &scan_id, &oas).await,
+            ScanCommands::Watch {
+                scan_id,
+                interval,
+                notify,
+            } => cli::scan::watch(opts, &scan_id, interval, &notify).await,
+            ScanCommands::Gate {
+                scan_id,
+                app,
+                env,
+                fail_on,
+                include_triaged,
+            } => {
+                cli::scan::gate(
+                    opts,
+                    &scan_id,
+                    app.as_deref(),
+                    env.as_deref(),
+                    &fail_on,
+                    include_triaged,
+                )
+                .await
+            }
+            ScanCommands::UploadGithub {
+                scan_id,
+                repo,
+                git_ref,
+                sha,
+                token,
+                api_url,
+                location,
+                include_triaged,
+            } => {
+                let target = cli::scan::GitHubTarget {
+                    repo,
+                    git_ref,
+                    sha,
+                    token,
+                    api_url,
+                };
+                cli::scan::upload_github(opts, &scan_id, target, &location, include_triaged).await
+            }
+        },
+        Commands::Finding(finding_cmd) => match finding_cmd {
+            FindingCommands::List {
+                app,
+                env,
+                filters,
+                pagination,
+                all_orgs,
+            } => cli::finding::list(opts, &app, &env, &filters, &pagination, all_orgs).await,
+            FindingCommands::TriageBulk { file, stdin: _ } => {
+                cli::finding::triage_bulk(opts, file.as_deref()).await
+            }
+            FindingCommands::Ticket {
+                scan_id,
+                plugin_id,
+                uri,
+                jira_project,
+                issue_type,
+            } => {
+                cli::finding::ticket(
+                    opts,
+                    &scan_id,
+                    &plugin_id,
+                    uri.as_deref(),
+                    jira_project,
+                    issue_type,
+                )
+                .await
+            }
+        },
+        Commands::Gate(gate_cmd) => match gate_cmd {
+            GateCommands::Release {
+                apps,
+                env,
+                fail_on,
+                include_triaged,
+            } => cli::gate::release(opts, &apps, &env, &fail_on, include_triaged).await,
         },
         Commands::Run(run_cmd) => match run_cmd {
             RunCommands::Start {
@@ -161,164 +528,291 @@ async fn run() -> Result<()> {
                 env,
                 config,
                 watch,
-            } => cli::run::start(&opts, &app, env.as_deref(), config.as_deref(), watch).await,
-            RunCommands::Stop { app, yes } => cli::run::stop(&opts, &app, yes).await,
+                notify,
+            } => {
+                cli::run::start(
+                    opts,
+                    &app,
+                    env.as_deref(),
+                    config.as_deref(),
+                    watch,
+                    &notify,
+                )
+                .await
+            }
+            RunCommands::Stop { app, yes } => cli::run::stop(opts, &app, yes).await,
             RunCommands::Status {
                 app,
                 watch,
                 interval,
-            } => cli::run::status(&opts, &app, watch, interval).await,
+            } => cli::run::status(opts, &app, watch, interval).await,
         },
         Commands::User(user_cmd) => match user_cmd {
-            UserCommands::List { pagination } => cli::user::list(&opts, &pagination).await,
+            UserCommands::List { pagination, teams } => {
+                cli::user::list(opts, &pagination, teams).await
+            }
         },
         Commands::Team(team_cmd) => match team_cmd {
             TeamCommands::List {
                 pagination,
                 filters,
-            } => cli::team::list(&opts, &pagination, &filters).await,
-            TeamCommands::Get { team } => cli::team::get(&opts, &team).await,
+            } => cli::team::list(opts, &pagination, &filters).await,
+            TeamCommands::Get {
+                team,
+                members_only,
+                apps_only,
+            } => {
+                let sections = cli::team::TeamSections {
+                    members: !apps_only,
+                    apps: !members_only,
+                };
+                cli::team::get(opts, &team, sections).await
+            }
             TeamCommands::Create {
                 name,
                 users,
                 apps,
-                dry_run,
                 force,
-            } => cli::team::create(&opts, &name, users, apps, dry_run, force).await,
-            TeamCommands::Delete { team, yes, dry_run } => {
-                cli::team::delete(&opts, &team, yes, dry_run).await
+            } => cli::team::create(opts, &name, users, apps, force).await,
+            TeamCommands::Delete { team, yes } => cli::team::delete(opts, &team, yes).await,
+            TeamCommands::Rename { current, new_name } => {
+                cli::team::rename(opts, &current, &new_name).await
+            }
+            TeamCommands::AddUser { team, users, stdin } => {
+                cli::team::add_user(opts, &team, users, stdin).await
+            }
+            TeamCommands::RemoveUser { team, users, stdin } => {
+                cli::team::remove_user(opts, &team, users, stdin).await
             }
-            TeamCommands::Rename {
-                current,
-                new_name,
-                dry_run,
-            } => cli::team::rename(&opts, &current, &new_name, dry_run).await,
-            TeamCommands::AddUser {
-                team,
-                users,
-                stdin,
-                dry_run,
-            } => cli::team::add_user(&opts, &team, users, stdin, dry_run).await,
-            TeamCommands::RemoveUser {
-                team,
-                users,
-                stdin,
-                dry_run,
-            } => cli::team::remove_user(&opts, &team, users, stdin, dry_run).await,
             TeamCommands::SetUsers {
                 team,
                 users,
                 stdin,
-                dry_run,
                 yes,
-            } => cli::team::set_users(&opts, &team, users, stdin, dry_run, yes).await,
+            } => cli::team::set_users(opts, &team, users, stdin, yes).await,
             TeamCommands::AddApp {
                 team,
                 apps,
                 stdin,
-                dry_run,
                 force,
-            } => cli::team::add_app(&opts, &team, apps, stdin, dry_run, force).await,
-            TeamCommands::RemoveApp {
-                team,
-                apps,
-                stdin,
-                dry_run,
-            } => cli::team::remove_app(&opts, &team, apps, stdin, dry_run).await,
+            } => cli::team::add_app(opts, &team, apps, stdin, force).await,
+            TeamCommands::RemoveApp { team, apps, stdin } => {
+                cli::team::remove_app(opts, &team, apps, stdin).await
+            }
             TeamCommands::SetApps {
                 team,
                 apps,
                 stdin,
-                dry_run,
                 yes,
                 force,
-            } => cli::team::set_apps(&opts, &team, apps, stdin, dry_run, yes, force).await,
+            } => cli::team::set_apps(opts, &team, apps, stdin, yes, force).await,
+            TeamCommands::Import {
+                file,
+                stdin: _,
+                yes,
+                force,
+            } => cli::team::import(opts, file.as_deref(), yes, force).await,
+            TeamCommands::Audit { file, stdin: _ } => cli::team::audit(opts, file.as_deref()).await,
+            TeamCommands::Report { team, since } => cli::team::report(opts, &team, &since).await,
         },
         Commands::Policy(policy_cmd) => match policy_cmd {
-            PolicyCommands::List { pagination } => cli::policy::list(&opts, &pagination).await,
+            PolicyCommands::List { pagination } => cli::policy::list(opts, &pagination).await,
         },
         Commands::Repo(repo_cmd) => match repo_cmd {
-            RepoCommands::List { pagination } => cli::repo::list(&opts, &pagination).await,
+            RepoCommands::List { pagination } => cli::repo::list(opts, &pagination).await,
+            RepoCommands::Get { repo } => cli::repo::get(opts, &repo).await,
             RepoCommands::Link {
                 repo_id,
                 repo_name,
                 app_id,
                 app_name,
                 env,
-                dry_run,
             } => {
                 cli::repo::link(
-                    &opts,
+                    opts,
                     repo_id.as_deref(),
                     repo_name.as_deref(),
                     app_id.as_deref(),
                     app_name.as_deref(),
                     &env,
-                    dry_run,
                 )
                 .await
             }
+            RepoCommands::Map { repo, app } => cli::repo::map(opts, &repo, &app).await,
+            RepoCommands::Unmap { repo, app } => cli::repo::unmap(opts, &repo, &app).await,
             RepoCommands::SetApps {
                 repo_id,
                 app_ids,
                 yes,
-                dry_run,
-            } => cli::repo::set_apps(&opts, &repo_id, &app_ids, yes, dry_run).await,
+            } => cli::repo::set_apps(opts, &repo_id, &app_ids, yes).await,
         },
         Commands::Oas(oas_cmd) => match oas_cmd {
-            OasCommands::List { pagination } => cli::oas::list(&opts, &pagination).await,
+            OasCommands::List { pagination } => cli::oas::list(opts, &pagination).await,
             OasCommands::Get { oas_id, output } => {
-                cli::oas::get(&opts, &oas_id, output.as_deref()).await
+                cli::oas::get(opts, &oas_id, output.as_deref()).await
             }
-            OasCommands::Mappings { app } => cli::oas::mappings(&opts, &app).await,
+            OasCommands::Mappings { app } => cli::oas::mappings(opts, &app).await,
+        },
+        Commands::Surface(surface_cmd) => match surface_cmd {
+            SurfaceCommands::Summary => cli::surface::summary(opts).await,
         },
         Commands::Config(config_cmd) => match config_cmd {
-            ConfigCommands::List { pagination } => cli::config::list(&opts, &pagination).await,
+            ConfigCommands::List { pagination } => cli::config::list(opts, &pagination).await,
             ConfigCommands::Get { name, output } => {
-                cli::config::get(&opts, &name, output.as_deref()).await
+                cli::config::get(opts, &name, output.as_deref()).await
             }
-            ConfigCommands::Set { name, file } => cli::config::set(&opts, &name, &file).await,
-            ConfigCommands::Delete { name, yes } => cli::config::delete(&opts, &name, yes).await,
+            ConfigCommands::Set { name, file } => cli::config::set(opts, &name, &file).await,
+            ConfigCommands::Pull {
+                name,
+                output,
+                force,
+            } => cli::config::pull(opts, &name, output.as_deref(), force).await,
+            ConfigCommands::Push { file, name, force } => {
+                cli::config::push(opts, &file, name.as_deref(), force).await
+            }
+            ConfigCommands::Delete { name, yes } => cli::config::delete(opts, &name, yes).await,
             ConfigCommands::Rename { old_name, new_name } => {
-                cli::config::rename(&opts, &old_name, &new_name).await
+                cli::config::rename(opts, &old_name, &new_name).await
+            }
+            ConfigCommands::Validate { name, file, local } => {
+                cli::config::validate(opts, name.as_deref(), file.as_deref(), local).await
+            }
+            ConfigCommands::Generate {
+                app,
+                host,
+                env,
+                oas,
+                auth,
+                output,
+                force,
+            } => {
+                cli::config::generate(
+                    opts,
+                    &app,
+                    &host,
+                    env.as_deref(),
+                    oas.as_deref(),
+                    auth,
+                    output.as_deref(),
+                    force,
+                )
+                .await
             }
-            ConfigCommands::Validate { name, file } => {
-                cli::config::validate(&opts, name.as_deref(), file.as_deref()).await
+            ConfigCommands::Migrate { force } => cli::config::migrate(opts.dry_run, force),
+            ConfigCommands::Export { redact, output } => {
+                cli::config::export(opts, redact, output.as_deref())
             }
+            ConfigCommands::Import { file } => cli::config::import(opts, &file),
+            ConfigCommands::Lint { file } => cli::config::lint(opts, file.as_deref()),
         },
         Commands::Secret(secret_cmd) => match secret_cmd {
-            SecretCommands::List => cli::secret::list(&opts).await,
+            SecretCommands::List => cli::secret::list(opts).await,
+            SecretCommands::Usages { name } => cli::secret::usages(opts, &name).await,
         },
         Commands::Audit(audit_cmd) => match audit_cmd {
-            AuditCommands::List { filters } => cli::audit::list(&opts, &filters).await,
+            AuditCommands::List { filters } => cli::audit::list(opts, &filters).await,
+            AuditCommands::Summary {
+                since,
+                until,
+                business_hours,
+                utc,
+            } => cli::audit::summary(opts, &since, until.as_deref(), &business_hours, utc).await,
+        },
+        Commands::Suppress(suppress_cmd) => match suppress_cmd {
+            SuppressCommands::Add {
+                plugin,
+                paths,
+                app,
+                env,
+                expires,
+                reason,
+            } => cli::suppress::add(
+                opts.format,
+                &plugin,
+                paths,
+                app,
+                env,
+                expires.as_deref(),
+                &reason,
+            ),
+            SuppressCommands::List => cli::suppress::list(opts.format),
+            SuppressCommands::Prune => cli::suppress::prune(opts.format, opts.dry_run),
+        },
+        Commands::Api {
+            method,
+            path,
+            body,
+            cache_ttl,
+        } => cli::api::request(opts, method, &path, body.as_deref(), cache_ttl).await,
+        Commands::Query { tables: true, .. } => cli::query::tables(opts.format),
+        Commands::Query { sql, since, .. } => {
+            cli::query::run(opts, sql.as_deref().unwrap_or_default(), since.as_deref()).await
+        }
+        Commands::Auth(auth_cmd) => match auth_cmd {
+            AuthCommands::Login { api_key } => cli::auth::login(opts, api_key.as_deref()).await,
+            AuthCommands::Refresh => cli::auth::refresh(opts).await,
+            AuthCommands::Logout { forget_key } => cli::auth::logout(opts, forget_key),
+            AuthCommands::Status => cli::auth::status(opts),
+        },
+        Commands::Report(report_cmd) => match report_cmd {
+            ReportCommands::Findings {
+                since,
+                app,
+                group_by,
+            } => cli::report::findings(opts, &since, &app, group_by).await,
+            ReportCommands::Trend { app, env, since } => {
+                cli::report::trend(opts, &app, env.as_deref(), &since).await
+            }
+            ReportCommands::Coverage { scan_id, oas } => {
+                cli::report::coverage(opts, &scan_id, oas.as_deref()).await
+            }
+            ReportCommands::StaleApps { threshold, env } => {
+                cli::report::stale_apps(opts, &threshold, env.as_deref()).await
+            }
         },
         Commands::Env(env_cmd) => match env_cmd {
-            EnvCommands::List { app, pagination } => cli::env::list(&opts, &app, &pagination).await,
+            EnvCommands::List { app, pagination } => cli::env::list(opts, &app, &pagination).await,
             EnvCommands::Config { app, env, output } => {
-                cli::env::config(&opts, &app, &env, output.as_deref()).await
+                cli::env::config(opts, &app, &env, output.as_deref()).await
             }
-            EnvCommands::Create { app, name } => cli::env::create(&opts, &app, &name).await,
-            EnvCommands::Delete { app, env, yes } => cli::env::delete(&opts, &app, &env, yes).await,
+            EnvCommands::Create { app, name } => cli::env::create(opts, &app, &name).await,
+            EnvCommands::Delete { app, env, yes } => cli::env::delete(opts, &app, &env, yes).await,
         },
         Commands::Cache(cache_cmd) => match cache_cmd {
             CacheCommands::Status => cli::cache::status(opts.format),
             CacheCommands::Clear => cli::cache::clear(opts.format),
             CacheCommands::Path => cli::cache::path(),
+            CacheCommands::Warm {
+                scans,
+                apps,
+                teams,
+                ttl,
+            } => cli::cache::warm(opts, scans, apps, teams, ttl.as_deref()).await,
+        },
+        Commands::Daemon(daemon_cmd) => match daemon_cmd {
+            DaemonCommands::Start { interval } => cli::daemon::start(opts, interval).await,
+            DaemonCommands::Stop => cli::daemon::stop(opts.format).await,
+            DaemonCommands::Status => cli::daemon::status(opts.format),
+            DaemonCommands::Run { interval } => cli::daemon::run(opts, interval).await,
         },
-        Commands::Completion { shell } => {
-            generate(shell, &mut Cli::command(), "hawkop", &mut std::io::stdout());
+        Commands::History(history_cmd) => match history_cmd {
+            HistoryCommands::List { limit } => cli::history::list(opts.format, limit),
+            HistoryCommands::Search {
+                resource,
+                query,
+                limit,
+            } => cli::history::search(opts.format, &resource, query.as_deref(), limit),
+            HistoryCommands::Clear => cli::history::clear(opts.format),
+            HistoryCommands::Path => cli::history::path(),
+        },
+        Commands::Rerun { id, last: _ } => cli::history::rerun(id),
+        Commands::Completion { shell, dynamic } => {
+            if dynamic {
+                cli::completions::write_dynamic_script(shell, &mut std::io::stdout())?;
+            } else {
+                generate(shell, &mut Cli::command(), "hawkop", &mut std::io::stdout());
+            }
             Ok(())
         }
-    };
-
-    // Log debug info on completion
-    if debug {
-        if let Err(ref e) = result {
-            log::debug!("Error: {:?}", e);
-        } else {
-            log::debug!("Command completed successfully");
-        }
     }
-
-    result
 }
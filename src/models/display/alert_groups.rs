@@ -0,0 +1,168 @@
+//! Alerts split into sections for `scan get --group-by`
+
+use std::collections::HashMap;
+
+use crate::cli::AlertGroupBy;
+use crate::client::models::{ApplicationAlert, severity_rank};
+
+/// Heading for alerts without a category
+const UNCATEGORIZED: &str = "Uncategorized";
+
+/// Heading for alerts without a CWE
+const NO_CWE: &str = "No CWE";
+
+/// One section of the grouped alerts table
+#[derive(Debug, Clone)]
+pub struct AlertGroup {
+    /// Section heading: the category, severity, or `CWE-<id>`
+    pub label: String,
+
+    /// Alerts in the section, highest severity first
+    pub alerts: Vec<ApplicationAlert>,
+}
+
+impl AlertGroup {
+    /// Affected paths across the section's alerts
+    pub fn paths(&self) -> u32 {
+        self.alerts.iter().map(|a| a.uri_count).sum()
+    }
+}
+
+/// Split alerts into sections
+///
+/// `categories` maps plugin IDs to categories and is only read when grouping
+/// by category. Severity sections run from High to Low; other sections run
+/// from the most affected paths down, with the unlabeled section last.
+pub fn group_alerts(
+    alerts: Vec<ApplicationAlert>,
+    by: AlertGroupBy,
+    categories: &HashMap<String, String>,
+) -> Vec<AlertGroup> {
+    let mut groups: Vec<AlertGroup> = Vec::new();
+    for alert in alerts {
+        let label = group_label(&alert, by, categories);
+        match groups.iter_mut().find(|g| g.label == label) {
+            Some(group) => group.alerts.push(alert),
+            None => groups.push(AlertGroup {
+                label,
+                alerts: vec![alert],
+            }),
+        }
+    }
+
+    for group in &mut groups {
+        group.alerts.sort_by(|a, b| {
+            severity_rank(&a.severity)
+                .cmp(&severity_rank(&b.severity))
+                .then_with(|| a.plugin_id.cmp(&b.plugin_id))
+        });
+    }
+    match by {
+        AlertGroupBy::Severity => groups.sort_by_key(|g| severity_rank(&g.label)),
+        AlertGroupBy::Category | AlertGroupBy::Cwe => groups.sort_by(|a, b| {
+            let unlabeled = |g: &AlertGroup| g.label == UNCATEGORIZED || g.label == NO_CWE;
+            unlabeled(a)
+                .cmp(&unlabeled(b))
+                .then_with(|| b.paths().cmp(&a.paths()))
+                .then_with(|| a.label.cmp(&b.label))
+        }),
+    }
+    groups
+}
+
+fn group_label(
+    alert: &ApplicationAlert,
+    by: AlertGroupBy,
+    categories: &HashMap<String, String>,
+) -> String {
+    match by {
+        AlertGroupBy::Category => categories
+            .get(&alert.plugin_id)
+            .map(|c| c.trim())
+            .filter(|c| !c.is_empty())
+            .unwrap_or(UNCATEGORIZED)
+            .to_string(),
+        AlertGroupBy::Severity => {
+            if alert.severity.is_empty() {
+                "Unknown".to_string()
+            } else {
+                alert.severity.clone()
+            }
+        }
+        // ZAP reports -1 or 0 for plugins without a CWE
+        AlertGroupBy::Cwe => match alert.cwe_id.as_deref().map(str::trim) {
+            Some(id) if !id.is_empty() && id != "0" && id != "-1" => format!("CWE-{}", id),
+            _ => NO_CWE.to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn alert(plugin_id: &str, severity: &str, cwe: Option<&str>, paths: u32) -> ApplicationAlert {
+        ApplicationAlert {
+            plugin_id: plugin_id.to_string(),
+            name: format!("Plugin {}", plugin_id),
+            description: String::new(),
+            severity: severity.to_string(),
+            cwe_id: cwe.map(str::to_string),
+            references: vec![],
+            uri_count: paths,
+            alert_status_stats: vec![],
+        }
+    }
+
+    fn labels(groups: &[AlertGroup]) -> Vec<&str> {
+        groups.iter().map(|g| g.label.as_str()).collect()
+    }
+
+    fn alerts() -> Vec<ApplicationAlert> {
+        vec![
+            alert("10020", "Low", Some("-1"), 3),
+            alert("40012", "High", Some("79"), 2),
+            alert("40018", "High", Some("89"), 1),
+            alert("10038", "Medium", Some("693"), 9),
+            alert("40014", "Medium", Some("79"), 4),
+        ]
+    }
+
+    #[test]
+    fn test_group_by_severity() {
+        let groups = group_alerts(alerts(), AlertGroupBy::Severity, &HashMap::new());
+        assert_eq!(labels(&groups), vec!["High", "Medium", "Low"]);
+        assert_eq!(groups[1].paths(), 13);
+    }
+
+    #[test]
+    fn test_group_by_cwe() {
+        let groups = group_alerts(alerts(), AlertGroupBy::Cwe, &HashMap::new());
+        // Most paths first, alerts without a CWE last
+        assert_eq!(
+            labels(&groups),
+            vec!["CWE-693", "CWE-79", "CWE-89", "No CWE"]
+        );
+        let cwe79: Vec<_> = groups[1]
+            .alerts
+            .iter()
+            .map(|a| a.plugin_id.as_str())
+            .collect();
+        assert_eq!(cwe79, vec!["40012", "40014"]);
+    }
+
+    #[test]
+    fn test_group_by_category() {
+        let categories = HashMap::from([
+            ("40012".to_string(), "Injection".to_string()),
+            ("40018".to_string(), "Injection".to_string()),
+            ("10038".to_string(), "Security Misconfiguration".to_string()),
+        ]);
+        let groups = group_alerts(alerts(), AlertGroupBy::Category, &categories);
+        assert_eq!(
+            labels(&groups),
+            vec!["Security Misconfiguration", "Injection", "Uncategorized"]
+        );
+        assert_eq!(groups[2].alerts.len(), 2);
+    }
+}
@@ -1,12 +1,13 @@
 //! Application display model
 
+use schemars::JsonSchema;
 use serde::Serialize;
 use tabled::Tabled;
 
 use crate::client::models::Application;
 
 /// Application display model for table/JSON output.
-#[derive(Debug, Clone, Tabled, Serialize)]
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
 pub struct AppDisplay {
     /// Whether this is a cloud/hosted app
     #[tabled(rename = "CLOUD")]
@@ -19,6 +20,10 @@ pub struct AppDisplay {
     /// Application name
     #[tabled(rename = "NAME")]
     pub name: String,
+
+    /// Application status (ACTIVE, ENV_INCOMPLETE)
+    #[tabled(skip)]
+    pub status: String,
 }
 
 impl From<Application> for AppDisplay {
@@ -37,6 +42,7 @@ impl From<Application> for AppDisplay {
             },
             id: app.id,
             name: app.name,
+            status: app.status.unwrap_or_default(),
         }
     }
 }
@@ -57,6 +63,7 @@ impl From<&Application> for AppDisplay {
             },
             id: app.id.clone(),
             name: app.name.clone(),
+            status: app.status.clone().unwrap_or_default(),
         }
     }
 }
@@ -66,7 +73,7 @@ impl From<&Application> for AppDisplay {
 /// Shows more fields than `AppDisplay` (which is optimized for list tables).
 /// Used by single-app commands like `app get`, `app create`, `app update`.
 #[allow(dead_code)] // Used in Sprint 3: app get/update
-#[derive(Debug, Clone, Tabled, Serialize)]
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
 pub struct AppDetailDisplay {
     /// Application ID
     #[tabled(rename = "APP ID")]
@@ -0,0 +1,480 @@
+//! Duplicate application detection for `app duplicates`
+//!
+//! Applications are grouped when they share a name, a normalized name (case,
+//! punctuation, and environment/copy suffixes removed), a scan target host,
+//! or a near-identical normalized name (edit distance of one).
+
+use std::collections::{BTreeMap, HashMap};
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::client::models::Application;
+
+/// Name suffixes that usually indicate an environment or an accidental copy
+const NOISE_SUFFIXES: &[&str] = &[
+    "development",
+    "production",
+    "staging",
+    "stage",
+    "prod",
+    "test",
+    "copy",
+    "dev",
+    "qa",
+];
+
+/// Minimum normalized name length for fuzzy (edit distance) matching
+const FUZZY_MIN_LEN: usize = 5;
+
+/// Why applications were grouped together, strongest reason first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicateReason {
+    /// Names are identical ignoring case
+    SameName,
+    /// Names match after removing punctuation and env/copy suffixes
+    NormalizedName,
+    /// Scan targets point at the same host
+    SameHost,
+    /// Normalized names differ by a single character
+    SimilarName,
+}
+
+impl std::fmt::Display for DuplicateReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SameName => write!(f, "same name"),
+            Self::NormalizedName => write!(f, "normalized name"),
+            Self::SameHost => write!(f, "same host"),
+            Self::SimilarName => write!(f, "similar name"),
+        }
+    }
+}
+
+/// An application considered for duplicate detection
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct DuplicateCandidate {
+    /// Application ID
+    pub id: String,
+
+    /// Application name
+    pub name: String,
+
+    /// Environment name (if the listing provides one)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub env: Option<String>,
+
+    /// Scan target host (cloud apps only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+
+    /// Teams the application is assigned to
+    pub teams: Vec<String>,
+}
+
+impl DuplicateCandidate {
+    /// Build a candidate from an application and its team names
+    pub fn from_app(app: &Application, teams: Vec<String>) -> Self {
+        Self {
+            id: app.id.clone(),
+            name: app.name.clone(),
+            env: app.env.clone().filter(|e| !e.is_empty()),
+            host: app
+                .cloud_scan_target
+                .as_ref()
+                .and_then(|t| t.target_url.clone())
+                .filter(|u| !u.is_empty()),
+            teams,
+        }
+    }
+}
+
+/// A set of applications that look like duplicates of each other
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct DuplicateGroup {
+    /// 1-based group number
+    pub group: usize,
+
+    /// Strongest reason the applications were grouped
+    pub reason: DuplicateReason,
+
+    /// Application suggested as the one to keep
+    pub keep_id: String,
+
+    /// Human-readable cleanup suggestion
+    pub suggestion: String,
+
+    /// Applications in the group (the one to keep first)
+    pub apps: Vec<DuplicateCandidate>,
+}
+
+impl DuplicateGroup {
+    /// Table/CSV rows, one per application
+    pub fn rows(&self) -> Vec<DuplicateRow> {
+        self.apps
+            .iter()
+            .map(|app| DuplicateRow {
+                group: self.group,
+                action: if app.id == self.keep_id {
+                    "keep".to_string()
+                } else {
+                    "merge/archive".to_string()
+                },
+                id: app.id.clone(),
+                name: app.name.clone(),
+                env: app.env.clone().unwrap_or_else(|| "--".to_string()),
+                host: app.host.clone().unwrap_or_else(|| "--".to_string()),
+                teams: if app.teams.is_empty() {
+                    "--".to_string()
+                } else {
+                    app.teams.join(", ")
+                },
+                reason: self.reason.to_string(),
+            })
+            .collect()
+    }
+}
+
+/// Table row for `app duplicates` output
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct DuplicateRow {
+    /// Group number
+    #[tabled(rename = "GROUP")]
+    pub group: usize,
+
+    /// Suggested action (keep, merge/archive)
+    #[tabled(rename = "ACTION")]
+    pub action: String,
+
+    /// Application ID
+    #[tabled(rename = "APP ID")]
+    pub id: String,
+
+    /// Application name
+    #[tabled(rename = "NAME")]
+    pub name: String,
+
+    /// Environment name
+    #[tabled(rename = "ENV")]
+    pub env: String,
+
+    /// Scan target host
+    #[tabled(rename = "HOST")]
+    pub host: String,
+
+    /// Assigned teams
+    #[tabled(rename = "TEAMS")]
+    pub teams: String,
+
+    /// Grouping reason
+    #[tabled(rename = "REASON")]
+    pub reason: String,
+}
+
+/// Group candidates that look like duplicates.
+///
+/// Groups are ordered by reason strength, then by the kept application's name.
+pub fn find_duplicates(candidates: Vec<DuplicateCandidate>) -> Vec<DuplicateGroup> {
+    let n = candidates.len();
+    let mut sets = DisjointSet::new(n);
+    let mut reasons: HashMap<(usize, usize), DuplicateReason> = HashMap::new();
+
+    let mut link = |sets: &mut DisjointSet, a: usize, b: usize, reason: DuplicateReason| {
+        sets.union(a, b);
+        let key = (a.min(b), a.max(b));
+        let entry = reasons.entry(key).or_insert(reason);
+        *entry = (*entry).min(reason);
+    };
+
+    // Exact-key matches (name, normalized name, host)
+    for reason in [
+        DuplicateReason::SameName,
+        DuplicateReason::NormalizedName,
+        DuplicateReason::SameHost,
+    ] {
+        let mut by_key: HashMap<String, Vec<usize>> = HashMap::new();
+        for (i, c) in candidates.iter().enumerate() {
+            if let Some(key) = match_key(reason, c) {
+                by_key.entry(key).or_default().push(i);
+            }
+        }
+        for indices in by_key.values() {
+            for &other in &indices[1..] {
+                link(&mut sets, indices[0], other, reason);
+            }
+        }
+    }
+
+    // Fuzzy matches on normalized names
+    let normalized: Vec<String> = candidates.iter().map(|c| normalize_name(&c.name)).collect();
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let (a, b) = (&normalized[i], &normalized[j]);
+            if a != b
+                && a.len().min(b.len()) >= FUZZY_MIN_LEN
+                && a.len().abs_diff(b.len()) <= 1
+                && edit_distance(a, b) == 1
+            {
+                link(&mut sets, i, j, DuplicateReason::SimilarName);
+            }
+        }
+    }
+
+    // Collect groups with more than one member
+    let mut members: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for i in 0..n {
+        members.entry(sets.find(i)).or_default().push(i);
+    }
+
+    let mut groups: Vec<DuplicateGroup> = members
+        .into_values()
+        .filter(|m| m.len() > 1)
+        .map(|indices| {
+            let reason = reasons
+                .iter()
+                .filter(|((a, b), _)| indices.contains(a) && indices.contains(b))
+                .map(|(_, r)| *r)
+                .min()
+                .unwrap_or(DuplicateReason::SimilarName);
+
+            let mut apps: Vec<DuplicateCandidate> =
+                indices.iter().map(|&i| candidates[i].clone()).collect();
+            apps.sort_by_key(keep_rank);
+
+            let keep = &apps[0];
+            let others = apps.len() - 1;
+            let suggestion = format!(
+                "Keep '{}' ({}); move environments and team assignments from the other {} app(s), then archive them",
+                keep.name, keep.id, others
+            );
+
+            DuplicateGroup {
+                group: 0,
+                reason,
+                keep_id: keep.id.clone(),
+                suggestion,
+                apps,
+            }
+        })
+        .collect();
+
+    groups.sort_by(|a, b| {
+        a.reason.cmp(&b.reason).then_with(|| {
+            a.apps[0]
+                .name
+                .to_lowercase()
+                .cmp(&b.apps[0].name.to_lowercase())
+        })
+    });
+    for (i, group) in groups.iter_mut().enumerate() {
+        group.group = i + 1;
+    }
+
+    groups
+}
+
+/// Exact-match key for a reason (None = candidate can't match on it)
+fn match_key(reason: DuplicateReason, candidate: &DuplicateCandidate) -> Option<String> {
+    let key = match reason {
+        DuplicateReason::SameName => candidate.name.trim().to_lowercase(),
+        DuplicateReason::NormalizedName => normalize_name(&candidate.name),
+        DuplicateReason::SameHost => normalize_host(candidate.host.as_deref()?),
+        DuplicateReason::SimilarName => return None,
+    };
+    Some(key).filter(|k| !k.is_empty())
+}
+
+/// Preference order for the application to keep: assigned to a team, then
+/// the cleanest (shortest) name, then name and ID for stability
+fn keep_rank(app: &DuplicateCandidate) -> (bool, usize, String, String) {
+    (
+        app.teams.is_empty(),
+        app.name.len(),
+        app.name.to_lowercase(),
+        app.id.clone(),
+    )
+}
+
+/// Lowercase, strip punctuation, and drop trailing environment/copy markers
+/// (`My-App (Copy) 2` → `myapp`)
+pub fn normalize_name(name: &str) -> String {
+    let mut words: Vec<String> = name
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(String::from)
+        .collect();
+
+    while words.len() > 1 {
+        let last = words.last().map(String::as_str).unwrap_or("");
+        if NOISE_SUFFIXES.contains(&last) || last.chars().all(|c| c.is_ascii_digit()) {
+            words.pop();
+        } else {
+            break;
+        }
+    }
+
+    words.concat()
+}
+
+/// Reduce a URL to its host (`https://www.Example.com:443/api` → `example.com`)
+pub fn normalize_host(url: &str) -> String {
+    let without_scheme = url.split("://").nth(1).unwrap_or(url);
+    let host_port = without_scheme.split(['/', '?', '#']).next().unwrap_or("");
+    let host = host_port.split(':').next().unwrap_or("");
+    let host = host.to_lowercase();
+    host.strip_prefix("www.").unwrap_or(&host).to_string()
+}
+
+/// Levenshtein distance between two strings
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        curr[0] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            curr[j] = (prev[j] + 1).min(curr[j - 1] + 1).min(prev[j - 1] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()]
+}
+
+/// Minimal union-find for grouping candidates
+struct DisjointSet {
+    parent: Vec<usize>,
+}
+
+impl DisjointSet {
+    fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+        }
+    }
+
+    fn find(&mut self, i: usize) -> usize {
+        if self.parent[i] != i {
+            let root = self.find(self.parent[i]);
+            self.parent[i] = root;
+        }
+        self.parent[i]
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (ra, rb) = (self.find(a), self.find(b));
+        if ra != rb {
+            self.parent[rb.max(ra)] = rb.min(ra);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(id: &str, name: &str, host: Option<&str>) -> DuplicateCandidate {
+        DuplicateCandidate {
+            id: id.to_string(),
+            name: name.to_string(),
+            env: None,
+            host: host.map(String::from),
+            teams: vec![],
+        }
+    }
+
+    #[test]
+    fn test_normalize_name_strips_noise() {
+        assert_eq!(normalize_name("My-App"), "myapp");
+        assert_eq!(normalize_name("my_app (Copy) 2"), "myapp");
+        assert_eq!(normalize_name("Billing API - prod"), "billingapi");
+        // A name made only of a noise word is kept
+        assert_eq!(normalize_name("Dev"), "dev");
+    }
+
+    #[test]
+    fn test_normalize_host() {
+        assert_eq!(
+            normalize_host("https://www.Example.com:8443/api"),
+            "example.com"
+        );
+        assert_eq!(normalize_host("example.com/path"), "example.com");
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("webstore", "webstore"), 0);
+        assert_eq!(edit_distance("webstore", "webstores"), 1);
+        assert_eq!(edit_distance("webstore", "wepstore"), 1);
+        assert_eq!(edit_distance("webstore", "billing"), 8);
+    }
+
+    #[test]
+    fn test_find_duplicates_groups_by_reason() {
+        let groups = find_duplicates(vec![
+            candidate("1", "WebStore", None),
+            candidate("2", "webstore", None),
+            candidate("3", "Billing-API", None),
+            candidate("4", "billing_api_dev", None),
+            candidate("5", "Orders", Some("https://orders.example.com")),
+            candidate("6", "Checkout", Some("https://orders.example.com/v2")),
+            candidate("7", "Inventory", None),
+        ]);
+
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[0].reason, DuplicateReason::SameName);
+        assert_eq!(groups[1].reason, DuplicateReason::NormalizedName);
+        assert_eq!(groups[2].reason, DuplicateReason::SameHost);
+        assert!(groups.iter().all(|g| g.apps.len() == 2));
+        assert!(
+            !groups
+                .iter()
+                .any(|g| g.apps.iter().any(|a| a.name == "Inventory"))
+        );
+    }
+
+    #[test]
+    fn test_find_duplicates_similar_names() {
+        let groups = find_duplicates(vec![
+            candidate("1", "payments", None),
+            candidate("2", "paymemts", None),
+            candidate("3", "api", None),
+            candidate("4", "apx", None),
+        ]);
+
+        // Short names are not fuzzy-matched
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].reason, DuplicateReason::SimilarName);
+    }
+
+    #[test]
+    fn test_keep_prefers_team_assigned_app() {
+        let mut assigned = candidate("2", "webstore-copy", None);
+        assigned.teams = vec!["Platform".to_string()];
+        let groups = find_duplicates(vec![candidate("1", "webstore", None), assigned]);
+
+        assert_eq!(groups[0].keep_id, "2");
+        let rows = groups[0].rows();
+        assert_eq!(rows[0].action, "keep");
+        assert_eq!(rows[1].action, "merge/archive");
+    }
+
+    #[test]
+    fn test_groups_numbered_sequentially() {
+        let groups = find_duplicates(vec![
+            candidate("1", "alpha", None),
+            candidate("2", "Alpha", None),
+            candidate("3", "beta", None),
+            candidate("4", "BETA", None),
+        ]);
+
+        let numbers: Vec<_> = groups.iter().map(|g| g.group).collect();
+        assert_eq!(numbers, vec![1, 2]);
+    }
+}
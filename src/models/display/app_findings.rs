@@ -0,0 +1,179 @@
+//! Per-environment findings summary for `app view findings`
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::client::models::ScanResult;
+
+use super::common::timestamp_ms;
+use super::scan::{format_relative_time, format_status};
+
+/// Alert statuses counted as open: new and triaged (assigned) findings.
+/// Risk-accepted and false-positive findings are excluded.
+const OPEN_STATUSES: &[&str] = &["UNKNOWN", "PROMOTED"];
+
+/// Latest findings for one environment of an application
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct AppEnvFindings {
+    /// Environment name
+    #[tabled(rename = "ENV")]
+    pub env: String,
+
+    /// Status of the most recent scan (any status)
+    #[tabled(rename = "LAST SCAN")]
+    pub last_scan_status: String,
+
+    /// When the most recent scan started
+    #[tabled(rename = "STARTED")]
+    #[serde(skip)]
+    pub started: String,
+
+    /// Open high findings in the latest completed scan
+    #[tabled(rename = "HIGH")]
+    pub high: u32,
+
+    /// Open medium findings in the latest completed scan
+    #[tabled(rename = "MEDIUM")]
+    pub medium: u32,
+
+    /// Open low findings in the latest completed scan
+    #[tabled(rename = "LOW")]
+    pub low: u32,
+
+    /// Change in open findings vs the previous completed scan
+    #[tabled(rename = "TREND")]
+    pub trend: String,
+
+    /// Most recent scan ID
+    #[tabled(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_scan_id: Option<String>,
+
+    /// Latest completed scan the counts come from
+    #[tabled(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_scan_id: Option<String>,
+
+    /// Completed scan before it, used for the trend
+    #[tabled(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_scan_id: Option<String>,
+
+    /// Open findings (all severities) in the previous completed scan
+    #[tabled(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_open: Option<u32>,
+}
+
+impl AppEnvFindings {
+    /// Summarize an environment from its recent scans (any order)
+    pub fn from_scans(env: &str, scans: &[ScanResult]) -> Self {
+        let mut sorted: Vec<&ScanResult> = scans.iter().collect();
+        sorted.sort_by_key(|s| std::cmp::Reverse(timestamp_ms(s)));
+
+        let last = sorted.first();
+        let mut completed = sorted
+            .iter()
+            .filter(|s| s.scan.status.eq_ignore_ascii_case("COMPLETED"));
+        let current = completed.next();
+        let previous = completed.next();
+
+        let (high, medium, low) = current.map_or((0, 0, 0), |s| open_counts(s));
+        let previous_open = previous.map(|s| {
+            let (h, m, l) = open_counts(s);
+            h + m + l
+        });
+
+        Self {
+            env: env.to_string(),
+            last_scan_status: last
+                .map_or("No scans".to_string(), |s| format_status(&s.scan.status)),
+            started: last.map_or("--".to_string(), |s| format_relative_time(timestamp_ms(s))),
+            high,
+            medium,
+            low,
+            trend: format_trend(current.map(|_| high + medium + low), previous_open),
+            last_scan_id: last.map(|s| s.scan.id.clone()),
+            completed_scan_id: current.map(|s| s.scan.id.clone()),
+            previous_scan_id: previous.map(|s| s.scan.id.clone()),
+            previous_open,
+        }
+    }
+}
+
+/// Open (high, medium, low) findings in a scan's alert stats
+pub fn open_counts(result: &ScanResult) -> (u32, u32, u32) {
+    let Some(stats) = &result.alert_stats else {
+        return (0, 0, 0);
+    };
+    let count = |severity: &str| -> u32 {
+        stats
+            .alert_status_stats
+            .iter()
+            .filter(|s| OPEN_STATUSES.contains(&s.alert_status.as_str()))
+            .filter_map(|s| s.severity_stats.get(severity))
+            .sum()
+    };
+    (count("High"), count("Medium"), count("Low"))
+}
+
+/// "+3", "-2", "=", or "--" when there is nothing to compare
+fn format_trend(current: Option<u32>, previous: Option<u32>) -> String {
+    match (current, previous) {
+        (Some(current), Some(previous)) => match current.cmp(&previous) {
+            std::cmp::Ordering::Greater => format!("+{}", current - previous),
+            std::cmp::Ordering::Less => format!("-{}", previous - current),
+            std::cmp::Ordering::Equal => "=".to_string(),
+        },
+        _ => "--".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::fixtures::ScanResultBuilder;
+
+    #[test]
+    fn test_summary_uses_latest_completed_scans() {
+        let scans = vec![
+            ScanResultBuilder::new("old", "a1")
+                .timestamp("1000")
+                .with_findings(1, 1, 0)
+                .build(),
+            ScanResultBuilder::new("running", "a1")
+                .status("STARTED")
+                .timestamp("3000")
+                .build(),
+            ScanResultBuilder::new("latest", "a1")
+                .timestamp("2000")
+                .with_findings(2, 1, 1)
+                .with_triaged_findings(1, 0, 0)
+                .build(),
+        ];
+
+        let summary = AppEnvFindings::from_scans("prod", &scans);
+
+        assert_eq!(summary.last_scan_status, "Running");
+        assert_eq!(summary.last_scan_id.as_deref(), Some("running"));
+        assert_eq!(summary.completed_scan_id.as_deref(), Some("latest"));
+        assert_eq!((summary.high, summary.medium, summary.low), (3, 1, 1));
+        assert_eq!(summary.previous_open, Some(2));
+        assert_eq!(summary.trend, "+3");
+    }
+
+    #[test]
+    fn test_summary_without_scans() {
+        let summary = AppEnvFindings::from_scans("dev", &[]);
+        assert_eq!(summary.last_scan_status, "No scans");
+        assert_eq!(summary.trend, "--");
+    }
+
+    #[test]
+    fn test_format_trend() {
+        assert_eq!(format_trend(Some(2), Some(5)), "-3");
+        assert_eq!(format_trend(Some(4), Some(4)), "=");
+        assert_eq!(format_trend(Some(4), None), "--");
+    }
+}
@@ -0,0 +1,225 @@
+//! Application inventory with scan posture, for `app export`
+//!
+//! One row per application: its environments, type, status, risk level,
+//! team assignments, last scan, and open findings. Open findings are
+//! summed over each environment's latest completed scan among the scans
+//! given, as `scan list --group-by app` counts them.
+
+use std::collections::{BTreeSet, HashMap};
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::client::models::{Application, ScanResult};
+use crate::output::formatters::format_timestamp_local;
+
+use super::app_findings::open_counts;
+use super::common::timestamp_ms;
+
+/// One application in the inventory
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct AppInventoryRow {
+    /// Application name
+    #[tabled(rename = "APP")]
+    pub app: String,
+
+    /// Application ID
+    #[tabled(rename = "APP ID")]
+    pub app_id: String,
+
+    /// Environments, comma-separated
+    #[tabled(rename = "ENVS")]
+    pub envs: String,
+
+    /// Application type (STANDARD or CLOUD)
+    #[tabled(rename = "TYPE")]
+    pub app_type: String,
+
+    /// Application status (ACTIVE, ENV_INCOMPLETE)
+    #[tabled(rename = "STATUS")]
+    pub status: String,
+
+    /// Risk level set in StackHawk
+    #[tabled(rename = "RISK")]
+    pub risk_level: String,
+
+    /// Assigned teams, `; `-separated
+    #[tabled(rename = "TEAMS")]
+    pub teams: String,
+
+    /// When the last scan started (local time), or "never"
+    #[tabled(rename = "LAST SCAN")]
+    pub last_scan: String,
+
+    /// Open high findings
+    #[tabled(rename = "OPEN HIGH")]
+    pub high: u32,
+
+    /// Open medium findings
+    #[tabled(rename = "OPEN MEDIUM")]
+    pub medium: u32,
+
+    /// Last scan start time (epoch milliseconds)
+    #[tabled(skip)]
+    pub last_scanned: Option<i64>,
+
+    /// Last scan ID
+    #[tabled(skip)]
+    pub last_scan_id: Option<String>,
+}
+
+impl AppInventoryRow {
+    /// Inventory rows for `apps`, sorted by name
+    ///
+    /// `apps` may hold one entry per environment; they are merged by ID.
+    /// `teams_by_app` maps app IDs to team names, and `scans` are any of
+    /// the apps' scans, in any order.
+    pub fn build(
+        apps: &[Application],
+        teams_by_app: &HashMap<String, Vec<String>>,
+        scans: Vec<ScanResult>,
+    ) -> Vec<Self> {
+        let mut scans_by_app: HashMap<String, Vec<ScanResult>> = HashMap::new();
+        for scan in scans {
+            scans_by_app
+                .entry(scan.scan.application_id.clone())
+                .or_default()
+                .push(scan);
+        }
+
+        let mut envs_by_app: HashMap<&str, BTreeSet<String>> = HashMap::new();
+        let mut unique: Vec<&Application> = Vec::new();
+        for app in apps {
+            let envs = envs_by_app.entry(&app.id).or_insert_with(|| {
+                unique.push(app);
+                BTreeSet::new()
+            });
+            envs.extend(app.env.iter().filter(|e| !e.is_empty()).cloned());
+        }
+
+        let mut rows: Vec<Self> = unique
+            .into_iter()
+            .map(|app| {
+                let mut scans = scans_by_app.remove(&app.id).unwrap_or_default();
+                scans.sort_by_key(|s| std::cmp::Reverse(timestamp_ms(s)));
+                let mut envs = envs_by_app.remove(app.id.as_str()).unwrap_or_default();
+                envs.extend(scans.iter().map(|s| s.scan.env.clone()));
+                let mut teams = teams_by_app.get(&app.id).cloned().unwrap_or_default();
+                teams.sort();
+                Self::new(app, envs, teams, &scans)
+            })
+            .collect();
+        rows.sort_by(|a, b| {
+            a.app
+                .to_lowercase()
+                .cmp(&b.app.to_lowercase())
+                .then_with(|| a.app_id.cmp(&b.app_id))
+        });
+        rows
+    }
+
+    /// Row for `app` with its `scans`, newest first
+    fn new(
+        app: &Application,
+        envs: BTreeSet<String>,
+        teams: Vec<String>,
+        scans: &[ScanResult],
+    ) -> Self {
+        let (mut high, mut medium) = (0, 0);
+        for env in &envs {
+            let completed = scans
+                .iter()
+                .find(|s| s.scan.env == *env && s.scan.status.eq_ignore_ascii_case("COMPLETED"));
+            if let Some(scan) = completed {
+                let (h, m, _) = open_counts(scan);
+                high += h;
+                medium += m;
+            }
+        }
+
+        let last = scans.first();
+        Self {
+            app: app.name.clone(),
+            app_id: app.id.clone(),
+            envs: envs.into_iter().collect::<Vec<_>>().join(", "),
+            app_type: app
+                .application_type
+                .clone()
+                .unwrap_or_else(|| "STANDARD".to_string()),
+            status: app.status.clone().unwrap_or_default(),
+            risk_level: app.risk_level.clone().unwrap_or_default(),
+            teams: teams.join("; "),
+            last_scan: last.map_or_else(
+                || "never".to_string(),
+                |s| format_timestamp_local(&s.scan.timestamp),
+            ),
+            high,
+            medium,
+            last_scanned: last.map(timestamp_ms),
+            last_scan_id: last.map(|s| s.scan.id.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::fixtures::{ApplicationBuilder, ScanResultBuilder};
+
+    fn app(id: &str, name: &str, env: &str) -> Application {
+        ApplicationBuilder::new(id)
+            .name(name)
+            .env(env)
+            .risk_level("HIGH")
+            .build()
+    }
+
+    fn scan(app_id: &str, env: &str, status: &str, timestamp: i64, high: u32) -> ScanResult {
+        ScanResultBuilder::new(format!("{}-{}-{}", app_id, env, timestamp), app_id)
+            .env(env)
+            .status(status)
+            .timestamp(timestamp.to_string())
+            .with_findings(high, 1, 0)
+            .build()
+    }
+
+    #[test]
+    fn test_build_merges_apps_teams_and_scans() {
+        let apps = vec![
+            app("a1", "web", "prod"),
+            app("a1", "web", "dev"),
+            app("a2", "Api", ""),
+        ];
+        let teams = HashMap::from([(
+            "a1".to_string(),
+            vec!["Web".to_string(), "Platform".to_string()],
+        )]);
+        let scans = vec![
+            scan("a1", "prod", "COMPLETED", 1_000, 2),
+            scan("a1", "prod", "COMPLETED", 3_000, 5),
+            scan("a1", "staging", "COMPLETED", 2_000, 1),
+            // Newest, but still running: last scan, not counted
+            scan("a1", "dev", "STARTED", 4_000, 9),
+        ];
+
+        let rows = AppInventoryRow::build(&apps, &teams, scans);
+        assert_eq!(rows.len(), 2);
+
+        // Sorted by name, case-insensitively; never scanned
+        assert_eq!(rows[0].app, "Api");
+        assert_eq!(rows[0].envs, "");
+        assert_eq!(rows[0].last_scan, "never");
+        assert_eq!((rows[0].high, rows[0].medium), (0, 0));
+
+        let web = &rows[1];
+        assert_eq!(web.envs, "dev, prod, staging");
+        assert_eq!(web.teams, "Platform; Web");
+        assert_eq!(web.risk_level, "HIGH");
+        assert_eq!(web.app_type, "STANDARD");
+        assert_eq!(web.last_scanned, Some(4_000));
+        assert_eq!(web.last_scan_id.as_deref(), Some("a1-dev-4000"));
+        // Latest completed prod (5) + staging (1); dev has none completed
+        assert_eq!((web.high, web.medium), (6, 2));
+    }
+}
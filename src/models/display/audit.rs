@@ -1,13 +1,14 @@
 //! Audit log display model
 
 use chrono::DateTime;
+use schemars::JsonSchema;
 use serde::Serialize;
 use tabled::Tabled;
 
 use crate::client::models::AuditRecord;
 
 /// Audit log display model for table/JSON output.
-#[derive(Debug, Clone, Tabled, Serialize)]
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
 pub struct AuditDisplay {
     /// When the action occurred
     #[tabled(rename = "TIMESTAMP")]
@@ -0,0 +1,705 @@
+//! Audit log rollup and anomaly highlights for `audit summary`
+//!
+//! Aggregates audit records by user and by activity type, and flags three
+//! kinds of unusual activity:
+//!
+//! - **New IP**: a user acting from an IP address after earlier records in
+//!   the window came from other addresses. The audit log has no login
+//!   events, so every user action counts; a user's first address in the
+//!   window is the baseline.
+//! - **Mass deletion**: [`MASS_DELETE_COUNT`] or more removals by one user
+//!   within [`MASS_DELETE_WINDOW_MS`].
+//! - **Off hours**: user actions outside the business hours window, or on a
+//!   weekend. Scan events are left out, since CI pipelines scan around the
+//!   clock.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Datelike, NaiveTime, TimeZone, Weekday};
+use schemars::JsonSchema;
+use serde::Serialize;
+use tabled::Tabled;
+
+use super::common::or_dash;
+use crate::client::models::AuditRecord;
+
+/// Removals by one user that make a mass deletion
+pub const MASS_DELETE_COUNT: usize = 5;
+
+/// Span the removals of a mass deletion fall within (one hour)
+pub const MASS_DELETE_WINDOW_MS: i64 = 60 * 60 * 1000;
+
+/// Daily business hours, Monday to Friday
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BusinessHours {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl Default for BusinessHours {
+    fn default() -> Self {
+        Self {
+            start: NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            end: NaiveTime::from_hms_opt(18, 0, 0).unwrap(),
+        }
+    }
+}
+
+impl FromStr for BusinessHours {
+    type Err = String;
+
+    /// Parse `START-END` with hours (`8-18`) or hours and minutes
+    /// (`08:30-17:30`). A start after the end wraps past midnight.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            format!(
+                "Invalid business hours \"{}\". Use START-END, e.g. 8-18 or 08:30-17:30",
+                s
+            )
+        };
+        let (start, end) = s.split_once('-').ok_or_else(invalid)?;
+        let start = parse_time(start).ok_or_else(invalid)?;
+        let end = parse_time(end).ok_or_else(invalid)?;
+        if start == end {
+            return Err(invalid());
+        }
+        Ok(Self { start, end })
+    }
+}
+
+impl fmt::Display for BusinessHours {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{}",
+            self.start.format("%H:%M"),
+            self.end.format("%H:%M")
+        )
+    }
+}
+
+impl BusinessHours {
+    /// Whether `at` falls on a weekday within the window
+    pub fn contains<Tz: TimeZone>(&self, at: &DateTime<Tz>) -> bool {
+        if matches!(at.weekday(), Weekday::Sat | Weekday::Sun) {
+            return false;
+        }
+        let time = at.time();
+        if self.start < self.end {
+            self.start <= time && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
+
+/// `H`, `HH`, or `HH:MM`
+fn parse_time(s: &str) -> Option<NaiveTime> {
+    let s = s.trim();
+    let (hour, minute) = match s.split_once(':') {
+        Some((h, m)) => (h.parse().ok()?, m.parse().ok()?),
+        None => (s.parse().ok()?, 0),
+    };
+    // 24 means midnight at the end of the day
+    if hour == 24 && minute == 0 {
+        return NaiveTime::from_hms_opt(0, 0, 0);
+    }
+    NaiveTime::from_hms_opt(hour, minute, 0)
+}
+
+/// Kind of unusual activity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AnomalyKind {
+    NewIp,
+    MassDeletion,
+    OffHours,
+}
+
+impl fmt::Display for AnomalyKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            AnomalyKind::NewIp => "new IP",
+            AnomalyKind::MassDeletion => "mass deletion",
+            AnomalyKind::OffHours => "off hours",
+        })
+    }
+}
+
+/// One flagged piece of activity
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct AuditAnomaly {
+    /// What was flagged
+    #[tabled(rename = "KIND")]
+    pub kind: AnomalyKind,
+
+    /// User name (or email when the name is unknown)
+    #[tabled(rename = "USER")]
+    pub user: String,
+
+    /// When it happened (the latest record for off-hours activity)
+    #[tabled(rename = "WHEN")]
+    pub when: String,
+
+    /// What happened
+    #[tabled(rename = "DETAIL")]
+    pub detail: String,
+
+    /// User email
+    #[tabled(skip)]
+    pub email: String,
+
+    #[tabled(skip)]
+    #[serde(skip)]
+    timestamp: i64,
+}
+
+/// Activity of one user in the window
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct AuditUserActivity {
+    /// User name (or email when the name is unknown)
+    #[tabled(rename = "USER")]
+    pub user: String,
+
+    /// User email
+    #[tabled(rename = "EMAIL")]
+    pub email: String,
+
+    /// Audit records by this user
+    #[tabled(rename = "ACTIONS")]
+    pub actions: usize,
+
+    /// Distinct IP addresses
+    #[tabled(rename = "IPS")]
+    pub ips: usize,
+
+    /// Removals (`*_REMOVED`, `*_DELETED`, `REMOVE_USER`)
+    #[tabled(rename = "DELETIONS")]
+    pub deletions: usize,
+
+    /// Actions outside business hours, scan events excluded
+    #[tabled(rename = "OFF HOURS")]
+    pub off_hours: usize,
+
+    /// Most frequent activity types, with counts
+    #[tabled(rename = "TOP ACTIVITY")]
+    pub top_activity: String,
+
+    /// Most recent audit record by this user
+    #[tabled(rename = "LAST ACTIVE")]
+    pub last_active: String,
+}
+
+/// Records of one activity type in the window
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct AuditTypeCount {
+    /// User or organization activity type
+    #[tabled(rename = "TYPE")]
+    pub activity_type: String,
+
+    /// Records of this type
+    #[tabled(rename = "COUNT")]
+    pub count: usize,
+
+    /// Distinct users with records of this type
+    #[tabled(rename = "USERS")]
+    pub users: usize,
+}
+
+/// Audit log rollup for a time window
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct AuditSummary {
+    /// Start of the window as given (e.g. `30d`)
+    pub since: String,
+
+    /// Audit records in the window
+    pub records: usize,
+
+    /// Distinct users with records
+    pub users: usize,
+
+    /// Business hours window used for off-hours flags (e.g. `08:00-18:00`)
+    pub business_hours: String,
+
+    /// Time zone of business hours and times shown (`local` or `UTC`)
+    pub time_zone: String,
+
+    /// Flagged activity, newest first
+    pub anomalies: Vec<AuditAnomaly>,
+
+    /// One row per user, most active first
+    pub by_user: Vec<AuditUserActivity>,
+
+    /// One row per activity type, most frequent first
+    pub by_type: Vec<AuditTypeCount>,
+}
+
+/// Running totals for one user
+#[derive(Default)]
+struct UserStats<'a> {
+    name: &'a str,
+    email: &'a str,
+    actions: usize,
+    types: HashMap<&'a str, usize>,
+    ips: BTreeSet<&'a str>,
+    deletions: Vec<(i64, &'a str)>,
+    off_hours: Vec<(i64, &'a str)>,
+    last_active: i64,
+}
+
+impl AuditSummary {
+    /// Summarize `records` (any order), judging business hours in `tz`
+    pub fn build<Tz: TimeZone>(
+        records: &[AuditRecord],
+        since: &str,
+        hours: &BusinessHours,
+        tz: &Tz,
+        time_zone: &str,
+    ) -> Self {
+        let mut sorted: Vec<&AuditRecord> = records.iter().collect();
+        sorted.sort_by_key(|r| timestamp(r));
+
+        let format_time = |ms: i64| {
+            DateTime::from_timestamp_millis(ms)
+                .map(|at| {
+                    at.with_timezone(tz)
+                        .naive_local()
+                        .format("%Y-%m-%d %H:%M:%S")
+                        .to_string()
+                })
+                .unwrap_or_else(|| "--".to_string())
+        };
+
+        let mut anomalies = Vec::new();
+        let mut users: BTreeMap<&str, UserStats> = BTreeMap::new();
+        let mut types: BTreeMap<&str, (usize, BTreeSet<&str>)> = BTreeMap::new();
+
+        for record in &sorted {
+            let ts = timestamp(record);
+            let activity = activity_type(record);
+            let user_key = user_key(record);
+
+            let by_type = types.entry(activity).or_default();
+            by_type.0 += 1;
+            let Some(key) = user_key else {
+                continue;
+            };
+            by_type.1.insert(key);
+
+            let stats = users.entry(key).or_default();
+            stats.name = &record.user_name;
+            stats.email = &record.user_email;
+            stats.actions += 1;
+            *stats.types.entry(activity).or_insert(0) += 1;
+            stats.last_active = stats.last_active.max(ts);
+
+            if let Some(ip) = record.user_ip_addr.as_deref().filter(|ip| !ip.is_empty())
+                && !stats.ips.contains(ip)
+            {
+                if !stats.ips.is_empty() {
+                    let previous: Vec<&str> = stats.ips.iter().copied().collect();
+                    anomalies.push(AuditAnomaly {
+                        kind: AnomalyKind::NewIp,
+                        user: display_name(stats),
+                        when: format_time(ts),
+                        detail: format!(
+                            "{} via {} (previously {})",
+                            ip,
+                            activity,
+                            previous.join(", ")
+                        ),
+                        email: stats.email.to_string(),
+                        timestamp: ts,
+                    });
+                }
+                stats.ips.insert(ip);
+            }
+
+            if is_deletion(activity) {
+                stats.deletions.push((ts, activity));
+            }
+            if record.user_activity_type.is_some() && !is_scan_event(activity) {
+                let outside = DateTime::from_timestamp_millis(ts)
+                    .is_some_and(|at| !hours.contains(&at.with_timezone(tz)));
+                if outside {
+                    stats.off_hours.push((ts, activity));
+                }
+            }
+        }
+
+        for stats in users.values() {
+            for burst in deletion_bursts(&stats.deletions) {
+                let (first, _) = burst[0];
+                let (last, _) = burst[burst.len() - 1];
+                anomalies.push(AuditAnomaly {
+                    kind: AnomalyKind::MassDeletion,
+                    user: display_name(stats),
+                    when: format_time(last),
+                    detail: format!(
+                        "{} removals in {}: {}",
+                        burst.len(),
+                        format_span(last - first),
+                        type_counts(burst.iter().map(|(_, t)| *t))
+                    ),
+                    email: stats.email.to_string(),
+                    timestamp: last,
+                });
+            }
+            if let Some(&(last, _)) = stats.off_hours.last() {
+                anomalies.push(AuditAnomaly {
+                    kind: AnomalyKind::OffHours,
+                    user: display_name(stats),
+                    when: format_time(last),
+                    detail: format!(
+                        "{} outside {}: {}",
+                        plural(stats.off_hours.len(), "action", "actions"),
+                        hours,
+                        type_counts(stats.off_hours.iter().map(|(_, t)| *t))
+                    ),
+                    email: stats.email.to_string(),
+                    timestamp: last,
+                });
+            }
+        }
+        anomalies.sort_by_key(|a| std::cmp::Reverse(a.timestamp));
+
+        let mut by_user: Vec<AuditUserActivity> = users
+            .values()
+            .map(|stats| AuditUserActivity {
+                user: display_name(stats),
+                email: or_dash(stats.email).to_string(),
+                actions: stats.actions,
+                ips: stats.ips.len(),
+                deletions: stats.deletions.len(),
+                off_hours: stats.off_hours.len(),
+                top_activity: top_types(&stats.types),
+                last_active: format_time(stats.last_active),
+            })
+            .collect();
+        by_user.sort_by(|a, b| b.actions.cmp(&a.actions).then(a.user.cmp(&b.user)));
+
+        let mut by_type: Vec<AuditTypeCount> = types
+            .into_iter()
+            .map(|(activity_type, (count, users))| AuditTypeCount {
+                activity_type: activity_type.to_string(),
+                count,
+                users: users.len(),
+            })
+            .collect();
+        by_type.sort_by_key(|t| std::cmp::Reverse(t.count));
+
+        Self {
+            since: since.to_string(),
+            records: records.len(),
+            users: by_user.len(),
+            business_hours: hours.to_string(),
+            time_zone: time_zone.to_string(),
+            anomalies,
+            by_user,
+            by_type,
+        }
+    }
+}
+
+/// Runs of at least [`MASS_DELETE_COUNT`] removals within
+/// [`MASS_DELETE_WINDOW_MS`] of the run's first removal (input sorted by time)
+fn deletion_bursts<'a, 'b>(deletions: &'b [(i64, &'a str)]) -> Vec<&'b [(i64, &'a str)]> {
+    let mut bursts = Vec::new();
+    let mut i = 0;
+    while i < deletions.len() {
+        let start = deletions[i].0;
+        let len = deletions[i..]
+            .iter()
+            .take_while(|(ts, _)| ts - start <= MASS_DELETE_WINDOW_MS)
+            .count();
+        if len >= MASS_DELETE_COUNT {
+            bursts.push(&deletions[i..i + len]);
+            i += len;
+        } else {
+            i += 1;
+        }
+    }
+    bursts
+}
+
+fn timestamp(record: &AuditRecord) -> i64 {
+    record.timestamp.parse().unwrap_or(0)
+}
+
+fn activity_type(record: &AuditRecord) -> &str {
+    record
+        .user_activity_type
+        .as_deref()
+        .or(record.organization_activity_type.as_deref())
+        .unwrap_or("UNKNOWN")
+}
+
+/// Email, name, or ID, whichever is known first
+fn user_key(record: &AuditRecord) -> Option<&str> {
+    [&record.user_email, &record.user_name, &record.user_id]
+        .into_iter()
+        .map(String::as_str)
+        .find(|s| !s.is_empty())
+}
+
+fn is_deletion(activity: &str) -> bool {
+    activity.ends_with("_REMOVED") || activity.ends_with("_DELETED") || activity == "REMOVE_USER"
+}
+
+fn is_scan_event(activity: &str) -> bool {
+    matches!(activity, "SCAN_STARTED" | "SCAN_COMPLETED" | "SCAN_ERRORED")
+}
+
+fn display_name(stats: &UserStats) -> String {
+    if stats.name.is_empty() {
+        or_dash(stats.email).to_string()
+    } else {
+        stats.name.to_string()
+    }
+}
+
+/// Up to three most frequent types, e.g. `SCAN_STARTED 12, APPLICATION_MODIFIED 3`
+fn top_types(types: &HashMap<&str, usize>) -> String {
+    let mut counts: Vec<(&str, usize)> = types.iter().map(|(t, n)| (*t, *n)).collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    counts
+        .iter()
+        .take(3)
+        .map(|(t, n)| format!("{} {}", t, n))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Types with counts, most frequent first (`APPLICATION_REMOVED ×4, TEAM_DELETED`)
+fn type_counts<'a>(types: impl Iterator<Item = &'a str>) -> String {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for t in types {
+        *counts.entry(t).or_insert(0) += 1;
+    }
+    let mut counts: Vec<(&str, usize)> = counts.into_iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    counts
+        .iter()
+        .map(|(t, n)| {
+            if *n == 1 {
+                t.to_string()
+            } else {
+                format!("{} ×{}", t, n)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// A span of milliseconds as whole minutes, or seconds under a minute
+fn format_span(ms: i64) -> String {
+    if ms < 60_000 {
+        format!("{}s", ms / 1000)
+    } else {
+        format!("{}m", ms / 60_000)
+    }
+}
+
+fn plural(n: usize, one: &str, many: &str) -> String {
+    format!("{} {}", n, if n == 1 { one } else { many })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    /// 2026-03-02 (a Monday) 00:00 UTC
+    const MONDAY: i64 = 1_772_409_600_000;
+    const HOUR: i64 = 60 * 60 * 1000;
+
+    fn record(user: &str, activity: &str, at: i64, ip: &str) -> AuditRecord {
+        AuditRecord {
+            id: format!("{}-{}", user, at),
+            user_activity_type: Some(activity.to_string()),
+            organization_activity_type: None,
+            organization_id: "org-1".to_string(),
+            user_id: format!("id-{}", user),
+            user_name: user.to_string(),
+            user_email: format!("{}@example.com", user),
+            payload: String::new(),
+            timestamp: at.to_string(),
+            user_ip_addr: Some(ip.to_string()),
+        }
+    }
+
+    fn summarize(records: &[AuditRecord]) -> AuditSummary {
+        AuditSummary::build(records, "30d", &BusinessHours::default(), &Utc, "UTC")
+    }
+
+    #[test]
+    fn test_parse_business_hours() {
+        let hours: BusinessHours = "8-18".parse().unwrap();
+        assert_eq!(hours, BusinessHours::default());
+        assert_eq!(hours.to_string(), "08:00-18:00");
+        assert_eq!(
+            "09:30-17:30".parse::<BusinessHours>().unwrap().to_string(),
+            "09:30-17:30"
+        );
+        assert_eq!(
+            "22-6".parse::<BusinessHours>().unwrap().to_string(),
+            "22:00-06:00"
+        );
+        assert!("8".parse::<BusinessHours>().is_err());
+        assert!("8-25".parse::<BusinessHours>().is_err());
+        assert!("8-8".parse::<BusinessHours>().is_err());
+    }
+
+    #[test]
+    fn test_business_hours_contains() {
+        let at = |ms: i64| DateTime::from_timestamp_millis(ms).unwrap();
+        let hours = BusinessHours::default();
+        assert!(hours.contains(&at(MONDAY + 9 * HOUR)));
+        assert!(!hours.contains(&at(MONDAY + 18 * HOUR)));
+        assert!(!hours.contains(&at(MONDAY + 7 * HOUR)));
+        // Saturday at noon
+        assert!(!hours.contains(&at(MONDAY + 5 * 24 * HOUR + 12 * HOUR)));
+
+        let night: BusinessHours = "22-6".parse().unwrap();
+        assert!(night.contains(&at(MONDAY + 23 * HOUR)));
+        assert!(night.contains(&at(MONDAY + 24 * HOUR + 5 * HOUR)));
+        assert!(!night.contains(&at(MONDAY + 12 * HOUR)));
+    }
+
+    #[test]
+    fn test_rollup_by_user_and_type() {
+        let records = vec![
+            record("ann", "SCAN_STARTED", MONDAY + 10 * HOUR, "10.0.0.1"),
+            record("ann", "SCAN_STARTED", MONDAY + 11 * HOUR, "10.0.0.1"),
+            record(
+                "ann",
+                "APPLICATION_MODIFIED",
+                MONDAY + 12 * HOUR,
+                "10.0.0.1",
+            ),
+            record("bob", "SCAN_STARTED", MONDAY + 13 * HOUR, "10.0.0.2"),
+        ];
+
+        let summary = summarize(&records);
+
+        assert_eq!(summary.records, 4);
+        assert_eq!(summary.users, 2);
+        assert!(summary.anomalies.is_empty());
+        assert_eq!(summary.by_user[0].user, "ann");
+        assert_eq!(summary.by_user[0].actions, 3);
+        assert_eq!(
+            summary.by_user[0].top_activity,
+            "SCAN_STARTED 2, APPLICATION_MODIFIED 1"
+        );
+        assert_eq!(summary.by_user[0].last_active, "2026-03-02 12:00:00");
+        assert_eq!(summary.by_type[0].activity_type, "SCAN_STARTED");
+        assert_eq!((summary.by_type[0].count, summary.by_type[0].users), (3, 2));
+    }
+
+    #[test]
+    fn test_flags_new_ip() {
+        let records = vec![
+            record("ann", "SCAN_STARTED", MONDAY + 10 * HOUR, "10.0.0.1"),
+            record("ann", "API_KEY_ADDED", MONDAY + 11 * HOUR, "203.0.113.9"),
+            record("ann", "SCAN_STARTED", MONDAY + 12 * HOUR, "203.0.113.9"),
+        ];
+
+        let summary = summarize(&records);
+
+        assert_eq!(summary.anomalies.len(), 1);
+        let anomaly = &summary.anomalies[0];
+        assert_eq!(anomaly.kind, AnomalyKind::NewIp);
+        assert_eq!(
+            anomaly.detail,
+            "203.0.113.9 via API_KEY_ADDED (previously 10.0.0.1)"
+        );
+        assert_eq!(anomaly.when, "2026-03-02 11:00:00");
+        assert_eq!(summary.by_user[0].ips, 2);
+    }
+
+    #[test]
+    fn test_reads_api_ip_field() {
+        let record: AuditRecord = serde_json::from_value(serde_json::json!({
+            "userName": "ann",
+            "timestamp": "1",
+            "userIPAddr": "10.0.0.1"
+        }))
+        .unwrap();
+        assert_eq!(record.user_ip_addr.as_deref(), Some("10.0.0.1"));
+    }
+
+    #[test]
+    fn test_flags_mass_deletion() {
+        let mut records: Vec<AuditRecord> = (0..5)
+            .map(|i| {
+                record(
+                    "bob",
+                    "APPLICATION_REMOVED",
+                    MONDAY + 10 * HOUR + i * 60_000,
+                    "10.0.0.2",
+                )
+            })
+            .collect();
+        records.push(record(
+            "bob",
+            "TEAM_DELETED",
+            MONDAY + 10 * HOUR + 30 * 60_000,
+            "10.0.0.2",
+        ));
+        // Four removals spread over a day are not flagged
+        records.extend((0..4).map(|i| {
+            record(
+                "ann",
+                "APPLICATION_REMOVED",
+                MONDAY + 9 * HOUR + i * 2 * HOUR,
+                "10.0.0.1",
+            )
+        }));
+
+        let summary = summarize(&records);
+
+        assert_eq!(summary.anomalies.len(), 1);
+        let anomaly = &summary.anomalies[0];
+        assert_eq!(anomaly.kind, AnomalyKind::MassDeletion);
+        assert_eq!(anomaly.user, "bob");
+        assert_eq!(
+            anomaly.detail,
+            "6 removals in 30m: APPLICATION_REMOVED ×5, TEAM_DELETED"
+        );
+    }
+
+    #[test]
+    fn test_flags_off_hours_except_scans() {
+        let saturday = MONDAY + 5 * 24 * HOUR;
+        let records = vec![
+            record("ann", "SCAN_STARTED", MONDAY + 2 * HOUR, "10.0.0.1"),
+            record("ann", "USER_SECRET_ADDED", MONDAY + 2 * HOUR, "10.0.0.1"),
+            record(
+                "ann",
+                "SCAN_CONFIG_MODIFIED",
+                saturday + 12 * HOUR,
+                "10.0.0.1",
+            ),
+            record(
+                "ann",
+                "SCAN_CONFIG_MODIFIED",
+                saturday + 13 * HOUR,
+                "10.0.0.1",
+            ),
+        ];
+
+        let summary = summarize(&records);
+
+        assert_eq!(summary.anomalies.len(), 1);
+        let anomaly = &summary.anomalies[0];
+        assert_eq!(anomaly.kind, AnomalyKind::OffHours);
+        assert_eq!(
+            anomaly.detail,
+            "3 actions outside 08:00-18:00: SCAN_CONFIG_MODIFIED ×2, USER_SECRET_ADDED"
+        );
+        assert_eq!(anomaly.when, "2026-03-07 13:00:00");
+        assert_eq!(summary.by_user[0].off_hours, 3);
+    }
+}
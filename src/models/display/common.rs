@@ -2,13 +2,38 @@
 
 use chrono::{DateTime, Utc};
 
+use crate::client::models::ScanResult;
+
 /// Truncate string to max length with ellipsis
 pub fn truncate_string(s: &str, max_len: usize) -> String {
     if s.len() <= max_len {
         s.to_string()
     } else {
-        format!("{}...", &s[..max_len.saturating_sub(3)])
+        let mut end = max_len.saturating_sub(3);
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        format!("{}...", &s[..end])
+    }
+}
+
+/// `s`, or `--` when empty
+pub fn or_dash(s: &str) -> &str {
+    if s.is_empty() { "--" } else { s }
+}
+
+/// Scan start time in epoch milliseconds (0 if unparseable)
+pub fn timestamp_ms(scan: &ScanResult) -> i64 {
+    scan.scan.timestamp.parse().unwrap_or(0)
+}
+
+/// `part` as a percentage of `whole`, rounded to one decimal (0 when `whole`
+/// is 0)
+pub fn percent(part: usize, whole: usize) -> f64 {
+    if whole == 0 {
+        return 0.0;
     }
+    (part as f64 * 1000.0 / whole as f64).round() / 10.0
 }
 
 /// Format timestamp string to ISO datetime (YYYY-MM-DDTHH:MM:SSZ)
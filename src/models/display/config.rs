@@ -1,12 +1,13 @@
 //! Scan configuration display model
 
+use schemars::JsonSchema;
 use serde::Serialize;
 use tabled::Tabled;
 
 use crate::client::models::ScanConfig;
 
 /// Scan configuration display model for table/JSON output.
-#[derive(Debug, Clone, Tabled, Serialize)]
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
 pub struct ConfigDisplay {
     /// Configuration name
     #[tabled(rename = "NAME")]
@@ -0,0 +1,216 @@
+//! Scan coverage model for `report coverage`
+//!
+//! The API reports how many URLs a scan visited but not which ones, so the
+//! paths known to be reached come from findings. Against a spec, an
+//! operation counts as covered when a finding maps onto it (see
+//! [`OasFindingsMap`]), which makes the percentage a lower bound.
+
+use std::collections::HashSet;
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use tabled::Tabled;
+
+use super::common::percent;
+use super::oas_map::{OasFindingsMap, UncoveredOperation};
+use super::scan_diff::DiffFinding;
+use crate::client::models::ScanResult;
+use crate::openapi::OpenApiSpec;
+
+/// Coverage of a spec's operations by one scan
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct SpecCoverage {
+    /// Spec title, or the file name when it has none
+    pub spec: String,
+
+    /// Operations the spec declares
+    pub operations: usize,
+
+    /// Operations with at least one finding
+    pub covered: usize,
+
+    /// Operations without findings
+    pub uncovered: usize,
+
+    /// Covered operations as a percentage of all operations
+    pub coverage_percent: f64,
+
+    /// Operations without findings, in spec order
+    pub uncovered_operations: Vec<UncoveredOperation>,
+}
+
+/// Coverage summary of one scan
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct CoverageReport {
+    /// Scan ID
+    pub scan_id: String,
+
+    /// Application name
+    pub app: String,
+
+    /// Environment name
+    pub env: String,
+
+    /// URLs the scanner visited, as reported by the API
+    pub urls_scanned: Option<u32>,
+
+    /// Distinct request paths with at least one finding
+    pub paths_with_findings: usize,
+
+    /// Spec comparison, when a spec was given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec: Option<SpecCoverage>,
+}
+
+/// Single-row table view of a [`CoverageReport`]
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct CoverageRow {
+    /// Scan ID
+    #[tabled(rename = "SCAN ID")]
+    pub scan_id: String,
+
+    /// Application name
+    #[tabled(rename = "APP")]
+    pub app: String,
+
+    /// Environment name
+    #[tabled(rename = "ENV")]
+    pub env: String,
+
+    /// URLs scanned, or "--"
+    #[tabled(rename = "URLS")]
+    pub urls: String,
+
+    /// Distinct paths with findings
+    #[tabled(rename = "FINDING PATHS")]
+    pub paths_with_findings: usize,
+
+    /// Spec operations
+    #[tabled(rename = "OPERATIONS")]
+    pub operations: String,
+
+    /// Operations with findings
+    #[tabled(rename = "COVERED")]
+    pub covered: String,
+
+    /// Operations without findings
+    #[tabled(rename = "UNCOVERED")]
+    pub uncovered: String,
+
+    /// Covered percentage
+    #[tabled(rename = "COVERAGE")]
+    pub coverage: String,
+}
+
+impl CoverageReport {
+    /// Summarize a scan's findings, against `spec` when given
+    pub fn build(
+        scan: &ScanResult,
+        findings: &[DiffFinding],
+        spec: Option<(&str, &OpenApiSpec)>,
+    ) -> Self {
+        let paths: HashSet<&str> = findings
+            .iter()
+            .map(|f| f.uri.split(['?', '#']).next().unwrap_or_default())
+            .collect();
+
+        let spec = spec.map(|(name, spec)| {
+            let map = OasFindingsMap::build(&scan.scan.id, name, spec, findings);
+            let covered = map.by_operation.len();
+            SpecCoverage {
+                spec: map.spec,
+                operations: map.operations,
+                covered,
+                uncovered: map.without_findings.len(),
+                coverage_percent: percent(covered, map.operations),
+                uncovered_operations: map.without_findings,
+            }
+        });
+
+        Self {
+            scan_id: scan.scan.id.clone(),
+            app: scan.scan.application_name.clone(),
+            env: scan.scan.env.clone(),
+            urls_scanned: scan.url_count,
+            paths_with_findings: paths.len(),
+            spec,
+        }
+    }
+
+    /// Table row; spec columns show "--" without a spec
+    pub fn row(&self) -> CoverageRow {
+        let spec_column = |value: &dyn Fn(&SpecCoverage) -> String| {
+            self.spec.as_ref().map_or_else(|| "--".to_string(), value)
+        };
+        CoverageRow {
+            scan_id: self.scan_id.clone(),
+            app: self.app.clone(),
+            env: self.env.clone(),
+            urls: self
+                .urls_scanned
+                .map_or_else(|| "--".to_string(), |n| n.to_string()),
+            paths_with_findings: self.paths_with_findings,
+            operations: spec_column(&|s| s.operations.to_string()),
+            covered: spec_column(&|s| s.covered.to_string()),
+            uncovered: spec_column(&|s| s.uncovered.to_string()),
+            coverage: spec_column(&|s| format!("{:.1}%", s.coverage_percent)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::fixtures::ScanResultBuilder;
+
+    fn scan_result() -> ScanResult {
+        ScanResultBuilder::new("scan-1", "app-1")
+            .app_name("shop")
+            .env("prod")
+            .timestamp("1700000000000")
+            .url_count(120)
+            .build()
+    }
+
+    fn finding(method: &str, uri: &str) -> DiffFinding {
+        DiffFinding {
+            plugin_id: "10020".to_string(),
+            plugin_name: String::new(),
+            severity: "Low".to_string(),
+            method: method.to_string(),
+            uri: uri.to_string(),
+            status: "UNKNOWN".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_coverage_against_spec() {
+        let spec = OpenApiSpec::parse(
+            "openapi: 3.0.0\npaths:\n  /users:\n    get: {}\n    post: {}\n  /users/{id}:\n    get: {}\n",
+        )
+        .unwrap();
+        let findings = vec![
+            finding("GET", "/users?page=1"),
+            finding("GET", "/users"),
+            finding("GET", "/users/7"),
+        ];
+
+        let report = CoverageReport::build(&scan_result(), &findings, Some(("api.yaml", &spec)));
+
+        assert_eq!(report.paths_with_findings, 2);
+        let coverage = report.spec.as_ref().unwrap();
+        assert_eq!((coverage.covered, coverage.uncovered), (2, 1));
+        assert_eq!(coverage.coverage_percent, 66.7);
+        assert_eq!(coverage.uncovered_operations[0].method, "POST");
+        assert_eq!(report.row().coverage, "66.7%");
+    }
+
+    #[test]
+    fn test_coverage_without_spec() {
+        let report = CoverageReport::build(&scan_result(), &[finding("GET", "/")], None);
+        let row = report.row();
+        assert_eq!(row.urls, "120");
+        assert_eq!(row.paths_with_findings, 1);
+        assert_eq!(row.coverage, "--");
+    }
+}
@@ -3,13 +3,14 @@
 //! These models format environment data for CLI display.
 
 use chrono::{DateTime, Local, TimeZone, Utc};
+use schemars::JsonSchema;
 use serde::Serialize;
 use tabled::Tabled;
 
 use crate::client::models::Environment;
 
 /// Display model for environment list
-#[derive(Debug, Clone, Tabled, Serialize)]
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
 pub struct EnvDisplay {
     #[tabled(rename = "NAME")]
     pub name: String,
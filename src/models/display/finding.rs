@@ -1,5 +1,7 @@
 //! Alert and finding display models
 
+use colored::Colorize;
+use schemars::JsonSchema;
 use serde::Serialize;
 use tabled::Tabled;
 
@@ -7,13 +9,15 @@ use super::common::truncate_string;
 use crate::client::models::{
     AlertMsgResponse, AlertResponse, ApplicationAlert, ApplicationAlertUri,
 };
+use crate::output::body::decode_body;
+use crate::output::render::render;
 
 /// Alert (plugin) display model for `scan <id> alerts` table.
 ///
 /// Note: Replaced by `PrettyAlertDisplay` for the default view, but kept for
 /// backwards compatibility and potential future use in table format.
 #[allow(dead_code)]
-#[derive(Debug, Clone, Tabled, Serialize)]
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
 pub struct AlertDisplay {
     /// Plugin ID
     #[tabled(rename = "PLUGIN")]
@@ -89,7 +93,7 @@ impl From<&ApplicationAlert> for AlertDisplay {
 ///
 /// This display format matches the mockup with columns:
 /// PLUGIN | SEVERITY | NAME | PATHS | NEW | ASSIGNED | ACCEPTED | FALSE+ | CWE
-#[derive(Debug, Clone, Tabled, Serialize)]
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
 pub struct PrettyAlertDisplay {
     /// Plugin ID
     #[tabled(rename = "PLUGIN")]
@@ -169,8 +173,33 @@ impl From<&ApplicationAlert> for PrettyAlertDisplay {
     }
 }
 
+/// `scan get --owasp` alerts table: the pretty columns plus the OWASP Top 10
+/// (2021) category mapped from the alert's CWE
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct OwaspAlertDisplay {
+    /// Pretty alert columns
+    #[tabled(inline)]
+    #[serde(flatten)]
+    pub alert: PrettyAlertDisplay,
+
+    /// OWASP Top 10 category, e.g. "A03 Injection"
+    #[tabled(rename = "OWASP")]
+    pub owasp: String,
+}
+
+impl From<ApplicationAlert> for OwaspAlertDisplay {
+    fn from(alert: ApplicationAlert) -> Self {
+        let owasp = crate::owasp::for_cwe(alert.cwe_id.as_deref())
+            .map_or_else(|| "--".to_string(), |c| format!("{} {}", c.code(), c.name));
+        Self {
+            alert: PrettyAlertDisplay::from(alert),
+            owasp,
+        }
+    }
+}
+
 /// Alert finding (path) display model for `scan <id> alert <plugin>` table.
-#[derive(Debug, Clone, Tabled, Serialize)]
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
 pub struct AlertFindingDisplay {
     /// HTTP method
     #[tabled(rename = "METHOD")]
@@ -212,7 +241,7 @@ impl From<&ApplicationAlertUri> for AlertFindingDisplay {
 }
 
 /// Alert detail for multi-section display (`scan <id> alert <plugin>`)
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct AlertDetail {
     pub response: AlertResponse,
 }
@@ -233,9 +262,12 @@ impl AlertDetail {
         ));
 
         if let Some(ref cwe) = alert.cwe_id {
+            let owasp = crate::owasp::for_cwe(Some(cwe))
+                .map(|c| format!(" | OWASP {}", c.label()))
+                .unwrap_or_default();
             output.push_str(&format!(
-                "CWE-{} | {} paths affected\n",
-                cwe, alert.uri_count
+                "CWE-{}{} | {} paths affected\n",
+                cwe, owasp, alert.uri_count
             ));
         } else {
             output.push_str(&format!("{} paths affected\n", alert.uri_count));
@@ -243,14 +275,62 @@ impl AlertDetail {
 
         output
     }
+
+    /// Description, cheatsheet, and references (shown between the header
+    /// and the paths table)
+    ///
+    /// The description is markdown, rendered for the terminal and wrapped
+    /// to `width` unless `plain`, which prints it as written.
+    pub fn format_guidance(&self, plain: bool, width: usize) -> String {
+        let alert = &self.response.alert;
+        let mut sections = Vec::new();
+
+        let description = alert.description.trim();
+        if !description.is_empty() {
+            let body = if plain {
+                description.to_string()
+            } else {
+                render(description, width, "  ")
+            };
+            sections.push(format!("{}\n{}", "Description".bold(), body));
+        }
+
+        let links: Vec<(&str, &str)> = self
+            .response
+            .cheatsheet
+            .iter()
+            .map(|url| ("Cheatsheet: ", url.as_str()))
+            .chain(alert.references.iter().map(|url| ("", url.as_str())))
+            .filter(|(_, url)| !url.trim().is_empty())
+            .collect();
+        if !links.is_empty() {
+            let lines: Vec<String> = links
+                .into_iter()
+                .map(|(label, url)| {
+                    let url = url.trim();
+                    if plain {
+                        format!("  - {}{}", label, url)
+                    } else {
+                        format!("  • {}{}", label, url.blue().underline())
+                    }
+                })
+                .collect();
+            sections.push(format!("{}\n{}", "References".bold(), lines.join("\n")));
+        }
+
+        sections.join("\n\n")
+    }
 }
 
 /// Alert message display for HTTP request/response (`scan <id> alert <plugin> <uri> message`)
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct AlertMessageDetail {
     pub response: AlertMsgResponse,
     pub plugin_name: Option<String>,
     pub severity: Option<String>,
+    /// Show bodies exactly as captured (no decoding or pretty-printing)
+    #[serde(skip)]
+    pub raw: bool,
 }
 
 impl AlertMessageDetail {
@@ -259,6 +339,21 @@ impl AlertMessageDetail {
             response,
             plugin_name: None,
             severity: None,
+            raw: false,
+        }
+    }
+
+    pub fn with_raw(mut self, raw: bool) -> Self {
+        self.raw = raw;
+        self
+    }
+
+    /// Body as displayed: decoded and pretty-printed unless `raw`
+    fn display_body(&self, headers: Option<&String>, body: &str) -> String {
+        if self.raw {
+            body.to_string()
+        } else {
+            decode_body(headers.map(String::as_str), body)
         }
     }
 
@@ -307,8 +402,9 @@ impl AlertMessageDetail {
         if let Some(ref body) = msg.scan_message.request_body
             && !body.is_empty()
         {
+            let body = self.display_body(msg.scan_message.request_header.as_ref(), body);
             output.push('\n');
-            output.push_str(&truncate_string(body, 500));
+            output.push_str(&truncate_string(&body, 500));
             output.push('\n');
         }
 
@@ -323,8 +419,9 @@ impl AlertMessageDetail {
         if let Some(ref body) = msg.scan_message.response_body
             && !body.is_empty()
         {
+            let body = self.display_body(msg.scan_message.response_header.as_ref(), body);
             output.push('\n');
-            output.push_str(&truncate_string(body, 2000));
+            output.push_str(&truncate_string(&body, 2000));
             output.push('\n');
         }
 
@@ -0,0 +1,232 @@
+//! Org-wide vulnerability inventory for `finding list`
+//!
+//! Rows come from the organization findings report, which holds the current
+//! findings of each application environment (those seen in its latest scan),
+//! one row per finding path.
+
+use std::cmp::Ordering;
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use tabled::Tabled;
+
+use super::OrgScoped;
+use super::finding::format_triage_status;
+use crate::client::models::{CurrentFindingRow, severity_rank};
+
+/// Table row for `finding list` output
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct FindingRow {
+    /// Severity: High, Medium, Low
+    #[tabled(rename = "SEVERITY")]
+    pub severity: String,
+
+    /// Application name
+    #[tabled(rename = "APP")]
+    pub app: String,
+
+    /// Environment name
+    #[tabled(rename = "ENV")]
+    pub env: String,
+
+    /// Plugin ID and name (e.g. "40012 Cross Site Scripting")
+    #[tabled(rename = "FINDING")]
+    pub finding: String,
+
+    /// HTTP method and path
+    #[tabled(rename = "PATH")]
+    pub path: String,
+
+    /// Triage status (New, Triaged, Accepted, False Pos)
+    #[tabled(rename = "STATUS")]
+    pub status: String,
+
+    /// Date the finding was first seen
+    #[tabled(rename = "FIRST SEEN")]
+    pub first_seen: String,
+}
+
+impl From<&CurrentFindingRow> for FindingRow {
+    fn from(row: &CurrentFindingRow) -> Self {
+        let text = |value: &Option<String>| value.clone().unwrap_or_else(|| "--".to_string());
+        let finding = match (&row.finding_plugin_id, &row.finding_plugin_name) {
+            (Some(id), Some(name)) => format!("{} {}", id, name),
+            (id, name) => text(&id.clone().or_else(|| name.clone())),
+        };
+        let path = match (&row.finding_method, &row.finding_url) {
+            (Some(method), Some(url)) => format!("{} {}", method, url),
+            (_, url) => text(url),
+        };
+        Self {
+            severity: text(&row.finding_risk),
+            app: text(&row.application_name),
+            env: text(&row.environment_name),
+            finding,
+            path,
+            status: format_triage_status(finding_status(row)),
+            first_seen: row
+                .finding_first_seen_iso8601
+                .as_deref()
+                .map_or_else(|| "--".to_string(), date_part),
+        }
+    }
+}
+
+/// API triage status of a finding; findings without one are new
+pub fn finding_status(row: &CurrentFindingRow) -> &str {
+    row.status
+        .as_deref()
+        .filter(|s| !s.is_empty())
+        .unwrap_or("UNKNOWN")
+}
+
+/// Sort findings by `sort_by` (severity, app, env, plugin, path, status,
+/// first-seen, last-seen), or by severity, app, env, and plugin when unset
+///
+/// Severity sorts High first; the other fields ascending. `descending`
+/// reverses the chosen field; ties keep the default order.
+pub fn sort_findings(rows: &mut [CurrentFindingRow], sort_by: Option<&str>, descending: bool) {
+    let sort_by = sort_by.map(str::to_lowercase);
+    rows.sort_by(|a, b| compare_findings(a, b, sort_by.as_deref(), descending));
+}
+
+/// Sort findings from several orgs (`--all-orgs`) as [`sort_findings`] does
+///
+/// `sort_by` may also be `org`; otherwise ties are broken by org name.
+pub fn sort_org_findings(
+    rows: &mut [OrgScoped<CurrentFindingRow>],
+    sort_by: Option<&str>,
+    descending: bool,
+) {
+    let sort_by = sort_by.map(str::to_lowercase);
+    let by_org = matches!(sort_by.as_deref(), Some("org" | "organization"));
+    rows.sort_by(|a, b| {
+        let org = a.org.to_lowercase().cmp(&b.org.to_lowercase());
+        if by_org {
+            let org = if descending { org.reverse() } else { org };
+            org.then_with(|| compare_findings(&a.row, &b.row, None, false))
+        } else {
+            compare_findings(&a.row, &b.row, sort_by.as_deref(), descending).then(org)
+        }
+    });
+}
+
+/// Order two findings by a lowercase sort field, then the default order
+fn compare_findings(
+    a: &CurrentFindingRow,
+    b: &CurrentFindingRow,
+    sort_by: Option<&str>,
+    descending: bool,
+) -> Ordering {
+    let key = |value: &Option<String>| value.as_deref().unwrap_or_default().to_lowercase();
+    let by_default = |a: &CurrentFindingRow, b: &CurrentFindingRow| {
+        severity_rank(a.finding_risk.as_deref().unwrap_or_default())
+            .cmp(&severity_rank(
+                b.finding_risk.as_deref().unwrap_or_default(),
+            ))
+            .then_with(|| key(&a.application_name).cmp(&key(&b.application_name)))
+            .then_with(|| key(&a.environment_name).cmp(&key(&b.environment_name)))
+            .then_with(|| key(&a.finding_plugin_id).cmp(&key(&b.finding_plugin_id)))
+            .then_with(|| key(&a.finding_url).cmp(&key(&b.finding_url)))
+    };
+
+    let cmp = match sort_by {
+        Some("app" | "application") => key(&a.application_name).cmp(&key(&b.application_name)),
+        Some("env" | "environment") => key(&a.environment_name).cmp(&key(&b.environment_name)),
+        Some("plugin" | "finding") => key(&a.finding_plugin_id).cmp(&key(&b.finding_plugin_id)),
+        Some("path" | "url") => key(&a.finding_url).cmp(&key(&b.finding_url)),
+        Some("status") => finding_status(a).cmp(finding_status(b)),
+        Some("first-seen" | "first_seen" | "age") => {
+            key(&a.finding_first_seen_iso8601).cmp(&key(&b.finding_first_seen_iso8601))
+        }
+        Some("last-seen" | "last_seen") => {
+            key(&a.finding_last_seen_iso8601).cmp(&key(&b.finding_last_seen_iso8601))
+        }
+        _ => Ordering::Equal,
+    };
+    let cmp = if descending { cmp.reverse() } else { cmp };
+    cmp.then_with(|| by_default(a, b))
+}
+
+/// `2024-01-15` from an ISO 8601 timestamp
+fn date_part(timestamp: &str) -> String {
+    timestamp.split('T').next().unwrap_or(timestamp).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(risk: &str, app: &str, plugin: &str, status: Option<&str>) -> CurrentFindingRow {
+        serde_json::from_value(serde_json::json!({
+            "findingRisk": risk,
+            "applicationName": app,
+            "environmentName": "prod",
+            "findingPluginId": plugin,
+            "findingPluginName": "Plugin",
+            "findingMethod": "GET",
+            "findingUrl": "/api/users",
+            "findingFirstSeenISO8601": "2024-01-15T10:00:00Z",
+            "status": status,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_sort_findings() {
+        let mut rows = vec![
+            row("Low", "web", "1", None),
+            row("High", "web", "2", None),
+            row("High", "api", "3", Some("PROMOTED")),
+        ];
+
+        sort_findings(&mut rows, None, false);
+        let plugins: Vec<_> = rows
+            .iter()
+            .map(|r| r.finding_plugin_id.clone().unwrap())
+            .collect();
+        assert_eq!(plugins, vec!["3", "2", "1"]);
+
+        sort_findings(&mut rows, Some("status"), true);
+        let plugins: Vec<_> = rows
+            .iter()
+            .map(|r| r.finding_plugin_id.clone().unwrap())
+            .collect();
+        assert_eq!(plugins, vec!["2", "1", "3"]);
+    }
+
+    #[test]
+    fn test_sort_org_findings() {
+        let scoped = |org: &str, risk: &str, plugin: &str| OrgScoped {
+            org: org.to_string(),
+            org_id: format!("{}-id", org),
+            row: row(risk, "web", plugin, None),
+        };
+        let mut rows = vec![
+            scoped("beta", "Low", "1"),
+            scoped("Acme", "Low", "1"),
+            scoped("beta", "High", "2"),
+        ];
+        let orgs = |rows: &[OrgScoped<CurrentFindingRow>]| {
+            rows.iter()
+                .map(|r| format!("{}/{}", r.org, r.row.finding_plugin_id.as_deref().unwrap()))
+                .collect::<Vec<_>>()
+        };
+
+        sort_org_findings(&mut rows, None, false);
+        assert_eq!(orgs(&rows), vec!["beta/2", "Acme/1", "beta/1"]);
+
+        sort_org_findings(&mut rows, Some("org"), true);
+        assert_eq!(orgs(&rows), vec!["beta/2", "beta/1", "Acme/1"]);
+    }
+
+    #[test]
+    fn test_finding_row() {
+        let display = FindingRow::from(&row("High", "web", "40012", None));
+
+        assert_eq!(display.finding, "40012 Plugin");
+        assert_eq!(display.path, "GET /api/users");
+        assert_eq!(display.status, "New");
+        assert_eq!(display.first_seen, "2024-01-15");
+    }
+}
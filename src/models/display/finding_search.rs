@@ -0,0 +1,243 @@
+//! Text search across a scan's findings, for `scan search`
+//!
+//! Each finding (one path of an alert) is matched against its alert name,
+//! URI, and the evidence and other info of its HTTP message. A match is
+//! reported per field, with the text around it as context.
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use tabled::Tabled;
+
+use super::common::truncate_string;
+use crate::cli::SearchField;
+use crate::client::models::{AlertMsgResponse, ApplicationAlert, ApplicationAlertUri};
+
+/// Characters of context shown on each side of a match
+const CONTEXT_CHARS: usize = 30;
+
+/// A case-insensitive (ASCII) or exact text pattern
+#[derive(Debug, Clone)]
+pub struct SearchPattern {
+    pattern: String,
+    case_sensitive: bool,
+}
+
+impl SearchPattern {
+    pub fn new(pattern: &str, case_sensitive: bool) -> Self {
+        let pattern = if case_sensitive {
+            pattern.to_string()
+        } else {
+            pattern.to_ascii_lowercase()
+        };
+        Self {
+            pattern,
+            case_sensitive,
+        }
+    }
+
+    /// Byte offset of the first match in `text`
+    pub fn find(&self, text: &str) -> Option<usize> {
+        if self.case_sensitive {
+            text.find(&self.pattern)
+        } else {
+            text.to_ascii_lowercase().find(&self.pattern)
+        }
+    }
+
+    /// The match in `text` with up to [`CONTEXT_CHARS`] characters on each
+    /// side, whitespace collapsed and cut ends marked with `…`
+    pub fn context(&self, text: &str) -> Option<String> {
+        let start = self.find(text)?;
+        let end = start + self.pattern.len();
+        let before: Vec<char> = text[..start].chars().collect();
+        let after: Vec<char> = text[end..].chars().collect();
+
+        let mut context = String::new();
+        if before.len() > CONTEXT_CHARS {
+            context.push('…');
+        }
+        context.extend(&before[before.len().saturating_sub(CONTEXT_CHARS)..]);
+        context.push_str(&text[start..end]);
+        context.extend(after.iter().take(CONTEXT_CHARS));
+        if after.len() > CONTEXT_CHARS {
+            context.push('…');
+        }
+        Some(context.split_whitespace().collect::<Vec<_>>().join(" "))
+    }
+}
+
+/// One field of one finding matching the pattern
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct FindingMatch {
+    /// Plugin that reported the finding
+    pub plugin_id: String,
+
+    /// Alert (plugin) name
+    pub plugin_name: String,
+
+    /// Severity (High, Medium, Low)
+    pub severity: String,
+
+    /// HTTP method of the finding
+    pub method: String,
+
+    /// Path of the finding
+    pub uri: String,
+
+    /// Alert URI ID (for `scan get --uri-id`)
+    pub uri_id: String,
+
+    /// Triage status as the API reports it
+    pub status: String,
+
+    /// Field the pattern was found in
+    pub field: SearchField,
+
+    /// The match with the text around it
+    pub context: String,
+}
+
+impl FindingMatch {
+    /// Matches of `pattern` in the `fields` of one finding; `message` is
+    /// its HTTP message, when fetched
+    pub fn find_all(
+        pattern: &SearchPattern,
+        fields: &[SearchField],
+        alert: &ApplicationAlert,
+        path: &ApplicationAlertUri,
+        message: Option<&AlertMsgResponse>,
+    ) -> Vec<Self> {
+        fields
+            .iter()
+            .filter_map(|&field| {
+                let text = match field {
+                    SearchField::Name => Some(alert.name.as_str()),
+                    SearchField::Uri => Some(path.uri.as_str()),
+                    SearchField::Evidence => message.and_then(|m| m.evidence.as_deref()),
+                    SearchField::OtherInfo => message.and_then(|m| m.other_info.as_deref()),
+                }?;
+                let context = pattern.context(text)?;
+                Some(Self {
+                    plugin_id: alert.plugin_id.clone(),
+                    plugin_name: alert.name.clone(),
+                    severity: alert.severity.clone(),
+                    method: path.request_method.clone(),
+                    uri: path.uri.clone(),
+                    uri_id: path.alert_uri_id.clone(),
+                    status: path.status.clone(),
+                    field,
+                    context,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Table row for a [`FindingMatch`]
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct FindingMatchDisplay {
+    #[tabled(rename = "SEVERITY")]
+    pub severity: String,
+
+    #[tabled(rename = "PLUGIN")]
+    pub plugin: String,
+
+    #[tabled(rename = "FINDING")]
+    pub finding: String,
+
+    #[tabled(rename = "FIELD")]
+    pub field: String,
+
+    #[tabled(rename = "MATCH")]
+    pub context: String,
+
+    #[tabled(rename = "URI ID")]
+    pub uri_id: String,
+}
+
+impl From<&FindingMatch> for FindingMatchDisplay {
+    fn from(m: &FindingMatch) -> Self {
+        Self {
+            severity: m.severity.clone(),
+            plugin: format!("{} ({})", truncate_string(&m.plugin_name, 30), m.plugin_id),
+            finding: format!("{} {}", m.method, truncate_string(&m.uri, 40)),
+            field: m.field.to_string(),
+            context: m.context.clone(),
+            uri_id: m.uri_id.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_context_around_match() {
+        let pattern = SearchPattern::new("<SCRIPT>", false);
+        assert_eq!(
+            pattern.context("q=<script>alert(1)</script>").as_deref(),
+            Some("q=<script>alert(1)</script>")
+        );
+
+        let long = format!("{}\n\t<script>{}", "a".repeat(40), "b".repeat(40));
+        assert_eq!(
+            pattern.context(&long).unwrap(),
+            format!("…{} <script>{}…", "a".repeat(28), "b".repeat(30))
+        );
+
+        assert!(
+            SearchPattern::new("<SCRIPT>", true)
+                .find("<script>")
+                .is_none()
+        );
+        assert!(pattern.context("nothing here").is_none());
+    }
+
+    #[test]
+    fn test_find_all_matches_fields() {
+        let alert: ApplicationAlert = serde_json::from_value(serde_json::json!({
+            "pluginId": "40012", "name": "Cross Site Scripting (Reflected)",
+            "severity": "High", "uriCount": 1
+        }))
+        .unwrap();
+        let path: ApplicationAlertUri = serde_json::from_value(serde_json::json!({
+            "alertUriId": "u1", "uri": "/search?q=x", "requestMethod": "GET",
+            "msgId": "m1", "pluginId": "40012", "status": "UNKNOWN"
+        }))
+        .unwrap();
+        let message: AlertMsgResponse = serde_json::from_value(serde_json::json!({
+            "scanMessage": {"id": "m1"},
+            "evidence": "<p>search results for x</p>",
+            "otherInfo": "no match"
+        }))
+        .unwrap();
+        let all = [
+            SearchField::Name,
+            SearchField::Uri,
+            SearchField::Evidence,
+            SearchField::OtherInfo,
+        ];
+
+        let matches = FindingMatch::find_all(
+            &SearchPattern::new("search", false),
+            &all,
+            &alert,
+            &path,
+            Some(&message),
+        );
+        let fields: Vec<_> = matches.iter().map(|m| m.field).collect();
+        assert_eq!(fields, [SearchField::Uri, SearchField::Evidence]);
+        assert_eq!(matches[1].context, "<p>search results for x</p>");
+
+        // Message fields need the message
+        let matches = FindingMatch::find_all(
+            &SearchPattern::new("results", false),
+            &all,
+            &alert,
+            &path,
+            None,
+        );
+        assert!(matches.is_empty());
+    }
+}
@@ -0,0 +1,185 @@
+//! Bulk triage input rows and per-row results for `finding triage-bulk`
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::cli::TriageAction;
+use crate::error::{Error, Result};
+use crate::output::csv::CsvRecord;
+
+/// Expected input columns, in order (`note` is optional)
+const COLUMNS: &str = "scan_id,plugin_id,uri_id,status[,note]";
+
+/// One triage request from the input file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TriageRow {
+    /// Input line the row starts on
+    pub line: usize,
+    pub scan_id: String,
+    pub plugin_id: String,
+    pub uri_id: String,
+    pub action: TriageAction,
+    pub note: Option<String>,
+}
+
+impl TriageRow {
+    /// Parse CSV records, skipping a header row if present
+    ///
+    /// Every invalid row is reported in one error so the whole file can be
+    /// fixed before anything is applied.
+    pub fn parse_all(records: &[CsvRecord]) -> Result<Vec<Self>> {
+        let records = match records.first() {
+            Some(first) if is_header(first) => &records[1..],
+            _ => records,
+        };
+
+        let mut rows = Vec::with_capacity(records.len());
+        let mut errors = Vec::new();
+        for record in records {
+            match Self::parse(record) {
+                Ok(row) => rows.push(row),
+                Err(e) => errors.push(format!("  line {}: {}", record.line, e)),
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(Error::Other(format!(
+                "Invalid triage input (expected {}):\n{}",
+                COLUMNS,
+                errors.join("\n")
+            )));
+        }
+        Ok(rows)
+    }
+
+    fn parse(record: &CsvRecord) -> std::result::Result<Self, String> {
+        let fields = &record.fields;
+        if !(4..=5).contains(&fields.len()) {
+            return Err(format!("expected 4 or 5 columns, got {}", fields.len()));
+        }
+        for (name, value) in ["scan_id", "plugin_id", "uri_id"].iter().zip(fields) {
+            if value.is_empty() {
+                return Err(format!("{} is empty", name));
+            }
+        }
+        let action: TriageAction = fields[3].parse()?;
+        let note = fields.get(4).filter(|n| !n.is_empty()).cloned();
+        if action == TriageAction::Comment && note.is_none() {
+            return Err("comment requires a note".to_string());
+        }
+
+        Ok(Self {
+            line: record.line,
+            scan_id: fields[0].clone(),
+            plugin_id: fields[1].clone(),
+            uri_id: fields[2].clone(),
+            action,
+            note,
+        })
+    }
+}
+
+/// A header row names the first column instead of holding a scan ID
+fn is_header(record: &CsvRecord) -> bool {
+    record.fields.first().is_some_and(|f| {
+        matches!(
+            f.to_lowercase().replace(['-', ' '], "_").as_str(),
+            "scan_id" | "scanid"
+        )
+    })
+}
+
+/// Outcome of one triage row
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct TriageOutcome {
+    /// Input line
+    #[tabled(rename = "LINE")]
+    pub line: usize,
+
+    #[tabled(rename = "SCAN ID")]
+    pub scan_id: String,
+
+    #[tabled(rename = "PLUGIN")]
+    pub plugin_id: String,
+
+    #[tabled(rename = "URI ID")]
+    pub uri_id: String,
+
+    /// Requested action
+    #[tabled(rename = "STATUS")]
+    pub status: String,
+
+    /// "triaged", "would triage" (dry run), or the error
+    #[tabled(rename = "RESULT")]
+    pub result: String,
+
+    /// Whether the row was (or, in a dry run, can be) applied
+    #[tabled(skip)]
+    pub success: bool,
+
+    /// Finding hash the row resolved to
+    #[tabled(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finding_hash: Option<String>,
+}
+
+impl TriageOutcome {
+    /// Outcome for `row` with the given result
+    pub fn new(
+        row: &TriageRow,
+        finding_hash: Option<String>,
+        result: std::result::Result<&str, String>,
+    ) -> Self {
+        let (success, result) = match result {
+            Ok(msg) => (true, msg.to_string()),
+            Err(e) => (false, e),
+        };
+        Self {
+            line: row.line,
+            scan_id: row.scan_id.clone(),
+            plugin_id: row.plugin_id.clone(),
+            uri_id: row.uri_id.clone(),
+            status: row.action.to_string(),
+            result,
+            success,
+            finding_hash,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::csv::parse_csv;
+
+    #[test]
+    fn test_parse_all_skips_header_and_reads_notes() {
+        let records = parse_csv(
+            "scan_id,plugin_id,uri_id,status,note\n\
+             s1,40012,u1,false-positive,\"Sanitized, see PR 12\"\n\
+             s1,10020,u2,RISK_ACCEPTED\n",
+        )
+        .unwrap();
+
+        let rows = TriageRow::parse_all(&records).unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].line, 2);
+        assert_eq!(rows[0].action, TriageAction::FalsePositive);
+        assert_eq!(rows[0].note.as_deref(), Some("Sanitized, see PR 12"));
+        assert_eq!(rows[1].action, TriageAction::Accepted);
+        assert_eq!(rows[1].note, None);
+    }
+
+    #[test]
+    fn test_parse_all_reports_every_bad_line() {
+        let records = parse_csv("s1,40012,u1,fixed\ns1,40012\ns1,40012,u1,comment\n").unwrap();
+
+        let err = TriageRow::parse_all(&records).unwrap_err().to_string();
+
+        assert!(err.contains("line 1: unknown status 'fixed'"));
+        assert!(err.contains("line 2: expected 4 or 5 columns, got 2"));
+        assert!(err.contains("line 3: comment requires a note"));
+    }
+}
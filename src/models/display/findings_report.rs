@@ -0,0 +1,300 @@
+//! Org-wide findings summary for `report findings`
+//!
+//! Aggregates plugin-level alerts from the latest completed scan of each
+//! application environment, so repeated scans of the same app don't inflate
+//! the counts.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::cli::ReportGroupBy;
+use crate::client::models::{ApplicationAlert, ScanResult, severity_rank};
+
+use super::common::timestamp_ms;
+
+/// A scan and its plugin-level alerts
+#[derive(Debug, Clone)]
+pub struct ScanAlerts {
+    /// Scan result (app, env, timestamp)
+    pub scan: ScanResult,
+
+    /// Alerts reported by the scan
+    pub alerts: Vec<ApplicationAlert>,
+}
+
+/// Keep only the latest completed scan per application + environment
+pub fn latest_per_app_env(scans: Vec<ScanResult>) -> Vec<ScanResult> {
+    let mut latest: HashMap<(String, String), ScanResult> = HashMap::new();
+    for scan in scans {
+        if !scan.scan.status.eq_ignore_ascii_case("COMPLETED") {
+            continue;
+        }
+        let key = (scan.scan.application_id.clone(), scan.scan.env.clone());
+        let newer = latest
+            .get(&key)
+            .is_none_or(|existing| timestamp_ms(&scan) > timestamp_ms(existing));
+        if newer {
+            latest.insert(key, scan);
+        }
+    }
+
+    let mut scans: Vec<ScanResult> = latest.into_values().collect();
+    scans.sort_by(|a, b| {
+        a.scan
+            .application_name
+            .to_lowercase()
+            .cmp(&b.scan.application_name.to_lowercase())
+            .then_with(|| a.scan.env.cmp(&b.scan.env))
+    });
+    scans
+}
+
+/// Severity counts for the whole report
+#[derive(Debug, Clone, Default, Serialize, JsonSchema)]
+pub struct SeverityTotals {
+    /// High severity alerts
+    pub high: u32,
+
+    /// Medium severity alerts
+    pub medium: u32,
+
+    /// Low severity alerts
+    pub low: u32,
+
+    /// Affected paths across all alerts
+    pub paths: u32,
+}
+
+/// Table row for `report findings` output
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct FindingsSummaryRow {
+    /// Group key (app name, severity, or plugin)
+    #[tabled(rename = "GROUP")]
+    pub group: String,
+
+    /// Distinct applications contributing to the group
+    #[tabled(rename = "APPS")]
+    pub apps: usize,
+
+    /// Scans contributing to the group
+    #[tabled(rename = "SCANS")]
+    pub scans: usize,
+
+    /// High severity alerts
+    #[tabled(rename = "HIGH")]
+    pub high: u32,
+
+    /// Medium severity alerts
+    #[tabled(rename = "MEDIUM")]
+    pub medium: u32,
+
+    /// Low severity alerts
+    #[tabled(rename = "LOW")]
+    pub low: u32,
+
+    /// Affected paths
+    #[tabled(rename = "PATHS")]
+    pub paths: u32,
+}
+
+/// Aggregated org-wide findings report
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct FindingsReport {
+    /// Time window the report covers (as given by the user)
+    pub since: String,
+
+    /// Grouping dimension
+    pub group_by: ReportGroupBy,
+
+    /// Number of scans analyzed (latest per app + environment)
+    pub scans_analyzed: usize,
+
+    /// Totals across all groups
+    pub totals: SeverityTotals,
+
+    /// Per-group rows, worst first
+    pub groups: Vec<FindingsSummaryRow>,
+}
+
+#[derive(Default)]
+struct GroupAccumulator {
+    apps: BTreeSet<String>,
+    scans: BTreeSet<String>,
+    high: u32,
+    medium: u32,
+    low: u32,
+    paths: u32,
+}
+
+impl FindingsReport {
+    /// Aggregate alerts from the given scans
+    pub fn aggregate(since: &str, group_by: ReportGroupBy, data: &[ScanAlerts]) -> Self {
+        let mut groups: BTreeMap<String, GroupAccumulator> = BTreeMap::new();
+        let mut totals = SeverityTotals::default();
+
+        for entry in data {
+            let scan = &entry.scan.scan;
+            for alert in &entry.alerts {
+                let key = match group_by {
+                    ReportGroupBy::App => scan.application_name.clone(),
+                    ReportGroupBy::Severity => normalize_severity(&alert.severity),
+                    ReportGroupBy::Plugin => format!("{} {}", alert.plugin_id, alert.name),
+                };
+
+                let acc = groups.entry(key).or_default();
+                acc.apps.insert(scan.application_id.clone());
+                acc.scans.insert(scan.id.clone());
+                acc.paths += alert.uri_count;
+                totals.paths += alert.uri_count;
+
+                match normalize_severity(&alert.severity).as_str() {
+                    "High" => {
+                        acc.high += 1;
+                        totals.high += 1;
+                    }
+                    "Medium" => {
+                        acc.medium += 1;
+                        totals.medium += 1;
+                    }
+                    "Low" => {
+                        acc.low += 1;
+                        totals.low += 1;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut rows: Vec<FindingsSummaryRow> = groups
+            .into_iter()
+            .map(|(group, acc)| FindingsSummaryRow {
+                group,
+                apps: acc.apps.len(),
+                scans: acc.scans.len(),
+                high: acc.high,
+                medium: acc.medium,
+                low: acc.low,
+                paths: acc.paths,
+            })
+            .collect();
+
+        match group_by {
+            ReportGroupBy::Severity => rows.sort_by_key(|r| severity_rank(&r.group)),
+            _ => rows.sort_by(|a, b| {
+                (b.high, b.medium, b.low, b.paths)
+                    .cmp(&(a.high, a.medium, a.low, a.paths))
+                    .then_with(|| a.group.cmp(&b.group))
+            }),
+        }
+
+        Self {
+            since: since.to_string(),
+            group_by,
+            scans_analyzed: data.len(),
+            totals,
+            groups: rows,
+        }
+    }
+}
+
+/// Canonical severity label (High, Medium, Low, or the original value)
+fn normalize_severity(severity: &str) -> String {
+    match severity.to_lowercase().as_str() {
+        "high" => "High".to_string(),
+        "medium" => "Medium".to_string(),
+        "low" => "Low".to_string(),
+        _ => severity.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::fixtures::ScanResultBuilder;
+
+    fn scan(id: &str, app: &str, env: &str, status: &str, ts: &str) -> ScanResult {
+        ScanResultBuilder::new(id, format!("app-{}", app))
+            .app_name(app)
+            .env(env)
+            .status(status)
+            .timestamp(ts)
+            .build()
+    }
+
+    fn alert(plugin: &str, severity: &str, uris: u32) -> ApplicationAlert {
+        ApplicationAlert {
+            plugin_id: plugin.to_string(),
+            name: format!("Plugin {}", plugin),
+            description: String::new(),
+            severity: severity.to_string(),
+            cwe_id: None,
+            references: vec![],
+            uri_count: uris,
+            alert_status_stats: vec![],
+        }
+    }
+
+    fn data() -> Vec<ScanAlerts> {
+        vec![
+            ScanAlerts {
+                scan: scan("s1", "web", "prod", "COMPLETED", "2000"),
+                alerts: vec![alert("40012", "High", 3), alert("10020", "Low", 1)],
+            },
+            ScanAlerts {
+                scan: scan("s2", "api", "prod", "COMPLETED", "2000"),
+                alerts: vec![alert("40012", "High", 2), alert("90022", "Medium", 5)],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_latest_per_app_env() {
+        let scans = vec![
+            scan("old", "web", "prod", "COMPLETED", "1000"),
+            scan("new", "web", "prod", "COMPLETED", "2000"),
+            scan("running", "web", "prod", "STARTED", "3000"),
+            scan("dev", "web", "dev", "COMPLETED", "1500"),
+        ];
+
+        let latest = latest_per_app_env(scans);
+        let ids: Vec<_> = latest.iter().map(|s| s.scan.id.as_str()).collect();
+
+        assert_eq!(ids, vec!["dev", "new"]);
+    }
+
+    #[test]
+    fn test_aggregate_by_app() {
+        let report = FindingsReport::aggregate("30d", ReportGroupBy::App, &data());
+
+        assert_eq!(report.scans_analyzed, 2);
+        assert_eq!(report.totals.high, 2);
+        assert_eq!(report.totals.medium, 1);
+        assert_eq!(report.totals.low, 1);
+        assert_eq!(report.totals.paths, 11);
+        // Tied on high; api wins on medium
+        assert_eq!(report.groups[0].group, "api");
+        assert_eq!(report.groups[1].group, "web");
+    }
+
+    #[test]
+    fn test_aggregate_by_severity_ordered() {
+        let report = FindingsReport::aggregate("30d", ReportGroupBy::Severity, &data());
+        let groups: Vec<_> = report.groups.iter().map(|g| g.group.as_str()).collect();
+
+        assert_eq!(groups, vec!["High", "Medium", "Low"]);
+        assert_eq!(report.groups[0].apps, 2);
+        assert_eq!(report.groups[0].paths, 5);
+    }
+
+    #[test]
+    fn test_aggregate_by_plugin() {
+        let report = FindingsReport::aggregate("30d", ReportGroupBy::Plugin, &data());
+
+        assert_eq!(report.groups[0].group, "40012 Plugin 40012");
+        assert_eq!(report.groups[0].scans, 2);
+        assert_eq!(report.groups[0].high, 2);
+    }
+}
@@ -0,0 +1,250 @@
+//! Findings over time for `report trend`
+//!
+//! Turns an application's completed scans into one series per environment,
+//! counting new and triaged findings in each scan from its alert stats.
+
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::client::models::ScanResult;
+use crate::output::formatters::format_timestamp_local;
+
+use super::common::timestamp_ms;
+
+/// Alert status of new (untriaged) findings
+const NEW_STATUS: &str = "UNKNOWN";
+
+/// Alert status of triaged (assigned) findings
+const TRIAGED_STATUS: &str = "PROMOTED";
+
+/// Severities counted as findings
+const SEVERITIES: &[&str] = &["High", "Medium", "Low"];
+
+/// Sparkline glyphs, lowest to highest
+const SPARKS: &[char] = &['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Findings in one completed scan
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct TrendPoint {
+    /// When the scan started (local time)
+    #[tabled(rename = "STARTED")]
+    #[serde(skip)]
+    pub started: String,
+
+    /// Environment name
+    #[tabled(rename = "ENV")]
+    pub env: String,
+
+    /// New (untriaged) findings
+    #[tabled(rename = "NEW")]
+    pub new: u32,
+
+    /// Triaged findings
+    #[tabled(rename = "TRIAGED")]
+    pub triaged: u32,
+
+    /// Open high findings (new + triaged)
+    #[tabled(rename = "HIGH")]
+    pub high: u32,
+
+    /// Open medium findings (new + triaged)
+    #[tabled(rename = "MEDIUM")]
+    pub medium: u32,
+
+    /// Open low findings (new + triaged)
+    #[tabled(rename = "LOW")]
+    pub low: u32,
+
+    /// Scan ID
+    #[tabled(rename = "SCAN ID")]
+    pub scan_id: String,
+
+    /// Scan start time (epoch milliseconds)
+    #[tabled(skip)]
+    pub timestamp: i64,
+}
+
+impl TrendPoint {
+    /// Count a scan's findings from its alert stats
+    pub fn from_scan(result: &ScanResult) -> Self {
+        let count = |statuses: &[&str], severities: &[&str]| -> u32 {
+            result.alert_stats.as_ref().map_or(0, |stats| {
+                stats
+                    .alert_status_stats
+                    .iter()
+                    .filter(|s| statuses.contains(&s.alert_status.as_str()))
+                    .flat_map(|s| {
+                        severities
+                            .iter()
+                            .filter_map(|sev| s.severity_stats.get(*sev))
+                    })
+                    .sum()
+            })
+        };
+        let open = &[NEW_STATUS, TRIAGED_STATUS];
+
+        Self {
+            started: format_timestamp_local(&result.scan.timestamp),
+            env: result.scan.env.clone(),
+            new: count(&[NEW_STATUS], SEVERITIES),
+            triaged: count(&[TRIAGED_STATUS], SEVERITIES),
+            high: count(open, &["High"]),
+            medium: count(open, &["Medium"]),
+            low: count(open, &["Low"]),
+            scan_id: result.scan.id.clone(),
+            timestamp: timestamp_ms(result),
+        }
+    }
+}
+
+/// Scans of one environment, oldest first
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct TrendSeries {
+    /// Environment name
+    pub env: String,
+
+    /// New findings per scan as a sparkline
+    pub new_sparkline: String,
+
+    /// Triaged findings per scan as a sparkline
+    pub triaged_sparkline: String,
+
+    /// One point per completed scan
+    pub points: Vec<TrendPoint>,
+}
+
+/// Findings trend of an application
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct FindingsTrend {
+    /// Application name
+    pub app: String,
+
+    /// Application ID
+    pub app_id: String,
+
+    /// Time window as given (e.g. `90d`)
+    pub since: String,
+
+    /// Completed scans in the window
+    pub scans_analyzed: usize,
+
+    /// One series per environment, by name
+    pub series: Vec<TrendSeries>,
+}
+
+impl FindingsTrend {
+    /// Build per-environment series from completed scans (any order)
+    ///
+    /// Scans that did not complete are skipped.
+    pub fn build(app: &str, app_id: &str, since: &str, scans: &[ScanResult]) -> Self {
+        let mut by_env: BTreeMap<String, Vec<TrendPoint>> = BTreeMap::new();
+        for scan in scans
+            .iter()
+            .filter(|s| s.scan.status.eq_ignore_ascii_case("COMPLETED"))
+        {
+            by_env
+                .entry(scan.scan.env.clone())
+                .or_default()
+                .push(TrendPoint::from_scan(scan));
+        }
+
+        let series: Vec<TrendSeries> = by_env
+            .into_iter()
+            .map(|(env, mut points)| {
+                points.sort_by_key(|p| p.timestamp);
+                let new: Vec<u32> = points.iter().map(|p| p.new).collect();
+                let triaged: Vec<u32> = points.iter().map(|p| p.triaged).collect();
+                TrendSeries {
+                    env,
+                    new_sparkline: sparkline(&new),
+                    triaged_sparkline: sparkline(&triaged),
+                    points,
+                }
+            })
+            .collect();
+
+        Self {
+            app: app.to_string(),
+            app_id: app_id.to_string(),
+            since: since.to_string(),
+            scans_analyzed: series.iter().map(|s| s.points.len()).sum(),
+            series,
+        }
+    }
+
+    /// All points, grouped by environment and oldest first
+    pub fn rows(&self) -> Vec<TrendPoint> {
+        self.series
+            .iter()
+            .flat_map(|s| s.points.iter().cloned())
+            .collect()
+    }
+}
+
+/// Render values as a sparkline scaled from zero to the largest value
+pub fn sparkline(values: &[u32]) -> String {
+    let max = values.iter().copied().max().unwrap_or(0);
+    values
+        .iter()
+        .map(|&v| {
+            if max == 0 {
+                SPARKS[0]
+            } else {
+                let idx = (v as usize * (SPARKS.len() - 1) + max as usize / 2) / max as usize;
+                SPARKS[idx]
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::fixtures::ScanResultBuilder;
+
+    fn scan(id: &str, env: &str, status: &str, ts: &str, new: u32, triaged: u32) -> ScanResult {
+        ScanResultBuilder::new(id, "app-1")
+            .app_name("web")
+            .env(env)
+            .status(status)
+            .timestamp(ts)
+            .with_findings(new, 0, 1)
+            .with_triaged_findings(triaged, 0, 1)
+            .with_status_findings("FALSE_POSITIVE", 7, 0, 1)
+            .build()
+    }
+
+    #[test]
+    fn test_sparkline() {
+        assert_eq!(sparkline(&[]), "");
+        assert_eq!(sparkline(&[0, 0]), "▁▁");
+        assert_eq!(sparkline(&[0, 4, 8]), "▁▅█");
+    }
+
+    #[test]
+    fn test_build_series_per_env() {
+        let scans = vec![
+            scan("s2", "prod", "COMPLETED", "2000", 4, 1),
+            scan("s1", "prod", "COMPLETED", "1000", 0, 0),
+            scan("s3", "prod", "ERROR", "3000", 9, 9),
+            scan("d1", "dev", "COMPLETED", "1500", 2, 2),
+        ];
+
+        let trend = FindingsTrend::build("web", "app-1", "90d", &scans);
+        assert_eq!(trend.scans_analyzed, 3);
+        let envs: Vec<_> = trend.series.iter().map(|s| s.env.as_str()).collect();
+        assert_eq!(envs, vec!["dev", "prod"]);
+
+        let prod = &trend.series[1];
+        let ids: Vec<_> = prod.points.iter().map(|p| p.scan_id.as_str()).collect();
+        assert_eq!(ids, vec!["s1", "s2"]);
+        // High + Low counted per status; false positives excluded
+        assert_eq!((prod.points[1].new, prod.points[1].triaged), (5, 2));
+        assert_eq!(prod.points[1].high, 5);
+        assert_eq!(prod.points[1].low, 2);
+        assert_eq!(prod.new_sparkline, "▂█");
+    }
+}
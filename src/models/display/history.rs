@@ -0,0 +1,47 @@
+//! Command history display models
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::history::HistoryEntry;
+
+/// History entry display model for table/JSON output.
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct HistoryDisplay {
+    /// Position in history (use with `hawkop rerun <n>`)
+    #[tabled(rename = "#")]
+    pub id: usize,
+
+    /// When the command ran (local time)
+    #[tabled(rename = "WHEN")]
+    pub when: String,
+
+    /// OK or FAILED
+    #[tabled(rename = "STATUS")]
+    pub status: String,
+
+    /// Command line (without the `hawkop` prefix)
+    #[tabled(rename = "COMMAND")]
+    pub command: String,
+
+    /// Query identity (same command + org + host = same key)
+    #[tabled(skip)]
+    pub cache_key: String,
+}
+
+impl From<&HistoryEntry> for HistoryDisplay {
+    fn from(entry: &HistoryEntry) -> Self {
+        Self {
+            id: entry.id,
+            when: entry
+                .timestamp
+                .with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string(),
+            status: if entry.success { "OK" } else { "FAILED" }.to_string(),
+            command: entry.command_line(),
+            cache_key: entry.cache_key.clone(),
+        }
+    }
+}
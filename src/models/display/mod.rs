@@ -3,33 +3,63 @@
 //! Display models transform API response types into CLI-friendly formats
 //! with appropriate column names and serialization.
 
+pub mod alert_groups;
 mod app;
+pub mod app_duplicates;
+pub mod app_findings;
+pub mod app_inventory;
 mod audit;
+pub mod audit_summary;
 mod common;
 mod config;
+pub mod coverage_report;
 mod env;
 mod finding;
+pub mod finding_inventory;
+pub mod finding_search;
+pub mod finding_triage;
+pub mod findings_report;
+pub mod findings_trend;
+mod history;
 mod oas;
+pub mod oas_map;
 mod org;
 mod policy;
+pub mod redact;
+pub mod release_gate;
 mod repo;
 mod run;
 mod scan;
+pub mod scan_diff;
 pub mod scan_full;
+pub mod scan_gate;
+pub mod scan_group;
 mod secret;
+pub mod stale_apps;
+mod suppression;
+pub mod surface;
+pub mod team_audit;
+pub mod team_import;
+pub mod team_report;
+pub mod usage_report;
 mod user;
 
 // Re-export all display types used by CLI commands
 pub use app::{AppDetailDisplay, AppDisplay};
 pub use audit::AuditDisplay;
+pub use common::{or_dash, timestamp_ms};
 pub use config::ConfigDisplay;
 pub use env::EnvDisplay;
-pub use finding::{AlertDetail, AlertFindingDisplay, AlertMessageDetail, PrettyAlertDisplay};
+pub use finding::{
+    AlertDetail, AlertFindingDisplay, AlertMessageDetail, OwaspAlertDisplay, PrettyAlertDisplay,
+};
+pub use history::HistoryDisplay;
 pub use oas::OASDisplay;
-pub use org::OrgDisplay;
+pub use org::{OrgDetail, OrgDisplay, OrgScoped, OrgUsage, format_org_detail, format_quota};
 pub use policy::PolicyDisplay;
-pub use repo::RepoDisplay;
+pub use repo::{RepoDisplay, format_repo_detail};
 pub use run::{PrettyRunStatus, RunStatusDisplay};
-pub use scan::ScanDisplay;
-pub use secret::SecretDisplay;
-pub use user::{TeamListDisplay, UserDisplay};
+pub use scan::{RowChange, ScanDisplay, diff_rows, visible_tags};
+pub use secret::{SecretDisplay, SecretUsageDisplay};
+pub use suppression::SuppressionDisplay;
+pub use user::{TeamAppDisplay, TeamListDisplay, TeamMemberDisplay, UserDisplay, UserTeamsDisplay};
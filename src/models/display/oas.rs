@@ -1,12 +1,13 @@
 //! OpenAPI specification display model
 
+use schemars::JsonSchema;
 use serde::Serialize;
 use tabled::Tabled;
 
 use crate::client::models::OASAsset;
 
 /// OpenAPI specification asset display model for table/JSON output.
-#[derive(Debug, Clone, Tabled, Serialize)]
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
 pub struct OASDisplay {
     /// OAS ID
     #[tabled(rename = "OAS ID")]
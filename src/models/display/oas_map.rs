@@ -0,0 +1,249 @@
+//! Scan findings mapped to OpenAPI operations for `scan map-oas`
+//!
+//! Each path-level finding is matched to the spec operation whose method and
+//! path template fit its request. Operations with no findings and findings
+//! outside the spec are reported separately.
+
+use std::collections::BTreeSet;
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use tabled::Tabled;
+
+use super::scan_diff::DiffFinding;
+use crate::openapi::OpenApiSpec;
+
+/// Findings on one API operation
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct OperationFindings {
+    /// HTTP method
+    #[tabled(rename = "METHOD")]
+    pub method: String,
+
+    /// Path template from the spec
+    #[tabled(rename = "PATH")]
+    pub path: String,
+
+    /// operationId, or "--"
+    #[tabled(rename = "OPERATION")]
+    pub operation_id: String,
+
+    /// High severity findings
+    #[tabled(rename = "HIGH")]
+    pub high: usize,
+
+    /// Medium severity findings
+    #[tabled(rename = "MEDIUM")]
+    pub medium: usize,
+
+    /// Low severity findings
+    #[tabled(rename = "LOW")]
+    pub low: usize,
+
+    /// Plugins that reported findings on the operation
+    #[tabled(skip)]
+    pub plugin_ids: Vec<String>,
+}
+
+/// A spec operation without findings
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct UncoveredOperation {
+    /// HTTP method
+    #[tabled(rename = "METHOD")]
+    pub method: String,
+
+    /// Path template from the spec
+    #[tabled(rename = "PATH")]
+    pub path: String,
+
+    /// operationId, or "--"
+    #[tabled(rename = "OPERATION")]
+    pub operation_id: String,
+}
+
+/// A finding whose request matches no spec operation
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct UnmappedFinding {
+    /// Plugin ID
+    #[tabled(rename = "PLUGIN")]
+    pub plugin_id: String,
+
+    /// Severity level
+    #[tabled(rename = "SEVERITY")]
+    pub severity: String,
+
+    /// HTTP method
+    #[tabled(rename = "METHOD")]
+    pub method: String,
+
+    /// Request URI as reported by the scan
+    #[tabled(rename = "URI")]
+    pub uri: String,
+}
+
+/// Findings of one scan, mapped onto the operations of a spec
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct OasFindingsMap {
+    /// Scan the findings came from
+    pub scan_id: String,
+
+    /// Spec title, or the file name when it has none
+    pub spec: String,
+
+    /// Operations the spec declares
+    pub operations: usize,
+
+    /// Findings matched to an operation
+    pub mapped: usize,
+
+    /// Operations with findings, most severe first
+    pub by_operation: Vec<OperationFindings>,
+
+    /// Operations with no findings, in spec order
+    pub without_findings: Vec<UncoveredOperation>,
+
+    /// Findings outside the spec
+    pub unmapped: Vec<UnmappedFinding>,
+}
+
+impl OasFindingsMap {
+    /// Match every finding against `spec`
+    pub fn build(
+        scan_id: &str,
+        spec_name: &str,
+        spec: &OpenApiSpec,
+        findings: &[DiffFinding],
+    ) -> Self {
+        let mut counts: Vec<(usize, [usize; 3], BTreeSet<String>)> = Vec::new();
+        let mut unmapped = Vec::new();
+
+        for finding in findings {
+            let Some(op) = spec.match_uri(&finding.method, &finding.uri) else {
+                unmapped.push(UnmappedFinding {
+                    plugin_id: finding.plugin_id.clone(),
+                    severity: finding.severity.clone(),
+                    method: finding.method.to_uppercase(),
+                    uri: finding.uri.clone(),
+                });
+                continue;
+            };
+            let index = spec
+                .operations
+                .iter()
+                .position(|o| std::ptr::eq(o, op))
+                .expect("matched operation is in the spec");
+            let entry = match counts.iter_mut().position(|(i, _, _)| *i == index) {
+                Some(pos) => &mut counts[pos],
+                None => {
+                    counts.push((index, [0; 3], BTreeSet::new()));
+                    counts.last_mut().expect("just pushed")
+                }
+            };
+            match finding.severity.to_uppercase().as_str() {
+                "HIGH" => entry.1[0] += 1,
+                "MEDIUM" => entry.1[1] += 1,
+                _ => entry.1[2] += 1,
+            }
+            entry.2.insert(finding.plugin_id.clone());
+        }
+
+        let operation_id = |index: usize| {
+            spec.operations[index]
+                .operation_id
+                .clone()
+                .unwrap_or_else(|| "--".to_string())
+        };
+        let mapped = findings.len() - unmapped.len();
+        let mut by_operation: Vec<OperationFindings> = counts
+            .iter()
+            .map(|(index, [high, medium, low], plugins)| OperationFindings {
+                method: spec.operations[*index].method.clone(),
+                path: spec.operations[*index].path.clone(),
+                operation_id: operation_id(*index),
+                high: *high,
+                medium: *medium,
+                low: *low,
+                plugin_ids: plugins.iter().cloned().collect(),
+            })
+            .collect();
+        by_operation.sort_by(|a, b| {
+            (b.high, b.medium, b.low)
+                .cmp(&(a.high, a.medium, a.low))
+                .then_with(|| a.path.cmp(&b.path))
+                .then_with(|| a.method.cmp(&b.method))
+        });
+
+        let without_findings = (0..spec.operations.len())
+            .filter(|i| !counts.iter().any(|(index, _, _)| index == i))
+            .map(|i| UncoveredOperation {
+                method: spec.operations[i].method.clone(),
+                path: spec.operations[i].path.clone(),
+                operation_id: operation_id(i),
+            })
+            .collect();
+
+        unmapped.sort_by(|a, b| {
+            a.uri
+                .cmp(&b.uri)
+                .then_with(|| a.plugin_id.cmp(&b.plugin_id))
+        });
+
+        Self {
+            scan_id: scan_id.to_string(),
+            spec: spec.title.clone().unwrap_or_else(|| spec_name.to_string()),
+            operations: spec.operations.len(),
+            mapped,
+            by_operation,
+            without_findings,
+            unmapped,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finding(plugin_id: &str, severity: &str, method: &str, uri: &str) -> DiffFinding {
+        DiffFinding {
+            plugin_id: plugin_id.to_string(),
+            plugin_name: String::new(),
+            severity: severity.to_string(),
+            method: method.to_string(),
+            uri: uri.to_string(),
+            status: "UNKNOWN".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_build_map() {
+        let spec = OpenApiSpec::parse(
+            "openapi: 3.0.0\npaths:\n  /users:\n    get:\n      operationId: listUsers\n  \
+             /users/{id}:\n    get:\n      operationId: getUser\n    delete: {}\n",
+        )
+        .unwrap();
+        let findings = vec![
+            finding("10020", "Low", "GET", "/users"),
+            finding("40018", "High", "GET", "/users/1"),
+            finding("40018", "High", "GET", "/users/2"),
+            finding("10038", "Medium", "GET", "/users/2"),
+            finding("10020", "Low", "GET", "/admin"),
+        ];
+
+        let map = OasFindingsMap::build("scan-1", "spec.yaml", &spec, &findings);
+
+        assert_eq!(map.spec, "spec.yaml");
+        assert_eq!((map.operations, map.mapped), (3, 4));
+        assert_eq!(map.by_operation[0].operation_id, "getUser");
+        assert_eq!(
+            (map.by_operation[0].high, map.by_operation[0].medium),
+            (2, 1)
+        );
+        assert_eq!(map.by_operation[0].plugin_ids, vec!["10038", "40018"]);
+        assert_eq!(map.by_operation[1].operation_id, "listUsers");
+        assert_eq!(map.without_findings.len(), 1);
+        assert_eq!(map.without_findings[0].method, "DELETE");
+        assert_eq!(map.unmapped.len(), 1);
+        assert_eq!(map.unmapped[0].uri, "/admin");
+    }
+}
@@ -1,12 +1,17 @@
 //! Organization display model
 
+use std::borrow::Cow;
+
+use schemars::JsonSchema;
 use serde::Serialize;
 use tabled::Tabled;
 
 use crate::client::models::Organization;
 
+use super::common::format_as_iso_datetime;
+
 /// Organization display model for table/JSON output.
-#[derive(Debug, Clone, Tabled, Serialize)]
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
 pub struct OrgDisplay {
     /// Organization ID
     #[tabled(rename = "ORG ID")]
@@ -35,6 +40,200 @@ impl From<&Organization> for OrgDisplay {
     }
 }
 
+/// A list row tagged with the organization it came from, for `--all-orgs`
+///
+/// Tables gain a leading ORG column; JSON gains `org` and `orgId` fields
+/// beside the row's own.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OrgScoped<T> {
+    /// Organization name
+    pub org: String,
+
+    /// Organization ID
+    pub org_id: String,
+
+    /// The row itself
+    #[serde(flatten)]
+    pub row: T,
+}
+
+impl<T> OrgScoped<T> {
+    /// Tag `row` with `org`
+    pub fn new(org: &Organization, row: T) -> Self {
+        Self {
+            org: org.name.clone(),
+            org_id: org.id.clone(),
+            row,
+        }
+    }
+}
+
+// Implemented by hand so rows that are only serialized (not tabled) can be
+// tagged too
+impl<T: Tabled> Tabled for OrgScoped<T> {
+    const LENGTH: usize = T::LENGTH + 1;
+
+    fn fields(&self) -> Vec<Cow<'_, str>> {
+        std::iter::once(Cow::Borrowed(self.org.as_str()))
+            .chain(self.row.fields())
+            .collect()
+    }
+
+    fn headers() -> Vec<Cow<'static, str>> {
+        std::iter::once(Cow::Borrowed("ORG"))
+            .chain(T::headers())
+            .collect()
+    }
+}
+
+/// Organization with current consumption, for `org get`
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct OrgDetail {
+    #[serde(flatten)]
+    pub org: Organization,
+
+    /// Counts measured against the subscription's soft limits
+    pub usage: OrgUsage,
+}
+
+/// Current application and member counts (None if they could not be fetched)
+#[derive(Debug, Clone, Default, Serialize, JsonSchema)]
+pub struct OrgUsage {
+    pub apps: Option<usize>,
+    pub users: Option<usize>,
+}
+
+/// Multi-section text view of an organization's plan, quotas, and features
+pub fn format_org_detail(detail: &OrgDetail) -> String {
+    let org = &detail.org;
+    let sub = org.subscription.as_ref();
+    let mut out = String::new();
+
+    out.push_str(&format!("Organization: {}\n", org.name));
+    out.push_str(&format!("ID:           {}\n", org.id));
+    if let Some(created) = org.created_timestamp {
+        out.push_str(&format!(
+            "Created:      {}\n",
+            format_as_iso_datetime(&created.to_string())
+        ));
+    }
+    out.push_str(&format!(
+        "Plan:         {}\n",
+        org.plan.as_deref().unwrap_or("--")
+    ));
+
+    out.push_str("\nSubscription\n");
+    match sub {
+        None => out.push_str("  --\n"),
+        Some(sub) => {
+            out.push_str(&format!(
+                "  Product:  {}\n",
+                sub.product_name.as_deref().unwrap_or("--")
+            ));
+            out.push_str(&format!(
+                "  Status:   {}\n",
+                sub.status.as_deref().unwrap_or("--")
+            ));
+            if let Some(interval) = &sub.recurring_interval {
+                out.push_str(&format!("  Billing:  {}\n", interval));
+            }
+            let period = [sub.start_date, sub.end_date].map(|d| {
+                d.filter(|s| *s > 0)
+                    .map(|s| format_as_iso_datetime(&(s * 1000).to_string()))
+                    .unwrap_or_else(|| "--".to_string())
+            });
+            if sub.start_date.is_some() || sub.end_date.is_some() {
+                out.push_str(&format!("  Period:   {} → {}\n", period[0], period[1]));
+            }
+        }
+    }
+
+    out.push_str("\nQuotas\n");
+    out.push_str(&format!(
+        "  Applications:  {}\n",
+        format_quota(detail.usage.apps, sub.and_then(|s| s.app_count))
+    ));
+    out.push_str(&format!(
+        "  Users:         {}\n",
+        format_quota(detail.usage.users, sub.and_then(|s| s.user_count))
+    ));
+    let settings = org.settings.as_ref();
+    if let Some(limit) = settings
+        .and_then(|s| s.cloud_scan_settings.as_ref())
+        .and_then(|c| c.usage_period_limit_seconds)
+    {
+        out.push_str(&format!(
+            "  Hosted scans:  {} per usage period\n",
+            format_hours(limit)
+        ));
+    }
+    if let Some(limit) = settings.and_then(|s| s.custom_plugin_limit) {
+        out.push_str(&format!("  Custom plugins: {} allowed\n", limit));
+    }
+
+    out.push_str("\nFeatures\n");
+    let mut enabled: Vec<&str> = org
+        .features
+        .iter()
+        .filter(|f| f.enabled)
+        .map(|f| f.name.as_str())
+        .collect();
+    enabled.sort_unstable();
+    if enabled.is_empty() {
+        out.push_str("  --\n");
+    } else {
+        for name in enabled {
+            out.push_str(&format!("  {}\n", name));
+        }
+    }
+    if let Some(settings) = settings {
+        let disabled: Vec<&str> = [
+            (settings.is_turned_off_ai, "AI features"),
+            (settings.is_turned_off_oas_gen, "OpenAPI generation"),
+        ]
+        .into_iter()
+        .filter_map(|(off, name)| off.then_some(name))
+        .collect();
+        if !disabled.is_empty() {
+            out.push_str(&format!("  (turned off: {})\n", disabled.join(", ")));
+        }
+        if settings.is_organization_saml_only {
+            out.push_str("  (SAML-only sign-in)\n");
+        }
+    }
+
+    out
+}
+
+/// "12 of 50 (38 remaining)", "12 (no limit)", or "-- of 50"
+pub fn format_quota(used: Option<usize>, limit: Option<i64>) -> String {
+    let limit = limit.filter(|l| *l > 0);
+    match (used, limit) {
+        (Some(used), Some(limit)) => {
+            let remaining = limit - used as i64;
+            if remaining >= 0 {
+                format!("{} of {} ({} remaining)", used, limit, remaining)
+            } else {
+                format!("{} of {} ({} over)", used, limit, -remaining)
+            }
+        }
+        (Some(used), None) => format!("{} (no limit)", used),
+        (None, Some(limit)) => format!("-- of {}", limit),
+        (None, None) => "--".to_string(),
+    }
+}
+
+/// Seconds as hours, e.g. "10h" or "2h 30m"
+fn format_hours(secs: i64) -> String {
+    let (hours, minutes) = (secs / 3600, (secs % 3600) / 60);
+    if minutes == 0 {
+        format!("{}h", hours)
+    } else {
+        format!("{}h {}m", hours, minutes)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -46,6 +245,7 @@ mod tests {
             name: "Test Org".to_string(),
             user_count: Some(10),
             app_count: Some(5),
+            ..Default::default()
         };
 
         let display = OrgDisplay::from(org);
@@ -61,6 +261,7 @@ mod tests {
             name: "Another Org".to_string(),
             user_count: None,
             app_count: None,
+            ..Default::default()
         };
 
         let display = OrgDisplay::from(&org);
@@ -68,4 +269,41 @@ mod tests {
         assert_eq!(display.id, "org-456");
         assert_eq!(display.name, "Another Org");
     }
+
+    #[test]
+    fn test_org_scoped_row() {
+        let org = Organization {
+            id: "org-1".to_string(),
+            name: "Acme".to_string(),
+            ..Default::default()
+        };
+        let row = OrgScoped::new(
+            &org,
+            OrgDisplay {
+                id: "x".to_string(),
+                name: "y".to_string(),
+            },
+        );
+
+        assert_eq!(
+            OrgScoped::<OrgDisplay>::headers(),
+            vec!["ORG", "ORG ID", "NAME"]
+        );
+        assert_eq!(row.fields(), vec!["Acme", "x", "y"]);
+        let json = serde_json::to_value(&row).unwrap();
+        assert_eq!(json["org"], "Acme");
+        assert_eq!(json["orgId"], "org-1");
+        assert_eq!(json["name"], "y");
+    }
+
+    #[test]
+    fn test_format_quota() {
+        assert_eq!(format_quota(Some(12), Some(50)), "12 of 50 (38 remaining)");
+        assert_eq!(format_quota(Some(55), Some(50)), "55 of 50 (5 over)");
+        assert_eq!(format_quota(Some(12), None), "12 (no limit)");
+        assert_eq!(format_quota(Some(12), Some(0)), "12 (no limit)");
+        assert_eq!(format_quota(None, Some(50)), "-- of 50");
+        assert_eq!(format_hours(36000), "10h");
+        assert_eq!(format_hours(9000), "2h 30m");
+    }
 }
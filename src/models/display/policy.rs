@@ -1,12 +1,13 @@
 //! Policy display model
 
+use schemars::JsonSchema;
 use serde::Serialize;
 use tabled::Tabled;
 
 use crate::client::models::{OrgPolicy, PolicyType, StackHawkPolicy};
 
 /// Policy display model for table/JSON output.
-#[derive(Debug, Clone, Tabled, Serialize)]
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
 pub struct PolicyDisplay {
     /// Policy type (StackHawk or Organization)
     #[tabled(rename = "TYPE")]
@@ -0,0 +1,96 @@
+//! Redaction of credentials in HTTP messages shown by `scan get`
+//!
+//! Scan messages are the traffic HawkScan recorded, so they carry whatever
+//! credentials the scanner used: `Authorization` headers, session cookies,
+//! and API keys or tokens in query strings and bodies. [`redact_message`]
+//! replaces those values with `[REDACTED]` before a message is printed,
+//! keeping the names so the request still reads correctly. `--no-redact`
+//! shows the message as captured. The scanning is shared with logging and
+//! the cache (see [`crate::redact`]).
+
+use crate::cache::redact::redact_alert_message;
+use crate::client::models::AlertMsgResponse;
+use crate::output::body::decode_body_text;
+use crate::redact::redact_params;
+
+/// Copy of `message` with credentials redacted
+///
+/// Covers credential headers and cookies (as the cache does with
+/// `cache.redact_sensitive`), plus sensitive parameters in the URI, the
+/// request line, the curl command, and the request and response bodies.
+/// Encoded bodies are checked after decoding; one that needed redacting is
+/// replaced by its decoded, redacted text. Evidence is left alone, since it
+/// is what the finding is about.
+pub fn redact_message(message: &AlertMsgResponse) -> AlertMsgResponse {
+    let mut message = redact_alert_message(message);
+    message.uri = redact_params(&message.uri);
+    if let Some(curl) = message.validation_command.as_mut() {
+        *curl = redact_params(curl);
+    }
+
+    let scan_message = &mut message.scan_message;
+    if let Some(headers) = scan_message.request_header.as_mut() {
+        *headers = redact_params(headers);
+    }
+    for (headers, body) in [
+        (
+            scan_message.request_header.as_deref(),
+            &mut scan_message.request_body,
+        ),
+        (
+            scan_message.response_header.as_deref(),
+            &mut scan_message.response_body,
+        ),
+    ] {
+        if let Some(body) = body.as_mut() {
+            let decoded = decode_body_text(headers, body);
+            let redacted = redact_params(&decoded);
+            if redacted != decoded {
+                *body = redacted;
+            }
+        }
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_message() {
+        let message: AlertMsgResponse = serde_json::from_value(serde_json::json!({
+            "scanMessage": {
+                "id": "m1",
+                "requestHeader": "POST /login?apikey=k1 HTTP/1.1\r\nAuthorization: Bearer abc\r\nCookie: JSESSIONID=s1\r\n",
+                "requestBody": "user=alice&password=hunter2",
+                "responseHeader": "HTTP/1.1 200 OK\r\nSet-Cookie: sid=s2\r\n",
+                "responseBody": "{\"access_token\": \"t1\"}"
+            },
+            "uri": "https://example.com/login?apikey=k1",
+            "evidence": "hunter2",
+            "validationCommand": "curl -X POST 'https://example.com/login?apikey=k1' -H 'Authorization: Bearer abc' -d 'password=hunter2'"
+        }))
+        .unwrap();
+
+        let redacted = redact_message(&message);
+        let text = serde_json::to_string(&redacted).unwrap();
+        for secret in ["k1", "abc", "s1", "s2", "t1"] {
+            assert!(!text.contains(secret), "{} leaked: {}", secret, text);
+        }
+        assert_eq!(redacted.uri, "https://example.com/login?apikey=[REDACTED]");
+        assert_eq!(
+            redacted.scan_message.request_body.as_deref(),
+            Some("user=alice&password=[REDACTED]")
+        );
+        assert!(
+            redacted
+                .validation_command
+                .as_deref()
+                .unwrap()
+                .contains("Authorization: Bearer [REDACTED]")
+        );
+        // Evidence is what the finding reports, so it stays
+        assert_eq!(redacted.evidence.as_deref(), Some("hunter2"));
+    }
+}
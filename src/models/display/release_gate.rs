@@ -0,0 +1,295 @@
+//! Multi-application threshold evaluation for `gate release`
+//!
+//! Each requested application is checked per environment against the latest
+//! completed scan there, using the same rules as `scan gate`. An app/env with
+//! no completed scan fails the release, since there is nothing to vouch for it.
+
+use std::collections::BTreeSet;
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use tabled::Tabled;
+
+use super::common::timestamp_ms;
+use super::scan_gate::GateResult;
+use crate::cli::FindingsThreshold;
+use crate::client::models::ScanResult;
+use crate::output::junit::{Failure, TestCase, TestSuite};
+
+/// One application requested with `--apps`
+#[derive(Debug, Clone)]
+pub struct ReleaseApp {
+    /// Name or ID as given on the command line
+    pub label: String,
+
+    /// Resolved application ID
+    pub id: String,
+}
+
+/// Gate outcome for one application environment
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ReleaseEntry {
+    /// Application name (or the requested label when it has no scans)
+    pub app: String,
+
+    /// Environment name
+    pub env: String,
+
+    /// Whether this app/env passed
+    pub passed: bool,
+
+    /// Threshold results for the latest completed scan, if there is one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gate: Option<GateResult>,
+}
+
+/// One row of the release matrix
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct ReleaseRow {
+    /// Application name
+    #[tabled(rename = "APP")]
+    pub app: String,
+
+    /// Environment name
+    #[tabled(rename = "ENV")]
+    pub env: String,
+
+    /// Scan evaluated, or "--"
+    #[tabled(rename = "SCAN ID")]
+    pub scan_id: String,
+
+    /// Counted findings per rule (e.g. "high 0/1, medium 4/10")
+    #[tabled(rename = "FOUND")]
+    pub found: String,
+
+    /// "pass", "FAIL", or "NO SCAN"
+    #[tabled(rename = "RESULT")]
+    pub result: &'static str,
+}
+
+/// Release gate across several applications
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ReleaseGate {
+    /// True when every app/env passed
+    pub passed: bool,
+
+    /// Which findings were counted: "new" or "new+triaged"
+    pub counted: &'static str,
+
+    /// Results in `--apps` order, environments sorted by name
+    pub entries: Vec<ReleaseEntry>,
+}
+
+impl ReleaseGate {
+    /// Evaluate the latest completed scan of each app/env in `scans`
+    ///
+    /// With no `envs`, every environment an app has a completed scan in is
+    /// checked; an app with none gets a single failing entry.
+    pub fn evaluate(
+        apps: &[ReleaseApp],
+        envs: &[String],
+        scans: &[ScanResult],
+        thresholds: &[FindingsThreshold],
+        include_triaged: bool,
+    ) -> Self {
+        let mut entries = Vec::new();
+        for app in apps {
+            let completed: Vec<&ScanResult> = scans
+                .iter()
+                .filter(|s| s.scan.application_id == app.id)
+                .filter(|s| s.scan.status.eq_ignore_ascii_case("COMPLETED"))
+                .collect();
+            let name = completed
+                .first()
+                .map_or(app.label.as_str(), |s| s.scan.application_name.as_str());
+
+            let app_envs: Vec<String> = if envs.is_empty() {
+                let found: BTreeSet<&str> = completed.iter().map(|s| s.scan.env.as_str()).collect();
+                if found.is_empty() {
+                    vec!["--".to_string()]
+                } else {
+                    found.into_iter().map(str::to_string).collect()
+                }
+            } else {
+                envs.to_vec()
+            };
+
+            for env in app_envs {
+                let latest = completed
+                    .iter()
+                    .filter(|s| s.scan.env.eq_ignore_ascii_case(&env))
+                    .max_by_key(|s| timestamp_ms(s));
+                let gate = latest.map(|s| GateResult::evaluate(s, thresholds, include_triaged));
+                entries.push(ReleaseEntry {
+                    app: name.to_string(),
+                    env,
+                    passed: gate.as_ref().is_some_and(|g| g.passed),
+                    gate,
+                });
+            }
+        }
+
+        Self {
+            passed: entries.iter().all(|e| e.passed),
+            counted: if include_triaged {
+                "new+triaged"
+            } else {
+                "new"
+            },
+            entries,
+        }
+    }
+
+    /// Table rows, one per app/env
+    pub fn rows(&self) -> Vec<ReleaseRow> {
+        self.entries
+            .iter()
+            .map(|entry| match &entry.gate {
+                Some(gate) => ReleaseRow {
+                    app: entry.app.clone(),
+                    env: entry.env.clone(),
+                    scan_id: gate.scan_id.clone(),
+                    found: gate
+                        .rules
+                        .iter()
+                        .map(|r| format!("{} {}/{}", r.severity, r.found, r.threshold))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    result: if entry.passed { "pass" } else { "FAIL" },
+                },
+                None => ReleaseRow {
+                    app: entry.app.clone(),
+                    env: entry.env.clone(),
+                    scan_id: "--".to_string(),
+                    found: "--".to_string(),
+                    result: "NO SCAN",
+                },
+            })
+            .collect()
+    }
+
+    /// Failed app/envs with their reasons, for the exit error
+    pub fn failure_summary(&self) -> String {
+        self.entries
+            .iter()
+            .filter(|e| !e.passed)
+            .map(|entry| {
+                let reason = entry.gate.as_ref().map_or_else(
+                    || "no completed scan".to_string(),
+                    GateResult::failure_summary,
+                );
+                format!("{}/{}: {}", entry.app, entry.env, reason)
+            })
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+
+    /// JUnit suite with one test case per app/env
+    pub fn junit_suites(&self) -> Vec<TestSuite> {
+        let cases = self
+            .entries
+            .iter()
+            .map(|entry| {
+                let failure = match &entry.gate {
+                    None => Some(Failure {
+                        message: "No completed scan".to_string(),
+                        text: format!("hawkop scan list --app {} --env {}", entry.app, entry.env),
+                    }),
+                    Some(gate) if !gate.passed => Some(Failure {
+                        message: gate.failure_summary(),
+                        text: format!("Scan {} counted {} findings", gate.scan_id, self.counted),
+                    }),
+                    Some(_) => None,
+                };
+                TestCase {
+                    classname: "StackHawk".to_string(),
+                    name: format!("{}.{}", entry.app, entry.env),
+                    failure,
+                }
+            })
+            .collect();
+
+        vec![TestSuite {
+            name: "StackHawk release gate".to_string(),
+            cases,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::fixtures::ScanResultBuilder;
+
+    fn scan(app_id: &str, env: &str, ts: &str, status: &str, high: u32) -> ScanResult {
+        ScanResultBuilder::new(format!("{}-{}-{}", app_id, env, ts), app_id)
+            .app_name(format!("{}-name", app_id))
+            .env(env)
+            .status(status)
+            .timestamp(ts)
+            .with_findings(high, 0, 0)
+            .build()
+    }
+
+    fn app(id: &str) -> ReleaseApp {
+        ReleaseApp {
+            label: id.to_string(),
+            id: id.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_release_gate_uses_latest_completed_scan_per_env() {
+        let scans = vec![
+            scan("a1", "prod", "300", "STARTED", 9),
+            scan("a1", "prod", "200", "COMPLETED", 0),
+            scan("a1", "prod", "100", "COMPLETED", 5),
+            scan("a1", "dev", "150", "COMPLETED", 2),
+            scan("a2", "prod", "100", "ERROR", 0),
+        ];
+        let thresholds = vec!["high:1".parse().unwrap()];
+
+        let gate = ReleaseGate::evaluate(&[app("a1"), app("a2")], &[], &scans, &thresholds, false);
+
+        let rows = gate.rows();
+        let summary: Vec<_> = rows
+            .iter()
+            .map(|r| (r.app.as_str(), r.env.as_str(), r.result))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("a1-name", "dev", "FAIL"),
+                ("a1-name", "prod", "pass"),
+                ("a2", "--", "NO SCAN"),
+            ]
+        );
+        assert_eq!(rows[1].scan_id, "a1-prod-200");
+        assert_eq!(rows[0].found, "high 2/1");
+        assert!(!gate.passed);
+        assert_eq!(
+            gate.failure_summary(),
+            "a1-name/dev: high 2 (max 1); a2/--: no completed scan"
+        );
+    }
+
+    #[test]
+    fn test_release_gate_checks_requested_envs() {
+        let scans = vec![scan("a1", "prod", "100", "COMPLETED", 0)];
+        let thresholds = vec!["high:1".parse().unwrap()];
+        let envs = vec!["prod".to_string(), "staging".to_string()];
+
+        let gate = ReleaseGate::evaluate(&[app("a1")], &envs, &scans, &thresholds, false);
+
+        assert_eq!(gate.entries.len(), 2);
+        assert!(gate.entries[0].passed);
+        assert!(gate.entries[1].gate.is_none());
+        assert!(!gate.passed);
+        let suites = gate.junit_suites();
+        assert!(suites[0].cases[0].failure.is_none());
+        assert_eq!(
+            suites[0].cases[1].failure.as_ref().unwrap().message,
+            "No completed scan"
+        );
+    }
+}
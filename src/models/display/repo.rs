@@ -1,5 +1,6 @@
 //! Repository display model
 
+use schemars::JsonSchema;
 use serde::Serialize;
 use tabled::Tabled;
 
@@ -7,7 +8,7 @@ use super::common::format_as_iso_datetime;
 use crate::client::models::Repository;
 
 /// Repository display model for table/JSON output.
-#[derive(Debug, Clone, Tabled, Serialize)]
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
 pub struct RepoDisplay {
     /// Whether repo is in attack surface
     #[tabled(rename = "SURFACE")]
@@ -129,6 +130,119 @@ impl From<&Repository> for RepoDisplay {
     }
 }
 
+/// Multi-section detail view for `repo get`
+///
+/// Includes everything `repo list` truncates or omits: insights, frameworks,
+/// all sensitive-data tags, and the mapped applications.
+pub fn format_repo_detail(repo: &Repository) -> String {
+    let mut out = String::new();
+
+    let full_name = match &repo.provider_org_name {
+        Some(org) => format!("{}/{}", org, repo.name),
+        None => repo.name.clone(),
+    };
+    out.push_str(&format!("Repository: {}\n", full_name));
+    out.push_str(&format!(
+        "ID:             {}\n",
+        repo.id.as_deref().unwrap_or("--")
+    ));
+    out.push_str(&format!(
+        "Source:         {}\n",
+        repo.repo_source.as_deref().unwrap_or("--")
+    ));
+    out.push_str(&format!(
+        "Attack surface: {}\n",
+        if repo.is_in_attack_surface {
+            "yes"
+        } else {
+            "no"
+        }
+    ));
+
+    let oas = match (&repo.open_api_spec_info, repo.has_generated_open_api_spec) {
+        (Some(info), true) => format!("yes ({} generated)", info.generated_oas_count),
+        (_, true) => "yes".to_string(),
+        (_, false) => "no".to_string(),
+    };
+    out.push_str(&format!("Generated OAS:  {}\n", oas));
+
+    let last_commit = repo
+        .last_commit_timestamp
+        .as_deref()
+        .map(format_as_iso_datetime)
+        .unwrap_or_else(|| "--".to_string());
+    let contributor = repo.last_contributor.as_ref().and_then(|c| {
+        match (c.name.as_deref(), c.email.as_deref()) {
+            (Some(name), Some(email)) => Some(format!("{} <{}>", name, email)),
+            (Some(name), None) => Some(name.to_string()),
+            (None, Some(email)) => Some(email.to_string()),
+            (None, None) => None,
+        }
+    });
+    match contributor {
+        Some(who) => out.push_str(&format!("Last commit:    {} by {}\n", last_commit, who)),
+        None => out.push_str(&format!("Last commit:    {}\n", last_commit)),
+    }
+    out.push_str(&format!("30-day commits: {}\n", repo.commit_count));
+
+    out.push_str("\nInsights\n");
+    if repo.insights.is_empty() {
+        out.push_str("  --\n");
+    } else {
+        let width = repo
+            .insights
+            .iter()
+            .map(|i| i.name.len())
+            .max()
+            .unwrap_or(0);
+        for insight in &repo.insights {
+            out.push_str(&format!(
+                "  {:<width$}  {}\n",
+                insight.name,
+                insight.value,
+                width = width
+            ));
+        }
+    }
+
+    out.push_str("\nFrameworks\n");
+    out.push_str(&format!("  {}\n", join_or_dash(&repo.framework_names)));
+
+    out.push_str("\nSensitive data\n");
+    let tags: Vec<String> = repo
+        .sensitive_data_tags
+        .iter()
+        .map(|t| t.name.clone())
+        .collect();
+    out.push_str(&format!("  {}\n", join_or_dash(&tags)));
+
+    out.push_str(&format!(
+        "\nMapped applications ({})\n",
+        repo.app_infos.len()
+    ));
+    if repo.app_infos.is_empty() {
+        out.push_str("  --\n");
+    } else {
+        for app in &repo.app_infos {
+            out.push_str(&format!(
+                "  {}  {}\n",
+                app.app_name.as_deref().unwrap_or("--"),
+                app.app_id.as_deref().unwrap_or("--")
+            ));
+        }
+    }
+
+    out
+}
+
+fn join_or_dash(items: &[String]) -> String {
+    if items.is_empty() {
+        "--".to_string()
+    } else {
+        items.join(", ")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -185,4 +299,42 @@ mod tests {
         assert_eq!(display.attack_surface, ""); // Empty for false
         assert_eq!(display.oas, ""); // Empty for false
     }
+
+    #[test]
+    fn test_format_repo_detail_lists_all_sections() {
+        use crate::client::models::{RepoAppInfo, RepoInsight, SensitiveDataTag};
+
+        let repo = Repository {
+            id: Some("repo-123".to_string()),
+            repo_source: Some("GITHUB".to_string()),
+            provider_org_name: Some("myorg".to_string()),
+            name: "my-api".to_string(),
+            open_api_spec_info: None,
+            has_generated_open_api_spec: false,
+            is_in_attack_surface: true,
+            framework_names: vec!["Spring".to_string(), "Express".to_string()],
+            sensitive_data_tags: vec![SensitiveDataTag {
+                name: "PII".to_string(),
+            }],
+            last_commit_timestamp: None,
+            last_contributor: None,
+            commit_count: 4,
+            app_infos: vec![RepoAppInfo {
+                app_id: Some("app-1".to_string()),
+                app_name: Some("web".to_string()),
+            }],
+            insights: vec![RepoInsight {
+                name: "apiStyle".to_string(),
+                value: "REST".to_string(),
+            }],
+        };
+
+        let text = format_repo_detail(&repo);
+        assert!(text.starts_with("Repository: myorg/my-api\n"));
+        assert!(text.contains("Attack surface: yes\n"));
+        assert!(text.contains("  apiStyle  REST\n"));
+        assert!(text.contains("  Spring, Express\n"));
+        assert!(text.contains("  PII\n"));
+        assert!(text.contains("Mapped applications (1)\n  web  app-1\n"));
+    }
 }
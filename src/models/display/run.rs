@@ -3,13 +3,14 @@
 //! These models format Perch device status for CLI display.
 
 use colored::Colorize;
+use schemars::JsonSchema;
 use serde::Serialize;
 use tabled::Tabled;
 
 use crate::client::models::PerchDevice;
 
 /// Display model for scan runner status
-#[derive(Debug, Clone, Tabled, Serialize)]
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
 pub struct RunStatusDisplay {
     #[tabled(rename = "STATUS")]
     pub status: String,
@@ -1,13 +1,15 @@
 //! Scan display models and helpers
 
 use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
 use serde::Serialize;
 use tabled::Tabled;
 
-use crate::client::models::{AlertStats, ScanResult};
+use crate::client::models::{AlertStats, ScanResult, ScanTag};
+use crate::models::display::scan_gate::GateResult;
 
 /// Scan display model for table/JSON output.
-#[derive(Debug, Clone, Tabled, Serialize)]
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
 pub struct ScanDisplay {
     /// Scan ID
     #[tabled(rename = "SCAN ID")]
@@ -36,6 +38,16 @@ pub struct ScanDisplay {
     /// When the scan started (e.g., "2h ago")
     #[tabled(rename = "STARTED")]
     pub started: String,
+
+    /// Scan tags as `name=value` (shown with `--columns tags` or `--wide`)
+    #[tabled(skip)]
+    pub tags: Vec<String>,
+
+    /// `--highlight-threshold` rules the scan exceeds, e.g. "high 3 (max 1)"
+    /// (absent without the flag)
+    #[tabled(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exceeds: Option<Vec<String>>,
 }
 
 impl From<ScanResult> for ScanDisplay {
@@ -68,6 +80,11 @@ impl From<ScanResult> for ScanDisplay {
         // Format findings from alert_stats
         let findings = format_findings(&result);
 
+        let tags = visible_tags(&result.tags)
+            .into_iter()
+            .map(|t| format!("{}={}", t.name, t.value))
+            .collect();
+
         Self {
             id: scan.id.clone(),
             app: scan.application_name.clone(),
@@ -76,10 +93,42 @@ impl From<ScanResult> for ScanDisplay {
             findings,
             duration,
             started,
+            tags,
+            exceeds: None,
         }
     }
 }
 
+impl ScanDisplay {
+    /// Record the rules of `gate` the scan exceeds (`--highlight-threshold`)
+    pub fn with_gate(mut self, gate: &GateResult) -> Self {
+        self.exceeds = Some(
+            gate.rules
+                .iter()
+                .filter(|rule| !rule.passed)
+                .map(|rule| format!("{} {} (max {})", rule.severity, rule.found, rule.threshold))
+                .collect(),
+        );
+        self
+    }
+
+    /// Whether the scan exceeds a `--highlight-threshold` rule
+    pub fn is_highlighted(&self) -> bool {
+        self.exceeds.as_ref().is_some_and(|rules| !rules.is_empty())
+    }
+}
+
+/// Tags worth showing: first occurrence of each name, skipping empty values
+/// and unexpanded env vars like `${RELEASE_TAG}`
+pub fn visible_tags(tags: &[ScanTag]) -> Vec<&ScanTag> {
+    let mut seen = std::collections::HashSet::new();
+    tags.iter()
+        .filter(|tag| seen.insert(&tag.name))
+        .filter(|tag| !(tag.value.contains("${") && tag.value.contains('}')))
+        .filter(|tag| !tag.value.is_empty())
+        .collect()
+}
+
 impl From<&ScanResult> for ScanDisplay {
     fn from(result: &ScanResult) -> Self {
         ScanDisplay::from(result.clone())
@@ -91,7 +140,7 @@ impl From<&ScanResult> for ScanDisplay {
 /// Note: Replaced by the inline formatting in `show_pretty_overview()`, but kept
 /// for backwards compatibility and potential future use.
 #[allow(dead_code)]
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct ScanOverview {
     pub scan: ScanResult,
 }
@@ -386,6 +435,32 @@ fn count_findings_by_severity(stats: &AlertStats) -> (u32, u32, u32, u32, u32, u
     )
 }
 
+/// How a `scan list --watch` row differs from the previous refresh
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowChange {
+    /// Same status and findings as before
+    Unchanged,
+    /// Scan wasn't in the previous refresh
+    New,
+    /// Status or findings changed
+    Changed,
+}
+
+/// Compare each row of `current` with the row for the same scan in `previous`
+///
+/// Durations and relative start times move on every refresh, so only the
+/// status and findings count as changes.
+pub fn diff_rows(previous: &[ScanDisplay], current: &[ScanDisplay]) -> Vec<RowChange> {
+    current
+        .iter()
+        .map(|row| match previous.iter().find(|p| p.id == row.id) {
+            None => RowChange::New,
+            Some(p) if p.status != row.status || p.findings != row.findings => RowChange::Changed,
+            Some(_) => RowChange::Unchanged,
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -425,6 +500,24 @@ mod tests {
         assert_eq!(display.findings, "--");
     }
 
+    #[test]
+    fn test_visible_tags_dedupes_and_skips_unset() {
+        let tag = |name: &str, value: &str| ScanTag {
+            name: name.to_string(),
+            value: value.to_string(),
+        };
+        let tags = vec![
+            tag("branch", "main"),
+            tag("branch", "develop"),
+            tag("release", "${RELEASE_TAG}"),
+            tag("commit", ""),
+            tag("team", "core"),
+        ];
+
+        let visible: Vec<_> = visible_tags(&tags).iter().map(|t| &t.value).collect();
+        assert_eq!(visible, vec!["main", "core"]);
+    }
+
     #[test]
     fn test_scan_display_with_findings() {
         let mut high_severity = HashMap::new();
@@ -572,4 +665,63 @@ mod tests {
         assert!(findings.contains("2")); // 2 new
         assert!(findings.contains("1")); // 1 triaged
     }
+
+    #[test]
+    fn test_diff_rows() {
+        let row = |id: &str, status: &str, findings: &str| ScanDisplay {
+            id: id.to_string(),
+            app: "app".to_string(),
+            env: "dev".to_string(),
+            status: status.to_string(),
+            findings: findings.to_string(),
+            duration: "1m".to_string(),
+            started: "just now".to_string(),
+            tags: vec![],
+            exceeds: None,
+        };
+        let previous = vec![row("a", "Running", "--"), row("b", "Complete", "1H")];
+        let mut moved = row("b", "Complete", "1H");
+        moved.started = "5m ago".to_string();
+        let current = vec![row("c", "Running", "--"), row("a", "Complete", "2H"), moved];
+
+        assert_eq!(
+            diff_rows(&previous, &current),
+            vec![RowChange::New, RowChange::Changed, RowChange::Unchanged]
+        );
+    }
+
+    #[test]
+    fn test_with_gate_marks_exceeded_thresholds() {
+        let result: ScanResult = serde_json::from_value(serde_json::json!({
+            "scan": {
+                "id": "scan-3", "applicationId": "app-3", "applicationName": "App",
+                "env": "Prod", "status": "COMPLETED", "timestamp": "1703721600000",
+                "version": "5.0.0"
+            },
+            "alertStats": {
+                "totalAlerts": 4, "uniqueAlerts": 4,
+                "alertStatusStats": [
+                    {"alertStatus": "UNKNOWN", "totalCount": 3,
+                     "severityStats": {"High": 1, "Medium": 2}},
+                    {"alertStatus": "PROMOTED", "totalCount": 1,
+                     "severityStats": {"High": 1}}
+                ]
+            }
+        }))
+        .unwrap();
+
+        let gate = |rules: &[&str]| {
+            let thresholds: Vec<_> = rules.iter().map(|r| r.parse().unwrap()).collect();
+            GateResult::evaluate(&result, &thresholds, false)
+        };
+        let row = ScanDisplay::from(result.clone()).with_gate(&gate(&["high:0", "medium:5"]));
+        assert_eq!(row.exceeds, Some(vec!["high 1 (max 0)".to_string()]));
+        assert!(row.is_highlighted());
+
+        // Triaged findings are not counted, as with `scan gate`
+        let row = ScanDisplay::from(result.clone()).with_gate(&gate(&["high:1"]));
+        assert_eq!(row.exceeds, Some(vec![]));
+        assert!(!row.is_highlighted());
+        assert!(!ScanDisplay::from(result).is_highlighted());
+    }
 }
@@ -0,0 +1,412 @@
+//! Scan comparison models for `scan diff`
+//!
+//! Findings from two scans are matched by plugin ID + HTTP method + URI and
+//! classified as new (only in the head scan), fixed (only in the base scan),
+//! or persistent (present in both).
+
+use std::collections::{BTreeMap, HashSet};
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use tabled::Tabled;
+
+use super::common::{or_dash, truncate_string};
+use crate::client::models::{AlertResponse, ScanResult, severity_rank};
+
+/// A single path-level finding from one scan, flattened for comparison
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct DiffFinding {
+    /// Plugin ID that detected this finding
+    pub plugin_id: String,
+
+    /// Plugin/vulnerability name
+    pub plugin_name: String,
+
+    /// Severity level (High, Medium, Low)
+    pub severity: String,
+
+    /// HTTP method (GET, POST, etc.)
+    pub method: String,
+
+    /// Affected URI path
+    pub uri: String,
+
+    /// Triage status in the scan this finding was taken from
+    pub status: String,
+}
+
+impl DiffFinding {
+    /// Flatten an alert-with-paths response into per-path findings
+    pub fn from_alert_response(response: &AlertResponse) -> Vec<Self> {
+        response
+            .application_scan_alert_uris
+            .iter()
+            .map(|uri| Self {
+                plugin_id: response.alert.plugin_id.clone(),
+                plugin_name: response.alert.name.clone(),
+                severity: response.alert.severity.clone(),
+                method: uri.request_method.clone(),
+                uri: uri.uri.clone(),
+                status: uri.status.clone(),
+            })
+            .collect()
+    }
+
+    /// Identity used to match findings across scans (plugin + method + URI)
+    fn key(&self) -> (String, String, String) {
+        (
+            self.plugin_id.clone(),
+            self.method.to_uppercase(),
+            self.uri.clone(),
+        )
+    }
+}
+
+/// Identifying details of a scan on one side of the comparison
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct DiffScanInfo {
+    /// Scan ID
+    pub id: String,
+
+    /// Application name
+    pub application_name: String,
+
+    /// Environment name
+    pub environment: String,
+
+    /// Scan start time (Unix epoch milliseconds)
+    pub timestamp: String,
+}
+
+impl From<&ScanResult> for DiffScanInfo {
+    fn from(result: &ScanResult) -> Self {
+        Self {
+            id: result.scan.id.clone(),
+            application_name: result.scan.application_name.clone(),
+            environment: result.scan.env.clone(),
+            timestamp: result.scan.timestamp.clone(),
+        }
+    }
+}
+
+/// Finding counts per change category
+#[derive(Debug, Clone, Default, Serialize, JsonSchema)]
+pub struct DiffSummary {
+    /// Findings only in the head scan
+    pub new: usize,
+
+    /// Findings only in the base scan
+    pub fixed: usize,
+
+    /// Findings present in both scans
+    pub persistent: usize,
+}
+
+/// Result of comparing two scans
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ScanDiff {
+    /// Older scan (comparison baseline)
+    pub base: DiffScanInfo,
+
+    /// Newer scan
+    pub head: DiffScanInfo,
+
+    /// Counts per change category
+    pub summary: DiffSummary,
+
+    /// Findings introduced in the head scan
+    pub new: Vec<DiffFinding>,
+
+    /// Findings no longer present in the head scan
+    pub fixed: Vec<DiffFinding>,
+
+    /// Findings present in both scans (triage status from the head scan)
+    pub persistent: Vec<DiffFinding>,
+}
+
+impl ScanDiff {
+    /// Compare base and head findings.
+    ///
+    /// Duplicate keys within a single scan are collapsed. Each output list is
+    /// sorted by severity (High first), then plugin ID, then URI.
+    pub fn compute(
+        base: DiffScanInfo,
+        head: DiffScanInfo,
+        base_findings: Vec<DiffFinding>,
+        head_findings: Vec<DiffFinding>,
+    ) -> Self {
+        let base_map: BTreeMap<_, _> = base_findings.into_iter().map(|f| (f.key(), f)).collect();
+        let head_map: BTreeMap<_, _> = head_findings.into_iter().map(|f| (f.key(), f)).collect();
+
+        let base_keys: HashSet<_> = base_map.keys().cloned().collect();
+
+        let mut new = Vec::new();
+        let mut persistent = Vec::new();
+        for (key, finding) in &head_map {
+            if base_keys.contains(key) {
+                persistent.push(finding.clone());
+            } else {
+                new.push(finding.clone());
+            }
+        }
+
+        let mut fixed: Vec<DiffFinding> = base_map
+            .into_iter()
+            .filter(|(key, _)| !head_map.contains_key(key))
+            .map(|(_, finding)| finding)
+            .collect();
+
+        for list in [&mut new, &mut fixed, &mut persistent] {
+            list.sort_by(|a, b| {
+                severity_rank(&a.severity)
+                    .cmp(&severity_rank(&b.severity))
+                    .then_with(|| a.plugin_id.cmp(&b.plugin_id))
+                    .then_with(|| a.uri.cmp(&b.uri))
+            });
+        }
+
+        Self {
+            base,
+            head,
+            summary: DiffSummary {
+                new: new.len(),
+                fixed: fixed.len(),
+                persistent: persistent.len(),
+            },
+            new,
+            fixed,
+            persistent,
+        }
+    }
+
+    /// Table rows: new first, then fixed, then persistent
+    pub fn rows(&self) -> Vec<ScanDiffRow> {
+        let tagged = [
+            ("NEW", &self.new),
+            ("FIXED", &self.fixed),
+            ("PERSISTENT", &self.persistent),
+        ];
+        tagged
+            .into_iter()
+            .flat_map(|(change, list)| list.iter().map(move |f| ScanDiffRow::new(change, f)))
+            .collect()
+    }
+
+    /// Render the comparison as a Markdown report (for PR comments, release notes)
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+
+        out.push_str("## Scan comparison\n\n");
+        out.push_str(&format!(
+            "- **Base:** `{}` ({} / {})\n",
+            self.base.id,
+            or_dash(&self.base.application_name),
+            or_dash(&self.base.environment)
+        ));
+        out.push_str(&format!(
+            "- **Head:** `{}` ({} / {})\n\n",
+            self.head.id,
+            or_dash(&self.head.application_name),
+            or_dash(&self.head.environment)
+        ));
+
+        out.push_str("| New | Fixed | Persistent |\n");
+        out.push_str("|----:|------:|-----------:|\n");
+        out.push_str(&format!(
+            "| {} | {} | {} |\n",
+            self.summary.new, self.summary.fixed, self.summary.persistent
+        ));
+
+        for (title, list) in [
+            ("New findings", &self.new),
+            ("Fixed findings", &self.fixed),
+            ("Persistent findings", &self.persistent),
+        ] {
+            out.push_str(&format!("\n### {} ({})\n\n", title, list.len()));
+            if list.is_empty() {
+                out.push_str("_None._\n");
+                continue;
+            }
+            out.push_str("| Severity | Plugin | Name | Method | Path |\n");
+            out.push_str("|----------|--------|------|--------|------|\n");
+            for f in list {
+                out.push_str(&format!(
+                    "| {} | {} | {} | {} | `{}` |\n",
+                    f.severity,
+                    f.plugin_id,
+                    escape_markdown_cell(&f.plugin_name),
+                    f.method,
+                    escape_markdown_cell(&f.uri)
+                ));
+            }
+        }
+
+        out
+    }
+}
+
+/// Table row for `scan diff` output
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct ScanDiffRow {
+    /// Change category (NEW, FIXED, PERSISTENT)
+    #[tabled(rename = "CHANGE")]
+    pub change: String,
+
+    /// Severity level
+    #[tabled(rename = "SEVERITY")]
+    pub severity: String,
+
+    /// Plugin ID
+    #[tabled(rename = "PLUGIN")]
+    pub plugin_id: String,
+
+    /// Plugin/vulnerability name
+    #[tabled(rename = "NAME")]
+    pub name: String,
+
+    /// HTTP method
+    #[tabled(rename = "METHOD")]
+    pub method: String,
+
+    /// Affected URI path
+    #[tabled(rename = "PATH")]
+    pub path: String,
+}
+
+impl ScanDiffRow {
+    fn new(change: &str, finding: &DiffFinding) -> Self {
+        Self {
+            change: change.to_string(),
+            severity: finding.severity.clone(),
+            plugin_id: finding.plugin_id.clone(),
+            name: truncate_string(&finding.plugin_name, 30),
+            method: finding.method.clone(),
+            path: truncate_string(&finding.uri, 50),
+        }
+    }
+}
+
+/// Escape pipe characters so values don't break Markdown table cells
+fn escape_markdown_cell(s: &str) -> String {
+    s.replace('|', "\\|")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finding(plugin: &str, severity: &str, method: &str, uri: &str) -> DiffFinding {
+        DiffFinding {
+            plugin_id: plugin.to_string(),
+            plugin_name: format!("Plugin {}", plugin),
+            severity: severity.to_string(),
+            method: method.to_string(),
+            uri: uri.to_string(),
+            status: "UNKNOWN".to_string(),
+        }
+    }
+
+    fn info(id: &str) -> DiffScanInfo {
+        DiffScanInfo {
+            id: id.to_string(),
+            application_name: "app".to_string(),
+            environment: "dev".to_string(),
+            timestamp: "0".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_compute_classifies_findings() {
+        let base = vec![
+            finding("40012", "High", "GET", "/search"),
+            finding("10020", "Low", "GET", "/"),
+        ];
+        let head = vec![
+            finding("40012", "High", "GET", "/search"),
+            finding("90022", "Medium", "POST", "/login"),
+        ];
+
+        let diff = ScanDiff::compute(info("a"), info("b"), base, head);
+
+        assert_eq!(diff.summary.new, 1);
+        assert_eq!(diff.summary.fixed, 1);
+        assert_eq!(diff.summary.persistent, 1);
+        assert_eq!(diff.new[0].plugin_id, "90022");
+        assert_eq!(diff.fixed[0].plugin_id, "10020");
+        assert_eq!(diff.persistent[0].plugin_id, "40012");
+    }
+
+    #[test]
+    fn test_compute_matches_method_case_insensitively() {
+        let base = vec![finding("40012", "High", "get", "/search")];
+        let head = vec![finding("40012", "High", "GET", "/search")];
+
+        let diff = ScanDiff::compute(info("a"), info("b"), base, head);
+
+        assert_eq!(diff.summary.persistent, 1);
+        assert_eq!(diff.summary.new, 0);
+        assert_eq!(diff.summary.fixed, 0);
+    }
+
+    #[test]
+    fn test_compute_distinguishes_method() {
+        let base = vec![finding("40012", "High", "GET", "/search")];
+        let head = vec![finding("40012", "High", "POST", "/search")];
+
+        let diff = ScanDiff::compute(info("a"), info("b"), base, head);
+
+        assert_eq!(diff.summary.new, 1);
+        assert_eq!(diff.summary.fixed, 1);
+        assert_eq!(diff.summary.persistent, 0);
+    }
+
+    #[test]
+    fn test_compute_sorts_by_severity() {
+        let head = vec![
+            finding("1", "Low", "GET", "/a"),
+            finding("2", "High", "GET", "/b"),
+            finding("3", "Medium", "GET", "/c"),
+        ];
+
+        let diff = ScanDiff::compute(info("a"), info("b"), vec![], head);
+
+        let severities: Vec<_> = diff.new.iter().map(|f| f.severity.as_str()).collect();
+        assert_eq!(severities, vec!["High", "Medium", "Low"]);
+    }
+
+    #[test]
+    fn test_rows_ordered_by_change() {
+        let base = vec![
+            finding("1", "High", "GET", "/kept"),
+            finding("2", "High", "GET", "/gone"),
+        ];
+        let head = vec![
+            finding("1", "High", "GET", "/kept"),
+            finding("3", "Low", "GET", "/added"),
+        ];
+
+        let diff = ScanDiff::compute(info("a"), info("b"), base, head);
+        let changes: Vec<_> = diff.rows().into_iter().map(|r| r.change).collect();
+
+        assert_eq!(changes, vec!["NEW", "FIXED", "PERSISTENT"]);
+    }
+
+    #[test]
+    fn test_to_markdown_sections() {
+        let diff = ScanDiff::compute(
+            info("scan-a"),
+            info("scan-b"),
+            vec![],
+            vec![finding("40012", "High", "GET", "/a|b")],
+        );
+
+        let md = diff.to_markdown();
+
+        assert!(md.contains("## Scan comparison"));
+        assert!(md.contains("`scan-a`"));
+        assert!(md.contains("### New findings (1)"));
+        assert!(md.contains("### Fixed findings (0)"));
+        assert!(md.contains("_None._"));
+        assert!(md.contains("/a\\|b"));
+    }
+}
@@ -8,13 +8,17 @@
 
 use std::collections::HashMap;
 
+use schemars::JsonSchema;
 use serde::Serialize;
 
+use crate::output::warnings::Warning;
+use crate::owasp::Category;
+
 /// Top-level output document for `scan get --detail full`
 ///
 /// Contains everything an AI agent needs to understand and fix vulnerabilities
 /// from a single scan. Designed to be consumed as a single JSON document.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct ScanFullDetail {
     /// Schema version for forward-compatible parsing
     pub schema_version: String,
@@ -33,7 +37,7 @@ pub struct ScanFullDetail {
 }
 
 /// Scan metadata extracted from the scan result
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct ScanInfo {
     /// Scan UUID
     pub id: String,
@@ -79,7 +83,7 @@ pub struct ScanInfo {
 }
 
 /// Aggregate summary counts for the scan
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct FindingsSummary {
     /// Total number of findings (paths) across all plugins
     pub total_findings: usize,
@@ -96,7 +100,7 @@ pub struct FindingsSummary {
 }
 
 /// Finding counts per severity level
-#[derive(Debug, Clone, Default, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, JsonSchema)]
 pub struct SeverityCounts {
     pub high: usize,
     pub medium: usize,
@@ -106,7 +110,7 @@ pub struct SeverityCounts {
 }
 
 /// Finding counts per triage status
-#[derive(Debug, Clone, Default, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, JsonSchema)]
 pub struct StatusCounts {
     /// New/untriaged findings (UNKNOWN status)
     pub new: usize,
@@ -122,7 +126,7 @@ pub struct StatusCounts {
 }
 
 /// Full detail for a single finding/plugin type
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct FindingFull {
     /// Scanner plugin ID (e.g., "40012")
     pub plugin_id: String,
@@ -137,6 +141,10 @@ pub struct FindingFull {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cwe_id: Option<String>,
 
+    /// OWASP Top 10 (2021) category mapped from the CWE
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owasp: Option<Category>,
+
     /// Detailed description (markdown)
     pub description: String,
 
@@ -168,7 +176,7 @@ pub struct FindingFull {
 }
 
 /// Full detail for a single affected path/endpoint
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct PathFull {
     /// URI ID (for future triage operations)
     pub uri_id: String,
@@ -224,7 +232,7 @@ pub struct PathFull {
 }
 
 /// HTTP request or response message
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct HttpMessage {
     /// HTTP headers
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -240,7 +248,7 @@ pub struct HttpMessage {
 }
 
 /// Output metadata for diagnostics and tooling
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct OutputMeta {
     /// ISO 8601 timestamp of when this output was generated
     pub generated_at: String,
@@ -261,6 +269,10 @@ pub struct OutputMeta {
     /// Whether any response bodies were truncated (due to --max-body-size)
     #[serde(skip_serializing_if = "std::ops::Not::not")]
     pub bodies_truncated: bool,
+
+    /// Warnings raised while assembling this output
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<Warning>,
 }
 
 /// Helper for skip_serializing_if on usize fields
@@ -311,6 +323,7 @@ mod tests {
                 plugin_name: "Cross Site Scripting (Reflected)".to_string(),
                 severity: "High".to_string(),
                 cwe_id: Some("79".to_string()),
+                owasp: crate::owasp::for_cwe(Some("79")),
                 description: "Reflected XSS vulnerability".to_string(),
                 category: Some("Injection".to_string()),
                 references: vec!["https://owasp.org/xss".to_string()],
@@ -350,6 +363,7 @@ mod tests {
                 fetch_duration_ms: 2340,
                 findings_omitted: None,
                 bodies_truncated: false,
+                warnings: Vec::new(),
             },
         };
 
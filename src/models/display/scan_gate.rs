@@ -0,0 +1,376 @@
+//! Findings threshold evaluation for `scan gate`
+
+use std::collections::HashMap;
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::cli::FindingsThreshold;
+use crate::client::models::{AlertResponse, ApplicationAlert, ScanResult};
+use crate::output::junit::{Failure, TestCase, TestSuite};
+
+/// Alert statuses counted by default (new, untriaged findings)
+const NEW_STATUSES: &[&str] = &["UNKNOWN"];
+
+/// Alert statuses counted with `--include-triaged` (adds assigned findings)
+const OPEN_STATUSES: &[&str] = &["UNKNOWN", "PROMOTED"];
+
+/// Outcome of one `--fail-on` rule
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct GateRule {
+    /// Severity the rule applies to
+    #[tabled(rename = "SEVERITY")]
+    pub severity: String,
+
+    /// Most findings allowed
+    #[tabled(rename = "MAX")]
+    pub threshold: u32,
+
+    /// Findings counted in the scan
+    #[tabled(rename = "FOUND")]
+    pub found: u32,
+
+    /// Findings left out by local suppressions
+    #[tabled(skip)]
+    pub suppressed: u32,
+
+    /// "pass" or "FAIL"
+    #[tabled(rename = "RESULT")]
+    #[serde(skip)]
+    pub result: &'static str,
+
+    /// Whether the rule passed
+    #[tabled(skip)]
+    pub passed: bool,
+}
+
+/// Result of evaluating a scan against findings thresholds
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct GateResult {
+    pub scan_id: String,
+    pub app: String,
+    pub env: String,
+    /// True when every rule passed
+    pub passed: bool,
+    /// Which findings were counted: "new" or "new+triaged"
+    pub counted: &'static str,
+    pub rules: Vec<GateRule>,
+    /// Findings that would have been counted but are locally suppressed
+    pub suppressed: u32,
+    /// Suppressed findings per plugin ID
+    #[serde(skip)]
+    suppressed_by_plugin: HashMap<String, u32>,
+}
+
+impl GateResult {
+    /// Evaluate a scan's alert stats against `thresholds`
+    ///
+    /// Risk-accepted and false-positive findings are never counted.
+    pub fn evaluate(
+        result: &ScanResult,
+        thresholds: &[FindingsThreshold],
+        include_triaged: bool,
+    ) -> Self {
+        let statuses = if include_triaged {
+            OPEN_STATUSES
+        } else {
+            NEW_STATUSES
+        };
+
+        let count = |severity: &str| -> u32 {
+            result.alert_stats.as_ref().map_or(0, |stats| {
+                stats
+                    .alert_status_stats
+                    .iter()
+                    .filter(|s| statuses.contains(&s.alert_status.as_str()))
+                    .filter_map(|s| s.severity_stats.get(severity))
+                    .sum()
+            })
+        };
+
+        let rules: Vec<GateRule> = thresholds
+            .iter()
+            .map(|t| {
+                let found = count(t.severity.api_name());
+                let passed = found <= t.count;
+                GateRule {
+                    severity: t.severity.to_string(),
+                    threshold: t.count,
+                    found,
+                    suppressed: 0,
+                    result: if passed { "pass" } else { "FAIL" },
+                    passed,
+                }
+            })
+            .collect();
+
+        Self {
+            scan_id: result.scan.id.clone(),
+            app: result.scan.application_name.clone(),
+            env: result.scan.env.clone(),
+            passed: rules.iter().all(|r| r.passed),
+            counted: if include_triaged {
+                "new+triaged"
+            } else {
+                "new"
+            },
+            rules,
+            suppressed: 0,
+            suppressed_by_plugin: HashMap::new(),
+        }
+    }
+
+    /// Stop counting findings that `is_suppressed(plugin_id, uri)` accepts
+    ///
+    /// `alerts` are the scan's alerts with their paths. Each counted path a
+    /// suppression covers comes off its severity's count, and the rules are
+    /// evaluated again.
+    pub fn suppress(
+        &mut self,
+        alerts: &[AlertResponse],
+        is_suppressed: impl Fn(&str, &str) -> bool,
+    ) {
+        let statuses = self.statuses();
+        let mut by_severity: HashMap<String, u32> = HashMap::new();
+        for response in alerts {
+            let plugin = &response.alert.plugin_id;
+            let suppressed = response
+                .application_scan_alert_uris
+                .iter()
+                .filter(|uri| statuses.contains(&uri.status.as_str()))
+                .filter(|uri| is_suppressed(plugin, &uri.uri))
+                .count() as u32;
+            if suppressed > 0 {
+                *by_severity
+                    .entry(response.alert.severity.to_lowercase())
+                    .or_insert(0) += suppressed;
+                *self.suppressed_by_plugin.entry(plugin.clone()).or_insert(0) += suppressed;
+                self.suppressed += suppressed;
+            }
+        }
+
+        for rule in &mut self.rules {
+            let suppressed = by_severity.get(&rule.severity).copied().unwrap_or(0);
+            rule.found = rule.found.saturating_sub(suppressed);
+            rule.suppressed += suppressed;
+            rule.passed = rule.found <= rule.threshold;
+            rule.result = if rule.passed { "pass" } else { "FAIL" };
+        }
+        self.passed = self.rules.iter().all(|r| r.passed);
+    }
+
+    /// Alert statuses this gate counts
+    fn statuses(&self) -> &'static [&'static str] {
+        if self.counted == "new" {
+            NEW_STATUSES
+        } else {
+            OPEN_STATUSES
+        }
+    }
+
+    /// JUnit suites: one test case per rule, and one per plugin
+    ///
+    /// A plugin fails when it has counted findings at a severity whose rule
+    /// failed, so CI points at the vulnerabilities that broke the gate.
+    pub fn junit_suites(&self, alerts: &[ApplicationAlert]) -> Vec<TestSuite> {
+        let statuses = self.statuses();
+        let classname = format!("{}.{}", self.app, self.env);
+
+        let rules = self
+            .rules
+            .iter()
+            .map(|rule| TestCase {
+                classname: classname.clone(),
+                name: format!("{} findings at most {}", rule.severity, rule.threshold),
+                failure: (!rule.passed).then(|| Failure {
+                    message: format!(
+                        "{} {} findings (max {})",
+                        rule.found, rule.severity, rule.threshold
+                    ),
+                    text: format!("Scan {} counted {} findings", self.scan_id, self.counted),
+                }),
+            })
+            .collect();
+
+        let failed: Vec<&str> = self
+            .rules
+            .iter()
+            .filter(|r| !r.passed)
+            .map(|r| r.severity.as_str())
+            .collect();
+        let plugins = alerts
+            .iter()
+            .map(|alert| {
+                let found: u32 = alert
+                    .alert_status_stats
+                    .iter()
+                    .filter(|s| statuses.contains(&s.alert_status.as_str()))
+                    .flat_map(|s| s.severity_stats.values())
+                    .sum::<u32>()
+                    .saturating_sub(
+                        self.suppressed_by_plugin
+                            .get(&alert.plugin_id)
+                            .copied()
+                            .unwrap_or(0),
+                    );
+                let breaks_gate =
+                    found > 0 && failed.contains(&alert.severity.to_lowercase().as_str());
+                TestCase {
+                    classname: classname.clone(),
+                    name: format!("[{}] {}", alert.plugin_id, alert.name),
+                    failure: breaks_gate.then(|| Failure {
+                        message: format!(
+                            "{} {} {} finding(s)",
+                            found,
+                            self.counted,
+                            alert.severity.to_lowercase()
+                        ),
+                        text: format!(
+                            "hawkop scan get {} --plugin-id {}",
+                            self.scan_id, alert.plugin_id
+                        ),
+                    }),
+                }
+            })
+            .collect();
+
+        vec![
+            TestSuite {
+                name: "StackHawk thresholds".to_string(),
+                cases: rules,
+            },
+            TestSuite {
+                name: "StackHawk findings".to_string(),
+                cases: plugins,
+            },
+        ]
+    }
+
+    /// Human-readable list of failed rules (e.g. "high 3 (max 1)")
+    pub fn failure_summary(&self) -> String {
+        self.rules
+            .iter()
+            .filter(|r| !r.passed)
+            .map(|r| format!("{} {} (max {})", r.severity, r.found, r.threshold))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::fixtures::ScanResultBuilder;
+
+    /// A scan with (triage status, high, medium) finding counts
+    fn scan(stats: &[(&str, u32, u32)]) -> ScanResult {
+        stats
+            .iter()
+            .fold(
+                ScanResultBuilder::new("s1", "a1"),
+                |builder, &(status, high, medium)| {
+                    builder.with_status_findings(status, high, medium, 0)
+                },
+            )
+            .build()
+    }
+
+    fn thresholds(spec: &str) -> Vec<FindingsThreshold> {
+        spec.split(',').map(|s| s.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_gate_counts_new_findings_only_by_default() {
+        let result = scan(&[
+            ("UNKNOWN", 0, 4),
+            ("PROMOTED", 2, 0),
+            ("RISK_ACCEPTED", 5, 5),
+        ]);
+
+        let gate = GateResult::evaluate(&result, &thresholds("high:1,medium:10"), false);
+        assert!(gate.passed);
+        assert_eq!(gate.rules[0].found, 0);
+        assert_eq!(gate.rules[1].found, 4);
+    }
+
+    #[test]
+    fn test_gate_fails_when_count_exceeds_threshold() {
+        let result = scan(&[("UNKNOWN", 1, 0), ("PROMOTED", 2, 0)]);
+
+        let gate = GateResult::evaluate(&result, &thresholds("high:2"), true);
+        assert!(!gate.passed);
+        assert_eq!(gate.counted, "new+triaged");
+        assert_eq!(gate.failure_summary(), "high 3 (max 2)");
+
+        let gate = GateResult::evaluate(&result, &thresholds("high:3"), true);
+        assert!(gate.passed);
+        let gate = GateResult::evaluate(&result, &thresholds("high:2"), false);
+        assert!(gate.passed);
+    }
+
+    #[test]
+    fn test_junit_suites_fail_plugins_behind_failed_rules() {
+        let result = scan(&[("UNKNOWN", 1, 2)]);
+        let gate = GateResult::evaluate(&result, &thresholds("high:0,medium:10"), false);
+        let alerts: Vec<ApplicationAlert> = serde_json::from_value(serde_json::json!([
+            {"pluginId": "40012", "name": "XSS", "severity": "High",
+             "alertStatusStats": [{"alertStatus": "UNKNOWN", "severityStats": {"High": 1}}]},
+            {"pluginId": "10020", "name": "Frame Options", "severity": "Medium",
+             "alertStatusStats": [{"alertStatus": "UNKNOWN", "severityStats": {"Medium": 2}}]}
+        ]))
+        .unwrap();
+
+        let suites = gate.junit_suites(&alerts);
+
+        assert_eq!(suites[0].cases[0].name, "high findings at most 0");
+        assert!(suites[0].cases[0].failure.is_some());
+        assert!(suites[0].cases[1].failure.is_none());
+        assert_eq!(suites[1].cases[0].name, "[40012] XSS");
+        assert_eq!(
+            suites[1].cases[0].failure.as_ref().unwrap().message,
+            "1 new high finding(s)"
+        );
+        assert!(suites[1].cases[1].failure.is_none());
+    }
+
+    #[test]
+    fn test_suppressed_findings_are_not_counted() {
+        let result = scan(&[("UNKNOWN", 2, 0)]);
+        let mut gate = GateResult::evaluate(&result, &thresholds("high:0"), false);
+        assert!(!gate.passed);
+
+        let path = |uri: &str, status: &str| {
+            serde_json::json!({"alertUriId": uri, "uri": uri, "requestMethod": "GET",
+                               "msgId": "1", "pluginId": "40012", "status": status})
+        };
+        let responses: Vec<AlertResponse> = serde_json::from_value(serde_json::json!([{
+            "alert": {"pluginId": "40012", "name": "XSS", "severity": "High",
+                      "alertStatusStats": [{"alertStatus": "UNKNOWN", "severityStats": {"High": 2}}]},
+            "applicationScanAlertUris": [
+                path("/legacy/a", "UNKNOWN"),
+                path("/search", "UNKNOWN"),
+                path("/legacy/b", "RISK_ACCEPTED")
+            ]
+        }]))
+        .unwrap();
+
+        gate.suppress(&responses, |plugin, uri| {
+            plugin == "40012" && uri.starts_with("/legacy/")
+        });
+        assert_eq!(gate.suppressed, 1);
+        assert_eq!(gate.rules[0].found, 1);
+        assert_eq!(gate.rules[0].suppressed, 1);
+        assert!(!gate.passed);
+        let suites = gate.junit_suites(std::slice::from_ref(&responses[0].alert));
+        assert_eq!(
+            suites[1].cases[0].failure.as_ref().unwrap().message,
+            "1 new high finding(s)"
+        );
+
+        gate.suppress(&responses, |_, uri| uri == "/search");
+        assert_eq!(gate.rules[0].found, 0);
+        assert!(gate.passed);
+        assert_eq!(gate.rules[0].result, "pass");
+    }
+}
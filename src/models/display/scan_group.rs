@@ -0,0 +1,237 @@
+//! Per-application rollup for `scan list --group-by app`
+
+use std::collections::BTreeSet;
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::client::models::ScanResult;
+
+use super::app_findings::open_counts;
+use super::common::timestamp_ms;
+use super::scan::{ScanDisplay, format_relative_time, format_status};
+
+/// Scans of one application within the listed window
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct AppScanGroup {
+    /// Application name
+    #[tabled(rename = "APP")]
+    pub app: String,
+
+    /// Environments scanned, comma-separated
+    #[tabled(rename = "ENVS")]
+    pub envs: String,
+
+    /// Scans in the window
+    #[tabled(rename = "SCANS")]
+    pub scan_count: usize,
+
+    /// Status of the most recent scan
+    #[tabled(rename = "LAST SCAN")]
+    pub last_scan_status: String,
+
+    /// When the most recent scan started (e.g., "2h ago")
+    #[tabled(rename = "STARTED")]
+    pub started: String,
+
+    /// Open high findings, summed over each environment's latest completed scan
+    #[tabled(rename = "HIGH")]
+    pub high: u32,
+
+    /// Open medium findings, summed over each environment's latest completed scan
+    #[tabled(rename = "MEDIUM")]
+    pub medium: u32,
+
+    /// Open low findings, summed over each environment's latest completed scan
+    #[tabled(rename = "LOW")]
+    pub low: u32,
+
+    /// Application ID
+    #[tabled(skip)]
+    pub app_id: String,
+
+    /// Most recent scan ID
+    #[tabled(skip)]
+    pub last_scan_id: String,
+
+    /// The application's scans, newest first (with `--expand`)
+    #[tabled(skip)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub scans: Vec<ScanDisplay>,
+}
+
+impl AppScanGroup {
+    /// Group scans (any order) by application, most recently scanned first
+    ///
+    /// With `expand`, each group keeps its scans for nested display.
+    pub fn group(scans: Vec<ScanResult>, expand: bool) -> Vec<Self> {
+        let mut sorted = scans;
+        sorted.sort_by_key(|s| std::cmp::Reverse(timestamp_ms(s)));
+
+        let mut apps: Vec<(String, Vec<ScanResult>)> = Vec::new();
+        for scan in sorted {
+            match apps
+                .iter_mut()
+                .find(|(id, _)| *id == scan.scan.application_id)
+            {
+                Some((_, scans)) => scans.push(scan),
+                None => apps.push((scan.scan.application_id.clone(), vec![scan])),
+            }
+        }
+
+        apps.into_iter()
+            .map(|(app_id, scans)| Self::from_scans(app_id, scans, expand))
+            .collect()
+    }
+
+    /// Roll up one application's scans, newest first
+    fn from_scans(app_id: String, scans: Vec<ScanResult>, expand: bool) -> Self {
+        let latest = &scans[0];
+        let envs: BTreeSet<&str> = scans.iter().map(|s| s.scan.env.as_str()).collect();
+
+        // Open findings from each environment's latest completed scan
+        let (mut high, mut medium, mut low) = (0, 0, 0);
+        for env in &envs {
+            let completed = scans
+                .iter()
+                .find(|s| s.scan.env == *env && s.scan.status.eq_ignore_ascii_case("COMPLETED"));
+            if let Some(scan) = completed {
+                let (h, m, l) = open_counts(scan);
+                high += h;
+                medium += m;
+                low += l;
+            }
+        }
+
+        Self {
+            app: latest.scan.application_name.clone(),
+            envs: envs.into_iter().collect::<Vec<_>>().join(", "),
+            scan_count: scans.len(),
+            last_scan_status: format_status(&latest.scan.status),
+            started: format_relative_time(timestamp_ms(latest)),
+            high,
+            medium,
+            low,
+            app_id,
+            last_scan_id: latest.scan.id.clone(),
+            scans: if expand {
+                scans.into_iter().map(ScanDisplay::from).collect()
+            } else {
+                Vec::new()
+            },
+        }
+    }
+}
+
+/// A row of the `--expand` table: an application, or one of its scans
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct ExpandedRow {
+    #[tabled(rename = "APP / SCAN")]
+    pub name: String,
+    #[tabled(rename = "ENV")]
+    pub env: String,
+    #[tabled(rename = "SCANS")]
+    pub scans: String,
+    #[tabled(rename = "STATUS")]
+    pub status: String,
+    #[tabled(rename = "STARTED")]
+    pub started: String,
+    #[tabled(rename = "FINDINGS")]
+    pub findings: String,
+}
+
+/// Table rows for `--expand`: each application followed by its scans
+///
+/// Application rows show open findings as `H/M/L`; scan rows show the
+/// scan's own findings summary, as in `scan list`.
+pub fn expanded_rows(groups: &[AppScanGroup]) -> Vec<ExpandedRow> {
+    let mut rows = Vec::new();
+    for group in groups {
+        rows.push(ExpandedRow {
+            name: group.app.clone(),
+            env: group.envs.clone(),
+            scans: group.scan_count.to_string(),
+            status: group.last_scan_status.clone(),
+            started: group.started.clone(),
+            findings: format!("{}/{}/{}", group.high, group.medium, group.low),
+        });
+        let last = group.scans.len().saturating_sub(1);
+        for (i, scan) in group.scans.iter().enumerate() {
+            let branch = if i == last { "└─" } else { "├─" };
+            rows.push(ExpandedRow {
+                name: format!("  {} {}", branch, scan.id),
+                env: scan.env.clone(),
+                scans: String::new(),
+                status: scan.status.clone(),
+                started: scan.started.clone(),
+                findings: scan.findings.clone(),
+            });
+        }
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::fixtures::ScanResultBuilder;
+
+    fn scan(id: &str, app: &str, env: &str, timestamp: &str) -> ScanResultBuilder {
+        ScanResultBuilder::new(id, app)
+            .app_name(app.to_uppercase())
+            .env(env)
+            .timestamp(timestamp)
+    }
+
+    #[test]
+    fn test_group_rolls_up_each_app() {
+        let scans = vec![
+            scan("s1", "web", "prod", "1000")
+                .with_findings(1, 0, 0)
+                .build(),
+            scan("s2", "api", "dev", "2000")
+                .with_findings(0, 2, 0)
+                .build(),
+            scan("s3", "web", "prod", "3000")
+                .with_findings(2, 1, 0)
+                .build(),
+            scan("s4", "web", "dev", "4000")
+                .status("STARTED")
+                .with_findings(9, 9, 9)
+                .build(),
+            scan("s5", "web", "qa", "500")
+                .with_findings(0, 0, 4)
+                .build(),
+        ];
+
+        let groups = AppScanGroup::group(scans, false);
+
+        assert_eq!(groups.len(), 2);
+        let web = &groups[0];
+        assert_eq!(web.app, "WEB");
+        assert_eq!(web.envs, "dev, prod, qa");
+        assert_eq!(web.scan_count, 4);
+        assert_eq!(web.last_scan_status, "Running");
+        assert_eq!(web.last_scan_id, "s4");
+        // Latest completed scan in prod (s3) and qa (s5); dev has none
+        assert_eq!((web.high, web.medium, web.low), (2, 1, 4));
+        assert!(web.scans.is_empty());
+        assert_eq!(groups[1].app_id, "api");
+    }
+
+    #[test]
+    fn test_expanded_rows_nest_scans() {
+        let scans = vec![
+            scan("s1", "web", "prod", "1000").build(),
+            scan("s2", "web", "prod", "2000").build(),
+        ];
+
+        let rows = expanded_rows(&AppScanGroup::group(scans, true));
+
+        let names: Vec<&str> = rows.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["WEB", "  ├─ s2", "  └─ s1"]);
+        assert_eq!(rows[0].scans, "2");
+        assert_eq!(rows[0].findings, "0/0/0");
+    }
+}
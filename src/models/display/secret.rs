@@ -1,18 +1,43 @@
 //! Secret display model
 
+use schemars::JsonSchema;
 use serde::Serialize;
 use tabled::Tabled;
 
 use crate::client::models::Secret;
 
 /// Secret display model for table/JSON output.
-#[derive(Debug, Clone, Tabled, Serialize)]
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
 pub struct SecretDisplay {
     /// Secret name
     #[tabled(rename = "NAME")]
     pub name: String,
 }
 
+/// A scan configuration that references a secret, for `secret usages`
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct SecretUsageDisplay {
+    /// Scan configuration name
+    #[tabled(rename = "CONFIG")]
+    pub config: String,
+
+    /// Name of the application the config scans (its ID if not found)
+    #[tabled(rename = "APP")]
+    pub app: String,
+
+    /// ID of the application the config scans
+    #[tabled(skip)]
+    pub app_id: String,
+
+    /// Environment the config scans
+    #[tabled(rename = "ENV")]
+    pub env: String,
+
+    /// Lines with a reference, comma-separated
+    #[tabled(rename = "LINES")]
+    pub lines: String,
+}
+
 impl From<Secret> for SecretDisplay {
     fn from(secret: Secret) -> Self {
         Self { name: secret.name }
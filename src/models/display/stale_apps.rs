@@ -0,0 +1,201 @@
+//! Applications overdue for a scan, for `report stale-apps`
+//!
+//! An app is stale when its latest scan (in any environment, or only the
+//! one asked about) started before the threshold, or when it has never been
+//! scanned. Scans count whatever their status, so the last scan's status is
+//! shown alongside.
+
+use std::collections::HashMap;
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::client::models::{Application, ScanResult};
+use crate::output::formatters::format_timestamp_local;
+
+use super::common::timestamp_ms;
+
+/// Milliseconds per day
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+
+/// An application not scanned since the threshold
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct StaleApp {
+    /// Application name
+    #[tabled(rename = "APP")]
+    pub app: String,
+
+    /// Application ID
+    #[tabled(rename = "APP ID")]
+    pub app_id: String,
+
+    /// When the last scan started (local time), or "never"
+    #[tabled(rename = "LAST SCAN")]
+    #[serde(skip)]
+    pub last_scan: String,
+
+    /// Whole days since the last scan, or "--"
+    #[tabled(rename = "DAYS")]
+    #[serde(skip)]
+    pub days: String,
+
+    /// Environment and status of the last scan, e.g. "prod (Complete)"
+    #[tabled(rename = "LAST SCAN ENV")]
+    #[serde(skip)]
+    pub last_env: String,
+
+    /// Last scan start time (epoch milliseconds)
+    #[tabled(skip)]
+    pub last_scanned: Option<i64>,
+
+    /// Whole days since the last scan
+    #[tabled(skip)]
+    pub days_since_scan: Option<i64>,
+
+    /// Last scan ID
+    #[tabled(skip)]
+    pub last_scan_id: Option<String>,
+
+    /// Last scan environment
+    #[tabled(skip)]
+    pub last_scan_env: Option<String>,
+
+    /// Last scan status as the API reports it
+    #[tabled(skip)]
+    pub last_scan_status: Option<String>,
+}
+
+impl StaleApp {
+    /// Row for `app`, whose latest scan (if any) is `scan`
+    fn new(app: &Application, scan: Option<&ScanResult>, now_ms: i64) -> Self {
+        let started = scan.map(timestamp_ms);
+        let days = started.map(|ms| ((now_ms - ms) / DAY_MS).max(0));
+        Self {
+            app: app.name.clone(),
+            app_id: app.id.clone(),
+            last_scan: scan.map_or_else(
+                || "never".to_string(),
+                |s| format_timestamp_local(&s.scan.timestamp),
+            ),
+            days: days.map_or_else(|| "--".to_string(), |d| d.to_string()),
+            last_env: scan.map_or_else(
+                || "--".to_string(),
+                |s| {
+                    format!(
+                        "{} ({})",
+                        s.scan.env,
+                        super::scan::format_status(&s.scan.status)
+                    )
+                },
+            ),
+            last_scanned: started,
+            days_since_scan: days,
+            last_scan_id: scan.map(|s| s.scan.id.clone()),
+            last_scan_env: scan.map(|s| s.scan.env.clone()),
+            last_scan_status: scan.map(|s| s.scan.status.clone()),
+        }
+    }
+}
+
+/// Applications overdue for a scan
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct StaleAppsReport {
+    /// Threshold as given (e.g. `30d`)
+    pub threshold: String,
+
+    /// Only scans of this environment counted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub env: Option<String>,
+
+    /// Applications checked
+    pub apps_checked: usize,
+
+    /// Stale applications that have never been scanned
+    pub never_scanned: usize,
+
+    /// Stale applications: never scanned first, then oldest scan first
+    pub stale: Vec<StaleApp>,
+}
+
+impl StaleAppsReport {
+    /// Find the apps whose latest scan in `latest` (keyed by app ID) started
+    /// before `cutoff_ms`, or that have none
+    pub fn build(
+        threshold: &str,
+        env: Option<&str>,
+        apps: &[Application],
+        latest: &HashMap<String, ScanResult>,
+        cutoff_ms: i64,
+        now_ms: i64,
+    ) -> Self {
+        let mut stale: Vec<StaleApp> = apps
+            .iter()
+            .filter(|app| {
+                latest
+                    .get(&app.id)
+                    .is_none_or(|scan| timestamp_ms(scan) < cutoff_ms)
+            })
+            .map(|app| StaleApp::new(app, latest.get(&app.id), now_ms))
+            .collect();
+        stale.sort_by(|a, b| {
+            a.last_scanned
+                .cmp(&b.last_scanned)
+                .then_with(|| a.app.to_lowercase().cmp(&b.app.to_lowercase()))
+        });
+
+        Self {
+            threshold: threshold.to_string(),
+            env: env.map(str::to_string),
+            apps_checked: apps.len(),
+            never_scanned: stale.iter().filter(|s| s.last_scanned.is_none()).count(),
+            stale,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::fixtures::{ApplicationBuilder, ScanResultBuilder};
+
+    fn app(id: &str, name: &str) -> Application {
+        ApplicationBuilder::new(id).name(name).build()
+    }
+
+    fn scan(app_id: &str, timestamp: i64) -> ScanResult {
+        ScanResultBuilder::new(format!("scan-{}", app_id), app_id)
+            .env("prod")
+            .timestamp(timestamp.to_string())
+            .build()
+    }
+
+    #[test]
+    fn test_build_lists_never_scanned_then_oldest() {
+        let now = 100 * DAY_MS;
+        let apps = vec![
+            app("a", "fresh"),
+            app("b", "old"),
+            app("c", "older"),
+            app("d", "never"),
+        ];
+        let latest: HashMap<String, ScanResult> = [
+            ("a", now - DAY_MS),
+            ("b", now - 40 * DAY_MS),
+            ("c", now - 90 * DAY_MS),
+        ]
+        .into_iter()
+        .map(|(id, ts)| (id.to_string(), scan(id, ts)))
+        .collect();
+
+        let report = StaleAppsReport::build("30d", None, &apps, &latest, now - 30 * DAY_MS, now);
+
+        let names: Vec<&str> = report.stale.iter().map(|s| s.app.as_str()).collect();
+        assert_eq!(names, vec!["never", "older", "old"]);
+        assert_eq!(report.apps_checked, 4);
+        assert_eq!(report.never_scanned, 1);
+        assert_eq!(report.stale[0].last_scan, "never");
+        assert_eq!(report.stale[1].days_since_scan, Some(90));
+        assert_eq!(report.stale[2].last_env, "prod (Complete)");
+    }
+}
@@ -0,0 +1,67 @@
+//! Finding suppression display models
+
+use chrono::NaiveDate;
+use schemars::JsonSchema;
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::suppress::Suppression;
+
+/// Suppression display model for table/JSON output.
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct SuppressionDisplay {
+    /// Plugin ID of the suppressed finding
+    #[tabled(rename = "PLUGIN")]
+    pub plugin: String,
+
+    /// Path patterns, comma-separated ("*" = every path)
+    #[tabled(rename = "PATHS")]
+    pub paths: String,
+
+    /// Application name or ID ("*" = every app)
+    #[tabled(rename = "APP")]
+    pub app: String,
+
+    /// Environment ("*" = every environment)
+    #[tabled(rename = "ENV")]
+    pub env: String,
+
+    /// Last day the suppression applies ("never" when it does not expire)
+    #[tabled(rename = "EXPIRES")]
+    pub expires: String,
+
+    /// "active" or "expired"
+    #[tabled(rename = "STATUS")]
+    pub status: String,
+
+    /// Why the finding is accepted
+    #[tabled(rename = "REASON")]
+    pub reason: String,
+}
+
+impl SuppressionDisplay {
+    /// Display a suppression, judging expiry against `today`
+    pub fn new(suppression: &Suppression, today: NaiveDate) -> Self {
+        let any = |value: &Option<String>| value.clone().unwrap_or_else(|| "*".to_string());
+        Self {
+            plugin: suppression.plugin.clone(),
+            paths: if suppression.paths.is_empty() {
+                "*".to_string()
+            } else {
+                suppression.paths.join(", ")
+            },
+            app: any(&suppression.app),
+            env: any(&suppression.env),
+            expires: suppression
+                .expires
+                .map_or_else(|| "never".to_string(), |d| d.to_string()),
+            status: if suppression.is_active(today) {
+                "active"
+            } else {
+                "expired"
+            }
+            .to_string(),
+            reason: suppression.reason.clone(),
+        }
+    }
+}
@@ -0,0 +1,267 @@
+//! Attack surface summary for `surface summary`
+//!
+//! Joins the org's repositories, applications, and hosted OpenAPI specs. A
+//! repository counts as mapped when one of its app mappings names an
+//! application that still exists, and as having a spec when a hosted spec
+//! belongs to it or StackHawk generated one from its code. Unmapped repos
+//! and APIs without specs are only counted among repositories in the attack
+//! surface, since those are the ones that should be scanned.
+
+use std::collections::{BTreeMap, HashSet};
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use tabled::Tabled;
+
+use super::common::percent;
+use crate::client::models::{Application, OASAsset, Repository};
+
+/// Attack surface coverage across repositories, apps, and specs
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct SurfaceSummary {
+    /// Repositories StackHawk knows about
+    pub repos: usize,
+
+    /// Repositories in the attack surface
+    pub in_attack_surface: usize,
+
+    /// Attack surface repositories as a percentage of all repositories
+    pub attack_surface_percent: f64,
+
+    /// Repositories with at least one sensitive data tag
+    pub sensitive_data_repos: usize,
+
+    /// Repositories per sensitive data tag (e.g. PII, PCI)
+    pub sensitive_data_tags: BTreeMap<String, usize>,
+
+    /// Applications in the org
+    pub apps: usize,
+
+    /// Applications not mapped to any repository
+    pub apps_without_repo: usize,
+
+    /// Hosted OpenAPI specs
+    pub specs: usize,
+
+    /// Attack surface repositories with no mapped application
+    pub unmapped_repos: Vec<SurfaceRepo>,
+
+    /// Attack surface repositories with no hosted or generated spec
+    pub apis_without_specs: Vec<SurfaceRepo>,
+}
+
+/// A repository listed in the summary
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct SurfaceRepo {
+    /// Repository name, with its provider org when known
+    #[tabled(rename = "REPO")]
+    pub name: String,
+
+    /// Sensitive data tags, comma-separated
+    #[tabled(rename = "SENSITIVE")]
+    pub sensitive_data: String,
+
+    /// Detected frameworks, comma-separated
+    #[tabled(rename = "FRAMEWORKS")]
+    pub frameworks: String,
+
+    /// Repository ID
+    #[tabled(skip)]
+    pub id: String,
+}
+
+/// Single-row table view of a [`SurfaceSummary`]
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct SurfaceRow {
+    #[tabled(rename = "REPOS")]
+    pub repos: usize,
+    #[tabled(rename = "ATTACK SURFACE")]
+    pub in_attack_surface: String,
+    #[tabled(rename = "SENSITIVE DATA")]
+    pub sensitive_data_repos: usize,
+    #[tabled(rename = "UNMAPPED")]
+    pub unmapped_repos: usize,
+    #[tabled(rename = "NO SPEC")]
+    pub apis_without_specs: usize,
+    #[tabled(rename = "APPS")]
+    pub apps: usize,
+    #[tabled(rename = "APPS W/O REPO")]
+    pub apps_without_repo: usize,
+    #[tabled(rename = "SPECS")]
+    pub specs: usize,
+}
+
+impl SurfaceSummary {
+    /// Summarize the three listings
+    pub fn build(repos: &[Repository], apps: &[Application], specs: &[OASAsset]) -> Self {
+        let app_ids: HashSet<&str> = apps.iter().map(|a| a.id.as_str()).collect();
+        let spec_repos: HashSet<&str> = specs
+            .iter()
+            .filter_map(|s| s.repository_id.as_deref())
+            .collect();
+        let mapped_apps: HashSet<&str> = repos
+            .iter()
+            .flat_map(|r| &r.app_infos)
+            .filter_map(|info| info.app_id.as_deref())
+            .collect();
+
+        let mut sensitive_data_tags = BTreeMap::new();
+        for repo in repos {
+            let tags: HashSet<&str> = repo
+                .sensitive_data_tags
+                .iter()
+                .map(|t| t.name.as_str())
+                .collect();
+            for tag in tags {
+                *sensitive_data_tags.entry(tag.to_string()).or_insert(0) += 1;
+            }
+        }
+
+        let surface: Vec<&Repository> = repos.iter().filter(|r| r.is_in_attack_surface).collect();
+        let is_mapped = |repo: &Repository| {
+            repo.app_infos
+                .iter()
+                .filter_map(|info| info.app_id.as_deref())
+                .any(|id| app_ids.contains(id))
+        };
+        let has_spec = |repo: &Repository| {
+            repo.has_generated_open_api_spec
+                || repo
+                    .open_api_spec_info
+                    .as_ref()
+                    .is_some_and(|info| info.generated_oas_count > 0)
+                || repo.id.as_deref().is_some_and(|id| spec_repos.contains(id))
+        };
+
+        Self {
+            repos: repos.len(),
+            in_attack_surface: surface.len(),
+            attack_surface_percent: percent(surface.len(), repos.len()),
+            sensitive_data_repos: repos
+                .iter()
+                .filter(|r| !r.sensitive_data_tags.is_empty())
+                .count(),
+            sensitive_data_tags,
+            apps: apps.len(),
+            apps_without_repo: apps
+                .iter()
+                .filter(|a| !mapped_apps.contains(a.id.as_str()))
+                .count(),
+            specs: specs.len(),
+            unmapped_repos: surface
+                .iter()
+                .filter(|r| !is_mapped(r))
+                .map(|r| SurfaceRepo::from(*r))
+                .collect(),
+            apis_without_specs: surface
+                .iter()
+                .filter(|r| !has_spec(r))
+                .map(|r| SurfaceRepo::from(*r))
+                .collect(),
+        }
+    }
+
+    /// The headline numbers as one table row
+    pub fn row(&self) -> SurfaceRow {
+        SurfaceRow {
+            repos: self.repos,
+            in_attack_surface: format!(
+                "{} ({:.0}%)",
+                self.in_attack_surface, self.attack_surface_percent
+            ),
+            sensitive_data_repos: self.sensitive_data_repos,
+            unmapped_repos: self.unmapped_repos.len(),
+            apis_without_specs: self.apis_without_specs.len(),
+            apps: self.apps,
+            apps_without_repo: self.apps_without_repo,
+            specs: self.specs,
+        }
+    }
+}
+
+impl From<&Repository> for SurfaceRepo {
+    fn from(repo: &Repository) -> Self {
+        let name = match &repo.provider_org_name {
+            Some(org) => format!("{}/{}", org, repo.name),
+            None => repo.name.clone(),
+        };
+        Self {
+            name,
+            sensitive_data: repo
+                .sensitive_data_tags
+                .iter()
+                .map(|t| t.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+            frameworks: repo.framework_names.join(", "),
+            id: repo.id.clone().unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::fixtures::test_app;
+
+    fn repo(id: &str, surface: bool, app: Option<&str>, tags: &[&str]) -> Repository {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "name": id,
+            "providerOrgName": "acme",
+            "isInAttackSurface": surface,
+            "appInfos": app.map(|a| vec![serde_json::json!({"appId": a})]).unwrap_or_default(),
+            "sensitiveDataTags": tags.iter().map(|t| serde_json::json!({"name": t})).collect::<Vec<_>>(),
+        }))
+        .unwrap()
+    }
+
+    fn spec(repo: &str) -> OASAsset {
+        serde_json::from_value(serde_json::json!({"oasId": "o1", "repositoryId": repo})).unwrap()
+    }
+
+    #[test]
+    fn test_build_summary() {
+        let repos = vec![
+            repo("web", true, Some("app-1"), &["PII", "PCI"]),
+            repo("api", true, None, &["PII"]),
+            repo("old", true, Some("deleted-app"), &[]),
+            repo("docs", false, None, &[]),
+        ];
+        let apps = vec![test_app("app-1"), test_app("app-2")];
+        let specs = vec![spec("web")];
+
+        let summary = SurfaceSummary::build(&repos, &apps, &specs);
+
+        assert_eq!(summary.repos, 4);
+        assert_eq!(summary.in_attack_surface, 3);
+        assert_eq!(summary.attack_surface_percent, 75.0);
+        assert_eq!(summary.sensitive_data_repos, 2);
+        assert_eq!(summary.sensitive_data_tags["PII"], 2);
+        assert_eq!(summary.sensitive_data_tags["PCI"], 1);
+        assert_eq!(summary.apps_without_repo, 1);
+
+        // Mapped to an app that no longer exists counts as unmapped
+        let unmapped: Vec<&str> = summary
+            .unmapped_repos
+            .iter()
+            .map(|r| r.id.as_str())
+            .collect();
+        assert_eq!(unmapped, ["api", "old"]);
+        let no_spec: Vec<&str> = summary
+            .apis_without_specs
+            .iter()
+            .map(|r| r.id.as_str())
+            .collect();
+        assert_eq!(no_spec, ["api", "old"]);
+        assert_eq!(summary.unmapped_repos[0].name, "acme/api");
+        assert_eq!(summary.row().in_attack_surface, "3 (75%)");
+    }
+
+    #[test]
+    fn test_empty_summary() {
+        let summary = SurfaceSummary::build(&[], &[], &[]);
+        assert_eq!(summary.attack_surface_percent, 0.0);
+        assert!(summary.unmapped_repos.is_empty());
+    }
+}
@@ -0,0 +1,200 @@
+//! Membership drift between teams and an IdP group export, for `team audit`
+//!
+//! The export is read as a team manifest (see [`TeamManifest`]). Each team
+//! it lists is compared with the team of the same name: listed users who
+//! are not members are missing, and members who are not listed are extra.
+//! Teams whose members are not listed are only checked for existence, and
+//! teams not in the export are not audited.
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::client::models::{TeamDetail, User};
+
+use super::team_import::TeamManifest;
+
+/// How a team differs from the export
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DriftKind {
+    /// The team does not exist
+    MissingTeam,
+    /// A listed user is not a member
+    MissingMember,
+    /// A member is not listed
+    ExtraMember,
+    /// A listed user is not in the organization
+    UnknownUser,
+}
+
+impl fmt::Display for DriftKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::MissingTeam => "missing team",
+            Self::MissingMember => "missing member",
+            Self::ExtraMember => "extra member",
+            Self::UnknownUser => "unknown user",
+        })
+    }
+}
+
+/// One difference between a team and the export
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct TeamDrift {
+    /// Team name (as in the export for missing teams)
+    #[tabled(rename = "TEAM")]
+    pub team: String,
+
+    #[tabled(rename = "DRIFT")]
+    pub drift: DriftKind,
+
+    /// Member email, or the entry as listed (empty for missing teams)
+    #[tabled(rename = "MEMBER")]
+    pub member: String,
+
+    /// User ID (absent for unknown users and missing teams)
+    #[tabled(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+
+    /// Team ID (absent for missing teams)
+    #[tabled(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+}
+
+impl TeamDrift {
+    /// Drift of `teams` from `manifest`, in manifest order
+    ///
+    /// `teams` are the details of the existing teams named in the manifest
+    /// and `users` the organization's users, used to match listed emails
+    /// and IDs.
+    pub fn audit(manifest: &TeamManifest, teams: &[TeamDetail], users: &[User]) -> Vec<Self> {
+        let mut drift = Vec::new();
+        for spec in &manifest.teams {
+            let Some(team) = teams
+                .iter()
+                .find(|t| t.name.eq_ignore_ascii_case(&spec.name))
+            else {
+                drift.push(Self {
+                    team: spec.name.clone(),
+                    drift: DriftKind::MissingTeam,
+                    member: String::new(),
+                    user_id: None,
+                    team_id: None,
+                });
+                continue;
+            };
+            let Some(members) = &spec.members else {
+                continue;
+            };
+
+            // (kind, member, user ID), sorted and deduplicated
+            let mut rows: BTreeSet<(DriftKind, String, Option<String>)> = BTreeSet::new();
+            let mut listed: BTreeSet<&str> = BTreeSet::new();
+            for entry in members {
+                let user = users.iter().find(|u| {
+                    u.external.id == *entry || u.external.email.eq_ignore_ascii_case(entry)
+                });
+                match user {
+                    Some(user) => {
+                        listed.insert(&user.external.id);
+                        if !team.users.iter().any(|m| m.user_id == user.external.id) {
+                            rows.insert((
+                                DriftKind::MissingMember,
+                                user.external.email.clone(),
+                                Some(user.external.id.clone()),
+                            ));
+                        }
+                    }
+                    None => {
+                        rows.insert((DriftKind::UnknownUser, entry.clone(), None));
+                    }
+                }
+            }
+            for member in &team.users {
+                if listed.contains(member.user_id.as_str()) {
+                    continue;
+                }
+                let email = member.email.clone().or_else(|| {
+                    users
+                        .iter()
+                        .find(|u| u.external.id == member.user_id)
+                        .map(|u| u.external.email.clone())
+                });
+                rows.insert((
+                    DriftKind::ExtraMember,
+                    email.unwrap_or_else(|| member.user_id.clone()),
+                    Some(member.user_id.clone()),
+                ));
+            }
+
+            drift.extend(rows.into_iter().map(|(kind, member, user_id)| Self {
+                team: team.name.clone(),
+                drift: kind,
+                member,
+                user_id,
+                team_id: Some(team.id.clone()),
+            }));
+        }
+        drift
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::csv::parse_csv;
+
+    fn user(id: &str, email: &str) -> User {
+        serde_json::from_value(serde_json::json!({"external": {"id": id, "email": email}})).unwrap()
+    }
+
+    #[test]
+    fn test_audit_reports_drift() {
+        let records = parse_csv(
+            "group,email\n\
+             Platform,a@x.com\n\
+             Platform,B@X.com\n\
+             Platform,ghost@x.com\n\
+             Security,a@x.com\n\
+             Data,c@x.com\n",
+        )
+        .unwrap();
+        let manifest = TeamManifest::from_csv(&records).unwrap();
+        let users = vec![
+            user("u-a", "a@x.com"),
+            user("u-b", "b@x.com"),
+            user("u-c", "c@x.com"),
+        ];
+        let teams: Vec<TeamDetail> = serde_json::from_value(serde_json::json!([
+            {"id": "t1", "name": "platform", "users": [
+                {"userId": "u-a", "email": "a@x.com"},
+                {"userId": "u-c"}
+            ]},
+            {"id": "t2", "name": "Security", "users": [{"userId": "u-a"}]}
+        ]))
+        .unwrap();
+
+        let drift = TeamDrift::audit(&manifest, &teams, &users);
+        let rows: Vec<_> = drift
+            .iter()
+            .map(|d| (d.team.as_str(), d.drift, d.member.as_str()))
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                ("platform", DriftKind::MissingMember, "b@x.com"),
+                ("platform", DriftKind::ExtraMember, "c@x.com"),
+                ("platform", DriftKind::UnknownUser, "ghost@x.com"),
+                ("Data", DriftKind::MissingTeam, ""),
+            ]
+        );
+        assert_eq!(drift[0].user_id.as_deref(), Some("u-b"));
+        assert_eq!(drift[3].team_id, None);
+    }
+}
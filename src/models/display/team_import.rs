@@ -0,0 +1,318 @@
+//! Team manifests, membership diffs, and per-team results for `team import`
+
+use std::collections::{BTreeMap, HashSet};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tabled::Tabled;
+
+use crate::error::{Error, Result};
+use crate::output::csv::CsvRecord;
+
+/// Expected CSV columns, in order (lists are `;`-separated)
+const COLUMNS: &str = "team,members,apps";
+
+/// Desired state of one team
+///
+/// `None` leaves that side of the team as it is; an empty list clears it.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TeamSpec {
+    pub name: String,
+    /// Members by email or user ID
+    #[serde(default)]
+    pub members: Option<Vec<String>>,
+    /// Applications by name or ID
+    #[serde(default)]
+    pub apps: Option<Vec<String>>,
+}
+
+/// Teams to create or update, read from a CSV or YAML manifest
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TeamManifest {
+    pub teams: Vec<TeamSpec>,
+}
+
+impl TeamManifest {
+    /// Parse a YAML manifest (`teams: [{name, members, apps}]`)
+    pub fn from_yaml(text: &str) -> Result<Self> {
+        let manifest: Self = serde_yaml::from_str(text)
+            .map_err(|e| Error::Other(format!("Invalid team manifest: {}", e)))?;
+
+        let mut seen = HashSet::new();
+        for spec in &manifest.teams {
+            if spec.name.trim().is_empty() {
+                return Err(Error::Other(
+                    "Invalid team manifest: team name cannot be empty".to_string(),
+                ));
+            }
+            if !seen.insert(spec.name.trim().to_lowercase()) {
+                return Err(Error::Other(format!(
+                    "Invalid team manifest: team \"{}\" is listed more than once",
+                    spec.name.trim()
+                )));
+            }
+        }
+
+        Ok(Self {
+            teams: manifest
+                .teams
+                .into_iter()
+                .map(|spec| TeamSpec {
+                    name: spec.name.trim().to_string(),
+                    members: spec.members.map(clean_list),
+                    apps: spec.apps.map(clean_list),
+                })
+                .collect(),
+        })
+    }
+
+    /// Parse CSV records (`team,members,apps`), skipping a header row
+    ///
+    /// Rows for the same team are merged. An empty cell leaves that side of
+    /// the team unmanaged unless another row for the team fills it in.
+    pub fn from_csv(records: &[CsvRecord]) -> Result<Self> {
+        let records = match records.first() {
+            Some(first) if is_header(first) => &records[1..],
+            _ => records,
+        };
+
+        // Keyed by lowercased name; keeps the first spelling and file order
+        let mut order: Vec<String> = Vec::new();
+        let mut specs: BTreeMap<String, TeamSpec> = BTreeMap::new();
+        let mut errors = Vec::new();
+
+        for record in records {
+            let fields = &record.fields;
+            if !(1..=3).contains(&fields.len()) {
+                errors.push(format!(
+                    "  line {}: expected up to 3 columns, got {}",
+                    record.line,
+                    fields.len()
+                ));
+                continue;
+            }
+            let name = fields[0].trim();
+            if name.is_empty() {
+                errors.push(format!("  line {}: team is empty", record.line));
+                continue;
+            }
+
+            let key = name.to_lowercase();
+            let spec = specs.entry(key.clone()).or_insert_with(|| {
+                order.push(key);
+                TeamSpec {
+                    name: name.to_string(),
+                    members: None,
+                    apps: None,
+                }
+            });
+            merge_cell(&mut spec.members, fields.get(1));
+            merge_cell(&mut spec.apps, fields.get(2));
+        }
+
+        if !errors.is_empty() {
+            return Err(Error::Other(format!(
+                "Invalid team manifest (expected {}):\n{}",
+                COLUMNS,
+                errors.join("\n")
+            )));
+        }
+
+        Ok(Self {
+            teams: order.iter().filter_map(|key| specs.remove(key)).collect(),
+        })
+    }
+}
+
+/// Add a cell's `;`-separated entries to a list (empty cells add nothing)
+fn merge_cell(list: &mut Option<Vec<String>>, cell: Option<&String>) {
+    let entries: Vec<String> = cell
+        .map(|c| c.split(';').map(str::to_string).collect())
+        .map(clean_list)
+        .unwrap_or_default();
+    if !entries.is_empty() {
+        list.get_or_insert_with(Vec::new).extend(entries);
+    }
+}
+
+/// Trim entries and drop blanks
+fn clean_list(list: Vec<String>) -> Vec<String> {
+    list.into_iter()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// Whether a record is the header row (first column names the team column,
+/// or the group column of an IdP export)
+fn is_header(record: &CsvRecord) -> bool {
+    record.fields.first().is_some_and(|f| {
+        matches!(
+            f.to_lowercase().as_str(),
+            "team" | "team_name" | "name" | "group" | "group_name"
+        )
+    })
+}
+
+/// Changes needed to turn a current ID set into a desired one
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SyncDiff {
+    pub to_add: Vec<String>,
+    pub to_remove: Vec<String>,
+    pub unchanged: Vec<String>,
+}
+
+impl SyncDiff {
+    /// Diff two ID sets (each list is sorted for stable output)
+    pub fn compute(current: &HashSet<String>, desired: &HashSet<String>) -> Self {
+        let sorted = |ids: Vec<&String>| {
+            let mut ids: Vec<String> = ids.into_iter().cloned().collect();
+            ids.sort();
+            ids
+        };
+        Self {
+            to_add: sorted(desired.difference(current).collect()),
+            to_remove: sorted(current.difference(desired).collect()),
+            unchanged: sorted(desired.intersection(current).collect()),
+        }
+    }
+
+    /// Whether applying the diff changes anything
+    pub fn has_changes(&self) -> bool {
+        !self.to_add.is_empty() || !self.to_remove.is_empty()
+    }
+
+    /// Compact summary, e.g. `+2 -1 =5`
+    pub fn summary(&self) -> String {
+        format!(
+            "+{} -{} ={}",
+            self.to_add.len(),
+            self.to_remove.len(),
+            self.unchanged.len()
+        )
+    }
+}
+
+/// Planned or applied change for one manifest team
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct TeamImportOutcome {
+    #[tabled(rename = "TEAM")]
+    pub team: String,
+
+    /// "create", "update", or "unchanged"
+    #[tabled(rename = "ACTION")]
+    pub action: String,
+
+    /// Member changes (`+added -removed =unchanged`, `--` if unmanaged)
+    #[tabled(rename = "MEMBERS")]
+    pub members: String,
+
+    /// Application changes (`+added -removed =unchanged`, `--` if unmanaged)
+    #[tabled(rename = "APPS")]
+    pub apps: String,
+
+    /// "created", "updated", "would create", ..., or the error
+    #[tabled(rename = "RESULT")]
+    pub result: String,
+
+    /// Whether the change was (or, in a dry run, can be) applied
+    #[tabled(skip)]
+    pub success: bool,
+
+    /// Team ID (absent for teams not created yet)
+    #[tabled(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+}
+
+impl TeamImportOutcome {
+    pub fn new(
+        team: &str,
+        action: &str,
+        members: Option<&SyncDiff>,
+        apps: Option<&SyncDiff>,
+        team_id: Option<String>,
+        result: std::result::Result<&str, String>,
+    ) -> Self {
+        let (success, result) = match result {
+            Ok(msg) => (true, msg.to_string()),
+            Err(e) => (false, e),
+        };
+        let summary = |diff: Option<&SyncDiff>| diff.map_or("--".to_string(), SyncDiff::summary);
+        Self {
+            team: team.to_string(),
+            action: action.to_string(),
+            members: summary(members),
+            apps: summary(apps),
+            result,
+            success,
+            team_id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::csv::parse_csv;
+
+    #[test]
+    fn test_from_csv_merges_rows_and_skips_header() {
+        let records = parse_csv(
+            "team,members,apps\n\
+             Platform,a@x.com;b@x.com,\n\
+             Security,,web\n\
+             platform,,api; billing\n",
+        )
+        .unwrap();
+        let manifest = TeamManifest::from_csv(&records).unwrap();
+
+        assert_eq!(manifest.teams.len(), 2);
+        assert_eq!(manifest.teams[0].name, "Platform");
+        assert_eq!(
+            manifest.teams[0].members.as_deref(),
+            Some(&["a@x.com".to_string(), "b@x.com".to_string()][..])
+        );
+        assert_eq!(
+            manifest.teams[0].apps.as_deref(),
+            Some(&["api".to_string(), "billing".to_string()][..])
+        );
+        assert_eq!(manifest.teams[1].members, None);
+    }
+
+    #[test]
+    fn test_from_csv_reports_bad_lines() {
+        let records = parse_csv("Platform,a,b,c\n,x,y\n").unwrap();
+        let err = TeamManifest::from_csv(&records).unwrap_err().to_string();
+        assert!(err.contains("line 1: expected up to 3 columns"));
+        assert!(err.contains("line 2: team is empty"));
+    }
+
+    #[test]
+    fn test_from_yaml() {
+        let manifest = TeamManifest::from_yaml(
+            "teams:\n  - name: Platform\n    members: [a@x.com]\n    apps: []\n  - name: Security\n",
+        )
+        .unwrap();
+        assert_eq!(manifest.teams[0].apps, Some(vec![]));
+        assert_eq!(manifest.teams[1].members, None);
+
+        assert!(TeamManifest::from_yaml("teams:\n  - name: A\n  - name: a\n").is_err());
+        assert!(TeamManifest::from_yaml("teams:\n  - name: A\n    owners: [x]\n").is_err());
+    }
+
+    #[test]
+    fn test_sync_diff() {
+        let set = |ids: &[&str]| ids.iter().map(|s| s.to_string()).collect::<HashSet<_>>();
+        let diff = SyncDiff::compute(&set(&["a", "b", "c"]), &set(&["c", "b", "d"]));
+
+        assert_eq!(diff.to_add, vec!["d"]);
+        assert_eq!(diff.to_remove, vec!["a"]);
+        assert_eq!(diff.unchanged, vec!["b", "c"]);
+        assert_eq!(diff.summary(), "+1 -1 =2");
+        assert!(diff.has_changes());
+        assert!(!SyncDiff::compute(&set(&["a"]), &set(&["a"])).has_changes());
+    }
+}
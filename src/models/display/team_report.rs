@@ -0,0 +1,334 @@
+//! Team activity for `team report`
+//!
+//! Joins a team's members and applications with the audit log and scan
+//! history: scans of the team's apps, audit records that touch the team's
+//! apps or the team itself, membership changes, and activity per member.
+
+use std::collections::HashSet;
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::client::models::{AuditRecord, ScanResult, TeamDetail};
+use crate::models::display::audit::{AuditDisplay, format_audit_timestamp};
+use crate::models::display::scan::ScanDisplay;
+
+/// Audit activity of one team member
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct MemberActivity {
+    /// Member name (or email when the name is unknown)
+    #[tabled(rename = "MEMBER")]
+    pub member: String,
+
+    /// Member email
+    #[tabled(rename = "EMAIL")]
+    pub email: String,
+
+    /// Audit records by this member in the window
+    #[tabled(rename = "ACTIONS")]
+    pub actions: usize,
+
+    /// Scan events (started, completed, failed) by this member
+    #[tabled(rename = "SCANS")]
+    pub scans: usize,
+
+    /// Most recent audit record by this member
+    #[tabled(rename = "LAST ACTIVE")]
+    pub last_active: String,
+}
+
+/// Recent activity of a team
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct TeamActivityReport {
+    /// Team name
+    pub team: String,
+
+    /// Team ID
+    pub team_id: String,
+
+    /// Time window as given (e.g. `30d`)
+    pub since: String,
+
+    /// Number of team members
+    pub members: usize,
+
+    /// Number of applications assigned to the team
+    pub applications: usize,
+
+    /// Scans of the team's applications, newest first
+    pub scans: Vec<ScanDisplay>,
+
+    /// Audit records naming the team's applications or the team, newest first
+    pub config_changes: Vec<AuditDisplay>,
+
+    /// Team and user audit records naming the team, newest first
+    pub membership_changes: Vec<AuditDisplay>,
+
+    /// One row per member, most active first
+    pub member_activity: Vec<MemberActivity>,
+}
+
+/// How an audit record relates to the team
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Relation {
+    Membership,
+    Config,
+    Unrelated,
+}
+
+impl TeamActivityReport {
+    /// Join a team with scans and audit records from the report window
+    ///
+    /// Scans of other applications are ignored. Audit records are matched to
+    /// the team by the IDs and names in their payloads.
+    pub fn build(
+        team: &TeamDetail,
+        since: &str,
+        scans: &[ScanResult],
+        audit: &[AuditRecord],
+    ) -> Self {
+        let app_ids: HashSet<&str> = team
+            .applications
+            .iter()
+            .map(|a| a.application_id.as_str())
+            .collect();
+        let mut team_scans: Vec<&ScanResult> = scans
+            .iter()
+            .filter(|s| app_ids.contains(s.scan.application_id.as_str()))
+            .collect();
+        team_scans.sort_by_key(|s| std::cmp::Reverse(timestamp(&s.scan.timestamp)));
+
+        let team_refs = lowercase_set(
+            [team.id.as_str(), team.name.as_str()]
+                .into_iter()
+                .filter(|s| !s.is_empty()),
+        );
+        let app_refs = lowercase_set(team.applications.iter().flat_map(|a| {
+            std::iter::once(a.application_id.as_str()).chain(a.application_name.as_deref())
+        }));
+
+        let mut records: Vec<&AuditRecord> = audit.iter().collect();
+        records.sort_by_key(|r| std::cmp::Reverse(timestamp(&r.timestamp)));
+        let mut config_changes = Vec::new();
+        let mut membership_changes = Vec::new();
+        for record in &records {
+            match classify(record, &team_refs, &app_refs) {
+                Relation::Membership => membership_changes.push(AuditDisplay::from(*record)),
+                Relation::Config => config_changes.push(AuditDisplay::from(*record)),
+                Relation::Unrelated => {}
+            }
+        }
+
+        let mut member_activity: Vec<MemberActivity> = team
+            .users
+            .iter()
+            .map(|user| {
+                let email = user.email.clone().unwrap_or_default();
+                let own: Vec<&&AuditRecord> = records
+                    .iter()
+                    .filter(|r| {
+                        r.user_id == user.user_id
+                            || (!email.is_empty() && r.user_email.eq_ignore_ascii_case(&email))
+                    })
+                    .collect();
+                MemberActivity {
+                    member: user.user_name.clone().unwrap_or_else(|| email.clone()),
+                    email: if email.is_empty() {
+                        "--".to_string()
+                    } else {
+                        email.clone()
+                    },
+                    actions: own.len(),
+                    scans: own
+                        .iter()
+                        .filter(|r| activity_type(r).starts_with("SCAN_"))
+                        .count(),
+                    // Records are newest first
+                    last_active: own
+                        .first()
+                        .map(|r| format_audit_timestamp(timestamp(&r.timestamp)))
+                        .unwrap_or_else(|| "--".to_string()),
+                }
+            })
+            .collect();
+        member_activity.sort_by(|a, b| {
+            b.actions
+                .cmp(&a.actions)
+                .then_with(|| a.member.to_lowercase().cmp(&b.member.to_lowercase()))
+        });
+
+        Self {
+            team: team.name.clone(),
+            team_id: team.id.clone(),
+            since: since.to_string(),
+            members: team.users.len(),
+            applications: team.applications.len(),
+            scans: team_scans.into_iter().map(ScanDisplay::from).collect(),
+            config_changes,
+            membership_changes,
+            member_activity,
+        }
+    }
+}
+
+/// Sort an audit record into the report's sections
+///
+/// Scan events are left to the scan history. Team and user records naming
+/// the team are membership changes; anything else naming one of the team's
+/// applications (or the team) is a configuration change.
+fn classify(
+    record: &AuditRecord,
+    team_refs: &HashSet<String>,
+    app_refs: &HashSet<String>,
+) -> Relation {
+    let activity = activity_type(record);
+    if activity.starts_with("SCAN_") {
+        return Relation::Unrelated;
+    }
+    let payload: serde_json::Value =
+        serde_json::from_str(&record.payload).unwrap_or(serde_json::Value::Null);
+    let names_team = mentions(&payload, team_refs);
+
+    if names_team && (activity.contains("TEAM") || activity.starts_with("USER_")) {
+        Relation::Membership
+    } else if names_team || mentions(&payload, app_refs) {
+        Relation::Config
+    } else {
+        Relation::Unrelated
+    }
+}
+
+/// User activity type if present, otherwise the org activity type
+fn activity_type(record: &AuditRecord) -> &str {
+    record
+        .user_activity_type
+        .as_deref()
+        .or(record.organization_activity_type.as_deref())
+        .unwrap_or("")
+}
+
+/// Whether any string in the payload is one of `refs` (case-insensitive)
+fn mentions(value: &serde_json::Value, refs: &HashSet<String>) -> bool {
+    match value {
+        serde_json::Value::String(s) => refs.contains(&s.to_lowercase()),
+        serde_json::Value::Array(items) => items.iter().any(|v| mentions(v, refs)),
+        serde_json::Value::Object(map) => map.values().any(|v| mentions(v, refs)),
+        _ => false,
+    }
+}
+
+fn lowercase_set<'a>(values: impl Iterator<Item = &'a str>) -> HashSet<String> {
+    values.map(str::to_lowercase).collect()
+}
+
+fn timestamp(value: &str) -> i64 {
+    value.parse().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::fixtures::ScanResultBuilder;
+    use crate::client::models::{TeamApplication, TeamUser};
+
+    fn team() -> TeamDetail {
+        TeamDetail {
+            id: "team-1".to_string(),
+            name: "Payments".to_string(),
+            organization_id: None,
+            users: vec![
+                TeamUser {
+                    user_id: "u1".to_string(),
+                    user_name: Some("Alice".to_string()),
+                    email: Some("alice@example.com".to_string()),
+                    role: None,
+                },
+                TeamUser {
+                    user_id: "u2".to_string(),
+                    user_name: Some("Bob".to_string()),
+                    email: Some("bob@example.com".to_string()),
+                    role: None,
+                },
+            ],
+            applications: vec![TeamApplication {
+                application_id: "app-1".to_string(),
+                application_name: Some("checkout".to_string()),
+                environments: vec![],
+            }],
+        }
+    }
+
+    fn record(activity: &str, user_id: &str, ts: &str, payload: &str) -> AuditRecord {
+        AuditRecord {
+            id: ts.to_string(),
+            user_activity_type: Some(activity.to_string()),
+            organization_activity_type: None,
+            organization_id: "org".to_string(),
+            user_id: user_id.to_string(),
+            user_name: String::new(),
+            user_email: String::new(),
+            payload: payload.to_string(),
+            timestamp: ts.to_string(),
+            user_ip_addr: None,
+        }
+    }
+
+    fn scan(id: &str, app_id: &str, ts: &str) -> ScanResult {
+        ScanResultBuilder::new(id, app_id)
+            .env("prod")
+            .timestamp(ts)
+            .build()
+    }
+
+    #[test]
+    fn test_build_joins_audit_and_scans() {
+        let audit = vec![
+            record(
+                "APPLICATION_UPDATED",
+                "u1",
+                "1000",
+                r#"{"appName":"Checkout"}"#,
+            ),
+            record("TEAM_UPDATED", "u3", "3000", r#"{"teamName":"Payments"}"#),
+            record("SCAN_STARTED", "u1", "2000", r#"{"appName":"checkout"}"#),
+            record(
+                "APPLICATION_UPDATED",
+                "u2",
+                "4000",
+                r#"{"appName":"other"}"#,
+            ),
+            record(
+                "POLICY_UPDATED",
+                "u4",
+                "5000",
+                r#"{"apps":[{"id":"app-1"}]}"#,
+            ),
+        ];
+        let scans = vec![
+            scan("s1", "app-1", "100"),
+            scan("s2", "app-9", "300"),
+            scan("s3", "app-1", "200"),
+        ];
+
+        let report = TeamActivityReport::build(&team(), "30d", &scans, &audit);
+
+        let scan_ids: Vec<_> = report.scans.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(scan_ids, vec!["s3", "s1"]);
+        let config: Vec<_> = report
+            .config_changes
+            .iter()
+            .map(|c| c.activity_type.as_str())
+            .collect();
+        assert_eq!(config, vec!["POLICY_UPDATED", "APPLICATION_UPDATED"]);
+        assert_eq!(report.membership_changes.len(), 1);
+
+        // Alice: app update + scan; Bob: one unrelated update still counts
+        let alice = &report.member_activity[0];
+        assert_eq!(
+            (alice.member.as_str(), alice.actions, alice.scans),
+            ("Alice", 2, 1)
+        );
+        assert_eq!(report.member_activity[1].actions, 1);
+    }
+}
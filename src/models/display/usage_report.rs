@@ -0,0 +1,220 @@
+//! Organization consumption over time for `org usage`
+//!
+//! StackHawk has no usage endpoint, so consumption is counted from the
+//! org's scans: scans run, distinct apps scanned, scan hours, and distinct
+//! users who started scans, per calendar month (UTC). Seats are the current
+//! member count against the subscription's limit.
+
+use std::collections::{BTreeMap, HashSet};
+
+use chrono::DateTime;
+use schemars::JsonSchema;
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::client::models::ScanResult;
+
+/// Consumption in one calendar month
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct MonthlyUsage {
+    /// Month, `YYYY-MM` (UTC)
+    #[tabled(rename = "MONTH")]
+    pub month: String,
+
+    /// Scans started
+    #[tabled(rename = "SCANS")]
+    pub scans: usize,
+
+    /// Distinct applications scanned
+    #[tabled(rename = "APPS SCANNED")]
+    pub apps_scanned: usize,
+
+    /// Distinct environments scanned
+    #[tabled(rename = "ENVS")]
+    pub envs_scanned: usize,
+
+    /// Distinct users who started scans
+    #[tabled(rename = "ACTIVE USERS")]
+    pub active_users: usize,
+
+    /// Total scan duration in hours
+    #[tabled(rename = "SCAN HOURS")]
+    pub scan_hours: f64,
+}
+
+/// Current use of a subscription quota
+#[derive(Debug, Clone, Default, Serialize, JsonSchema)]
+pub struct Quota {
+    /// Current count, if it could be fetched
+    pub used: Option<usize>,
+
+    /// Subscription limit, if the plan has one
+    pub limit: Option<i64>,
+}
+
+/// Organization usage across a time window
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct UsageReport {
+    /// Organization name
+    pub org: String,
+
+    /// The `--since` value the report covers
+    pub since: String,
+
+    /// Members against the subscription's user limit
+    pub seats: Quota,
+
+    /// Applications against the subscription's app limit
+    pub apps: Quota,
+
+    /// Totals across the window (`month` is "Total")
+    pub totals: MonthlyUsage,
+
+    /// Per-month consumption, oldest first
+    pub months: Vec<MonthlyUsage>,
+}
+
+/// Running totals for one period
+#[derive(Default)]
+struct Tally<'a> {
+    scans: usize,
+    apps: HashSet<&'a str>,
+    envs: HashSet<(&'a str, &'a str)>,
+    users: HashSet<&'a str>,
+    seconds: f64,
+}
+
+impl<'a> Tally<'a> {
+    fn add(&mut self, result: &'a ScanResult) {
+        let scan = &result.scan;
+        self.scans += 1;
+        self.apps.insert(&scan.application_id);
+        self.envs.insert((&scan.application_id, &scan.env));
+        if let Some(user) = scan_user(result) {
+            self.users.insert(user);
+        }
+        self.seconds += result
+            .scan_duration
+            .as_deref()
+            .and_then(|d| d.parse::<f64>().ok())
+            .filter(|secs| *secs > 0.0)
+            .unwrap_or(0.0);
+    }
+
+    fn finish(&self, month: String) -> MonthlyUsage {
+        MonthlyUsage {
+            month,
+            scans: self.scans,
+            apps_scanned: self.apps.len(),
+            envs_scanned: self.envs.len(),
+            active_users: self.users.len(),
+            scan_hours: (self.seconds / 360.0).round() / 10.0,
+        }
+    }
+}
+
+impl UsageReport {
+    /// Count consumption from the scans started within the window
+    pub fn build(org: &str, since: &str, seats: Quota, apps: Quota, scans: &[ScanResult]) -> Self {
+        let mut months: BTreeMap<String, Tally> = BTreeMap::new();
+        let mut totals = Tally::default();
+        for result in scans {
+            let Some(month) = result
+                .scan
+                .timestamp
+                .parse::<i64>()
+                .ok()
+                .and_then(DateTime::from_timestamp_millis)
+                .map(|started| started.format("%Y-%m").to_string())
+            else {
+                continue;
+            };
+            months.entry(month).or_default().add(result);
+            totals.add(result);
+        }
+
+        Self {
+            org: org.to_string(),
+            since: since.to_string(),
+            seats,
+            apps,
+            totals: totals.finish("Total".to_string()),
+            months: months
+                .into_iter()
+                .map(|(month, tally)| tally.finish(month))
+                .collect(),
+        }
+    }
+
+    /// Monthly rows followed by the totals row
+    pub fn rows(&self) -> Vec<MonthlyUsage> {
+        let mut rows = self.months.clone();
+        rows.push(self.totals.clone());
+        rows
+    }
+}
+
+/// User who started a scan: the `userId` tag, else the external user ID
+fn scan_user(result: &ScanResult) -> Option<&str> {
+    result
+        .metadata
+        .as_ref()
+        .and_then(|m| m.tags.get("userId"))
+        .or(result.scan.external_user_id.as_ref())
+        .map(String::as_str)
+        .filter(|id| !id.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::fixtures::ScanResultBuilder;
+
+    fn scan(app_id: &str, env: &str, ts: &str, user: Option<&str>, secs: u64) -> ScanResult {
+        let builder = ScanResultBuilder::new(format!("{}-{}", app_id, ts), app_id)
+            .env(env)
+            .timestamp(ts)
+            .duration_seconds(secs);
+        match user {
+            Some(user) => builder.external_user_id(user),
+            None => builder,
+        }
+        .build()
+    }
+
+    #[test]
+    fn test_build_monthly_usage() {
+        // 2024-01-15, 2024-01-20, 2024-02-03 (UTC)
+        let scans = vec![
+            scan("app-1", "prod", "1705312800000", Some("u1"), 1800),
+            scan("app-1", "dev", "1705744800000", Some("u2"), 1800),
+            scan("app-2", "prod", "1706954400000", Some("u1"), 5400),
+            scan("app-2", "prod", "not-a-time", None, 60),
+        ];
+
+        let report = UsageReport::build(
+            "Acme",
+            "90d",
+            Quota {
+                used: Some(4),
+                limit: Some(10),
+            },
+            Quota::default(),
+            &scans,
+        );
+
+        let months: Vec<_> = report.months.iter().map(|m| m.month.as_str()).collect();
+        assert_eq!(months, vec!["2024-01", "2024-02"]);
+        let january = &report.months[0];
+        assert_eq!(
+            (january.scans, january.apps_scanned, january.envs_scanned),
+            (2, 1, 2)
+        );
+        assert_eq!((january.active_users, january.scan_hours), (2, 1.0));
+        assert_eq!(report.totals.scans, 3);
+        assert_eq!(report.totals.apps_scanned, 2);
+        assert_eq!(report.totals.active_users, 2);
+        assert_eq!(report.totals.scan_hours, 2.5);
+        assert_eq!(report.rows().last().unwrap().month, "Total");
+    }
+}
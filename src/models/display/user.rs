@@ -1,12 +1,13 @@
 //! User and team display models
 
+use schemars::JsonSchema;
 use serde::Serialize;
 use tabled::Tabled;
 
 use crate::client::models::{Team, User, UserExternal};
 
 /// User/member display model for table/JSON output.
-#[derive(Debug, Clone, Tabled, Serialize)]
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
 pub struct UserDisplay {
     /// User ID
     #[tabled(rename = "USER ID")]
@@ -53,7 +54,68 @@ impl From<UserExternal> for UserDisplay {
             id: user.id,
             email: user.email,
             name,
-            role: "--".to_string(), // Role not available in current API response
+            role: "--".to_string(), // Org-specific; see `UserDisplay::for_org`
+        }
+    }
+}
+
+impl UserDisplay {
+    /// Display a member with their role in `org_id`
+    pub fn for_org(user: &User, org_id: &str) -> Self {
+        let role = user.org_role(org_id).unwrap_or("--").to_string();
+        Self {
+            role,
+            ..Self::from(user)
+        }
+    }
+}
+
+/// Member display model with team memberships, for `user list --teams`.
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct UserTeamsDisplay {
+    /// User ID
+    #[tabled(rename = "USER ID")]
+    pub id: String,
+
+    /// User email
+    #[tabled(rename = "EMAIL")]
+    pub email: String,
+
+    /// User name (first + last)
+    #[tabled(rename = "NAME")]
+    pub name: String,
+
+    /// Role in organization
+    #[tabled(rename = "ROLE")]
+    pub role: String,
+
+    /// Teams with the member's team role, e.g. `Platform (ADMIN), Web`
+    #[tabled(rename = "TEAMS")]
+    pub teams: String,
+}
+
+impl UserTeamsDisplay {
+    /// Combine a member with `(team name, team role)` pairs
+    pub fn new(user: UserDisplay, teams: &[(String, Option<String>)]) -> Self {
+        let mut teams: Vec<String> = teams
+            .iter()
+            .map(|(name, role)| match role {
+                Some(role) => format!("{} ({})", name, role),
+                None => name.clone(),
+            })
+            .collect();
+        teams.sort_by_key(|t| t.to_lowercase());
+
+        Self {
+            id: user.id,
+            email: user.email,
+            name: user.name,
+            role: user.role,
+            teams: if teams.is_empty() {
+                "--".to_string()
+            } else {
+                teams.join(", ")
+            },
         }
     }
 }
@@ -61,7 +123,7 @@ impl From<UserExternal> for UserDisplay {
 /// Team display model for table/JSON output (basic, without counts).
 /// Note: This is kept for potential future use but TeamListDisplay is preferred.
 #[allow(dead_code)]
-#[derive(Debug, Clone, Tabled, Serialize)]
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
 pub struct TeamDisplay {
     /// Team ID
     #[tabled(rename = "TEAM ID")]
@@ -92,7 +154,7 @@ use crate::client::models::TeamDetail;
 /// Team list display model with user and app counts.
 ///
 /// This is used for `team list` output to show membership and assignment counts.
-#[derive(Debug, Clone, Tabled, Serialize)]
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
 pub struct TeamListDisplay {
     /// Team ID
     #[tabled(rename = "TEAM ID")]
@@ -128,6 +190,72 @@ impl From<&TeamDetail> for TeamListDisplay {
     }
 }
 
+use crate::client::models::{TeamApplication, TeamUser};
+
+/// Team member display model for `team get` output.
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct TeamMemberDisplay {
+    /// User ID
+    #[tabled(rename = "USER ID")]
+    pub id: String,
+
+    /// User email
+    #[tabled(rename = "EMAIL")]
+    pub email: String,
+
+    /// User display name
+    #[tabled(rename = "NAME")]
+    pub name: String,
+
+    /// Role in the team
+    #[tabled(rename = "ROLE")]
+    pub role: String,
+}
+
+impl From<&TeamUser> for TeamMemberDisplay {
+    fn from(user: &TeamUser) -> Self {
+        Self {
+            id: user.user_id.clone(),
+            email: user.email.clone().unwrap_or_else(|| "--".to_string()),
+            name: user.user_name.clone().unwrap_or_else(|| "--".to_string()),
+            role: user.role.clone().unwrap_or_else(|| "--".to_string()),
+        }
+    }
+}
+
+/// Team application display model for `team get` output.
+#[derive(Debug, Clone, Tabled, Serialize, JsonSchema)]
+pub struct TeamAppDisplay {
+    /// Application ID
+    #[tabled(rename = "APP ID")]
+    pub id: String,
+
+    /// Application name
+    #[tabled(rename = "NAME")]
+    pub name: String,
+
+    /// Environments, comma-separated
+    #[tabled(rename = "ENVIRONMENTS")]
+    pub environments: String,
+}
+
+impl From<&TeamApplication> for TeamAppDisplay {
+    fn from(app: &TeamApplication) -> Self {
+        Self {
+            id: app.application_id.clone(),
+            name: app
+                .application_name
+                .clone()
+                .unwrap_or_else(|| "--".to_string()),
+            environments: if app.environments.is_empty() {
+                "--".to_string()
+            } else {
+                app.environments.join(", ")
+            },
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -146,6 +274,29 @@ mod tests {
         assert_eq!(display.name, "Security Team");
     }
 
+    #[test]
+    fn test_team_member_and_app_display() {
+        let user = TeamUser {
+            user_id: "user-1".to_string(),
+            user_name: None,
+            email: Some("alice@example.com".to_string()),
+            role: Some("ADMIN".to_string()),
+        };
+        let member = TeamMemberDisplay::from(&user);
+        assert_eq!(member.email, "alice@example.com");
+        assert_eq!(member.name, "--");
+        assert_eq!(member.role, "ADMIN");
+
+        let app = TeamApplication {
+            application_id: "app-1".to_string(),
+            application_name: Some("Web".to_string()),
+            environments: vec!["dev".to_string(), "prod".to_string()],
+        };
+        let display = TeamAppDisplay::from(&app);
+        assert_eq!(display.name, "Web");
+        assert_eq!(display.environments, "dev, prod");
+    }
+
     #[test]
     fn test_user_display_from_user() {
         let user = User {
@@ -155,6 +306,7 @@ mod tests {
                 first_name: Some("John".to_string()),
                 last_name: Some("Doe".to_string()),
                 full_name: Some("John Doe".to_string()),
+                organizations: Vec::new(),
             },
         };
 
@@ -165,6 +317,33 @@ mod tests {
         assert_eq!(display.name, "John Doe");
     }
 
+    #[test]
+    fn test_user_display_for_org_and_teams() {
+        let user: User = serde_json::from_value(serde_json::json!({
+            "external": {
+                "id": "user-1",
+                "email": "a@example.com",
+                "fullName": "Alice",
+                "organizations": [
+                    {"organization": {"id": "org-1", "name": "One"}, "role": "ADMIN"}
+                ]
+            }
+        }))
+        .unwrap();
+
+        let display = UserDisplay::for_org(&user, "org-1");
+        assert_eq!(display.role, "ADMIN");
+        assert_eq!(UserDisplay::for_org(&user, "org-2").role, "--");
+
+        let teams = vec![
+            ("web".to_string(), None),
+            ("Platform".to_string(), Some("MEMBER".to_string())),
+        ];
+        let with_teams = UserTeamsDisplay::new(display.clone(), &teams);
+        assert_eq!(with_teams.teams, "Platform (MEMBER), web");
+        assert_eq!(UserTeamsDisplay::new(display, &[]).teams, "--");
+    }
+
     #[test]
     fn test_user_display_without_full_name() {
         let user = User {
@@ -174,6 +353,7 @@ mod tests {
                 first_name: Some("Jane".to_string()),
                 last_name: Some("Smith".to_string()),
                 full_name: None,
+                organizations: Vec::new(),
             },
         };
 
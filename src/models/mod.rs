@@ -8,6 +8,8 @@ pub mod display;
 #[allow(unused_imports)] // AppDetailDisplay used in Sprint 3: app get/update
 pub use display::{
     AlertDetail, AlertFindingDisplay, AlertMessageDetail, AppDetailDisplay, AppDisplay,
-    AuditDisplay, ConfigDisplay, EnvDisplay, OASDisplay, OrgDisplay, PolicyDisplay,
-    PrettyAlertDisplay, RepoDisplay, ScanDisplay, SecretDisplay, TeamListDisplay, UserDisplay,
+    AuditDisplay, ConfigDisplay, EnvDisplay, HistoryDisplay, OASDisplay, OrgDisplay,
+    OwaspAlertDisplay, PolicyDisplay, PrettyAlertDisplay, RepoDisplay, ScanDisplay, SecretDisplay,
+    SecretUsageDisplay, TeamAppDisplay, TeamListDisplay, TeamMemberDisplay, UserDisplay,
+    UserTeamsDisplay,
 };
@@ -0,0 +1,296 @@
+//! Scan completion notifications
+//!
+//! `--notify` targets are parsed into [`NotifyTarget`]s, and each target has
+//! a [`Notifier`] backend that delivers a [`ScanNotification`]. Desktop
+//! notifications use the platform's notifier command (`notify-send` on
+//! Linux, `osascript` on macOS); Slack notifications post to an incoming
+//! webhook. A failed delivery is reported as a warning and never fails the
+//! command.
+
+use std::str::FromStr;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use log::debug;
+use serde_json::json;
+
+use crate::client::models::ScanResult;
+use crate::error::{Error, Result};
+use crate::output::warnings::{WarningKind, warn};
+
+/// How long a webhook post may take
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Where to send a notification
+#[derive(Clone, PartialEq, Eq)]
+pub enum NotifyTarget {
+    /// Desktop notification on this machine
+    Desktop,
+    /// Slack incoming webhook URL
+    Slack(String),
+}
+
+impl FromStr for NotifyTarget {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("desktop") {
+            return Ok(Self::Desktop);
+        }
+        if let Some(url) = s.strip_prefix("slack:") {
+            if !url.starts_with("https://") && !url.starts_with("http://") {
+                return Err("expected slack:<webhook-url> with an http(s) URL".to_string());
+            }
+            return Ok(Self::Slack(url.to_string()));
+        }
+        Err(format!(
+            "unknown notification target '{}'; use desktop or slack:<webhook-url>",
+            s
+        ))
+    }
+}
+
+// Webhook URLs are credentials; keep them out of debug logs
+impl std::fmt::Debug for NotifyTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Desktop => write!(f, "Desktop"),
+            Self::Slack(_) => write!(f, "Slack(<webhook>)"),
+        }
+    }
+}
+
+impl NotifyTarget {
+    fn notifier(&self) -> Box<dyn Notifier> {
+        match self {
+            Self::Desktop => Box::new(DesktopNotifier),
+            Self::Slack(url) => Box::new(SlackNotifier { url: url.clone() }),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Desktop => "desktop",
+            Self::Slack(_) => "Slack",
+        }
+    }
+}
+
+/// What a notification says about a finished scan
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScanNotification {
+    /// Scan ID
+    pub scan_id: String,
+    /// Application name
+    pub app: String,
+    /// Environment name
+    pub env: String,
+    /// Final scan status, e.g. COMPLETED or ERROR
+    pub status: String,
+    /// Findings by severity: high, medium, low
+    pub findings: [u32; 3],
+}
+
+impl ScanNotification {
+    /// Summarize a finished scan
+    pub fn from_scan(result: &ScanResult) -> Self {
+        let mut findings = [0; 3];
+        let stats = result
+            .alert_stats
+            .iter()
+            .flat_map(|s| &s.alert_status_stats);
+        for (severity, count) in stats.flat_map(|s| &s.severity_stats) {
+            match severity.as_str() {
+                "High" => findings[0] += count,
+                "Medium" => findings[1] += count,
+                "Low" => findings[2] += count,
+                _ => {}
+            }
+        }
+        Self {
+            scan_id: result.scan.id.clone(),
+            app: result.scan.application_name.clone(),
+            env: result.scan.env.clone(),
+            status: result.scan.status.to_uppercase(),
+            findings,
+        }
+    }
+
+    /// Whether the scan completed normally
+    pub fn succeeded(&self) -> bool {
+        self.status == "COMPLETED"
+    }
+
+    /// One-line title, e.g. "StackHawk scan completed: checkout / prod"
+    pub fn title(&self) -> String {
+        let outcome = if self.succeeded() {
+            "completed".to_string()
+        } else {
+            format!("ended with {}", self.status)
+        };
+        format!("StackHawk scan {}: {} / {}", outcome, self.app, self.env)
+    }
+
+    /// Finding counts and scan ID
+    pub fn body(&self) -> String {
+        let [high, medium, low] = self.findings;
+        format!(
+            "High {} | Medium {} | Low {}\nScan {}",
+            high, medium, low, self.scan_id
+        )
+    }
+}
+
+/// A notification backend
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    /// Deliver one notification
+    async fn send(&self, notification: &ScanNotification) -> Result<()>;
+}
+
+/// Desktop notification through the platform's notifier command
+struct DesktopNotifier;
+
+#[async_trait]
+impl Notifier for DesktopNotifier {
+    async fn send(&self, notification: &ScanNotification) -> Result<()> {
+        let title = notification.title();
+        let body = notification.body();
+        let mut command = if cfg!(target_os = "macos") {
+            let script = format!(
+                "display notification {} with title {}",
+                applescript_string(&body),
+                applescript_string(&title)
+            );
+            let mut command = tokio::process::Command::new("osascript");
+            command.arg("-e").arg(script);
+            command
+        } else if cfg!(unix) {
+            let mut command = tokio::process::Command::new("notify-send");
+            command.arg("--app-name=hawkop").arg(&title).arg(&body);
+            command
+        } else {
+            return Err(Error::Other(
+                "desktop notifications are not supported on this platform".to_string(),
+            ));
+        };
+
+        let status = command
+            .status()
+            .await
+            .map_err(|e| Error::Other(format!("cannot run the desktop notifier: {}", e)))?;
+        if !status.success() {
+            return Err(Error::Other(format!(
+                "the desktop notifier exited with {}",
+                status
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Slack incoming webhook
+struct SlackNotifier {
+    url: String,
+}
+
+#[async_trait]
+impl Notifier for SlackNotifier {
+    async fn send(&self, notification: &ScanNotification) -> Result<()> {
+        let emoji = if notification.succeeded() {
+            ":white_check_mark:"
+        } else {
+            ":x:"
+        };
+        let payload = json!({
+            "text": format!("{} *{}*\n{}", emoji, notification.title(), notification.body()),
+        });
+
+        let http = reqwest::Client::builder()
+            .timeout(WEBHOOK_TIMEOUT)
+            .build()
+            .map_err(|e| Error::Other(e.to_string()))?;
+        // Errors are reported without the URL, which carries the webhook secret
+        let response = http
+            .post(&self.url)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| Error::Other(format!("webhook request failed: {}", e.without_url())))?;
+        if !response.status().is_success() {
+            return Err(Error::Other(format!(
+                "webhook returned {}",
+                response.status()
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Send `notification` to every target, warning about failed deliveries
+pub async fn send_all(targets: &[NotifyTarget], notification: &ScanNotification) {
+    for target in targets {
+        debug!(
+            "Sending {:?} notification for {}",
+            target, notification.scan_id
+        );
+        if let Err(e) = target.notifier().send(notification).await {
+            warn(
+                WarningKind::NotificationFailed,
+                format!("Could not send the {} notification: {}", target.name(), e),
+            );
+        }
+    }
+}
+
+/// Quote text as an AppleScript string literal
+fn applescript_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::fixtures::ScanResultBuilder;
+
+    #[test]
+    fn test_parse_targets() {
+        assert_eq!("desktop".parse(), Ok(NotifyTarget::Desktop));
+        assert_eq!(
+            "slack:https://hooks.slack.com/services/T/B/x".parse(),
+            Ok(NotifyTarget::Slack(
+                "https://hooks.slack.com/services/T/B/x".to_string()
+            ))
+        );
+        assert!("slack:hooks.slack.com".parse::<NotifyTarget>().is_err());
+        assert!("email".parse::<NotifyTarget>().is_err());
+        assert_eq!(
+            format!("{:?}", NotifyTarget::Slack("https://secret".to_string())),
+            "Slack(<webhook>)"
+        );
+    }
+
+    #[test]
+    fn test_notification_from_scan() {
+        let result = ScanResultBuilder::new("scan-1", "app-1")
+            .app_name("checkout")
+            .env("prod")
+            .status("ERROR")
+            .with_findings(2, 0, 4)
+            .with_triaged_findings(1, 0, 0)
+            .build();
+
+        let notification = ScanNotification::from_scan(&result);
+
+        assert_eq!(notification.findings, [3, 0, 4]);
+        assert!(!notification.succeeded());
+        assert_eq!(
+            notification.title(),
+            "StackHawk scan ended with ERROR: checkout / prod"
+        );
+        assert_eq!(
+            notification.body(),
+            "High 3 | Medium 0 | Low 4\nScan scan-1"
+        );
+    }
+}
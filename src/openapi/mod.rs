@@ -0,0 +1,326 @@
+//! OpenAPI specification parsing and path-template matching
+//!
+//! Reads the operations of an OpenAPI 3.x or Swagger 2.0 document (YAML or
+//! JSON) and matches request URIs against their path templates, so a finding
+//! on `/users/42/orders` can be attributed to `GET /users/{id}/orders`.
+
+use serde_yaml::Value;
+
+use crate::error::{Error, Result};
+
+/// HTTP methods that may appear as keys of an OpenAPI path item
+const METHODS: &[&str] = &[
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+/// An API operation declared in the spec
+#[derive(Debug, Clone)]
+pub struct Operation {
+    /// HTTP method, uppercase
+    pub method: String,
+
+    /// Path template as written in the spec (e.g. `/users/{id}`)
+    pub path: String,
+
+    /// The operation's `operationId`, if declared
+    pub operation_id: Option<String>,
+
+    segments: Vec<Segment>,
+}
+
+/// One `/`-separated piece of a path template
+#[derive(Debug, Clone)]
+enum Segment {
+    /// Matches only itself
+    Literal(String),
+    /// Literal text around `{param}` placeholders, e.g. `{id}.json` is
+    /// `["", ".json"]`
+    Template(Vec<String>),
+}
+
+impl Segment {
+    fn parse(segment: &str) -> Self {
+        if !segment.contains('{') {
+            return Segment::Literal(segment.to_string());
+        }
+        let mut pieces = Vec::new();
+        let mut rest = segment;
+        while let Some(open) = rest.find('{') {
+            pieces.push(rest[..open].to_string());
+            rest = rest[open..]
+                .find('}')
+                .map_or("", |close| &rest[open + close + 1..]);
+        }
+        pieces.push(rest.to_string());
+        Segment::Template(pieces)
+    }
+
+    fn matches(&self, segment: &str) -> bool {
+        match self {
+            Segment::Literal(literal) => literal == segment,
+            Segment::Template(pieces) => {
+                if segment.is_empty() {
+                    return false;
+                }
+                let (first, rest) = pieces.split_first().expect("at least one piece");
+                let Some(mut remaining) = segment.strip_prefix(first.as_str()) else {
+                    return false;
+                };
+                let (last, middle) = rest.split_last().expect("at least two pieces");
+                for piece in middle {
+                    match remaining.find(piece.as_str()) {
+                        Some(i) if i > 0 => remaining = &remaining[i + piece.len()..],
+                        _ => return false,
+                    }
+                }
+                remaining.len() > last.len() && remaining.ends_with(last.as_str())
+            }
+        }
+    }
+}
+
+/// The operations of a parsed OpenAPI document
+#[derive(Debug, Clone)]
+pub struct OpenApiSpec {
+    /// `info.title`, if present
+    pub title: Option<String>,
+
+    /// Path prefixes from `servers` (OpenAPI 3) or `basePath` (Swagger 2)
+    pub base_paths: Vec<String>,
+
+    /// Operations in document order
+    pub operations: Vec<Operation>,
+}
+
+impl OpenApiSpec {
+    /// Read and parse a spec file
+    pub fn load(path: &str) -> Result<Self> {
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| Error::Other(format!("Cannot read {}: {}", path, e)))?;
+        Self::parse(&source)
+    }
+
+    /// Parse a YAML or JSON OpenAPI document
+    pub fn parse(source: &str) -> Result<Self> {
+        let root: Value = serde_yaml::from_str(source)
+            .map_err(|e| Error::Other(format!("Invalid OpenAPI document: {}", e)))?;
+        if root.get("openapi").is_none() && root.get("swagger").is_none() {
+            return Err(Error::Other(
+                "Not an OpenAPI document: missing the `openapi` or `swagger` version field"
+                    .to_string(),
+            ));
+        }
+
+        let title = root
+            .get("info")
+            .and_then(|info| info.get("title"))
+            .and_then(Value::as_str)
+            .map(str::to_string);
+
+        let mut base_paths: Vec<String> = Vec::new();
+        let server_urls = root
+            .get("servers")
+            .and_then(Value::as_sequence)
+            .into_iter()
+            .flatten()
+            .filter_map(|server| server.get("url").and_then(Value::as_str));
+        for url in server_urls.chain(root.get("basePath").and_then(Value::as_str)) {
+            if let Some(base) = base_path(url)
+                && !base_paths.contains(&base)
+            {
+                base_paths.push(base);
+            }
+        }
+
+        let mut operations = Vec::new();
+        let paths = root.get("paths").and_then(Value::as_mapping);
+        for (path, item) in paths.into_iter().flatten() {
+            let (Some(path), Some(item)) = (path.as_str(), item.as_mapping()) else {
+                continue;
+            };
+            let segments: Vec<Segment> = split_path(path).map(Segment::parse).collect();
+            for (method, operation) in item {
+                let Some(method) = method.as_str().filter(|m| METHODS.contains(m)) else {
+                    continue;
+                };
+                operations.push(Operation {
+                    method: method.to_uppercase(),
+                    path: path.to_string(),
+                    operation_id: operation
+                        .get("operationId")
+                        .and_then(Value::as_str)
+                        .map(str::to_string),
+                    segments: segments.clone(),
+                });
+            }
+        }
+        if operations.is_empty() {
+            return Err(Error::Other(
+                "The OpenAPI document declares no operations under `paths`".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            title,
+            base_paths,
+            operations,
+        })
+    }
+
+    /// The operation a request was made against, if the spec declares one
+    ///
+    /// `uri` may be a full URL or a path; the query string and any server
+    /// base path are ignored. When several templates match, the one with the
+    /// most literal segments wins (`/users/me` before `/users/{id}`). An
+    /// empty `method` matches any method.
+    pub fn match_uri(&self, method: &str, uri: &str) -> Option<&Operation> {
+        let path = request_path(uri);
+        let mut candidates = vec![path];
+        for base in &self.base_paths {
+            if let Some(rest) = path.strip_prefix(base.as_str())
+                && (rest.is_empty() || rest.starts_with('/'))
+            {
+                candidates.push(rest);
+            }
+        }
+
+        let mut best: Option<(&Operation, usize)> = None;
+        for candidate in candidates {
+            let segments: Vec<&str> = split_path(candidate).collect();
+            for op in &self.operations {
+                if !method.is_empty() && !op.method.eq_ignore_ascii_case(method) {
+                    continue;
+                }
+                if op.segments.len() != segments.len()
+                    || !op.segments.iter().zip(&segments).all(|(s, v)| s.matches(v))
+                {
+                    continue;
+                }
+                let literals = op
+                    .segments
+                    .iter()
+                    .filter(|s| matches!(s, Segment::Literal(_)))
+                    .count();
+                if best.is_none_or(|(_, most)| literals > most) {
+                    best = Some((op, literals));
+                }
+            }
+        }
+        best.map(|(op, _)| op)
+    }
+}
+
+/// Non-empty `/`-separated segments of a path
+fn split_path(path: &str) -> impl Iterator<Item = &str> {
+    path.split('/').filter(|s| !s.is_empty())
+}
+
+/// The path of a request URI, without scheme, host, query, or fragment
+fn request_path(uri: &str) -> &str {
+    let uri = uri.split(['?', '#']).next().unwrap_or_default();
+    match uri.split_once("://") {
+        Some((_, rest)) => rest.find('/').map_or("/", |i| &rest[i..]),
+        None => uri,
+    }
+}
+
+/// The path prefix of a server URL, or `None` for the root or a templated path
+fn base_path(url: &str) -> Option<String> {
+    let path = request_path(url).trim_end_matches('/');
+    if path.is_empty() || path.contains('{') {
+        return None;
+    }
+    Some(path.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: &str = r#"
+openapi: 3.0.3
+info:
+  title: Shop API
+servers:
+  - url: https://api.example.com/v1
+paths:
+  /users:
+    get:
+      operationId: listUsers
+    post:
+      operationId: createUser
+  /users/{id}:
+    parameters:
+      - name: id
+        in: path
+    get:
+      operationId: getUser
+  /users/me:
+    get:
+      operationId: getCurrentUser
+  /files/{name}.json:
+    get: {}
+"#;
+
+    #[test]
+    fn test_parse_operations() {
+        let spec = OpenApiSpec::parse(SPEC).unwrap();
+        assert_eq!(spec.title.as_deref(), Some("Shop API"));
+        assert_eq!(spec.base_paths, vec!["/v1"]);
+        let ops: Vec<_> = spec
+            .operations
+            .iter()
+            .map(|o| format!("{} {}", o.method, o.path))
+            .collect();
+        assert_eq!(
+            ops,
+            vec![
+                "GET /users",
+                "POST /users",
+                "GET /users/{id}",
+                "GET /users/me",
+                "GET /files/{name}.json"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_match_uri() {
+        let spec = OpenApiSpec::parse(SPEC).unwrap();
+        let id = |method: &str, uri: &str| {
+            spec.match_uri(method, uri)
+                .map(|o| o.operation_id.clone().unwrap_or_else(|| o.path.clone()))
+        };
+        assert_eq!(id("GET", "/v1/users?page=2").as_deref(), Some("listUsers"));
+        assert_eq!(
+            id("post", "https://api.example.com/v1/users/").as_deref(),
+            Some("createUser")
+        );
+        assert_eq!(id("GET", "/users/42").as_deref(), Some("getUser"));
+        assert_eq!(id("GET", "/v1/users/me").as_deref(), Some("getCurrentUser"));
+        assert_eq!(
+            id("GET", "/files/report.json").as_deref(),
+            Some("/files/{name}.json")
+        );
+        assert_eq!(id("DELETE", "/users/42"), None);
+        assert_eq!(id("GET", "/files/.json"), None);
+        assert_eq!(id("GET", "/admin"), None);
+    }
+
+    #[test]
+    fn test_swagger2_base_path() {
+        let spec = OpenApiSpec::parse(
+            r#"{"swagger": "2.0", "basePath": "/api", "paths": {"/pets/{petId}": {"get": {"operationId": "getPet"}}}}"#,
+        )
+        .unwrap();
+        let op = spec.match_uri("GET", "/api/pets/7").unwrap();
+        assert_eq!(op.operation_id.as_deref(), Some("getPet"));
+    }
+
+    #[test]
+    fn test_parse_rejects_other_documents() {
+        assert!(OpenApiSpec::parse("app:\n  env: dev\n").is_err());
+        assert!(OpenApiSpec::parse("openapi: 3.0.0\npaths: {}\n").is_err());
+        assert!(OpenApiSpec::parse("openapi: [").is_err());
+    }
+}
@@ -0,0 +1,204 @@
+//! HTTP message body decoding for display
+//!
+//! Scan messages carry bodies as captured on the wire. This decodes base64
+//! and gzip bodies when the headers say so and re-indents JSON, falling back
+//! to the original text whenever a step fails.
+
+use std::borrow::Cow;
+use std::io::Read;
+
+use base64::{Engine as _, engine::general_purpose};
+use flate2::read::GzDecoder;
+
+/// Gzip stream magic bytes
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Decode and pretty-print a message body for display
+///
+/// `headers` is the raw header block of the same request or response.
+pub fn decode_body(headers: Option<&str>, body: &str) -> String {
+    let text = decode_body_text(headers, body);
+    let content_type = header_value(headers.unwrap_or(""), "content-type")
+        .unwrap_or("")
+        .to_ascii_lowercase();
+
+    let looks_like_json = matches!(text.trim_start().chars().next(), Some('{' | '['));
+    if (content_type.contains("json") || looks_like_json)
+        && let Some(pretty) = pretty_json(&text)
+    {
+        return pretty;
+    }
+
+    text
+}
+
+/// Undo the base64 and gzip encodings the headers declare
+///
+/// Returns the body unchanged when it is not encoded or fails to decode.
+pub fn decode_body_text(headers: Option<&str>, body: &str) -> String {
+    let headers = headers.unwrap_or("");
+    let content_encoding = header_value(headers, "content-encoding")
+        .unwrap_or("")
+        .to_ascii_lowercase();
+    let transfer_encoding = header_value(headers, "content-transfer-encoding")
+        .unwrap_or("")
+        .to_ascii_lowercase();
+
+    let mut text: Cow<str> = Cow::Borrowed(body);
+
+    if (transfer_encoding.contains("base64") || content_encoding.contains("base64"))
+        && let Some(bytes) = decode_base64(&text)
+    {
+        text = Cow::Owned(String::from_utf8_lossy(&bytes).into_owned());
+    }
+
+    if content_encoding.contains("gzip")
+        && let Some(decoded) = gunzip_text(&text)
+    {
+        text = Cow::Owned(decoded);
+    }
+
+    text.into_owned()
+}
+
+/// Value of the first header named `name` (case-insensitive)
+pub fn header_value<'a>(headers: &'a str, name: &str) -> Option<&'a str> {
+    headers.lines().find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        key.trim()
+            .eq_ignore_ascii_case(name)
+            .then_some(value.trim())
+    })
+}
+
+/// Decode standard base64, ignoring whitespace and line breaks
+fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    let compact: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+    general_purpose::STANDARD.decode(compact).ok()
+}
+
+/// Gunzip a body captured either as base64 or as one char per byte
+fn gunzip_text(text: &str) -> Option<String> {
+    let candidates = [
+        // Bytes stored as Latin-1 chars
+        text.chars()
+            .map(|c| u8::try_from(u32::from(c)).ok())
+            .collect::<Option<Vec<u8>>>(),
+        decode_base64(text),
+    ];
+
+    candidates
+        .into_iter()
+        .flatten()
+        .filter(|bytes| bytes.starts_with(&GZIP_MAGIC))
+        .find_map(|bytes| {
+            let mut out = Vec::new();
+            GzDecoder::new(bytes.as_slice())
+                .read_to_end(&mut out)
+                .ok()?;
+            Some(String::from_utf8_lossy(&out).into_owned())
+        })
+}
+
+/// Re-indent valid JSON, keeping key order and number formatting as sent
+fn pretty_json(text: &str) -> Option<String> {
+    serde_json::from_str::<serde::de::IgnoredAny>(text).ok()?;
+
+    let mut out = String::with_capacity(text.len() * 2);
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut chars = text.trim().chars().peekable();
+
+    let newline = |out: &mut String, depth: usize| {
+        out.push('\n');
+        out.push_str(&"  ".repeat(depth));
+    };
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match c {
+            '"' => {
+                in_string = true;
+                out.push(c);
+            }
+            '{' | '[' => {
+                out.push(c);
+                // Keep empty containers on one line
+                while chars.peek().is_some_and(|n| n.is_whitespace()) {
+                    chars.next();
+                }
+                if matches!(chars.peek(), Some('}' | ']')) {
+                    out.push(chars.next().unwrap_or_default());
+                } else {
+                    depth += 1;
+                    newline(&mut out, depth);
+                }
+            }
+            '}' | ']' => {
+                depth = depth.saturating_sub(1);
+                newline(&mut out, depth);
+                out.push(c);
+            }
+            ',' => {
+                out.push(c);
+                newline(&mut out, depth);
+            }
+            ':' => out.push_str(": "),
+            c if c.is_whitespace() => {}
+            c => out.push(c),
+        }
+    }
+
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::Compression;
+    use flate2::write::GzEncoder;
+    use std::io::Write;
+
+    #[test]
+    fn test_pretty_prints_json_in_order() {
+        let headers = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n";
+        let body = r#"{"z":1,"a":{"s":"x, {y}: \"q\""},"e":[],"n":[1.50,2]}"#;
+
+        assert_eq!(
+            decode_body(Some(headers), body),
+            "{\n  \"z\": 1,\n  \"a\": {\n    \"s\": \"x, {y}: \\\"q\\\"\"\n  },\n  \"e\": [],\n  \"n\": [\n    1.50,\n    2\n  ]\n}"
+        );
+    }
+
+    #[test]
+    fn test_decodes_base64_gzip() {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(br#"{"ok":true}"#).unwrap();
+        let body = general_purpose::STANDARD.encode(encoder.finish().unwrap());
+        let headers = "Content-Encoding: gzip\nContent-Type: application/json; charset=utf-8";
+
+        assert_eq!(decode_body(Some(headers), &body), "{\n  \"ok\": true\n}");
+    }
+
+    #[test]
+    fn test_leaves_other_bodies_alone() {
+        assert_eq!(decode_body(None, "<html>{x}</html>"), "<html>{x}</html>");
+        assert_eq!(decode_body(None, "{not json"), "{not json");
+        assert_eq!(
+            decode_body(Some("Content-Encoding: gzip"), "plain text"),
+            "plain text"
+        );
+    }
+}
@@ -0,0 +1,321 @@
+//! Column selection for list output (`--columns`, `--wide`, `--ids-only`)
+//!
+//! Display models describe their columns through two sources: the `Tabled`
+//! headers shown by default, and the serialized (JSON) fields, which include
+//! values the default table leaves out. A selection picks columns from
+//! either, so any field in the JSON output can be shown in a table.
+
+use std::sync::RwLock;
+
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::error::{Error, Result};
+
+/// Process-wide selection set from the global flags
+static SELECTION: RwLock<Option<ColumnSelection>> = RwLock::new(None);
+
+/// Which columns list output should show
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColumnSelection {
+    /// Only these columns, in this order
+    Columns(Vec<String>),
+    /// Default columns followed by every other serialized field
+    Wide,
+    /// Only each row's identifier, one per line (any output format)
+    Ids,
+}
+
+/// Set the selection for this process (from `--columns` / `--wide`)
+pub fn set_selection(selection: ColumnSelection) {
+    *SELECTION.write().unwrap_or_else(|e| e.into_inner()) = Some(selection);
+}
+
+/// The active selection, if any
+pub fn selection() -> Option<ColumnSelection> {
+    SELECTION.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Whether `--ids-only` is active
+pub fn ids_only() -> bool {
+    selection() == Some(ColumnSelection::Ids)
+}
+
+/// Column metadata for a display model
+struct ColumnSource {
+    /// Normalized `Tabled` headers, in table order
+    headers: Vec<String>,
+    /// Serialized field names (alphabetical, as `serde_json` orders them)
+    fields: Vec<String>,
+}
+
+/// A resolved column: a table column index or a serialized field name
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Column {
+    Table(usize),
+    Field(String),
+}
+
+/// Headers and rows for `data` under `selection`
+///
+/// Column names are matched case-insensitively with spaces and dashes
+/// treated as underscores (`scan-id`, `SCAN ID`, and `scan_id` are the same).
+/// A name that is the last word of exactly one table header also matches it,
+/// so `id` selects `SCAN ID`; serialized fields are matched last.
+pub fn select<T: Tabled + Serialize>(
+    data: &[T],
+    selection: &ColumnSelection,
+) -> Result<(Vec<String>, Vec<Vec<String>>)> {
+    let (source, values) = ColumnSource::new(data)?;
+
+    let columns = match selection {
+        ColumnSelection::Columns(names) => names
+            .iter()
+            .map(|name| source.resolve(name))
+            .collect::<Result<Vec<_>>>()?,
+        ColumnSelection::Wide => source.wide(),
+        ColumnSelection::Ids => vec![source.id()?],
+    };
+
+    let headers = columns
+        .iter()
+        .map(|c| match c {
+            Column::Table(i) => source.headers[*i].replace('_', " "),
+            Column::Field(name) => normalize(name).replace('_', " "),
+        })
+        .collect();
+
+    Ok((headers, rows(data, &values, &columns)))
+}
+
+/// Each row's identifier for `--ids-only`
+///
+/// The identifier is an `ID` column, else the first `... ID` column (the
+/// row's own ID by convention, e.g. `SCAN ID` before `APP ID`), else an
+/// `id` or `name` field.
+pub fn ids<T: Tabled + Serialize>(data: &[T]) -> Result<Vec<String>> {
+    let (source, values) = ColumnSource::new(data)?;
+    let column = source.id()?;
+    Ok(rows(data, &values, &[column])
+        .into_iter()
+        .flatten()
+        .collect())
+}
+
+fn rows<T: Tabled>(
+    data: &[T],
+    values: &[serde_json::Value],
+    columns: &[Column],
+) -> Vec<Vec<String>> {
+    data.iter()
+        .zip(values)
+        .map(|(item, value)| {
+            let fields = item.fields();
+            columns
+                .iter()
+                .map(|c| match c {
+                    Column::Table(i) => fields[*i].to_string(),
+                    Column::Field(name) => field_text(value.get(name)),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+impl ColumnSource {
+    fn new<T: Tabled + Serialize>(data: &[T]) -> Result<(Self, Vec<serde_json::Value>)> {
+        let values: Vec<serde_json::Value> = data
+            .iter()
+            .map(serde_json::to_value)
+            .collect::<std::result::Result<_, _>>()?;
+
+        let source = Self {
+            headers: T::headers().iter().map(|h| normalize(h)).collect(),
+            fields: values
+                .iter()
+                .find_map(|v| v.as_object())
+                .map(|obj| obj.keys().cloned().collect())
+                .unwrap_or_default(),
+        };
+        Ok((source, values))
+    }
+
+    fn id(&self) -> Result<Column> {
+        if let Some(i) = self
+            .headers
+            .iter()
+            .position(|h| h == "id" || h.ends_with("_id"))
+        {
+            return Ok(Column::Table(i));
+        }
+        for name in ["id", "name"] {
+            if let Some(field) = self.fields.iter().find(|f| f.as_str() == name) {
+                return Ok(Column::Field(field.clone()));
+            }
+        }
+        Err(Error::Other(
+            "--ids-only is not supported for this output (no ID column)".to_string(),
+        ))
+    }
+
+    fn resolve(&self, name: &str) -> Result<Column> {
+        let wanted = normalize(name);
+
+        if let Some(i) = self.headers.iter().position(|h| *h == wanted) {
+            return Ok(Column::Table(i));
+        }
+        let suffix = format!("_{}", wanted);
+        let suffixed: Vec<usize> = (0..self.headers.len())
+            .filter(|&i| self.headers[i].ends_with(&suffix))
+            .collect();
+        if let [i] = suffixed[..] {
+            return Ok(Column::Table(i));
+        }
+        if let Some(field) = self.fields.iter().find(|f| normalize(f) == wanted) {
+            return Ok(Column::Field(field.clone()));
+        }
+
+        Err(Error::Other(format!(
+            "Unknown column '{}'. Available: {}",
+            name,
+            self.available().join(", ")
+        )))
+    }
+
+    /// Table columns, then serialized fields not already shown
+    fn wide(&self) -> Vec<Column> {
+        let mut columns: Vec<Column> = (0..self.headers.len()).map(Column::Table).collect();
+        for field in &self.fields {
+            let name = normalize(field);
+            let shown = self
+                .headers
+                .iter()
+                .any(|h| *h == name || h.ends_with(&format!("_{}", name)));
+            if !shown {
+                columns.push(Column::Field(field.clone()));
+            }
+        }
+        columns
+    }
+
+    fn available(&self) -> Vec<String> {
+        let mut names = self.headers.clone();
+        for field in &self.fields {
+            let name = normalize(field);
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        names
+    }
+}
+
+/// Lowercase with spaces and dashes as underscores; camelCase is split
+fn normalize(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    let mut prev_lower = false;
+    for c in name.trim().chars() {
+        if c == ' ' || c == '-' {
+            out.push('_');
+            prev_lower = false;
+        } else if c.is_uppercase() {
+            if prev_lower {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+            prev_lower = false;
+        } else {
+            out.push(c);
+            prev_lower = c.is_lowercase() || c.is_ascii_digit();
+        }
+    }
+    out
+}
+
+/// Render a serialized field value as table text
+fn field_text(value: Option<&serde_json::Value>) -> String {
+    match value {
+        None | Some(serde_json::Value::Null) => "--".to_string(),
+        Some(serde_json::Value::String(s)) => s.clone(),
+        Some(serde_json::Value::Array(items)) if items.iter().all(|v| v.is_string()) => items
+            .iter()
+            .filter_map(|v| v.as_str())
+            .collect::<Vec<_>>()
+            .join(", "),
+        Some(other) => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Tabled, Serialize)]
+    struct Row {
+        #[tabled(rename = "SCAN ID")]
+        id: String,
+        #[tabled(rename = "APP")]
+        app: String,
+        #[tabled(rename = "STATUS")]
+        status: String,
+        #[tabled(skip)]
+        previous_scan_id: Option<String>,
+        #[tabled(skip)]
+        tags: Vec<String>,
+    }
+
+    fn rows() -> Vec<Row> {
+        vec![Row {
+            id: "s1".to_string(),
+            app: "web".to_string(),
+            status: "Complete".to_string(),
+            previous_scan_id: None,
+            tags: vec!["a".to_string(), "b".to_string()],
+        }]
+    }
+
+    #[test]
+    fn test_select_columns_by_header_field_and_suffix() {
+        let selection = ColumnSelection::Columns(vec![
+            "status".to_string(),
+            "id".to_string(),
+            "tags".to_string(),
+        ]);
+        let (headers, rows) = select(&rows(), &selection).unwrap();
+
+        assert_eq!(headers, vec!["status", "scan id", "tags"]);
+        assert_eq!(rows[0], vec!["Complete", "s1", "a, b"]);
+    }
+
+    #[test]
+    fn test_select_unknown_column_lists_available() {
+        let selection = ColumnSelection::Columns(vec!["nope".to_string()]);
+        let err = select(&rows(), &selection).unwrap_err().to_string();
+
+        assert!(err.contains("Unknown column 'nope'"));
+        assert!(err.contains("previous_scan_id"));
+    }
+
+    #[test]
+    fn test_wide_appends_hidden_fields() {
+        let (headers, rows) = select(&rows(), &ColumnSelection::Wide).unwrap();
+
+        assert_eq!(
+            headers,
+            vec!["scan id", "app", "status", "previous scan id", "tags"]
+        );
+        assert_eq!(rows[0][3], "--");
+    }
+
+    #[test]
+    fn test_ids_use_first_id_column() {
+        assert_eq!(ids(&rows()).unwrap(), vec!["s1"]);
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("SCAN ID"), "scan_id");
+        assert_eq!(normalize("scan-id"), "scan_id");
+        assert_eq!(normalize("applicationName"), "application_name");
+    }
+}
@@ -0,0 +1,167 @@
+//! CSV output formatting
+//!
+//! Uses the same column headers as the table output (from `Tabled`), so a
+//! CSV export always matches what the user sees on screen. [`parse_csv`]
+//! reads CSV input for bulk commands.
+
+use tabled::Tabled;
+
+/// Format data as RFC 4180 CSV with a header row
+pub fn format_csv<T: Tabled>(data: &[T]) -> String {
+    let mut out = String::new();
+    push_record(&mut out, T::headers().iter().map(|h| h.as_ref()));
+    for item in data {
+        push_record(&mut out, item.fields().iter().map(|f| f.as_ref()));
+    }
+    out
+}
+
+fn push_record<'a>(out: &mut String, fields: impl Iterator<Item = &'a str>) {
+    let record: Vec<String> = fields.map(escape_field).collect();
+    out.push_str(&record.join(","));
+    out.push_str("\r\n");
+}
+
+/// Quote a field if it contains a delimiter, quote, or line break
+fn escape_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// One parsed CSV record and the line it starts on (1-based)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvRecord {
+    pub line: usize,
+    pub fields: Vec<String>,
+}
+
+/// Parse RFC 4180 CSV, skipping blank lines
+///
+/// Quoted fields may contain commas, doubled quotes, and line breaks.
+/// Unquoted fields are trimmed.
+pub fn parse_csv(text: &str) -> std::result::Result<Vec<CsvRecord>, String> {
+    let mut records = Vec::new();
+    let mut fields: Vec<String> = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut was_quoted = false;
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut chars = text.chars().peekable();
+
+    let end_field = |field: &mut String, was_quoted: &mut bool, fields: &mut Vec<String>| {
+        let value = std::mem::take(field);
+        fields.push(if *was_quoted {
+            value
+        } else {
+            value.trim().to_string()
+        });
+        *was_quoted = false;
+    };
+
+    while let Some(c) = chars.next() {
+        if quoted {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => quoted = false,
+                c => {
+                    if c == '\n' {
+                        line += 1;
+                    }
+                    field.push(c);
+                }
+            }
+            continue;
+        }
+        match c {
+            '"' if field.trim().is_empty() => {
+                field.clear();
+                quoted = true;
+                was_quoted = true;
+            }
+            ',' => end_field(&mut field, &mut was_quoted, &mut fields),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                end_field(&mut field, &mut was_quoted, &mut fields);
+                let record = std::mem::take(&mut fields);
+                if !(record.len() == 1 && record[0].is_empty()) {
+                    records.push(CsvRecord {
+                        line: record_line,
+                        fields: record,
+                    });
+                }
+                line += 1;
+                record_line = line;
+            }
+            // Whitespace after a closing quote
+            c if was_quoted && c.is_whitespace() => {}
+            c => field.push(c),
+        }
+    }
+
+    if quoted {
+        return Err(format!("line {}: unterminated quoted field", record_line));
+    }
+    if !field.is_empty() || was_quoted || !fields.is_empty() {
+        end_field(&mut field, &mut was_quoted, &mut fields);
+        if !(fields.len() == 1 && fields[0].is_empty()) {
+            records.push(CsvRecord {
+                line: record_line,
+                fields,
+            });
+        }
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Tabled)]
+    struct TestRow {
+        #[tabled(rename = "ID")]
+        id: String,
+        #[tabled(rename = "NAME")]
+        name: String,
+    }
+
+    #[test]
+    fn test_format_csv_header_only_when_empty() {
+        let items: Vec<TestRow> = vec![];
+        assert_eq!(format_csv(&items), "ID,NAME\r\n");
+    }
+
+    #[test]
+    fn test_format_csv_escapes_fields() {
+        let items = vec![TestRow {
+            id: "1".to_string(),
+            name: "Team \"A\", West".to_string(),
+        }];
+        assert_eq!(
+            format_csv(&items),
+            "ID,NAME\r\n1,\"Team \"\"A\"\", West\"\r\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_csv_quotes_and_blank_lines() {
+        let records =
+            parse_csv("a, b ,c\r\n\n\"x,1\",\"say \"\"hi\"\"\",\"two\nlines\"\nlast,,").unwrap();
+
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].fields, vec!["a", "b", "c"]);
+        assert_eq!(records[1].line, 3);
+        assert_eq!(records[1].fields, vec!["x,1", "say \"hi\"", "two\nlines"]);
+        assert_eq!(records[2].line, 5);
+        assert_eq!(records[2].fields, vec!["last", "", ""]);
+
+        assert!(parse_csv("a,\"open").is_err());
+    }
+}
@@ -0,0 +1,64 @@
+//! Line diffs of text files, e.g. scan configurations
+//!
+//! Diffs are unified (`---`/`+++` headers, `@@` hunks with three lines of
+//! context) and colored by line: additions green, removals red, hunk headers
+//! cyan. Color follows the `colored` settings, so piped output is plain.
+
+use colored::Colorize;
+use similar::TextDiff;
+
+/// Unified diff from `old` to `new`, or `None` when they are the same
+pub fn unified(old: &str, new: &str, old_label: &str, new_label: &str) -> Option<String> {
+    plain(old, new, old_label, new_label).map(|text| colorize(&text))
+}
+
+fn plain(old: &str, new: &str, old_label: &str, new_label: &str) -> Option<String> {
+    if old == new {
+        return None;
+    }
+    let diff = TextDiff::from_lines(old, new);
+    Some(
+        diff.unified_diff()
+            .context_radius(3)
+            .header(old_label, new_label)
+            .to_string(),
+    )
+}
+
+/// Color each line of a unified diff by its prefix
+fn colorize(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for line in text.lines() {
+        let styled = if line.starts_with("+++") || line.starts_with("---") {
+            line.bold().to_string()
+        } else if line.starts_with("@@") {
+            line.cyan().to_string()
+        } else if line.starts_with('+') {
+            line.green().to_string()
+        } else if line.starts_with('-') {
+            line.red().to_string()
+        } else {
+            line.to_string()
+        };
+        out.push_str(&styled);
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unified_diff() {
+        let old = "app:\n  env: dev\n  host: a\n";
+        let new = "app:\n  env: prod\n  host: a\n";
+
+        assert_eq!(plain(old, old, "a", "b"), None);
+        assert_eq!(
+            plain(old, new, "base", "local").unwrap(),
+            "--- base\n+++ local\n@@ -1,3 +1,3 @@\n app:\n-  env: dev\n+  env: prod\n   host: a\n"
+        );
+    }
+}
@@ -0,0 +1,304 @@
+//! HAR 1.2 output for scan HTTP messages
+//!
+//! Converts a finding's captured request/response into an HTTP Archive log
+//! that browsers' dev tools, Burp, and other proxies can import and replay.
+//! Header blocks are parsed from their raw text; bodies are stored decoded,
+//! as HAR expects, while the original headers are kept as captured.
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde_json::{Value, json};
+
+use crate::client::models::AlertMsgResponse;
+use crate::output::body::{decode_body_text, header_value};
+
+/// A finding's HTTP message plus the context used to describe it
+pub struct HarMessage<'a> {
+    pub message: &'a AlertMsgResponse,
+    /// Scan start time (Unix epoch milliseconds), used as `startedDateTime`
+    pub timestamp: &'a str,
+    /// Base URL for request lines that carry only a path
+    pub host: Option<&'a str>,
+    /// Free-text note stored as the entry's `comment`
+    pub comment: String,
+}
+
+/// Build a HAR log with one entry per message
+pub fn from_messages(messages: &[HarMessage]) -> Value {
+    json!({
+        "log": {
+            "version": "1.2",
+            "creator": {
+                "name": "hawkop",
+                "version": env!("CARGO_PKG_VERSION"),
+            },
+            "entries": messages.iter().map(entry).collect::<Vec<_>>(),
+        }
+    })
+}
+
+fn entry(msg: &HarMessage) -> Value {
+    let scan_message = &msg.message.scan_message;
+    let request_header = scan_message.request_header.as_deref().unwrap_or("");
+    let response_header = scan_message.response_header.as_deref().unwrap_or("");
+
+    let request = HeaderBlock::parse(request_header);
+    let response = HeaderBlock::parse(response_header);
+
+    // Request line: METHOD URL VERSION
+    let mut request_line = request.start_line.split_whitespace();
+    let method = request_line.next().unwrap_or("GET");
+    let target = request_line
+        .next()
+        .unwrap_or(msg.message.uri.as_str())
+        .to_string();
+    let request_version = request_line.next().unwrap_or("HTTP/1.1");
+    let url = absolute_url(&target, &request, msg.host);
+
+    // Status line: VERSION CODE REASON
+    let mut status_line = response.start_line.splitn(3, ' ');
+    let response_version = status_line.next().filter(|v| !v.is_empty());
+    let status: i64 = status_line
+        .next()
+        .and_then(|code| code.parse().ok())
+        .unwrap_or(0);
+    let status_text = status_line.next().unwrap_or("").trim();
+
+    let request_body = scan_message.request_body.as_deref().unwrap_or("");
+    let response_body = decode_body_text(
+        scan_message.response_header.as_deref(),
+        scan_message.response_body.as_deref().unwrap_or(""),
+    );
+
+    let mut har_request = json!({
+        "method": method,
+        "url": url,
+        "httpVersion": request_version,
+        "cookies": request_cookies(&request),
+        "headers": request.headers_json(),
+        "queryString": query_string(&url),
+        "headersSize": header_size(request_header),
+        "bodySize": request_body.len(),
+    });
+    if !request_body.is_empty() {
+        har_request["postData"] = json!({
+            "mimeType": header_value(request_header, "content-type").unwrap_or(""),
+            "text": request_body,
+        });
+    }
+
+    json!({
+        "startedDateTime": started_date_time(msg.timestamp),
+        "time": 0,
+        "comment": msg.comment,
+        "request": har_request,
+        "response": {
+            "status": status,
+            "statusText": status_text,
+            "httpVersion": response_version.unwrap_or("HTTP/1.1"),
+            "cookies": response_cookies(&response),
+            "headers": response.headers_json(),
+            "content": {
+                "size": response_body.len(),
+                "mimeType": header_value(response_header, "content-type").unwrap_or(""),
+                "text": response_body,
+            },
+            "redirectURL": header_value(response_header, "location").unwrap_or(""),
+            "headersSize": header_size(response_header),
+            "bodySize": scan_message.response_body.as_deref().map_or(0, str::len),
+        },
+        "cache": {},
+        "timings": {"send": 0, "wait": 0, "receive": 0},
+    })
+}
+
+/// A raw header block split into its start line and name/value pairs
+struct HeaderBlock<'a> {
+    start_line: &'a str,
+    headers: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> HeaderBlock<'a> {
+    fn parse(block: &'a str) -> Self {
+        let mut lines = block.lines().map(str::trim).filter(|l| !l.is_empty());
+        let start_line = lines.next().unwrap_or("");
+        let headers = lines
+            .filter_map(|line| {
+                let (name, value) = line.split_once(':')?;
+                Some((name.trim(), value.trim()))
+            })
+            .collect();
+        Self {
+            start_line,
+            headers,
+        }
+    }
+
+    fn values(&self, name: &str) -> impl Iterator<Item = &'a str> {
+        self.headers
+            .iter()
+            .filter(move |(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| *v)
+    }
+
+    fn headers_json(&self) -> Vec<Value> {
+        self.headers
+            .iter()
+            .map(|(name, value)| json!({"name": name, "value": value}))
+            .collect()
+    }
+}
+
+/// Resolve a request-line target against the Host header or app host
+fn absolute_url(target: &str, request: &HeaderBlock, host: Option<&str>) -> String {
+    if target.starts_with("http://") || target.starts_with("https://") {
+        return target.to_string();
+    }
+    let base = match (request.values("host").next(), host) {
+        (_, Some(app_host)) => app_host.trim_end_matches('/').to_string(),
+        (Some(header_host), None) => format!("http://{}", header_host),
+        (None, None) => String::new(),
+    };
+    format!("{}{}", base, target)
+}
+
+fn query_string(url: &str) -> Vec<Value> {
+    let Some((_, query)) = url.split_once('?') else {
+        return Vec::new();
+    };
+    let query = query.split('#').next().unwrap_or("");
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            json!({"name": name, "value": value})
+        })
+        .collect()
+}
+
+/// Cookies sent in `Cookie` headers (`a=1; b=2`)
+fn request_cookies(request: &HeaderBlock) -> Vec<Value> {
+    request
+        .values("cookie")
+        .flat_map(|value| value.split(';'))
+        .filter_map(|pair| {
+            let (name, value) = pair.trim().split_once('=')?;
+            Some(json!({"name": name, "value": value}))
+        })
+        .collect()
+}
+
+/// Cookies set by `Set-Cookie` headers (attributes are not broken out)
+fn response_cookies(response: &HeaderBlock) -> Vec<Value> {
+    response
+        .values("set-cookie")
+        .filter_map(|value| {
+            let pair = value.split(';').next()?;
+            let (name, value) = pair.trim().split_once('=')?;
+            Some(json!({"name": name, "value": value}))
+        })
+        .collect()
+}
+
+/// Header block size in bytes, or -1 when nothing was captured
+fn header_size(block: &str) -> i64 {
+    if block.is_empty() {
+        -1
+    } else {
+        block.len() as i64
+    }
+}
+
+/// ISO 8601 time for an epoch-millisecond timestamp (now if unparseable)
+fn started_date_time(timestamp: &str) -> String {
+    timestamp
+        .parse::<i64>()
+        .ok()
+        .and_then(DateTime::<Utc>::from_timestamp_millis)
+        .unwrap_or_else(Utc::now)
+        .to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::models::ScanMessage;
+
+    fn message(
+        request_header: &str,
+        request_body: &str,
+        response_header: &str,
+    ) -> AlertMsgResponse {
+        AlertMsgResponse {
+            scan_message: ScanMessage {
+                id: "m1".to_string(),
+                request_header: Some(request_header.to_string()),
+                request_body: Some(request_body.to_string()),
+                response_header: Some(response_header.to_string()),
+                response_body: Some("{\"ok\":true}".to_string()),
+                cookie_params: None,
+            },
+            uri: "/api/login".to_string(),
+            evidence: None,
+            other_info: None,
+            description: None,
+            param: None,
+            validation_command: None,
+            finding_hash: None,
+        }
+    }
+
+    #[test]
+    fn test_from_messages_builds_entry() {
+        let msg = message(
+            "POST /api/login?next=/home&x HTTP/1.1\r\nHost: example.com\r\nContent-Type: application/json\r\nCookie: a=1; b=2\r\n",
+            "{\"user\":\"u\"}",
+            "HTTP/1.1 401 Unauthorized\r\nContent-Type: application/json\r\nSet-Cookie: sid=abc; HttpOnly\r\n",
+        );
+        let har = from_messages(&[HarMessage {
+            message: &msg,
+            timestamp: "1700000000000",
+            host: Some("https://example.com/"),
+            comment: "SQL Injection (High)".to_string(),
+        }]);
+
+        assert_eq!(har["log"]["version"], "1.2");
+        let entry = &har["log"]["entries"][0];
+        assert_eq!(entry["startedDateTime"], "2023-11-14T22:13:20.000Z");
+        assert_eq!(entry["comment"], "SQL Injection (High)");
+
+        let request = &entry["request"];
+        assert_eq!(request["method"], "POST");
+        assert_eq!(request["url"], "https://example.com/api/login?next=/home&x");
+        assert_eq!(request["queryString"][0]["value"], "/home");
+        assert_eq!(request["queryString"][1]["name"], "x");
+        assert_eq!(request["cookies"][1]["name"], "b");
+        assert_eq!(request["headers"].as_array().unwrap().len(), 3);
+        assert_eq!(request["postData"]["mimeType"], "application/json");
+
+        let response = &entry["response"];
+        assert_eq!(response["status"], 401);
+        assert_eq!(response["statusText"], "Unauthorized");
+        assert_eq!(response["cookies"][0]["value"], "abc");
+        assert_eq!(response["content"]["text"], "{\"ok\":true}");
+    }
+
+    #[test]
+    fn test_absolute_request_line_kept() {
+        let msg = message(
+            "GET http://other.test/x HTTP/1.1\r\nHost: other.test\r\n",
+            "",
+            "HTTP/1.1 200 OK\r\n",
+        );
+        let har = from_messages(&[HarMessage {
+            message: &msg,
+            timestamp: "",
+            host: Some("https://example.com"),
+            comment: String::new(),
+        }]);
+
+        let request = &har["log"]["entries"][0]["request"];
+        assert_eq!(request["url"], "http://other.test/x");
+        assert!(request.get("postData").is_none());
+    }
+}
@@ -0,0 +1,30 @@
+//! Progress and hint messages on stderr
+//!
+//! Informational stderr lines ("Fetching ...", "→ hawkop ..." next steps,
+//! tips) go through [`hint!`] so `--quiet` can suppress them. Warnings and
+//! errors are not hints and are always printed.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Suppress hints for this process (from `--quiet`)
+pub fn set_quiet(quiet: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+}
+
+/// Whether hints are suppressed
+pub fn quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+/// `eprintln!` unless `--quiet` is set
+macro_rules! hint {
+    ($($arg:tt)*) => {
+        if !$crate::output::hints::quiet() {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+pub(crate) use hint;
@@ -1,10 +1,64 @@
 //! JSON output formatting
 
+use std::sync::{Mutex, RwLock};
+
 use chrono::Utc;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use super::jsonpath::JsonPath;
+use super::warnings::{self, Warning};
+
+/// Process-wide `--query` filter applied to JSON output
+static QUERY: RwLock<Option<JsonPath>> = RwLock::new(None);
+
+/// Set the query for this process (from `--query`)
+pub fn set_query(query: JsonPath) {
+    *QUERY.write().unwrap_or_else(|e| e.into_inner()) = Some(query);
+}
+
+fn query() -> Option<JsonPath> {
+    QUERY.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// JSON documents kept for `--post-to`; `None` unless capturing
+static CAPTURED: Mutex<Option<Vec<serde_json::Value>>> = Mutex::new(None);
+
+/// Keep every JSON document this process prints, for [`take_captured`]
+pub fn start_capture() {
+    *CAPTURED.lock().unwrap_or_else(|e| e.into_inner()) = Some(Vec::new());
+}
+
+/// The documents printed since [`start_capture`], each in a `{data, meta}`
+/// envelope and without any `--query` filter
+pub fn take_captured() -> Vec<serde_json::Value> {
+    CAPTURED
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_mut()
+        .map(std::mem::take)
+        .unwrap_or_default()
+}
+
+fn capture(document: impl FnOnce() -> Result<serde_json::Value, serde_json::Error>) {
+    let mut captured = CAPTURED.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(documents) = captured.as_mut() {
+        match document() {
+            Ok(value) => documents.push(value),
+            Err(e) => log::debug!("Failed to capture JSON output: {}", e),
+        }
+    }
+}
+
+/// Whether `value` is already a `{data, meta}` envelope
+fn is_envelope(value: &serde_json::Value) -> bool {
+    value
+        .as_object()
+        .is_some_and(|obj| obj.contains_key("data") && obj.contains_key("meta"))
+}
+
 /// Wrapper for JSON output with metadata
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct JsonOutput<T> {
     /// The actual data
     pub data: T,
@@ -14,13 +68,17 @@ pub struct JsonOutput<T> {
 }
 
 /// Metadata included in JSON output
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct Metadata {
     /// Timestamp of the response
     pub timestamp: String,
 
     /// CLI version
     pub version: String,
+
+    /// Warnings raised while producing this output
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<Warning>,
 }
 
 impl<T> JsonOutput<T> {
@@ -31,6 +89,7 @@ impl<T> JsonOutput<T> {
             meta: Metadata {
                 timestamp: Utc::now().to_rfc3339(),
                 version: env!("CARGO_PKG_VERSION").to_string(),
+                warnings: warnings::collected(),
             },
         }
     }
@@ -39,7 +98,57 @@ impl<T> JsonOutput<T> {
 /// Format data as pretty-printed JSON
 pub fn format_json<T: Serialize + ?Sized>(data: &T) -> Result<String, serde_json::Error> {
     let output = JsonOutput::new(data);
-    serde_json::to_string_pretty(&output)
+    capture(|| serde_json::to_value(&output));
+    if let Some(rendered) = super::template::render(|| serde_json::to_value(&output)) {
+        return rendered;
+    }
+    match query() {
+        Some(query) => Ok(render_matches(&query, &serde_json::to_value(data)?)),
+        None => serde_json::to_string_pretty(&output),
+    }
+}
+
+/// Format a value that is not wrapped by [`format_json`] as pretty-printed JSON
+///
+/// Used for output with its own shape (raw API responses, config files).
+/// A `--query` applies to `data` when the value is already a
+/// `{data, meta}` envelope, otherwise to the whole value.
+pub fn format_raw_json<T: Serialize + ?Sized>(value: &T) -> Result<String, serde_json::Error> {
+    let envelope = || {
+        let value = serde_json::to_value(value)?;
+        if is_envelope(&value) {
+            Ok(value)
+        } else {
+            serde_json::to_value(JsonOutput::new(value))
+        }
+    };
+    capture(envelope);
+    if let Some(rendered) = super::template::render(envelope) {
+        return rendered;
+    }
+    let Some(query) = query() else {
+        return serde_json::to_string_pretty(value);
+    };
+    let value = serde_json::to_value(value)?;
+    let target = if is_envelope(&value) {
+        &value["data"]
+    } else {
+        &value
+    };
+    Ok(render_matches(&query, target))
+}
+
+/// One line per match: strings unquoted (like `jq -r`), everything else as JSON
+fn render_matches(query: &JsonPath, value: &serde_json::Value) -> String {
+    query
+        .select(value)
+        .into_iter()
+        .map(|v| match v {
+            serde_json::Value::String(s) => s.clone(),
+            other => serde_json::to_string_pretty(other).unwrap_or_default(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 #[cfg(test)]
@@ -87,6 +196,28 @@ mod tests {
         assert!(result.contains("\"data\": []"));
     }
 
+    #[test]
+    fn test_captured_output_is_enveloped() {
+        start_capture();
+        format_raw_json(&serde_json::json!({"capture_test": 1})).unwrap();
+
+        // Tests run in parallel, so other output may be captured too
+        let captured = take_captured();
+        let document = captured
+            .iter()
+            .find(|d| d["data"]["capture_test"] == 1)
+            .unwrap();
+        assert_eq!(document["meta"]["version"], env!("CARGO_PKG_VERSION"));
+    }
+
+    #[test]
+    fn test_render_matches() {
+        let query = JsonPath::parse("$[*]").unwrap();
+        let value = serde_json::json!(["a", 1, {"b": true}]);
+
+        assert_eq!(render_matches(&query, &value), "a\n1\n{\n  \"b\": true\n}");
+    }
+
     #[test]
     fn test_format_json_multiple_items() {
         let items = vec![
@@ -0,0 +1,543 @@
+//! JSONPath subset for the global `--query` flag
+//!
+//! Supported syntax (a leading `.` is accepted in place of `$`, jq-style):
+//!
+//! - `$` root, `.name` / `['name']` child, `.*` / `[*]` / `[]` all children
+//! - `..name` / `..*` recursive descent
+//! - `[0]`, `[-1]`, `[0,2]`, `[1:3]`, `[::2]` array indices, unions, slices
+//! - `[?(@.severity == 'High')]` filters with `==`, `!=`, `<`, `<=`, `>`,
+//!   `>=`, existence (`[?(@.tags)]`), and `&&` / `||`
+
+use serde_json::Value;
+
+use crate::error::{Error, Result};
+
+/// A compiled JSONPath expression
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonPath {
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Child(Selector),
+    Descendant(Selector),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Selector {
+    Name(String),
+    Wildcard,
+    Index(i64),
+    Union(Vec<Selector>),
+    Slice(Option<i64>, Option<i64>, i64),
+    Filter(Filter),
+}
+
+/// Disjunction of conjunctions (`&&` binds tighter than `||`)
+#[derive(Debug, Clone, PartialEq)]
+struct Filter {
+    any: Vec<Vec<Condition>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Condition {
+    path: Vec<Segment>,
+    comparison: Option<(Op, Value)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl JsonPath {
+    /// Compile an expression, reporting the position of any syntax error
+    pub fn parse(expr: &str) -> Result<Self> {
+        let mut parser = Parser::new(expr);
+        let segments = parser.path(true)?;
+        parser.skip_ws();
+        if !parser.at_end() {
+            return Err(parser.error("unexpected input"));
+        }
+        Ok(Self { segments })
+    }
+
+    /// All values matched by the expression, in document order
+    pub fn select<'a>(&self, root: &'a Value) -> Vec<&'a Value> {
+        apply(&self.segments, vec![root])
+    }
+}
+
+fn apply<'a>(segments: &[Segment], mut nodes: Vec<&'a Value>) -> Vec<&'a Value> {
+    for segment in segments {
+        let mut next = Vec::new();
+        for node in nodes {
+            match segment {
+                Segment::Child(selector) => select(selector, node, &mut next),
+                Segment::Descendant(selector) => {
+                    let mut stack = vec![node];
+                    let mut ordered = Vec::new();
+                    while let Some(n) = stack.pop() {
+                        ordered.push(n);
+                        match n {
+                            Value::Array(items) => stack.extend(items.iter().rev()),
+                            Value::Object(map) => stack.extend(map.values().rev()),
+                            _ => {}
+                        }
+                    }
+                    for n in ordered {
+                        select(selector, n, &mut next);
+                    }
+                }
+            }
+        }
+        nodes = next;
+    }
+    nodes
+}
+
+fn select<'a>(selector: &Selector, node: &'a Value, out: &mut Vec<&'a Value>) {
+    match selector {
+        Selector::Name(name) => {
+            if let Some(v) = node.as_object().and_then(|m| m.get(name)) {
+                out.push(v);
+            }
+        }
+        Selector::Wildcard => match node {
+            Value::Array(items) => out.extend(items.iter()),
+            Value::Object(map) => out.extend(map.values()),
+            _ => {}
+        },
+        Selector::Index(i) => {
+            if let Some(items) = node.as_array()
+                && let Some(idx) = normalize_index(*i, items.len())
+            {
+                out.push(&items[idx]);
+            }
+        }
+        Selector::Union(selectors) => {
+            for s in selectors {
+                select(s, node, out);
+            }
+        }
+        Selector::Slice(start, end, step) => {
+            if let Some(items) = node.as_array() {
+                let len = items.len() as i64;
+                let clamp = |v: i64| if v < 0 { (len + v).max(0) } else { v.min(len) };
+                let start = start.map_or(0, clamp);
+                let end = end.map_or(len, clamp);
+                let mut i = start;
+                while i < end {
+                    out.push(&items[i as usize]);
+                    i += step;
+                }
+            }
+        }
+        Selector::Filter(filter) => {
+            let children: Vec<&Value> = match node {
+                Value::Array(items) => items.iter().collect(),
+                Value::Object(map) => map.values().collect(),
+                _ => Vec::new(),
+            };
+            out.extend(children.into_iter().filter(|c| filter.matches(c)));
+        }
+    }
+}
+
+fn normalize_index(i: i64, len: usize) -> Option<usize> {
+    let idx = if i < 0 { len as i64 + i } else { i };
+    (0..len as i64).contains(&idx).then_some(idx as usize)
+}
+
+impl Filter {
+    fn matches(&self, node: &Value) -> bool {
+        self.any
+            .iter()
+            .any(|all| all.iter().all(|c| c.matches(node)))
+    }
+}
+
+impl Condition {
+    fn matches(&self, node: &Value) -> bool {
+        let found = apply(&self.path, vec![node]);
+        let Some((op, literal)) = &self.comparison else {
+            return !found.is_empty();
+        };
+        let Some(value) = found.first() else {
+            return *op == Op::Ne;
+        };
+        match (value, literal) {
+            (Value::Number(a), Value::Number(b)) => {
+                let (a, b) = (a.as_f64().unwrap_or(0.0), b.as_f64().unwrap_or(0.0));
+                compare(op, a.partial_cmp(&b))
+            }
+            (Value::String(a), Value::String(b)) => compare(op, Some(a.cmp(b))),
+            (a, b) => match op {
+                Op::Eq => *a == b,
+                Op::Ne => *a != b,
+                _ => false,
+            },
+        }
+    }
+}
+
+fn compare(op: &Op, ordering: Option<std::cmp::Ordering>) -> bool {
+    use std::cmp::Ordering::*;
+    let Some(ord) = ordering else {
+        return false;
+    };
+    match op {
+        Op::Eq => ord == Equal,
+        Op::Ne => ord != Equal,
+        Op::Lt => ord == Less,
+        Op::Le => ord != Greater,
+        Op::Gt => ord == Greater,
+        Op::Ge => ord != Less,
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    fn error(&self, msg: &str) -> Error {
+        Error::Other(format!(
+            "Invalid --query '{}': {} at position {}",
+            self.input,
+            msg,
+            self.pos + 1
+        ))
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn at_end(&self) -> bool {
+        self.pos >= self.input.len()
+    }
+
+    fn eat(&mut self, s: &str) -> bool {
+        if self.rest().starts_with(s) {
+            self.pos += s.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn skip_ws(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    /// Parse segments; `root` expects a leading `$` (or jq-style `.`),
+    /// otherwise a leading `@` for filter-relative paths
+    fn path(&mut self, root: bool) -> Result<Vec<Segment>> {
+        self.skip_ws();
+        if root {
+            let dollar = self.eat("$");
+            if !dollar && !self.rest().starts_with('.') && !self.rest().starts_with('[') {
+                return Err(self.error("expected '$'"));
+            }
+            // jq-style identity
+            if !dollar && self.rest() == "." {
+                self.pos += 1;
+                return Ok(Vec::new());
+            }
+        } else if !self.eat("@") {
+            return Err(self.error("expected '@'"));
+        }
+
+        let mut segments = Vec::new();
+        loop {
+            if self.eat("..") {
+                let selector = if self.peek() == Some('[') {
+                    self.pos += 1;
+                    self.bracket()?
+                } else {
+                    self.dot_selector()?
+                };
+                segments.push(Segment::Descendant(selector));
+            } else if self.eat(".") {
+                if self.peek() == Some('[') {
+                    continue;
+                }
+                segments.push(Segment::Child(self.dot_selector()?));
+            } else if self.eat("[") {
+                segments.push(Segment::Child(self.bracket()?));
+            } else {
+                return Ok(segments);
+            }
+        }
+    }
+
+    fn dot_selector(&mut self) -> Result<Selector> {
+        if self.eat("*") {
+            return Ok(Selector::Wildcard);
+        }
+        let name: String = self
+            .rest()
+            .chars()
+            .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '-')
+            .collect();
+        if name.is_empty() {
+            return Err(self.error("expected a field name"));
+        }
+        self.pos += name.len();
+        Ok(Selector::Name(name))
+    }
+
+    /// Parse the inside of `[...]` (opening bracket already consumed)
+    fn bracket(&mut self) -> Result<Selector> {
+        self.skip_ws();
+        if self.eat("]") {
+            return Ok(Selector::Wildcard);
+        }
+        if self.eat("*") {
+            self.expect("]")?;
+            return Ok(Selector::Wildcard);
+        }
+        if self.eat("?") {
+            self.skip_ws();
+            let parens = self.eat("(");
+            let filter = self.filter()?;
+            if parens {
+                self.expect(")")?;
+            }
+            self.expect("]")?;
+            return Ok(Selector::Filter(filter));
+        }
+
+        let mut items = Vec::new();
+        loop {
+            self.skip_ws();
+            let item = match self.peek() {
+                Some('\'') | Some('"') => Selector::Name(self.string()?),
+                _ => self.index_or_slice()?,
+            };
+            items.push(item);
+            self.skip_ws();
+            if self.eat(",") {
+                continue;
+            }
+            self.expect("]")?;
+            break;
+        }
+        Ok(if items.len() == 1 {
+            items.remove(0)
+        } else {
+            Selector::Union(items)
+        })
+    }
+
+    fn index_or_slice(&mut self) -> Result<Selector> {
+        let start = self.integer();
+        self.skip_ws();
+        if !self.eat(":") {
+            return start
+                .map(Selector::Index)
+                .ok_or_else(|| self.error("expected an index, slice, or quoted name"));
+        }
+        self.skip_ws();
+        let end = self.integer();
+        self.skip_ws();
+        let step = if self.eat(":") {
+            self.skip_ws();
+            self.integer().unwrap_or(1)
+        } else {
+            1
+        };
+        if step < 1 {
+            return Err(self.error("slice step must be positive"));
+        }
+        Ok(Selector::Slice(start, end, step))
+    }
+
+    fn integer(&mut self) -> Option<i64> {
+        let digits: String = self
+            .rest()
+            .char_indices()
+            .take_while(|(i, c)| c.is_ascii_digit() || (*i == 0 && *c == '-'))
+            .map(|(_, c)| c)
+            .collect();
+        let value = digits.parse().ok()?;
+        self.pos += digits.len();
+        Some(value)
+    }
+
+    fn string(&mut self) -> Result<String> {
+        let quote = self.peek().ok_or_else(|| self.error("expected a string"))?;
+        self.pos += 1;
+        let mut out = String::new();
+        let mut chars = self.rest().char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '\\' => {
+                    if let Some((_, escaped)) = chars.next() {
+                        out.push(escaped);
+                    }
+                }
+                c if c == quote => {
+                    self.pos += i + 1;
+                    return Ok(out);
+                }
+                c => out.push(c),
+            }
+        }
+        Err(self.error("unterminated string"))
+    }
+
+    fn expect(&mut self, s: &str) -> Result<()> {
+        self.skip_ws();
+        if self.eat(s) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", s)))
+        }
+    }
+
+    fn filter(&mut self) -> Result<Filter> {
+        let mut any = vec![vec![self.condition()?]];
+        loop {
+            self.skip_ws();
+            if self.eat("&&") {
+                let cond = self.condition()?;
+                any.last_mut().expect("non-empty").push(cond);
+            } else if self.eat("||") {
+                any.push(vec![self.condition()?]);
+            } else {
+                return Ok(Filter { any });
+            }
+        }
+    }
+
+    fn condition(&mut self) -> Result<Condition> {
+        let path = self.path(false)?;
+        self.skip_ws();
+        let op = [
+            ("==", Op::Eq),
+            ("!=", Op::Ne),
+            ("<=", Op::Le),
+            (">=", Op::Ge),
+            ("<", Op::Lt),
+            (">", Op::Gt),
+        ]
+        .into_iter()
+        .find(|(token, _)| self.eat(token))
+        .map(|(_, op)| op);
+
+        let comparison = match op {
+            Some(op) => Some((op, self.literal()?)),
+            None => None,
+        };
+        Ok(Condition { path, comparison })
+    }
+
+    fn literal(&mut self) -> Result<Value> {
+        self.skip_ws();
+        if matches!(self.peek(), Some('\'') | Some('"')) {
+            return Ok(Value::String(self.string()?));
+        }
+        for (word, value) in [
+            ("true", Value::Bool(true)),
+            ("false", Value::Bool(false)),
+            ("null", Value::Null),
+        ] {
+            if self.eat(word) {
+                return Ok(value);
+            }
+        }
+        let number: String = self
+            .rest()
+            .chars()
+            .take_while(|c| c.is_ascii_digit() || matches!(c, '-' | '.' | 'e' | 'E' | '+'))
+            .collect();
+        let value: serde_json::Number = number
+            .parse()
+            .map_err(|_| self.error("expected a string, number, boolean, or null"))?;
+        self.pos += number.len();
+        Ok(Value::Number(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn doc() -> Value {
+        json!([
+            {"id": "a", "severity": "High", "paths": 3, "tags": ["x"]},
+            {"id": "b", "severity": "Low", "paths": 1},
+            {"id": "c", "severity": "High", "paths": 7, "meta": {"id": "nested"}}
+        ])
+    }
+
+    fn query(expr: &str) -> Vec<Value> {
+        JsonPath::parse(expr)
+            .unwrap()
+            .select(&doc())
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    #[test]
+    fn test_children_wildcards_and_indices() {
+        assert_eq!(query("$[*].id"), vec![json!("a"), json!("b"), json!("c")]);
+        assert_eq!(query(".[].id"), vec![json!("a"), json!("b"), json!("c")]);
+        assert_eq!(query("$[-1].id"), vec![json!("c")]);
+        assert_eq!(query("$[0,2]['id']"), vec![json!("a"), json!("c")]);
+        assert_eq!(query("$[1:].id"), vec![json!("b"), json!("c")]);
+        assert_eq!(query("$[::2].id"), vec![json!("a"), json!("c")]);
+        assert_eq!(query("."), vec![doc()]);
+    }
+
+    #[test]
+    fn test_recursive_descent() {
+        assert_eq!(
+            query("$..id"),
+            vec![json!("a"), json!("b"), json!("c"), json!("nested")]
+        );
+    }
+
+    #[test]
+    fn test_filters() {
+        assert_eq!(
+            query("$[?(@.severity == 'High')].id"),
+            vec![json!("a"), json!("c")]
+        );
+        assert_eq!(query("$[?@.paths > 2 && @.paths < 5].id"), vec![json!("a")]);
+        assert_eq!(
+            query("$[?(@.tags || @.paths >= 7)].id"),
+            vec![json!("a"), json!("c")]
+        );
+        assert_eq!(query("$[?(@.severity != \"High\")].id"), vec![json!("b")]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for expr in ["id", "$[", "$[?(@.a == )]", "$.", "$[1:2:0]", "$['x"] {
+            let err = JsonPath::parse(expr).unwrap_err().to_string();
+            assert!(err.contains("Invalid --query"), "{}: {}", expr, err);
+        }
+    }
+}
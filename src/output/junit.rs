@@ -0,0 +1,137 @@
+//! JUnit XML output for CI systems
+//!
+//! Jenkins, GitLab, Azure Pipelines, and most other CI servers render JUnit
+//! reports natively, so findings rendered as test cases show up in the
+//! pipeline UI without plugins.
+
+/// A group of test cases (`<testsuite>`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestSuite {
+    pub name: String,
+    pub cases: Vec<TestCase>,
+}
+
+/// A single check (`<testcase>`), failed when `failure` is set
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestCase {
+    pub classname: String,
+    pub name: String,
+    pub failure: Option<Failure>,
+}
+
+/// Why a test case failed: a one-line message and optional detail text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    pub message: String,
+    pub text: String,
+}
+
+impl TestSuite {
+    fn failures(&self) -> usize {
+        self.cases.iter().filter(|c| c.failure.is_some()).count()
+    }
+}
+
+/// Render suites as a `<testsuites>` document
+pub fn format_junit(name: &str, suites: &[TestSuite]) -> String {
+    let tests: usize = suites.iter().map(|s| s.cases.len()).sum();
+    let failures: usize = suites.iter().map(TestSuite::failures).sum();
+
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str(&format!(
+        "<testsuites name=\"{}\" tests=\"{}\" failures=\"{}\">\n",
+        escape(name),
+        tests,
+        failures
+    ));
+    for suite in suites {
+        out.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"0\">\n",
+            escape(&suite.name),
+            suite.cases.len(),
+            suite.failures()
+        ));
+        for case in &suite.cases {
+            let open = format!(
+                "    <testcase classname=\"{}\" name=\"{}\"",
+                escape(&case.classname),
+                escape(&case.name)
+            );
+            match &case.failure {
+                None => out.push_str(&format!("{}/>\n", open)),
+                Some(failure) => {
+                    out.push_str(&format!("{}>\n", open));
+                    out.push_str(&format!(
+                        "      <failure message=\"{}\">{}</failure>\n",
+                        escape(&failure.message),
+                        escape(&failure.text)
+                    ));
+                    out.push_str("    </testcase>\n");
+                }
+            }
+        }
+        out.push_str("  </testsuite>\n");
+    }
+    out.push_str("</testsuites>");
+    out
+}
+
+/// Escape text for XML attributes and content
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            // Control characters other than tab and newlines are not valid XML 1.0
+            c if c.is_control() && !matches!(c, '\t' | '\n' | '\r') => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_junit_counts_and_failures() {
+        let suites = vec![TestSuite {
+            name: "thresholds".to_string(),
+            cases: vec![
+                TestCase {
+                    classname: "web.prod".to_string(),
+                    name: "high < 1".to_string(),
+                    failure: Some(Failure {
+                        message: "3 high findings".to_string(),
+                        text: "fail at 1".to_string(),
+                    }),
+                },
+                TestCase {
+                    classname: "web.prod".to_string(),
+                    name: "medium < 10".to_string(),
+                    failure: None,
+                },
+            ],
+        }];
+
+        let xml = format_junit("gate", &suites);
+
+        assert!(xml.contains("<testsuites name=\"gate\" tests=\"2\" failures=\"1\">"));
+        assert!(xml.contains("name=\"high &lt; 1\">"));
+        assert!(xml.contains("<failure message=\"3 high findings\">fail at 1</failure>"));
+        assert!(xml.contains("name=\"medium &lt; 10\"/>"));
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(
+            escape("a & \"b\" <c>\u{1}"),
+            "a &amp; &quot;b&quot; &lt;c&gt;"
+        );
+    }
+}
@@ -0,0 +1,210 @@
+//! GitHub-flavored markdown output
+//!
+//! Documents meant for pasting into pull requests and issues: GFM tables,
+//! fenced code blocks, and `<details>` sections that GitHub renders
+//! collapsed. Text taken from scans (paths, headers, evidence) is escaped or
+//! fenced so it can't break the markup around it.
+
+use tabled::Tabled;
+
+/// A markdown document built block by block
+#[derive(Debug, Default)]
+pub struct Markdown {
+    out: String,
+}
+
+impl Markdown {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start a new block, separated from the previous one by a blank line
+    fn block(&mut self) -> &mut String {
+        if !self.out.is_empty() {
+            self.out.push('\n');
+        }
+        &mut self.out
+    }
+
+    /// `#`-style heading (`text` is escaped)
+    pub fn heading(&mut self, level: usize, text: &str) -> &mut Self {
+        let out = self.block();
+        out.push_str(&"#".repeat(level.clamp(1, 6)));
+        out.push(' ');
+        out.push_str(&escape(text));
+        out.push('\n');
+        self
+    }
+
+    /// A paragraph of markdown, used as given
+    pub fn paragraph(&mut self, text: &str) -> &mut Self {
+        let out = self.block();
+        out.push_str(text.trim_end());
+        out.push('\n');
+        self
+    }
+
+    /// `- **Label:** value` list; values are markdown, used as given
+    pub fn fields(&mut self, fields: &[(&str, String)]) -> &mut Self {
+        if fields.is_empty() {
+            return self;
+        }
+        let out = self.block();
+        for (label, value) in fields {
+            out.push_str(&format!("- **{}:** {}\n", label, value));
+        }
+        self
+    }
+
+    /// GFM table; cells are escaped and kept to one line
+    pub fn table<S: AsRef<str>>(&mut self, headers: &[S], rows: &[Vec<String>]) -> &mut Self {
+        let out = self.block();
+        let row = |cells: &mut dyn Iterator<Item = &str>| {
+            let cells: Vec<String> = cells.map(cell).collect();
+            format!("| {} |\n", cells.join(" | "))
+        };
+        out.push_str(&row(&mut headers.iter().map(AsRef::as_ref)));
+        out.push_str(&format!("|{}\n", "---|".repeat(headers.len())));
+        for cells in rows {
+            out.push_str(&row(&mut cells.iter().map(String::as_str)));
+        }
+        self
+    }
+
+    /// GFM table of display rows, with their table headers
+    pub fn tabled<T: Tabled>(&mut self, rows: &[T]) -> &mut Self {
+        let cells: Vec<Vec<String>> = rows
+            .iter()
+            .map(|r| r.fields().into_iter().map(String::from).collect())
+            .collect();
+        self.table(&T::headers(), &cells)
+    }
+
+    /// Fenced code block, highlighted as `lang`
+    pub fn code(&mut self, lang: &str, text: &str) -> &mut Self {
+        let out = self.block();
+        out.push_str(&code_block(lang, text));
+        self
+    }
+
+    /// Collapsible `<details>` section holding the blocks `content` adds
+    pub fn details(&mut self, summary: &str, content: impl FnOnce(&mut Markdown)) -> &mut Self {
+        let mut inner = Markdown::new();
+        content(&mut inner);
+        let out = self.block();
+        out.push_str(&format!(
+            "<details>\n<summary>{}</summary>\n\n",
+            escape_html(summary)
+        ));
+        out.push_str(&inner.out);
+        out.push_str("\n</details>\n");
+        self
+    }
+
+    /// The document
+    pub fn finish(self) -> String {
+        self.out
+    }
+}
+
+/// Escape characters markdown would otherwise treat as markup
+pub fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | '`' | '*' | '_' | '[' | ']' | '#' | '|' | '~' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// `text` as inline code, e.g. a path or ID
+pub fn inline_code(text: &str) -> String {
+    let ticks = "`".repeat(longest_backtick_run(text) + 1);
+    // A space keeps a leading or trailing backtick off the delimiters
+    let pad = if text.starts_with('`') || text.ends_with('`') {
+        " "
+    } else {
+        ""
+    };
+    format!(
+        "{ticks}{pad}{text}{pad}{ticks}",
+        text = text.replace('\n', " ")
+    )
+}
+
+/// Fenced code block, with a fence longer than any backtick run in `text`
+fn code_block(lang: &str, text: &str) -> String {
+    let fence = "`".repeat((longest_backtick_run(text) + 1).max(3));
+    format!("{fence}{lang}\n{}\n{fence}\n", text.trim_end_matches('\n'))
+}
+
+/// A table cell: escaped, on one line
+fn cell(text: &str) -> String {
+    escape(text.trim())
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn longest_backtick_run(text: &str) -> usize {
+    text.split(|c| c != '`').map(str::len).max().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_document_blocks() {
+        let mut md = Markdown::new();
+        md.heading(3, "SQL Injection [High]")
+            .fields(&[("Scan", inline_code("scan-1"))])
+            .table(
+                &["PATH", "STATUS"],
+                &[vec!["/a|b".to_string(), "New".to_string()]],
+            )
+            .details("Request <raw>", |md| {
+                md.code("http", "GET /a HTTP/1.1\n```\n");
+            });
+
+        assert_eq!(
+            md.finish(),
+            "### SQL Injection \\[High\\]\n\
+             \n\
+             - **Scan:** `scan-1`\n\
+             \n\
+             | PATH | STATUS |\n\
+             |---|---|\n\
+             | /a\\|b | New |\n\
+             \n\
+             <details>\n\
+             <summary>Request &lt;raw&gt;</summary>\n\
+             \n\
+             ````http\n\
+             GET /a HTTP/1.1\n\
+             ```\n\
+             ````\n\
+             \n\
+             </details>\n"
+        );
+    }
+
+    #[test]
+    fn test_inline_code() {
+        assert_eq!(inline_code("/items/{id}"), "`/items/{id}`");
+        assert_eq!(inline_code("a`b"), "``a`b``");
+        assert_eq!(inline_code("`x"), "`` `x ``");
+    }
+}
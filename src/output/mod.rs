@@ -9,9 +9,26 @@ use tabled::Tabled;
 use crate::cli::OutputFormat;
 use crate::error::Result;
 
+pub mod body;
+pub mod columns;
+pub mod csv;
+pub mod diff;
 pub mod formatters;
+pub mod har;
+pub mod hints;
 pub mod json;
+pub mod jsonpath;
+pub mod junit;
+pub mod markdown;
+pub mod pager;
+pub mod render;
+pub mod sarif;
+pub mod schema;
+pub mod stats;
 pub mod table;
+pub mod template;
+pub mod warnings;
+pub mod webhook;
 
 /// Trait for types that can be formatted for output.
 ///
@@ -21,9 +38,19 @@ pub trait Formattable {
     fn format(&self, format: OutputFormat) -> Result<String>;
 
     /// Format and print to stdout.
+    ///
+    /// Tables taller than the terminal go through the built-in pager.
     fn print(&self, format: OutputFormat) -> Result<()> {
         let output = self.format(format)?;
-        println!("{}", output);
+        // Nothing at all (not a blank line) for an empty --ids-only list
+        if output.is_empty() {
+            return Ok(());
+        }
+        if format == OutputFormat::Json || columns::ids_only() {
+            println!("{}", output);
+        } else {
+            pager::print(&output);
+        }
         Ok(())
     }
 }
@@ -37,9 +64,18 @@ where
     T: Tabled + Serialize,
 {
     fn format(&self, format: OutputFormat) -> Result<String> {
+        if columns::ids_only() {
+            return Ok(columns::ids(self)?.join("\n"));
+        }
         match format {
             // Pretty and Table both use table format for list commands
-            OutputFormat::Pretty | OutputFormat::Table => Ok(table::format_table(self)),
+            OutputFormat::Pretty | OutputFormat::Table => match columns::selection() {
+                Some(selection) => {
+                    let (headers, rows) = columns::select(self, &selection)?;
+                    Ok(table::format_rows(&headers, &rows))
+                }
+                None => Ok(table::format_table(self)),
+            },
             OutputFormat::Json => json::format_json(self).map_err(|e| {
                 crate::error::Error::Other(format!("JSON serialization failed: {}", e))
             }),
@@ -0,0 +1,224 @@
+//! Built-in pager for long output
+//!
+//! When output is taller than the terminal, [`print`] shows it a screen at a
+//! time on the alternate screen, with `less`-style keys. ANSI colors pass
+//! through as they do with `less -R`; lines wider than the terminal are cut
+//! and can be scrolled sideways. Output that fits is printed as usual, and
+//! paging is skipped with `--no-pager` or when stdin or stdout isn't a
+//! terminal.
+
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use console::{Key, Term};
+use log::debug;
+
+static DISABLED: AtomicBool = AtomicBool::new(false);
+
+/// Enter the alternate screen
+const ALT_SCREEN_ON: &str = "\x1b[?1049h";
+
+/// Leave the alternate screen, restoring what was there before
+const ALT_SCREEN_OFF: &str = "\x1b[?1049l";
+
+/// Never page output for this process (from `--no-pager`)
+pub fn set_disabled(disabled: bool) {
+    DISABLED.store(disabled, Ordering::Relaxed);
+}
+
+/// Print `output` to stdout, through the pager when it won't fit on screen
+pub fn print(output: &str) {
+    let term = Term::stdout();
+    let lines: Vec<&str> = output.lines().collect();
+    match screen_rows(&term) {
+        Some(rows) if lines.len() >= rows => {
+            if let Err(e) = run(&term, &lines) {
+                debug!("Pager failed, printing instead: {}", e);
+                println!("{}", output);
+            }
+        }
+        _ => println!("{}", output),
+    }
+}
+
+/// Terminal height when paging is possible
+fn screen_rows(term: &Term) -> Option<usize> {
+    if DISABLED.load(Ordering::Relaxed) || !term.is_term() || !std::io::stdin().is_terminal() {
+        return None;
+    }
+    term.size_checked().map(|(rows, _)| rows as usize)
+}
+
+fn run(term: &Term, lines: &[&str]) -> std::io::Result<()> {
+    term.write_str(ALT_SCREEN_ON)?;
+    term.hide_cursor()?;
+    let result = interact(term, lines);
+    let _ = term.show_cursor();
+    let _ = term.write_str(ALT_SCREEN_OFF);
+    result
+}
+
+/// Show screens until the user quits
+fn interact(term: &Term, lines: &[&str]) -> std::io::Result<()> {
+    let mut view = View::default();
+    loop {
+        let (rows, cols) = term.size();
+        let (rows, cols) = (rows as usize, cols as usize);
+        let body = rows.saturating_sub(1).max(1);
+        view.top = view.top.min(lines.len().saturating_sub(body));
+
+        // Home + clear, then the visible lines and a status line at the bottom
+        let mut screen = String::from("\x1b[H\x1b[2J");
+        for line in lines.iter().skip(view.top).take(body) {
+            screen.push_str(&slice_columns(line, view.left, cols));
+            screen.push_str("\x1b[0m\r\n");
+        }
+        screen.push_str(&format!("\x1b[{};1H", rows));
+        screen.push_str(&status_line(view.top, body, lines.len(), cols));
+        term.write_str(&screen)?;
+
+        let key = match term.read_key() {
+            Ok(key) => key,
+            // Ctrl-C while reading a key
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => return Ok(()),
+            Err(e) => return Err(e),
+        };
+        match view.scroll(&key, body, cols, lines.len()) {
+            Some(next) => view = next,
+            None => return Ok(()),
+        }
+    }
+}
+
+/// Scroll position: first visible line and first visible column
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct View {
+    top: usize,
+    left: usize,
+}
+
+impl View {
+    /// Position after `key`, or None to quit
+    fn scroll(self, key: &Key, body: usize, cols: usize, total: usize) -> Option<Self> {
+        let last_top = total.saturating_sub(body);
+        let half = (body / 2).max(1);
+        let side = (cols / 2).max(1);
+        let top = |top: usize| {
+            Some(Self {
+                top: top.min(last_top),
+                ..self
+            })
+        };
+
+        match key {
+            Key::Char('q' | 'Q') | Key::Escape | Key::CtrlC => None,
+            Key::Char(' ' | 'f') | Key::PageDown => top(self.top + body),
+            Key::Char('b') | Key::PageUp => top(self.top.saturating_sub(body)),
+            Key::Char('j' | 'e') | Key::Enter | Key::ArrowDown => top(self.top + 1),
+            Key::Char('k' | 'y') | Key::ArrowUp => top(self.top.saturating_sub(1)),
+            Key::Char('d') => top(self.top + half),
+            Key::Char('u') => top(self.top.saturating_sub(half)),
+            Key::Char('g' | '<') | Key::Home => top(0),
+            Key::Char('G' | '>') | Key::End => top(last_top),
+            Key::ArrowRight => Some(Self {
+                left: self.left + side,
+                ..self
+            }),
+            Key::ArrowLeft => Some(Self {
+                left: self.left.saturating_sub(side),
+                ..self
+            }),
+            _ => Some(self),
+        }
+    }
+}
+
+/// Reverse-video position and key help, fit to the terminal width
+fn status_line(top: usize, body: usize, total: usize, cols: usize) -> String {
+    let last = (top + body).min(total);
+    let position = if last >= total {
+        "(END)".to_string()
+    } else {
+        format!(
+            "lines {}-{} of {} ({}%)",
+            top + 1,
+            last,
+            total,
+            last * 100 / total
+        )
+    };
+    let text = format!(" {}  space/b page  ↑/↓ line  ←/→ scroll  q quit ", position);
+    format!("\x1b[7m{}\x1b[0m", console::truncate_str(&text, cols, ""))
+}
+
+/// The `width` display columns of `line` starting at column `skip`
+///
+/// Escape sequences (colors) are always kept so styles carry across the cut.
+fn slice_columns(line: &str, skip: usize, width: usize) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut col = 0;
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            out.push(c);
+            // CSI sequences run to a final byte in `@`..=`~`
+            if let Some(next) = chars.next() {
+                out.push(next);
+                if next == '[' {
+                    for c in chars.by_ref() {
+                        out.push(c);
+                        if ('@'..='~').contains(&c) {
+                            break;
+                        }
+                    }
+                }
+            }
+            continue;
+        }
+        let w = console::measure_text_width(c.encode_utf8(&mut [0; 4]));
+        if col >= skip && col + w <= skip + width {
+            out.push(c);
+        }
+        col += w;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slice_columns_keeps_colors() {
+        assert_eq!(slice_columns("abcdef", 2, 3), "cde");
+        assert_eq!(slice_columns("abc", 5, 3), "");
+        assert_eq!(
+            slice_columns("\x1b[31mred\x1b[0m text", 1, 4),
+            "\x1b[31med\x1b[0m t"
+        );
+        // Wide characters are never split
+        assert_eq!(slice_columns("日本語", 0, 3), "日");
+    }
+
+    #[test]
+    fn test_scroll_keys() {
+        let view = View::default();
+        let scroll = |v: View, key: Key| v.scroll(&key, 10, 80, 25);
+
+        let down = scroll(view, Key::Char(' ')).unwrap();
+        assert_eq!(down.top, 10);
+        // Stops with the last line at the bottom
+        assert_eq!(scroll(down, Key::PageDown).unwrap().top, 15);
+        assert_eq!(scroll(view, Key::End).unwrap().top, 15);
+        assert_eq!(scroll(down, Key::Char('b')).unwrap().top, 0);
+        assert_eq!(scroll(view, Key::ArrowUp).unwrap().top, 0);
+        assert_eq!(scroll(view, Key::ArrowRight).unwrap().left, 40);
+        assert!(scroll(view, Key::Char('q')).is_none());
+    }
+
+    #[test]
+    fn test_status_line() {
+        assert!(status_line(0, 10, 40, 80).contains("lines 1-10 of 40 (25%)"));
+        assert!(status_line(30, 10, 40, 80).contains("(END)"));
+    }
+}
@@ -0,0 +1,374 @@
+//! Markdown rendered for the terminal
+//!
+//! Plugin descriptions and remediation guidance are written in markdown.
+//! [`render`] parses it as CommonMark (with `pulldown-cmark`) and turns
+//! headings, emphasis, inline code, links, lists, quotes, and code blocks
+//! into styled text wrapped to the terminal width, so guidance reads as prose
+//! instead of markup. Raw HTML is shown as written. Styling goes through
+//! `colored`, so `--no-color` and `NO_COLOR` leave plain wrapped text.
+
+use colored::Colorize;
+use console::measure_text_width;
+use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+
+/// Width used when stdout is not a terminal
+const DEFAULT_WIDTH: usize = 80;
+
+/// Widest wrapping, even on wide terminals, to keep lines readable
+const MAX_WIDTH: usize = 100;
+
+/// Width to wrap rendered text to: the terminal's, within bounds
+pub fn wrap_width() -> usize {
+    console::Term::stdout()
+        .size_checked()
+        .map_or(DEFAULT_WIDTH, |(_, cols)| cols as usize)
+        .min(MAX_WIDTH)
+}
+
+/// Render `markdown` as styled text, each line starting with `indent` and
+/// wrapped to `width` columns (indent included)
+pub fn render(markdown: &str, width: usize, indent: &str) -> String {
+    let mut renderer = Renderer::new(width, indent);
+    for event in Parser::new(markdown) {
+        renderer.event(event);
+    }
+    renderer.finish()
+}
+
+/// Inline style of a run of text
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Style {
+    Plain,
+    Bold,
+    Italic,
+    Code,
+    Link,
+    Url,
+}
+
+fn styled(text: &str, style: Style) -> String {
+    match style {
+        Style::Plain => text.to_string(),
+        Style::Bold => text.bold().to_string(),
+        Style::Italic => text.italic().to_string(),
+        Style::Code => text.cyan().to_string(),
+        Style::Link => text.blue().underline().to_string(),
+        Style::Url => text.dimmed().to_string(),
+    }
+}
+
+/// An open list: the next item number (None for bullets) and the width of
+/// the current item's marker, which its wrapped lines hang under
+struct List {
+    next: Option<u64>,
+    hang: usize,
+}
+
+/// An open link: its URL and the text seen so far
+struct Link {
+    url: String,
+    text: String,
+}
+
+/// Lines rendered so far, and the block being filled
+struct Renderer<'a> {
+    width: usize,
+    indent: &'a str,
+    out: Vec<String>,
+    /// Styled words of the current block, and the word being built
+    ///
+    /// Runs that touch without whitespace (`**bold**,`) stay one word.
+    words: Vec<String>,
+    word: String,
+    styles: Vec<Style>,
+    lists: Vec<List>,
+    /// Marker of a list item whose first line is not written yet
+    marker: Option<String>,
+    links: Vec<Link>,
+    quotes: usize,
+    in_code: bool,
+}
+
+impl<'a> Renderer<'a> {
+    fn new(width: usize, indent: &'a str) -> Self {
+        Self {
+            width,
+            indent,
+            out: Vec::new(),
+            words: Vec::new(),
+            word: String::new(),
+            styles: Vec::new(),
+            lists: Vec::new(),
+            marker: None,
+            links: Vec::new(),
+            quotes: 0,
+            in_code: false,
+        }
+    }
+
+    fn event(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) if self.in_code => {
+                let prefix = format!("{}    ", self.prefix());
+                for line in text.lines() {
+                    self.out.push(format!("{}{}", prefix, line.dimmed()));
+                }
+            }
+            Event::Text(text) | Event::Html(text) | Event::InlineHtml(text) => {
+                self.text(&text, self.style());
+            }
+            Event::Code(code) => self.text(&code, Style::Code),
+            Event::SoftBreak => self.end_word(),
+            Event::HardBreak => self.flush(),
+            Event::Rule => {
+                self.separate();
+                let prefix = self.prefix();
+                let rule = "─".repeat(self.width.saturating_sub(measure_text_width(&prefix)));
+                self.out.push(format!("{}{}", prefix, rule.dimmed()));
+            }
+            _ => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph => self.separate(),
+            Tag::Heading { .. } => {
+                self.separate();
+                self.styles.push(Style::Bold);
+            }
+            Tag::BlockQuote(_) => {
+                self.separate();
+                self.quotes += 1;
+            }
+            Tag::CodeBlock(_) => {
+                self.separate();
+                self.in_code = true;
+            }
+            Tag::List(start) => {
+                if self.lists.is_empty() {
+                    self.separate();
+                } else {
+                    self.flush();
+                }
+                self.lists.push(List {
+                    next: start,
+                    hang: 0,
+                });
+            }
+            Tag::Item => {
+                self.flush();
+                if let Some(list) = self.lists.last_mut() {
+                    let marker = match list.next.as_mut() {
+                        Some(n) => {
+                            *n += 1;
+                            format!("{}.", *n - 1)
+                        }
+                        None => "•".to_string(),
+                    };
+                    list.hang = measure_text_width(&marker) + 1;
+                    self.marker = Some(marker);
+                }
+            }
+            Tag::Emphasis => self.styles.push(Style::Italic),
+            Tag::Strong => self.styles.push(Style::Bold),
+            Tag::Link { dest_url, .. } => {
+                self.styles.push(Style::Link);
+                self.links.push(Link {
+                    url: dest_url.to_string(),
+                    text: String::new(),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph | TagEnd::Item => self.flush(),
+            TagEnd::Heading(_) => {
+                self.flush();
+                self.styles.pop();
+            }
+            TagEnd::BlockQuote(_) => {
+                self.flush();
+                self.quotes -= 1;
+            }
+            TagEnd::CodeBlock => self.in_code = false,
+            TagEnd::List(_) => {
+                self.flush();
+                self.lists.pop();
+            }
+            TagEnd::Emphasis | TagEnd::Strong => {
+                self.styles.pop();
+            }
+            TagEnd::Link => {
+                self.styles.pop();
+                if let Some(link) = self.links.pop()
+                    && link.text != link.url
+                {
+                    self.text(&format!(" ({})", link.url), Style::Url);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Innermost inline style
+    fn style(&self) -> Style {
+        self.styles.last().copied().unwrap_or(Style::Plain)
+    }
+
+    /// Add inline text to the current block
+    fn text(&mut self, text: &str, style: Style) {
+        if let Some(link) = self.links.last_mut() {
+            link.text.push_str(text);
+        }
+        for (i, part) in text.split(' ').enumerate() {
+            if i > 0 {
+                self.end_word();
+            }
+            if !part.is_empty() {
+                self.word.push_str(&styled(part, style));
+            }
+        }
+    }
+
+    fn end_word(&mut self) {
+        if !self.word.is_empty() {
+            self.words.push(std::mem::take(&mut self.word));
+        }
+    }
+
+    /// Indent and quote bars that start every line of the current block
+    fn prefix(&self) -> String {
+        let bars = format!("{} ", "│".dimmed()).repeat(self.quotes);
+        format!("{}{}", self.indent, bars)
+    }
+
+    /// Wrap the current block into the output
+    fn flush(&mut self) {
+        self.end_word();
+        if self.words.is_empty() {
+            return;
+        }
+        let base = self.prefix();
+        let outer: usize = self.lists.iter().rev().skip(1).map(|l| l.hang).sum();
+        let current = self.lists.last().map_or(0, |l| l.hang);
+        let rest = format!("{}{}", base, " ".repeat(outer + current));
+        let first = match self.marker.take() {
+            Some(marker) => format!("{}{}{} ", base, " ".repeat(outer), marker),
+            None => rest.clone(),
+        };
+        let words = std::mem::take(&mut self.words);
+        self.out.extend(wrap(&words, self.width, &first, &rest));
+    }
+
+    /// Finish the current block and leave a blank line before the next one
+    /// (blocks inside a list follow each other directly)
+    fn separate(&mut self) {
+        self.flush();
+        if self.lists.is_empty() && self.out.last().is_some_and(|l| !l.is_empty()) {
+            self.out.push(String::new());
+        }
+    }
+
+    fn finish(mut self) -> String {
+        self.flush();
+        while self.out.last().is_some_and(String::is_empty) {
+            self.out.pop();
+        }
+        self.out.join("\n")
+    }
+}
+
+/// Lines of `words` filled to `width`, the first starting with `first`
+/// and the rest with `rest`
+fn wrap(words: &[String], width: usize, first: &str, rest: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = first.to_string();
+    let mut empty = true;
+    for word in words {
+        let fits = measure_text_width(&line) + 1 + measure_text_width(word) <= width;
+        if !empty && !fits {
+            lines.push(std::mem::replace(&mut line, rest.to_string()));
+            empty = true;
+        }
+        if !empty {
+            line.push(' ');
+        }
+        line.push_str(word);
+        empty = false;
+    }
+    if !empty {
+        lines.push(line);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(markdown: &str, width: usize) -> String {
+        console::strip_ansi_codes(&render(markdown, width, "  ")).into_owned()
+    }
+
+    #[test]
+    fn test_render_wraps_paragraphs_and_lists() {
+        let markdown = "Cross-site scripting lets an attacker run script\nin a victim's browser.\n\n\
+                        - Encode output for the context it is written to\n\
+                        - Validate input\n\n\
+                        1. First step\n";
+        assert_eq!(
+            plain(markdown, 30),
+            "  Cross-site scripting lets an\n  attacker run script in a\n  victim's browser.\n\n  \
+             • Encode output for the\n    context it is written to\n  • Validate input\n\n  \
+             1. First step"
+        );
+    }
+
+    #[test]
+    fn test_render_inline_markup() {
+        assert_eq!(
+            plain(
+                "Use **parameterized** queries, `PreparedStatement`, and *never* trust input.",
+                100
+            ),
+            "  Use parameterized queries, PreparedStatement, and never trust input."
+        );
+        assert_eq!(
+            plain(
+                "See the [XSS Cheat Sheet](https://cheatsheetseries.owasp.org/xss.html) or <https://owasp.org>.",
+                100
+            ),
+            "  See the XSS Cheat Sheet (https://cheatsheetseries.owasp.org/xss.html) or https://owasp.org."
+        );
+        // Escapes and unmatched markers are shown as written
+        assert_eq!(
+            plain(r"2 \* 3 * 4 and snake_case_name", 100),
+            "  2 * 3 * 4 and snake_case_name"
+        );
+    }
+
+    #[test]
+    fn test_render_headings_quotes_and_code() {
+        let markdown = "## Solution\n\n> Note this\n\n```\nSELECT * FROM t\n```";
+        assert_eq!(
+            plain(markdown, 80),
+            "  Solution\n\n  │ Note this\n\n      SELECT * FROM t"
+        );
+    }
+
+    #[test]
+    fn test_render_nested_lists() {
+        let markdown =
+            "3. Encode output\n   - HTML body\n   - Attributes in quoted values\n4. Validate input";
+        assert_eq!(
+            plain(markdown, 30),
+            "  3. Encode output\n     • HTML body\n     • Attributes in quoted\n       values\n  4. Validate input"
+        );
+    }
+}
@@ -0,0 +1,229 @@
+//! SARIF 2.1.0 output for scan findings
+//!
+//! Converts a scan's alerts and paths into a SARIF log that code-scanning
+//! tools (GitHub code scanning in particular) can ingest. DAST findings have
+//! no source location, so every result points at a single artifact (the
+//! HawkScan config by default) and carries the HTTP method and path in its
+//! message and properties.
+
+use std::collections::BTreeMap;
+
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+
+use crate::client::models::{AlertResponse, ScanResult};
+
+/// SARIF schema referenced by generated logs
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Triage statuses left out unless triaged findings are requested
+const TRIAGED_STATUSES: &[&str] = &["RISK_ACCEPTED", "FALSE_POSITIVE"];
+
+/// Build a SARIF log from a scan and its alerts with paths
+///
+/// Risk-accepted and false-positive paths are skipped unless
+/// `include_triaged` is set. `location` is the artifact URI every result
+/// points at.
+pub fn from_scan(
+    scan: &ScanResult,
+    alerts: &[AlertResponse],
+    include_triaged: bool,
+    location: &str,
+) -> Value {
+    let mut rules: BTreeMap<&str, Value> = BTreeMap::new();
+    let mut results = Vec::new();
+
+    for response in alerts {
+        let alert = &response.alert;
+        let paths: Vec<_> = response
+            .application_scan_alert_uris
+            .iter()
+            .filter(|uri| include_triaged || !TRIAGED_STATUSES.contains(&uri.status.as_str()))
+            .collect();
+        if paths.is_empty() {
+            continue;
+        }
+
+        rules.entry(alert.plugin_id.as_str()).or_insert_with(|| {
+            let mut tags = vec![json!("security")];
+            if let Some(cwe) = alert.cwe_id.as_deref().filter(|c| !c.is_empty()) {
+                tags.push(json!(format!("external/cwe/cwe-{}", cwe)));
+            }
+            if let Some(category) = crate::owasp::for_cwe(alert.cwe_id.as_deref()) {
+                tags.push(json!(format!("external/owasp/{}", category.id)));
+            }
+            let help_uri = response
+                .cheatsheet
+                .clone()
+                .or_else(|| alert.references.first().cloned());
+            let mut rule = json!({
+                "id": alert.plugin_id,
+                "name": alert.name,
+                "shortDescription": {"text": alert.name},
+                "fullDescription": {"text": first_paragraph(&alert.description)},
+                "help": {
+                    "text": alert.description,
+                    "markdown": alert.description,
+                },
+                "defaultConfiguration": {"level": level(&alert.severity)},
+                "properties": {
+                    "tags": tags,
+                    "security-severity": security_severity(&alert.severity),
+                },
+            });
+            if let Some(uri) = help_uri {
+                rule["helpUri"] = json!(uri);
+            }
+            rule
+        });
+
+        for uri in paths {
+            let fingerprint = finding_fingerprint(&alert.plugin_id, &uri.request_method, &uri.uri);
+            results.push(json!({
+                "ruleId": alert.plugin_id,
+                "level": level(&alert.severity),
+                "message": {
+                    "text": format!("{}: {} {}", alert.name, uri.request_method, uri.uri),
+                },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": {"uri": location},
+                        "region": {"startLine": 1},
+                    },
+                }],
+                "partialFingerprints": {"stackhawkFinding/v1": fingerprint},
+                "properties": {
+                    "method": uri.request_method,
+                    "path": uri.uri,
+                    "status": uri.status,
+                    "alertUriId": uri.alert_uri_id,
+                },
+            }));
+        }
+    }
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "StackHawk",
+                    "informationUri": "https://www.stackhawk.com",
+                    "semanticVersion": scan.scan.version,
+                    "rules": rules.into_values().collect::<Vec<_>>(),
+                },
+            },
+            "automationDetails": {
+                "id": format!("stackhawk/{}/{}/", scan.scan.application_name, scan.scan.env),
+            },
+            "properties": {
+                "scanId": scan.scan.id,
+                "applicationId": scan.scan.application_id,
+                "host": scan.app_host,
+            },
+            "results": results,
+        }],
+    })
+}
+
+/// SARIF result level for a StackHawk severity
+fn level(severity: &str) -> &'static str {
+    match severity.to_uppercase().as_str() {
+        "HIGH" => "error",
+        "MEDIUM" => "warning",
+        _ => "note",
+    }
+}
+
+/// GitHub `security-severity` score (High 8.0, Medium 5.0, Low 2.0)
+fn security_severity(severity: &str) -> &'static str {
+    match severity.to_uppercase().as_str() {
+        "HIGH" => "8.0",
+        "MEDIUM" => "5.0",
+        "LOW" => "2.0",
+        _ => "0.0",
+    }
+}
+
+/// Stable across scans: plugin, method, and path (the `scan diff` match key)
+fn finding_fingerprint(plugin_id: &str, method: &str, uri: &str) -> String {
+    let digest = Sha256::digest(format!("{}|{}|{}", plugin_id, method, uri));
+    digest.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn first_paragraph(text: &str) -> &str {
+    text.split("\n\n").next().unwrap_or(text).trim()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::fixtures::ScanResultBuilder;
+
+    fn alert(status: &str) -> AlertResponse {
+        serde_json::from_value(json!({
+            "alert": {
+                "pluginId": "40012",
+                "name": "Cross Site Scripting (Reflected)",
+                "description": "Reflected XSS.\n\nMore detail.",
+                "severity": "High",
+                "cweId": "79",
+                "references": ["https://owasp.org/xss"]
+            },
+            "applicationScanAlertUris": [
+                {"alertUriId": "u1", "uri": "/search", "requestMethod": "GET",
+                 "msgId": "m1", "status": "UNKNOWN", "pluginId": "40012"},
+                {"alertUriId": "u2", "uri": "/login", "requestMethod": "POST",
+                 "msgId": "m2", "status": status, "pluginId": "40012"}
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_from_scan_builds_rules_and_results() {
+        let scan = ScanResultBuilder::new("s1", "a1").build();
+        let log = from_scan(&scan, &[alert("FALSE_POSITIVE")], false, "stackhawk.yml");
+
+        let run = &log["runs"][0];
+        let rule = &run["tool"]["driver"]["rules"][0];
+        assert_eq!(rule["id"], "40012");
+        assert_eq!(rule["fullDescription"]["text"], "Reflected XSS.");
+        assert_eq!(rule["properties"]["security-severity"], "8.0");
+        assert_eq!(rule["properties"]["tags"][1], "external/cwe/cwe-79");
+        assert_eq!(rule["properties"]["tags"][2], "external/owasp/A03:2021");
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0]["level"], "error");
+        assert_eq!(
+            results[0]["message"]["text"],
+            "Cross Site Scripting (Reflected): GET /search"
+        );
+        assert_eq!(
+            results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "stackhawk.yml"
+        );
+    }
+
+    #[test]
+    fn test_from_scan_includes_triaged_on_request() {
+        let scan = ScanResultBuilder::new("s1", "a1").build();
+        let log = from_scan(&scan, &[alert("RISK_ACCEPTED")], true, "stackhawk.yml");
+
+        assert_eq!(log["runs"][0]["results"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_finding_fingerprint_is_stable() {
+        assert_eq!(
+            finding_fingerprint("1", "GET", "/a"),
+            finding_fingerprint("1", "GET", "/a")
+        );
+        assert_ne!(
+            finding_fingerprint("1", "GET", "/a"),
+            finding_fingerprint("1", "POST", "/a")
+        );
+    }
+}
@@ -0,0 +1,199 @@
+//! JSON Schema of command output (`--schema`)
+//!
+//! Output models derive [`JsonSchema`] next to `Serialize`, so a schema
+//! follows the serde attributes and doc comments of the fields it
+//! describes and can't drift from the output. Schemas follow JSON Schema
+//! draft 2020-12 with every subschema inlined, describing serialized output
+//! (fields skipped when empty are not required).
+
+use schemars::JsonSchema;
+use schemars::generate::{Contract, SchemaSettings};
+use serde_json::{Value, json};
+
+use super::json::JsonOutput;
+
+/// JSON Schema dialect of every document
+pub const DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Schema of `T`'s serialized JSON
+pub fn schema_of<T: JsonSchema + ?Sized>() -> Value {
+    let generator = SchemaSettings::draft2020_12()
+        .with(|settings| {
+            settings.inline_subschemas = true;
+            settings.contract = Contract::Serialize;
+            settings.meta_schema = None;
+        })
+        .into_generator();
+    let mut schema = generator.into_root_schema_for::<T>().to_value();
+    // The document has a title of its own
+    if let Some(object) = schema.as_object_mut() {
+        object.remove("title");
+    }
+    schema
+}
+
+/// Document for `T` printed as-is (`format_raw_json`)
+pub fn document<T: JsonSchema + ?Sized>(title: &str) -> Value {
+    let mut document = json!({ "$schema": DIALECT, "title": title });
+    if let (Some(document), Value::Object(schema)) = (document.as_object_mut(), schema_of::<T>()) {
+        document.extend(schema);
+    }
+    document
+}
+
+/// Document for `T` in the `{data, meta}` envelope (`format_json`)
+pub fn envelope<T: JsonSchema>(title: &str) -> Value {
+    document::<JsonOutput<T>>(title)
+}
+
+/// Ways `value` doesn't match `schema`, each with its JSON path
+///
+/// Checks the subset of JSON Schema that [`schema_of`] produces: types,
+/// required and declared properties, array items, map values, enums and
+/// constants, and `anyOf`/`oneOf`. Properties a schema doesn't declare are
+/// reported too, so output that bypasses its model is caught.
+#[cfg(test)]
+pub fn check(schema: &Value, value: &Value) -> Vec<String> {
+    let mut errors = Vec::new();
+    check_at(schema, value, "$", &mut errors);
+    errors
+}
+
+#[cfg(test)]
+fn check_at(schema: &Value, value: &Value, path: &str, errors: &mut Vec<String>) {
+    // `true` accepts any value (e.g. a `serde_json::Value` field)
+    if schema == &Value::Bool(true) {
+        return;
+    }
+    if let Some(Value::Array(options)) = schema.get("anyOf").or_else(|| schema.get("oneOf")) {
+        let matches = options.iter().any(|option| {
+            let mut option_errors = Vec::new();
+            check_at(option, value, path, &mut option_errors);
+            option_errors.is_empty()
+        });
+        if !matches {
+            errors.push(format!("{}: matches no anyOf option", path));
+        }
+        return;
+    }
+
+    if let Some(ty) = schema.get("type") {
+        let types: Vec<&str> = match ty {
+            Value::Array(types) => types.iter().filter_map(Value::as_str).collect(),
+            other => other.as_str().into_iter().collect(),
+        };
+        let actual = match value {
+            Value::Null => "null",
+            Value::Bool(_) => "boolean",
+            Value::Number(n) if n.is_i64() || n.is_u64() => "integer",
+            Value::Number(_) => "number",
+            Value::String(_) => "string",
+            Value::Array(_) => "array",
+            Value::Object(_) => "object",
+        };
+        let allowed = types
+            .iter()
+            .any(|ty| *ty == actual || (*ty == "number" && actual == "integer"));
+        if !allowed {
+            errors.push(format!(
+                "{}: expected {}, got {}",
+                path,
+                types.join("|"),
+                actual
+            ));
+            return;
+        }
+    }
+
+    if let Some(Value::Array(options)) = schema.get("enum")
+        && !options.contains(value)
+    {
+        errors.push(format!("{}: {} is not one of the enum values", path, value));
+    }
+    if let Some(constant) = schema.get("const")
+        && constant != value
+    {
+        errors.push(format!("{}: expected {}, got {}", path, constant, value));
+    }
+
+    match value {
+        Value::Array(items) => {
+            if let Some(item_schema) = schema.get("items") {
+                for (i, item) in items.iter().enumerate() {
+                    check_at(item_schema, item, &format!("{}[{}]", path, i), errors);
+                }
+            }
+        }
+        Value::Object(object) => {
+            let properties = schema.get("properties").and_then(Value::as_object);
+            if let Some(Value::Array(required)) = schema.get("required") {
+                for name in required.iter().filter_map(Value::as_str) {
+                    if !object.contains_key(name) {
+                        errors.push(format!("{}: missing required `{}`", path, name));
+                    }
+                }
+            }
+            for (name, field) in object {
+                let field_path = format!("{}.{}", path, name);
+                match (
+                    properties.and_then(|p| p.get(name)),
+                    schema.get("additionalProperties"),
+                ) {
+                    (Some(field_schema), _) => check_at(field_schema, field, &field_path, errors),
+                    (None, Some(value_schema)) if properties.is_none() => {
+                        check_at(value_schema, field, &field_path, errors)
+                    }
+                    _ => errors.push(format!("{}: not declared in the schema", field_path)),
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+
+    /// A row
+    #[derive(Serialize, JsonSchema)]
+    #[allow(dead_code)]
+    struct Row {
+        /// ID
+        id: String,
+        /// Count
+        count: Option<u32>,
+        /// Tags
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
+    }
+
+    #[test]
+    fn test_check_reports_mismatches() {
+        let schema = schema_of::<Row>();
+        assert_eq!(schema["properties"]["id"]["description"], "ID");
+
+        assert!(check(&schema, &json!({ "id": "a", "count": null })).is_empty());
+        assert!(check(&schema, &json!({ "id": "a", "count": 3, "tags": ["x"] })).is_empty());
+        assert_eq!(
+            check(&schema, &json!({ "count": "3", "extra": true })),
+            vec![
+                "$: missing required `id`",
+                "$.count: expected integer|null, got string",
+                "$.extra: not declared in the schema",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_envelope_lists_warning_kinds() {
+        let document = envelope::<Vec<Row>>("test");
+        assert_eq!(document["$schema"], DIALECT);
+        assert_eq!(document["title"], "test");
+        let schema = &document["properties"]["meta"]["properties"]["warnings"]["items"];
+        let warning = json!({ "kind": "partial_results", "message": "1 page(s) not fetched" });
+        assert!(check(schema, &warning).is_empty());
+        assert!(!check(schema, &json!({ "kind": "nope", "message": "" })).is_empty());
+    }
+}
@@ -0,0 +1,96 @@
+//! `--stats` summary printed after a command
+//!
+//! Shows the request metrics collected in [`hawkop_core::client::stats`]
+//! on stderr, so it never mixes with the command's output.
+
+use std::time::Duration;
+
+use colored::Colorize;
+
+use super::formatters::format_size;
+use crate::client::stats::Stats;
+
+/// Print the summary for a command that ran for `total`
+pub fn print(stats: &Stats, total: Duration) {
+    eprintln!("{}", "stats:".bold());
+    eprint!("{}", format(stats, total));
+}
+
+fn format(stats: &Stats, total: Duration) -> String {
+    let mut problems = Vec::new();
+    if stats.rate_limited > 0 {
+        problems.push(format!("{} rate-limited", stats.rate_limited));
+    }
+    if stats.failed_calls > 0 {
+        problems.push(format!("{} failed", stats.failed_calls));
+    }
+    let calls = if problems.is_empty() {
+        stats.api_calls.to_string()
+    } else {
+        format!("{} ({})", stats.api_calls, problems.join(", "))
+    };
+
+    let rows = [
+        ("API calls", calls),
+        (
+            "Cache",
+            format!(
+                "{}, {}",
+                count(stats.cache_hits, "hit", "hits"),
+                count(stats.cache_misses, "miss", "misses")
+            ),
+        ),
+        (
+            "Transferred",
+            format!(
+                "{} received, {} sent",
+                format_size(stats.bytes_received as usize),
+                format_size(stats.bytes_sent as usize)
+            ),
+        ),
+        (
+            "Request time",
+            format!(
+                "{:.2}s (summed over parallel requests)",
+                stats.request_time.as_secs_f64()
+            ),
+        ),
+        ("Total time", format!("{:.2}s", total.as_secs_f64())),
+    ];
+    rows.iter()
+        .map(|(label, value)| format!("  {:<14} {}\n", label, value))
+        .collect()
+}
+
+/// `n` with the singular or plural noun
+fn count(n: u64, one: &str, many: &str) -> String {
+    format!("{} {}", n, if n == 1 { one } else { many })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_summary() {
+        let stats = Stats {
+            api_calls: 14,
+            failed_calls: 0,
+            rate_limited: 2,
+            bytes_sent: 512,
+            bytes_received: 3 * 1024 * 1024 / 2,
+            request_time: Duration::from_millis(8420),
+            cache_hits: 1,
+            cache_misses: 14,
+        };
+
+        assert_eq!(
+            format(&stats, Duration::from_millis(2100)),
+            "  API calls      14 (2 rate-limited)\n\
+             \x20 Cache          1 hit, 14 misses\n\
+             \x20 Transferred    1.50 MB received, 512 bytes sent\n\
+             \x20 Request time   8.42s (summed over parallel requests)\n\
+             \x20 Total time     2.10s\n"
+        );
+    }
+}
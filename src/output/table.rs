@@ -1,6 +1,12 @@
 //! Table output formatting
+//!
+//! Severity, status, and findings columns are colored after rendering, so
+//! column widths are computed on plain text. Colors follow the `colored`
+//! crate's switch: off with `--no-color` or `NO_COLOR`, or when stdout isn't
+//! a terminal.
 
-use tabled::{Table, Tabled, settings::Style};
+use colored::{ColoredString, Colorize};
+use tabled::{Table, Tabled, builder::Builder, settings::Style};
 
 /// Format data as a table with clean minimal style
 pub fn format_table<T: Tabled>(data: &[T]) -> String {
@@ -9,14 +15,122 @@ pub fn format_table<T: Tabled>(data: &[T]) -> String {
     }
 
     let mut table = Table::new(data);
+    apply_style(&mut table);
+    highlight(table.to_string())
+}
 
-    // Clean minimal style: vertical separators, thin header underline, no outer borders
+/// Format rows whose columns are only known at runtime (e.g. `hawkop query`)
+pub fn format_rows(headers: &[String], rows: &[Vec<String>]) -> String {
+    if rows.is_empty() {
+        return "No results found.".to_string();
+    }
+
+    let mut builder = Builder::default();
+    builder.push_record(headers.iter().map(|h| h.to_uppercase()));
+    for row in rows {
+        builder.push_record(row.iter().cloned());
+    }
+
+    let mut table = builder.build();
+    apply_style(&mut table);
+    highlight(table.to_string())
+}
+
+/// Clean minimal style: vertical separators, thin header underline, no outer borders
+fn apply_style(table: &mut Table) {
     table.with(Style::blank().vertical('│').horizontals([(
         1,
         tabled::settings::style::HorizontalLine::new('─').intersection('┼'),
     )]));
+}
+
+/// Color the cells of known columns when colors are on
+fn highlight(table: String) -> String {
+    if !colored::control::SHOULD_COLORIZE.should_colorize() {
+        return table;
+    }
+    highlight_with(&table, |header, value| {
+        paint(header, value).map(|v| v.to_string())
+    })
+}
+
+/// Replace each cell's text with `paint(header, text)`, keeping its padding
+fn highlight_with(table: &str, paint: impl Fn(&str, &str) -> Option<String>) -> String {
+    let mut lines = table.lines();
+    let Some(header_line) = lines.next() else {
+        return table.to_string();
+    };
+    let headers: Vec<String> = header_line
+        .split('│')
+        .map(|h| h.trim().to_uppercase())
+        .collect();
+
+    let mut out = vec![header_line.to_string()];
+    for line in lines {
+        // The header underline has no `│`
+        if !line.contains('│') {
+            out.push(line.to_string());
+            continue;
+        }
+        let cells: Vec<String> = line
+            .split('│')
+            .enumerate()
+            .map(|(i, cell)| {
+                let value = cell.trim();
+                match headers.get(i).and_then(|h| paint(h, value)) {
+                    Some(painted) if !value.is_empty() => cell.replacen(value, &painted, 1),
+                    _ => cell.to_string(),
+                }
+            })
+            .collect();
+        out.push(cells.join("│"));
+    }
+    out.join("\n")
+}
+
+/// Colored form of a cell in a severity, status, or findings column
+fn paint(header: &str, value: &str) -> Option<ColoredString> {
+    match header {
+        "SEVERITY" | "SEV" => paint_severity(value),
+        "STATUS" => paint_status(value),
+        "FINDINGS" => Some(paint_findings(value)),
+        _ => None,
+    }
+}
 
-    table.to_string()
+fn paint_severity(value: &str) -> Option<ColoredString> {
+    match value.to_ascii_lowercase().as_str() {
+        "high" | "critical" => Some(value.red().bold()),
+        "medium" => Some(value.yellow()),
+        "low" => Some(value.blue()),
+        "info" | "informational" => Some(value.dimmed()),
+        _ => None,
+    }
+}
+
+fn paint_status(value: &str) -> Option<ColoredString> {
+    match value.to_ascii_lowercase().as_str() {
+        "running" | "started" | "scanning" | "pending" | "in progress" => Some(value.cyan()),
+        "failed" | "error" | "fail" => Some(value.red()),
+        "complete" | "completed" | "pass" | "success" => Some(value.green()),
+        _ => None,
+    }
+}
+
+/// Findings summaries like `3H1 5M0 2L0`: each count in its severity's color
+fn paint_findings(value: &str) -> ColoredString {
+    let painted: Vec<String> = value
+        .split(' ')
+        .map(
+            |part| match part.chars().find(|c| c.is_ascii_alphabetic()) {
+                Some('H') => part.red().bold().to_string(),
+                Some('M') => part.yellow().to_string(),
+                Some('L') => part.blue().to_string(),
+                _ => part.to_string(),
+            },
+        )
+        .collect();
+    painted.join(" ").normal()
 }
 
 #[cfg(test)]
@@ -31,6 +145,41 @@ mod tests {
         name: String,
     }
 
+    #[test]
+    fn test_format_rows() {
+        let headers = vec!["name".to_string(), "count(*)".to_string()];
+        let rows = vec![vec!["web".to_string(), "3".to_string()]];
+
+        let result = format_rows(&headers, &rows);
+
+        assert!(result.contains("NAME"));
+        assert!(result.contains("COUNT(*)"));
+        assert!(result.contains("web"));
+        assert_eq!(format_rows(&headers, &[]), "No results found.");
+    }
+
+    #[test]
+    fn test_highlight_known_columns() {
+        let table = format_rows(
+            &["name".to_string(), "severity".to_string()],
+            &[
+                vec!["sqli".to_string(), "High".to_string()],
+                vec!["xss".to_string(), "Unknown".to_string()],
+            ],
+        );
+        let painted = highlight_with(&table, |header, value| {
+            (header == "SEVERITY" && paint_severity(value).is_some())
+                .then(|| format!("<{}>", value))
+        });
+
+        let lines: Vec<&str> = painted.lines().collect();
+        assert!(lines[2].contains("<High>"));
+        assert!(!lines[3].contains('<'));
+        // Header, underline, and other columns are untouched
+        assert_eq!(lines[..2], table.lines().collect::<Vec<_>>()[..2]);
+        assert!(lines[2].starts_with(" sqli "));
+    }
+
     #[test]
     fn test_format_table_empty() {
         let items: Vec<TestRow> = vec![];
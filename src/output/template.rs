@@ -0,0 +1,141 @@
+//! `--template`: render JSON output through a user-supplied template
+//!
+//! Templates use Jinja2 syntax (rendered by minijinja). Each document a
+//! command would print as JSON is rendered instead, with the envelope's
+//! `data` and `meta` as variables, so a template sees exactly what
+//! `--format json` shows. Templates can `{% include %}` or `{% extends %}`
+//! files next to them, and output is HTML-escaped when the template file
+//! ends in `.html`.
+
+use std::path::Path;
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use minijinja::{Environment, Value, path_loader};
+
+use crate::error::{Error, Result};
+use crate::output::formatters::format_timestamp_local;
+
+/// Process-wide template set by `--template`
+static TEMPLATE: RwLock<Option<OutputTemplate>> = RwLock::new(None);
+
+/// Documents rendered so far
+static RENDERED: AtomicUsize = AtomicUsize::new(0);
+
+struct OutputTemplate {
+    env: Environment<'static>,
+    name: String,
+}
+
+/// Load and compile the template at `path` for this process
+///
+/// Syntax errors are reported here, before the command runs.
+pub fn load(path: &str) -> Result<()> {
+    let file = Path::new(path);
+    if !file.is_file() {
+        return Err(Error::Other(format!("Template file not found: {}", path)));
+    }
+    let name = file
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let dir = file
+        .parent()
+        .filter(|d| !d.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+
+    let mut env = environment();
+    env.set_loader(path_loader(dir));
+    env.get_template(&name)
+        .map_err(|e| Error::Other(format!("Invalid template {}: {:#}", path, e)))?;
+
+    *TEMPLATE.write().unwrap_or_else(|e| e.into_inner()) = Some(OutputTemplate { env, name });
+    Ok(())
+}
+
+/// Environment with hawkop's filters
+fn environment() -> Environment<'static> {
+    let mut env = Environment::new();
+    env.add_filter("timestamp", timestamp);
+    env
+}
+
+/// `{{ scan.timestamp | timestamp }}`: epoch milliseconds (number or string)
+/// as local time, the way tables show them
+fn timestamp(value: Value) -> String {
+    let millis = value
+        .as_i64()
+        .map(|ms| ms.to_string())
+        .unwrap_or_else(|| value.as_str().unwrap_or_default().to_string());
+    format_timestamp_local(&millis)
+}
+
+/// Render a `{data, meta}` envelope through the template, or `None` when no
+/// template is set
+///
+/// The envelope is only built when there is a template to render.
+pub fn render(
+    envelope: impl FnOnce() -> std::result::Result<serde_json::Value, serde_json::Error>,
+) -> Option<std::result::Result<String, serde_json::Error>> {
+    let template = TEMPLATE.read().unwrap_or_else(|e| e.into_inner());
+    let template = template.as_ref()?;
+    Some(envelope().and_then(|envelope| {
+        RENDERED.fetch_add(1, Ordering::Relaxed);
+        render_with(&template.env, &template.name, &envelope)
+            .map_err(<serde_json::Error as serde::ser::Error>::custom)
+    }))
+}
+
+/// Render template `name` with the envelope's `data` and `meta` as variables
+fn render_with(
+    env: &Environment<'_>,
+    name: &str,
+    envelope: &serde_json::Value,
+) -> std::result::Result<String, String> {
+    env.get_template(name)
+        .and_then(|t| t.render(envelope))
+        .map_err(|e| format!("template {} failed: {}", name, e))
+}
+
+/// Whether `--template` is set
+pub fn is_set() -> bool {
+    TEMPLATE.read().unwrap_or_else(|e| e.into_inner()).is_some()
+}
+
+/// How many documents have been rendered
+pub fn rendered() -> usize {
+    RENDERED.load(Ordering::Relaxed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_envelope() {
+        let mut env = environment();
+        env.add_template(
+            "apps.md",
+            "{% for app in data %}- {{ app.name }} ({{ app.id }})\n{% endfor %}\
+             {{ data | length }} apps, hawkop {{ meta.version }}",
+        )
+        .unwrap();
+        let envelope = serde_json::json!({
+            "data": [{"name": "web", "id": "a1"}, {"name": "api", "id": "a2"}],
+            "meta": {"version": "1.2.3"}
+        });
+
+        assert_eq!(
+            render_with(&env, "apps.md", &envelope).unwrap(),
+            "- web (a1)\n- api (a2)\n2 apps, hawkop 1.2.3"
+        );
+    }
+
+    #[test]
+    fn test_render_errors_name_the_template() {
+        let mut env = environment();
+        env.add_template("bad.txt", "{{ data | nope }}").unwrap();
+        let error = render_with(&env, "bad.txt", &serde_json::json!({"data": {}})).unwrap_err();
+        assert!(error.starts_with("template bad.txt failed"), "{}", error);
+    }
+}
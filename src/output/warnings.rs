@@ -0,0 +1,13 @@
+//! Structured warnings channel
+//!
+//! Re-exports the warning log from [`hawkop_core::warnings`] and adds the
+//! CLI's stderr printer, installed at startup.
+
+use colored::Colorize;
+
+pub use hawkop_core::warnings::*;
+
+/// Print a warning to stderr as `warning: <message>`
+pub fn print(warning: &Warning) {
+    eprintln!("{} {}", "warning:".yellow().bold(), warning.message);
+}
@@ -0,0 +1,171 @@
+//! OWASP Top 10 (2021) categories for findings
+//!
+//! Findings are mapped through their CWE, using the CWE lists OWASP
+//! publishes for each 2021 category. A CWE in no list (or a plugin without
+//! a CWE) has no category.
+
+use schemars::JsonSchema;
+use serde::Serialize;
+
+/// An OWASP Top 10 (2021) category
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct Category {
+    /// Category code, e.g. `A03:2021`
+    pub id: &'static str,
+
+    /// Category name, e.g. `Injection`
+    pub name: &'static str,
+}
+
+impl Category {
+    /// Short code, e.g. `A03`
+    pub fn code(&self) -> &'static str {
+        &self.id[..3]
+    }
+
+    /// Code and name, e.g. `A03:2021 Injection`
+    pub fn label(&self) -> String {
+        format!("{} {}", self.id, self.name)
+    }
+}
+
+/// The ten categories with the CWEs mapped to each
+const TOP_10: &[(Category, &[u32])] = &[
+    (
+        Category {
+            id: "A01:2021",
+            name: "Broken Access Control",
+        },
+        &[
+            22, 23, 35, 59, 200, 201, 219, 264, 275, 276, 284, 285, 352, 359, 377, 402, 425, 441,
+            497, 538, 540, 548, 552, 566, 601, 639, 651, 668, 706, 862, 863, 913, 922, 1275,
+        ],
+    ),
+    (
+        Category {
+            id: "A02:2021",
+            name: "Cryptographic Failures",
+        },
+        &[
+            261, 296, 310, 319, 321, 322, 323, 324, 325, 326, 327, 328, 329, 330, 331, 335, 336,
+            337, 338, 340, 347, 523, 720, 757, 759, 760, 780, 818, 916,
+        ],
+    ),
+    (
+        Category {
+            id: "A03:2021",
+            name: "Injection",
+        },
+        &[
+            20, 74, 75, 77, 78, 79, 80, 83, 87, 88, 89, 90, 91, 93, 94, 95, 96, 97, 98, 99, 100,
+            113, 116, 138, 184, 470, 471, 564, 610, 643, 644, 652, 917,
+        ],
+    ),
+    (
+        Category {
+            id: "A04:2021",
+            name: "Insecure Design",
+        },
+        &[
+            73, 183, 209, 213, 235, 256, 257, 266, 269, 280, 311, 312, 313, 316, 419, 430, 434,
+            444, 451, 472, 501, 522, 525, 539, 579, 598, 602, 642, 646, 650, 653, 656, 657, 799,
+            807, 840, 841, 927, 1021, 1173,
+        ],
+    ),
+    (
+        Category {
+            id: "A05:2021",
+            name: "Security Misconfiguration",
+        },
+        &[
+            2, 11, 13, 15, 16, 260, 315, 520, 526, 537, 541, 547, 611, 614, 756, 776, 942, 1004,
+            1032, 1174,
+        ],
+    ),
+    (
+        Category {
+            id: "A06:2021",
+            name: "Vulnerable and Outdated Components",
+        },
+        &[937, 1035, 1104],
+    ),
+    (
+        Category {
+            id: "A07:2021",
+            name: "Identification and Authentication Failures",
+        },
+        &[
+            255, 259, 287, 288, 290, 294, 295, 297, 300, 302, 304, 306, 307, 346, 384, 521, 613,
+            620, 640, 798, 940, 1216,
+        ],
+    ),
+    (
+        Category {
+            id: "A08:2021",
+            name: "Software and Data Integrity Failures",
+        },
+        &[345, 353, 426, 494, 502, 565, 784, 829, 830, 915],
+    ),
+    (
+        Category {
+            id: "A09:2021",
+            name: "Security Logging and Monitoring Failures",
+        },
+        &[117, 223, 532, 778],
+    ),
+    (
+        Category {
+            id: "A10:2021",
+            name: "Server-Side Request Forgery",
+        },
+        &[918],
+    ),
+];
+
+/// Category for a CWE ID as the API reports it (`"79"`, `"CWE-79"`)
+///
+/// ZAP reports `-1` or `0` for plugins without a CWE; those have none.
+pub fn for_cwe(cwe_id: Option<&str>) -> Option<Category> {
+    let id = cwe_id?.trim();
+    let id = id
+        .strip_prefix("CWE-")
+        .or_else(|| id.strip_prefix("cwe-"))
+        .unwrap_or(id);
+    let cwe: u32 = id.parse().ok()?;
+    TOP_10
+        .iter()
+        .find(|(_, cwes)| cwes.contains(&cwe))
+        .map(|(category, _)| *category)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_cwe() {
+        assert_eq!(for_cwe(Some("79")).unwrap().id, "A03:2021");
+        assert_eq!(
+            for_cwe(Some("CWE-918")).unwrap().name,
+            "Server-Side Request Forgery"
+        );
+        assert_eq!(for_cwe(Some(" 352 ")).unwrap().code(), "A01");
+        assert_eq!(
+            for_cwe(Some("89")).map(|c| c.label()).as_deref(),
+            Some("A03:2021 Injection")
+        );
+        assert_eq!(for_cwe(Some("1")), None);
+        assert_eq!(for_cwe(Some("-1")), None);
+        assert_eq!(for_cwe(None), None);
+    }
+
+    #[test]
+    fn test_each_cwe_maps_to_one_category() {
+        let mut seen = std::collections::HashSet::new();
+        for (_, cwes) in TOP_10 {
+            for cwe in *cwes {
+                assert!(seen.insert(cwe), "CWE-{} is listed twice", cwe);
+            }
+        }
+    }
+}
@@ -0,0 +1,961 @@
+//! Query evaluation over an in-memory [`Dataset`]
+
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+use serde::ser::{SerializeMap, SerializeSeq};
+use serde::{Serialize, Serializer};
+
+use crate::error::QueryError;
+
+use super::parser::{AggFunc, CmpOp, ColumnRef, Expr, OrderItem, Query, SelectItem};
+use super::schema;
+use super::{Dataset, Value};
+
+/// Result of a query: labeled columns and rows
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryResult {
+    /// Output column labels (aliases or expression text)
+    pub columns: Vec<String>,
+
+    /// Output rows
+    pub rows: Vec<Vec<Value>>,
+}
+
+/// Serializes as an array of objects keyed by column label
+impl Serialize for QueryResult {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.rows.len()))?;
+        for row in &self.rows {
+            seq.serialize_element(&LabeledRow {
+                columns: &self.columns,
+                values: row,
+            })?;
+        }
+        seq.end()
+    }
+}
+
+struct LabeledRow<'a> {
+    columns: &'a [String],
+    values: &'a [Value],
+}
+
+impl Serialize for LabeledRow<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.columns.len()))?;
+        for (column, value) in self.columns.iter().zip(self.values) {
+            map.serialize_entry(column, value)?;
+        }
+        map.end()
+    }
+}
+
+fn invalid(message: impl Into<String>) -> QueryError {
+    QueryError::Invalid(message.into())
+}
+
+/// One column of the joined row set
+#[derive(Debug, Clone)]
+struct SchemaColumn {
+    /// Table alias (or name when unaliased)
+    qualifier: String,
+    /// Underlying table name
+    table: String,
+    column: String,
+}
+
+/// Joined rows and their combined schema
+struct RowSet {
+    schema: Vec<SchemaColumn>,
+    rows: Vec<Vec<Value>>,
+}
+
+impl RowSet {
+    fn resolve(&self, col: &ColumnRef) -> Result<usize, QueryError> {
+        let matches: Vec<usize> = self
+            .schema
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| {
+                c.column.eq_ignore_ascii_case(&col.column)
+                    && col.table.as_ref().is_none_or(|t| {
+                        c.qualifier.eq_ignore_ascii_case(t) || c.table.eq_ignore_ascii_case(t)
+                    })
+            })
+            .map(|(i, _)| i)
+            .collect();
+
+        match matches.as_slice() {
+            [i] => Ok(*i),
+            [] => {
+                if let Some(table) = &col.table
+                    && !self
+                        .schema
+                        .iter()
+                        .any(|c| c.qualifier.eq_ignore_ascii_case(table))
+                {
+                    return Err(invalid(format!(
+                        "Unknown table or alias '{}' in '{}'",
+                        table, col
+                    )));
+                }
+                Err(invalid(format!("Unknown column '{}'", col)))
+            }
+            many => {
+                let options: Vec<String> = many
+                    .iter()
+                    .map(|i| format!("{}.{}", self.schema[*i].qualifier, self.schema[*i].column))
+                    .collect();
+                Err(invalid(format!(
+                    "Ambiguous column '{}'; qualify it as one of: {}",
+                    col,
+                    options.join(", ")
+                )))
+            }
+        }
+    }
+
+    fn qualifiers(&self) -> Vec<(&str, &str)> {
+        let mut seen = Vec::new();
+        for c in &self.schema {
+            let pair = (c.qualifier.as_str(), c.table.as_str());
+            if !seen.contains(&pair) {
+                seen.push(pair);
+            }
+        }
+        seen
+    }
+}
+
+/// Expression with column references resolved to row indexes
+#[derive(Debug, Clone)]
+enum Bound {
+    Col(usize),
+    Lit(Value),
+    Agg {
+        func: AggFunc,
+        arg: Option<Box<Bound>>,
+        distinct: bool,
+    },
+    Cmp(Box<Bound>, CmpOp, Box<Bound>),
+    Like(Box<Bound>, Box<Bound>, bool),
+    In(Box<Bound>, Vec<Bound>, bool),
+    IsNull(Box<Bound>, bool),
+    And(Box<Bound>, Box<Bound>),
+    Or(Box<Bound>, Box<Bound>),
+    Not(Box<Bound>),
+}
+
+fn bind(expr: &Expr, rs: &RowSet) -> Result<Bound, QueryError> {
+    let b = |e: &Expr| bind(e, rs).map(Box::new);
+    Ok(match expr {
+        Expr::Column(c) => Bound::Col(rs.resolve(c)?),
+        Expr::Literal(v) => Bound::Lit(v.clone()),
+        Expr::Aggregate {
+            func,
+            arg,
+            distinct,
+        } => {
+            if arg.as_ref().is_some_and(|a| a.has_aggregate()) {
+                return Err(invalid("Aggregates cannot be nested"));
+            }
+            Bound::Agg {
+                func: *func,
+                arg: arg.as_deref().map(b).transpose()?,
+                distinct: *distinct,
+            }
+        }
+        Expr::Compare { left, op, right } => Bound::Cmp(b(left)?, *op, b(right)?),
+        Expr::Like {
+            expr,
+            pattern,
+            negated,
+        } => Bound::Like(b(expr)?, b(pattern)?, *negated),
+        Expr::In {
+            expr,
+            list,
+            negated,
+        } => Bound::In(
+            b(expr)?,
+            list.iter().map(|e| bind(e, rs)).collect::<Result<_, _>>()?,
+            *negated,
+        ),
+        Expr::IsNull { expr, negated } => Bound::IsNull(b(expr)?, *negated),
+        Expr::And(l, r) => Bound::And(b(l)?, b(r)?),
+        Expr::Or(l, r) => Bound::Or(b(l)?, b(r)?),
+        Expr::Not(e) => Bound::Not(b(e)?),
+    })
+}
+
+fn bool_value(b: Option<bool>) -> Value {
+    match b {
+        Some(true) => Value::Int(1),
+        Some(false) => Value::Int(0),
+        None => Value::Null,
+    }
+}
+
+fn truthy(v: &Value) -> Option<bool> {
+    match v {
+        Value::Null => None,
+        other => Some(other.as_f64().is_some_and(|f| f != 0.0)),
+    }
+}
+
+/// Case-insensitive LIKE with `%` and `_` wildcards
+fn like(text: &str, pattern: &str) -> bool {
+    let t: Vec<char> = text.to_lowercase().chars().collect();
+    let p: Vec<char> = pattern.to_lowercase().chars().collect();
+
+    // dp[j]: pattern[..i] matches text[..j]
+    let mut dp = vec![false; t.len() + 1];
+    dp[0] = true;
+    for pc in &p {
+        let mut next = vec![false; t.len() + 1];
+        match pc {
+            '%' => {
+                let mut any = false;
+                for j in 0..=t.len() {
+                    any |= dp[j];
+                    next[j] = any;
+                }
+            }
+            _ => {
+                for j in 1..=t.len() {
+                    next[j] = dp[j - 1] && (*pc == '_' || *pc == t[j - 1]);
+                }
+            }
+        }
+        dp = next;
+    }
+    dp[t.len()]
+}
+
+/// Evaluate `expr` where column references read from `row` and aggregates
+/// (if any) are computed over `group`
+fn eval(expr: &Bound, row: &[Value], group: &[&Vec<Value>]) -> Value {
+    match expr {
+        Bound::Col(i) => row[*i].clone(),
+        Bound::Lit(v) => v.clone(),
+        Bound::Agg {
+            func,
+            arg,
+            distinct,
+        } => aggregate(*func, arg.as_deref(), *distinct, group),
+        Bound::Cmp(l, op, r) => {
+            let ord = eval(l, row, group).compare(&eval(r, row, group));
+            bool_value(ord.map(|o| match op {
+                CmpOp::Eq => o == Ordering::Equal,
+                CmpOp::Ne => o != Ordering::Equal,
+                CmpOp::Lt => o == Ordering::Less,
+                CmpOp::Le => o != Ordering::Greater,
+                CmpOp::Gt => o == Ordering::Greater,
+                CmpOp::Ge => o != Ordering::Less,
+            }))
+        }
+        Bound::Like(e, p, negated) => {
+            let (v, p) = (eval(e, row, group), eval(p, row, group));
+            if v.is_null() || p.is_null() {
+                return Value::Null;
+            }
+            bool_value(Some(like(&v.to_string(), &p.to_string()) != *negated))
+        }
+        Bound::In(e, list, negated) => {
+            let v = eval(e, row, group);
+            if v.is_null() {
+                return Value::Null;
+            }
+            let found = list
+                .iter()
+                .any(|item| v.compare(&eval(item, row, group)) == Some(Ordering::Equal));
+            bool_value(Some(found != *negated))
+        }
+        Bound::IsNull(e, negated) => bool_value(Some(eval(e, row, group).is_null() != *negated)),
+        Bound::And(l, r) => match (truthy(&eval(l, row, group)), truthy(&eval(r, row, group))) {
+            (Some(false), _) | (_, Some(false)) => bool_value(Some(false)),
+            (Some(true), Some(true)) => bool_value(Some(true)),
+            _ => Value::Null,
+        },
+        Bound::Or(l, r) => match (truthy(&eval(l, row, group)), truthy(&eval(r, row, group))) {
+            (Some(true), _) | (_, Some(true)) => bool_value(Some(true)),
+            (Some(false), Some(false)) => bool_value(Some(false)),
+            _ => Value::Null,
+        },
+        Bound::Not(e) => bool_value(truthy(&eval(e, row, group)).map(|b| !b)),
+    }
+}
+
+fn aggregate(func: AggFunc, arg: Option<&Bound>, distinct: bool, group: &[&Vec<Value>]) -> Value {
+    let Some(arg) = arg else {
+        // count(*)
+        return group.len().into();
+    };
+
+    let mut values: Vec<Value> = group
+        .iter()
+        .map(|row| eval(arg, row, &[]))
+        .filter(|v| !v.is_null())
+        .collect();
+    if distinct {
+        let mut seen = HashSet::new();
+        values.retain(|v| seen.insert(v.key()));
+    }
+
+    match func {
+        AggFunc::Count => values.len().into(),
+        AggFunc::Sum | AggFunc::Avg => {
+            let nums: Vec<f64> = values.iter().filter_map(Value::as_f64).collect();
+            if nums.is_empty() {
+                return if func == AggFunc::Sum {
+                    Value::Int(0)
+                } else {
+                    Value::Null
+                };
+            }
+            let sum: f64 = nums.iter().sum();
+            if func == AggFunc::Avg {
+                Value::Float(sum / nums.len() as f64)
+            } else if sum.fract() == 0.0 {
+                Value::Int(sum as i64)
+            } else {
+                Value::Float(sum)
+            }
+        }
+        AggFunc::Min => values
+            .into_iter()
+            .min_by(|a, b| a.sort_cmp(b))
+            .unwrap_or(Value::Null),
+        AggFunc::Max => values
+            .into_iter()
+            .max_by(|a, b| a.sort_cmp(b))
+            .unwrap_or(Value::Null),
+    }
+}
+
+/// Build the joined row set for FROM + JOINs
+fn join_tables(query: &Query, data: &Dataset) -> Result<RowSet, QueryError> {
+    let load = |name: &str| {
+        data.get(name).ok_or_else(|| {
+            let known: Vec<&str> = schema::TABLES.iter().map(|t| t.name).collect();
+            invalid(format!(
+                "Unknown table '{}'. Available tables: {}",
+                name,
+                known.join(", ")
+            ))
+        })
+    };
+
+    let from = load(&query.from.name)?;
+    let mut rs = RowSet {
+        schema: from
+            .columns
+            .iter()
+            .map(|c| SchemaColumn {
+                qualifier: query.from.qualifier().to_string(),
+                table: query.from.name.clone(),
+                column: c.clone(),
+            })
+            .collect(),
+        rows: from.rows.clone(),
+    };
+
+    for join in &query.joins {
+        let right = load(&join.table.name)?;
+        let qualifier = join.table.qualifier();
+        if rs
+            .qualifiers()
+            .iter()
+            .any(|(q, _)| q.eq_ignore_ascii_case(qualifier))
+        {
+            return Err(invalid(format!(
+                "Table '{}' appears more than once; give it an alias",
+                qualifier
+            )));
+        }
+
+        let right_schema: Vec<SchemaColumn> = right
+            .columns
+            .iter()
+            .map(|c| SchemaColumn {
+                qualifier: qualifier.to_string(),
+                table: join.table.name.clone(),
+                column: c.clone(),
+            })
+            .collect();
+        let right_set = RowSet {
+            schema: right_schema.clone(),
+            rows: Vec::new(),
+        };
+
+        // Key columns: (index into left rows, index into right rows)
+        let (left_key, right_key) = match &join.on {
+            Some((a, b)) => match (rs.resolve(a), right_set.resolve(b)) {
+                (Ok(l), Ok(r)) => (l, r),
+                _ => match (rs.resolve(b), right_set.resolve(a)) {
+                    (Ok(l), Ok(r)) => (l, r),
+                    _ => {
+                        return Err(invalid(format!(
+                            "ON clause must compare a column of '{}' with an earlier table: {} = {}",
+                            qualifier, a, b
+                        )));
+                    }
+                },
+            },
+            None => {
+                let inferred = rs.qualifiers().into_iter().find_map(|(q, table)| {
+                    schema::relationship(table, &join.table.name)
+                        .map(|(lc, rc)| (q.to_string(), lc, rc))
+                });
+                let Some((left_q, lc, rc)) = inferred else {
+                    return Err(invalid(format!(
+                        "Don't know how to join '{}'; add ON <a.col> = <b.col>",
+                        join.table.name
+                    )));
+                };
+                let l = rs.resolve(&ColumnRef {
+                    table: Some(left_q),
+                    column: lc.to_string(),
+                })?;
+                let r = right_set.resolve(&ColumnRef {
+                    table: Some(qualifier.to_string()),
+                    column: rc.to_string(),
+                })?;
+                (l, r)
+            }
+        };
+
+        let mut index: HashMap<String, Vec<usize>> = HashMap::new();
+        for (i, row) in right.rows.iter().enumerate() {
+            if !row[right_key].is_null() {
+                index.entry(row[right_key].key()).or_default().push(i);
+            }
+        }
+
+        let width = right.columns.len();
+        let mut rows = Vec::new();
+        for left_row in &rs.rows {
+            let matches = if left_row[left_key].is_null() {
+                None
+            } else {
+                index.get(&left_row[left_key].key())
+            };
+            match matches {
+                Some(idxs) => {
+                    for &i in idxs {
+                        let mut row = left_row.clone();
+                        row.extend(right.rows[i].iter().cloned());
+                        rows.push(row);
+                    }
+                }
+                None if join.left => {
+                    let mut row = left_row.clone();
+                    row.extend(std::iter::repeat_n(Value::Null, width));
+                    rows.push(row);
+                }
+                None => {}
+            }
+        }
+
+        rs.schema.extend(right_schema);
+        rs.rows = rows;
+    }
+
+    Ok(rs)
+}
+
+/// Sort key source for an ORDER BY item
+enum SortKey {
+    Output(usize),
+    Expr(Bound),
+}
+
+/// Execute a parsed query against the dataset
+pub fn execute(query: &Query, data: &Dataset) -> Result<QueryResult, QueryError> {
+    let rs = join_tables(query, data)?;
+
+    // Expand projection
+    let mut exprs: Vec<(Expr, String, Option<String>)> = Vec::new();
+    for item in &query.select {
+        match item {
+            SelectItem::Wildcard => {
+                for c in &rs.schema {
+                    let col = ColumnRef {
+                        table: Some(c.qualifier.clone()),
+                        column: c.column.clone(),
+                    };
+                    exprs.push((Expr::Column(col), c.column.clone(), None));
+                }
+            }
+            SelectItem::Expr { expr, alias } => {
+                let label = alias.clone().unwrap_or_else(|| expr.label());
+                exprs.push((expr.clone(), label, alias.clone()));
+            }
+        }
+    }
+    let columns = unique_labels(&exprs);
+
+    let select: Vec<Bound> = exprs
+        .iter()
+        .map(|(e, _, _)| bind(e, &rs))
+        .collect::<Result<_, _>>()?;
+
+    if let Some(filter) = &query.filter
+        && filter.has_aggregate()
+    {
+        return Err(invalid(
+            "Aggregates are not allowed in WHERE; use HAVING instead",
+        ));
+    }
+    let filter = query.filter.as_ref().map(|f| bind(f, &rs)).transpose()?;
+
+    // GROUP BY items may name a select alias or position
+    let group_exprs: Vec<Expr> = query
+        .group_by
+        .iter()
+        .map(|g| resolve_output_ref(g, &exprs).map_or_else(|| g.clone(), |i| exprs[i].0.clone()))
+        .collect();
+    if group_exprs.iter().any(Expr::has_aggregate) {
+        return Err(invalid("Aggregates are not allowed in GROUP BY"));
+    }
+    let group_by: Vec<Bound> = group_exprs
+        .iter()
+        .map(|g| bind(g, &rs))
+        .collect::<Result<_, _>>()?;
+
+    let grouped = !group_by.is_empty()
+        || query.having.is_some()
+        || exprs.iter().any(|(e, _, _)| e.has_aggregate());
+
+    if grouped {
+        let group_cols: Vec<usize> = group_by
+            .iter()
+            .filter_map(|g| match g {
+                Bound::Col(i) => Some(*i),
+                _ => None,
+            })
+            .collect();
+        for (expr, label, _) in &exprs {
+            check_grouped(expr, &rs, &group_cols, label)?;
+        }
+    }
+
+    let having = query
+        .having
+        .as_ref()
+        .map(|h| bind(&resolve_aliases(h, &exprs), &rs))
+        .transpose()?;
+
+    let sort_keys: Vec<(SortKey, bool)> = query
+        .order_by
+        .iter()
+        .map(|item| order_key(item, &exprs, &rs, grouped))
+        .collect::<Result<_, _>>()?;
+
+    let filtered: Vec<&Vec<Value>> = rs
+        .rows
+        .iter()
+        .filter(|row| {
+            filter
+                .as_ref()
+                .is_none_or(|f| truthy(&eval(f, row, &[])) == Some(true))
+        })
+        .collect();
+
+    // (output row, sort values)
+    let mut out: Vec<(Vec<Value>, Vec<Value>)> = Vec::new();
+    let project = |row: &[Value], group: &[&Vec<Value>]| -> (Vec<Value>, Vec<Value>) {
+        let values: Vec<Value> = select.iter().map(|e| eval(e, row, group)).collect();
+        let keys = sort_keys
+            .iter()
+            .map(|(k, _)| match k {
+                SortKey::Output(i) => values[*i].clone(),
+                SortKey::Expr(e) => eval(e, row, group),
+            })
+            .collect();
+        (values, keys)
+    };
+
+    if grouped {
+        let mut order: Vec<String> = Vec::new();
+        let mut groups: HashMap<String, Vec<&Vec<Value>>> = HashMap::new();
+        for row in filtered {
+            let key = group_by
+                .iter()
+                .map(|g| eval(g, row, &[]).key())
+                .collect::<Vec<_>>()
+                .join("\u{1f}");
+            if !groups.contains_key(&key) {
+                order.push(key.clone());
+            }
+            groups.entry(key).or_default().push(row);
+        }
+
+        // Aggregates without GROUP BY always produce one row
+        if group_by.is_empty() && order.is_empty() {
+            order.push(String::new());
+            groups.insert(String::new(), Vec::new());
+        }
+
+        let null_row = vec![Value::Null; rs.schema.len()];
+        for key in order {
+            let rows = &groups[&key];
+            let first = rows.first().map_or(null_row.as_slice(), |r| r.as_slice());
+            if let Some(h) = &having
+                && truthy(&eval(h, first, rows)) != Some(true)
+            {
+                continue;
+            }
+            out.push(project(first, rows));
+        }
+    } else {
+        for row in filtered {
+            out.push(project(row, &[]));
+        }
+    }
+
+    if !sort_keys.is_empty() {
+        out.sort_by(|(_, a), (_, b)| {
+            for (i, (_, desc)) in sort_keys.iter().enumerate() {
+                let ord = a[i].sort_cmp(&b[i]);
+                let ord = if *desc { ord.reverse() } else { ord };
+                if ord != Ordering::Equal {
+                    return ord;
+                }
+            }
+            Ordering::Equal
+        });
+    }
+
+    let mut rows: Vec<Vec<Value>> = out.into_iter().map(|(values, _)| values).collect();
+    if let Some(limit) = query.limit {
+        rows.truncate(limit);
+    }
+
+    Ok(QueryResult { columns, rows })
+}
+
+/// Output labels, qualifying or suffixing duplicates so JSON keys stay unique
+fn unique_labels(exprs: &[(Expr, String, Option<String>)]) -> Vec<String> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for (_, label, _) in exprs {
+        *counts.entry(label.as_str()).or_default() += 1;
+    }
+
+    let mut seen: HashSet<String> = HashSet::new();
+    exprs
+        .iter()
+        .map(|(expr, label, alias)| {
+            let mut label = label.clone();
+            if counts[label.as_str()] > 1
+                && alias.is_none()
+                && let Expr::Column(c) = expr
+            {
+                label = c.to_string();
+            }
+            let base = label.clone();
+            let mut n = 2;
+            while !seen.insert(label.clone()) {
+                label = format!("{}_{}", base, n);
+                n += 1;
+            }
+            label
+        })
+        .collect()
+}
+
+/// Match an ORDER BY / GROUP BY item to an output column by position or alias
+fn resolve_output_ref(expr: &Expr, exprs: &[(Expr, String, Option<String>)]) -> Option<usize> {
+    match expr {
+        Expr::Literal(Value::Int(n)) if *n >= 1 && (*n as usize) <= exprs.len() => {
+            Some(*n as usize - 1)
+        }
+        Expr::Column(ColumnRef {
+            table: None,
+            column,
+        }) => exprs.iter().position(|(_, _, alias)| {
+            alias
+                .as_ref()
+                .is_some_and(|a| a.eq_ignore_ascii_case(column))
+        }),
+        _ => None,
+    }
+}
+
+/// Replace bare references to select aliases (for HAVING)
+fn resolve_aliases(expr: &Expr, exprs: &[(Expr, String, Option<String>)]) -> Expr {
+    let r = |e: &Expr| Box::new(resolve_aliases(e, exprs));
+    match expr {
+        Expr::Column(ColumnRef { table: None, .. }) => match resolve_output_ref(expr, exprs) {
+            Some(i) => exprs[i].0.clone(),
+            None => expr.clone(),
+        },
+        Expr::Compare { left, op, right } => Expr::Compare {
+            left: r(left),
+            op: *op,
+            right: r(right),
+        },
+        Expr::Like {
+            expr,
+            pattern,
+            negated,
+        } => Expr::Like {
+            expr: r(expr),
+            pattern: r(pattern),
+            negated: *negated,
+        },
+        Expr::In {
+            expr,
+            list,
+            negated,
+        } => Expr::In {
+            expr: r(expr),
+            list: list.iter().map(|e| resolve_aliases(e, exprs)).collect(),
+            negated: *negated,
+        },
+        Expr::IsNull { expr, negated } => Expr::IsNull {
+            expr: r(expr),
+            negated: *negated,
+        },
+        Expr::And(a, b) => Expr::And(r(a), r(b)),
+        Expr::Or(a, b) => Expr::Or(r(a), r(b)),
+        Expr::Not(e) => Expr::Not(r(e)),
+        other => other.clone(),
+    }
+}
+
+fn order_key(
+    item: &OrderItem,
+    exprs: &[(Expr, String, Option<String>)],
+    rs: &RowSet,
+    grouped: bool,
+) -> Result<(SortKey, bool), QueryError> {
+    if let Expr::Literal(Value::Int(n)) = item.expr
+        && (n < 1 || n as usize > exprs.len())
+    {
+        return Err(invalid(format!(
+            "ORDER BY position {} is out of range (1-{})",
+            n,
+            exprs.len()
+        )));
+    }
+    if let Some(i) = resolve_output_ref(&item.expr, exprs) {
+        return Ok((SortKey::Output(i), item.desc));
+    }
+    // Same expression as an output column (e.g. ORDER BY count(*))
+    if let Some(i) = exprs.iter().position(|(e, _, _)| *e == item.expr) {
+        return Ok((SortKey::Output(i), item.desc));
+    }
+    if grouped {
+        return Err(invalid(format!(
+            "ORDER BY '{}' must refer to a selected column in a grouped query",
+            item.expr.label()
+        )));
+    }
+    Ok((SortKey::Expr(bind(&item.expr, rs)?), item.desc))
+}
+
+/// Ensure non-aggregated column references are GROUP BY columns
+fn check_grouped(
+    expr: &Expr,
+    rs: &RowSet,
+    group_cols: &[usize],
+    label: &str,
+) -> Result<(), QueryError> {
+    match expr {
+        Expr::Aggregate { .. } | Expr::Literal(_) => Ok(()),
+        Expr::Column(c) => {
+            let i = rs.resolve(c)?;
+            if group_cols.contains(&i) {
+                Ok(())
+            } else {
+                Err(invalid(format!(
+                    "Column '{}' must appear in GROUP BY or be used in an aggregate",
+                    label
+                )))
+            }
+        }
+        Expr::Compare { left, right, .. } => {
+            check_grouped(left, rs, group_cols, label)?;
+            check_grouped(right, rs, group_cols, label)
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::{Table, parse};
+
+    fn dataset() -> Dataset {
+        let mut apps = Table::new(&["id", "name", "env"]);
+        apps.push(vec![
+            Value::text("a1"),
+            Value::text("web"),
+            Value::text("prod"),
+        ]);
+        apps.push(vec![
+            Value::text("a2"),
+            Value::text("api"),
+            Value::text("prod"),
+        ]);
+        apps.push(vec![Value::text("a3"), Value::text("legacy"), Value::Null]);
+
+        let mut latest = Table::new(&["id", "app_id", "high", "findings"]);
+        latest.push(vec![
+            Value::text("s1"),
+            Value::text("a1"),
+            3i64.into(),
+            7i64.into(),
+        ]);
+        latest.push(vec![
+            Value::text("s2"),
+            Value::text("a2"),
+            1i64.into(),
+            2i64.into(),
+        ]);
+
+        let mut team_apps = Table::new(&["team_id", "team", "app_id"]);
+        team_apps.push(vec![
+            Value::text("t1"),
+            Value::text("Payments"),
+            Value::text("a1"),
+        ]);
+        team_apps.push(vec![
+            Value::text("t1"),
+            Value::text("Payments"),
+            Value::text("a2"),
+        ]);
+        team_apps.push(vec![
+            Value::text("t2"),
+            Value::text("Platform"),
+            Value::text("a3"),
+        ]);
+
+        let mut data = Dataset::new();
+        data.insert("apps", apps);
+        data.insert("latest_scan", latest);
+        data.insert("team_apps", team_apps);
+        data
+    }
+
+    fn run(sql: &str) -> Result<QueryResult, QueryError> {
+        execute(&parse(sql).unwrap(), &dataset())
+    }
+
+    fn cells(result: &QueryResult) -> Vec<Vec<String>> {
+        result
+            .rows
+            .iter()
+            .map(|r| r.iter().map(|v| v.to_string()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_select_where_order() {
+        let r = run("select name from apps where env = 'PROD' order by name").unwrap();
+        assert_eq!(r.columns, vec!["name"]);
+        assert_eq!(cells(&r), vec![vec!["api"], vec!["web"]]);
+    }
+
+    #[test]
+    fn test_inferred_join_group_by() {
+        let r = run(
+            "select team, count(*) as apps, sum(latest_scan.findings) as findings \
+             from apps join team_apps left join latest_scan \
+             group by team order by findings desc",
+        )
+        .unwrap();
+        assert_eq!(r.columns, vec!["team", "apps", "findings"]);
+        assert_eq!(
+            cells(&r),
+            vec![vec!["Payments", "2", "9"], vec!["Platform", "1", "0"]]
+        );
+    }
+
+    #[test]
+    fn test_left_join_nulls() {
+        let r = run("select name from apps a left join latest_scan l where l.id is null").unwrap();
+        assert_eq!(cells(&r), vec![vec!["legacy"]]);
+
+        let inner = run("select name from apps join latest_scan").unwrap();
+        assert_eq!(inner.rows.len(), 2);
+    }
+
+    #[test]
+    fn test_explicit_on_and_having() {
+        let r = run("select t.team, max(l.high) as worst from team_apps t \
+             join latest_scan l on l.app_id = t.app_id \
+             group by t.team having worst >= 3")
+        .unwrap();
+        assert_eq!(cells(&r), vec![vec!["Payments", "3"]]);
+    }
+
+    #[test]
+    fn test_aggregate_without_group_by() {
+        let r = run("select count(*), avg(high) from latest_scan where high > 100").unwrap();
+        assert_eq!(r.rows, vec![vec![Value::Int(0), Value::Null]]);
+    }
+
+    #[test]
+    fn test_like_in_and_limit() {
+        let r = run(
+            "select name from apps where name like '%E%' or name in ('api') \
+             order by 1 desc limit 2",
+        )
+        .unwrap();
+        assert_eq!(cells(&r), vec![vec!["web"], vec!["legacy"]]);
+    }
+
+    #[test]
+    fn test_wildcard_qualifies_duplicate_labels() {
+        let r = run("select * from apps join latest_scan").unwrap();
+        assert_eq!(
+            r.columns,
+            vec![
+                "apps.id",
+                "name",
+                "env",
+                "latest_scan.id",
+                "app_id",
+                "high",
+                "findings"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let err = run("select name from nope").unwrap_err();
+        assert!(err.to_string().contains("Unknown table 'nope'"));
+
+        let err = run("select id from apps join latest_scan").unwrap_err();
+        assert!(err.to_string().contains("Ambiguous column 'id'"));
+
+        let err = run("select name, count(*) from apps").unwrap_err();
+        assert!(err.to_string().contains("GROUP BY"));
+
+        let err = run("select name from apps where count(*) > 1").unwrap_err();
+        assert!(err.to_string().contains("HAVING"));
+
+        let err = run("select bogus from apps").unwrap_err();
+        assert!(err.to_string().contains("Unknown column 'bogus'"));
+    }
+
+    #[test]
+    fn test_like_matcher() {
+        assert!(like("WebStore", "web%"));
+        assert!(like("webstore", "%STORE"));
+        assert!(like("abc", "a_c"));
+        assert!(!like("abc", "a_"));
+        assert!(like("", "%"));
+    }
+
+    #[test]
+    fn test_json_serialization() {
+        let r = run("select name, env from apps where name = 'legacy'").unwrap();
+        let json = serde_json::to_value(&r).unwrap();
+        assert_eq!(json, serde_json::json!([{ "name": "legacy", "env": null }]));
+    }
+}
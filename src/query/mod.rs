@@ -0,0 +1,77 @@
+//! Embedded query language for `hawkop query`
+//!
+//! A deliberately small SQL subset evaluated over an in-memory dataset
+//! hydrated from API (or cached) resources:
+//!
+//! ```text
+//! SELECT expr [AS alias], ... | *
+//! FROM table [alias]
+//! [[LEFT] JOIN table [alias] [ON a.col = b.col]]...
+//! [WHERE cond [AND|OR cond]...]
+//! [GROUP BY expr, ...]
+//! [ORDER BY expr|alias|position [ASC|DESC], ...]
+//! [LIMIT n]
+//! ```
+//!
+//! Joins without `ON` use the built-in relationships in [`schema`]. Supported
+//! aggregates are `count`, `sum`, `min`, `max`, and `avg`.
+
+mod engine;
+mod parser;
+pub mod schema;
+mod value;
+
+pub use engine::execute;
+pub use parser::parse;
+pub use value::Value;
+
+use std::collections::BTreeMap;
+
+/// In-memory table: named columns and rows of values
+#[derive(Debug, Clone, Default)]
+pub struct Table {
+    /// Column names
+    pub columns: Vec<String>,
+
+    /// Rows, each with one value per column
+    pub rows: Vec<Vec<Value>>,
+}
+
+impl Table {
+    /// Create an empty table with the given columns
+    pub fn new(columns: &[&str]) -> Self {
+        Self {
+            columns: columns.iter().map(|c| c.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Append a row (must match the column count)
+    pub fn push(&mut self, row: Vec<Value>) {
+        debug_assert_eq!(row.len(), self.columns.len());
+        self.rows.push(row);
+    }
+}
+
+/// Named tables available to a query
+#[derive(Debug, Clone, Default)]
+pub struct Dataset {
+    tables: BTreeMap<String, Table>,
+}
+
+impl Dataset {
+    /// Create an empty dataset
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add or replace a table
+    pub fn insert(&mut self, name: &str, table: Table) {
+        self.tables.insert(name.to_string(), table);
+    }
+
+    /// Look up a table by name
+    pub fn get(&self, name: &str) -> Option<&Table> {
+        self.tables.get(name)
+    }
+}
@@ -0,0 +1,845 @@
+//! Tokenizer and recursive-descent parser for the query subset
+
+use crate::error::QueryError;
+
+use super::value::Value;
+
+/// Parsed query
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query {
+    /// Projection list
+    pub select: Vec<SelectItem>,
+
+    /// Primary table
+    pub from: TableRef,
+
+    /// Joined tables, in order
+    pub joins: Vec<Join>,
+
+    /// WHERE condition
+    pub filter: Option<Expr>,
+
+    /// GROUP BY expressions
+    pub group_by: Vec<Expr>,
+
+    /// HAVING condition (evaluated per group)
+    pub having: Option<Expr>,
+
+    /// ORDER BY items
+    pub order_by: Vec<OrderItem>,
+
+    /// LIMIT
+    pub limit: Option<usize>,
+}
+
+impl Query {
+    /// Names of all tables referenced by the query (FROM and JOINs)
+    pub fn tables(&self) -> Vec<&str> {
+        std::iter::once(&self.from)
+            .chain(self.joins.iter().map(|j| &j.table))
+            .map(|t| t.name.as_str())
+            .collect()
+    }
+}
+
+/// One item in the SELECT list
+#[derive(Debug, Clone, PartialEq)]
+pub enum SelectItem {
+    /// `*`: every column of every table
+    Wildcard,
+
+    /// Expression with optional `AS` alias
+    Expr { expr: Expr, alias: Option<String> },
+}
+
+/// Table in FROM or JOIN, with optional alias
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableRef {
+    /// Table name
+    pub name: String,
+
+    /// Alias (`FROM apps a`)
+    pub alias: Option<String>,
+}
+
+impl TableRef {
+    /// Name used to qualify this table's columns
+    pub fn qualifier(&self) -> &str {
+        self.alias.as_deref().unwrap_or(&self.name)
+    }
+}
+
+/// JOIN clause
+#[derive(Debug, Clone, PartialEq)]
+pub struct Join {
+    /// Joined table
+    pub table: TableRef,
+
+    /// LEFT JOIN keeps unmatched rows from the left side
+    pub left: bool,
+
+    /// Explicit `ON a.x = b.y`; inferred from the schema when absent
+    pub on: Option<(ColumnRef, ColumnRef)>,
+}
+
+/// Column reference, optionally qualified by table or alias
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnRef {
+    /// Table name or alias
+    pub table: Option<String>,
+
+    /// Column name
+    pub column: String,
+}
+
+impl std::fmt::Display for ColumnRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.table {
+            Some(t) => write!(f, "{}.{}", t, self.column),
+            None => write!(f, "{}", self.column),
+        }
+    }
+}
+
+/// Aggregate functions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AggFunc {
+    Count,
+    Sum,
+    Min,
+    Max,
+    Avg,
+}
+
+impl AggFunc {
+    fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "count" => Some(Self::Count),
+            "sum" => Some(Self::Sum),
+            "min" => Some(Self::Min),
+            "max" => Some(Self::Max),
+            "avg" => Some(Self::Avg),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Count => "count",
+            Self::Sum => "sum",
+            Self::Min => "min",
+            Self::Max => "max",
+            Self::Avg => "avg",
+        }
+    }
+}
+
+/// Comparison operators
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CmpOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl CmpOp {
+    fn symbol(self) -> &'static str {
+        match self {
+            Self::Eq => "=",
+            Self::Ne => "!=",
+            Self::Lt => "<",
+            Self::Le => "<=",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+        }
+    }
+}
+
+/// Expression
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Column(ColumnRef),
+    Literal(Value),
+    Aggregate {
+        func: AggFunc,
+        /// `None` for `count(*)`
+        arg: Option<Box<Expr>>,
+        distinct: bool,
+    },
+    Compare {
+        left: Box<Expr>,
+        op: CmpOp,
+        right: Box<Expr>,
+    },
+    Like {
+        expr: Box<Expr>,
+        pattern: Box<Expr>,
+        negated: bool,
+    },
+    In {
+        expr: Box<Expr>,
+        list: Vec<Expr>,
+        negated: bool,
+    },
+    IsNull {
+        expr: Box<Expr>,
+        negated: bool,
+    },
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+}
+
+impl Expr {
+    /// Output column label for this expression
+    pub fn label(&self) -> String {
+        match self {
+            Expr::Column(c) => c.column.clone(),
+            Expr::Literal(Value::Text(s)) => format!("'{}'", s),
+            Expr::Literal(v) => v.to_string(),
+            Expr::Aggregate {
+                func,
+                arg,
+                distinct,
+            } => {
+                let inner = match arg {
+                    None => "*".to_string(),
+                    Some(a) => a.qualified_label(),
+                };
+                let distinct = if *distinct { "distinct " } else { "" };
+                format!("{}({}{})", func.name(), distinct, inner)
+            }
+            Expr::Compare { left, op, right } => format!(
+                "{} {} {}",
+                left.qualified_label(),
+                op.symbol(),
+                right.qualified_label()
+            ),
+            _ => "expr".to_string(),
+        }
+    }
+
+    fn qualified_label(&self) -> String {
+        match self {
+            Expr::Column(c) => c.to_string(),
+            other => other.label(),
+        }
+    }
+
+    /// Whether the expression contains an aggregate
+    pub fn has_aggregate(&self) -> bool {
+        match self {
+            Expr::Aggregate { .. } => true,
+            Expr::Column(_) | Expr::Literal(_) => false,
+            Expr::Compare { left, right, .. } => left.has_aggregate() || right.has_aggregate(),
+            Expr::Like { expr, pattern, .. } => expr.has_aggregate() || pattern.has_aggregate(),
+            Expr::In { expr, list, .. } => {
+                expr.has_aggregate() || list.iter().any(Expr::has_aggregate)
+            }
+            Expr::IsNull { expr, .. } | Expr::Not(expr) => expr.has_aggregate(),
+            Expr::And(a, b) | Expr::Or(a, b) => a.has_aggregate() || b.has_aggregate(),
+        }
+    }
+}
+
+/// ORDER BY item
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderItem {
+    /// Sort expression (integer literal = output column position)
+    pub expr: Expr,
+
+    /// Descending order
+    pub desc: bool,
+}
+
+// ============================================================================
+// Tokenizer
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum Tok {
+    Ident(String),
+    Str(String),
+    Num(String),
+    Comma,
+    Dot,
+    LParen,
+    RParen,
+    Star,
+    Op(CmpOp),
+    Eof,
+}
+
+/// Words that cannot be used as bare aliases
+const RESERVED: &[&str] = &[
+    "select", "from", "join", "left", "inner", "on", "where", "and", "or", "not", "group", "by",
+    "having", "order", "asc", "desc", "limit", "as", "like", "is", "null", "in", "distinct",
+];
+
+fn syntax(position: usize, message: impl Into<String>) -> QueryError {
+    QueryError::Syntax {
+        position,
+        message: message.into(),
+    }
+}
+
+/// Tokenize into (token, 1-based character position) pairs
+fn tokenize(input: &str) -> Result<Vec<(Tok, usize)>, QueryError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let pos = i + 1;
+        match c {
+            c if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            ',' => tokens.push((Tok::Comma, pos)),
+            '.' => tokens.push((Tok::Dot, pos)),
+            '(' => tokens.push((Tok::LParen, pos)),
+            ')' => tokens.push((Tok::RParen, pos)),
+            '*' => tokens.push((Tok::Star, pos)),
+            '=' => tokens.push((Tok::Op(CmpOp::Eq), pos)),
+            '!' | '<' | '>' => {
+                let next = chars.get(i + 1).copied();
+                let (op, len) = match (c, next) {
+                    ('!', Some('=')) => (CmpOp::Ne, 2),
+                    ('<', Some('>')) => (CmpOp::Ne, 2),
+                    ('<', Some('=')) => (CmpOp::Le, 2),
+                    ('>', Some('=')) => (CmpOp::Ge, 2),
+                    ('<', _) => (CmpOp::Lt, 1),
+                    ('>', _) => (CmpOp::Gt, 1),
+                    _ => return Err(syntax(pos, "expected '=' after '!'")),
+                };
+                tokens.push((Tok::Op(op), pos));
+                i += len;
+                continue;
+            }
+            '\'' => {
+                // Single-quoted string; '' escapes a quote
+                let mut s = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        None => return Err(syntax(pos, "unterminated string")),
+                        Some('\'') if chars.get(i + 1) == Some(&'\'') => {
+                            s.push('\'');
+                            i += 2;
+                        }
+                        Some('\'') => break,
+                        Some(ch) => {
+                            s.push(*ch);
+                            i += 1;
+                        }
+                    }
+                }
+                tokens.push((Tok::Str(s), pos));
+            }
+            c if c.is_ascii_digit()
+                || (c == '-' && chars.get(i + 1).is_some_and(|d| d.is_ascii_digit())) =>
+            {
+                let start = i;
+                i += 1;
+                while chars
+                    .get(i)
+                    .is_some_and(|ch| ch.is_ascii_digit() || *ch == '.')
+                {
+                    i += 1;
+                }
+                tokens.push((Tok::Num(chars[start..i].iter().collect()), pos));
+                continue;
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while chars
+                    .get(i)
+                    .is_some_and(|ch| ch.is_alphanumeric() || *ch == '_')
+                {
+                    i += 1;
+                }
+                tokens.push((Tok::Ident(chars[start..i].iter().collect()), pos));
+                continue;
+            }
+            other => return Err(syntax(pos, format!("unexpected character '{}'", other))),
+        }
+        i += 1;
+    }
+
+    tokens.push((Tok::Eof, chars.len() + 1));
+    Ok(tokens)
+}
+
+// ============================================================================
+// Parser
+// ============================================================================
+
+/// Parse a query string
+pub fn parse(input: &str) -> Result<Query, QueryError> {
+    let mut parser = Parser {
+        tokens: tokenize(input)?,
+        pos: 0,
+    };
+    let query = parser.query()?;
+    if parser.peek() != &Tok::Eof {
+        return Err(parser.error("unexpected input after end of query"));
+    }
+    Ok(query)
+}
+
+struct Parser {
+    tokens: Vec<(Tok, usize)>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &Tok {
+        &self.tokens[self.pos].0
+    }
+
+    fn position(&self) -> usize {
+        self.tokens[self.pos].1
+    }
+
+    fn advance(&mut self) -> Tok {
+        let tok = self.tokens[self.pos].0.clone();
+        if tok != Tok::Eof {
+            self.pos += 1;
+        }
+        tok
+    }
+
+    fn error(&self, message: impl Into<String>) -> QueryError {
+        syntax(self.position(), message)
+    }
+
+    fn is_keyword(&self, kw: &str) -> bool {
+        matches!(self.peek(), Tok::Ident(s) if s.eq_ignore_ascii_case(kw))
+    }
+
+    fn eat_keyword(&mut self, kw: &str) -> bool {
+        if self.is_keyword(kw) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect_keyword(&mut self, kw: &str) -> Result<(), QueryError> {
+        if self.eat_keyword(kw) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected {}", kw.to_uppercase())))
+        }
+    }
+
+    fn eat(&mut self, tok: &Tok) -> bool {
+        if self.peek() == tok {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, tok: &Tok, what: &str) -> Result<(), QueryError> {
+        if self.eat(tok) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected {}", what)))
+        }
+    }
+
+    /// Non-reserved identifier
+    fn identifier(&mut self, what: &str) -> Result<String, QueryError> {
+        match self.peek() {
+            Tok::Ident(s) if !RESERVED.contains(&s.to_lowercase().as_str()) => {
+                let s = s.clone();
+                self.pos += 1;
+                Ok(s)
+            }
+            _ => Err(self.error(format!("expected {}", what))),
+        }
+    }
+
+    /// Optional alias: `AS name` or a bare non-reserved identifier
+    fn alias(&mut self) -> Result<Option<String>, QueryError> {
+        if self.eat_keyword("as") {
+            return self.identifier("alias after AS").map(Some);
+        }
+        match self.peek() {
+            Tok::Ident(s) if !RESERVED.contains(&s.to_lowercase().as_str()) => {
+                Ok(Some(self.identifier("alias")?))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn query(&mut self) -> Result<Query, QueryError> {
+        self.expect_keyword("select")?;
+        let select = self.select_list()?;
+
+        self.expect_keyword("from")?;
+        let from = self.table_ref()?;
+
+        let mut joins = Vec::new();
+        loop {
+            let left = if self.eat_keyword("left") {
+                true
+            } else {
+                self.eat_keyword("inner");
+                false
+            };
+            if !self.eat_keyword("join") {
+                if left {
+                    return Err(self.error("expected JOIN after LEFT"));
+                }
+                break;
+            }
+            let table = self.table_ref()?;
+            let on = if self.eat_keyword("on") {
+                let a = self.column_ref()?;
+                self.expect(&Tok::Op(CmpOp::Eq), "'=' in ON clause")?;
+                let b = self.column_ref()?;
+                Some((a, b))
+            } else {
+                None
+            };
+            joins.push(Join { table, left, on });
+        }
+
+        let filter = if self.eat_keyword("where") {
+            Some(self.or_expr()?)
+        } else {
+            None
+        };
+
+        let mut group_by = Vec::new();
+        if self.eat_keyword("group") {
+            self.expect_keyword("by")?;
+            loop {
+                group_by.push(self.operand()?);
+                if !self.eat(&Tok::Comma) {
+                    break;
+                }
+            }
+        }
+
+        let having = if self.eat_keyword("having") {
+            Some(self.or_expr()?)
+        } else {
+            None
+        };
+
+        let mut order_by = Vec::new();
+        if self.eat_keyword("order") {
+            self.expect_keyword("by")?;
+            loop {
+                let expr = self.operand()?;
+                let desc = if self.eat_keyword("desc") {
+                    true
+                } else {
+                    self.eat_keyword("asc");
+                    false
+                };
+                order_by.push(OrderItem { expr, desc });
+                if !self.eat(&Tok::Comma) {
+                    break;
+                }
+            }
+        }
+
+        let limit = if self.eat_keyword("limit") {
+            match self.advance() {
+                Tok::Num(n) => Some(
+                    n.parse()
+                        .map_err(|_| self.error("LIMIT must be a non-negative integer"))?,
+                ),
+                _ => return Err(self.error("expected number after LIMIT")),
+            }
+        } else {
+            None
+        };
+
+        Ok(Query {
+            select,
+            from,
+            joins,
+            filter,
+            group_by,
+            having,
+            order_by,
+            limit,
+        })
+    }
+
+    fn select_list(&mut self) -> Result<Vec<SelectItem>, QueryError> {
+        let mut items = Vec::new();
+        loop {
+            if self.eat(&Tok::Star) {
+                items.push(SelectItem::Wildcard);
+            } else {
+                let expr = self.operand()?;
+                let alias = self.alias()?;
+                items.push(SelectItem::Expr { expr, alias });
+            }
+            if !self.eat(&Tok::Comma) {
+                break;
+            }
+        }
+        Ok(items)
+    }
+
+    fn table_ref(&mut self) -> Result<TableRef, QueryError> {
+        let name = self.identifier("table name")?.to_lowercase();
+        let alias = self.alias()?;
+        Ok(TableRef { name, alias })
+    }
+
+    fn column_ref(&mut self) -> Result<ColumnRef, QueryError> {
+        let first = self.identifier("column name")?;
+        if self.eat(&Tok::Dot) {
+            let column = self.identifier("column name after '.'")?;
+            Ok(ColumnRef {
+                table: Some(first),
+                column,
+            })
+        } else {
+            Ok(ColumnRef {
+                table: None,
+                column: first,
+            })
+        }
+    }
+
+    fn or_expr(&mut self) -> Result<Expr, QueryError> {
+        let mut expr = self.and_expr()?;
+        while self.eat_keyword("or") {
+            expr = Expr::Or(Box::new(expr), Box::new(self.and_expr()?));
+        }
+        Ok(expr)
+    }
+
+    fn and_expr(&mut self) -> Result<Expr, QueryError> {
+        let mut expr = self.not_expr()?;
+        while self.eat_keyword("and") {
+            expr = Expr::And(Box::new(expr), Box::new(self.not_expr()?));
+        }
+        Ok(expr)
+    }
+
+    fn not_expr(&mut self) -> Result<Expr, QueryError> {
+        if self.eat_keyword("not") {
+            return Ok(Expr::Not(Box::new(self.not_expr()?)));
+        }
+        self.predicate()
+    }
+
+    fn predicate(&mut self) -> Result<Expr, QueryError> {
+        if self.eat(&Tok::LParen) {
+            let expr = self.or_expr()?;
+            self.expect(&Tok::RParen, "')'")?;
+            return Ok(expr);
+        }
+
+        let left = self.operand()?;
+
+        if let Tok::Op(op) = *self.peek() {
+            self.pos += 1;
+            let right = self.operand()?;
+            return Ok(Expr::Compare {
+                left: Box::new(left),
+                op,
+                right: Box::new(right),
+            });
+        }
+
+        if self.eat_keyword("is") {
+            let negated = self.eat_keyword("not");
+            self.expect_keyword("null")?;
+            return Ok(Expr::IsNull {
+                expr: Box::new(left),
+                negated,
+            });
+        }
+
+        let negated = self.eat_keyword("not");
+        if self.eat_keyword("like") {
+            let pattern = self.operand()?;
+            return Ok(Expr::Like {
+                expr: Box::new(left),
+                pattern: Box::new(pattern),
+                negated,
+            });
+        }
+        if self.eat_keyword("in") {
+            self.expect(&Tok::LParen, "'(' after IN")?;
+            let mut list = Vec::new();
+            loop {
+                list.push(self.operand()?);
+                if !self.eat(&Tok::Comma) {
+                    break;
+                }
+            }
+            self.expect(&Tok::RParen, "')'")?;
+            return Ok(Expr::In {
+                expr: Box::new(left),
+                list,
+                negated,
+            });
+        }
+        if negated {
+            return Err(self.error("expected LIKE or IN after NOT"));
+        }
+
+        Err(self.error("expected comparison (=, !=, <, >, LIKE, IN, IS NULL)"))
+    }
+
+    /// Column, literal, or aggregate call
+    fn operand(&mut self) -> Result<Expr, QueryError> {
+        match self.peek().clone() {
+            Tok::Str(s) => {
+                self.pos += 1;
+                Ok(Expr::Literal(Value::Text(s)))
+            }
+            Tok::Num(n) => {
+                self.pos += 1;
+                let value = match n.parse::<i64>() {
+                    Ok(i) => Value::Int(i),
+                    Err(_) => Value::Float(
+                        n.parse()
+                            .map_err(|_| self.error(format!("invalid number '{}'", n)))?,
+                    ),
+                };
+                Ok(Expr::Literal(value))
+            }
+            Tok::Ident(name) if name.eq_ignore_ascii_case("null") => {
+                self.pos += 1;
+                Ok(Expr::Literal(Value::Null))
+            }
+            Tok::Ident(name) => {
+                if let Some(func) = AggFunc::from_name(&name)
+                    && self.tokens.get(self.pos + 1).map(|t| &t.0) == Some(&Tok::LParen)
+                {
+                    self.pos += 2;
+                    let distinct = self.eat_keyword("distinct");
+                    let arg = if self.eat(&Tok::Star) {
+                        if func != AggFunc::Count || distinct {
+                            return Err(self.error("'*' is only valid in count(*)"));
+                        }
+                        None
+                    } else {
+                        Some(Box::new(self.operand()?))
+                    };
+                    self.expect(&Tok::RParen, "')'")?;
+                    return Ok(Expr::Aggregate {
+                        func,
+                        arg,
+                        distinct,
+                    });
+                }
+                Ok(Expr::Column(self.column_ref()?))
+            }
+            _ => Err(self.error("expected column, value, or aggregate")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn col(table: Option<&str>, column: &str) -> Expr {
+        Expr::Column(ColumnRef {
+            table: table.map(String::from),
+            column: column.to_string(),
+        })
+    }
+
+    #[test]
+    fn test_parse_full_query() {
+        let q = parse(
+            "SELECT team, count(*) AS apps, sum(l.high) FROM apps \
+             JOIN team_apps LEFT JOIN latest_scan l \
+             WHERE apps.status = 'ACTIVE' AND l.high > 0 \
+             GROUP BY team ORDER BY 2 DESC LIMIT 5",
+        )
+        .unwrap();
+
+        assert_eq!(q.tables(), vec!["apps", "team_apps", "latest_scan"]);
+        assert_eq!(q.select.len(), 3);
+        assert!(matches!(
+            &q.select[1],
+            SelectItem::Expr { alias: Some(a), .. } if a == "apps"
+        ));
+        assert!(q.joins[1].left);
+        assert_eq!(q.joins[1].table.qualifier(), "l");
+        assert_eq!(q.group_by, vec![col(None, "team")]);
+        assert!(q.order_by[0].desc);
+        assert_eq!(q.limit, Some(5));
+    }
+
+    #[test]
+    fn test_parse_join_on() {
+        let q = parse("select * from apps a join scans s on a.id = s.app_id").unwrap();
+        let (left, right) = q.joins[0].on.clone().unwrap();
+        assert_eq!(left.to_string(), "a.id");
+        assert_eq!(right.to_string(), "s.app_id");
+        assert_eq!(q.select, vec![SelectItem::Wildcard]);
+    }
+
+    #[test]
+    fn test_parse_predicates() {
+        let q = parse(
+            "select name from apps where not (name like '%test%' or env in ('dev', 'qa')) \
+             and host is not null",
+        )
+        .unwrap();
+        let Some(Expr::And(left, right)) = q.filter else {
+            panic!("expected AND");
+        };
+        assert!(matches!(*left, Expr::Not(_)));
+        assert!(matches!(*right, Expr::IsNull { negated: true, .. }));
+    }
+
+    #[test]
+    fn test_parse_string_escape() {
+        let q = parse("select name from apps where name = 'bob''s app'").unwrap();
+        let Some(Expr::Compare { right, .. }) = q.filter else {
+            panic!("expected comparison");
+        };
+        assert_eq!(*right, Expr::Literal(Value::text("bob's app")));
+    }
+
+    #[test]
+    fn test_labels() {
+        let q = parse("select count(*), sum(distinct s.high), apps.name from apps").unwrap();
+        let labels: Vec<_> = q
+            .select
+            .iter()
+            .map(|s| match s {
+                SelectItem::Expr { expr, .. } => expr.label(),
+                SelectItem::Wildcard => "*".to_string(),
+            })
+            .collect();
+        assert_eq!(labels, vec!["count(*)", "sum(distinct s.high)", "name"]);
+    }
+
+    #[test]
+    fn test_syntax_errors() {
+        let err = parse("select from apps").unwrap_err();
+        assert!(matches!(err, QueryError::Syntax { position: 8, .. }));
+
+        assert!(parse("select name from").is_err());
+        assert!(parse("select name from apps where name = 'x").is_err());
+        assert!(parse("select name from apps limit ten").is_err());
+        assert!(parse("select sum(*) from apps").is_err());
+        assert!(parse("select name from apps extra junk").is_err());
+    }
+}
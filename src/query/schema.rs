@@ -0,0 +1,250 @@
+//! Queryable tables, their columns, and built-in join relationships
+//!
+//! Each table is hydrated from API resources only when a query references it.
+
+use std::collections::HashMap;
+
+use crate::client::models::{Application, ScanResult, TeamDetail};
+use crate::models::display::timestamp_ms;
+
+use super::{Table, Value};
+
+/// Description of a queryable table
+#[derive(Debug, Clone, Copy)]
+pub struct TableInfo {
+    /// Table name used in FROM / JOIN
+    pub name: &'static str,
+
+    /// What one row represents
+    pub description: &'static str,
+
+    /// Column names, in `SELECT *` order
+    pub columns: &'static [&'static str],
+}
+
+/// Columns shared by `scans` and `latest_scan`
+const SCAN_COLUMNS: &[&str] = &[
+    "id",
+    "app_id",
+    "app",
+    "env",
+    "status",
+    "started",
+    "timestamp",
+    "duration",
+    "urls",
+    "high",
+    "medium",
+    "low",
+    "findings",
+    "new",
+    "policy",
+    "version",
+];
+
+/// All tables available to queries
+pub const TABLES: &[TableInfo] = &[
+    TableInfo {
+        name: "apps",
+        description: "One row per application",
+        columns: &[
+            "id", "name", "env", "env_id", "status", "type", "risk", "host",
+        ],
+    },
+    TableInfo {
+        name: "scans",
+        description: "One row per scan (limit with --since)",
+        columns: SCAN_COLUMNS,
+    },
+    TableInfo {
+        name: "latest_scan",
+        description: "Most recent completed scan of each application",
+        columns: SCAN_COLUMNS,
+    },
+    TableInfo {
+        name: "teams",
+        description: "One row per team",
+        columns: &["id", "name", "users", "apps"],
+    },
+    TableInfo {
+        name: "team_apps",
+        description: "Team-to-application assignments",
+        columns: &["team_id", "team", "app_id"],
+    },
+    TableInfo {
+        name: "team_users",
+        description: "Team memberships",
+        columns: &["team_id", "team", "user_id", "user", "email", "role"],
+    },
+];
+
+/// Join keys used when a JOIN has no ON clause: (table, column, table, column)
+pub const RELATIONSHIPS: &[(&str, &str, &str, &str)] = &[
+    ("apps", "id", "scans", "app_id"),
+    ("apps", "id", "latest_scan", "app_id"),
+    ("apps", "id", "team_apps", "app_id"),
+    ("teams", "id", "team_apps", "team_id"),
+    ("teams", "id", "team_users", "team_id"),
+    ("scans", "app_id", "team_apps", "app_id"),
+    ("latest_scan", "app_id", "team_apps", "app_id"),
+    ("scans", "app_id", "latest_scan", "app_id"),
+];
+
+/// Look up a table description by name
+pub fn table_info(name: &str) -> Option<&'static TableInfo> {
+    TABLES.iter().find(|t| t.name == name)
+}
+
+/// Join key between two tables, oriented as (left column, right column)
+pub fn relationship(left: &str, right: &str) -> Option<(&'static str, &'static str)> {
+    RELATIONSHIPS.iter().find_map(|(a, ac, b, bc)| {
+        if *a == left && *b == right {
+            Some((*ac, *bc))
+        } else if *b == left && *a == right {
+            Some((*bc, *ac))
+        } else {
+            None
+        }
+    })
+}
+
+/// Build the `apps` table
+pub fn apps_table(apps: &[Application]) -> Table {
+    let mut table = Table::new(table_info("apps").expect("apps table").columns);
+    for app in apps {
+        table.push(vec![
+            Value::text(&app.id),
+            Value::text(&app.name),
+            Value::opt_text(app.env.as_deref()),
+            Value::opt_text(app.env_id.as_deref()),
+            Value::opt_text(app.status.as_deref()),
+            Value::opt_text(app.application_type.as_deref()),
+            Value::opt_text(app.risk_level.as_deref()),
+            Value::opt_text(
+                app.cloud_scan_target
+                    .as_ref()
+                    .and_then(|t| t.target_url.as_deref()),
+            ),
+        ]);
+    }
+    table
+}
+
+/// Build the `scans` table
+pub fn scans_table(scans: &[ScanResult]) -> Table {
+    let mut table = Table::new(SCAN_COLUMNS);
+    for scan in scans {
+        table.push(scan_row(scan));
+    }
+    table
+}
+
+/// Build the `latest_scan` table: latest completed scan per application
+pub fn latest_scan_table(scans: &[ScanResult]) -> Table {
+    let mut latest: HashMap<&str, &ScanResult> = HashMap::new();
+    for scan in scans {
+        if !scan.scan.status.eq_ignore_ascii_case("COMPLETED") {
+            continue;
+        }
+        let newer = latest
+            .get(scan.scan.application_id.as_str())
+            .is_none_or(|existing| timestamp_ms(scan) > timestamp_ms(existing));
+        if newer {
+            latest.insert(&scan.scan.application_id, scan);
+        }
+    }
+
+    let mut rows: Vec<&ScanResult> = latest.into_values().collect();
+    rows.sort_by(|a, b| a.scan.application_name.cmp(&b.scan.application_name));
+
+    let mut table = Table::new(SCAN_COLUMNS);
+    for scan in rows {
+        table.push(scan_row(scan));
+    }
+    table
+}
+
+/// Build the `teams`, `team_apps`, and `team_users` tables
+pub fn team_tables(teams: &[TeamDetail]) -> (Table, Table, Table) {
+    let mut team_table = Table::new(table_info("teams").expect("teams table").columns);
+    let mut team_apps = Table::new(table_info("team_apps").expect("team_apps table").columns);
+    let mut team_users = Table::new(table_info("team_users").expect("team_users table").columns);
+
+    for team in teams {
+        team_table.push(vec![
+            Value::text(&team.id),
+            Value::text(&team.name),
+            team.users.len().into(),
+            team.applications.len().into(),
+        ]);
+        for app in &team.applications {
+            team_apps.push(vec![
+                Value::text(&team.id),
+                Value::text(&team.name),
+                Value::text(&app.application_id),
+            ]);
+        }
+        for user in &team.users {
+            team_users.push(vec![
+                Value::text(&team.id),
+                Value::text(&team.name),
+                Value::text(&user.user_id),
+                Value::opt_text(user.user_name.as_deref()),
+                Value::opt_text(user.email.as_deref()),
+                Value::opt_text(user.role.as_deref()),
+            ]);
+        }
+    }
+
+    (team_table, team_apps, team_users)
+}
+
+fn scan_row(result: &ScanResult) -> Vec<Value> {
+    let scan = &result.scan;
+
+    let (mut high, mut medium, mut low, mut new) = (0u32, 0u32, 0u32, 0u32);
+    if let Some(stats) = &result.alert_stats {
+        for status in &stats.alert_status_stats {
+            for (severity, count) in &status.severity_stats {
+                match severity.as_str() {
+                    "High" => high += count,
+                    "Medium" => medium += count,
+                    "Low" => low += count,
+                    _ => {}
+                }
+                if status.alert_status == "UNKNOWN" {
+                    new += count;
+                }
+            }
+        }
+    }
+
+    let ts = timestamp_ms(result);
+    let started = chrono::DateTime::from_timestamp_millis(ts)
+        .map(|dt| Value::text(dt.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)))
+        .unwrap_or(Value::Null);
+    let duration = result
+        .scan_duration
+        .as_deref()
+        .and_then(|d| d.parse::<f64>().ok())
+        .map_or(Value::Null, |secs| Value::Int(secs as i64));
+
+    vec![
+        Value::text(&scan.id),
+        Value::text(&scan.application_id),
+        Value::text(&scan.application_name),
+        Value::text(&scan.env),
+        Value::text(&scan.status),
+        started,
+        Value::Int(ts),
+        duration,
+        result.url_count.map_or(Value::Null, Value::from),
+        high.into(),
+        medium.into(),
+        low.into(),
+        (high + medium + low).into(),
+        new.into(),
+        Value::opt_text(result.policy_name.as_deref()),
+        Value::text(&scan.version),
+    ]
+}
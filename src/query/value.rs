@@ -0,0 +1,132 @@
+//! Dynamically typed cell values
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use serde::{Serialize, Serializer};
+
+/// A single cell value
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// Missing value (unmatched LEFT JOIN side, absent field)
+    Null,
+
+    /// Integer
+    Int(i64),
+
+    /// Floating point (results of `avg`)
+    Float(f64),
+
+    /// Text
+    Text(String),
+}
+
+impl Value {
+    /// Text value from anything string-like
+    pub fn text(s: impl Into<String>) -> Self {
+        Value::Text(s.into())
+    }
+
+    /// Text value, or NULL when absent
+    pub fn opt_text(s: Option<impl Into<String>>) -> Self {
+        s.map_or(Value::Null, |s| Value::Text(s.into()))
+    }
+
+    /// Whether this is NULL
+    pub fn is_null(&self) -> bool {
+        matches!(self, Value::Null)
+    }
+
+    /// Numeric view of the value (text is parsed if it looks numeric)
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Int(i) => Some(*i as f64),
+            Value::Float(f) => Some(*f),
+            Value::Text(s) => s.trim().parse().ok(),
+            Value::Null => None,
+        }
+    }
+
+    /// SQL comparison; `None` when either side is NULL
+    ///
+    /// Numbers compare numerically (including numeric-looking text);
+    /// otherwise text compares case-insensitively.
+    pub fn compare(&self, other: &Value) -> Option<Ordering> {
+        if self.is_null() || other.is_null() {
+            return None;
+        }
+        if let (Some(a), Some(b)) = (self.as_f64(), other.as_f64()) {
+            return a.partial_cmp(&b);
+        }
+        Some(
+            self.to_string()
+                .to_lowercase()
+                .cmp(&other.to_string().to_lowercase()),
+        )
+    }
+
+    /// Total ordering for ORDER BY: NULLs sort first
+    pub fn sort_cmp(&self, other: &Value) -> Ordering {
+        match (self.is_null(), other.is_null()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            _ => self.compare(other).unwrap_or(Ordering::Equal),
+        }
+    }
+
+    /// Key used for grouping and join matching
+    pub(crate) fn key(&self) -> String {
+        match self {
+            Value::Null => "\0null".to_string(),
+            Value::Float(f) if f.fract() == 0.0 => format!("{}", *f as i64),
+            other => other.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => Ok(()),
+            Value::Int(i) => write!(f, "{}", i),
+            Value::Float(v) => {
+                if v.fract() == 0.0 {
+                    write!(f, "{}", *v as i64)
+                } else {
+                    write!(f, "{:.2}", v)
+                }
+            }
+            Value::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Value::Null => serializer.serialize_none(),
+            Value::Int(i) => serializer.serialize_i64(*i),
+            Value::Float(f) => serializer.serialize_f64(*f),
+            Value::Text(s) => serializer.serialize_str(s),
+        }
+    }
+}
+
+impl From<i64> for Value {
+    fn from(v: i64) -> Self {
+        Value::Int(v)
+    }
+}
+
+impl From<u32> for Value {
+    fn from(v: u32) -> Self {
+        Value::Int(v as i64)
+    }
+}
+
+impl From<usize> for Value {
+    fn from(v: usize) -> Self {
+        Value::Int(v as i64)
+    }
+}
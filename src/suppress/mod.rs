@@ -0,0 +1,436 @@
+//! Local finding suppressions for `hawkop suppress`
+//!
+//! A `.hawkop-suppressions.yaml` file checked into a repository lists
+//! findings the team has accepted: a plugin ID, optionally narrowed to path
+//! patterns, an app, or an environment, with a reason and an optional expiry
+//! date. Because the file lives next to the code, suppressions go through
+//! code review like any other change. `scan gate`, `finding list`, and
+//! `scan upload-github` leave out findings an active suppression matches.
+//!
+//! The file is found by walking up from the working directory to the
+//! repository root, or named explicitly with `HAWKOP_SUPPRESSIONS`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{Local, NaiveDate};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::client::models::{AlertResponse, CurrentFindingRow};
+use crate::error::{Error, Result};
+
+/// File name looked for in the working directory and its parents
+pub const SUPPRESSIONS_FILE: &str = ".hawkop-suppressions.yaml";
+
+/// Environment variable naming the suppressions file to use instead
+pub const SUPPRESSIONS_ENV: &str = "HAWKOP_SUPPRESSIONS";
+
+/// An accepted finding
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Suppression {
+    /// Plugin ID of the finding (e.g. `40012`)
+    #[serde(deserialize_with = "plugin_id")]
+    pub plugin: String,
+
+    /// Path patterns, where `*` matches any run of characters (empty = every path)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub paths: Vec<String>,
+
+    /// Application name or ID (None = every app)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app: Option<String>,
+
+    /// Environment name (None = every environment)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub env: Option<String>,
+
+    /// Last day the suppression applies (None = never expires)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires: Option<NaiveDate>,
+
+    /// Why the finding is accepted
+    pub reason: String,
+
+    /// When the suppression was added
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added: Option<NaiveDate>,
+}
+
+impl Suppression {
+    /// Whether the suppression still applies on `today`
+    pub fn is_active(&self, today: NaiveDate) -> bool {
+        self.expires.is_none_or(|expires| today <= expires)
+    }
+
+    /// Whether the suppression covers findings of this app and environment
+    ///
+    /// The app may be given by name or ID; both compare case-insensitively.
+    pub fn applies_to(&self, app_id: &str, app_name: &str, env: &str) -> bool {
+        let app_matches = self.app.as_deref().is_none_or(|app| {
+            app.eq_ignore_ascii_case(app_id) || app.eq_ignore_ascii_case(app_name)
+        });
+        let env_matches = self
+            .env
+            .as_deref()
+            .is_none_or(|e| e.eq_ignore_ascii_case(env));
+        app_matches && env_matches
+    }
+
+    /// Whether the suppression covers a finding of `plugin` at `uri`
+    ///
+    /// Patterns are matched against the URI's path, so a full URL and a bare
+    /// path match the same way.
+    pub fn covers(&self, plugin: &str, uri: &str) -> bool {
+        if self.plugin != plugin {
+            return false;
+        }
+        let path = url_path(uri);
+        self.paths.is_empty()
+            || self
+                .paths
+                .iter()
+                .any(|pattern| crate::cli::args::glob_match(pattern, path))
+    }
+}
+
+/// The suppressions file and its entries
+#[derive(Debug, Clone)]
+pub struct Suppressions {
+    /// Where the file is (or will be written)
+    pub path: PathBuf,
+
+    /// Entries in file order
+    pub entries: Vec<Suppression>,
+
+    /// Date expiry is judged against
+    pub today: NaiveDate,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SuppressionFile {
+    #[serde(default)]
+    suppressions: Vec<Suppression>,
+}
+
+impl Suppressions {
+    /// The suppressions file in effect, if there is one
+    ///
+    /// Consumers call this: without a file nothing is suppressed.
+    pub fn discover() -> Result<Option<Self>> {
+        match locate() {
+            Some(path) if path.exists() => Self::load(&path).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// The file to manage: the one in effect, or a new one at the
+    /// repository root (the working directory outside a repository)
+    pub fn open() -> Result<Self> {
+        let path = match locate() {
+            Some(path) => path,
+            None => {
+                let cwd = std::env::current_dir()?;
+                repo_root(&cwd).unwrap_or(cwd).join(SUPPRESSIONS_FILE)
+            }
+        };
+        if path.exists() {
+            Self::load(&path)
+        } else {
+            Ok(Self::empty(path))
+        }
+    }
+
+    /// Read a suppressions file
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        let file: SuppressionFile = if contents.trim().is_empty() {
+            SuppressionFile::default()
+        } else {
+            serde_yaml::from_str(&contents)
+                .map_err(|e| Error::Other(format!("Invalid {}: {}", path.display(), e)))?
+        };
+        Ok(Self {
+            path: path.to_path_buf(),
+            entries: file.suppressions,
+            today: Local::now().date_naive(),
+        })
+    }
+
+    fn empty(path: PathBuf) -> Self {
+        Self {
+            path,
+            entries: Vec::new(),
+            today: Local::now().date_naive(),
+        }
+    }
+
+    /// Write the entries back to the file
+    ///
+    /// The file is rewritten from the parsed entries, so YAML comments are
+    /// not kept; put context in each entry's `reason` instead.
+    pub fn save(&self) -> Result<()> {
+        let file = SuppressionFile {
+            suppressions: self.entries.clone(),
+        };
+        let yaml = serde_yaml::to_string(&file)
+            .map_err(|e| Error::Other(format!("Failed to write suppressions: {}", e)))?;
+        fs::write(&self.path, yaml)?;
+        Ok(())
+    }
+
+    /// Active suppressions covering an app and environment
+    pub fn scoped(&self, app_id: &str, app_name: &str, env: &str) -> Vec<&Suppression> {
+        self.entries
+            .iter()
+            .filter(|s| s.is_active(self.today) && s.applies_to(app_id, app_name, env))
+            .collect()
+    }
+
+    /// Whether an active suppression covers a finding
+    pub fn suppresses(
+        &self,
+        plugin: &str,
+        uri: &str,
+        app_id: &str,
+        app_name: &str,
+        env: &str,
+    ) -> bool {
+        self.scoped(app_id, app_name, env)
+            .iter()
+            .any(|s| s.covers(plugin, uri))
+    }
+
+    /// Whether an active suppression covers a row of the findings report
+    pub fn suppresses_finding(&self, row: &CurrentFindingRow) -> bool {
+        self.suppresses(
+            row.finding_plugin_id.as_deref().unwrap_or_default(),
+            row.finding_url.as_deref().unwrap_or_default(),
+            row.application_id.as_deref().unwrap_or_default(),
+            row.application_name.as_deref().unwrap_or_default(),
+            row.environment_name.as_deref().unwrap_or_default(),
+        )
+    }
+
+    /// Drop the paths of a scan's alerts that an active suppression covers,
+    /// returning how many were dropped
+    pub fn strip_paths(
+        &self,
+        alerts: &mut [AlertResponse],
+        app_id: &str,
+        app_name: &str,
+        env: &str,
+    ) -> usize {
+        let scoped = self.scoped(app_id, app_name, env);
+        if scoped.is_empty() {
+            return 0;
+        }
+        let mut stripped = 0;
+        for response in alerts {
+            let plugin = &response.alert.plugin_id;
+            let before = response.application_scan_alert_uris.len();
+            response
+                .application_scan_alert_uris
+                .retain(|uri| !scoped.iter().any(|s| s.covers(plugin, &uri.uri)));
+            stripped += before - response.application_scan_alert_uris.len();
+        }
+        stripped
+    }
+
+    /// Remove expired entries, returning them
+    pub fn prune(&mut self) -> Vec<Suppression> {
+        let today = self.today;
+        let (active, expired) = std::mem::take(&mut self.entries)
+            .into_iter()
+            .partition(|s| s.is_active(today));
+        self.entries = active;
+        expired
+    }
+}
+
+/// `HAWKOP_SUPPRESSIONS`, or the nearest suppressions file between the
+/// working directory and the repository root
+fn locate() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os(SUPPRESSIONS_ENV).filter(|p| !p.is_empty()) {
+        return Some(PathBuf::from(path));
+    }
+    let cwd = std::env::current_dir().ok()?;
+    find_upwards(&cwd)
+}
+
+/// The nearest suppressions file from `dir` up, stopping at the repository root
+fn find_upwards(dir: &Path) -> Option<PathBuf> {
+    for ancestor in dir.ancestors() {
+        let candidate = ancestor.join(SUPPRESSIONS_FILE);
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+        if ancestor.join(".git").exists() {
+            break;
+        }
+    }
+    None
+}
+
+/// The nearest directory from `dir` up that contains `.git`
+fn repo_root(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .find(|ancestor| ancestor.join(".git").exists())
+        .map(Path::to_path_buf)
+}
+
+/// An expiry date: `YYYY-MM-DD`, or a length of time from `today` (`90d`, `12w`)
+pub fn parse_expiry(input: &str, today: NaiveDate) -> Result<NaiveDate> {
+    if let Ok(date) = NaiveDate::parse_from_str(input.trim(), "%Y-%m-%d") {
+        return Ok(date);
+    }
+    let duration = crate::timeparse::parse_duration(input).map_err(|_| {
+        Error::Other(format!(
+            "Invalid expiry '{}'. Use a date (2026-12-31) or a length of time (90d, 12w)",
+            input
+        ))
+    })?;
+    let days = (duration.as_secs() / 86_400) as i64;
+    Ok(today + chrono::Duration::days(days))
+}
+
+/// Path of a URL, without scheme, host, query, or fragment
+fn url_path(uri: &str) -> &str {
+    let rest = match uri.find("://") {
+        Some(i) => {
+            let after = &uri[i + 3..];
+            after.find('/').map_or("/", |j| &after[j..])
+        }
+        None => uri,
+    };
+    rest.split(['?', '#']).next().unwrap_or(rest)
+}
+
+/// Accept plugin IDs written as numbers (`plugin: 40012`) or strings
+fn plugin_id<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<String, D::Error> {
+    match serde_yaml::Value::deserialize(deserializer)? {
+        serde_yaml::Value::String(id) => Ok(id),
+        serde_yaml::Value::Number(id) => Ok(id.to_string()),
+        other => Err(D::Error::custom(format!(
+            "expected a plugin ID, got {:?}",
+            other
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FILE: &str = "\
+suppressions:
+  - plugin: 10038
+    reason: CSP handled by the CDN
+  - plugin: \"40012\"
+    paths: [\"/legacy/*\", \"/search\"]
+    app: Web
+    env: prod
+    expires: 2026-06-30
+    reason: Legacy pages retire in June
+";
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 6, 1).unwrap()
+    }
+
+    fn suppressions(today: NaiveDate) -> Suppressions {
+        let file: SuppressionFile = serde_yaml::from_str(FILE).unwrap();
+        Suppressions {
+            path: PathBuf::from(SUPPRESSIONS_FILE),
+            entries: file.suppressions,
+            today,
+        }
+    }
+
+    #[test]
+    fn test_parse_file() {
+        let s = suppressions(today());
+        assert_eq!(s.entries[0].plugin, "10038");
+        assert!(s.entries[0].paths.is_empty());
+        assert_eq!(s.entries[1].plugin, "40012");
+        assert_eq!(s.entries[1].expires, NaiveDate::from_ymd_opt(2026, 6, 30));
+    }
+
+    #[test]
+    fn test_suppresses_matching_findings() {
+        let s = suppressions(today());
+
+        // No paths, app, or env: every finding of the plugin
+        assert!(s.suppresses("10038", "/anything", "a1", "Api", "dev"));
+        assert!(!s.suppresses("10039", "/anything", "a1", "Api", "dev"));
+
+        assert!(s.suppresses("40012", "/legacy/cart", "a2", "web", "PROD"));
+        assert!(s.suppresses(
+            "40012",
+            "https://shop.example.com/search?q=x",
+            "a2",
+            "Web",
+            "prod"
+        ));
+        assert!(!s.suppresses("40012", "/search/advanced", "a2", "Web", "prod"));
+        assert!(!s.suppresses("40012", "/legacy/cart", "a2", "Web", "dev"));
+        assert!(!s.suppresses("40012", "/legacy/cart", "a3", "Api", "prod"));
+    }
+
+    #[test]
+    fn test_expired_suppressions_are_inactive_and_pruned() {
+        let after = NaiveDate::from_ymd_opt(2026, 7, 1).unwrap();
+        let mut s = suppressions(after);
+        assert!(!s.suppresses("40012", "/search", "a2", "Web", "prod"));
+
+        let expired = s.prune();
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].plugin, "40012");
+        assert_eq!(s.entries.len(), 1);
+
+        // Still active on the expiry date itself
+        let mut s = suppressions(NaiveDate::from_ymd_opt(2026, 6, 30).unwrap());
+        assert!(s.prune().is_empty());
+    }
+
+    #[test]
+    fn test_save_round_trips() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut s = suppressions(today());
+        s.path = dir.path().join(SUPPRESSIONS_FILE);
+        s.save().unwrap();
+
+        let loaded = Suppressions::load(&s.path).unwrap();
+        assert_eq!(loaded.entries, s.entries);
+    }
+
+    #[test]
+    fn test_find_upwards_stops_at_repo_root() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = dir.path().join("repo");
+        let nested = repo.join("services/web");
+        fs::create_dir_all(&nested).unwrap();
+        fs::create_dir(repo.join(".git")).unwrap();
+
+        // A file above the repository root is not used
+        fs::write(dir.path().join(SUPPRESSIONS_FILE), "").unwrap();
+        assert_eq!(find_upwards(&nested), None);
+        assert_eq!(repo_root(&nested), Some(repo.clone()));
+
+        fs::write(repo.join(SUPPRESSIONS_FILE), "").unwrap();
+        assert_eq!(find_upwards(&nested), Some(repo.join(SUPPRESSIONS_FILE)));
+    }
+
+    #[test]
+    fn test_parse_expiry() {
+        assert_eq!(
+            parse_expiry("2026-12-31", today()).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 12, 31).unwrap()
+        );
+        assert_eq!(
+            parse_expiry("90d", today()).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 8, 30).unwrap()
+        );
+        assert!(parse_expiry("someday", today()).is_err());
+    }
+}
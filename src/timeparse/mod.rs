@@ -0,0 +1,223 @@
+//! Time parsing for `--since` / `--until` style flags
+//!
+//! Accepts relative offsets from now (`48h`, `7d`, `2w`), ISO 8601 dates and
+//! datetimes, and Unix epoch timestamps. All results are Unix milliseconds,
+//! the unit the StackHawk API uses for time filters.
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Utc};
+
+use crate::error::{Error, Result};
+
+/// Epoch values at or above this are treated as milliseconds, below as
+/// seconds (1e11 seconds is the year 5138; 1e11 ms is March 1973)
+const EPOCH_MILLIS_THRESHOLD: i64 = 100_000_000_000;
+
+/// Parse a time expression to Unix milliseconds, relative to the current time
+///
+/// Supports:
+/// - Relative: `48h` (hours ago), `7d` (days ago), `2w` (weeks ago)
+/// - ISO date: `2024-01-15` (midnight UTC)
+/// - ISO datetime: `2024-01-15T10:30:00Z`, or without offset (assumed UTC)
+/// - Epoch: `1705314600` (seconds) or `1705314600000` (milliseconds)
+pub fn parse_millis(input: &str) -> Result<i64> {
+    parse_millis_at(input, Utc::now())
+}
+
+/// Parse a time expression with an explicit "now" for relative offsets
+pub fn parse_millis_at(input: &str, now: DateTime<Utc>) -> Result<i64> {
+    let input = input.trim();
+
+    if let Some(offset) = parse_relative(input) {
+        return Ok((now - offset).timestamp_millis());
+    }
+
+    if !input.is_empty()
+        && input.chars().all(|c| c.is_ascii_digit())
+        && let Ok(epoch) = input.parse::<i64>()
+    {
+        return Ok(if epoch >= EPOCH_MILLIS_THRESHOLD {
+            epoch
+        } else {
+            epoch * 1000
+        });
+    }
+
+    if input.len() == 10
+        && input.chars().nth(4) == Some('-')
+        && let Ok(date) = NaiveDate::parse_from_str(input, "%Y-%m-%d")
+    {
+        let datetime = date.and_hms_opt(0, 0, 0).expect("valid time").and_utc();
+        return Ok(datetime.timestamp_millis());
+    }
+
+    if let Ok(dt) = DateTime::parse_from_rfc3339(input) {
+        return Ok(dt.timestamp_millis());
+    }
+
+    if let Ok(dt) = NaiveDateTime::parse_from_str(input, "%Y-%m-%dT%H:%M:%S") {
+        return Ok(dt.and_utc().timestamp_millis());
+    }
+
+    Err(Error::Other(format!(
+        "Invalid time '{}'. Use relative (48h, 7d, 2w), ISO (YYYY-MM-DD or \
+         YYYY-MM-DDTHH:MM:SSZ), or epoch seconds/milliseconds",
+        input
+    )))
+}
+
+/// Parse an optional `--since` / `--until` pair, rejecting inverted ranges
+pub fn parse_range(since: Option<&str>, until: Option<&str>) -> Result<(Option<i64>, Option<i64>)> {
+    let now = Utc::now();
+    let start = since.map(|s| parse_millis_at(s, now)).transpose()?;
+    let end = until.map(|s| parse_millis_at(s, now)).transpose()?;
+
+    if let (Some(start), Some(end)) = (start, end)
+        && start > end
+    {
+        return Err(Error::Other(format!(
+            "--since ({}) is after --until ({})",
+            since.unwrap_or_default(),
+            until.unwrap_or_default()
+        )));
+    }
+    Ok((start, end))
+}
+
+/// Parse a length of time: `90s`, `10m`, `4h`, `1d`, `2w`, or bare seconds
+pub fn parse_duration(input: &str) -> Result<std::time::Duration> {
+    let input = input.trim();
+    let secs = match input.parse::<u64>() {
+        Ok(secs) => Some(secs),
+        Err(_) => match input.strip_suffix('s') {
+            Some(secs) => secs.parse().ok(),
+            None => match input.strip_suffix('m') {
+                Some(mins) => mins.parse::<u64>().ok().map(|m| m * 60),
+                None => parse_relative(input)
+                    .filter(|d| *d >= Duration::zero())
+                    .map(|d| d.num_seconds() as u64),
+            },
+        },
+    };
+    secs.map(std::time::Duration::from_secs).ok_or_else(|| {
+        Error::Other(format!(
+            "Invalid duration '{}'. Use seconds or a unit: 90s, 10m, 4h, 1d, 2w",
+            input
+        ))
+    })
+}
+
+/// `<n>h`, `<n>d`, or `<n>w` as a duration
+fn parse_relative(input: &str) -> Option<Duration> {
+    let unit = input.chars().last()?;
+    let amount: i64 = input[..input.len() - unit.len_utf8()].parse().ok()?;
+    match unit {
+        'h' => Some(Duration::hours(amount)),
+        'd' => Some(Duration::days(amount)),
+        'w' => Some(Duration::weeks(amount)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_relative_offsets() {
+        let base = now();
+        let cases = [
+            ("48h", Duration::hours(48)),
+            ("7d", Duration::days(7)),
+            ("30d", Duration::days(30)),
+            ("2w", Duration::weeks(2)),
+        ];
+        for (input, offset) in cases {
+            assert_eq!(
+                parse_millis_at(input, base).unwrap(),
+                (base - offset).timestamp_millis(),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_relative_uses_current_time() {
+        let expected = (Utc::now() - Duration::days(7)).timestamp_millis();
+        let result = parse_millis("7d").unwrap();
+        // Allow 1 second tolerance for test timing
+        assert!((result - expected).abs() < 1000);
+    }
+
+    #[test]
+    fn test_iso_formats() {
+        let midnight = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+        assert_eq!(
+            parse_millis_at("2024-01-15", now()).unwrap(),
+            midnight.timestamp_millis()
+        );
+
+        let expected = Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 0).unwrap();
+        assert_eq!(
+            parse_millis_at("2024-01-15T10:30:00Z", now()).unwrap(),
+            expected.timestamp_millis()
+        );
+        assert_eq!(
+            parse_millis_at("2024-01-15T10:30:00", now()).unwrap(),
+            expected.timestamp_millis()
+        );
+        assert_eq!(
+            parse_millis_at("2024-01-15T12:30:00+02:00", now()).unwrap(),
+            expected.timestamp_millis()
+        );
+    }
+
+    #[test]
+    fn test_epoch_seconds_and_millis() {
+        assert_eq!(
+            parse_millis_at("1705314600", now()).unwrap(),
+            1_705_314_600_000
+        );
+        assert_eq!(
+            parse_millis_at("1705314600000", now()).unwrap(),
+            1_705_314_600_000
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        for input in ["invalid", "yesterday", "01-15-2024", "", "7x", "-5"] {
+            assert!(parse_millis_at(input, now()).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_parse_range() {
+        let (start, end) = parse_range(Some("2024-01-01"), Some("2024-02-01")).unwrap();
+        assert!(start.unwrap() < end.unwrap());
+
+        assert_eq!(parse_range(None, None).unwrap(), (None, None));
+
+        let err = parse_range(Some("2024-02-01"), Some("2024-01-01")).unwrap_err();
+        assert!(err.to_string().contains("after --until"));
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        let secs = |input| parse_duration(input).unwrap().as_secs();
+        assert_eq!(secs("300"), 300);
+        assert_eq!(secs("90s"), 90);
+        assert_eq!(secs("10m"), 600);
+        assert_eq!(secs("4h"), 4 * 3600);
+        assert_eq!(secs("1d"), 86_400);
+        assert_eq!(secs("2w"), 14 * 86_400);
+        for input in ["", "5x", "-1h", "m"] {
+            assert!(parse_duration(input).is_err(), "{}", input);
+        }
+    }
+}
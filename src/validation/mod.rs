@@ -0,0 +1,402 @@
+//! Client-side validation of HawkScan configuration files (`stackhawk.yml`)
+//!
+//! Checks what HawkScan needs before anything is sent to the API: YAML
+//! syntax, the required `app` fields, and the types of well-known keys from
+//! StackHawk's configuration schema. Problems are reported as
+//! [`ValidationMarker`]s, the same shape the API validator returns, so local
+//! and server results print together. The server-side check remains
+//! authoritative for everything this module does not cover.
+
+use serde_yaml::Value;
+
+use crate::client::models::{ValidatedAssetResponse, ValidationMarker};
+
+pub mod references;
+
+/// Marker owner for problems found locally
+const OWNER: &str = "hawkop";
+
+/// Keys HawkScan accepts at the top level
+const TOP_LEVEL_KEYS: &[&str] = &["app", "hawk", "hawkAddOn", "tags"];
+
+/// Accepted values for `hawk.failureThreshold`
+const THRESHOLDS: &[&str] = &["high", "medium", "low"];
+
+/// Expected type of a configuration value
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    String,
+    Bool,
+    Int,
+    Map,
+    List,
+    StringList,
+    Url,
+    Uuid,
+    Threshold,
+}
+
+impl Kind {
+    fn describe(self) -> &'static str {
+        match self {
+            Kind::String => "a string",
+            Kind::Bool => "true or false",
+            Kind::Int => "a whole number",
+            Kind::Map => "a mapping",
+            Kind::List => "a list",
+            Kind::StringList => "a list of strings",
+            Kind::Url => "an http(s) URL",
+            Kind::Uuid => "a UUID",
+            Kind::Threshold => "one of high, medium, low",
+        }
+    }
+}
+
+/// Schema rules: (dotted path, expected kind, required when parent present)
+const RULES: &[(&str, Kind, bool)] = &[
+    ("app", Kind::Map, true),
+    ("app.applicationId", Kind::Uuid, true),
+    ("app.env", Kind::String, true),
+    ("app.host", Kind::Url, true),
+    ("app.excludePaths", Kind::StringList, false),
+    ("app.includePaths", Kind::StringList, false),
+    ("app.antiCsrfParam", Kind::String, false),
+    ("app.authentication", Kind::Map, false),
+    ("app.openApiConf", Kind::Map, false),
+    ("app.graphqlConf", Kind::Map, false),
+    ("app.soapConf", Kind::Map, false),
+    ("app.autoPolicy", Kind::Bool, false),
+    ("app.autoInputVectors", Kind::Bool, false),
+    ("hawk", Kind::Map, false),
+    ("hawk.spider", Kind::Map, false),
+    ("hawk.spider.base", Kind::Bool, false),
+    ("hawk.spider.ajax", Kind::Bool, false),
+    ("hawk.spider.maxDurationMinutes", Kind::Int, false),
+    ("hawk.scan", Kind::Map, false),
+    ("hawk.scan.maxDurationMinutes", Kind::Int, false),
+    ("hawk.scan.threads", Kind::Int, false),
+    ("hawk.scan.policyName", Kind::String, false),
+    ("hawk.failureThreshold", Kind::Threshold, false),
+    ("hawk.config", Kind::StringList, false),
+    ("hawkAddOn", Kind::Map, false),
+    ("tags", Kind::List, false),
+];
+
+/// Validate HawkScan configuration content
+pub fn validate(source: &str) -> ValidatedAssetResponse {
+    let mut markers = Vec::new();
+
+    let root: Value = match serde_yaml::from_str(source) {
+        Ok(value) => value,
+        Err(e) => {
+            let line = e.location().map(|l| l.line() as i32);
+            let message = format!("Invalid YAML: {}", e);
+            markers.push(marker("error", message, line));
+            return ValidatedAssetResponse { markers };
+        }
+    };
+
+    let Some(root_map) = root.as_mapping() else {
+        markers.push(marker(
+            "error",
+            "Configuration must be a YAML mapping with an `app` section".to_string(),
+            Some(1),
+        ));
+        return ValidatedAssetResponse { markers };
+    };
+
+    for key in root_map.keys().filter_map(Value::as_str) {
+        if !TOP_LEVEL_KEYS.contains(&key) {
+            markers.push(marker(
+                "warning",
+                format!(
+                    "Unknown top-level key `{}` (expected one of: {})",
+                    key,
+                    TOP_LEVEL_KEYS.join(", ")
+                ),
+                find_line(source, &[key]),
+            ));
+        }
+    }
+
+    for (path, kind, required) in RULES {
+        let segments: Vec<&str> = path.split('.').collect();
+        let (parent, _) = segments.split_at(segments.len() - 1);
+
+        // Only check children of parents that exist and are mappings
+        if !parent.is_empty() && lookup(&root, parent).and_then(Value::as_mapping).is_none() {
+            continue;
+        }
+
+        match lookup(&root, &segments) {
+            None | Some(Value::Null) => {
+                if *required {
+                    markers.push(marker(
+                        "error",
+                        format!("Missing required key `{}`", path),
+                        find_line(source, parent).or(Some(1)),
+                    ));
+                }
+            }
+            Some(value) => {
+                if let Some(problem) = check_kind(value, *kind) {
+                    markers.push(marker(
+                        "error",
+                        format!("`{}` must be {}{}", path, kind.describe(), problem),
+                        find_line(source, &segments),
+                    ));
+                }
+            }
+        }
+    }
+
+    if let Some(Value::Sequence(tags)) = lookup(&root, &["tags"]) {
+        for (i, tag) in tags.iter().enumerate() {
+            let valid = tag.as_mapping().is_some_and(|m| {
+                ["name", "value"]
+                    .iter()
+                    .all(|k| m.get(*k).and_then(Value::as_str).is_some())
+            });
+            if !valid {
+                markers.push(marker(
+                    "error",
+                    format!("`tags[{}]` must have string `name` and `value` keys", i),
+                    find_line(source, &["tags"]),
+                ));
+            }
+        }
+    }
+
+    ValidatedAssetResponse { markers }
+}
+
+/// Walk a dotted path through nested mappings
+fn lookup<'a>(root: &'a Value, path: &[&str]) -> Option<&'a Value> {
+    path.iter()
+        .try_fold(root, |node, key| node.as_mapping()?.get(*key))
+}
+
+/// Return a short reason if `value` does not match `kind`, `None` if it does
+///
+/// Strings containing `${...}` are environment-variable interpolations that
+/// HawkScan resolves at scan time, so they satisfy any scalar kind.
+fn check_kind(value: &Value, kind: Kind) -> Option<String> {
+    if let Some(s) = value.as_str()
+        && s.contains("${")
+        && !matches!(kind, Kind::Map | Kind::List | Kind::StringList)
+    {
+        return None;
+    }
+
+    let ok = match kind {
+        Kind::String => value.is_string(),
+        Kind::Bool => value.is_bool(),
+        Kind::Int => value.is_i64() || value.is_u64(),
+        Kind::Map => value.is_mapping(),
+        Kind::List => value.is_sequence(),
+        Kind::StringList => value
+            .as_sequence()
+            .is_some_and(|items| items.iter().all(Value::is_string)),
+        Kind::Url => {
+            return match value.as_str() {
+                Some(s) if s.starts_with("http://") || s.starts_with("https://") => None,
+                Some(s) => Some(format!(" (got `{}`)", s)),
+                None => Some(String::new()),
+            };
+        }
+        Kind::Uuid => {
+            return match value.as_str() {
+                Some(s) if is_uuid(s) => None,
+                Some(s) => Some(format!(" (got `{}`)", s)),
+                None => Some(String::new()),
+            };
+        }
+        Kind::Threshold => {
+            return match value.as_str() {
+                Some(s) if THRESHOLDS.contains(&s.to_lowercase().as_str()) => None,
+                Some(s) => Some(format!(" (got `{}`)", s)),
+                None => Some(String::new()),
+            };
+        }
+    };
+    if ok { None } else { Some(String::new()) }
+}
+
+/// 8-4-4-4-12 hex UUID check
+fn is_uuid(s: &str) -> bool {
+    let parts: Vec<&str> = s.split('-').collect();
+    let lengths = [8, 4, 4, 4, 12];
+    parts.len() == lengths.len()
+        && parts
+            .iter()
+            .zip(lengths)
+            .all(|(p, len)| p.len() == len && p.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Best-effort 1-based line of a dotted key path in block-style YAML
+pub(crate) fn find_line(source: &str, path: &[&str]) -> Option<i32> {
+    if path.is_empty() {
+        return None;
+    }
+
+    let mut depth = 0;
+    let mut parent_indent: Option<usize> = None;
+    for (i, line) in source.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let indent = line.len() - trimmed.len();
+
+        if parent_indent.is_some_and(|parent| indent <= parent) {
+            // Left the parent's block without finding the child
+            return None;
+        }
+
+        let key = trimmed.split(':').next().unwrap_or("").trim();
+        if key == path[depth] && trimmed[key.len()..].trim_start().starts_with(':') {
+            depth += 1;
+            if depth == path.len() {
+                return Some(i as i32 + 1);
+            }
+            parent_indent = Some(indent);
+        }
+    }
+    None
+}
+
+fn marker(severity: &str, message: String, line: Option<i32>) -> ValidationMarker {
+    ValidationMarker {
+        code: None,
+        end_column: None,
+        end_line_number: line,
+        message: Some(message),
+        owner: Some(OWNER.to_string()),
+        resource: None,
+        severity: Some(severity.to_string()),
+        start_column: None,
+        start_line_number: line,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID: &str = "\
+app:
+  applicationId: 3fa85f64-5717-4562-b3fc-2c963f66afa6
+  env: Development
+  host: http://localhost:8080
+  excludePaths:
+    - /logout
+hawk:
+  spider:
+    base: true
+  failureThreshold: high
+tags:
+  - name: branch
+    value: main
+";
+
+    fn messages(source: &str) -> Vec<(String, String, Option<i32>)> {
+        validate(source)
+            .markers
+            .into_iter()
+            .map(|m| (m.severity.unwrap(), m.message.unwrap(), m.start_line_number))
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_config_has_no_markers() {
+        assert!(messages(VALID).is_empty());
+    }
+
+    #[test]
+    fn test_missing_required_keys() {
+        let result = validate("app:\n  env: Development\n");
+        assert!(!result.is_valid());
+        let errors: Vec<_> = result
+            .errors()
+            .iter()
+            .map(|m| m.message.clone().unwrap())
+            .collect();
+        assert_eq!(
+            errors,
+            vec![
+                "Missing required key `app.applicationId`",
+                "Missing required key `app.host`"
+            ]
+        );
+        assert_eq!(result.errors()[0].start_line_number, Some(1));
+
+        let result = validate("hawk:\n  spider:\n    base: true\n");
+        assert_eq!(
+            result.errors()[0].message.as_deref(),
+            Some("Missing required key `app`")
+        );
+    }
+
+    #[test]
+    fn test_type_errors_point_at_the_key() {
+        let source = VALID
+            .replace("base: true", "base: yes please")
+            .replace("failureThreshold: high", "failureThreshold: critical");
+        let found = messages(&source);
+        assert_eq!(found.len(), 2);
+        assert_eq!(
+            found[0],
+            (
+                "error".to_string(),
+                "`hawk.spider.base` must be true or false".to_string(),
+                Some(9)
+            )
+        );
+        assert_eq!(
+            found[1].1,
+            "`hawk.failureThreshold` must be one of high, medium, low (got `critical`)"
+        );
+        assert_eq!(found[1].2, Some(10));
+    }
+
+    #[test]
+    fn test_env_interpolation_is_accepted() {
+        let source = "app:\n  applicationId: ${APP_ID}\n  env: ${APP_ENV:dev}\n  host: ${HOST}\n";
+        assert!(messages(source).is_empty());
+    }
+
+    #[test]
+    fn test_yaml_syntax_error_reports_line() {
+        let found = messages("app:\n  env: [unclosed\n");
+        assert_eq!(found.len(), 1);
+        assert!(found[0].1.starts_with("Invalid YAML"));
+        assert!(found[0].2.is_some());
+    }
+
+    #[test]
+    fn test_unknown_top_level_key_warns() {
+        let source = format!("{}hawkscan:\n  foo: 1\n", VALID);
+        let result = validate(&source);
+        assert!(result.is_valid());
+        assert_eq!(result.warnings().len(), 1);
+        assert_eq!(result.warnings()[0].start_line_number, Some(14));
+    }
+
+    #[test]
+    fn test_bad_tags() {
+        let source = VALID.replace("    value: main\n", "");
+        let found = messages(&source);
+        assert_eq!(found.len(), 1);
+        assert_eq!(
+            found[0].1,
+            "`tags[0]` must have string `name` and `value` keys"
+        );
+    }
+
+    #[test]
+    fn test_is_uuid() {
+        assert!(is_uuid("3fa85f64-5717-4562-b3fc-2c963f66afa6"));
+        assert!(!is_uuid("3fa85f64-5717-4562-b3fc"));
+        assert!(!is_uuid("zfa85f64-5717-4562-b3fc-2c963f66afa6"));
+    }
+}
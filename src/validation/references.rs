@@ -0,0 +1,124 @@
+//! Secret references in HawkScan configuration files
+//!
+//! HawkScan interpolates `${NAME}` placeholders (optionally with a default,
+//! `${NAME:default}`) from the environment at scan time, and user secrets
+//! are injected the same way. `${secret.NAME}` is accepted as well. A
+//! configuration depends on a secret when one of its placeholders names it;
+//! placeholders in comment lines are ignored, since HawkScan never reads them.
+
+use serde_yaml::Value;
+
+use super::lookup;
+
+/// A placeholder naming a secret
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reference {
+    /// 1-based line of the placeholder
+    pub line: usize,
+    /// The placeholder as written, e.g. `${API_KEY:none}`
+    pub placeholder: String,
+}
+
+/// Placeholders in `source` that name `secret`
+///
+/// Names are matched exactly, since environment variables are case-sensitive.
+pub fn find_references(source: &str, secret: &str) -> Vec<Reference> {
+    let mut references = Vec::new();
+    for (i, line) in source.lines().enumerate() {
+        if line.trim_start().starts_with('#') {
+            continue;
+        }
+        let mut rest = line;
+        while let Some(start) = rest.find("${") {
+            let Some(len) = rest[start..].find('}') else {
+                break;
+            };
+            let placeholder = &rest[start..start + len + 1];
+            if placeholder_name(placeholder) == secret {
+                references.push(Reference {
+                    line: i + 1,
+                    placeholder: placeholder.to_string(),
+                });
+            }
+            rest = &rest[start + len + 1..];
+        }
+    }
+    references
+}
+
+/// Name in a `${...}` placeholder, without default or `secret.` prefix
+fn placeholder_name(placeholder: &str) -> &str {
+    let body = &placeholder[2..placeholder.len() - 1];
+    let name = body.split(':').next().unwrap_or(body).trim();
+    name.strip_prefix("secret.").unwrap_or(name)
+}
+
+/// The `app.applicationId` and `app.env` a configuration scans, where they
+/// are literal values
+///
+/// Values that are themselves placeholders are resolved only at scan time,
+/// so they are left out.
+pub fn scanned_app(source: &str) -> (Option<String>, Option<String>) {
+    let Ok(root) = serde_yaml::from_str::<Value>(source) else {
+        return (None, None);
+    };
+    let literal = |key: &str| {
+        lookup(&root, &["app", key])
+            .and_then(Value::as_str)
+            .filter(|value| !value.contains("${"))
+            .map(str::to_string)
+    };
+    (literal("applicationId"), literal("env"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = "\
+app:
+  applicationId: 3fa85f64-5717-4562-b3fc-2c963f66afa6
+  env: ${APP_ENV:dev}
+  authentication:
+    usernamePassword:
+      username: ${USER}
+      password: ${API_KEY}
+    # password: ${API_KEY_OLD}
+hawk:
+  headers: \"Bearer ${secret.API_KEY} ${API_KEY:none}\"
+";
+
+    #[test]
+    fn test_find_references() {
+        let references = find_references(CONFIG, "API_KEY");
+        let lines: Vec<(usize, &str)> = references
+            .iter()
+            .map(|r| (r.line, r.placeholder.as_str()))
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                (7, "${API_KEY}"),
+                (10, "${secret.API_KEY}"),
+                (10, "${API_KEY:none}")
+            ]
+        );
+
+        // Commented out, and case-sensitive
+        assert!(find_references(CONFIG, "API_KEY_OLD").is_empty());
+        assert!(find_references(CONFIG, "api_key").is_empty());
+        assert!(find_references("token: ${UNCLOSED", "UNCLOSED").is_empty());
+    }
+
+    #[test]
+    fn test_scanned_app() {
+        assert_eq!(
+            scanned_app(CONFIG),
+            (
+                Some("3fa85f64-5717-4562-b3fc-2c963f66afa6".to_string()),
+                None
+            )
+        );
+        assert_eq!(scanned_app("not: [valid"), (None, None));
+    }
+}
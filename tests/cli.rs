@@ -1,7 +1,7 @@
 use assert_cmd::prelude::*;
 use chrono::Utc;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use tempfile::tempdir;
 
@@ -9,7 +9,7 @@ fn future_timestamp() -> String {
     (Utc::now() + chrono::Duration::hours(1)).to_rfc3339()
 }
 
-fn write_config(temp: &PathBuf, org_id: &str) -> PathBuf {
+fn write_config(temp: &Path, org_id: &str) -> PathBuf {
     let path = temp.join("config.yaml");
     let contents = format!(
         "api_key: test-key\norg_id: {org_id}\njwt:\n  token: dummy\n  expires_at: {}\npreferences:\n  page_size: 1000\n",
@@ -19,10 +19,19 @@ fn write_config(temp: &PathBuf, org_id: &str) -> PathBuf {
     path
 }
 
+/// Config with `preferences.retries` set, so failure tests don't wait on backoff
+fn write_config_with_retries(temp: &Path, org_id: &str, retries: u32) -> PathBuf {
+    let path = write_config(temp, org_id);
+    let mut contents = fs::read_to_string(&path).expect("failed to read config");
+    contents.push_str(&format!("  retries: {retries}\n"));
+    fs::write(&path, contents).expect("failed to write config");
+    path
+}
+
 #[test]
 fn status_uses_custom_config_path() -> Result<(), Box<dyn std::error::Error>> {
     let temp = tempdir()?;
-    let config_path = write_config(&temp.path().to_path_buf(), "org-status");
+    let config_path = write_config(temp.path(), "org-status");
 
     let assert = Command::new(assert_cmd::cargo::cargo_bin!("hawkop"))
         .arg("status")
@@ -63,7 +72,7 @@ fn org_get_prefers_runtime_org_override() -> Result<(), Box<dyn std::error::Erro
         .create();
 
     let temp = tempdir()?;
-    let config_path = write_config(&temp.path().to_path_buf(), "config-org");
+    let config_path = write_config(temp.path(), "config-org");
 
     let assert = Command::new(assert_cmd::cargo::cargo_bin!("hawkop"))
         .arg("org")
@@ -106,7 +115,7 @@ fn org_set_updates_custom_config_path() -> Result<(), Box<dyn std::error::Error>
         .create();
 
     let temp = tempdir()?;
-    let config_path = write_config(&temp.path().to_path_buf(), "old-org");
+    let config_path = write_config(temp.path(), "old-org");
 
     Command::new(assert_cmd::cargo::cargo_bin!("hawkop"))
         .arg("--no-cache")
@@ -160,7 +169,7 @@ fn app_list_uses_v2_base_url() -> Result<(), Box<dyn std::error::Error>> {
         .create();
 
     let temp = tempdir()?;
-    let config_path = write_config(&temp.path().to_path_buf(), "org-123");
+    let config_path = write_config(temp.path(), "org-123");
 
     let assert = Command::new(assert_cmd::cargo::cargo_bin!("hawkop"))
         .arg("--no-cache")
@@ -239,7 +248,7 @@ fn invalid_org_id_returns_not_found() -> Result<(), Box<dyn std::error::Error>>
         .create();
 
     let temp = tempdir()?;
-    let config_path = write_config(&temp.path().to_path_buf(), "nonexistent-org-xyz");
+    let config_path = write_config(temp.path(), "nonexistent-org-xyz");
 
     let assert = Command::new(assert_cmd::cargo::cargo_bin!("hawkop"))
         .arg("--no-cache")
@@ -292,7 +301,7 @@ fn unauthorized_error_suggests_init() -> Result<(), Box<dyn std::error::Error>>
         .create();
 
     let temp = tempdir()?;
-    let config_path = write_config(&temp.path().to_path_buf(), "org-123");
+    let config_path = write_config(temp.path(), "org-123");
 
     let assert = Command::new(assert_cmd::cargo::cargo_bin!("hawkop"))
         .arg("--no-cache")
@@ -332,7 +341,7 @@ fn rate_limit_shows_retry_message() -> Result<(), Box<dyn std::error::Error>> {
         .create();
 
     let temp = tempdir()?;
-    let config_path = write_config(&temp.path().to_path_buf(), "org-123");
+    let config_path = write_config(temp.path(), "org-123");
 
     let assert = Command::new(assert_cmd::cargo::cargo_bin!("hawkop"))
         .arg("--no-cache")
@@ -362,15 +371,17 @@ fn server_error_shows_helpful_message() -> Result<(), Box<dyn std::error::Error>
     let mut server = mockito::Server::new();
     let api_host = server.url();
 
-    // Mock user endpoint to return 500
-    let _server_error = server
+    // Mock user endpoint to return 500 (retried once, then reported)
+    let server_error = server
         .mock("GET", "/api/v1/user")
         .with_status(500)
+        .with_header("retry-after", "0")
         .with_body(r#"{"error": "Internal server error"}"#)
+        .expect(2)
         .create();
 
     let temp = tempdir()?;
-    let config_path = write_config(&temp.path().to_path_buf(), "org-123");
+    let config_path = write_config_with_retries(temp.path(), "org-123", 1);
 
     let assert = Command::new(assert_cmd::cargo::cargo_bin!("hawkop"))
         .arg("--no-cache")
@@ -391,6 +402,7 @@ fn server_error_shows_helpful_message() -> Result<(), Box<dyn std::error::Error>
         "Expected error to mention server error, got: {}",
         stderr
     );
+    server_error.assert();
 
     Ok(())
 }
@@ -399,7 +411,7 @@ fn server_error_shows_helpful_message() -> Result<(), Box<dyn std::error::Error>
 #[test]
 fn connection_error_shows_network_message() -> Result<(), Box<dyn std::error::Error>> {
     let temp = tempdir()?;
-    let config_path = write_config(&temp.path().to_path_buf(), "org-123");
+    let config_path = write_config_with_retries(temp.path(), "org-123", 0);
 
     // Point to a port that nothing is listening on
     let assert = Command::new(assert_cmd::cargo::cargo_bin!("hawkop"))